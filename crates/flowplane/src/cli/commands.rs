@@ -107,6 +107,31 @@ pub enum OrgCommand {
         #[command(subcommand)]
         command: OrgMemberCommand,
     },
+    /// Manage the filters merged into each team's first listener.
+    DefaultFilters {
+        #[command(subcommand)]
+        command: OrgDefaultFiltersCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum OrgDefaultFiltersCommand {
+    /// Show an organization's default listener filters.
+    Get {
+        /// Organization whose defaults to show.
+        org: String,
+    },
+    /// Replace an organization's default listener filters.
+    #[command(
+        after_help = "Example (the file holds {\"filters\": [...]}, entries shaped like listener http_filters):\n  flowplane org default-filters set acme -f defaults.json"
+    )]
+    Set {
+        /// Organization whose defaults to replace.
+        org: String,
+        /// Path to the JSON request body (use `-` for stdin).
+        #[arg(short, long)]
+        file: PathBuf,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -157,6 +182,48 @@ pub enum TeamCommand {
         /// Optional human-readable display name for the team.
         #[arg(long)]
         display_name: Option<String>,
+        /// Optional free-text description of the team.
+        #[arg(long)]
+        description: Option<String>,
+        /// Envoy admin port the team's dataplane bootstraps default to.
+        #[arg(long)]
+        envoy_admin_port: Option<u16>,
+        /// Envoy admin interface exposure the team's dataplane bootstraps default to.
+        #[arg(long, value_enum)]
+        envoy_admin_access: Option<EnvoyAdminAccessArg>,
+    },
+    /// Show a team.
+    Get {
+        /// Team to show; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Update a team's display name, description, or Envoy admin settings.
+    #[command(
+        after_help = "Example:\n  flowplane team update --team payments --envoy-admin-port 9902\n\nFlags left out keep their current value."
+    )]
+    Update {
+        /// Team to update; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// New human-readable display name.
+        #[arg(long)]
+        display_name: Option<String>,
+        /// New free-text description.
+        #[arg(long)]
+        description: Option<String>,
+        /// New Envoy admin port for the team's dataplane bootstraps.
+        #[arg(long, conflicts_with = "clear_envoy_admin_port")]
+        envoy_admin_port: Option<u16>,
+        /// Remove the team's Envoy admin port so bootstraps use the default (9901).
+        #[arg(long)]
+        clear_envoy_admin_port: bool,
+        /// New Envoy admin interface exposure for the team's dataplane bootstraps.
+        #[arg(long, value_enum, conflicts_with = "clear_envoy_admin_access")]
+        envoy_admin_access: Option<EnvoyAdminAccessArg>,
+        /// Remove the team's Envoy admin access so bootstraps use the default (localhost).
+        #[arg(long)]
+        clear_envoy_admin_access: bool,
     },
     /// Delete a team.
     Delete {
@@ -164,6 +231,24 @@ pub enum TeamCommand {
         #[arg(long)]
         team: Option<String>,
     },
+    /// Show a team's resource quotas and current usage.
+    Quotas {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Replace a team's quota overrides from a JSON file (platform admins).
+    #[command(
+        after_help = "Example:\n  flowplane team set-quotas --team 018ff2ef-bfc6-7000-8000-000000000001 -f quotas.json\n\nThe body is {\"limits\": {\"clusters\": 100}}; kinds left out use the default."
+    )]
+    SetQuotas {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Path to the JSON request body (use `-` for stdin).
+        #[arg(short, long)]
+        file: PathBuf,
+    },
     /// Manage team members.
     Member {
         #[command(subcommand)]
@@ -258,7 +343,7 @@ pub enum ResourceCommand {
     },
     /// Create a resource from a JSON file.
     #[command(
-        after_help = "Example (resource create takes the JSON body via -f):\n  flowplane cluster create --team payments -f resource.json\n\nThe same -f body shape applies to listener / ai providers|routes|budgets / rate-limit domain."
+        after_help = "Example (resource create takes the JSON body via -f):\n  flowplane cluster create --team payments -f resource.json\n\nThe same -f body shape applies to listener / ai providers|routes|budgets / rate-limit domain / filter-preset / retry-policy."
     )]
     Create {
         /// Team scope; defaults to the active context's team.
@@ -270,7 +355,7 @@ pub enum ResourceCommand {
     },
     /// Update a resource from a JSON file (requires `--revision`).
     #[command(
-        after_help = "Example (resource update takes the JSON body via -f and the current --revision):\n  flowplane cluster update web --team payments -f resource.json --revision 3\n\nThe same shape applies to listener / ai providers|routes|budgets / rate-limit domain."
+        after_help = "Example (resource update takes the JSON body via -f and the current --revision):\n  flowplane cluster update web --team payments -f resource.json --revision 3\n\nThe same shape applies to listener / ai providers|routes|budgets / rate-limit domain / filter-preset / retry-policy."
     )]
     Update {
         /// Team scope; defaults to the active context's team.
@@ -292,6 +377,120 @@ pub enum ResourceCommand {
     },
 }
 
+/// Cluster and listener verbs: the shared resource CRUD plus `clone` and `transfer`.
+#[derive(Debug, Subcommand)]
+pub enum GatewayResourceCommand {
+    #[command(flatten)]
+    Crud(ResourceCommand),
+    /// Copy a resource under a new name, optionally into another team.
+    #[command(
+        after_help = "Example:\n  flowplane cluster clone web web-canary --team payments\n  flowplane listener clone edge edge --team payments --to-team checkout"
+    )]
+    Clone {
+        /// Team scope of the source; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the resource to copy.
+        name: String,
+        /// Name of the copy.
+        new_name: String,
+        /// Team (name or UUID) that should own the copy; defaults to the source team.
+        #[arg(long)]
+        to_team: Option<String>,
+    },
+    /// Move a resource to another team of the same org (`--revision` optional).
+    #[command(
+        after_help = "Example:\n  flowplane cluster transfer web --team payments --to-team checkout\n  flowplane listener transfer edge --team payments --to-team checkout --revision 4"
+    )]
+    Transfer {
+        /// Team that owns the resource now; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the resource to move.
+        name: String,
+        /// Team (name or UUID) that should own the resource.
+        #[arg(long)]
+        to_team: String,
+    },
+}
+
+/// Cluster verbs: the shared gateway-resource verbs plus live outlier-ejection status,
+/// upstream stats, and reverse dependencies.
+#[derive(Debug, Subcommand)]
+pub enum ClusterCommand {
+    #[command(flatten)]
+    Resource(GatewayResourceCommand),
+    /// Show endpoints currently ejected by outlier detection.
+    Outliers {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the cluster.
+        name: String,
+    },
+    /// Show active requests, ejections, and open circuit breakers per dataplane.
+    Stats {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the cluster.
+        name: String,
+    },
+    /// Show the routes, route configs, listeners and aggregates that depend on a cluster.
+    References {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the cluster.
+        name: String,
+    },
+}
+
+/// Listener verbs: the shared gateway-resource verbs plus a filter-chain dry run.
+#[derive(Debug, Subcommand)]
+pub enum ListenerCommand {
+    #[command(flatten)]
+    Resource(GatewayResourceCommand),
+    /// Validate an HTTP filter chain without creating anything.
+    #[command(
+        after_help = "Example (the file is `{\"http_filters\": [...]}`, as in a listener spec):\n  flowplane listener validate-filters -f filters.json"
+    )]
+    ValidateFilters {
+        /// Path to the JSON request body (use `-` for stdin).
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+    /// Have the control plane's Envoy binary validate a listener, without creating anything.
+    #[command(
+        after_help = "Example (the file is `{\"name\": ..., \"spec\": {...}}`, as for listener create):\n  flowplane listener validate-envoy -f listener.json"
+    )]
+    ValidateEnvoy {
+        /// Path to the JSON request body (use `-` for stdin).
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+    /// Serve a maintenance response instead of the listener's routes, or (`--off`) stop.
+    #[command(
+        after_help = "Example:\n  flowplane listener maintenance edge --team payments\n  flowplane listener maintenance edge --team payments --off"
+    )]
+    Maintenance {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the listener.
+        name: String,
+        /// Leave maintenance mode and serve the listener's routes again.
+        #[arg(long, conflicts_with_all = ["status", "body"])]
+        off: bool,
+        /// Response status while in maintenance, 500-599 (server default 503).
+        #[arg(long)]
+        status: Option<u16>,
+        /// Response body while in maintenance (server default: a short notice).
+        #[arg(long)]
+        body: Option<String>,
+    },
+}
+
 #[derive(Debug, Subcommand)]
 pub enum RouteCommand {
     /// List route configurations.
@@ -340,6 +539,104 @@ pub enum RouteCommand {
         /// Name of the route configuration to delete.
         name: String,
     },
+    /// Copy a route configuration under a new name, optionally into another team.
+    #[command(
+        after_help = "Example:\n  flowplane route clone edge edge-v2 --team payments\n  flowplane route clone edge edge --team payments --to-team checkout"
+    )]
+    Clone {
+        /// Team scope of the source; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the route configuration to copy.
+        name: String,
+        /// Name of the copy.
+        new_name: String,
+        /// Team (name or UUID) that should own the copy; defaults to the source team.
+        #[arg(long)]
+        to_team: Option<String>,
+    },
+    /// Move a route configuration to another team of the same org (`--revision` optional).
+    #[command(
+        after_help = "Example:\n  flowplane route transfer edge --team payments --to-team checkout"
+    )]
+    Transfer {
+        /// Team that owns the route configuration now; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the route configuration to move.
+        name: String,
+        /// Team (name or UUID) that should own the route configuration.
+        #[arg(long)]
+        to_team: String,
+    },
+    /// Append one virtual host (JSON file) to a route configuration (`--revision` optional).
+    #[command(
+        after_help = "Example:\n  flowplane route add-vhost edge --team payments -f vhost.json"
+    )]
+    AddVhost {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the route configuration to extend.
+        name: String,
+        /// Path to the virtual-host JSON (use `-` for stdin).
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+    /// Remove one virtual host, by name, from a route configuration (`--revision` optional).
+    RemoveVhost {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the route configuration.
+        name: String,
+        /// Name of the virtual host to remove.
+        vhost: String,
+    },
+    /// Show the filters one route runs on each listener serving the route configuration,
+    /// with the virtual-host or route override that wins for each.
+    EffectiveFilters {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the route configuration.
+        name: String,
+        /// Name of the virtual host holding the route.
+        vhost: String,
+        /// Name of the route.
+        route: String,
+    },
+    /// Split one route's traffic between a stable and a canary cluster, or (`--promote` /
+    /// `--abort`) move all of it to the canary or back to stable.
+    #[command(
+        after_help = "Example:\n  flowplane route canary edge default api --team payments --stable api-v1 --canary api-v2 --weight 10\n  flowplane route canary edge default api --team payments --promote"
+    )]
+    Canary {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the route configuration.
+        name: String,
+        /// Name of the virtual host holding the route.
+        vhost: String,
+        /// Name of the route.
+        route: String,
+        /// Cluster serving the current release.
+        #[arg(long, required_unless_present_any = ["promote", "abort"])]
+        stable: Option<String>,
+        /// Cluster serving the new release.
+        #[arg(long, required_unless_present_any = ["promote", "abort"])]
+        canary: Option<String>,
+        /// Percentage of traffic sent to the canary (0-100).
+        #[arg(long, required_unless_present_any = ["promote", "abort"])]
+        weight: Option<u32>,
+        /// Send all traffic to the canary of the current split.
+        #[arg(long, conflicts_with_all = ["stable", "canary", "weight", "abort"])]
+        promote: bool,
+        /// Send all traffic back to the stable cluster of the current split.
+        #[arg(long, conflicts_with_all = ["stable", "canary", "weight"])]
+        abort: bool,
+    },
     /// Generate a route plan from a published API spec.
     #[command(
         after_help = "Example:\n  flowplane route generate --team payments --from-spec 018ff2ef-bfc6-7000-8000-000000000001 --listener-port 19090"
@@ -354,6 +651,12 @@ pub enum RouteCommand {
         /// Listener port the generated routes bind to.
         #[arg(long)]
         listener_port: u16,
+        /// Emit one route per path matching all of its methods instead of one per operation.
+        #[arg(long)]
+        coalesce_methods: bool,
+        /// Plan an update of the resources generated by the API's last applied plan.
+        #[arg(long)]
+        sync: bool,
     },
     /// Apply a previously generated route plan.
     Apply {
@@ -363,6 +666,28 @@ pub enum RouteCommand {
         /// Identifier of the previously generated route plan to apply.
         plan_id: String,
     },
+    /// List the team's route generation plans, newest first.
+    Plans {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Show the resources an applied route plan produced, with their current revisions.
+    PlanResources {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Identifier of the applied route plan.
+        plan_id: String,
+    },
+    /// Delete the resources an applied route plan produced: listener, route config, cluster.
+    PlanTeardown {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Identifier of the applied route plan.
+        plan_id: String,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -783,6 +1108,18 @@ pub enum LearnCommand {
         /// Maximum number of distinct paths to track.
         #[arg(long, default_value_t = 500)]
         max_distinct_paths: i32,
+        /// Percentage of requests (1-100) to capture; all requests when omitted.
+        #[arg(long)]
+        sample_percent: Option<i32>,
+        /// Maximum bytes stored per request or response body (at most 65536).
+        #[arg(long)]
+        max_body_bytes: Option<i32>,
+        /// Header name to store as [REDACTED]; repeat for more headers.
+        #[arg(long = "redact-header")]
+        redact_headers: Vec<String>,
+        /// JSON body field whose value is stored as [REDACTED]; repeat for more fields.
+        #[arg(long = "redact-body-field")]
+        redact_body_fields: Vec<String>,
     },
     /// List capture sessions.
     List {
@@ -823,6 +1160,14 @@ pub enum LearnCommand {
         /// Identifier of the capture session to generate a spec from.
         session: String,
     },
+    /// Print the OpenAPI document learned so far, without storing a spec version.
+    ExportSpec {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Identifier of the capturing or completed session to export.
+        session: String,
+    },
     /// Cancel a capture session.
     Cancel {
         /// Team scope; defaults to the active context's team.
@@ -934,6 +1279,19 @@ pub enum SecretCommand {
         #[arg(short, long)]
         file: PathBuf,
     },
+    /// Create several secrets at once from a JSON file (`{"secrets": [...]}`); all are
+    /// created or none are.
+    #[command(
+        after_help = "Example:\n  flowplane secret create-batch --team payments -f secrets.json"
+    )]
+    CreateBatch {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Path to the JSON request body (use `-` for stdin).
+        #[arg(short, long)]
+        file: PathBuf,
+    },
     /// Rotate a secret's value from a JSON file.
     #[command(
         after_help = "Example:\n  flowplane secret rotate db-password --team payments --revision 2 -f secret.json"
@@ -953,6 +1311,177 @@ pub enum SecretCommand {
     },
 }
 
+#[derive(Debug, Subcommand)]
+pub enum ProtoDescriptorCommand {
+    /// List uploaded proto descriptor sets (metadata only).
+    List {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Show one proto descriptor set's metadata and declared services.
+    Get {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the proto descriptor to show.
+        name: String,
+    },
+    /// Upload a compiled FileDescriptorSet for the gRPC-JSON transcoder.
+    #[command(
+        after_help = "Example:\n  flowplane proto-descriptor create bookstore --team payments --descriptor-set bookstore.pb"
+    )]
+    Create {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name for the uploaded descriptor set.
+        name: String,
+        /// Path to the binary FileDescriptorSet (`protoc --include_imports --descriptor_set_out`).
+        #[arg(long)]
+        descriptor_set: PathBuf,
+    },
+    /// Delete an unreferenced proto descriptor set.
+    Delete {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the proto descriptor to delete.
+        name: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WasmModuleCommand {
+    /// List uploaded wasm modules, or every version of one module (metadata only).
+    List {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Only list the versions of this module.
+        name: Option<String>,
+    },
+    /// Show one wasm module version's metadata and SHA-256.
+    Get {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the wasm module.
+        name: String,
+        /// Version to show.
+        version: i64,
+    },
+    /// Upload a compiled wasm module; each upload under a name adds the next version.
+    #[command(
+        after_help = "Example:\n  flowplane wasm-module create authz-plugin --team payments --module authz.wasm"
+    )]
+    Create {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name for the module; an existing name gets a new version.
+        name: String,
+        /// Path to the compiled `.wasm` binary.
+        #[arg(long)]
+        module: PathBuf,
+    },
+    /// Delete a wasm module version no listener references.
+    Delete {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the wasm module.
+        name: String,
+        /// Version to delete.
+        version: i64,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RuntimeCommand {
+    /// List the team's runtime values.
+    List {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Show one runtime value.
+    Get {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Runtime key, e.g. `routes.split.enabled`.
+        key: String,
+    },
+    /// Set a runtime value; proxies pick it up without a config push.
+    #[command(
+        after_help = "Example:\n  flowplane runtime set routes.checkout.canary 10 --team payments"
+    )]
+    Set {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Runtime key, e.g. `routes.split.enabled`.
+        key: String,
+        /// A number, `true`/`false`, or any other text as a string.
+        value: String,
+    },
+    /// Remove a runtime value; readers fall back to their configured default.
+    Delete {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Runtime key to remove.
+        key: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WebhookCommand {
+    /// List the team's webhook subscriptions.
+    List {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Show one webhook subscription.
+    Get {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the webhook to show.
+        name: String,
+    },
+    /// Subscribe a URL to the team's domain events.
+    #[command(
+        after_help = "Example:\n  flowplane webhook create deploys --team payments --url https://hooks.example.com/flowplane --event 'cluster.*' --event route_config.upserted"
+    )]
+    Create {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the webhook to create.
+        name: String,
+        /// `http://` or `https://` endpoint that receives the events.
+        #[arg(long)]
+        url: String,
+        /// Event filter: an exact kind, a family like `cluster.*`, or `*`; repeatable.
+        #[arg(long = "event", required = true)]
+        events: Vec<String>,
+        /// UUID of a team `generic_secret` that signs each delivery.
+        #[arg(long)]
+        secret_id: Option<String>,
+    },
+    /// Remove a webhook subscription.
+    Delete {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the webhook to remove.
+        name: String,
+    },
+}
+
 #[derive(Debug, Subcommand)]
 pub enum DataplaneCommand {
     /// List dataplanes.
@@ -969,6 +1498,12 @@ pub enum DataplaneCommand {
         /// Name of the dataplane to show.
         name: String,
     },
+    /// List Envoys currently streaming xDS from the control-plane replica that answers.
+    Connected {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
     /// Register a dataplane.
     #[command(after_help = "Example:\n  flowplane dataplane create edge-1 --team payments")]
     Create {
@@ -1015,9 +1550,15 @@ pub enum DataplaneCommand {
         /// xDS server port the Envoy bootstrap points at.
         #[arg(long, default_value_t = 18000)]
         xds_port: u16,
-        /// Envoy admin interface port.
-        #[arg(long, default_value_t = 9901)]
-        admin_port: u16,
+        /// Envoy admin interface port; defaults to the team's admin port, else 9901.
+        #[arg(long)]
+        admin_port: Option<u16>,
+        /// Envoy admin interface exposure; defaults to the team's setting, else localhost.
+        #[arg(long, value_enum)]
+        admin_access: Option<EnvoyAdminAccessArg>,
+        /// Unix socket path for `--admin-access socket` (default /var/run/envoy/admin.sock).
+        #[arg(long)]
+        admin_socket_path: Option<String>,
         /// Path to the client certificate for mTLS xDS.
         #[arg(long)]
         cert_path: Option<String>,
@@ -1027,12 +1568,79 @@ pub enum DataplaneCommand {
         /// Path to the CA certificate for mTLS xDS.
         #[arg(long)]
         ca_path: Option<String>,
+        /// Have the control plane's Envoy binary validate the bootstrap before returning it.
+        #[arg(long)]
+        validate: bool,
     },
     /// Manage dataplane proxy certificates.
     Cert {
         #[command(subcommand)]
         command: CertCommand,
     },
+    /// List the config versions recorded for the team, newest first.
+    ConfigVersions {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Hold a dataplane on a recorded config version.
+    #[command(
+        after_help = "Example:\n  flowplane dataplane pin edge-1 --config-version 3f2a9c0d1e4b5a6c --team payments"
+    )]
+    Pin {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the dataplane to pin.
+        name: String,
+        /// Recorded config version to hold the dataplane on.
+        #[arg(long)]
+        config_version: String,
+    },
+    /// Release a pinned dataplane to the latest config.
+    Promote {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Name of the dataplane to promote.
+        name: String,
+    },
+    /// Manage the team's staged config rollout.
+    Rollout {
+        #[command(subcommand)]
+        command: RolloutCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RolloutCommand {
+    /// Show the team's config rollout.
+    Get {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Start or retarget a rollout: the canary share gets latest, the rest stay on the stable version.
+    #[command(
+        after_help = "Example:\n  flowplane dataplane rollout set --stable-version 3f2a9c0d1e4b5a6c --canary-percent 10 --team payments"
+    )]
+    Set {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+        /// Recorded config version proxies outside the canary share stay on.
+        #[arg(long)]
+        stable_version: String,
+        /// Share of the team's proxies (0-100) that receive the latest config.
+        #[arg(long)]
+        canary_percent: i64,
+    },
+    /// End the rollout and move every proxy that is not pinned to the latest config.
+    Promote {
+        /// Team scope; defaults to the active context's team.
+        #[arg(long)]
+        team: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
@@ -1052,6 +1660,26 @@ impl DataplaneBootstrapMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum EnvoyAdminAccessArg {
+    /// Admin API on 127.0.0.1 at the admin port.
+    Localhost,
+    /// Admin API on a Unix domain socket readable only by Envoy's user.
+    Socket,
+    /// No admin API.
+    Disabled,
+}
+
+impl EnvoyAdminAccessArg {
+    pub(crate) fn as_query_value(self) -> &'static str {
+        match self {
+            Self::Localhost => "localhost",
+            Self::Socket => "socket",
+            Self::Disabled => "disabled",
+        }
+    }
+}
+
 #[derive(Debug, Args)]
 pub struct ExposeCommand {
     /// Upstream address to expose through the gateway.
@@ -1165,6 +1793,13 @@ pub enum OpsCommand {
         #[arg(long, default_value_t = 50)]
         limit: i64,
     },
+    /// Platform-wide support bundle (platform admin only).
+    Diagnostics,
+    /// Webhook notices that failed every retry (platform admin only).
+    WebhookDeadLetters,
+    /// Rebuild every team's xDS snapshot from the database and list what was corrected
+    /// (platform admin only).
+    Reconcile,
 }
 
 #[derive(Debug, Subcommand)]
@@ -18,11 +18,12 @@ use std::time::{Duration, Instant};
 use client::RestClient;
 pub use commands::{
     AiCommand, AiRetentionCommand, ApiCommand, ApplyCommand, AuthCommand, CertCommand,
-    ConfigCommand, DataplaneBootstrapMode, DataplaneCommand, ExposeCommand, GrantCommand,
-    LearnCommand, LearnDiscoverCommand, McpCommand, OpsCommand, OrgCommand, OrgMemberCommand,
-    RateLimitCommand, RateLimitOverrideCommand, RateLimitPolicyCommand, ResourceCommand,
-    RouteCommand, SecretCommand, StatsCommand, TeamCommand, TeamMemberCommand, UnexposeCommand,
-    XdsCommand,
+    ClusterCommand, ConfigCommand, DataplaneBootstrapMode, DataplaneCommand, ExposeCommand,
+    GatewayResourceCommand, GrantCommand, LearnCommand, LearnDiscoverCommand, ListenerCommand,
+    McpCommand, OpsCommand, OrgCommand, OrgDefaultFiltersCommand, OrgMemberCommand,
+    ProtoDescriptorCommand, RateLimitCommand, RateLimitOverrideCommand, RateLimitPolicyCommand,
+    ResourceCommand, RolloutCommand, RouteCommand, RuntimeCommand, SecretCommand, StatsCommand,
+    TeamCommand, TeamMemberCommand, UnexposeCommand, WasmModuleCommand, WebhookCommand, XdsCommand,
 };
 pub use config::GlobalOptions;
 use config::{
@@ -706,6 +707,26 @@ pub async fn run_org(global: GlobalOptions, command: OrgCommand) -> Result<()> {
                 .await?
         }
         OrgCommand::Member { command } => return run_org_member(client, command).await,
+        OrgCommand::DefaultFilters { command } => match command {
+            OrgDefaultFiltersCommand::Get { org } => {
+                client
+                    .request(
+                        reqwest::Method::GET,
+                        &format!("/api/v1/orgs/{org}/default-filters"),
+                        None,
+                    )
+                    .await?
+            }
+            OrgDefaultFiltersCommand::Set { org, file } => {
+                client
+                    .request(
+                        reqwest::Method::PUT,
+                        &format!("/api/v1/orgs/{org}/default-filters"),
+                        Some(body_from_file(&file)?),
+                    )
+                    .await?
+            }
+        },
     };
     Ok(())
 }
@@ -768,13 +789,69 @@ pub async fn run_team(global: GlobalOptions, command: TeamCommand) -> Result<()>
                 .request(reqwest::Method::GET, "/api/v1/teams", None)
                 .await?
         }
-        TeamCommand::Create { name, display_name } => {
+        TeamCommand::Create {
+            name,
+            display_name,
+            description,
+            envoy_admin_port,
+            envoy_admin_access,
+        } => client
+            .request(
+                reqwest::Method::POST,
+                "/api/v1/teams",
+                Some(json!({
+                    "name": name,
+                    "display_name": display_name.unwrap_or_default(),
+                    "description": description.unwrap_or_default(),
+                    "envoy_admin_port": envoy_admin_port,
+                    "envoy_admin_access": envoy_admin_access.map(|access| access.as_query_value()),
+                })),
+            )
+            .await?,
+        TeamCommand::Get { team } => {
+            let team = client.team(team)?;
             client
-                .request(
-                    reqwest::Method::POST,
-                    "/api/v1/teams",
-                    Some(json!({"name": name, "display_name": display_name.unwrap_or_default()})),
+                .request(reqwest::Method::GET, &format!("/api/v1/teams/{team}"), None)
+                .await?
+        }
+        TeamCommand::Update {
+            team,
+            display_name,
+            description,
+            envoy_admin_port,
+            clear_envoy_admin_port,
+            envoy_admin_access,
+            clear_envoy_admin_access,
+        } => {
+            let team = client.team(team)?;
+            let path = format!("/api/v1/teams/{team}");
+            // PUT replaces every editable field, so start from the current team and only
+            // change what was passed.
+            let current = client
+                .get_optional(&path)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("team {team} not found"))?;
+            let envoy_admin_port = if clear_envoy_admin_port {
+                Value::Null
+            } else {
+                envoy_admin_port.map_or_else(|| current["envoy_admin_port"].clone(), Value::from)
+            };
+            let envoy_admin_access = if clear_envoy_admin_access {
+                Value::Null
+            } else {
+                envoy_admin_access.map_or_else(
+                    || current["envoy_admin_access"].clone(),
+                    |access| Value::from(access.as_query_value()),
                 )
+            };
+            let body = json!({
+                "display_name": display_name.map_or_else(|| current["display_name"].clone(), Value::from),
+                "description": description.map_or_else(|| current["description"].clone(), Value::from),
+                "envoy_admin_port": envoy_admin_port,
+                "envoy_admin_access": envoy_admin_access,
+            });
+            client
+                .request(reqwest::Method::PUT, &path, Some(body))
                 .await?
         }
         TeamCommand::Delete { team } => {
@@ -787,6 +864,26 @@ pub async fn run_team(global: GlobalOptions, command: TeamCommand) -> Result<()>
                 )
                 .await?
         }
+        TeamCommand::Quotas { team } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/quotas"),
+                    None,
+                )
+                .await?
+        }
+        TeamCommand::SetQuotas { team, file } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::PUT,
+                    &format!("/api/v1/teams/{team}/quotas"),
+                    Some(body_from_file(&file)?),
+                )
+                .await?
+        }
         TeamCommand::Member { command } => return run_team_member(client, command).await,
         TeamCommand::Grant { command } => return run_grant(client, command).await,
     };
@@ -950,6 +1047,200 @@ pub async fn run_expose(global: GlobalOptions, command: ExposeCommand) -> Result
     Ok(())
 }
 
+/// Cluster/listener dispatch: CRUD goes through [`run_resource`]; `clone` and `transfer` post
+/// to the resource's `/clone` and `/transfer` actions.
+pub async fn run_gateway_resource(
+    global: GlobalOptions,
+    segment: &str,
+    command: GatewayResourceCommand,
+) -> Result<()> {
+    match command {
+        GatewayResourceCommand::Crud(command) => run_resource(global, segment, command).await,
+        GatewayResourceCommand::Clone {
+            team,
+            name,
+            new_name,
+            to_team,
+        } => run_clone(global, segment, team, name, new_name, to_team).await,
+        GatewayResourceCommand::Transfer {
+            team,
+            name,
+            to_team,
+        } => run_transfer(global, segment, team, name, to_team).await,
+    }
+}
+
+/// Listener dispatch: the shared gateway verbs, plus `validate-filters` and `validate-envoy`,
+/// which post to the team-less dry-run endpoints.
+pub async fn run_cluster(global: GlobalOptions, command: ClusterCommand) -> Result<()> {
+    match command {
+        ClusterCommand::Resource(command) => {
+            run_gateway_resource(global, "clusters", command).await
+        }
+        ClusterCommand::Outliers { team, name } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/clusters/{}/outliers",
+                        query_component(&name)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+        ClusterCommand::Stats { team, name } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/clusters/{}/stats",
+                        query_component(&name)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+        ClusterCommand::References { team, name } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/clusters/{}/references",
+                        query_component(&name)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+    }
+}
+
+pub async fn run_listener(global: GlobalOptions, command: ListenerCommand) -> Result<()> {
+    match command {
+        ListenerCommand::Resource(command) => {
+            run_gateway_resource(global, "listeners", command).await
+        }
+        ListenerCommand::ValidateFilters { file } => {
+            let client = RestClient::new(global)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    "/api/v1/validate/filters",
+                    Some(body_from_file(&file)?),
+                )
+                .await?;
+            Ok(())
+        }
+        ListenerCommand::ValidateEnvoy { file } => {
+            let client = RestClient::new(global)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    "/api/v1/validate/listener",
+                    Some(body_from_file(&file)?),
+                )
+                .await?;
+            Ok(())
+        }
+        ListenerCommand::Maintenance {
+            team,
+            name,
+            off,
+            status,
+            body,
+        } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            let mut request = json!({ "enabled": !off });
+            if let Some(status) = status {
+                request["status"] = json!(status);
+            }
+            if let Some(body) = body {
+                request["body"] = json!(body);
+            }
+            client
+                .request_and_render(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/listeners/{}/maintenance",
+                        query_component(&name)
+                    ),
+                    Some(request),
+                )
+                .await?;
+            Ok(())
+        }
+    }
+}
+
+async fn run_clone(
+    global: GlobalOptions,
+    segment: &str,
+    team: Option<String>,
+    name: String,
+    new_name: String,
+    to_team: Option<String>,
+) -> Result<()> {
+    let client = RestClient::new(global)?;
+    let team = client.team(team)?;
+    let mut body = json!({ "new_name": new_name });
+    if let Some(to_team) = to_team {
+        body["team"] = json!(to_team);
+    }
+    client
+        .request(
+            reqwest::Method::POST,
+            &format!(
+                "/api/v1/teams/{team}/{segment}/{}/clone",
+                query_component(&name)
+            ),
+            Some(body),
+        )
+        .await?;
+    Ok(())
+}
+
+/// POST the `/transfer` action. The action is guarded by `If-Match`; without `--revision` the
+/// resource's current revision is read first, as for update and delete.
+async fn run_transfer(
+    global: GlobalOptions,
+    segment: &str,
+    team: Option<String>,
+    name: String,
+    to_team: String,
+) -> Result<()> {
+    let explicit = global.revision;
+    let client = RestClient::new(global)?;
+    let team = client.team(team)?;
+    let path = format!("/api/v1/teams/{team}/{segment}/{}", query_component(&name));
+    let revision = match explicit {
+        Some(revision) => Some(revision),
+        None => client
+            .get_optional(&path)
+            .await?
+            .and_then(|current| current["revision"].as_i64()),
+    };
+    client
+        .request_with_revision(
+            reqwest::Method::POST,
+            &format!("{path}/transfer"),
+            Some(json!({ "team": to_team })),
+            revision,
+        )
+        .await?;
+    Ok(())
+}
+
 pub async fn run_route(global: GlobalOptions, command: RouteCommand) -> Result<()> {
     match command {
         RouteCommand::List { team } => {
@@ -982,10 +1273,117 @@ pub async fn run_route(global: GlobalOptions, command: RouteCommand) -> Result<(
             )
             .await
         }
+        RouteCommand::Clone {
+            team,
+            name,
+            new_name,
+            to_team,
+        } => run_clone(global, "route-configs", team, name, new_name, to_team).await,
+        RouteCommand::Transfer {
+            team,
+            name,
+            to_team,
+        } => run_transfer(global, "route-configs", team, name, to_team).await,
+        RouteCommand::AddVhost { team, name, file } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/route-configs/{}/virtual-hosts",
+                        query_component(&name)
+                    ),
+                    Some(body_from_file(&file)?),
+                )
+                .await?;
+            Ok(())
+        }
+        RouteCommand::RemoveVhost { team, name, vhost } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::DELETE,
+                    &format!(
+                        "/api/v1/teams/{team}/route-configs/{}/virtual-hosts/{}",
+                        query_component(&name),
+                        query_component(&vhost)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+        RouteCommand::EffectiveFilters {
+            team,
+            name,
+            vhost,
+            route,
+        } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request_and_render(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/route-configs/{}/virtual-hosts/{}/routes/{}/effective-filters",
+                        query_component(&name),
+                        query_component(&vhost),
+                        query_component(&route)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+        RouteCommand::Canary {
+            team,
+            name,
+            vhost,
+            route,
+            stable,
+            canary,
+            weight,
+            promote,
+            abort,
+        } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            let (tail, body) = if promote {
+                ("canary:promote", None)
+            } else if abort {
+                ("canary:abort", None)
+            } else {
+                (
+                    "canary",
+                    Some(json!({
+                        "stable_cluster": stable,
+                        "canary_cluster": canary,
+                        "canary_weight": weight,
+                    })),
+                )
+            };
+            client
+                .request_and_render(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/route-configs/{}/virtual-hosts/{}/routes/{}/{tail}",
+                        query_component(&name),
+                        query_component(&vhost),
+                        query_component(&route)
+                    ),
+                    body,
+                )
+                .await?;
+            Ok(())
+        }
         RouteCommand::Generate {
             team,
             from_spec,
             listener_port,
+            coalesce_methods,
+            sync,
         } => {
             let client = RestClient::new(global)?;
             let team = client.team(team)?;
@@ -996,6 +1394,8 @@ pub async fn run_route(global: GlobalOptions, command: RouteCommand) -> Result<(
                     Some(json!({
                         "spec_version_id": from_spec,
                         "listener_port": listener_port,
+                        "coalesce_methods": coalesce_methods,
+                        "sync": sync,
                     })),
                 )
                 .await?;
@@ -1016,6 +1416,48 @@ pub async fn run_route(global: GlobalOptions, command: RouteCommand) -> Result<(
                 .await?;
             Ok(())
         }
+        RouteCommand::Plans { team } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request_and_render(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/route-generation-plans"),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+        RouteCommand::PlanResources { team, plan_id } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request_and_render(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/route-generation-plans/{}/resources",
+                        query_component(&plan_id)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
+        RouteCommand::PlanTeardown { team, plan_id } => {
+            let client = RestClient::new(global)?;
+            let team = client.team(team)?;
+            client
+                .request_and_render(
+                    reqwest::Method::DELETE,
+                    &format!(
+                        "/api/v1/teams/{team}/route-generation-plans/{}/resources",
+                        query_component(&plan_id)
+                    ),
+                    None,
+                )
+                .await?;
+            Ok(())
+        }
     }
 }
 
@@ -1575,6 +2017,10 @@ pub async fn run_learn(global: GlobalOptions, command: LearnCommand) -> Result<(
             max_duration_seconds,
             max_bytes,
             max_distinct_paths,
+            sample_percent,
+            max_body_bytes,
+            redact_headers,
+            redact_body_fields,
         } => {
             let team = client.team(team)?;
             let target_count = [
@@ -1613,81 +2059,324 @@ pub async fn run_learn(global: GlobalOptions, command: LearnCommand) -> Result<(
                         "max_duration_seconds": max_duration_seconds,
                         "max_bytes": max_bytes,
                         "max_distinct_paths": max_distinct_paths,
+                        "sample_percent": sample_percent,
+                        "max_body_bytes": max_body_bytes,
+                        "redact_headers": redact_headers,
+                        "redact_body_fields": redact_body_fields,
                     })),
                 )
                 .await?
         }
-        LearnCommand::List {
+        LearnCommand::List {
+            team,
+            status,
+            limit,
+            offset,
+        } => {
+            let team = client.team(team)?;
+            let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
+            if let Some(status) = status {
+                query.push(("status", status));
+            }
+            let query = query
+                .into_iter()
+                .map(|(key, value)| format!("{key}={}", query_component(&value)))
+                .collect::<Vec<_>>()
+                .join("&");
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/learning-sessions?{query}"),
+                    None,
+                )
+                .await?
+        }
+        LearnCommand::Get { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-sessions/{}",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        LearnCommand::Stop { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-sessions/{}/stop",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        LearnCommand::GenerateSpec { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-sessions/{}/spec-version",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        LearnCommand::ExportSpec { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-sessions/{}/openapi",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        LearnCommand::Cancel { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::DELETE,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-sessions/{}",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+    };
+    Ok(())
+}
+
+async fn run_learn_discover(client: RestClient, command: LearnDiscoverCommand) -> Result<()> {
+    match command {
+        LearnDiscoverCommand::Start {
+            team,
+            name,
+            upstream,
+            listener_port,
+            upstream_tls,
+            target_sample_count,
+            max_duration_seconds,
+            max_bytes,
+            max_distinct_paths,
+        } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!("/api/v1/teams/{team}/learning-discovery-sessions"),
+                    Some(json!({
+                        "name": name,
+                        "upstream": upstream,
+                        "listener_port": listener_port,
+                        "upstream_tls": upstream_tls,
+                        "target_sample_count": target_sample_count,
+                        "max_duration_seconds": max_duration_seconds,
+                        "max_bytes": max_bytes,
+                        "max_distinct_paths": max_distinct_paths,
+                    })),
+                )
+                .await?
+        }
+        LearnDiscoverCommand::List {
+            team,
+            status,
+            limit,
+            offset,
+        } => {
+            let team = client.team(team)?;
+            let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
+            if let Some(status) = status {
+                query.push(("status", status));
+            }
+            let query = query
+                .into_iter()
+                .map(|(key, value)| format!("{key}={}", query_component(&value)))
+                .collect::<Vec<_>>()
+                .join("&");
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/learning-discovery-sessions?{query}"),
+                    None,
+                )
+                .await?
+        }
+        LearnDiscoverCommand::Status { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-discovery-sessions/{}",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        LearnDiscoverCommand::Stop { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-discovery-sessions/{}/stop",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        LearnDiscoverCommand::GenerateSpec { team, session } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/v1/teams/{team}/learning-discovery-sessions/{}/spec-versions",
+                        query_component(&session)
+                    ),
+                    None,
+                )
+                .await?
+        }
+    };
+    Ok(())
+}
+
+pub async fn run_secret(global: GlobalOptions, command: SecretCommand) -> Result<()> {
+    let client = RestClient::new(global)?;
+    match command {
+        SecretCommand::List { team } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/secrets"),
+                    None,
+                )
+                .await?
+        }
+        SecretCommand::Get { team, name } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/secrets/{name}"),
+                    None,
+                )
+                .await?
+        }
+        SecretCommand::Create { team, file } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!("/api/v1/teams/{team}/secrets"),
+                    Some(body_from_file(&file)?),
+                )
+                .await?
+        }
+        SecretCommand::CreateBatch { team, file } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!("/api/v1/teams/{team}/secrets:batch"),
+                    Some(body_from_file(&file)?),
+                )
+                .await?
+        }
+        SecretCommand::Rotate {
             team,
-            status,
-            limit,
-            offset,
+            name,
+            revision,
+            file,
         } => {
             let team = client.team(team)?;
-            let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
-            if let Some(status) = status {
-                query.push(("status", status));
-            }
-            let query = query
-                .into_iter()
-                .map(|(key, value)| format!("{key}={}", query_component(&value)))
-                .collect::<Vec<_>>()
-                .join("&");
             client
-                .request(
-                    reqwest::Method::GET,
-                    &format!("/api/v1/teams/{team}/learning-sessions?{query}"),
-                    None,
+                .request_with_revision(
+                    reqwest::Method::POST,
+                    &format!("/api/v1/teams/{team}/secrets/{name}/rotate"),
+                    Some(body_from_file(&file)?),
+                    Some(revision),
                 )
                 .await?
         }
-        LearnCommand::Get { team, session } => {
+    };
+    Ok(())
+}
+
+pub async fn run_proto_descriptor(
+    global: GlobalOptions,
+    command: ProtoDescriptorCommand,
+) -> Result<()> {
+    let client = RestClient::new(global)?;
+    match command {
+        ProtoDescriptorCommand::List { team } => {
             let team = client.team(team)?;
             client
                 .request(
                     reqwest::Method::GET,
-                    &format!(
-                        "/api/v1/teams/{team}/learning-sessions/{}",
-                        query_component(&session)
-                    ),
+                    &format!("/api/v1/teams/{team}/proto-descriptors"),
                     None,
                 )
                 .await?
         }
-        LearnCommand::Stop { team, session } => {
+        ProtoDescriptorCommand::Get { team, name } => {
             let team = client.team(team)?;
             client
                 .request(
-                    reqwest::Method::POST,
+                    reqwest::Method::GET,
                     &format!(
-                        "/api/v1/teams/{team}/learning-sessions/{}/stop",
-                        query_component(&session)
+                        "/api/v1/teams/{team}/proto-descriptors/{}",
+                        query_component(&name)
                     ),
                     None,
                 )
                 .await?
         }
-        LearnCommand::GenerateSpec { team, session } => {
+        ProtoDescriptorCommand::Create {
+            team,
+            name,
+            descriptor_set,
+        } => {
             let team = client.team(team)?;
+            let bytes = fs::read(&descriptor_set)
+                .with_context(|| format!("read {}", descriptor_set.display()))?;
             client
                 .request(
                     reqwest::Method::POST,
-                    &format!(
-                        "/api/v1/teams/{team}/learning-sessions/{}/spec-version",
-                        query_component(&session)
-                    ),
-                    None,
+                    &format!("/api/v1/teams/{team}/proto-descriptors"),
+                    Some(json!({
+                        "name": name,
+                        "descriptor_set": base64::engine::general_purpose::STANDARD.encode(bytes),
+                    })),
                 )
                 .await?
         }
-        LearnCommand::Cancel { team, session } => {
+        ProtoDescriptorCommand::Delete { team, name } => {
             let team = client.team(team)?;
             client
                 .request(
                     reqwest::Method::DELETE,
                     &format!(
-                        "/api/v1/teams/{team}/learning-sessions/{}",
-                        query_component(&session)
+                        "/api/v1/teams/{team}/proto-descriptors/{}",
+                        query_component(&name)
                     ),
                     None,
                 )
@@ -1697,96 +2386,111 @@ pub async fn run_learn(global: GlobalOptions, command: LearnCommand) -> Result<(
     Ok(())
 }
 
-async fn run_learn_discover(client: RestClient, command: LearnDiscoverCommand) -> Result<()> {
+pub async fn run_wasm_module(global: GlobalOptions, command: WasmModuleCommand) -> Result<()> {
+    let client = RestClient::new(global)?;
     match command {
-        LearnDiscoverCommand::Start {
+        WasmModuleCommand::List { team, name } => {
+            let team = client.team(team)?;
+            let path = match name {
+                Some(name) => format!(
+                    "/api/v1/teams/{team}/wasm-modules/{}",
+                    query_component(&name)
+                ),
+                None => format!("/api/v1/teams/{team}/wasm-modules"),
+            };
+            client.request(reqwest::Method::GET, &path, None).await?
+        }
+        WasmModuleCommand::Get {
             team,
             name,
-            upstream,
-            listener_port,
-            upstream_tls,
-            target_sample_count,
-            max_duration_seconds,
-            max_bytes,
-            max_distinct_paths,
+            version,
         } => {
             let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/api/v1/teams/{team}/wasm-modules/{}/versions/{version}",
+                        query_component(&name)
+                    ),
+                    None,
+                )
+                .await?
+        }
+        WasmModuleCommand::Create { team, name, module } => {
+            let team = client.team(team)?;
+            let bytes = fs::read(&module).with_context(|| format!("read {}", module.display()))?;
             client
                 .request(
                     reqwest::Method::POST,
-                    &format!("/api/v1/teams/{team}/learning-discovery-sessions"),
+                    &format!("/api/v1/teams/{team}/wasm-modules"),
                     Some(json!({
                         "name": name,
-                        "upstream": upstream,
-                        "listener_port": listener_port,
-                        "upstream_tls": upstream_tls,
-                        "target_sample_count": target_sample_count,
-                        "max_duration_seconds": max_duration_seconds,
-                        "max_bytes": max_bytes,
-                        "max_distinct_paths": max_distinct_paths,
+                        "module": base64::engine::general_purpose::STANDARD.encode(bytes),
                     })),
                 )
                 .await?
         }
-        LearnDiscoverCommand::List {
+        WasmModuleCommand::Delete {
             team,
-            status,
-            limit,
-            offset,
+            name,
+            version,
         } => {
             let team = client.team(team)?;
-            let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
-            if let Some(status) = status {
-                query.push(("status", status));
-            }
-            let query = query
-                .into_iter()
-                .map(|(key, value)| format!("{key}={}", query_component(&value)))
-                .collect::<Vec<_>>()
-                .join("&");
             client
                 .request(
-                    reqwest::Method::GET,
-                    &format!("/api/v1/teams/{team}/learning-discovery-sessions?{query}"),
+                    reqwest::Method::DELETE,
+                    &format!(
+                        "/api/v1/teams/{team}/wasm-modules/{}/versions/{version}",
+                        query_component(&name)
+                    ),
                     None,
                 )
                 .await?
         }
-        LearnDiscoverCommand::Status { team, session } => {
+    };
+    Ok(())
+}
+
+pub async fn run_runtime(global: GlobalOptions, command: RuntimeCommand) -> Result<()> {
+    let client = RestClient::new(global)?;
+    match command {
+        RuntimeCommand::List { team } => {
             let team = client.team(team)?;
             client
                 .request(
                     reqwest::Method::GET,
-                    &format!(
-                        "/api/v1/teams/{team}/learning-discovery-sessions/{}",
-                        query_component(&session)
-                    ),
+                    &format!("/api/v1/teams/{team}/runtime"),
                     None,
                 )
                 .await?
         }
-        LearnDiscoverCommand::Stop { team, session } => {
+        RuntimeCommand::Get { team, key } => {
             let team = client.team(team)?;
             client
                 .request(
-                    reqwest::Method::POST,
-                    &format!(
-                        "/api/v1/teams/{team}/learning-discovery-sessions/{}/stop",
-                        query_component(&session)
-                    ),
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/runtime/{}", query_component(&key)),
                     None,
                 )
                 .await?
         }
-        LearnDiscoverCommand::GenerateSpec { team, session } => {
+        RuntimeCommand::Set { team, key, value } => {
             let team = client.team(team)?;
             client
                 .request(
-                    reqwest::Method::POST,
-                    &format!(
-                        "/api/v1/teams/{team}/learning-discovery-sessions/{}/spec-versions",
-                        query_component(&session)
-                    ),
+                    reqwest::Method::PUT,
+                    &format!("/api/v1/teams/{team}/runtime/{}", query_component(&key)),
+                    Some(json!({ "value": runtime_value_arg(&value) })),
+                )
+                .await?
+        }
+        RuntimeCommand::Delete { team, key } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::DELETE,
+                    &format!("/api/v1/teams/{team}/runtime/{}", query_component(&key)),
                     None,
                 )
                 .await?
@@ -1795,52 +2499,64 @@ async fn run_learn_discover(client: RestClient, command: LearnDiscoverCommand) -
     Ok(())
 }
 
-pub async fn run_secret(global: GlobalOptions, command: SecretCommand) -> Result<()> {
+/// A `runtime set` value: numbers and booleans keep their type, anything else is a string.
+fn runtime_value_arg(raw: &str) -> Value {
+    match serde_json::from_str::<Value>(raw) {
+        Ok(value @ (Value::Number(_) | Value::Bool(_))) => value,
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+pub async fn run_webhook(global: GlobalOptions, command: WebhookCommand) -> Result<()> {
     let client = RestClient::new(global)?;
     match command {
-        SecretCommand::List { team } => {
+        WebhookCommand::List { team } => {
             let team = client.team(team)?;
             client
                 .request(
                     reqwest::Method::GET,
-                    &format!("/api/v1/teams/{team}/secrets"),
+                    &format!("/api/v1/teams/{team}/webhooks"),
                     None,
                 )
                 .await?
         }
-        SecretCommand::Get { team, name } => {
+        WebhookCommand::Get { team, name } => {
             let team = client.team(team)?;
             client
                 .request(
                     reqwest::Method::GET,
-                    &format!("/api/v1/teams/{team}/secrets/{name}"),
+                    &format!("/api/v1/teams/{team}/webhooks/{}", query_component(&name)),
                     None,
                 )
                 .await?
         }
-        SecretCommand::Create { team, file } => {
+        WebhookCommand::Create {
+            team,
+            name,
+            url,
+            events,
+            secret_id,
+        } => {
             let team = client.team(team)?;
+            let mut body = json!({ "name": name, "url": url, "events": events });
+            if let Some(secret_id) = secret_id {
+                body["secret_id"] = Value::String(secret_id);
+            }
             client
                 .request(
                     reqwest::Method::POST,
-                    &format!("/api/v1/teams/{team}/secrets"),
-                    Some(body_from_file(&file)?),
+                    &format!("/api/v1/teams/{team}/webhooks"),
+                    Some(body),
                 )
                 .await?
         }
-        SecretCommand::Rotate {
-            team,
-            name,
-            revision,
-            file,
-        } => {
+        WebhookCommand::Delete { team, name } => {
             let team = client.team(team)?;
             client
-                .request_with_revision(
-                    reqwest::Method::POST,
-                    &format!("/api/v1/teams/{team}/secrets/{name}/rotate"),
-                    Some(body_from_file(&file)?),
-                    Some(revision),
+                .request(
+                    reqwest::Method::DELETE,
+                    &format!("/api/v1/teams/{team}/webhooks/{}", query_component(&name)),
+                    None,
                 )
                 .await?
         }
@@ -1872,6 +2588,16 @@ pub async fn run_dataplane(global: GlobalOptions, command: DataplaneCommand) ->
                 )
                 .await?
         }
+        DataplaneCommand::Connected { team } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/connected-proxies"),
+                    None,
+                )
+                .await?
+        }
         DataplaneCommand::Create {
             team,
             name,
@@ -1903,9 +2629,12 @@ pub async fn run_dataplane(global: GlobalOptions, command: DataplaneCommand) ->
             xds_host,
             xds_port,
             admin_port,
+            admin_access,
+            admin_socket_path,
             cert_path,
             key_path,
             ca_path,
+            validate,
         } => {
             let team = client.team(team)?;
             if mode == DataplaneBootstrapMode::Mtls
@@ -1919,8 +2648,16 @@ pub async fn run_dataplane(global: GlobalOptions, command: DataplaneCommand) ->
                 ("mode", mode.as_query_value().to_string()),
                 ("xds_host", xds_host),
                 ("xds_port", xds_port.to_string()),
-                ("admin_port", admin_port.to_string()),
             ];
+            if let Some(admin_port) = admin_port {
+                query.push(("admin_port", admin_port.to_string()));
+            }
+            if let Some(admin_access) = admin_access {
+                query.push(("admin_access", admin_access.as_query_value().to_string()));
+            }
+            if let Some(admin_socket_path) = admin_socket_path {
+                query.push(("admin_socket_path", admin_socket_path));
+            }
             if let Some(cert_path) = cert_path {
                 query.push(("cert_path", cert_path));
             }
@@ -1930,6 +2667,9 @@ pub async fn run_dataplane(global: GlobalOptions, command: DataplaneCommand) ->
             if let Some(ca_path) = ca_path {
                 query.push(("ca_path", ca_path));
             }
+            if validate {
+                query.push(("validate", "true".to_string()));
+            }
             let query = query
                 .into_iter()
                 .map(|(key, value)| format!("{key}={}", query_component(&value)))
@@ -1948,6 +2688,41 @@ pub async fn run_dataplane(global: GlobalOptions, command: DataplaneCommand) ->
             None
         }
         DataplaneCommand::Cert { command } => return run_cert(client, command).await,
+        DataplaneCommand::ConfigVersions { team } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/config-versions"),
+                    None,
+                )
+                .await?
+        }
+        DataplaneCommand::Pin {
+            team,
+            name,
+            config_version,
+        } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::PUT,
+                    &format!("/api/v1/teams/{team}/dataplanes/{name}/pin"),
+                    Some(json!({"config_version": config_version})),
+                )
+                .await?
+        }
+        DataplaneCommand::Promote { team, name } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!("/api/v1/teams/{team}/dataplanes/{name}/promote"),
+                    None,
+                )
+                .await?
+        }
+        DataplaneCommand::Rollout { command } => return run_rollout(client, command).await,
     };
     Ok(())
 }
@@ -2006,6 +2781,49 @@ async fn run_cert(client: RestClient, command: CertCommand) -> Result<()> {
     Ok(())
 }
 
+async fn run_rollout(client: RestClient, command: RolloutCommand) -> Result<()> {
+    match command {
+        RolloutCommand::Get { team } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/api/v1/teams/{team}/config-rollout"),
+                    None,
+                )
+                .await?
+        }
+        RolloutCommand::Set {
+            team,
+            stable_version,
+            canary_percent,
+        } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::PUT,
+                    &format!("/api/v1/teams/{team}/config-rollout"),
+                    Some(json!({
+                        "stable_version": stable_version,
+                        "canary_percent": canary_percent,
+                    })),
+                )
+                .await?
+        }
+        RolloutCommand::Promote { team } => {
+            let team = client.team(team)?;
+            client
+                .request(
+                    reqwest::Method::POST,
+                    &format!("/api/v1/teams/{team}/config-rollout/promote"),
+                    None,
+                )
+                .await?
+        }
+    };
+    Ok(())
+}
+
 pub async fn run_stats(global: GlobalOptions, command: StatsCommand) -> Result<()> {
     let client = RestClient::new(global)?;
     match command {
@@ -2082,6 +2900,25 @@ pub async fn run_ops(global: GlobalOptions, command: OpsCommand) -> Result<()> {
                 )
                 .await?;
         }
+        OpsCommand::Diagnostics => {
+            client
+                .request(reqwest::Method::GET, "/api/v1/admin/diagnostics", None)
+                .await?;
+        }
+        OpsCommand::WebhookDeadLetters => {
+            client
+                .request(
+                    reqwest::Method::GET,
+                    "/api/v1/admin/webhook-dead-letters",
+                    None,
+                )
+                .await?;
+        }
+        OpsCommand::Reconcile => {
+            client
+                .request(reqwest::Method::POST, "/api/v1/admin/reconcile", None)
+                .await?;
+        }
     }
     Ok(())
 }
@@ -2619,12 +3456,27 @@ fn cli_endpoint_templates() -> BTreeSet<&'static str> {
         "/api/v1/teams/{team}/grants/{grant_id}",
         "/api/v1/teams/{team}/clusters",
         "/api/v1/teams/{team}/clusters/{name}",
+        "/api/v1/teams/{team}/clusters/{name}/clone",
+        "/api/v1/teams/{team}/clusters/{name}/transfer",
         "/api/v1/teams/{team}/listeners",
         "/api/v1/teams/{team}/listeners/{name}",
+        "/api/v1/teams/{team}/listeners/{name}/clone",
+        "/api/v1/teams/{team}/listeners/{name}/maintenance",
+        "/api/v1/teams/{team}/listeners/{name}/transfer",
         "/api/v1/teams/{team}/route-configs",
         "/api/v1/teams/{team}/route-configs/{name}",
+        "/api/v1/teams/{team}/route-configs/{name}/clone",
+        "/api/v1/teams/{team}/route-configs/{name}/transfer",
+        "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts",
+        "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}",
+        "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/effective-filters",
+        "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/canary",
+        "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/canary:promote",
+        "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/canary:abort",
+        "/api/v1/teams/{team}/quotas",
         "/api/v1/teams/{team}/route-generation-plans",
         "/api/v1/teams/{team}/route-generation-plans/{plan_id}/apply",
+        "/api/v1/teams/{team}/route-generation-plans/{plan_id}/resources",
         "/api/v1/teams/{team}/expose",
         "/api/v1/teams/{team}/expose/{name}",
         "/api/v1/teams/{team}/api-definitions",
@@ -2650,10 +3502,20 @@ fn cli_endpoint_templates() -> BTreeSet<&'static str> {
         "/api/v1/teams/{team}/rate-limit-domains/{domain}/policies/{name}",
         "/api/v1/teams/{team}/rate-limit-domains/{domain}/policies/{policy}/override",
         "/api/v1/admin/rls/force-repush",
+        "/api/v1/admin/diagnostics",
+        "/api/v1/admin/webhook-dead-letters",
+        "/api/v1/admin/reconcile",
+        "/api/v1/validate/filters",
+        "/api/v1/validate/listener",
+        "/api/v1/orgs/{org}/default-filters",
+        "/api/v1/teams/{team}/clusters/{name}/outliers",
+        "/api/v1/teams/{team}/clusters/{name}/stats",
+        "/api/v1/teams/{team}/clusters/{name}/references",
         "/api/v1/teams/{team}/learning-sessions",
         "/api/v1/teams/{team}/learning-sessions/{session}",
         "/api/v1/teams/{team}/learning-sessions/{session}/stop",
         "/api/v1/teams/{team}/learning-sessions/{session}/spec-version",
+        "/api/v1/teams/{team}/learning-sessions/{session}/openapi",
         "/api/v1/teams/{team}/learning-discovery-sessions",
         "/api/v1/teams/{team}/learning-discovery-sessions/{session}",
         "/api/v1/teams/{team}/learning-discovery-sessions/{session}/stop",
@@ -2662,12 +3524,32 @@ fn cli_endpoint_templates() -> BTreeSet<&'static str> {
         "/api/v1/teams/{team}/dataplanes/{name}",
         "/api/v1/teams/{team}/dataplanes/{name}/telemetry",
         "/api/v1/teams/{team}/dataplanes/{name}/envoy-config",
+        "/api/v1/teams/{team}/connected-proxies",
+        "/api/v1/teams/{team}/config-versions",
+        "/api/v1/teams/{team}/dataplanes/{name}/pin",
+        "/api/v1/teams/{team}/dataplanes/{name}/promote",
+        "/api/v1/teams/{team}/config-rollout",
+        "/api/v1/teams/{team}/config-rollout/promote",
         "/api/v1/teams/{team}/proxy-certificates",
         "/api/v1/teams/{team}/proxy-certificates/issue",
         "/api/v1/teams/{team}/proxy-certificates/{serial_number}/revoke",
         "/api/v1/teams/{team}/secrets",
+        "/api/v1/teams/{team}/secrets:batch",
         "/api/v1/teams/{team}/secrets/{name}",
         "/api/v1/teams/{team}/secrets/{name}/rotate",
+        "/api/v1/teams/{team}/proto-descriptors",
+        "/api/v1/teams/{team}/proto-descriptors/{name}",
+        "/api/v1/teams/{team}/wasm-modules",
+        "/api/v1/teams/{team}/wasm-modules/{name}",
+        "/api/v1/teams/{team}/wasm-modules/{name}/versions/{version}",
+        "/api/v1/teams/{team}/filter-presets",
+        "/api/v1/teams/{team}/filter-presets/{name}",
+        "/api/v1/teams/{team}/retry-policies",
+        "/api/v1/teams/{team}/retry-policies/{name}",
+        "/api/v1/teams/{team}/runtime",
+        "/api/v1/teams/{team}/runtime/{key}",
+        "/api/v1/teams/{team}/webhooks",
+        "/api/v1/teams/{team}/webhooks/{name}",
         "/api/v1/teams/{team}/stats/overview",
         "/api/v1/teams/{team}/xds/nacks",
         "/api/v1/teams/{team}/xds/status",
@@ -217,9 +217,11 @@ pub(crate) fn apply_error_envelope(failures: Vec<Value>) -> Value {
 }
 
 /// Whether a failed HTTP status is worth retrying (CLI-R-32): rate-limit and server errors
-/// are transient; 4xx terminal. Transport failures are handled in `render_transport_error`.
+/// are transient; 4xx terminal, as is 501 (`not_configured`: the server lacks the feature
+/// until an operator enables it). Transport failures are handled in `render_transport_error`.
 pub(crate) fn is_retryable(status: StatusCode) -> bool {
-    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    status == StatusCode::TOO_MANY_REQUESTS
+        || (status.is_server_error() && status != StatusCode::NOT_IMPLEMENTED)
 }
 
 /// Map an HTTP status to the CLI's scriptable exit code (CLI-R-31): `3` auth (401/403),
@@ -330,6 +332,10 @@ fn kind_override(path: &str) -> Option<&'static str> {
     }
     // Singleton / aggregate GET views (would mis-singularize to `statu`, `stat`, `op`, or the
     // governing collection).
+    // Batch create returns `{items}` of the collection's resource.
+    if path.ends_with("/secrets:batch") {
+        return Some("secret");
+    }
     if path.ends_with("/mcp/status") {
         return Some("mcpStatus");
     }
@@ -342,12 +348,60 @@ fn kind_override(path: &str) -> Option<&'static str> {
     if path.ends_with("/ops/trace") {
         return Some("trace");
     }
+    if path.ends_with("/admin/diagnostics") {
+        return Some("diagnostics");
+    }
+    if path.ends_with("/admin/reconcile") {
+        return Some("reconcileReport");
+    }
+    if path.ends_with("/admin/webhook-dead-letters") {
+        return Some("webhookDeadLetterList");
+    }
+    // Canary shifts return the updated route config, not the route they name.
+    if path.contains("/virtual-hosts/")
+        && ["/canary", "/canary:promote", "/canary:abort"]
+            .iter()
+            .any(|t| path.ends_with(t))
+    {
+        return Some("routeConfig");
+    }
+    if path.contains("/route-generation-plans/") && path.ends_with("/resources") {
+        return Some("routePlanResources");
+    }
+    if path.contains("/learning-sessions/") && path.ends_with("/openapi") {
+        return Some("learnedOpenapi");
+    }
+    if path.ends_with("/effective-filters") {
+        return Some("effectiveFilters");
+    }
     if path.ends_with("/ai/trace") {
         return Some("aiTrace");
     }
     if path.ends_with("/ai/retention") {
         return Some("aiRetention");
     }
+    if path.ends_with("/outliers") {
+        return Some("clusterOutliers");
+    }
+    if path.contains("/clusters/") && path.ends_with("/stats") {
+        return Some("clusterStats");
+    }
+    if path.contains("/clusters/") && path.ends_with("/references") {
+        return Some("clusterReferences");
+    }
+    // Runtime keys are dotted paths, never a collection noun.
+    if path.contains("/runtime") {
+        return Some("runtimeValue");
+    }
+    if path.ends_with("/default-filters") {
+        return Some("orgDefaultFilters");
+    }
+    if path.ends_with("/validate/filters") {
+        return Some("filterChainValidation");
+    }
+    if path.ends_with("/validate/listener") {
+        return Some("envoyValidation");
+    }
     if path.ends_with("/envoy-config") {
         return Some("envoyConfig");
     }
@@ -382,6 +436,10 @@ pub(crate) fn derive_kind(path: &str, value: &Value) -> String {
         "route-configs",
         "route-generation-plans",
         "secrets",
+        "proto-descriptors",
+        "wasm-modules",
+        "filter-presets",
+        "retry-policies",
         "api-definitions",
         "specs",
         "spec-versions",
@@ -403,6 +461,7 @@ pub(crate) fn derive_kind(path: &str, value: &Value) -> String {
         "grants",
         "agents",
         "nacks",
+        "webhooks",
     ];
     let path = path.split(['?', '#']).next().unwrap_or(path);
     let mut segments: Vec<&str> = path
@@ -945,6 +1004,7 @@ mod tests {
         assert!(is_retryable(StatusCode::TOO_MANY_REQUESTS));
         assert!(is_retryable(StatusCode::SERVICE_UNAVAILABLE));
         assert!(is_retryable(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_retryable(StatusCode::NOT_IMPLEMENTED));
         assert!(!is_retryable(StatusCode::NOT_FOUND));
         assert!(!is_retryable(StatusCode::BAD_REQUEST));
         assert!(!is_retryable(StatusCode::UNAUTHORIZED));
@@ -1206,8 +1266,15 @@ mod tests {
         let exact: &[(&str, &str)] = &[
             ("/api/v1/teams/p/clusters/c1", "cluster"),
             ("/api/v1/teams/p/listeners/l1", "listener"),
+            ("/api/v1/teams/p/listeners/l1/maintenance", "listener"),
             ("/api/v1/teams/p/route-configs/r1", "routeConfig"),
             ("/api/v1/teams/p/secrets/s1", "secret"),
+            ("/api/v1/teams/p/secrets:batch", "secret"),
+            ("/api/v1/teams/p/proto-descriptors/b1", "protoDescriptor"),
+            ("/api/v1/teams/p/wasm-modules/m1", "wasmModule"),
+            ("/api/v1/teams/p/filter-presets/f1", "filterPreset"),
+            ("/api/v1/teams/p/retry-policies/r1", "retryPolicy"),
+            ("/api/v1/teams/p/webhooks/w1", "webhook"),
             ("/api/v1/teams/p/api-definitions/a1", "apiDefinition"),
             ("/api/v1/teams/p/dataplanes/d1", "dataplane"),
             ("/api/v1/teams/p/rate-limit-domains/d", "rateLimitDomain"),
@@ -1224,6 +1291,32 @@ mod tests {
             ),
             ("/api/v1/teams/p/stats/overview", "statsOverview"),
             ("/api/v1/teams/p/ops/trace", "trace"),
+            ("/api/v1/admin/diagnostics", "diagnostics"),
+            ("/api/v1/admin/reconcile", "reconcileReport"),
+            ("/api/v1/admin/webhook-dead-letters", "webhookDeadLetterList"),
+            (
+                "/api/v1/teams/p/route-configs/edge/virtual-hosts/main/routes/api/effective-filters",
+                "effectiveFilters",
+            ),
+            (
+                "/api/v1/teams/p/route-configs/edge/virtual-hosts/main/routes/api/canary",
+                "routeConfig",
+            ),
+            (
+                "/api/v1/teams/p/route-configs/edge/virtual-hosts/main/routes/api/canary:promote",
+                "routeConfig",
+            ),
+            (
+                "/api/v1/teams/p/route-generation-plans/0190/resources",
+                "routePlanResources",
+            ),
+            ("/api/v1/validate/filters", "filterChainValidation"),
+            ("/api/v1/validate/listener", "envoyValidation"),
+            ("/api/v1/orgs/acme/default-filters", "orgDefaultFilters"),
+            ("/api/v1/teams/p/clusters/c1/outliers", "clusterOutliers"),
+            ("/api/v1/teams/p/clusters/c1/stats", "clusterStats"),
+            ("/api/v1/teams/p/clusters/c1/references", "clusterReferences"),
+            ("/api/v1/teams/p/runtime/routes.split", "runtimeValue"),
             ("/api/v1/teams/p/ai/trace", "aiTrace"),
             ("/api/v1/teams/p/ai/retention", "aiRetention"),
             ("/api/v1/teams/p/ai/usage", "usage"),
@@ -1250,6 +1343,10 @@ mod tests {
             ),
             ("/api/v1/admin/rls/force-repush", "mutationResult"),
             ("/api/v1/teams/p/learning-sessions/s/stop", "mutationResult"),
+            (
+                "/api/v1/teams/p/learning-sessions/sess/openapi",
+                "learnedOpenapi",
+            ),
             // spec-version sub-resources resolve to the spec version, not the parent session.
             (
                 "/api/v1/teams/p/learning-sessions/sess/spec-version",
@@ -63,12 +63,12 @@ enum Command {
     /// Gateway clusters.
     Cluster {
         #[command(subcommand)]
-        command: cli::ResourceCommand,
+        command: cli::ClusterCommand,
     },
     /// Gateway listeners.
     Listener {
         #[command(subcommand)]
-        command: cli::ResourceCommand,
+        command: cli::ListenerCommand,
     },
     /// Route configs.
     Route {
@@ -105,6 +105,36 @@ enum Command {
         #[command(subcommand)]
         command: cli::SecretCommand,
     },
+    /// Protobuf descriptor sets for gRPC-JSON transcoding.
+    ProtoDescriptor {
+        #[command(subcommand)]
+        command: cli::ProtoDescriptorCommand,
+    },
+    /// Versioned wasm modules for the wasm HTTP filter.
+    WasmModule {
+        #[command(subcommand)]
+        command: cli::WasmModuleCommand,
+    },
+    /// Named filter configs that listener chains instantiate as `{"type": "preset"}`.
+    FilterPreset {
+        #[command(subcommand)]
+        command: cli::ResourceCommand,
+    },
+    /// Named retry policies that routes reference via `retry_policy_ref`.
+    RetryPolicy {
+        #[command(subcommand)]
+        command: cli::ResourceCommand,
+    },
+    /// Runtime values served to proxies over RTDS.
+    Runtime {
+        #[command(subcommand)]
+        command: cli::RuntimeCommand,
+    },
+    /// Team webhook subscriptions that receive domain events over HTTP.
+    Webhook {
+        #[command(subcommand)]
+        command: cli::WebhookCommand,
+    },
     /// Dataplane registration and certificates.
     Dataplane {
         #[command(subcommand)]
@@ -217,12 +247,8 @@ fn run() -> anyhow::Result<()> {
         Command::Config { command } => cli::run_config(cli.client, command),
         Command::Org { command } => runtime.block_on(cli::run_org(cli.client, command)),
         Command::Team { command } => runtime.block_on(cli::run_team(cli.client, command)),
-        Command::Cluster { command } => {
-            runtime.block_on(cli::run_resource(cli.client, "clusters", command))
-        }
-        Command::Listener { command } => {
-            runtime.block_on(cli::run_resource(cli.client, "listeners", command))
-        }
+        Command::Cluster { command } => runtime.block_on(cli::run_cluster(cli.client, command)),
+        Command::Listener { command } => runtime.block_on(cli::run_listener(cli.client, command)),
         Command::Route { command } => runtime.block_on(cli::run_route(cli.client, command)),
         Command::Api { command } => runtime.block_on(cli::run_api(cli.client, command)),
         Command::Mcp { command } => runtime.block_on(cli::run_mcp(cli.client, command)),
@@ -232,6 +258,20 @@ fn run() -> anyhow::Result<()> {
         }
         Command::Learn { command } => runtime.block_on(cli::run_learn(cli.client, command)),
         Command::Secret { command } => runtime.block_on(cli::run_secret(cli.client, command)),
+        Command::ProtoDescriptor { command } => {
+            runtime.block_on(cli::run_proto_descriptor(cli.client, command))
+        }
+        Command::WasmModule { command } => {
+            runtime.block_on(cli::run_wasm_module(cli.client, command))
+        }
+        Command::FilterPreset { command } => {
+            runtime.block_on(cli::run_resource(cli.client, "filter-presets", command))
+        }
+        Command::RetryPolicy { command } => {
+            runtime.block_on(cli::run_resource(cli.client, "retry-policies", command))
+        }
+        Command::Runtime { command } => runtime.block_on(cli::run_runtime(cli.client, command)),
+        Command::Webhook { command } => runtime.block_on(cli::run_webhook(cli.client, command)),
         Command::Dataplane { command } => runtime.block_on(cli::run_dataplane(cli.client, command)),
         Command::Expose { command } => runtime.block_on(cli::run_expose(cli.client, command)),
         Command::Unexpose { command } => runtime.block_on(cli::run_unexpose(cli.client, command)),
@@ -246,7 +286,11 @@ fn run() -> anyhow::Result<()> {
         Command::Version => cli::output::render(
             &cli.client,
             "version",
-            &serde_json::json!({ "version": VERSION }),
+            &serde_json::json!({
+                "version": VERSION,
+                "gitCommit": fp_api::build_info::GIT_COMMIT,
+                "buildTimestamp": fp_api::build_info::build_timestamp(),
+            }),
         ),
         // CLI-R-50: short-circuit before any network call — the schema is the CLI's own
         // structure, serialized from the clap tree.
@@ -589,21 +633,35 @@ mod tests {
         // from `--help`. The union guard forces every FUTURE leaf to be classified one way or the
         // other. Pure in-process (no temp dir / network) so it is inherently parallel-safe.
 
-        // 46 SPINE leaves (space-joined paths) — each must expose a parseable example.
+        // 71 SPINE leaves (space-joined paths) — each must expose a parseable example.
         const SPINE: &[&str] = &[
             "auth login",
             "config set-context",
             "org create",
             "org member add",
+            "org default-filters set",
             "team create",
             "team member add",
             "team grant add",
+            "team set-quotas",
+            "team update",
             "cluster create",
             "cluster update",
+            "cluster clone",
+            "cluster transfer",
             "listener create",
             "listener update",
+            "listener clone",
+            "listener transfer",
+            "listener validate-filters",
+            "listener validate-envoy",
+            "listener maintenance",
             "route create",
             "route update",
+            "route clone",
+            "route transfer",
+            "route add-vhost",
+            "route canary",
             "route generate",
             "api create",
             "api spec reject",
@@ -627,19 +685,30 @@ mod tests {
             "learn start",
             "learn discover start",
             "secret create",
+            "secret create-batch",
             "secret rotate",
+            "proto-descriptor create",
+            "wasm-module create",
+            "filter-preset create",
+            "filter-preset update",
+            "retry-policy create",
+            "retry-policy update",
+            "runtime set",
+            "webhook create",
             "dataplane create",
             "dataplane telemetry",
             "dataplane bootstrap",
             "dataplane cert register",
             "dataplane cert issue",
             "dataplane cert revoke",
+            "dataplane pin",
+            "dataplane rollout set",
             "expose",
             "unexpose",
             "apply",
         ];
 
-        // 78 EXEMPT leaves (space-joined paths) — no example required.
+        // 116 EXEMPT leaves (space-joined paths) — no example required.
         const EXEMPT: &[&str] = &[
             "ai budgets delete",
             "ai budgets get",
@@ -668,14 +737,23 @@ mod tests {
             "config show",
             "config use-context",
             "dataplane cert list",
+            "dataplane config-versions",
+            "dataplane connected",
             "dataplane get",
             "dataplane list",
+            "dataplane promote",
+            "dataplane rollout get",
+            "dataplane rollout promote",
             "db migrate",
+            "filter-preset delete",
+            "filter-preset get",
+            "filter-preset list",
             "learn cancel",
             "learn discover generate-spec",
             "learn discover list",
             "learn discover status",
             "learn discover stop",
+            "learn export-spec",
             "learn generate-spec",
             "learn get",
             "learn list",
@@ -686,14 +764,24 @@ mod tests {
             "mcp connections",
             "mcp status",
             "openapi",
+            "ops diagnostics",
+            "ops reconcile",
             "ops trace",
+            "ops webhook-dead-letters",
             "ops xds nacks",
             "ops xds status",
+            "cluster outliers",
+            "cluster references",
+            "cluster stats",
+            "org default-filters get",
             "org delete",
             "org get",
             "org list",
             "org member list",
             "org member remove",
+            "proto-descriptor delete",
+            "proto-descriptor get",
+            "proto-descriptor list",
             "rate-limit domain delete",
             "rate-limit domain get",
             "rate-limit domain list",
@@ -703,22 +791,41 @@ mod tests {
             "rate-limit policy delete",
             "rate-limit policy get",
             "rate-limit policy list",
+            "retry-policy delete",
+            "retry-policy get",
+            "retry-policy list",
             "route apply",
             "route delete",
+            "route effective-filters",
+            "route plan-resources",
+            "route plan-teardown",
+            "route plans",
+            "route remove-vhost",
             "route get",
             "route list",
+            "runtime delete",
+            "runtime get",
+            "runtime list",
             "schema",
             "secret get",
             "secret list",
             "serve",
             "stats overview",
             "team delete",
+            "team get",
             "team grant list",
             "team grant remove",
             "team list",
             "team member list",
             "team member remove",
+            "team quotas",
             "version",
+            "wasm-module delete",
+            "wasm-module get",
+            "wasm-module list",
+            "webhook delete",
+            "webhook get",
+            "webhook list",
         ];
 
         use std::collections::BTreeSet;
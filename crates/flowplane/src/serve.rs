@@ -21,7 +21,16 @@ pub async fn run() -> anyhow::Result<()> {
         );
     }
 
-    let pool = fp_storage::connect(&config.database_url, config.db_max_connections).await?;
+    let pool = fp_storage::connect_with(
+        &config.database_url,
+        fp_storage::PoolSettings {
+            max_connections: config.db_max_connections,
+            acquire_timeout: std::time::Duration::from_secs(config.db_acquire_timeout_secs),
+            max_lifetime: (config.db_max_lifetime_secs > 0)
+                .then(|| std::time::Duration::from_secs(config.db_max_lifetime_secs)),
+        },
+    )
+    .await?;
     fp_storage::migrate(&pool).await?;
     tracing::info!("database connected and migrations applied");
 
@@ -55,7 +64,9 @@ pub async fn run() -> anyhow::Result<()> {
     }
 
     // xDS pipeline: snapshot cache primed from the DB (restart safety), then kept fresh by
-    // the outbox consumer, which also feeds certificate revocations to live streams.
+    // the outbox consumer, which also feeds certificate revocations to live streams. Priming
+    // runs beside the API, so `/readyz` reports the cache unprimed until it finishes, and the
+    // xDS listener only opens once it has.
     let (xds_shutdown_tx, xds_shutdown_rx) = tokio::sync::watch::channel(false);
     // S6: when an RLS gRPC endpoint is configured, the snapshot cache injects the built-in
     // rate_limit_cluster into every team's CDS. Build its config from ServerConfig (fp-xds stays
@@ -85,12 +96,10 @@ pub async fn run() -> anyhow::Result<()> {
         );
     }
     let snapshot_cache = fp_xds::snapshot::SnapshotCache::with_rls(rls_cluster);
+    // Readiness for the xDS listener: only probed when one is actually started below.
+    let xds_server_addr = (config.xds_tls.is_some() || config.dev_mode).then_some(config.xds_addr);
     let xds_consumer_failed = Arc::new(AtomicBool::new(false));
-    let primed = snapshot_cache
-        .prime_all(&pool)
-        .await
-        .map_err(|e| anyhow::anyhow!("failed to prime xDS snapshot cache: {e}"))?;
-    tracing::info!(teams = primed, "xDS snapshot cache primed from database");
+    let xds_primed = Arc::new(AtomicBool::new(false));
     let (revocation_tx, _) = tokio::sync::broadcast::channel::<uuid::Uuid>(64);
     // Handles for spawned background tasks; awaited (bounded) on shutdown so streams, the
     // outbox consumer, and read-only samplers drain rather than being abandoned mid-flight.
@@ -114,11 +123,13 @@ pub async fn run() -> anyhow::Result<()> {
         let consumer_pool = pool.clone();
         let revocations = revocation_tx.clone();
         let consumer_failed = xds_consumer_failed.clone();
+        let debounce = std::time::Duration::from_millis(config.xds_debounce_ms);
         xds_tasks.push(tokio::spawn(async move {
             let handler_pool = consumer_pool.clone();
             let result = fp_storage::outbox::run_consumer(
                 consumer_pool,
                 fp_xds::snapshot::XDS_CONSUMER,
+                debounce,
                 move |events| {
                     let cache = cache.clone();
                     let pool = handler_pool.clone();
@@ -137,57 +148,74 @@ pub async fn run() -> anyhow::Result<()> {
             }
         }));
     }
-    if let Some(xds_tls) = &config.xds_tls {
-        // Production path: mandatory mTLS, team identity from the certificate registry.
-        let cache = snapshot_cache.clone();
-        let xds_addr = config.xds_addr;
-        let tls = fp_xds::server::XdsTlsPaths {
-            cert_path: xds_tls.cert_path.clone(),
-            key_path: xds_tls.key_path.clone(),
-            client_ca_path: xds_tls.client_ca_path.clone(),
+    let xds_server: Option<std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>> =
+        if let Some(xds_tls) = &config.xds_tls {
+            // Production path: mandatory mTLS, team identity from the certificate registry.
+            let cache = snapshot_cache.clone();
+            let xds_addr = config.xds_addr;
+            let tls = fp_xds::server::XdsTlsPaths {
+                cert_path: xds_tls.cert_path.clone(),
+                key_path: xds_tls.key_path.clone(),
+                client_ca_path: xds_tls.client_ca_path.clone(),
+            };
+            let resolver =
+                std::sync::Arc::new(fp_xds::ads::CertRegistryResolver::new(pool.clone()));
+            let revocations = revocation_tx.clone();
+            let nack_pool = pool.clone();
+            let shutdown = xds_shutdown_signal(&xds_shutdown_tx);
+            Some(Box::pin(async move {
+                if let Err(e) = fp_xds::server::serve_mtls(
+                    xds_addr,
+                    cache,
+                    resolver,
+                    revocations,
+                    nack_pool,
+                    &tls,
+                    shutdown,
+                )
+                .await
+                {
+                    tracing::error!("xds server exited: {e}");
+                }
+            }))
+        } else if config.dev_mode {
+            let cache = snapshot_cache.clone();
+            let xds_addr = config.xds_addr;
+            let nack_pool = pool.clone();
+            let shutdown = xds_shutdown_signal(&xds_shutdown_tx);
+            Some(Box::pin(async move {
+                if let Err(e) = fp_xds::server::serve_plaintext(
+                    xds_addr,
+                    cache,
+                    std::sync::Arc::new(fp_xds::ads::NodeIdTeamResolver),
+                    Some(nack_pool),
+                    shutdown,
+                )
+                .await
+                {
+                    tracing::error!("xds server exited: {e}");
+                }
+            }))
+        } else {
+            tracing::warn!(
+                "xDS listener disabled: mTLS is mandatory for xDS — set FLOWPLANE_XDS_TLS_CERT, \
+                 FLOWPLANE_XDS_TLS_KEY, and FLOWPLANE_XDS_TLS_CLIENT_CA to enable it"
+            );
+            None
         };
-        let resolver = std::sync::Arc::new(fp_xds::ads::CertRegistryResolver::new(pool.clone()));
-        let revocations = revocation_tx.clone();
-        let nack_pool = pool.clone();
-        let shutdown = xds_shutdown_signal(&xds_shutdown_tx);
-        xds_tasks.push(tokio::spawn(async move {
-            if let Err(e) = fp_xds::server::serve_mtls(
-                xds_addr,
-                cache,
-                resolver,
-                revocations,
-                nack_pool,
-                &tls,
-                shutdown,
-            )
-            .await
-            {
-                tracing::error!("xds server exited: {e}");
-            }
-        }));
-    } else if config.dev_mode {
+    {
         let cache = snapshot_cache.clone();
-        let xds_addr = config.xds_addr;
-        let nack_pool = pool.clone();
-        let shutdown = xds_shutdown_signal(&xds_shutdown_tx);
+        let prime_pool = pool.clone();
+        let primed = xds_primed.clone();
+        let shutdown = xds_shutdown_tx.subscribe();
         xds_tasks.push(tokio::spawn(async move {
-            if let Err(e) = fp_xds::server::serve_plaintext(
-                xds_addr,
-                cache,
-                std::sync::Arc::new(fp_xds::ads::NodeIdTeamResolver),
-                Some(nack_pool),
-                shutdown,
-            )
-            .await
-            {
-                tracing::error!("xds server exited: {e}");
+            if !prime_xds_cache(&cache, &prime_pool, &primed, shutdown, XDS_PRIME_RETRY).await {
+                return;
+            }
+            if let Some(server) = xds_server {
+                server.await;
             }
         }));
-    } else {
-        tracing::warn!(
-            "xDS listener disabled: mTLS is mandatory for xDS — set FLOWPLANE_XDS_TLS_CERT, \
-             FLOWPLANE_XDS_TLS_KEY, and FLOWPLANE_XDS_TLS_CLIENT_CA to enable it"
-        );
     }
 
     let discovery_forwarding_policy =
@@ -243,6 +271,79 @@ pub async fn run() -> anyhow::Result<()> {
         );
     }
 
+    // Secret expiry sweep: withdraw secrets whose expires_at has passed. The emitted
+    // `secret.expired` events drive the xDS consumer, so Envoy sees the SDS resource removed.
+    {
+        let webhook = match &config.secret_expiry_webhook_url {
+            Some(url) => Some(fp_core::services::webhooks::Webhook {
+                url: url.clone(),
+                secret: config
+                    .secret_expiry_webhook_secret
+                    .as_ref()
+                    .map(|secret| secret.secret().to_owned()),
+                client: reqwest::Client::builder()
+                    .redirect(reqwest::redirect::Policy::none())
+                    .timeout(std::time::Duration::from_secs(10))
+                    .build()
+                    .map_err(|e| {
+                        anyhow::anyhow!("cannot build the secret expiry webhook client: {e}")
+                    })?,
+                retry: fp_core::services::webhooks::RetryPolicy::default(),
+            }),
+            None => None,
+        };
+        tokio::spawn(run_secret_expiry_sweep(
+            pool.clone(),
+            webhook,
+            xds_shutdown_tx.subscribe(),
+        ));
+        tracing::info!(
+            interval_secs = SECRET_EXPIRY_SWEEP_INTERVAL_SECS,
+            webhook = config.secret_expiry_webhook_url.is_some(),
+            webhook_signed = config.secret_expiry_webhook_secret.is_some(),
+            "secret expiry sweep started"
+        );
+    }
+
+    // Team webhook subscriptions: their own outbox consumer fans team events out. The cursor
+    // starts at the event head the first time, so a new deployment never replays history.
+    {
+        fp_storage::outbox::register_consumer_from_head(
+            &pool,
+            fp_core::services::webhooks::SUBSCRIPTION_CONSUMER,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("cannot register the webhook outbox consumer: {e}"))?;
+        let delivery = fp_core::services::webhooks::SubscriptionDelivery {
+            advisory: egress_advisory.clone(),
+            timeout: std::time::Duration::from_secs(10),
+            retry: fp_core::services::webhooks::RetryPolicy::default(),
+            workers: Default::default(),
+        };
+        let consumer_pool = pool.clone();
+        let shutdown = xds_shutdown_tx.subscribe();
+        tokio::spawn(async move {
+            let handler_pool = consumer_pool.clone();
+            let result = fp_storage::outbox::run_consumer(
+                consumer_pool,
+                fp_core::services::webhooks::SUBSCRIPTION_CONSUMER,
+                std::time::Duration::ZERO,
+                move |events| {
+                    let pool = handler_pool.clone();
+                    let delivery = delivery.clone();
+                    async move {
+                        fp_core::services::webhooks::handle_events(&pool, &delivery, events).await
+                    }
+                },
+                shutdown,
+            )
+            .await;
+            if let Err(e) = result {
+                tracing::error!("webhook outbox consumer exited with error: {e}");
+            }
+        });
+    }
+
     let state = fp_api::AppState {
         pool,
         prometheus,
@@ -251,15 +352,33 @@ pub async fn run() -> anyhow::Result<()> {
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(
             config.tenant_write_limit_per_minute,
         )),
+        request_rate_limit: Arc::new(fp_api::throttle::RequestRateLimit::new(
+            config.api_rate_limit_per_sec,
+            config.api_admin_rate_limit_per_sec,
+        )),
+        max_body_bytes: config.api_max_body_bytes,
         xds_readiness: Some(fp_api::state::XdsReadiness {
             consumer: fp_xds::snapshot::XDS_CONSUMER,
             max_lag: 0,
             failed: xds_consumer_failed,
+            primed: xds_primed,
+            server_addr: xds_server_addr,
         }),
         discovery_forwarding_policy,
         egress_advisory,
         rls_repush,
         rls_grpc_configured: config.rls_grpc_url.is_some(),
+        debug_team_header: config.debug_team_header,
+        connected_proxies: snapshot_cache.connected_proxies(),
+        envoy_validator: config
+            .envoy_validate_binary
+            .as_ref()
+            .map(|binary| Arc::new(fp_xds::validate::EnvoyValidator::new(binary))),
+        read_cache: Arc::new(fp_api::read_cache::ReadCache::new(
+            (config.read_cache_ttl_secs > 0)
+                .then(|| std::time::Duration::from_secs(config.read_cache_ttl_secs)),
+        )),
+        xds_snapshots: Some(snapshot_cache.clone()),
     };
     let router = fp_api::build_router(state);
 
@@ -287,26 +406,35 @@ pub async fn run() -> anyhow::Result<()> {
                     })?;
             axum_server::bind_rustls(config.api_addr, rustls_config)
                 .handle(handle)
-                .serve(router.into_make_service())
+                .serve(router.into_make_service_with_connect_info::<std::net::SocketAddr>())
                 .await?;
         }
         None => {
             axum_server::bind(config.api_addr)
                 .handle(handle)
-                .serve(router.into_make_service())
+                .serve(router.into_make_service_with_connect_info::<std::net::SocketAddr>())
                 .await?;
         }
     }
 
-    // Signal the xDS tasks and let them drain (bounded). serve_with_shutdown finishes the
-    // in-flight gRPC streams; the outbox consumer exits its loop on the watch flag.
+    // Drain xDS: live ADS streams get a final push and `xds_drain_grace_secs` before they
+    // close, while serve_with_shutdown stops accepting new connections and waits for them;
+    // the outbox consumer exits its loop on the watch flag. Each task is bounded by the
+    // grace plus a small margin so a stuck task cannot hold the process.
+    let drain_grace = std::time::Duration::from_secs(config.xds_drain_grace_secs);
+    tracing::info!(
+        grace_secs = config.xds_drain_grace_secs,
+        "draining xDS streams"
+    );
+    snapshot_cache.begin_drain(drain_grace);
     let _ = xds_shutdown_tx.send(true);
+    let drain_budget = drain_grace + std::time::Duration::from_secs(5);
     for task in xds_tasks {
-        if tokio::time::timeout(std::time::Duration::from_secs(10), task)
-            .await
-            .is_err()
-        {
-            tracing::warn!("an xDS task did not drain within 10s; abandoning it");
+        if tokio::time::timeout(drain_budget, task).await.is_err() {
+            tracing::warn!(
+                budget_secs = drain_budget.as_secs(),
+                "an xDS task did not drain in time; abandoning it"
+            );
         }
     }
     if let Some(provider) = otel_provider {
@@ -318,6 +446,35 @@ pub async fn run() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Pause between attempts to prime the xDS snapshot cache at startup.
+const XDS_PRIME_RETRY: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Prime the snapshot cache, retrying every `retry` until it succeeds, then set `primed`.
+/// Serving before the cache is primed would hand reconnecting dataplanes empty snapshots,
+/// so a failed prime is retried rather than skipped. Returns `false` if shutdown came first.
+async fn prime_xds_cache(
+    cache: &fp_xds::snapshot::SnapshotCache,
+    pool: &sqlx::PgPool,
+    primed: &AtomicBool,
+    mut shutdown: tokio::sync::watch::Receiver<bool>,
+    retry: std::time::Duration,
+) -> bool {
+    loop {
+        match cache.prime_all(pool).await {
+            Ok(teams) => {
+                tracing::info!(teams, "xDS snapshot cache primed from database");
+                primed.store(true, Ordering::SeqCst);
+                return true;
+            }
+            Err(e) => tracing::error!("failed to prime xDS snapshot cache; retrying: {e}"),
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(retry) => {}
+            _ = shutdown.wait_for(|flag| *flag) => return false,
+        }
+    }
+}
+
 /// A future that resolves when the xDS shutdown flag flips to `true`. Each xDS server task
 /// gets its own receiver so `serve_with_shutdown` can drain its streams on shutdown.
 fn xds_shutdown_signal(
@@ -486,6 +643,92 @@ async fn run_ai_trace_sweep(pool: sqlx::PgPool, mut shutdown: tokio::sync::watch
     }
 }
 
+/// Secret expiry cadence. Expiry is a revocation, so it runs far tighter than the trace
+/// sweep: a secret stays served for at most a minute past its `expires_at`.
+const SECRET_EXPIRY_SWEEP_INTERVAL_SECS: u64 = 60;
+
+/// Secret expiry loop: each tick marks due secrets and emits their `secret.expired` events
+/// (fp-core `expire_due_secrets`), then, when a webhook is configured, POSTs one notice per
+/// withdrawn secret. A full batch re-runs immediately instead of waiting for the next tick.
+/// Errors are logged and the loop keeps running.
+async fn run_secret_expiry_sweep(
+    pool: sqlx::PgPool,
+    webhook: Option<fp_core::services::webhooks::Webhook>,
+    mut shutdown: tokio::sync::watch::Receiver<bool>,
+) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+        SECRET_EXPIRY_SWEEP_INTERVAL_SECS,
+    ));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            changed = shutdown.changed() => {
+                if changed.is_err() || *shutdown.borrow() {
+                    return;
+                }
+                continue;
+            }
+        }
+        loop {
+            let as_of = sqlx::types::chrono::Utc::now();
+            let expired = match fp_core::services::secrets::expire_due_secrets(&pool, as_of).await {
+                Ok(expired) => expired,
+                Err(e) => {
+                    tracing::warn!("secret expiry sweep failed: {e}");
+                    break;
+                }
+            };
+            for secret in &expired {
+                tracing::info!(team = %secret.team.id, secret = %secret.name,
+                    "secret expired; withdrawn from SDS");
+                if let Some(webhook) = &webhook {
+                    tokio::spawn(notify_secret_expired(
+                        pool.clone(),
+                        webhook.clone(),
+                        secret.clone(),
+                    ));
+                }
+            }
+            if (expired.len() as i64) < fp_core::services::secrets::SECRET_EXPIRY_BATCH {
+                break;
+            }
+        }
+    }
+}
+
+/// Deliver one expiry notice off the sweep loop, so retries never delay the next withdrawal.
+/// A notice that exhausts its retries is dead-lettered.
+async fn notify_secret_expired(
+    pool: sqlx::PgPool,
+    webhook: fp_core::services::webhooks::Webhook,
+    secret: fp_storage::repos::secrets::ExpiredSecret,
+) {
+    const EVENT: &str = "secret.expired";
+    let notice = serde_json::json!({
+        "event": EVENT,
+        "org_id": secret.team.org_id.as_uuid(),
+        "team_id": secret.team.id.as_uuid(),
+        "secret_id": secret.id.as_uuid(),
+        "name": secret.name,
+        "expires_at": secret.expires_at,
+    });
+    if let Err(failure) = fp_core::services::webhooks::deliver(&webhook, EVENT, &notice).await {
+        tracing::warn!(secret = %secret.name, attempts = failure.attempts,
+            status = ?failure.last_status, error = %failure.last_error,
+            "secret expiry webhook failed; dead-lettered");
+        fp_core::services::webhooks::dead_letter(
+            &pool,
+            secret.team.id,
+            None,
+            EVENT,
+            &notice,
+            &failure,
+        )
+        .await;
+    }
+}
+
 fn observe_pool_metrics(pool: &sqlx::PgPool, max_connections: u32) {
     let size = pool.size();
     let idle = pool.num_idle() as u32;
@@ -923,6 +1166,60 @@ mod rls_push_client_tests {
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod xds_priming_tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// `/readyz` reads `primed`: it must stay false while priming fails and flip only once a
+    /// prime succeeds.
+    #[tokio::test]
+    async fn primed_stays_false_until_a_prime_succeeds() {
+        let cache = fp_xds::snapshot::SnapshotCache::new();
+        let primed = AtomicBool::new(false);
+        let (shutdown_tx, _) = tokio::sync::watch::channel(false);
+
+        let unreachable = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(200))
+            .connect_lazy("postgres://flowplane@127.0.0.1:1/flowplane")
+            .expect("lazy pool");
+        let stop = shutdown_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(600)).await;
+            let _ = stop.send(true);
+        });
+        let done = prime_xds_cache(
+            &cache,
+            &unreachable,
+            &primed,
+            shutdown_tx.subscribe(),
+            Duration::from_millis(50),
+        )
+        .await;
+        assert!(!done, "shutdown ends a prime that never succeeds");
+        assert!(
+            !primed.load(Ordering::SeqCst),
+            "a failed prime is not ready"
+        );
+
+        let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+            eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+            return;
+        };
+        let pool = fp_storage::connect(&url, 4).await.expect("connect");
+        fp_storage::migrate(&pool).await.expect("migrate");
+        let (_running, shutdown) = tokio::sync::watch::channel(false);
+        let done =
+            prime_xds_cache(&cache, &pool, &primed, shutdown, Duration::from_millis(50)).await;
+        assert!(done);
+        assert!(
+            primed.load(Ordering::SeqCst),
+            "primed once the cache is built"
+        );
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod bootstrap_token_tests {
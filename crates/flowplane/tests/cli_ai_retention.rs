@@ -83,11 +83,18 @@ async fn ai_retention_cli_get_and_set_work_as_rest_only_clients() {
         version: "test",
         validator: None,
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
         .await
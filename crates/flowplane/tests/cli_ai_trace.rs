@@ -106,11 +106,18 @@ async fn ai_trace_cli_renders_the_same_hops_as_rest() {
         version: "test",
         validator: None,
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
         .await
@@ -79,6 +79,12 @@ const EXEMPT: &[&str] = &[
 /// one is the no-silent-sampling guarantee: the union check below fails if any command is added
 /// or removed without being classified here.
 const SHARED_LAYER_COVERED: &[&str] = &[
+    // cluster (beyond the snapshotted CRUD)
+    "cluster clone",
+    "cluster transfer",
+    "cluster outliers",
+    "cluster references",
+    "cluster stats",
     // org
     "org list",
     "org get",
@@ -87,9 +93,13 @@ const SHARED_LAYER_COVERED: &[&str] = &[
     "org member list",
     "org member add",
     "org member remove",
+    "org default-filters get",
+    "org default-filters set",
     // team
     "team list",
     "team create",
+    "team get",
+    "team update",
     "team delete",
     "team member list",
     "team member add",
@@ -97,20 +107,36 @@ const SHARED_LAYER_COVERED: &[&str] = &[
     "team grant list",
     "team grant add",
     "team grant remove",
+    "team quotas",
+    "team set-quotas",
     // listener
     "listener list",
     "listener get",
     "listener create",
     "listener update",
     "listener delete",
+    "listener clone",
+    "listener transfer",
+    "listener validate-filters",
+    "listener validate-envoy",
+    "listener maintenance",
     // route
     "route list",
     "route get",
     "route create",
     "route update",
     "route delete",
+    "route clone",
+    "route transfer",
+    "route add-vhost",
+    "route remove-vhost",
+    "route effective-filters",
+    "route canary",
     "route generate",
     "route apply",
+    "route plans",
+    "route plan-resources",
+    "route plan-teardown",
     // api
     "api list",
     "api get",
@@ -180,15 +206,56 @@ const SHARED_LAYER_COVERED: &[&str] = &[
     "learn get",
     "learn stop",
     "learn generate-spec",
+    "learn export-spec",
     "learn cancel",
     // secret
     "secret list",
     "secret get",
     "secret create",
+    "secret create-batch",
     "secret rotate",
+    // proto-descriptor
+    "proto-descriptor list",
+    "proto-descriptor get",
+    "proto-descriptor create",
+    "proto-descriptor delete",
+    // wasm-module
+    "wasm-module list",
+    "wasm-module get",
+    "wasm-module create",
+    "wasm-module delete",
+    // filter-preset
+    "filter-preset list",
+    "filter-preset get",
+    "filter-preset create",
+    "filter-preset update",
+    "filter-preset delete",
+    // retry-policy
+    "retry-policy list",
+    "retry-policy get",
+    "retry-policy create",
+    "retry-policy update",
+    "retry-policy delete",
+    // runtime
+    "runtime list",
+    "runtime get",
+    "runtime set",
+    "runtime delete",
+    // webhook
+    "webhook list",
+    "webhook get",
+    "webhook create",
+    "webhook delete",
     // dataplane
     "dataplane list",
     "dataplane get",
+    "dataplane connected",
+    "dataplane config-versions",
+    "dataplane pin",
+    "dataplane promote",
+    "dataplane rollout get",
+    "dataplane rollout set",
+    "dataplane rollout promote",
     "dataplane create",
     "dataplane telemetry",
     // stats
@@ -197,6 +264,9 @@ const SHARED_LAYER_COVERED: &[&str] = &[
     "ops xds status",
     "ops xds nacks",
     "ops trace",
+    "ops diagnostics",
+    "ops reconcile",
+    "ops webhook-dead-letters",
     // top-level
     "expose",
     "unexpose",
@@ -407,7 +477,7 @@ async fn cluster_family_json_envelopes_are_frozen() {
             .expect("run cluster command")
     };
 
-    // cluster list → clusterList, data is the Page wrapper {items,limit,offset,total}.
+    // cluster list → clusterList, data is the Page wrapper {items,limit,offset,total,has_more}.
     let list = run(vec![
         "cluster".into(),
         "list".into(),
@@ -421,7 +491,8 @@ async fn cluster_family_json_envelopes_are_frozen() {
         json!({
             "schemaVersion": 1,
             "kind": "clusterList",
-            // `cluster list` is Page-backed: data wraps items in {items,limit,offset,total}.
+            // `cluster list` is Page-backed: data wraps items in
+            // {items,limit,offset,total,has_more}.
             "data": {
                 "items": [
                     { "name": "alpha", "revision": 1, "service_name": "alpha-svc" },
@@ -429,7 +500,8 @@ async fn cluster_family_json_envelopes_are_frozen() {
                 ],
                 "limit": 50,
                 "offset": 0,
-                "total": 2
+                "total": 2,
+                "has_more": false
             }
         }),
         "cluster list -o json envelope drifted"
@@ -643,8 +715,13 @@ fn clap_lints_hold_across_the_whole_tree() {
         "cluster update",
         "listener create",
         "listener update",
+        "listener validate-filters",
+        "listener validate-envoy",
+        "org default-filters set",
         "route create",
         "route update",
+        "route add-vhost",
+        "team set-quotas",
         "ai providers create",
         "ai providers update",
         "ai routes create",
@@ -658,7 +735,12 @@ fn clap_lints_hold_across_the_whole_tree() {
         "rate-limit override set",
         "rate-limit override update",
         "secret create",
+        "secret create-batch",
         "secret rotate",
+        "filter-preset create",
+        "filter-preset update",
+        "retry-policy create",
+        "retry-policy update",
         "dataplane telemetry",
         "dataplane cert register",
     ]
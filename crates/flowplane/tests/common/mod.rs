@@ -92,7 +92,7 @@ pub async fn start_mock() -> MockServer {
 
 async fn list_clusters(Path(_team): Path<String>) -> Json<Value> {
     // The real `cluster list` endpoint is Page-backed: `data` wraps the items in
-    // `{ items, limit, offset, total }` (fp-api `Page<T>`), not a bare array. The mock mirrors
+    // `{ items, limit, offset, total, has_more }` (fp-api `Page<T>`), not a bare array. The mock mirrors
     // that so the fixture matches the server it stands in for (fpv2-86m.2 / F-1).
     Json(json!({
         "items": [
@@ -101,7 +101,8 @@ async fn list_clusters(Path(_team): Path<String>) -> Json<Value> {
         ],
         "limit": 50,
         "offset": 0,
-        "total": 2
+        "total": 2,
+        "has_more": false
     }))
 }
 
@@ -1,5 +1,6 @@
 //! Flowplane dataplane agent. The v2 shape is intentionally small: one outbound
-//! diagnostics stream, one bounded report queue, and a local health endpoint.
+//! diagnostics stream, one bounded report queue, and a local health endpoint. Each poll of
+//! the local Envoy's `/stats` yields a heartbeat and a report of per-cluster upstream gauges.
 
 use anyhow::{Context, Result};
 use axum::extract::State;
@@ -8,10 +9,11 @@ use axum::routing::get;
 use axum::Router;
 use clap::Parser;
 use fp_xds::diagnostics::{
-    diagnostics_report, AckStatus, DiagnosticsReport, EnvoyDiagnosticsServiceClient,
-    HeartbeatReport,
+    diagnostics_report, AckStatus, ClusterStatsReport, DiagnosticsReport,
+    EnvoyDiagnosticsServiceClient, HeartbeatReport, UpstreamClusterGauges, MAX_REPORTED_CLUSTERS,
 };
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -21,6 +23,8 @@ use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
 
 const DEFAULT_QUEUE_CAP: usize = 256;
+/// Reports sent per poll: a heartbeat and a cluster-stats report.
+const REPORTS_PER_POLL: usize = 2;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -179,6 +183,22 @@ impl StatsSnapshot {
     }
 }
 
+/// One `/stats` scrape: the counters behind the heartbeat and the per-cluster gauges.
+#[derive(Debug, Default, PartialEq)]
+struct EnvoyScrape {
+    totals: StatsSnapshot,
+    clusters: Vec<UpstreamClusterGauges>,
+}
+
+/// Circuit-breaker gauges that read 1 while the breaker is tripped.
+const OPEN_BREAKER_GAUGES: &[&str] = &[
+    "cx_open",
+    "cx_pool_open",
+    "rq_open",
+    "rq_pending_open",
+    "rq_retry_open",
+];
+
 #[derive(Debug, Default)]
 struct HealthState {
     last_admin_poll: Option<Instant>,
@@ -192,10 +212,11 @@ struct EnvoyStats {
     stats: Vec<serde_json::Value>,
 }
 
-fn parse_envoy_stats(body: &str) -> Result<StatsSnapshot> {
+fn parse_envoy_stats(body: &str) -> Result<EnvoyScrape> {
     let parsed: EnvoyStats =
         serde_json::from_str(body).context("parse Envoy /stats?format=json response")?;
     let mut snapshot = StatsSnapshot::default();
+    let mut clusters = BTreeMap::new();
     for entry in &parsed.stats {
         // Envoy's `/stats?format=json` mixes scalar `{name, value}` objects with at
         // least one non-scalar element (the histograms object, `{"histograms": {...}}`).
@@ -212,9 +233,56 @@ fn parse_envoy_stats(body: &str) -> Result<StatsSnapshot> {
             snapshot.requests += value;
         } else if name.ends_with(".downstream_rq_5xx") {
             snapshot.errors += value;
+        } else if let Some(stat) = name.strip_prefix("cluster.") {
+            fold_cluster_gauge(&mut clusters, stat, value);
         }
     }
-    Ok(snapshot)
+    Ok(EnvoyScrape {
+        totals: snapshot,
+        clusters: clusters.into_values().collect(),
+    })
+}
+
+/// Fold one `cluster.<name>.<stat>` value into its cluster's gauges. Flowplane cluster names
+/// carry no dots, so the first segment is the cluster; unrelated stats are ignored.
+fn fold_cluster_gauge(
+    clusters: &mut BTreeMap<String, UpstreamClusterGauges>,
+    stat: &str,
+    value: i64,
+) {
+    let Some((cluster, stat)) = stat.split_once('.') else {
+        return;
+    };
+    let open_breaker = stat.strip_prefix("circuit_breakers.").filter(|breaker| {
+        value > 0
+            && breaker
+                .split_once('.')
+                .is_some_and(|(_, gauge)| OPEN_BREAKER_GAUGES.contains(&gauge))
+    });
+    let slot: Option<fn(&mut UpstreamClusterGauges) -> &mut i64> = match stat {
+        "upstream_rq_active" => Some(|g| &mut g.rq_active),
+        "upstream_rq_pending_active" => Some(|g| &mut g.rq_pending_active),
+        "upstream_cx_active" => Some(|g| &mut g.cx_active),
+        "outlier_detection.ejections_active" => Some(|g| &mut g.ejections_active),
+        "membership_healthy" => Some(|g| &mut g.membership_healthy),
+        "membership_total" => Some(|g| &mut g.membership_total),
+        _ => None,
+    };
+    if open_breaker.is_none() && slot.is_none() {
+        return;
+    }
+    let gauges = clusters
+        .entry(cluster.to_string())
+        .or_insert_with(|| UpstreamClusterGauges {
+            cluster_name: cluster.to_string(),
+            ..Default::default()
+        });
+    if let Some(breaker) = open_breaker {
+        gauges.open_circuit_breakers.push(breaker.to_string());
+    }
+    if let Some(slot) = slot {
+        *slot(gauges) = value;
+    }
 }
 
 fn stat_value(value: &serde_json::Value) -> Option<i64> {
@@ -223,7 +291,7 @@ fn stat_value(value: &serde_json::Value) -> Option<i64> {
         .or_else(|| value.as_u64().and_then(|v| i64::try_from(v).ok()))
 }
 
-async fn scrape_stats(client: &reqwest::Client, admin_url: &str) -> Result<StatsSnapshot> {
+async fn scrape_stats(client: &reqwest::Client, admin_url: &str) -> Result<EnvoyScrape> {
     let url = format!("{}/stats?format=json", admin_url.trim_end_matches('/'));
     let body = client
         .get(url)
@@ -258,6 +326,28 @@ async fn diagnostics_channel(config: &Config) -> Result<Channel> {
     endpoint.connect().await.context("connect diagnostics gRPC")
 }
 
+fn cluster_stats_report(
+    config: &Config,
+    mut clusters: Vec<UpstreamClusterGauges>,
+) -> DiagnosticsReport {
+    if clusters.len() > MAX_REPORTED_CLUSTERS {
+        tracing::warn!(
+            clusters = clusters.len(),
+            "Envoy has more clusters than one stats report carries; reporting the first {MAX_REPORTED_CLUSTERS}"
+        );
+        clusters.truncate(MAX_REPORTED_CLUSTERS);
+    }
+    DiagnosticsReport {
+        schema_version: 1,
+        report_id: uuid::Uuid::now_v7().to_string(),
+        dataplane_id: config.dataplane_id.to_string(),
+        observed_at: None,
+        payload: Some(diagnostics_report::Payload::ClusterStats(
+            ClusterStatsReport { clusters },
+        )),
+    }
+}
+
 fn heartbeat_report(config: &Config, delta: StatsSnapshot) -> DiagnosticsReport {
     DiagnosticsReport {
         schema_version: 1,
@@ -281,8 +371,8 @@ async fn poll_loop(
 ) -> Result<()> {
     let mut previous = None;
     loop {
-        let snapshot = match scrape_stats(&http, &config.envoy_admin_url).await {
-            Ok(snapshot) => snapshot,
+        let scrape = match scrape_stats(&http, &config.envoy_admin_url).await {
+            Ok(scrape) => scrape,
             Err(error) => {
                 tracing::warn!(%error, "Envoy admin scrape failed; retrying");
                 if config.once {
@@ -293,11 +383,14 @@ async fn poll_loop(
             }
         };
         health.write().await.last_admin_poll = Some(Instant::now());
-        let delta = snapshot.delta_from(previous);
+        let delta = scrape.totals.delta_from(previous);
         tx.send(heartbeat_report(&config, delta))
             .await
             .context("diagnostics queue closed")?;
-        previous = Some(snapshot);
+        tx.send(cluster_stats_report(&config, scrape.clusters))
+            .await
+            .context("diagnostics queue closed")?;
+        previous = Some(scrape.totals);
         if config.once {
             return Ok(());
         }
@@ -317,6 +410,7 @@ async fn stream_loop(
         .await
         .context("open diagnostics stream")?
         .into_inner();
+    let mut acked = 0;
     while let Some(ack) = responses
         .message()
         .await
@@ -330,7 +424,8 @@ async fn stream_loop(
             );
         }
         health.write().await.last_ack = Some(Instant::now());
-        if config.once {
+        acked += 1;
+        if config.once && acked == REPORTS_PER_POLL {
             return Ok(());
         }
     }
@@ -417,7 +512,7 @@ async fn main() -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_envoy_stats, Config, StatsSnapshot};
+    use super::{parse_envoy_stats, Config, StatsSnapshot, UpstreamClusterGauges};
     use std::time::Duration;
 
     #[test]
@@ -431,7 +526,7 @@ mod tests {
             ]}"#,
         )?;
         assert_eq!(
-            parsed,
+            parsed.totals,
             StatsSnapshot {
                 requests: 9,
                 errors: 1
@@ -459,12 +554,22 @@ mod tests {
         );
         let parsed = parse_envoy_stats(ENVOY_1_37_STATS)?;
         assert_eq!(
-            parsed,
+            parsed.totals,
             StatsSnapshot {
                 requests: 12,
                 errors: 6
             }
         );
+        // The fixture's one upstream cluster: a healthy host and every breaker closed.
+        assert_eq!(
+            parsed.clusters,
+            vec![UpstreamClusterGauges {
+                cluster_name: "dead".into(),
+                membership_healthy: 1,
+                membership_total: 1,
+                ..Default::default()
+            }]
+        );
         Ok(())
     }
 
@@ -484,7 +589,7 @@ mod tests {
             ]}"#,
         )?;
         assert_eq!(
-            parsed,
+            parsed.totals,
             StatsSnapshot {
                 requests: 5,
                 errors: 2
@@ -499,7 +604,48 @@ mod tests {
         // zero snapshot (distinct from the malformed cases below, which must error).
         assert_eq!(
             parse_envoy_stats(r#"{"stats":[]}"#)?,
-            StatsSnapshot::default()
+            super::EnvoyScrape::default()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parser_collects_upstream_gauges_and_open_breakers_per_cluster() -> anyhow::Result<()> {
+        let parsed = parse_envoy_stats(
+            r#"{"stats":[
+                {"name":"cluster.orders.upstream_rq_active","value":4},
+                {"name":"cluster.orders.upstream_rq_pending_active","value":2},
+                {"name":"cluster.orders.upstream_cx_active","value":3},
+                {"name":"cluster.orders.outlier_detection.ejections_active","value":1},
+                {"name":"cluster.orders.membership_healthy","value":2},
+                {"name":"cluster.orders.membership_total","value":3},
+                {"name":"cluster.orders.circuit_breakers.default.rq_open","value":1},
+                {"name":"cluster.orders.circuit_breakers.default.cx_open","value":0},
+                {"name":"cluster.orders.circuit_breakers.high.remaining_rq","value":5},
+                {"name":"cluster.orders.upstream_rq_total","value":90},
+                {"name":"cluster.billing.upstream_rq_active","value":1},
+                {"name":"cluster_manager.active_clusters","value":2}
+            ]}"#,
+        )?;
+        assert_eq!(
+            parsed.clusters,
+            vec![
+                UpstreamClusterGauges {
+                    cluster_name: "billing".into(),
+                    rq_active: 1,
+                    ..Default::default()
+                },
+                UpstreamClusterGauges {
+                    cluster_name: "orders".into(),
+                    rq_active: 4,
+                    rq_pending_active: 2,
+                    cx_active: 3,
+                    ejections_active: 1,
+                    membership_healthy: 2,
+                    membership_total: 3,
+                    open_circuit_breakers: vec!["default.rq_open".into()],
+                },
+            ]
         );
         Ok(())
     }
@@ -0,0 +1,52 @@
+//! Compiles build provenance into the crate for `GET /api/v1/version`: the git commit
+//! (`FLOWPLANE_GIT_COMMIT` when set, e.g. by a container build without `.git`, else
+//! `git rev-parse HEAD`) and the build time (`SOURCE_DATE_EPOCH` when set, for reproducible
+//! builds, else now).
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=FLOWPLANE_GIT_COMMIT");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    let commit = std::env::var("FLOWPLANE_GIT_COMMIT")
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .or_else(|| git(&["rev-parse", "HEAD"]))
+        .unwrap_or_else(|| "unknown".into());
+    println!("cargo:rustc-env=FLOWPLANE_GIT_COMMIT={commit}");
+
+    // Re-stamp when HEAD moves (checkout or commit); a missing path would force a rerun on
+    // every build, so only existing ones are watched.
+    if let Some(git_dir) = git(&["rev-parse", "--absolute-git-dir"]) {
+        for watched in ["HEAD", "logs/HEAD"] {
+            let path = Path::new(&git_dir).join(watched);
+            if path.exists() {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+    }
+
+    let built_at = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0)
+        });
+    println!("cargo:rustc-env=FLOWPLANE_BUILD_EPOCH={built_at}");
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let out = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!out.is_empty()).then_some(out)
+}
@@ -161,12 +161,7 @@ pub async fn list_ai_providers(
         ai_svc::list_providers(&state.pool, &ctx, team, query.limit, query.offset, rid).await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(AiProviderView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/ai/providers",
@@ -301,12 +296,7 @@ pub async fn list_ai_routes(
         ai_svc::list_routes(&state.pool, &ctx, team, query.limit, query.offset, rid).await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(AiRouteView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/ai/routes",
@@ -422,12 +412,7 @@ pub async fn list_ai_budgets(
         ai_svc::list_budgets(&state.pool, &ctx, team, query.limit, query.offset, rid).await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(AiBudgetView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/ai/budgets",
@@ -219,12 +219,7 @@ pub async fn list_apis(
         svc::list_apis(&state.pool, &ctx, team, query.limit, query.offset, rid).await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(ApiDefinitionView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/api-definitions",
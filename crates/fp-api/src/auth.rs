@@ -3,6 +3,7 @@
 //! (spec/08a §6 — v1 never audited them).
 
 use crate::error::ApiError;
+use crate::middleware::AccessLogPrincipal;
 use crate::state::AppState;
 use axum::extract::{Request, State};
 use axum::middleware::Next;
@@ -15,6 +16,9 @@ use std::str::FromStr;
 /// Header carrying the active-org selector (D-014): an org name or UUID.
 const ORG_SELECTOR_HEADER: &str = "x-flowplane-org";
 
+/// Debug response header naming the team the request resolved to (`FLOWPLANE_DEBUG_TEAM_HEADER`).
+pub const TEAM_DEBUG_HEADER: &str = "x-flowplane-team";
+
 #[derive(Debug, Clone)]
 pub struct OrgMemberships(pub Vec<(OrgId, OrgRole)>);
 
@@ -117,13 +121,9 @@ pub async fn authenticate(
         return authenticate_agent(state, request, next, rid, &token).await;
     }
 
-    let Some(validator) = state.validator.clone() else {
-        return ApiError::new(
-            DomainError::unavailable("authentication is not configured on this server")
-                .with_hint("set FLOWPLANE_OIDC_ISSUER/FLOWPLANE_OIDC_AUDIENCE (or dev mode)"),
-            rid,
-        )
-        .into_response();
+    let validator = match state.oidc_validator() {
+        Ok(validator) => std::sync::Arc::clone(validator),
+        Err(e) => return ApiError::new(e, rid).into_response(),
     };
 
     let claims = match validator.validate(&token).await {
@@ -181,9 +181,17 @@ pub async fn authenticate(
                 org_selector_required,
                 grants: GrantSet::new(loaded.grants),
             };
+            let principal = AccessLogPrincipal {
+                kind: "user",
+                id: loaded.user_id.as_uuid(),
+            };
             request.extensions_mut().insert(memberships);
-            request.extensions_mut().insert(ctx);
-            next.run(request).await
+            request.extensions_mut().insert(ctx.clone());
+            let path = request.uri().path().to_string();
+            let mut response = next.run(request).await;
+            response.extensions_mut().insert(principal);
+            insert_team_debug_header(&state, &ctx, &path, &mut response).await;
+            response
         }
         Ok(None) => ApiError::new(
             DomainError::new(ErrorCode::Unauthorized, "account is not available"),
@@ -203,13 +211,22 @@ async fn authenticate_agent(
 ) -> Response {
     match identity::load_agent_principal_by_token(&state.pool, token).await {
         Ok(Some(loaded)) => {
-            request.extensions_mut().insert(PrincipalCtx::Agent {
+            let principal = AccessLogPrincipal {
+                kind: "agent",
+                id: loaded.agent_id.as_uuid(),
+            };
+            let ctx = PrincipalCtx::Agent {
                 agent_id: loaded.agent_id,
                 org_id: loaded.org_id,
                 kind: loaded.kind,
                 grants: GrantSet::new(loaded.grants),
-            });
-            next.run(request).await
+            };
+            request.extensions_mut().insert(ctx.clone());
+            let path = request.uri().path().to_string();
+            let mut response = next.run(request).await;
+            response.extensions_mut().insert(principal);
+            insert_team_debug_header(&state, &ctx, &path, &mut response).await;
+            response
         }
         Ok(None) => {
             audit_authn_failure(&state, rid, request.uri().path(), "unauthorized").await;
@@ -223,6 +240,63 @@ async fn authenticate_agent(
     }
 }
 
+/// When enabled, re-resolve the `{team}` path segment the way handlers do and echo
+/// `team=<id>; org=<id>; scopes=<...>` on the response. Scopes are the caller's standing on
+/// that team (`platform-admin`, `org:<role>`, then `resource:action` grants) — names only,
+/// never tokens or claims. Omitted when the path is not team-scoped or the team does not
+/// resolve for this caller, so it discloses nothing a 404 would not.
+async fn insert_team_debug_header(
+    state: &AppState,
+    ctx: &PrincipalCtx,
+    path: &str,
+    response: &mut Response,
+) {
+    if !state.debug_team_header {
+        return;
+    }
+    let Some(raw) = crate::middleware::team_segment(path) else {
+        return;
+    };
+    let Ok(team) = crate::resources::resolve_team(state, ctx, raw).await else {
+        return;
+    };
+    let (mut scopes, grants) = match ctx {
+        PrincipalCtx::User {
+            platform_admin,
+            org,
+            grants,
+            ..
+        } => {
+            let mut standing = Vec::new();
+            if *platform_admin {
+                standing.push("platform-admin".to_string());
+            }
+            if let Some((org_id, role)) = org {
+                if *org_id == team.org_id {
+                    standing.push(format!("org:{}", role.as_str()));
+                }
+            }
+            (standing, grants)
+        }
+        PrincipalCtx::Agent { grants, .. } => (Vec::new(), grants),
+    };
+    let mut granted: Vec<String> = grants
+        .for_team(team.id)
+        .map(|(resource, action)| format!("{}:{}", resource.as_str(), action.as_str()))
+        .collect();
+    granted.sort();
+    scopes.extend(granted);
+    let value = format!(
+        "team={}; org={}; scopes={}",
+        team.id,
+        team.org_id,
+        scopes.join(",")
+    );
+    if let Ok(value) = axum::http::HeaderValue::from_str(&value) {
+        response.headers_mut().insert(TEAM_DEBUG_HEADER, value);
+    }
+}
+
 async fn audit_authn_failure(state: &AppState, rid: RequestId, path: &str, code: &str) {
     audit::record_best_effort(
         &state.pool,
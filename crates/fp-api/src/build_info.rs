@@ -0,0 +1,59 @@
+//! Build provenance compiled in by `build.rs`, served by `GET /api/v1/version` and printed by
+//! `flowplane version`.
+
+use serde::Serialize;
+
+pub const APP_NAME: &str = "flowplane";
+
+/// Commit the binary was built from; `unknown` when neither `FLOWPLANE_GIT_COMMIT` nor a git
+/// checkout was available at build time.
+pub const GIT_COMMIT: &str = env!("FLOWPLANE_GIT_COMMIT");
+
+const BUILD_EPOCH: &str = env!("FLOWPLANE_BUILD_EPOCH");
+
+/// Build time as RFC 3339 UTC (`SOURCE_DATE_EPOCH` when the build set it).
+pub fn build_timestamp() -> String {
+    BUILD_EPOCH
+        .parse::<i64>()
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map(|at| at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .unwrap_or_else(|| "unknown".into())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildInfo {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub git_commit: &'static str,
+    pub build_timestamp: String,
+}
+
+impl BuildInfo {
+    /// `version` is the binary's own (`AppState::version`), not this crate's.
+    pub fn new(version: &'static str) -> Self {
+        Self {
+            name: APP_NAME,
+            version,
+            git_commit: GIT_COMMIT,
+            build_timestamp: build_timestamp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_provenance_is_compiled_in() {
+        let info = BuildInfo::new("1.2.3");
+        assert_eq!(info.name, "flowplane");
+        assert!(!info.git_commit.is_empty());
+        assert!(
+            chrono::DateTime::parse_from_rfc3339(&info.build_timestamp).is_ok(),
+            "{}",
+            info.build_timestamp
+        );
+    }
+}
@@ -12,8 +12,10 @@ use axum::Json;
 use fp_core::services::dataplanes as svc;
 use fp_core::PrincipalCtx;
 use fp_domain::authz::TeamRef;
+use fp_domain::connected::ConnectedProxy;
 use fp_domain::dataplane::{Dataplane, ProxyCertificate};
-use fp_domain::{DomainError, RequestId, TeamStatsOverview};
+use fp_domain::rollout::ConfigRollout;
+use fp_domain::{DomainError, EnvoyAdminAccess, RequestId, TeamStatsOverview};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
@@ -29,6 +31,8 @@ pub struct DataplaneView {
     pub total_requests: i64,
     pub total_errors: i64,
     pub warming_failures: i64,
+    /// Config version the dataplane is held on; `null` follows the latest config.
+    pub pinned_config_version: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -46,6 +50,7 @@ impl From<Dataplane> for DataplaneView {
             total_requests: value.total_requests,
             total_errors: value.total_errors,
             warming_failures: value.warming_failures,
+            pinned_config_version: value.pinned_config_version,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -196,9 +201,16 @@ pub struct EnvoyConfigQuery {
     /// xDS listener port.
     #[serde(default = "default_xds_port")]
     pub xds_port: u16,
-    /// Loopback admin port for Envoy.
-    #[serde(default = "default_admin_port")]
-    pub admin_port: u16,
+    /// Loopback admin port for Envoy. Defaults to the team's `envoy_admin_port`, else 9901.
+    #[serde(default)]
+    pub admin_port: Option<u16>,
+    /// Admin interface exposure: `localhost`, `socket`, or `disabled`. Defaults to the
+    /// team's `envoy_admin_access`, else `localhost`.
+    #[serde(default)]
+    pub admin_access: Option<EnvoyAdminAccess>,
+    /// Unix socket path for `admin_access=socket`; defaults to `/var/run/envoy/admin.sock`.
+    #[serde(default)]
+    pub admin_socket_path: Option<String>,
     /// Dataplane client certificate path as seen by Envoy.
     #[serde(default)]
     pub cert_path: Option<String>,
@@ -208,6 +220,55 @@ pub struct EnvoyConfigQuery {
     /// CA bundle Envoy uses to verify the control-plane xDS server certificate.
     #[serde(default)]
     pub ca_path: Option<String>,
+    /// Run the rendered bootstrap through the configured `envoy --mode validate` first;
+    /// Envoy's errors come back as a 400.
+    #[serde(default)]
+    pub validate: bool,
+}
+
+/// Query of `GET /api/v1/bootstrap`: the node identity, plus the same transport options
+/// as the dataplane `envoy-config` endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct StandaloneBootstrapQuery {
+    /// Envoy `node.id`. With `team`, it is prefixed `team=<uuid>/` so the dev xDS resolver
+    /// places the proxy in that team.
+    pub node_id: String,
+    /// Envoy `node.cluster`.
+    pub cluster: String,
+    /// Team name or UUID whose config the proxy should receive.
+    #[serde(default)]
+    pub team: Option<String>,
+    /// Bootstrap transport mode. Use `dev` only with FLOWPLANE_DEV_MODE plaintext xDS.
+    #[serde(default = "default_bootstrap_mode")]
+    pub mode: BootstrapMode,
+    /// Host or DNS name Envoy uses to reach the control plane xDS listener.
+    #[serde(default = "default_xds_host")]
+    pub xds_host: String,
+    /// xDS listener port.
+    #[serde(default = "default_xds_port")]
+    pub xds_port: u16,
+    /// Loopback admin port for Envoy. Defaults to the team's `envoy_admin_port`, else 9901.
+    #[serde(default)]
+    pub admin_port: Option<u16>,
+    /// Admin interface exposure: `localhost`, `socket`, or `disabled`. Defaults to the
+    /// team's `envoy_admin_access`, else `localhost`.
+    #[serde(default)]
+    pub admin_access: Option<EnvoyAdminAccess>,
+    /// Unix socket path for `admin_access=socket`; defaults to `/var/run/envoy/admin.sock`.
+    #[serde(default)]
+    pub admin_socket_path: Option<String>,
+    /// Client certificate path as seen by Envoy.
+    #[serde(default)]
+    pub cert_path: Option<String>,
+    /// Private key path as seen by Envoy.
+    #[serde(default)]
+    pub key_path: Option<String>,
+    /// CA bundle Envoy uses to verify the control-plane xDS server certificate.
+    #[serde(default)]
+    pub ca_path: Option<String>,
+    /// Run the rendered bootstrap through the configured `envoy --mode validate` first.
+    #[serde(default)]
+    pub validate: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -216,6 +277,33 @@ struct BootstrapRenderConfig {
     xds_host: String,
     xds_port: u16,
     admin_port: u16,
+    admin: BootstrapAdmin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BootstrapAdmin {
+    Localhost,
+    Socket { path: String },
+    Disabled,
+}
+
+/// Bootstrap settings the team stores; a query parameter overrides each one.
+#[derive(Debug, Clone, Copy, Default)]
+struct TeamBootstrapDefaults {
+    admin_port: Option<u16>,
+    admin_access: Option<EnvoyAdminAccess>,
+}
+
+impl TeamBootstrapDefaults {
+    async fn load(state: &AppState, team: TeamRef) -> Result<Self, DomainError> {
+        Ok(fp_storage::repos::identity::get_team(&state.pool, team.id)
+            .await?
+            .map(|team| Self {
+                admin_port: team.envoy_admin_port,
+                admin_access: team.envoy_admin_access,
+            })
+            .unwrap_or_default())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -236,9 +324,10 @@ fn default_xds_port() -> u16 {
     18000
 }
 
-fn default_admin_port() -> u16 {
-    9901
-}
+const DEFAULT_ADMIN_PORT: u16 = 9901;
+const DEFAULT_ADMIN_SOCKET_PATH: &str = "/var/run/envoy/admin.sock";
+/// `sun_path` holds 108 bytes including the terminating NUL.
+const MAX_ADMIN_SOCKET_PATH_LEN: usize = 107;
 
 #[utoipa::path(get, path = "/api/v1/teams/{team}/dataplanes",
     tag = "Dataplanes",
@@ -260,12 +349,7 @@ pub async fn list_dataplanes(
         svc::list_dataplanes(&state.pool, &ctx, team, query.limit, query.offset, rid).await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(DataplaneView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/dataplanes",
@@ -391,6 +475,284 @@ pub async fn stats_overview(
         .map_err(|e| ApiError::new(e, rid))
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectedProxyView {
+    pub stream_id: u64,
+    pub node_id: String,
+    pub team_id: uuid::Uuid,
+    /// `null` when the stream was admitted by the dev node-id resolver.
+    pub dataplane_id: Option<uuid::Uuid>,
+    pub envoy_version: Option<String>,
+    pub connected_at: chrono::DateTime<chrono::Utc>,
+    pub last_request_at: chrono::DateTime<chrono::Utc>,
+    /// Last ACKed version per xDS type URL.
+    pub acked_versions: std::collections::BTreeMap<String, String>,
+    /// Config version being served: latest, or the one a pin or rollout holds it on.
+    pub config_version: Option<String>,
+}
+
+impl From<ConnectedProxy> for ConnectedProxyView {
+    fn from(value: ConnectedProxy) -> Self {
+        Self {
+            stream_id: value.stream_id,
+            node_id: value.node_id,
+            team_id: value.team_id.as_uuid(),
+            dataplane_id: value.dataplane_id.map(|id| id.as_uuid()),
+            envoy_version: value.envoy_version,
+            connected_at: value.connected_at,
+            last_request_at: value.last_request_at,
+            acked_versions: value.acked_versions,
+            config_version: value.config_version,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectedProxiesView {
+    pub items: Vec<ConnectedProxyView>,
+}
+
+/// Live ADS streams for the team on the replica that served the request. The inventory is in
+/// memory, so behind a load balancer each replica only sees its own Envoys.
+#[utoipa::path(get, path = "/api/v1/teams/{team}/connected-proxies",
+    tag = "Dataplanes",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    responses(
+        (status = 200, body = ConnectedProxiesView),
+        (status = 401, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_connected_proxies(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ConnectedProxiesView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_connected_proxies(&state.pool, &ctx, &state.connected_proxies, team, rid).await
+    };
+    let proxies = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(ConnectedProxiesView {
+        items: proxies.into_iter().map(ConnectedProxyView::from).collect(),
+    }))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigVersionView {
+    pub config_version: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigVersionsView {
+    pub items: Vec<ConfigVersionView>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigRolloutView {
+    pub team_id: uuid::Uuid,
+    pub stable_version: String,
+    /// Share of the team's proxies (0-100) served the latest config.
+    pub canary_percent: u8,
+    pub revision: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ConfigRollout> for ConfigRolloutView {
+    fn from(value: ConfigRollout) -> Self {
+        Self {
+            team_id: value.team_id.as_uuid(),
+            stable_version: value.stable_version,
+            canary_percent: value.canary_percent,
+            revision: value.version,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PinDataplaneBody {
+    pub config_version: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigRolloutBody {
+    pub stable_version: String,
+    pub canary_percent: i64,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/config-versions",
+    tag = "Dataplanes",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    responses(
+        (status = 200, body = ConfigVersionsView),
+        (status = 401, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_config_versions(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ConfigVersionsView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_config_versions(&state.pool, &ctx, team, rid).await
+    };
+    let versions = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(ConfigVersionsView {
+        items: versions
+            .into_iter()
+            .map(|version| ConfigVersionView {
+                config_version: version.config_version,
+                created_at: version.created_at,
+            })
+            .collect(),
+    }))
+}
+
+#[utoipa::path(put, path = "/api/v1/teams/{team}/dataplanes/{name}/pin",
+    tag = "Dataplanes",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Dataplane name"),
+    ),
+    request_body = PinDataplaneBody,
+    responses(
+        (status = 200, body = DataplaneView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn pin_dataplane(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<PinDataplaneBody>,
+) -> Result<Json<DataplaneView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::pin_dataplane(&state.pool, &ctx, team, &name, &body.config_version, rid).await
+    };
+    run.await
+        .map(|dataplane| Json(DataplaneView::from(dataplane)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/dataplanes/{name}/promote",
+    tag = "Dataplanes",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Dataplane name"),
+    ),
+    responses(
+        (status = 200, body = DataplaneView),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn promote_dataplane(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<DataplaneView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::promote_dataplane(&state.pool, &ctx, team, &name, rid).await
+    };
+    run.await
+        .map(|dataplane| Json(DataplaneView::from(dataplane)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/config-rollout",
+    tag = "Dataplanes",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    responses(
+        (status = 200, body = ConfigRolloutView),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_config_rollout(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ConfigRolloutView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_config_rollout(&state.pool, &ctx, team, rid).await
+    };
+    run.await
+        .map(|rollout| Json(ConfigRolloutView::from(rollout)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(put, path = "/api/v1/teams/{team}/config-rollout",
+    tag = "Dataplanes",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = ConfigRolloutBody,
+    responses(
+        (status = 200, body = ConfigRolloutView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn set_config_rollout(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<ConfigRolloutBody>,
+) -> Result<Json<ConfigRolloutView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::set_config_rollout(
+            &state.pool,
+            &ctx,
+            team,
+            &body.stable_version,
+            body.canary_percent,
+            rid,
+        )
+        .await
+    };
+    run.await
+        .map(|rollout| Json(ConfigRolloutView::from(rollout)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/config-rollout/promote",
+    tag = "Dataplanes",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    responses(
+        (status = 200, body = ConfigRolloutView),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn promote_config_rollout(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ConfigRolloutView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::promote_config_rollout(&state.pool, &ctx, team, rid).await
+    };
+    run.await
+        .map(|rollout| Json(ConfigRolloutView::from(rollout)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
 #[utoipa::path(get, path = "/api/v1/teams/{team}/dataplanes/{name}/envoy-config",
     tag = "Dataplanes",
     params(
@@ -412,15 +774,125 @@ pub async fn get_envoy_config(
     Extension(rid): Extension<RequestId>,
 ) -> Result<Response, ApiError> {
     let run = async {
-        let bootstrap = validate_bootstrap_query(query)?;
         let team_ref = resolve_team(&state, &ctx, &team).await?;
         let dataplane = svc::get_dataplane(&state.pool, &ctx, team_ref, &name, rid).await?;
-        Ok::<_, fp_domain::DomainError>(render_envoy_bootstrap(team_ref, &dataplane, &bootstrap))
+        let team_defaults = TeamBootstrapDefaults::load(&state, team_ref).await?;
+        let envoy_validate = query.validate;
+        let bootstrap = validate_bootstrap_query(query, team_defaults)?;
+        let rendered = render_envoy_bootstrap(team_ref, &dataplane, &bootstrap);
+        if envoy_validate {
+            state
+                .envoy_validation()?
+                .validate_bootstrap_yaml(&rendered)
+                .await?;
+        }
+        Ok::<_, fp_domain::DomainError>(rendered)
     };
     let body = run.await.map_err(|e| ApiError::new(e, rid))?;
     Ok(([(header::CONTENT_TYPE, "text/yaml; charset=utf-8")], body).into_response())
 }
 
+/// A bootstrap for a throwaway Envoy that is not a registered dataplane. Nothing is stored;
+/// with `team`, the caller needs read access to the team's dataplanes.
+#[utoipa::path(get, path = "/api/v1/bootstrap",
+    tag = "Dataplanes",
+    params(StandaloneBootstrapQuery),
+    responses(
+        (status = 200, content_type = "text/yaml", body = String),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_standalone_bootstrap(
+    State(state): State<AppState>,
+    Query(query): Query<StandaloneBootstrapQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Response, ApiError> {
+    let run = async {
+        validate_node_field("node_id", &query.node_id, 256)?;
+        validate_node_field("cluster", &query.cluster, 128)?;
+        if query
+            .node_id
+            .split('/')
+            .any(|part| part.starts_with("team="))
+        {
+            return Err(
+                DomainError::validation("node_id must not carry a team= segment")
+                    .with_hint("pass the team as the team query parameter"),
+            );
+        }
+        let team = match &query.team {
+            Some(team) => {
+                let team = resolve_team(&state, &ctx, team).await?;
+                svc::check_bootstrap_access(&state.pool, &ctx, team, rid).await?;
+                Some(team)
+            }
+            None => None,
+        };
+        let team_defaults = match team {
+            Some(team) => TeamBootstrapDefaults::load(&state, team).await?,
+            None => TeamBootstrapDefaults::default(),
+        };
+        let node = match team {
+            Some(team) => {
+                let team_id = team.id.as_uuid().to_string();
+                BootstrapNode {
+                    id: format!("team={team_id}/{}", query.node_id),
+                    cluster: query.cluster.clone(),
+                    metadata: vec![("team", team_id)],
+                }
+            }
+            None => BootstrapNode {
+                id: query.node_id.clone(),
+                cluster: query.cluster.clone(),
+                metadata: Vec::new(),
+            },
+        };
+        let envoy_validate = query.validate;
+        let bootstrap = validate_bootstrap_query(
+            EnvoyConfigQuery {
+                mode: query.mode,
+                xds_host: query.xds_host,
+                xds_port: query.xds_port,
+                admin_port: query.admin_port,
+                admin_access: query.admin_access,
+                admin_socket_path: query.admin_socket_path,
+                cert_path: query.cert_path,
+                key_path: query.key_path,
+                ca_path: query.ca_path,
+                validate: envoy_validate,
+            },
+            team_defaults,
+        )?;
+        let rendered = render_bootstrap(&node, &bootstrap);
+        if envoy_validate {
+            state
+                .envoy_validation()?
+                .validate_bootstrap_yaml(&rendered)
+                .await?;
+        }
+        Ok::<_, fp_domain::DomainError>(rendered)
+    };
+    let body = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(([(header::CONTENT_TYPE, "text/yaml; charset=utf-8")], body).into_response())
+}
+
+fn validate_node_field(name: &str, value: &str, max_len: usize) -> Result<(), DomainError> {
+    if value.trim().is_empty() || value.len() > max_len {
+        return Err(DomainError::validation(format!(
+            "{name} must be 1-{max_len} characters"
+        )));
+    }
+    if value.chars().any(|c| c.is_control()) {
+        return Err(DomainError::validation(format!(
+            "{name} must not contain control characters"
+        )));
+    }
+    Ok(())
+}
+
 #[utoipa::path(get, path = "/api/v1/teams/{team}/proxy-certificates",
     tag = "Dataplanes",
     params(("team" = String, Path, description = "Team name or UUID")),
@@ -553,7 +1025,10 @@ pub async fn revoke_proxy_certificate(
         .map_err(|e| ApiError::new(e, rid))
 }
 
-fn validate_bootstrap_query(query: EnvoyConfigQuery) -> Result<BootstrapRenderConfig, DomainError> {
+fn validate_bootstrap_query(
+    query: EnvoyConfigQuery,
+    team: TeamBootstrapDefaults,
+) -> Result<BootstrapRenderConfig, DomainError> {
     let name = "xds_host";
     let value = query.xds_host.as_str();
     if value.trim().is_empty() {
@@ -572,14 +1047,47 @@ fn validate_bootstrap_query(query: EnvoyConfigQuery) -> Result<BootstrapRenderCo
             ca_path: required_bootstrap_path("ca_path", query.ca_path)?,
         },
     };
+    let admin_access = query.admin_access.or(team.admin_access).unwrap_or_default();
+    let admin = match (admin_access, query.admin_socket_path) {
+        (EnvoyAdminAccess::Socket, path) => BootstrapAdmin::Socket {
+            path: admin_socket_path(path)?,
+        },
+        (_, Some(_)) => {
+            return Err(DomainError::validation(
+                "admin_socket_path applies only with admin_access=socket",
+            ))
+        }
+        (EnvoyAdminAccess::Localhost, None) => BootstrapAdmin::Localhost,
+        (EnvoyAdminAccess::Disabled, None) => BootstrapAdmin::Disabled,
+    };
     Ok(BootstrapRenderConfig {
         mode,
         xds_host: query.xds_host,
         xds_port: query.xds_port,
-        admin_port: query.admin_port,
+        admin_port: query
+            .admin_port
+            .or(team.admin_port)
+            .unwrap_or(DEFAULT_ADMIN_PORT),
+        admin,
     })
 }
 
+fn admin_socket_path(path: Option<String>) -> Result<String, DomainError> {
+    let Some(path) = path else {
+        return Ok(DEFAULT_ADMIN_SOCKET_PATH.to_string());
+    };
+    if !path.starts_with('/')
+        || path.len() > MAX_ADMIN_SOCKET_PATH_LEN
+        || path.chars().any(|c| c.is_control())
+    {
+        return Err(DomainError::validation(format!(
+            "admin_socket_path must be an absolute path of at most \
+             {MAX_ADMIN_SOCKET_PATH_LEN} bytes without control characters"
+        )));
+    }
+    Ok(path)
+}
+
 fn required_bootstrap_path(name: &str, value: Option<String>) -> Result<String, DomainError> {
     let Some(value) = value else {
         return Err(
@@ -598,16 +1106,34 @@ fn required_bootstrap_path(name: &str, value: Option<String>) -> Result<String,
     Ok(value)
 }
 
+/// Envoy's `node` block: identity plus string metadata.
+struct BootstrapNode {
+    id: String,
+    cluster: String,
+    metadata: Vec<(&'static str, String)>,
+}
+
 fn render_envoy_bootstrap(
     team: TeamRef,
     dataplane: &Dataplane,
     query: &BootstrapRenderConfig,
 ) -> String {
     let team_id = team.id.as_uuid().to_string();
-    let node_id = format!("team={team_id}/dp-{}", dataplane.id.as_uuid());
-    let cluster = format!("{team_id}-cluster");
-    let dataplane_id = dataplane.id.as_uuid().to_string();
-    let transport_socket = match &query.mode {
+    let node = BootstrapNode {
+        id: format!("team={team_id}/dp-{}", dataplane.id.as_uuid()),
+        cluster: format!("{team_id}-cluster"),
+        metadata: vec![
+            ("team", team_id),
+            ("dataplane_id", dataplane.id.as_uuid().to_string()),
+            ("dataplane_name", dataplane.name.clone()),
+        ],
+    };
+    render_bootstrap(&node, query)
+}
+
+/// The xDS cluster's mTLS client context, or nothing for dev-mode plaintext.
+fn xds_transport_socket(mode: &BootstrapRenderMode) -> String {
+    match mode {
         BootstrapRenderMode::Dev => String::new(),
         BootstrapRenderMode::Mtls {
             cert_path,
@@ -632,21 +1158,39 @@ fn render_envoy_bootstrap(
             key_path = yaml_quote(key_path),
             ca_path = yaml_quote(ca_path),
         ),
+    }
+}
+
+fn render_bootstrap(node: &BootstrapNode, query: &BootstrapRenderConfig) -> String {
+    let metadata: String = if node.metadata.is_empty() {
+        String::new()
+    } else {
+        std::iter::once("\n  metadata:".to_string())
+            .chain(
+                node.metadata
+                    .iter()
+                    .map(|(key, value)| format!("\n    {key}: {}", yaml_quote(value))),
+            )
+            .collect()
+    };
+    // Envoy's admin API has no authentication, so it never listens on a routable address.
+    let admin = match &query.admin {
+        BootstrapAdmin::Localhost => format!(
+            "admin:\n  address:\n    socket_address:\n      address: 127.0.0.1\n      \
+             port_value: {}\n",
+            query.admin_port
+        ),
+        BootstrapAdmin::Socket { path } => format!(
+            "admin:\n  address:\n    pipe:\n      path: {}\n      mode: 384\n",
+            yaml_quote(path)
+        ),
+        BootstrapAdmin::Disabled => String::new(),
     };
     format!(
         r#"node:
   id: {node_id}
-  cluster: {cluster}
-  metadata:
-    team: {team}
-    dataplane_id: {dataplane_id}
-    dataplane_name: {dataplane_name}
-admin:
-  address:
-    socket_address:
-      address: 127.0.0.1
-      port_value: {admin_port}
-dynamic_resources:
+  cluster: {cluster}{metadata}
+{admin}dynamic_resources:
   ads_config:
     api_type: GRPC
     transport_api_version: V3
@@ -659,6 +1203,16 @@ dynamic_resources:
   lds_config:
     ads: {{}}
     resource_api_version: V3
+layered_runtime:
+  layers:
+    - name: rtds
+      rtds_layer:
+        name: {runtime_layer}
+        rtds_config:
+          ads: {{}}
+          resource_api_version: V3
+    - name: admin
+      admin_layer: {{}}
 static_resources:
   clusters:
     - name: xds_cluster
@@ -681,15 +1235,12 @@ static_resources:
                       port_value: {xds_port}
 {transport_socket}
 "#,
-        node_id = yaml_quote(&node_id),
-        cluster = yaml_quote(&cluster),
-        team = yaml_quote(&team_id),
-        dataplane_id = yaml_quote(&dataplane_id),
-        dataplane_name = yaml_quote(&dataplane.name),
-        admin_port = query.admin_port,
+        node_id = yaml_quote(&node.id),
+        runtime_layer = fp_domain::gateway::runtime::RUNTIME_LAYER_NAME,
+        cluster = yaml_quote(&node.cluster),
         xds_host = yaml_quote(&query.xds_host),
         xds_port = query.xds_port,
-        transport_socket = transport_socket.trim_end(),
+        transport_socket = xds_transport_socket(&query.mode).trim_end(),
     )
 }
 
@@ -698,7 +1249,7 @@ fn yaml_quote(value: &str) -> String {
 }
 
 #[cfg(test)]
-#[allow(clippy::panic)]
+#[allow(clippy::panic, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -709,15 +1260,21 @@ mod tests {
 
     #[test]
     fn mtls_bootstrap_validation_requires_concrete_paths() {
-        let result = validate_bootstrap_query(EnvoyConfigQuery {
-            mode: BootstrapMode::Mtls,
-            xds_host: "cp.local".into(),
-            xds_port: 18000,
-            admin_port: 9901,
-            cert_path: Some("/cert.pem".into()),
-            key_path: Some("/key.pem".into()),
-            ca_path: None,
-        });
+        let result = validate_bootstrap_query(
+            EnvoyConfigQuery {
+                mode: BootstrapMode::Mtls,
+                xds_host: "cp.local".into(),
+                xds_port: 18000,
+                admin_port: None,
+                admin_access: None,
+                admin_socket_path: None,
+                cert_path: Some("/cert.pem".into()),
+                key_path: Some("/key.pem".into()),
+                ca_path: None,
+                validate: false,
+            },
+            TeamBootstrapDefaults::default(),
+        );
         let Err(err) = result else {
             panic!("missing ca path must fail validation");
         };
@@ -727,15 +1284,21 @@ mod tests {
 
     #[test]
     fn mtls_bootstrap_validation_returns_non_optional_paths() {
-        let result = validate_bootstrap_query(EnvoyConfigQuery {
-            mode: BootstrapMode::Mtls,
-            xds_host: "cp.local".into(),
-            xds_port: 18000,
-            admin_port: 9901,
-            cert_path: Some("/cert.pem".into()),
-            key_path: Some("/key.pem".into()),
-            ca_path: Some("/ca.pem".into()),
-        });
+        let result = validate_bootstrap_query(
+            EnvoyConfigQuery {
+                mode: BootstrapMode::Mtls,
+                xds_host: "cp.local".into(),
+                xds_port: 18000,
+                admin_port: None,
+                admin_access: None,
+                admin_socket_path: None,
+                cert_path: Some("/cert.pem".into()),
+                key_path: Some("/key.pem".into()),
+                ca_path: Some("/ca.pem".into()),
+                validate: false,
+            },
+            TeamBootstrapDefaults::default(),
+        );
         let Ok(config) = result else {
             panic!("valid mTLS bootstrap must pass");
         };
@@ -749,4 +1312,100 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn admin_port_prefers_query_then_team_then_default() {
+        let query = |admin_port| EnvoyConfigQuery {
+            mode: BootstrapMode::Dev,
+            xds_host: "cp.local".into(),
+            xds_port: 18000,
+            admin_port,
+            admin_access: None,
+            admin_socket_path: None,
+            cert_path: None,
+            key_path: None,
+            ca_path: None,
+            validate: false,
+        };
+        let port = |admin_port, team_port| {
+            let team = TeamBootstrapDefaults {
+                admin_port: team_port,
+                admin_access: None,
+            };
+            validate_bootstrap_query(query(admin_port), team)
+                .map(|config| config.admin_port)
+                .ok()
+        };
+        assert_eq!(port(Some(9950), Some(9902)), Some(9950));
+        assert_eq!(port(None, Some(9902)), Some(9902));
+        assert_eq!(port(None, None), Some(DEFAULT_ADMIN_PORT));
+    }
+
+    #[test]
+    fn admin_access_prefers_query_then_team_and_never_binds_a_routable_address() {
+        let query = |admin_access, admin_socket_path: Option<&str>| EnvoyConfigQuery {
+            mode: BootstrapMode::Dev,
+            xds_host: "cp.local".into(),
+            xds_port: 18000,
+            admin_port: None,
+            admin_access,
+            admin_socket_path: admin_socket_path.map(Into::into),
+            cert_path: None,
+            key_path: None,
+            ca_path: None,
+            validate: false,
+        };
+        let team = |admin_access| TeamBootstrapDefaults {
+            admin_port: None,
+            admin_access,
+        };
+        let render = |query, team| {
+            let config = validate_bootstrap_query(query, team).expect("valid bootstrap");
+            let node = BootstrapNode {
+                id: "n".into(),
+                cluster: "c".into(),
+                metadata: Vec::new(),
+            };
+            render_bootstrap(&node, &config)
+        };
+
+        let localhost = render(query(None, None), team(None));
+        assert!(localhost.contains("address: 127.0.0.1\n      port_value: 9901"));
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&localhost).expect("yaml");
+        assert_eq!(
+            yaml["admin"]["address"]["socket_address"]["port_value"],
+            9901
+        );
+
+        let socket = render(query(None, None), team(Some(EnvoyAdminAccess::Socket)));
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&socket).expect("yaml");
+        assert_eq!(
+            yaml["admin"]["address"]["pipe"]["path"].as_str(),
+            Some(DEFAULT_ADMIN_SOCKET_PATH)
+        );
+        assert_eq!(yaml["admin"]["address"]["pipe"]["mode"], 0o600);
+
+        let disabled = render(
+            query(Some(EnvoyAdminAccess::Disabled), None),
+            team(Some(EnvoyAdminAccess::Socket)),
+        );
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&disabled).expect("yaml");
+        assert!(yaml["admin"].is_null(), "the query overrides the team");
+        assert!(yaml["dynamic_resources"].is_mapping());
+        assert_eq!(
+            yaml["layered_runtime"]["layers"][0]["rtds_layer"]["name"],
+            fp_domain::gateway::runtime::RUNTIME_LAYER_NAME,
+            "the bootstrap subscribes to the layer the control plane serves"
+        );
+
+        for (access, path) in [
+            (Some(EnvoyAdminAccess::Localhost), Some("/run/envoy.sock")),
+            (Some(EnvoyAdminAccess::Socket), Some("relative.sock")),
+        ] {
+            assert!(
+                validate_bootstrap_query(query(access, path), team(None)).is_err(),
+                "{access:?} {path:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,211 @@
+//! Admin diagnostics bundle: one JSON document to attach to a support ticket. The tenant
+//! side (counts, connections, NACKs, pool stats) comes from
+//! `fp_core::services::diagnostics`; the effective config is this process's [`AppState`],
+//! reduced to switches and thresholds — no URLs, keys, or tokens.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::state::AppState;
+use axum::extract::{Extension, State};
+use axum::Json;
+use fp_core::PrincipalCtx;
+use fp_domain::RequestId;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct DiagnosticsView {
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub config: EffectiveConfigView,
+    /// Rows per resource kind across every org and team.
+    pub resource_counts: BTreeMap<String, i64>,
+    /// Known dataplanes, most recently heard from first.
+    pub connections: Vec<ConnectionView>,
+    /// Newest NACKs across every team; error text is withheld (it can echo tenant config).
+    pub recent_nacks: Vec<NackSummaryView>,
+    pub database: DatabaseView,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct EffectiveConfigView {
+    pub version: String,
+    pub auth_configured: bool,
+    pub rls_sync_configured: bool,
+    pub rls_grpc_configured: bool,
+    pub debug_team_header: bool,
+    /// `None` when the xDS outbox consumer is not wired (API-only deployments).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xds: Option<XdsConfigView>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct XdsConfigView {
+    pub consumer: String,
+    pub max_lag: i64,
+    /// `None` when the lag query itself failed.
+    pub outbox_lag: Option<i64>,
+    pub snapshot_cache_primed: bool,
+    pub consumer_failed: bool,
+    pub server_enabled: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ConnectionView {
+    pub dataplane_id: uuid::Uuid,
+    pub team_id: uuid::Uuid,
+    pub live: bool,
+    pub version: i64,
+    pub last_heartbeat_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_config_verify_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub warming_failures: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct NackSummaryView {
+    pub id: uuid::Uuid,
+    pub team_id: uuid::Uuid,
+    pub type_url: String,
+    pub version_rejected: String,
+    pub quarantined_count: usize,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DatabaseView {
+    pub reachable: bool,
+    pub pool_size: u32,
+    pub pool_idle: usize,
+    pub pool_max: u32,
+}
+
+/// One-shot, redacted support bundle: effective config, resource counts, xDS connections,
+/// recent NACKs, and database pool stats. Platform admin only.
+#[utoipa::path(get, path = "/api/v1/admin/diagnostics", tag = "Ops",
+    responses(
+        (status = 200, body = DiagnosticsView),
+        (status = 401, body = ErrorBody),
+        (status = 403, body = ErrorBody)
+    ))]
+pub async fn diagnostics(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<DiagnosticsView>, ApiError> {
+    let bundle = fp_core::services::diagnostics::diagnostics(&state.pool, &ctx, rid)
+        .await
+        .map_err(|e| ApiError::new(e, rid))?;
+    let xds = match &state.xds_readiness {
+        Some(xds) => Some(XdsConfigView {
+            consumer: xds.consumer.to_string(),
+            max_lag: xds.max_lag,
+            outbox_lag: fp_storage::outbox::consumer_lag(&state.pool, xds.consumer)
+                .await
+                .ok(),
+            snapshot_cache_primed: xds.primed.load(Ordering::SeqCst),
+            consumer_failed: xds.failed.load(Ordering::SeqCst),
+            server_enabled: xds.server_addr.is_some(),
+        }),
+        None => None,
+    };
+    Ok(Json(DiagnosticsView {
+        generated_at: chrono::Utc::now(),
+        config: EffectiveConfigView {
+            version: state.version.to_string(),
+            auth_configured: state.validator.is_some(),
+            rls_sync_configured: state.rls_repush.is_some(),
+            rls_grpc_configured: state.rls_grpc_configured,
+            debug_team_header: state.debug_team_header,
+            xds,
+        },
+        resource_counts: bundle
+            .resource_counts
+            .into_iter()
+            .map(|(kind, n)| (kind.to_string(), n))
+            .collect(),
+        connections: bundle
+            .connections
+            .into_iter()
+            .map(|c| ConnectionView {
+                dataplane_id: c.dataplane_id.as_uuid(),
+                team_id: c.team_id.as_uuid(),
+                live: c.live,
+                version: c.version,
+                last_heartbeat_at: c.last_heartbeat_at,
+                last_config_verify_at: c.last_config_verify_at,
+                warming_failures: c.warming_failures,
+            })
+            .collect(),
+        recent_nacks: bundle
+            .recent_nacks
+            .into_iter()
+            .map(|n| NackSummaryView {
+                id: n.id,
+                team_id: n.team_id.as_uuid(),
+                type_url: n.type_url,
+                version_rejected: n.version_rejected,
+                quarantined_count: n.quarantined_count,
+                created_at: n.created_at,
+            })
+            .collect(),
+        database: DatabaseView {
+            reachable: bundle.database.reachable,
+            pool_size: bundle.database.pool_size,
+            pool_idle: bundle.database.pool_idle,
+            pool_max: bundle.database.pool_max,
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct WebhookDeadLetterView {
+    pub id: uuid::Uuid,
+    pub team_id: uuid::Uuid,
+    /// The team webhook that gave up; absent for the secret-expiry webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<uuid::Uuid>,
+    pub event: String,
+    pub attempts: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_status: Option<i32>,
+    pub last_error: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct WebhookDeadLettersView {
+    pub items: Vec<WebhookDeadLetterView>,
+}
+
+/// Webhook notices that failed every retry, newest first. Ids and delivery status only; the
+/// stored payload is not returned. Platform admin only.
+#[utoipa::path(get, path = "/api/v1/admin/webhook-dead-letters", tag = "Ops",
+    responses(
+        (status = 200, body = WebhookDeadLettersView),
+        (status = 401, body = ErrorBody),
+        (status = 403, body = ErrorBody)
+    ))]
+pub async fn webhook_dead_letters(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<WebhookDeadLettersView>, ApiError> {
+    let letters = fp_core::services::webhooks::list_dead_letters(&state.pool, &ctx, rid)
+        .await
+        .map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(WebhookDeadLettersView {
+        items: letters
+            .into_iter()
+            .map(|l| WebhookDeadLetterView {
+                id: l.id,
+                team_id: l.team_id.as_uuid(),
+                subscription_id: l.subscription_id.map(|id| id.as_uuid()),
+                event: l.event,
+                attempts: l.attempts,
+                last_status: l.last_status,
+                last_error: l.last_error,
+                created_at: l.created_at,
+            })
+            .collect(),
+    }))
+}
@@ -182,12 +182,7 @@ pub async fn list_discovery_sessions(
         .await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(DiscoverySessionView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/learning-discovery-sessions",
@@ -1,4 +1,9 @@
 //! HTTP rendering of the domain error taxonomy (spec/10 §8).
+//!
+//! The envelope is the default. A caller whose `Accept` names `application/problem+json` gets
+//! the same error as RFC 7807 problem details instead (see
+//! [`crate::middleware::problem_responses`]), with the envelope fields carried as extension
+//! members so nothing is lost.
 
 use axum::http::{header, HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
@@ -8,7 +13,7 @@ use serde::Serialize;
 
 /// The wire envelope. Stable contract: agents branch on `code`, humans read `message`/`hint`,
 /// operators grep `request_id`.
-#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct ErrorBody {
     pub code: &'static str,
     pub message: String,
@@ -19,6 +24,43 @@ pub struct ErrorBody {
     pub request_id: String,
 }
 
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// RFC 7807 problem details for one error. `type` is a stable URN per `code`, `title` the
+/// status's reason phrase, `detail` the envelope `message`, and `instance` the request path.
+#[derive(Debug, Serialize)]
+pub struct ProblemBody {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: &'static str,
+    pub status: u16,
+    pub detail: String,
+    pub instance: String,
+    #[serde(flatten)]
+    pub envelope: ErrorBody,
+}
+
+/// The envelope an [`ApiError`] rendered, left on the response so a caller that asked for
+/// problem details can be answered without re-parsing the body.
+#[derive(Debug, Clone)]
+pub(crate) struct RenderedError {
+    pub status: StatusCode,
+    pub body: ErrorBody,
+}
+
+impl RenderedError {
+    pub(crate) fn to_problem(&self, instance: &str) -> ProblemBody {
+        ProblemBody {
+            problem_type: format!("urn:flowplane:error:{}", self.body.code),
+            title: self.status.canonical_reason().unwrap_or("Error"),
+            status: self.status.as_u16(),
+            detail: self.body.message.clone(),
+            instance: instance.to_string(),
+            envelope: self.body.clone(),
+        }
+    }
+}
+
 /// A [`DomainError`] paired with the request id assigned by middleware.
 #[derive(Debug)]
 pub struct ApiError {
@@ -43,6 +85,7 @@ impl ApiError {
             ErrorCode::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
             ErrorCode::InvalidConfig | ErrorCode::Internal => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorCode::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorCode::NotConfigured => StatusCode::NOT_IMPLEMENTED,
         }
     }
 }
@@ -80,7 +123,12 @@ impl IntoResponse for ApiError {
             request_id: self.request_id.to_string(),
         };
 
+        let rendered = RenderedError {
+            status,
+            body: body.clone(),
+        };
         let mut response = (status, Json(body)).into_response();
+        response.extensions_mut().insert(rendered);
         if let Some(seconds) = self.error.retry_after_seconds {
             if let Ok(value) = HeaderValue::from_str(&seconds.to_string()) {
                 response.headers_mut().insert(header::RETRY_AFTER, value);
@@ -130,6 +178,31 @@ mod tests {
         assert!(json.get("details").is_none());
     }
 
+    #[test]
+    fn problem_details_carry_rfc7807_members_and_the_envelope() {
+        let rid = RequestId::generate();
+        let response = ApiError::new(
+            DomainError::new(ErrorCode::RevisionMismatch, "cluster changed").with_hint("re-read"),
+            rid,
+        )
+        .into_response();
+        let rendered = response
+            .extensions()
+            .get::<RenderedError>()
+            .expect("rendered error on the response");
+        let problem =
+            serde_json::to_value(rendered.to_problem("/api/v1/teams/a/clusters/b")).unwrap();
+        assert_eq!(problem["type"], "urn:flowplane:error:revision_mismatch");
+        assert_eq!(problem["title"], "Conflict");
+        assert_eq!(problem["status"], 409);
+        assert_eq!(problem["detail"], "cluster changed");
+        assert_eq!(problem["instance"], "/api/v1/teams/a/clusters/b");
+        assert_eq!(problem["code"], "revision_mismatch");
+        assert_eq!(problem["message"], "cluster changed");
+        assert_eq!(problem["hint"], "re-read");
+        assert_eq!(problem["request_id"], rid.to_string());
+    }
+
     #[test]
     fn rate_limited_carries_retry_after_header() {
         let rid = RequestId::generate();
@@ -147,4 +220,31 @@ mod tests {
             Some("30")
         );
     }
+
+    #[tokio::test]
+    async fn not_configured_is_501_with_its_message_and_hint() {
+        let rid = RequestId::generate();
+        let err = ApiError::new(
+            DomainError::not_configured("rate-limit sync", "FLOWPLANE_RLS_ADMIN_URL"),
+            rid,
+        );
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+        assert!(response.headers().get(header::RETRY_AFTER).is_none());
+        let body = response
+            .into_body()
+            .collect()
+            .await
+            .expect("body")
+            .to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json body");
+        assert_eq!(json["code"], "not_configured");
+        assert_eq!(
+            json["message"],
+            "rate-limit sync is not configured on this server"
+        );
+        assert!(json["hint"]
+            .as_str()
+            .is_some_and(|hint| hint.contains("FLOWPLANE_RLS_ADMIN_URL")));
+    }
 }
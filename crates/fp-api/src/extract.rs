@@ -1,7 +1,9 @@
 //! Request extractors that keep failures on the standard error envelope.
 
+use axum::body::Bytes;
 use axum::extract::rejection::JsonRejection;
 use axum::extract::{FromRequest, Request};
+use axum::http::{header, HeaderMap, StatusCode};
 use fp_domain::{DomainError, RequestId};
 
 use crate::error::ApiError;
@@ -11,6 +13,10 @@ use crate::error::ApiError;
 /// bare `422` plain-text [`JsonRejection`]. Use this for every REST request body
 /// so the documented contract ("every failure is the envelope; status derived
 /// from `code`") holds on the malformed-JSON path too.
+///
+/// A body sent as `Content-Type: application/yaml` (or `application/x-yaml`, `text/yaml`) is
+/// read with `serde_yaml` into the same type, so operators can post the YAML they keep in
+/// git; validation after deserialization is identical.
 pub struct ApiJson<T>(pub T);
 
 impl<T, S> FromRequest<S> for ApiJson<T>
@@ -29,8 +35,30 @@ where
             .copied()
             .unwrap_or_else(RequestId::generate);
 
+        if is_yaml(req.headers(), header::CONTENT_TYPE) {
+            let bytes = Bytes::from_request(req, state).await.map_err(|rejection| {
+                let error = if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                    crate::middleware::body_too_large(None, None)
+                } else {
+                    DomainError::validation(rejection.body_text())
+                };
+                ApiError::new(error, rid)
+            })?;
+            return serde_yaml::from_slice(&bytes).map(ApiJson).map_err(|e| {
+                ApiError::new(
+                    DomainError::validation(format!(
+                        "Failed to deserialize the YAML body into the target type: {e}"
+                    )),
+                    rid,
+                )
+            });
+        }
+
         match axum::Json::<T>::from_request(req, state).await {
             Ok(axum::Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE => Err(
+                ApiError::new(crate::middleware::body_too_large(None, None), rid),
+            ),
             Err(rejection) => Err(ApiError::new(
                 DomainError::validation(rejection_message(&rejection)),
                 rid,
@@ -46,3 +74,51 @@ where
 fn rejection_message(rejection: &JsonRejection) -> String {
     rejection.body_text()
 }
+
+/// Whether `name` (`Content-Type`, or `Accept` for responses) asks for YAML: its first JSON
+/// or YAML media type is a YAML one. Parameters and q-values are ignored.
+pub(crate) fn is_yaml(headers: &HeaderMap, name: header::HeaderName) -> bool {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|media| media.split(';').next().unwrap_or_default().trim())
+        .find_map(|media| {
+            if media.eq_ignore_ascii_case("application/json") {
+                Some(false)
+            } else if ["application/yaml", "application/x-yaml", "text/yaml"]
+                .iter()
+                .any(|yaml| media.eq_ignore_ascii_case(yaml))
+            {
+                Some(true)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_yaml;
+    use axum::http::{header, HeaderMap, HeaderValue};
+
+    fn accept(value: &'static str) -> bool {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static(value));
+        is_yaml(&headers, header::ACCEPT)
+    }
+
+    #[test]
+    fn the_first_json_or_yaml_media_type_decides() {
+        assert!(accept("application/yaml"));
+        assert!(accept(
+            "text/html, application/x-yaml;q=0.9, application/json"
+        ));
+        assert!(accept("TEXT/YAML; charset=utf-8"));
+        assert!(!accept("application/json, application/yaml"));
+        assert!(!accept("*/*"));
+        assert!(!is_yaml(&HeaderMap::new(), header::ACCEPT));
+    }
+}
@@ -0,0 +1,197 @@
+//! Filter preset endpoints. A listener chain entry `{"type": "preset", "name": ...}` is
+//! replaced on write by a copy of the preset, whose body is any single chain `filter`.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::resources::{resolve_team, revision_from, ListQuery, Page};
+use crate::state::AppState;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use fp_core::services::filter_presets as svc;
+use fp_core::PrincipalCtx;
+use fp_domain::gateway::filters::HttpFilterSpec;
+use fp_domain::gateway::FilterPreset;
+use fp_domain::RequestId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FilterPresetView {
+    /// Stable identifier (UUID).
+    pub id: uuid::Uuid,
+    /// Referenced as `{"type": "preset", "name": ...}` in a listener chain.
+    pub name: String,
+    pub spec: HttpFilterSpec,
+    /// Optimistic-concurrency revision; echo via If-Match on update/delete.
+    pub revision: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<FilterPreset> for FilterPresetView {
+    fn from(value: FilterPreset) -> Self {
+        Self {
+            id: value.id.as_uuid(),
+            name: value.name,
+            spec: value.spec,
+            revision: value.version,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateFilterPresetBody {
+    pub name: String,
+    pub spec: HttpFilterSpec,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateFilterPresetBody {
+    pub spec: HttpFilterSpec,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/filter-presets",
+    tag = "Filter presets",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, content(
+            (Page<FilterPresetView> = "application/json"),
+            (Page<FilterPresetView> = "application/yaml"),
+        )),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_filter_presets(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<FilterPresetView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_filter_presets(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/filter-presets",
+    tag = "Filter presets",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = CreateFilterPresetBody,
+    responses(
+        (status = 201, body = FilterPresetView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn create_filter_preset(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<CreateFilterPresetBody>,
+) -> Result<(StatusCode, Json<FilterPresetView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::create_filter_preset(&state.pool, &ctx, team, &body.name, body.spec, rid).await
+    };
+    let preset = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok((StatusCode::CREATED, Json(FilterPresetView::from(preset))))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/filter-presets/{name}",
+    tag = "Filter presets",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Filter preset name"),
+    ),
+    responses(
+        (status = 200, content(
+            (FilterPresetView = "application/json"),
+            (FilterPresetView = "application/yaml"),
+        )),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_filter_preset(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<FilterPresetView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_filter_preset(&state.pool, &ctx, team, &name, rid).await
+    };
+    run.await
+        .map(|preset| Json(FilterPresetView::from(preset)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(patch, path = "/api/v1/teams/{team}/filter-presets/{name}",
+    tag = "Filter presets",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Filter preset name"),
+        ("If-Match" = i64, Header, description = "Current resource revision"),
+    ),
+    request_body = UpdateFilterPresetBody,
+    responses(
+        (status = 200, body = FilterPresetView),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn update_filter_preset(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    headers: HeaderMap,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<UpdateFilterPresetBody>,
+) -> Result<Json<FilterPresetView>, ApiError> {
+    let run = async {
+        let revision = revision_from(&headers)?;
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::update_filter_preset(&state.pool, &ctx, team, &name, body.spec, revision, rid).await
+    };
+    run.await
+        .map(|preset| Json(FilterPresetView::from(preset)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/filter-presets/{name}",
+    tag = "Filter presets",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Filter preset name"),
+        ("If-Match" = i64, Header, description = "Current resource revision"),
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn delete_filter_preset(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    headers: HeaderMap,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<StatusCode, ApiError> {
+    let run = async {
+        let revision = revision_from(&headers)?;
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::delete_filter_preset(&state.pool, &ctx, team, &name, revision, rid).await
+    };
+    run.await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| ApiError::new(e, rid))
+}
@@ -21,6 +21,24 @@ pub struct TeamView {
     pub id: uuid::Uuid,
     pub name: String,
     pub display_name: String,
+    pub description: String,
+    /// Admin port the team's Envoy bootstraps use unless `admin_port` is passed explicitly.
+    pub envoy_admin_port: Option<u16>,
+    /// Admin interface exposure the team's bootstraps use unless `admin_access` is passed.
+    pub envoy_admin_access: Option<fp_domain::EnvoyAdminAccess>,
+}
+
+impl From<fp_domain::Team> for TeamView {
+    fn from(team: fp_domain::Team) -> Self {
+        Self {
+            id: team.id.as_uuid(),
+            name: team.name,
+            display_name: team.display_name,
+            description: team.description,
+            envoy_admin_port: team.envoy_admin_port,
+            envoy_admin_access: team.envoy_admin_access,
+        }
+    }
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -29,6 +47,26 @@ pub struct CreateTeamBody {
     pub name: String,
     #[serde(default)]
     pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub envoy_admin_port: Option<u16>,
+    #[serde(default)]
+    pub envoy_admin_access: Option<fp_domain::EnvoyAdminAccess>,
+}
+
+/// Full replacement of a team's editable fields; an omitted field is reset to its default.
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateTeamBody {
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub envoy_admin_port: Option<u16>,
+    #[serde(default)]
+    pub envoy_admin_access: Option<fp_domain::EnvoyAdminAccess>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -134,16 +172,7 @@ pub async fn list_teams(
     let teams = svc::list_teams(&state.pool, &ctx)
         .await
         .map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(
-        teams
-            .into_iter()
-            .map(|t| TeamView {
-                id: t.id.as_uuid(),
-                name: t.name,
-                display_name: t.display_name,
-            })
-            .collect(),
-    ))
+    Ok(Json(teams.into_iter().map(TeamView::from).collect()))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams", tag = "Teams",
@@ -156,17 +185,62 @@ pub async fn create_team(
     Extension(rid): Extension<RequestId>,
     ApiJson(body): ApiJson<CreateTeamBody>,
 ) -> Result<(StatusCode, Json<TeamView>), ApiError> {
-    let team = svc::create_team(&state.pool, &ctx, &body.name, &body.display_name, rid)
+    let profile = fp_domain::TeamProfile {
+        display_name: body.display_name,
+        description: body.description,
+        envoy_admin_port: body.envoy_admin_port,
+        envoy_admin_access: body.envoy_admin_access,
+    };
+    let team = svc::create_team(&state.pool, &ctx, &body.name, &profile, rid)
         .await
         .map_err(|e| ApiError::new(e, rid))?;
-    Ok((
-        StatusCode::CREATED,
-        Json(TeamView {
-            id: team.id.as_uuid(),
-            name: team.name,
-            display_name: team.display_name,
-        }),
-    ))
+    Ok((StatusCode::CREATED, Json(TeamView::from(team))))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}", tag = "Teams",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    responses((status = 200, body = TeamView), (status = 404, body = ErrorBody)))]
+pub async fn get_team(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<TeamView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_team(&state.pool, &ctx, team).await
+    };
+    run.await
+        .map(|team| Json(TeamView::from(team)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(put, path = "/api/v1/teams/{team}", tag = "Teams",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = UpdateTeamBody,
+    responses((status = 200, body = TeamView), (status = 400, body = ErrorBody),
+              (status = 403, body = ErrorBody), (status = 404, body = ErrorBody),
+              (status = 409, body = ErrorBody)))]
+pub async fn update_team(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<UpdateTeamBody>,
+) -> Result<Json<TeamView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        let profile = fp_domain::TeamProfile {
+            display_name: body.display_name,
+            description: body.description,
+            envoy_admin_port: body.envoy_admin_port,
+            envoy_admin_access: body.envoy_admin_access,
+        };
+        svc::update_team(&state.pool, &ctx, team, &profile, rid).await
+    };
+    run.await
+        .map(|team| Json(TeamView::from(team)))
+        .map_err(|e| ApiError::new(e, rid))
 }
 
 #[utoipa::path(delete, path = "/api/v1/teams/{team}", tag = "Teams",
@@ -187,6 +261,86 @@ pub async fn delete_team(
         .map_err(|e| ApiError::new(e, rid))
 }
 
+/// Replacement set of per-team quota overrides: resource kind → limit. Kinds left out use
+/// the built-in default.
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TeamQuotasBody {
+    pub limits: std::collections::BTreeMap<String, i64>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct TeamQuotaView {
+    /// Resource kind, e.g. "clusters".
+    pub resource: String,
+    /// Effective limit: the override when set, else the default.
+    pub limit: i64,
+    pub default_limit: i64,
+    pub used: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct TeamQuotasView {
+    pub items: Vec<TeamQuotaView>,
+}
+
+impl From<Vec<fp_core::services::quota::TeamQuota>> for TeamQuotasView {
+    fn from(quotas: Vec<fp_core::services::quota::TeamQuota>) -> Self {
+        Self {
+            items: quotas
+                .into_iter()
+                .map(|q| TeamQuotaView {
+                    resource: q.resource.as_str().into(),
+                    limit: q.limit,
+                    default_limit: q.default_limit,
+                    used: q.used,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/quotas", tag = "Teams",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    responses((status = 200, body = TeamQuotasView), (status = 404, body = ErrorBody)))]
+pub async fn get_quotas(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<TeamQuotasView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        fp_core::services::quota::get_team_quotas(&state.pool, &ctx, team).await
+    };
+    run.await
+        .map(|quotas| Json(quotas.into()))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+/// Replace a team's quota overrides (platform admins; address a team outside your own org by
+/// UUID).
+#[utoipa::path(put, path = "/api/v1/teams/{team}/quotas", tag = "Teams",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = TeamQuotasBody,
+    responses((status = 200, body = TeamQuotasView), (status = 400, body = ErrorBody),
+              (status = 403, body = ErrorBody), (status = 404, body = ErrorBody)))]
+pub async fn set_quotas(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<TeamQuotasBody>,
+) -> Result<Json<TeamQuotasView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        fp_core::services::quota::set_team_quotas(&state.pool, &ctx, team, &body.limits, rid).await
+    };
+    run.await
+        .map(|quotas| Json(quotas.into()))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
 #[utoipa::path(get, path = "/api/v1/teams/{team}/members", tag = "Teams",
     params(("team" = String, Path, description = "Team name or UUID")),
     responses((status = 200, body = [MemberView]), (status = 404, body = ErrorBody)))]
@@ -37,6 +37,14 @@ pub struct LearningSessionView {
     pub max_duration_seconds: Option<i32>,
     pub max_bytes: i64,
     pub max_distinct_paths: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_percent: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_body_bytes: Option<i32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub redact_headers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub redact_body_fields: Vec<String>,
     pub sample_count: i64,
     pub byte_count: i64,
     pub path_count: i64,
@@ -65,6 +73,10 @@ impl From<CaptureSession> for LearningSessionView {
             max_duration_seconds: value.max_duration_seconds,
             max_bytes: value.max_bytes,
             max_distinct_paths: value.max_distinct_paths,
+            sample_percent: value.sample_percent,
+            max_body_bytes: value.max_body_bytes,
+            redact_headers: value.redact_headers,
+            redact_body_fields: value.redact_body_fields,
             sample_count: value.sample_count,
             byte_count: value.byte_count,
             path_count: value.path_count,
@@ -127,6 +139,14 @@ pub struct StartLearningSessionBody {
     pub max_bytes: i64,
     #[serde(default = "default_max_distinct_paths")]
     pub max_distinct_paths: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_percent: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_body_bytes: Option<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redact_headers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redact_body_fields: Vec<String>,
 }
 
 fn default_target_sample_count() -> i32 {
@@ -156,6 +176,10 @@ impl StartLearningSessionBody {
                 max_duration_seconds: self.max_duration_seconds,
                 max_bytes: self.max_bytes,
                 max_distinct_paths: self.max_distinct_paths,
+                sample_percent: self.sample_percent,
+                max_body_bytes: self.max_body_bytes,
+                redact_headers: self.redact_headers,
+                redact_body_fields: self.redact_body_fields,
             },
         }
     }
@@ -212,12 +236,7 @@ pub async fn list_learning_sessions(
         .await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(LearningSessionView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/learning-sessions",
@@ -328,6 +347,31 @@ pub async fn create_learned_spec_version(
     ))
 }
 
+#[utoipa::path(get, path = "/api/v1/teams/{team}/learning-sessions/{session}/openapi",
+    tag = "LearningSessions",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("session" = String, Path, description = "Capturing or completed learning session name or UUID"),
+    ),
+    responses(
+        (status = 200, description = "OpenAPI 3.1 document inferred from the session's observations", body = Object),
+        (status = 400, body = crate::error::ErrorBody),
+        (status = 404, body = crate::error::ErrorBody),
+        (status = 409, body = crate::error::ErrorBody),
+    ))]
+pub async fn export_learned_openapi(
+    State(state): State<AppState>,
+    Path((team, session)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::learned_openapi(&state.pool, &ctx, team, &session, rid).await
+    };
+    run.await.map(Json).map_err(|e| ApiError::new(e, rid))
+}
+
 #[utoipa::path(delete, path = "/api/v1/teams/{team}/learning-sessions/{session}",
     tag = "LearningSessions",
     params(
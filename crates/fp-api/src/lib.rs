@@ -7,23 +7,33 @@
 pub mod ai_api;
 pub mod api_lifecycle_api;
 pub mod auth;
+pub mod build_info;
 pub mod dataplanes_api;
+pub mod diagnostics_api;
 pub mod discovery_api;
 pub mod error;
 pub mod expose_api;
 pub mod extract;
+pub mod filter_presets_api;
 pub mod identity_api;
 pub mod learning_api;
 pub mod mcp_api;
 pub mod middleware;
 pub mod orgs_api;
+pub mod proto_descriptors_api;
 pub mod rate_limit_api;
+pub mod read_cache;
 pub mod resources;
+pub mod retry_policies_api;
 pub mod route_generation_api;
 pub mod routes;
+pub mod runtime_api;
 pub mod secrets_api;
 pub mod state;
 pub mod throttle;
+pub mod validate_api;
+pub mod wasm_modules_api;
+pub mod webhooks_api;
 pub mod xds_api;
 
 pub use error::ApiError;
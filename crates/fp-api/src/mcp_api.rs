@@ -977,6 +977,8 @@ async fn execute_static_tool(
                 &state.pool,
                 ctx,
                 team,
+                None,
+                Default::default(),
                 integer_arg(&arguments, "limit").unwrap_or(50),
                 integer_arg(&arguments, "offset").unwrap_or(0),
                 rid,
@@ -1047,6 +1049,8 @@ async fn execute_static_tool(
                 &state.pool,
                 ctx,
                 team,
+                None,
+                Default::default(),
                 integer_arg(&arguments, "limit").unwrap_or(50),
                 integer_arg(&arguments, "offset").unwrap_or(0),
                 rid,
@@ -1117,6 +1121,8 @@ async fn execute_static_tool(
                 &state.pool,
                 ctx,
                 team,
+                None,
+                Default::default(),
                 integer_arg(&arguments, "limit").unwrap_or(50),
                 integer_arg(&arguments, "offset").unwrap_or(0),
                 rid,
@@ -2699,6 +2705,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let binding = ApiRouteBinding {
             id: fp_domain::ApiRouteBindingId::generate(),
@@ -2767,6 +2778,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let binding = ApiRouteBinding {
             id: fp_domain::ApiRouteBindingId::generate(),
@@ -2845,11 +2861,18 @@ mod tests {
             version: "test",
             validator: None,
             write_throttle: std::sync::Arc::new(crate::throttle::WriteThrottle::new(1000)),
+            request_rate_limit: std::sync::Arc::new(crate::throttle::RequestRateLimit::disabled()),
+            max_body_bytes: crate::state::DEFAULT_MAX_BODY_BYTES,
             xds_readiness: None,
             discovery_forwarding_policy: Default::default(),
             egress_advisory: Default::default(),
             rls_repush: None,
             rls_grpc_configured: false,
+            debug_team_header: false,
+            connected_proxies: Default::default(),
+            envoy_validator: None,
+            read_cache: Default::default(),
+            xds_snapshots: None,
         }
     }
 
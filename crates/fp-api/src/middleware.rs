@@ -5,12 +5,26 @@
 //! response header, and recorded on the request's tracing span so one id links error body,
 //! log lines, and trace. Inbound W3C `traceparent` context is honored, so Flowplane spans
 //! join the caller's distributed trace.
+//!
+//! The same layer writes the structured access log: one `request completed` event per request
+//! carrying method, matched route template, team path segment, authenticated principal, status,
+//! and latency, so a user action can be followed from the CLI through the logging stack.
+//!
+//! [`yaml_responses`] re-renders JSON API responses as YAML for callers whose `Accept` asks
+//! for it; everything else is served as the handlers produced it.
+//!
+//! [`problem_responses`] re-renders error envelopes as RFC 7807 problem details for callers
+//! whose `Accept` names `application/problem+json`.
+//!
+//! [`limit_request_body`] refuses a request whose declared `Content-Length` is over the
+//! configured maximum before any of it is read.
 
-use axum::extract::Request;
-use axum::http::{HeaderMap, HeaderValue};
+use axum::body::Body;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::{header, HeaderMap, HeaderValue};
 use axum::middleware::Next;
-use axum::response::Response;
-use fp_domain::RequestId;
+use axum::response::{IntoResponse, Response};
+use fp_domain::{DomainError, ErrorCode, RequestId};
 use opentelemetry::propagation::Extractor;
 use std::str::FromStr;
 use tracing::Instrument;
@@ -18,6 +32,29 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
+/// The authenticated caller, handed back on the response by the auth layer (which runs inside
+/// this one) so the access log can name who made the request. Agents authenticate with their
+/// token, so the agent id is the token's identity.
+#[derive(Debug, Clone, Copy)]
+pub struct AccessLogPrincipal {
+    pub kind: &'static str,
+    pub id: uuid::Uuid,
+}
+
+impl std::fmt::Display for AccessLogPrincipal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.kind, self.id)
+    }
+}
+
+/// The raw `{team}` segment of a team-scoped path (name or UUID, as the caller sent it).
+pub(crate) fn team_segment(path: &str) -> Option<&str> {
+    path.strip_prefix("/api/v1/teams/")?
+        .split('/')
+        .next()
+        .filter(|segment| !segment.is_empty())
+}
+
 struct HeaderMapExtractor<'a>(&'a HeaderMap);
 
 impl Extractor for HeaderMapExtractor<'_> {
@@ -40,11 +77,25 @@ pub async fn request_id(mut request: Request, next: Next) -> Response {
 
     request.extensions_mut().insert(rid);
 
+    let method = request.method().clone();
+    // Route template, not the concrete path: bounded cardinality, and it names the resource
+    // kind without echoing caller-supplied identifiers twice.
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_owned())
+        .unwrap_or_default();
+    let team = team_segment(request.uri().path())
+        .unwrap_or_default()
+        .to_owned();
     let span = tracing::info_span!(
         "http_request",
         request_id = %rid,
-        method = %request.method(),
+        method = %method,
         path = %request.uri().path(),
+        route = %route,
+        team = %team,
+        principal = tracing::field::Empty,
         trace_id = tracing::field::Empty,
     );
 
@@ -75,8 +126,25 @@ pub async fn request_id(mut request: Request, next: Next) -> Response {
     let elapsed_ms = started.elapsed().as_millis() as u64;
 
     let status = response.status().as_u16();
+    let principal = response
+        .extensions()
+        .get::<AccessLogPrincipal>()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    if !principal.is_empty() {
+        span.record("principal", principal.as_str());
+    }
     span.in_scope(|| {
-        tracing::info!(request_id = %rid_for_log, status, elapsed_ms, "request completed");
+        tracing::info!(
+            request_id = %rid_for_log,
+            method = %method,
+            route = %route,
+            team = %team,
+            principal = %principal,
+            status,
+            elapsed_ms,
+            "request completed"
+        );
     });
     metrics::counter!("fp_api_requests_total", "status" => status.to_string()).increment(1);
     metrics::histogram!("fp_api_request_duration_ms").record(elapsed_ms as f64);
@@ -86,3 +154,142 @@ pub async fn request_id(mut request: Request, next: Next) -> Response {
     }
     response
 }
+
+/// Render a JSON response as YAML when the request's `Accept` prefers YAML
+/// (`application/yaml`, `application/x-yaml`, `text/yaml`). Error envelopes are converted
+/// too, so a YAML client never has to parse JSON; non-JSON bodies pass through unchanged.
+/// Negotiable responses carry `Vary: Accept` so caches keep the two renderings apart.
+pub async fn yaml_responses(request: Request, next: Next) -> Response {
+    let wants_yaml = crate::extract::is_yaml(request.headers(), header::ACCEPT);
+    let mut response = next.run(request).await;
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json {
+        return response;
+    }
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+    if !wants_yaml {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!(error = %e, "could not buffer response for YAML rendering");
+            parts.headers.remove(header::CONTENT_LENGTH);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let yaml = serde_json::from_slice::<serde_json::Value>(&bytes)
+        .ok()
+        .and_then(|value| serde_yaml::to_string(&value).ok());
+    let Some(yaml) = yaml else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/yaml"),
+    );
+    Response::from_parts(parts, Body::from(yaml))
+}
+
+/// Error responses as `application/problem+json` when the caller's `Accept` names it. Only
+/// responses rendered from an [`crate::ApiError`] are touched; the status, headers, and
+/// envelope fields are kept.
+pub async fn problem_responses(request: Request, next: Next) -> Response {
+    let wants_problem = request
+        .headers()
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|media| media.split(';').next().unwrap_or_default().trim())
+        .any(|media| media.eq_ignore_ascii_case(crate::error::PROBLEM_JSON));
+    let instance = request.uri().path().to_string();
+    let mut response = next.run(request).await;
+    let Some(rendered) = response
+        .extensions_mut()
+        .remove::<crate::error::RenderedError>()
+    else {
+        return response;
+    };
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+    if !wants_problem {
+        return response;
+    }
+    let Ok(body) = serde_json::to_vec(&rendered.to_problem(&instance)) else {
+        return response;
+    };
+    let (mut parts, _) = response.into_parts();
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(crate::error::PROBLEM_JSON),
+    );
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// 413 for a body declared larger than `max_body_bytes`. Chunked bodies carry no length; the
+/// router's `DefaultBodyLimit` stops those while they are read, and [`crate::extract::ApiJson`]
+/// renders that as the same 413.
+pub async fn limit_request_body(
+    State(state): State<crate::state::AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let declared = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    if let Some(declared) = declared.filter(|len| *len > state.max_body_bytes as u64) {
+        let rid = request
+            .extensions()
+            .get::<RequestId>()
+            .copied()
+            .unwrap_or_else(RequestId::generate);
+        let error = body_too_large(Some(declared), Some(state.max_body_bytes));
+        return crate::error::ApiError::new(error, rid).into_response();
+    }
+    next.run(request).await
+}
+
+/// The 413 for an oversized body; sizes are named when known (a streamed body that outgrew
+/// the limit only reports that it did).
+pub(crate) fn body_too_large(declared: Option<u64>, max_body_bytes: Option<usize>) -> DomainError {
+    let message = match (declared, max_body_bytes) {
+        (Some(declared), Some(max)) => {
+            format!("request body is {declared} bytes; the limit is {max}")
+        }
+        _ => "request body exceeds the configured size limit".to_string(),
+    };
+    DomainError::new(ErrorCode::PayloadTooLarge, message).with_hint(
+        "split the resource into smaller ones (e.g. several route configs), or ask the \
+         operator to raise FLOWPLANE_API_MAX_BODY_BYTES",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::team_segment;
+
+    #[test]
+    fn team_segment_is_taken_only_from_team_scoped_paths() {
+        assert_eq!(
+            team_segment("/api/v1/teams/payments/clusters/web"),
+            Some("payments")
+        );
+        assert_eq!(team_segment("/api/v1/teams/payments"), Some("payments"));
+        assert_eq!(team_segment("/api/v1/teams/"), None);
+        assert_eq!(team_segment("/api/v1/teams"), None);
+        assert_eq!(team_segment("/api/v1/orgs/acme"), None);
+    }
+}
@@ -8,6 +8,7 @@ use axum::http::StatusCode;
 use axum::Json;
 use fp_core::services::orgs as svc;
 use fp_core::PrincipalCtx;
+use fp_domain::gateway::filters::HttpFilterEntry;
 use fp_domain::{DomainError, DomainResult, OrgId, OrgRole, RequestId, UserId};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -49,6 +50,13 @@ pub struct AddOrgMemberBody {
     pub role: String,
 }
 
+/// Filters merged into each team's first listener (GET response and PUT body).
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrgDefaultFiltersBody {
+    pub filters: Vec<HttpFilterEntry>,
+}
+
 async fn resolve_org(state: &AppState, raw: &str) -> DomainResult<OrgId> {
     if let Ok(id) = OrgId::from_str(raw) {
         return Ok(id);
@@ -239,3 +247,44 @@ pub async fn remove_member(
         .map(|_| StatusCode::NO_CONTENT)
         .map_err(|e| ApiError::new(e, rid))
 }
+
+#[utoipa::path(get, path = "/api/v1/orgs/{org}/default-filters", tag = "Organizations",
+    params(("org" = String, Path, description = "Organization name or UUID")),
+    responses((status = 200, body = OrgDefaultFiltersBody), (status = 404, body = ErrorBody)))]
+pub async fn get_default_filters(
+    State(state): State<AppState>,
+    Path(org): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<OrgDefaultFiltersBody>, ApiError> {
+    let run = async {
+        let org_id = resolve_org(&state, &org).await?;
+        svc::get_default_listener_filters(&state.pool, &ctx, org_id).await
+    };
+    run.await
+        .map(|filters| Json(OrgDefaultFiltersBody { filters }))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+/// Replace the org's default listener filters. Each team's first listener merges in every
+/// default whose filter type it does not already configure.
+#[utoipa::path(put, path = "/api/v1/orgs/{org}/default-filters", tag = "Organizations",
+    params(("org" = String, Path, description = "Organization name or UUID")),
+    request_body = OrgDefaultFiltersBody,
+    responses((status = 200, body = OrgDefaultFiltersBody), (status = 400, body = ErrorBody),
+              (status = 403, body = ErrorBody)))]
+pub async fn set_default_filters(
+    State(state): State<AppState>,
+    Path(org): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<OrgDefaultFiltersBody>,
+) -> Result<Json<OrgDefaultFiltersBody>, ApiError> {
+    let run = async {
+        let org_id = resolve_org(&state, &org).await?;
+        svc::set_default_listener_filters(&state.pool, &ctx, org_id, body.filters, rid).await
+    };
+    run.await
+        .map(|filters| Json(OrgDefaultFiltersBody { filters }))
+        .map_err(|e| ApiError::new(e, rid))
+}
@@ -0,0 +1,169 @@
+//! Proto descriptor endpoints (gRPC-JSON transcoding). Uploads carry a base64-encoded
+//! `FileDescriptorSet`; responses are metadata only — the id is what a
+//! `grpc_json_transcoder` filter references.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::resources::{resolve_team, revision_from, ListQuery, Page};
+use crate::state::AppState;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use base64::Engine as _;
+use fp_core::services::proto_descriptors as svc;
+use fp_core::PrincipalCtx;
+use fp_domain::gateway::ProtoDescriptor;
+use fp_domain::{DomainError, RequestId};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProtoDescriptorView {
+    /// Referenced as `proto_descriptor_id` by `grpc_json_transcoder` filters.
+    pub id: uuid::Uuid,
+    pub team_id: uuid::Uuid,
+    pub name: String,
+    /// Fully-qualified gRPC services declared by the set.
+    pub services: Vec<String>,
+    pub size_bytes: i64,
+    pub revision: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ProtoDescriptor> for ProtoDescriptorView {
+    fn from(value: ProtoDescriptor) -> Self {
+        Self {
+            id: value.id.as_uuid(),
+            team_id: value.team_id.as_uuid(),
+            name: value.name,
+            services: value.services,
+            size_bytes: value.size_bytes,
+            revision: value.version,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProtoDescriptorBody {
+    pub name: String,
+    /// Base64-encoded `google.protobuf.FileDescriptorSet` (`protoc --descriptor_set_out`).
+    pub descriptor_set: String,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/proto-descriptors",
+    tag = "Proto descriptors",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, body = Page<ProtoDescriptorView>),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_proto_descriptors(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<ProtoDescriptorView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_proto_descriptors(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/proto-descriptors",
+    tag = "Proto descriptors",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = CreateProtoDescriptorBody,
+    responses(
+        (status = 201, body = ProtoDescriptorView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn create_proto_descriptor(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<CreateProtoDescriptorBody>,
+) -> Result<(StatusCode, Json<ProtoDescriptorView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(body.descriptor_set.trim())
+            .map_err(|e| {
+                DomainError::validation(format!("descriptor_set is not valid base64: {e}"))
+                    .with_hint(
+                        "send the FileDescriptorSet bytes base64-encoded (standard alphabet)",
+                    )
+            })?;
+        svc::create_proto_descriptor(&state.pool, &ctx, team, &body.name, &bytes, rid).await
+    };
+    let descriptor = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ProtoDescriptorView::from(descriptor)),
+    ))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/proto-descriptors/{name}",
+    tag = "Proto descriptors",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Proto descriptor name"),
+    ),
+    responses(
+        (status = 200, body = ProtoDescriptorView),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_proto_descriptor(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ProtoDescriptorView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_proto_descriptor(&state.pool, &ctx, team, &name, rid).await
+    };
+    run.await
+        .map(|descriptor| Json(ProtoDescriptorView::from(descriptor)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/proto-descriptors/{name}",
+    tag = "Proto descriptors",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Proto descriptor name"),
+        ("If-Match" = i64, Header, description = "Current resource revision"),
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn delete_proto_descriptor(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    headers: HeaderMap,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<StatusCode, ApiError> {
+    let run = async {
+        let revision = revision_from(&headers)?;
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::delete_proto_descriptor(&state.pool, &ctx, team, &name, revision, rid).await
+    };
+    run.await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| ApiError::new(e, rid))
+}
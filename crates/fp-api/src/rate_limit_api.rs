@@ -18,7 +18,7 @@ use fp_domain::rate_limit::{
     RateLimitDomain, RateLimitPolicy, RateLimitPolicySpec, RateLimitTeamOverride,
     RateLimitTeamOverrideSpec,
 };
-use fp_domain::{DomainError, RequestId};
+use fp_domain::RequestId;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -49,17 +49,11 @@ pub async fn force_repush(
     Extension(rid): Extension<RequestId>,
 ) -> Result<StatusCode, ApiError> {
     fp_core::services::rls_sync::authorize_repush(&ctx).map_err(|e| ApiError::new(e, rid))?;
-    match &state.rls_repush {
-        Some(notify) => {
-            notify.notify_one();
-            Ok(StatusCode::ACCEPTED)
-        }
-        None => Err(ApiError::new(
-            DomainError::unavailable("rate-limit sync is not configured")
-                .with_hint("set FLOWPLANE_RLS_ADMIN_URL to enable CP→RLS policy sync"),
-            rid,
-        )),
-    }
+    state
+        .rls_sync()
+        .map_err(|e| ApiError::new(e, rid))?
+        .notify_one();
+    Ok(StatusCode::ACCEPTED)
 }
 
 // ---- Views -------------------------------------------------------------------------------
@@ -145,12 +139,7 @@ impl From<RateLimitTeamOverride> for RateLimitOverrideView {
 }
 
 fn page<T, V: From<T>>(items: Vec<T>, total: i64, q: &ListQuery) -> Page<V> {
-    Page {
-        items: items.into_iter().map(V::from).collect(),
-        total,
-        limit: q.limit.clamp(1, 500),
-        offset: q.offset.max(0),
-    }
+    Page::new(items, total, q.limit, q.offset)
 }
 
 // ---- Request bodies ----------------------------------------------------------------------
@@ -0,0 +1,230 @@
+//! Short-lived cache for the single-resource GETs dashboards poll (clusters, listeners, route
+//! configs). Off unless `FLOWPLANE_READ_CACHE_TTL_SECS` is set. Entries are keyed by kind, team,
+//! and name. Authorization is still decided on every read, and the cache only replaces the row
+//! fetch. Any mutating request through this replica empties the cache when it completes. A
+//! write through another replica becomes visible here after at most the TTL.
+
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
+use fp_domain::{DomainResult, TeamId};
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+const MAX_ENTRIES: usize = 10_000;
+
+type Key = (&'static str, TeamId, String);
+
+#[derive(Default)]
+pub struct ReadCache {
+    /// `None` = disabled: every read goes to the database.
+    ttl: Option<Duration>,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// Bumped on every flush. A load that started before a flush does not store its result,
+    /// because the row it read may predate the write that caused the flush.
+    generation: u64,
+    entries: HashMap<Key, (Instant, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl ReadCache {
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl: ttl.filter(|ttl| !ttl.is_zero()),
+            inner: Mutex::default(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // A poisoned lock only means a panic mid-update of a cache; the data is still a
+        // cache, so keep serving rather than failing reads.
+        match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// The cached `kind` resource `name` of `team`, or the result of `load` (cached on success).
+    pub async fn get_or_load<T, F, Fut>(
+        &self,
+        kind: &'static str,
+        team: TeamId,
+        name: &str,
+        load: F,
+    ) -> DomainResult<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = DomainResult<T>>,
+    {
+        let Some(ttl) = self.ttl else {
+            return load().await;
+        };
+        let key = (kind, team, name.to_string());
+        let generation = {
+            let inner = self.lock();
+            let hit = inner
+                .entries
+                .get(&key)
+                .filter(|(stored_at, _)| stored_at.elapsed() < ttl)
+                .and_then(|(_, value)| value.downcast_ref::<T>().cloned());
+            if let Some(value) = hit {
+                metrics::counter!("fp_api_read_cache_total", "kind" => kind, "result" => "hit")
+                    .increment(1);
+                return Ok(value);
+            }
+            inner.generation
+        };
+        metrics::counter!("fp_api_read_cache_total", "kind" => kind, "result" => "miss")
+            .increment(1);
+        let value = load().await?;
+        let mut inner = self.lock();
+        if inner.generation == generation {
+            if inner.entries.len() >= MAX_ENTRIES {
+                inner
+                    .entries
+                    .retain(|_, (stored_at, _)| stored_at.elapsed() < ttl);
+                if inner.entries.len() >= MAX_ENTRIES {
+                    inner.entries.clear();
+                }
+            }
+            inner
+                .entries
+                .insert(key, (Instant::now(), Arc::new(value.clone())));
+        }
+        Ok(value)
+    }
+
+    /// Drop every entry.
+    pub fn flush(&self) {
+        if self.ttl.is_none() {
+            return;
+        }
+        let mut inner = self.lock();
+        inner.generation += 1;
+        inner.entries.clear();
+    }
+}
+
+/// Flush the read cache after every mutating request, whatever its outcome. Writes are rare
+/// next to dashboard reads, and flushing everything means no write path can leave a stale entry.
+pub async fn flush_on_write(
+    State(state): State<crate::state::AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mutating = matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    );
+    let response = next.run(request).await;
+    if mutating {
+        state.read_cache.flush();
+    }
+    response
+}
+
+#[cfg(test)]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn hits_until_flushed_and_a_load_racing_a_flush_is_not_stored() {
+        let cache = ReadCache::new(Some(Duration::from_secs(60)));
+        let team = TeamId::generate();
+        let loads = AtomicU32::new(0);
+        let load = || async {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, fp_domain::DomainError>(loads.load(Ordering::SeqCst))
+        };
+
+        assert_eq!(
+            cache
+                .get_or_load("cluster", team, "web", load)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            cache
+                .get_or_load("cluster", team, "web", load)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            cache
+                .get_or_load("listener", team, "web", load)
+                .await
+                .unwrap(),
+            2
+        );
+
+        cache.flush();
+        assert_eq!(
+            cache
+                .get_or_load("cluster", team, "web", load)
+                .await
+                .unwrap(),
+            3
+        );
+
+        let raced = cache
+            .get_or_load("route_config", team, "edge", || async {
+                cache.flush();
+                Ok::<_, fp_domain::DomainError>(0)
+            })
+            .await
+            .unwrap();
+        assert_eq!(raced, 0);
+        assert_eq!(
+            cache
+                .get_or_load("route_config", team, "edge", load)
+                .await
+                .unwrap(),
+            4
+        );
+    }
+
+    #[tokio::test]
+    async fn disabled_cache_always_loads_and_errors_are_not_cached() {
+        let team = TeamId::generate();
+        let loads = AtomicU32::new(0);
+        let load = || async {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, fp_domain::DomainError>(())
+        };
+        let disabled = ReadCache::default();
+        disabled
+            .get_or_load("cluster", team, "web", load)
+            .await
+            .unwrap();
+        disabled
+            .get_or_load("cluster", team, "web", load)
+            .await
+            .unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+
+        let cache = ReadCache::new(Some(Duration::from_secs(60)));
+        let missing =
+            || async { Err::<(), _>(fp_domain::DomainError::not_found("cluster", "web")) };
+        assert!(cache
+            .get_or_load("cluster", team, "web", missing)
+            .await
+            .is_err());
+        cache
+            .get_or_load("cluster", team, "web", load)
+            .await
+            .unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 3);
+    }
+}
@@ -11,11 +11,12 @@ use axum::http::HeaderMap;
 use axum::Json;
 use fp_core::services::{clusters as cluster_svc, gateway as gateway_svc};
 use fp_core::PrincipalCtx;
-use fp_domain::authz::TeamRef;
+use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::gateway::cluster::{Cluster, ClusterSpec};
-use fp_domain::gateway::listener::{Listener, ListenerSpec};
+use fp_domain::gateway::listener::{Listener, ListenerMaintenance, ListenerSpec};
 use fp_domain::gateway::route_config::{RouteConfig, RouteConfigSpec};
-use fp_domain::{DomainError, DomainResult, ErrorCode, RequestId};
+use fp_domain::gateway::ListSort;
+use fp_domain::{DomainError, DomainResult, ErrorCode, RequestId, ResourceSource};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use utoipa::{IntoParams, ToSchema};
@@ -109,23 +110,65 @@ fn default_limit() -> i64 {
     50
 }
 
+/// List query for clusters, listeners and route configs: [`ListQuery`] plus a source filter
+/// and sort order.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GatewayListQuery {
+    /// Max items (default 50, cap 500).
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    /// Items to skip.
+    #[serde(default)]
+    pub offset: i64,
+    /// `native_api` for resources written through the API, `openapi_import` for those an
+    /// applied route generation plan produced. Omit for both.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// `name` (default), `created_at` or `updated_at`.
+    #[serde(default)]
+    pub sort: Option<String>,
+    /// `asc` (default) or `desc`.
+    #[serde(default)]
+    pub order: Option<String>,
+}
+
 /// Uniform list envelope (spec/01).
 #[derive(Debug, Serialize, ToSchema)]
 pub struct Page<T> {
     pub items: Vec<T>,
+    /// Matching items across all pages.
     pub total: i64,
     pub limit: i64,
     pub offset: i64,
+    /// Whether items remain past this page (`offset + items.len() < total`); fetch the next
+    /// page with `offset + limit`.
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// One page of a list whose full size is `total`, echoing the effective (clamped) paging.
+    pub fn new<D: Into<T>>(items: Vec<D>, total: i64, limit: i64, offset: i64) -> Self {
+        let offset = offset.max(0);
+        let items: Vec<T> = items.into_iter().map(Into::into).collect();
+        Self {
+            has_more: offset + (items.len() as i64) < total,
+            items,
+            total,
+            limit: limit.clamp(1, 500),
+            offset,
+        }
+    }
 }
 
 macro_rules! views {
-    ($view:ident, $domain:ty, $spec:ty) => {
+    ($view:ident, $domain:ty, $spec:ty $(, $(#[$meta:meta])* $extra:ident: $extra_ty:ty)*) => {
         #[derive(Debug, Serialize, ToSchema)]
         pub struct $view {
             /// Stable identifier (UUID).
             pub id: uuid::Uuid,
             pub name: String,
             pub spec: $spec,
+            $($(#[$meta])* pub $extra: $extra_ty,)*
             /// Optimistic-concurrency revision; echo via If-Match on update/delete.
             pub revision: i64,
             pub created_at: chrono::DateTime<chrono::Utc>,
@@ -138,6 +181,7 @@ macro_rules! views {
                     id: value.id.as_uuid(),
                     name: value.name,
                     spec: value.spec,
+                    $($extra: value.$extra,)*
                     revision: value.version,
                     created_at: value.created_at,
                     updated_at: value.updated_at,
@@ -148,7 +192,10 @@ macro_rules! views {
 }
 
 views!(ClusterView, Cluster, ClusterSpec);
-views!(ListenerView, Listener, ListenerSpec);
+views!(ListenerView, Listener, ListenerSpec,
+    /// The direct response served instead of the route config while in maintenance mode;
+    /// null when serving normally.
+    maintenance: Option<ListenerMaintenance>);
 views!(RouteConfigView, RouteConfig, RouteConfigSpec);
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -190,46 +237,84 @@ pub struct UpdateRouteConfigBody {
     pub spec: RouteConfigSpec,
 }
 
+/// Body for `POST …/{name}/clone`: the copy's name and, optionally, the team (name or UUID,
+/// same org) that should own it. Omitting `team` clones within the source team.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CloneResourceBody {
+    pub new_name: String,
+    #[serde(default)]
+    pub team: Option<String>,
+}
+
+/// Body for `POST …/{name}/transfer`: the team (name or UUID, same org) that should own the
+/// resource from now on.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TransferResourceBody {
+    pub team: String,
+}
+
+/// Listener create options.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListenerCreateQuery {
+    /// Sort `http_filters` into the recommended order before validating (router stays
+    /// last); the response shows the final order.
+    #[serde(default)]
+    pub auto_order: bool,
+}
+
 macro_rules! endpoints {
     ($mod_name:ident, $segment:literal, $tag:literal,
      view: $view:ident, create: $create_body:ident, update: $update_body:ident,
      svc_create: $svc_create:path, svc_get: $svc_get:path, svc_list: $svc_list:path,
-     svc_update: $svc_update:path, svc_delete: $svc_delete:path
-     $(, rls: $rls:ident)?) => {
+     svc_update: $svc_update:path, svc_delete: $svc_delete:path, svc_clone: $svc_clone:path,
+     svc_transfer: $svc_transfer:path, cache: $kind:literal => $resource:expr
+     $(, rls: $rls:ident)? $(, create_query: $cq:ty => $cq_field:ident)?) => {
         pub mod $mod_name {
             use super::*;
 
             #[utoipa::path(get, path = concat!("/api/v1/teams/{team}/", $segment),
                 tag = $tag,
-                params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+                params(("team" = String, Path, description = "Team name or UUID"), GatewayListQuery),
                 responses(
-                    (status = 200, body = Page<$view>),
+                    (status = 200, content(
+                        (Page<$view> = "application/json"),
+                        (Page<$view> = "application/yaml"),
+                    )),
                     (status = 401, body = crate::error::ErrorBody),
                     (status = 404, body = crate::error::ErrorBody),
                 ))]
             pub async fn list(
                 State(state): State<AppState>,
                 Path(team): Path<String>,
-                Query(query): Query<ListQuery>,
+                Query(query): Query<GatewayListQuery>,
                 Extension(ctx): Extension<PrincipalCtx>,
                 Extension(rid): Extension<RequestId>,
             ) -> Result<Json<Page<$view>>, ApiError> {
                 let run = async {
                     let team = resolve_team(&state, &ctx, &team).await?;
-                    $svc_list(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+                    let source = query.source.as_deref().map(ResourceSource::parse).transpose()?;
+                    let sort = ListSort::parse(query.sort.as_deref(), query.order.as_deref())?;
+                    $svc_list(
+                        &state.pool,
+                        &ctx,
+                        team,
+                        source,
+                        sort,
+                        query.limit,
+                        query.offset,
+                        rid,
+                    )
+                    .await
                 };
                 let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-                Ok(Json(Page {
-                    items: items.into_iter().map($view::from).collect(),
-                    total,
-                    limit: query.limit.clamp(1, 500),
-                    offset: query.offset.max(0),
-                }))
+                Ok(Json(Page::new(items, total, query.limit, query.offset)))
             }
 
             #[utoipa::path(post, path = concat!("/api/v1/teams/{team}/", $segment),
                 tag = $tag,
-                params(("team" = String, Path, description = "Team name or UUID")),
+                params(("team" = String, Path, description = "Team name or UUID") $(, $cq)?),
                 request_body = $create_body,
                 responses(
                     (status = 201, body = $view),
@@ -242,11 +327,12 @@ macro_rules! endpoints {
                 Path(team): Path<String>,
                 Extension(ctx): Extension<PrincipalCtx>,
                 Extension(rid): Extension<RequestId>,
+                $(Query(create_query): Query<$cq>,)?
                 ApiJson(body): ApiJson<$create_body>,
             ) -> Result<(axum::http::StatusCode, Json<$view>), ApiError> {
                 let run = async {
                     let team = resolve_team(&state, &ctx, &team).await?;
-                    $svc_create(&state.pool, &ctx, team, &body.name, body.spec, rid $(, state.$rls)?).await
+                    $svc_create(&state.pool, &ctx, team, &body.name, body.spec, rid $(, state.$rls)? $(, create_query.$cq_field)?).await
                 };
                 let created = run.await.map_err(|e| ApiError::new(e, rid))?;
                 Ok((axum::http::StatusCode::CREATED, Json($view::from(created))))
@@ -259,7 +345,10 @@ macro_rules! endpoints {
                     ("name" = String, Path, description = "Resource name"),
                 ),
                 responses(
-                    (status = 200, body = $view),
+                    (status = 200, content(
+                        ($view = "application/json"),
+                        ($view = "application/yaml"),
+                    )),
                     (status = 404, body = crate::error::ErrorBody),
                 ))]
             pub async fn get(
@@ -270,7 +359,20 @@ macro_rules! endpoints {
             ) -> Result<Json<$view>, ApiError> {
                 let run = async {
                     let team = resolve_team(&state, &ctx, &team).await?;
-                    $svc_get(&state.pool, &ctx, team, &name, rid).await
+                    // A denied read skips the cache so the service records the denial.
+                    let allowed = matches!(
+                        fp_core::check_resource_access(&ctx, $resource, Action::Read, Some(team)),
+                        fp_core::Decision::Allow(_)
+                    );
+                    if !allowed {
+                        return $svc_get(&state.pool, &ctx, team, &name, rid).await;
+                    }
+                    state
+                        .read_cache
+                        .get_or_load($kind, team.id, &name, || {
+                            $svc_get(&state.pool, &ctx, team, &name, rid)
+                        })
+                        .await
                 };
                 run.await.map(|v| Json($view::from(v))).map_err(|e| ApiError::new(e, rid))
             }
@@ -332,6 +434,70 @@ macro_rules! endpoints {
                     .map(|_| axum::http::StatusCode::NO_CONTENT)
                     .map_err(|e| ApiError::new(e, rid))
             }
+
+            #[utoipa::path(post, path = concat!("/api/v1/teams/{team}/", $segment, "/{name}/clone"),
+                tag = $tag,
+                params(
+                    ("team" = String, Path, description = "Team name or UUID"),
+                    ("name" = String, Path, description = "Resource name"),
+                ),
+                request_body = CloneResourceBody,
+                responses(
+                    (status = 201, body = $view),
+                    (status = 400, body = crate::error::ErrorBody),
+                    (status = 404, body = crate::error::ErrorBody),
+                    (status = 409, body = crate::error::ErrorBody),
+                ))]
+            pub async fn clone(
+                State(state): State<AppState>,
+                Path((team, name)): Path<(String, String)>,
+                Extension(ctx): Extension<PrincipalCtx>,
+                Extension(rid): Extension<RequestId>,
+                ApiJson(body): ApiJson<CloneResourceBody>,
+            ) -> Result<(axum::http::StatusCode, Json<$view>), ApiError> {
+                let run = async {
+                    let team = resolve_team(&state, &ctx, &team).await?;
+                    let target = match &body.team {
+                        Some(raw) => resolve_team(&state, &ctx, raw).await?,
+                        None => team,
+                    };
+                    $svc_clone(&state.pool, &ctx, team, &name, target, &body.new_name, rid $(, state.$rls)?).await
+                };
+                let cloned = run.await.map_err(|e| ApiError::new(e, rid))?;
+                Ok((axum::http::StatusCode::CREATED, Json($view::from(cloned))))
+            }
+
+            #[utoipa::path(post, path = concat!("/api/v1/teams/{team}/", $segment, "/{name}/transfer"),
+                tag = $tag,
+                params(
+                    ("team" = String, Path, description = "Team name or UUID"),
+                    ("name" = String, Path, description = "Resource name"),
+                    ("If-Match" = i64, Header, description = "Current resource revision"),
+                ),
+                request_body = TransferResourceBody,
+                responses(
+                    (status = 200, body = $view),
+                    (status = 400, body = crate::error::ErrorBody),
+                    (status = 403, body = crate::error::ErrorBody),
+                    (status = 404, body = crate::error::ErrorBody),
+                    (status = 409, body = crate::error::ErrorBody),
+                ))]
+            pub async fn transfer(
+                State(state): State<AppState>,
+                Path((team, name)): Path<(String, String)>,
+                headers: HeaderMap,
+                Extension(ctx): Extension<PrincipalCtx>,
+                Extension(rid): Extension<RequestId>,
+                ApiJson(body): ApiJson<TransferResourceBody>,
+            ) -> Result<Json<$view>, ApiError> {
+                let run = async {
+                    let revision = revision_from(&headers)?;
+                    let team = resolve_team(&state, &ctx, &team).await?;
+                    let target = resolve_team(&state, &ctx, &body.team).await?;
+                    $svc_transfer(&state.pool, &ctx, team, &name, target, revision, rid $(, state.$rls)?).await
+                };
+                run.await.map(|v| Json($view::from(v))).map_err(|e| ApiError::new(e, rid))
+            }
         }
     };
 }
@@ -340,18 +506,480 @@ endpoints!(clusters, "clusters", "Clusters",
     view: ClusterView, create: CreateClusterBody, update: UpdateClusterBody,
     svc_create: cluster_svc::create_cluster, svc_get: cluster_svc::get_cluster,
     svc_list: cluster_svc::list_clusters, svc_update: cluster_svc::update_cluster,
-    svc_delete: cluster_svc::delete_cluster,
+    svc_delete: cluster_svc::delete_cluster, svc_clone: cluster_svc::clone_cluster,
+    svc_transfer: cluster_svc::transfer_cluster, cache: "cluster" => Resource::Clusters,
     rls: egress_advisory);
 
 endpoints!(listeners, "listeners", "Listeners",
     view: ListenerView, create: CreateListenerBody, update: UpdateListenerBody,
-    svc_create: gateway_svc::create_listener, svc_get: gateway_svc::get_listener,
+    svc_create: gateway_svc::create_listener_with_order, svc_get: gateway_svc::get_listener,
     svc_list: gateway_svc::list_listeners, svc_update: gateway_svc::update_listener,
-    svc_delete: gateway_svc::delete_listener,
-    rls: rls_grpc_configured);
+    svc_delete: gateway_svc::delete_listener, svc_clone: gateway_svc::clone_listener,
+    svc_transfer: gateway_svc::transfer_listener, cache: "listener" => Resource::Listeners,
+    rls: rls_grpc_configured, create_query: ListenerCreateQuery => auto_order);
 
 endpoints!(route_configs, "route-configs", "RouteConfigs",
     view: RouteConfigView, create: CreateRouteConfigBody, update: UpdateRouteConfigBody,
     svc_create: gateway_svc::create_route_config, svc_get: gateway_svc::get_route_config,
     svc_list: gateway_svc::list_route_configs, svc_update: gateway_svc::update_route_config,
-    svc_delete: gateway_svc::delete_route_config);
+    svc_delete: gateway_svc::delete_route_config, svc_clone: gateway_svc::clone_route_config,
+    svc_transfer: gateway_svc::transfer_route_config,
+    cache: "route_config" => Resource::RouteConfigs);
+
+/// Listener maintenance mode: a reversible switch that serves a direct response instead of
+/// the listener's route config.
+pub mod listener_maintenance {
+    use super::*;
+
+    /// Body for `POST …/listeners/{name}/maintenance`.
+    #[derive(Debug, Deserialize, ToSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct MaintenanceBody {
+        /// `true` enters maintenance mode, `false` leaves it.
+        pub enabled: bool,
+        /// Response status while enabled, 500–599 (default 503).
+        #[serde(default)]
+        pub status: Option<u16>,
+        /// Response body while enabled (default: a short maintenance notice).
+        #[serde(default)]
+        pub body: Option<String>,
+    }
+
+    #[utoipa::path(post, path = "/api/v1/teams/{team}/listeners/{name}/maintenance",
+        tag = "Listeners",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Listener name"),
+            ("If-Match" = Option<i64>, Header, description = "Expected listener revision"),
+        ),
+        request_body = MaintenanceBody,
+        responses(
+            (status = 200, body = ListenerView),
+            (status = 400, body = crate::error::ErrorBody),
+            (status = 403, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+            (status = 409, body = crate::error::ErrorBody),
+        ))]
+    pub async fn set(
+        State(state): State<AppState>,
+        Path((team, name)): Path<(String, String)>,
+        headers: HeaderMap,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+        ApiJson(body): ApiJson<MaintenanceBody>,
+    ) -> Result<Json<ListenerView>, ApiError> {
+        let run = async {
+            let revision = optional_revision_from(&headers)?;
+            let maintenance = match body {
+                MaintenanceBody {
+                    enabled: true,
+                    status,
+                    body,
+                } => {
+                    let defaults = ListenerMaintenance::default();
+                    Some(ListenerMaintenance {
+                        status: status.unwrap_or(defaults.status),
+                        body: body.unwrap_or(defaults.body),
+                    })
+                }
+                MaintenanceBody {
+                    enabled: false,
+                    status: None,
+                    body: None,
+                } => None,
+                MaintenanceBody { enabled: false, .. } => {
+                    return Err(DomainError::validation(
+                        "status and body only apply when enabling maintenance",
+                    ))
+                }
+            };
+            let team = resolve_team(&state, &ctx, &team).await?;
+            gateway_svc::set_listener_maintenance(
+                &state.pool,
+                &ctx,
+                team,
+                &name,
+                maintenance,
+                revision,
+                rid,
+            )
+            .await
+        };
+        run.await
+            .map(|v| Json(ListenerView::from(v)))
+            .map_err(|e| ApiError::new(e, rid))
+    }
+}
+
+/// Reverse dependencies of a cluster, for planning a change or deletion.
+pub mod cluster_references {
+    use super::*;
+
+    #[derive(Debug, Serialize, ToSchema)]
+    pub struct ClusterRouteReferenceView {
+        pub route_config: String,
+        pub virtual_host: String,
+        pub route: String,
+    }
+
+    #[derive(Debug, Serialize, ToSchema)]
+    pub struct ClusterReferencesView {
+        pub cluster: String,
+        /// Route configs with at least one route sending traffic to the cluster.
+        pub route_configs: Vec<String>,
+        /// Those routes: the cluster is their target, in their weighted list, their fallback,
+        /// or a mirror.
+        pub routes: Vec<ClusterRouteReferenceView>,
+        /// Listeners serving one of `route_configs`.
+        pub listeners: Vec<String>,
+        /// Listeners naming the cluster in a filter, access log, or tracer.
+        pub listener_services: Vec<String>,
+        /// Aggregate clusters listing it as a member.
+        pub aggregates: Vec<String>,
+    }
+
+    #[utoipa::path(get, path = "/api/v1/teams/{team}/clusters/{name}/references",
+        tag = "Clusters",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Cluster name"),
+        ),
+        responses(
+            (status = 200, body = ClusterReferencesView),
+            (status = 403, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+        ))]
+    pub async fn list(
+        State(state): State<AppState>,
+        Path((team, name)): Path<(String, String)>,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+    ) -> Result<Json<ClusterReferencesView>, ApiError> {
+        let run = async {
+            let team = resolve_team(&state, &ctx, &team).await?;
+            gateway_svc::cluster_references(&state.pool, &ctx, team, &name, rid).await
+        };
+        let refs = run.await.map_err(|e| ApiError::new(e, rid))?;
+        Ok(Json(ClusterReferencesView {
+            cluster: name,
+            route_configs: refs.route_configs,
+            routes: refs
+                .routes
+                .into_iter()
+                .map(|r| ClusterRouteReferenceView {
+                    route_config: r.route_config,
+                    virtual_host: r.virtual_host,
+                    route: r.route,
+                })
+                .collect(),
+            listeners: refs.listeners,
+            listener_services: refs.listener_services,
+            aggregates: refs.aggregates,
+        }))
+    }
+}
+
+/// Incremental virtual-host edits on a route config: the result is the updated route config,
+/// whose new revision the caller needs for its next guarded write. Also the read-only preview
+/// of the filters one route of a virtual host ends up running, and the canary traffic shifts
+/// of one route (which return the updated route config the same way).
+pub mod route_config_virtual_hosts {
+    use super::*;
+    use fp_domain::gateway::filters::{EffectiveFilter, FilterOverride};
+    use fp_domain::gateway::route_config::VirtualHost;
+
+    #[utoipa::path(post, path = "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts",
+        tag = "RouteConfigs",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Route config name"),
+            ("If-Match" = Option<i64>, Header, description = "Expected route-config revision"),
+        ),
+        request_body = VirtualHost,
+        responses(
+            (status = 201, body = RouteConfigView),
+            (status = 400, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+            (status = 409, body = crate::error::ErrorBody),
+        ))]
+    pub async fn add(
+        State(state): State<AppState>,
+        Path((team, name)): Path<(String, String)>,
+        headers: HeaderMap,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+        ApiJson(body): ApiJson<VirtualHost>,
+    ) -> Result<(axum::http::StatusCode, Json<RouteConfigView>), ApiError> {
+        let run = async {
+            let revision = optional_revision_from(&headers)?;
+            let team = resolve_team(&state, &ctx, &team).await?;
+            gateway_svc::add_virtual_host(&state.pool, &ctx, team, &name, body, revision, rid).await
+        };
+        let updated = run.await.map_err(|e| ApiError::new(e, rid))?;
+        Ok((
+            axum::http::StatusCode::CREATED,
+            Json(RouteConfigView::from(updated)),
+        ))
+    }
+
+    #[utoipa::path(delete, path = "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}",
+        tag = "RouteConfigs",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Route config name"),
+            ("vhost" = String, Path, description = "Virtual host name"),
+            ("If-Match" = Option<i64>, Header, description = "Expected route-config revision"),
+        ),
+        responses(
+            (status = 200, body = RouteConfigView),
+            (status = 400, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+            (status = 409, body = crate::error::ErrorBody),
+        ))]
+    pub async fn remove(
+        State(state): State<AppState>,
+        Path((team, name, vhost)): Path<(String, String, String)>,
+        headers: HeaderMap,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+    ) -> Result<Json<RouteConfigView>, ApiError> {
+        let run = async {
+            let revision = optional_revision_from(&headers)?;
+            let team = resolve_team(&state, &ctx, &team).await?;
+            gateway_svc::remove_virtual_host(&state.pool, &ctx, team, &name, &vhost, revision, rid)
+                .await
+        };
+        run.await
+            .map(|v| Json(RouteConfigView::from(v)))
+            .map_err(|e| ApiError::new(e, rid))
+    }
+
+    /// The filters one listener runs for the route, in chain order (the router runs last).
+    #[derive(Debug, Serialize, ToSchema)]
+    pub struct ListenerEffectiveFiltersView {
+        pub listener: String,
+        pub filters: Vec<EffectiveFilter>,
+        /// Overrides on the virtual host or route naming a filter type this listener's chain
+        /// lacks; Envoy ignores them.
+        pub unmatched_overrides: Vec<FilterOverride>,
+    }
+
+    #[derive(Debug, Serialize, ToSchema)]
+    pub struct EffectiveFiltersView {
+        pub route_config: String,
+        pub virtual_host: String,
+        pub route: String,
+        /// One entry per listener serving the route config; empty while it is unbound.
+        pub listeners: Vec<ListenerEffectiveFiltersView>,
+    }
+
+    /// Preview the merged filter set of one route: each listener chain entry with the
+    /// vhost- or route-level override that wins over it (the most specific scope wins; scopes
+    /// are not merged).
+    #[utoipa::path(get, path = "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/effective-filters",
+        tag = "RouteConfigs",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Route config name"),
+            ("vhost" = String, Path, description = "Virtual host name"),
+            ("route" = String, Path, description = "Route name"),
+        ),
+        responses(
+            (status = 200, body = EffectiveFiltersView),
+            (status = 403, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+        ))]
+    pub async fn effective_filters(
+        State(state): State<AppState>,
+        Path((team, name, vhost, route)): Path<(String, String, String, String)>,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+    ) -> Result<Json<EffectiveFiltersView>, ApiError> {
+        let run = async {
+            let team = resolve_team(&state, &ctx, &team).await?;
+            gateway_svc::effective_route_filters(
+                &state.pool,
+                &ctx,
+                team,
+                &name,
+                &vhost,
+                &route,
+                rid,
+            )
+            .await
+        };
+        let listeners = run.await.map_err(|e| ApiError::new(e, rid))?;
+        Ok(Json(EffectiveFiltersView {
+            route_config: name,
+            virtual_host: vhost,
+            route,
+            listeners: listeners
+                .into_iter()
+                .map(|l| ListenerEffectiveFiltersView {
+                    listener: l.listener,
+                    filters: l.filters,
+                    unmatched_overrides: l.unmatched_overrides,
+                })
+                .collect(),
+        }))
+    }
+
+    /// Canary split request: `canary_weight` percent of the route's traffic goes to
+    /// `canary_cluster`, the rest to `stable_cluster`.
+    #[derive(Debug, Deserialize, ToSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct CanaryBody {
+        pub stable_cluster: String,
+        pub canary_cluster: String,
+        /// 0-100; 0 and 100 leave the route on a single cluster.
+        pub canary_weight: u32,
+    }
+
+    async fn shift(
+        state: &AppState,
+        ctx: &PrincipalCtx,
+        rid: RequestId,
+        headers: &HeaderMap,
+        (team, name, vhost, route): (String, String, String, String),
+        shift: gateway_svc::CanaryShift,
+    ) -> Result<Json<RouteConfigView>, ApiError> {
+        let run = async {
+            let revision = optional_revision_from(headers)?;
+            let team = resolve_team(state, ctx, &team).await?;
+            gateway_svc::shift_route_canary(
+                &state.pool,
+                ctx,
+                team,
+                &name,
+                &vhost,
+                &route,
+                shift,
+                revision,
+                rid,
+            )
+            .await
+        };
+        run.await
+            .map(|v| Json(RouteConfigView::from(v)))
+            .map_err(|e| ApiError::new(e, rid))
+    }
+
+    /// Split one route's traffic between a stable and a canary cluster (a weighted-cluster
+    /// action, stable first). Call again with a new weight to shift further.
+    #[utoipa::path(post, path = "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/canary",
+        tag = "RouteConfigs",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Route config name"),
+            ("vhost" = String, Path, description = "Virtual host name"),
+            ("route" = String, Path, description = "Route name"),
+            ("If-Match" = Option<i64>, Header, description = "Expected route-config revision"),
+        ),
+        request_body = CanaryBody,
+        responses(
+            (status = 200, body = RouteConfigView),
+            (status = 400, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+            (status = 409, body = crate::error::ErrorBody),
+        ))]
+    pub async fn canary(
+        State(state): State<AppState>,
+        Path(path): Path<(String, String, String, String)>,
+        headers: HeaderMap,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+        ApiJson(body): ApiJson<CanaryBody>,
+    ) -> Result<Json<RouteConfigView>, ApiError> {
+        let split = gateway_svc::CanaryShift::Split {
+            stable_cluster: body.stable_cluster,
+            canary_cluster: body.canary_cluster,
+            canary_weight: body.canary_weight,
+        };
+        shift(&state, &ctx, rid, &headers, path, split).await
+    }
+
+    /// Send all of the route's traffic to the canary of its current split.
+    #[utoipa::path(post, path = "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/canary:promote",
+        tag = "RouteConfigs",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Route config name"),
+            ("vhost" = String, Path, description = "Virtual host name"),
+            ("route" = String, Path, description = "Route name"),
+            ("If-Match" = Option<i64>, Header, description = "Expected route-config revision"),
+        ),
+        responses(
+            (status = 200, body = RouteConfigView),
+            (status = 400, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+            (status = 409, body = crate::error::ErrorBody),
+        ))]
+    pub async fn canary_promote(
+        State(state): State<AppState>,
+        Path(path): Path<(String, String, String, String)>,
+        headers: HeaderMap,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+    ) -> Result<Json<RouteConfigView>, ApiError> {
+        shift(
+            &state,
+            &ctx,
+            rid,
+            &headers,
+            path,
+            gateway_svc::CanaryShift::Promote,
+        )
+        .await
+    }
+
+    /// Send all of the route's traffic back to the stable cluster of its current split.
+    #[utoipa::path(post, path = "/api/v1/teams/{team}/route-configs/{name}/virtual-hosts/{vhost}/routes/{route}/canary:abort",
+        tag = "RouteConfigs",
+        params(
+            ("team" = String, Path, description = "Team name or UUID"),
+            ("name" = String, Path, description = "Route config name"),
+            ("vhost" = String, Path, description = "Virtual host name"),
+            ("route" = String, Path, description = "Route name"),
+            ("If-Match" = Option<i64>, Header, description = "Expected route-config revision"),
+        ),
+        responses(
+            (status = 200, body = RouteConfigView),
+            (status = 400, body = crate::error::ErrorBody),
+            (status = 404, body = crate::error::ErrorBody),
+            (status = 409, body = crate::error::ErrorBody),
+        ))]
+    pub async fn canary_abort(
+        State(state): State<AppState>,
+        Path(path): Path<(String, String, String, String)>,
+        headers: HeaderMap,
+        Extension(ctx): Extension<PrincipalCtx>,
+        Extension(rid): Extension<RequestId>,
+    ) -> Result<Json<RouteConfigView>, ApiError> {
+        shift(
+            &state,
+            &ctx,
+            rid,
+            &headers,
+            path,
+            gateway_svc::CanaryShift::Abort,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Page;
+
+    #[test]
+    fn page_reports_whether_items_remain_past_it() {
+        let first: Page<i64> = Page::new(vec![1, 2], 5, 2, 0);
+        assert!(first.has_more);
+        let last: Page<i64> = Page::new(vec![5], 5, 2, 4);
+        assert!(!last.has_more);
+        let past_end: Page<i64> = Page::new(Vec::<i64>::new(), 5, 2, 10);
+        assert!(!past_end.has_more);
+        let clamped: Page<i64> = Page::new(Vec::<i64>::new(), 0, 9_999, -3);
+        assert_eq!((clamped.limit, clamped.offset), (500, 0));
+    }
+}
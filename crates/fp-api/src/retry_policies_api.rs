@@ -0,0 +1,191 @@
+//! Named retry policy endpoints. A route forward action references a policy by name via
+//! `retry_policy_ref`; the policy body is the same shape as an inline `retry_policy`.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::resources::{resolve_team, revision_from, ListQuery, Page};
+use crate::state::AppState;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use fp_core::services::retry_policies as svc;
+use fp_core::PrincipalCtx;
+use fp_domain::gateway::route_config::RetryPolicy;
+use fp_domain::gateway::NamedRetryPolicy;
+use fp_domain::RequestId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RetryPolicyView {
+    /// Stable identifier (UUID).
+    pub id: uuid::Uuid,
+    /// Referenced as `retry_policy_ref` by route forward actions.
+    pub name: String,
+    pub spec: RetryPolicy,
+    /// Optimistic-concurrency revision; echo via If-Match on update/delete.
+    pub revision: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<NamedRetryPolicy> for RetryPolicyView {
+    fn from(value: NamedRetryPolicy) -> Self {
+        Self {
+            id: value.id.as_uuid(),
+            name: value.name,
+            spec: value.spec,
+            revision: value.version,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateRetryPolicyBody {
+    pub name: String,
+    pub spec: RetryPolicy,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateRetryPolicyBody {
+    pub spec: RetryPolicy,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/retry-policies",
+    tag = "Retry policies",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, body = Page<RetryPolicyView>),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_retry_policies(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<RetryPolicyView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_retry_policies(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/retry-policies",
+    tag = "Retry policies",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = CreateRetryPolicyBody,
+    responses(
+        (status = 201, body = RetryPolicyView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn create_retry_policy(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<CreateRetryPolicyBody>,
+) -> Result<(StatusCode, Json<RetryPolicyView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::create_retry_policy(&state.pool, &ctx, team, &body.name, body.spec, rid).await
+    };
+    let policy = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok((StatusCode::CREATED, Json(RetryPolicyView::from(policy))))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/retry-policies/{name}",
+    tag = "Retry policies",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Retry policy name"),
+    ),
+    responses(
+        (status = 200, body = RetryPolicyView),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_retry_policy(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<RetryPolicyView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_retry_policy(&state.pool, &ctx, team, &name, rid).await
+    };
+    run.await
+        .map(|policy| Json(RetryPolicyView::from(policy)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(patch, path = "/api/v1/teams/{team}/retry-policies/{name}",
+    tag = "Retry policies",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Retry policy name"),
+        ("If-Match" = i64, Header, description = "Current resource revision"),
+    ),
+    request_body = UpdateRetryPolicyBody,
+    responses(
+        (status = 200, body = RetryPolicyView),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn update_retry_policy(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    headers: HeaderMap,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<UpdateRetryPolicyBody>,
+) -> Result<Json<RetryPolicyView>, ApiError> {
+    let run = async {
+        let revision = revision_from(&headers)?;
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::update_retry_policy(&state.pool, &ctx, team, &name, body.spec, revision, rid).await
+    };
+    run.await
+        .map(|policy| Json(RetryPolicyView::from(policy)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/retry-policies/{name}",
+    tag = "Retry policies",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Retry policy name"),
+        ("If-Match" = i64, Header, description = "Current resource revision"),
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn delete_retry_policy(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    headers: HeaderMap,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<StatusCode, ApiError> {
+    let run = async {
+        let revision = revision_from(&headers)?;
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::delete_retry_policy(&state.pool, &ctx, team, &name, revision, rid).await
+    };
+    run.await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| ApiError::new(e, rid))
+}
@@ -2,21 +2,28 @@
 
 use crate::error::ApiError;
 use crate::extract::ApiJson;
-use crate::resources::resolve_team;
+use crate::resources::{resolve_team, ListQuery, Page};
 use crate::state::AppState;
-use axum::extract::{Extension, Path, State};
+use axum::extract::{Extension, Path, Query, State};
 use axum::Json;
 use fp_core::services::route_generation as svc;
 use fp_core::PrincipalCtx;
 use fp_domain::{RequestId, RouteGenerationPlan, RouteGenerationPlanId, SpecVersionId};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CreateRoutePlanBody {
     pub spec_version_id: uuid::Uuid,
     pub listener_port: u16,
+    /// Emit one route per path matching all of its methods instead of one per operation.
+    #[serde(default)]
+    pub coalesce_methods: bool,
+    /// Diff against the API's last applied plan and update its resources on apply instead of
+    /// creating new ones; the diff is returned in `plan.sync`.
+    #[serde(default)]
+    pub sync: bool,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -51,6 +58,67 @@ pub struct ApplyRoutePlanView {
     pub listener: String,
 }
 
+/// One resource an applied plan produced.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlanResourceView {
+    /// `cluster`, `route_config` or `listener`.
+    pub kind: String,
+    pub name: String,
+    /// Current revision; null once the resource has been deleted.
+    pub revision: Option<i64>,
+}
+
+/// What an applied plan produced: its resources and the routes it generated.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoutePlanResourcesView {
+    pub plan_id: uuid::Uuid,
+    pub api_name: String,
+    pub spec_version_id: uuid::Uuid,
+    pub applied_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub resources: Vec<PlanResourceView>,
+    /// `<virtual host>/<route>` names of the generated route config.
+    pub routes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TeardownRoutePlanQuery {
+    /// Run the dependency checks and list what would be deleted without deleting anything.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Resources a plan teardown deleted, or would delete on a dry run, in deletion order.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoutePlanTeardownView {
+    pub plan_id: uuid::Uuid,
+    pub api_name: String,
+    pub dry_run: bool,
+    pub deleted: Vec<PlanResourceView>,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/route-generation-plans",
+    tag = "RouteGeneration",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, body = Page<RouteGenerationPlanView>),
+        (status = 403, body = crate::error::ErrorBody),
+        (status = 404, body = crate::error::ErrorBody),
+    ))]
+pub async fn list_route_plans(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<RouteGenerationPlanView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_plans(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
 #[utoipa::path(post, path = "/api/v1/teams/{team}/route-generation-plans",
     tag = "RouteGeneration",
     params(("team" = String, Path, description = "Team name or UUID")),
@@ -77,6 +145,8 @@ pub async fn create_route_plan(
             svc::CreateRoutePlanInput {
                 spec_version_id: SpecVersionId::from(body.spec_version_id),
                 listener_port: body.listener_port,
+                coalesce_methods: body.coalesce_methods,
+                sync: body.sync,
             },
             rid,
         )
@@ -126,3 +196,110 @@ pub async fn apply_route_plan(
         listener: applied.listener.name,
     }))
 }
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/route-generation-plans/{plan_id}/resources",
+    tag = "RouteGeneration",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("plan_id" = uuid::Uuid, Path, description = "Route generation plan ID"),
+    ),
+    responses(
+        (status = 200, body = RoutePlanResourcesView),
+        (status = 403, body = crate::error::ErrorBody),
+        (status = 404, body = crate::error::ErrorBody),
+        (status = 409, body = crate::error::ErrorBody),
+    ))]
+pub async fn route_plan_resources(
+    State(state): State<AppState>,
+    Path((team, plan_id)): Path<(String, uuid::Uuid)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<RoutePlanResourcesView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::plan_resources(
+            &state.pool,
+            &ctx,
+            team,
+            RouteGenerationPlanId::from(plan_id),
+            rid,
+        )
+        .await
+    };
+    let (plan, resources) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    let routes = plan
+        .plan
+        .route_config_spec
+        .virtual_hosts
+        .iter()
+        .flat_map(|vhost| {
+            vhost
+                .routes
+                .iter()
+                .map(move |route| format!("{}/{}", vhost.name, route.name))
+        })
+        .collect();
+    Ok(Json(RoutePlanResourcesView {
+        plan_id: plan.id.as_uuid(),
+        api_name: plan.plan.api_name,
+        spec_version_id: plan.spec_version_id.as_uuid(),
+        applied_at: plan.applied_at,
+        resources: resources
+            .into_iter()
+            .map(|r| PlanResourceView {
+                kind: r.kind.as_str().into(),
+                name: r.name,
+                revision: r.version,
+            })
+            .collect(),
+        routes,
+    }))
+}
+
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/route-generation-plans/{plan_id}/resources",
+    tag = "RouteGeneration",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("plan_id" = uuid::Uuid, Path, description = "Route generation plan ID"),
+        TeardownRoutePlanQuery,
+    ),
+    responses(
+        (status = 200, body = RoutePlanTeardownView),
+        (status = 403, body = crate::error::ErrorBody),
+        (status = 404, body = crate::error::ErrorBody),
+        (status = 409, body = crate::error::ErrorBody),
+    ))]
+pub async fn teardown_route_plan(
+    State(state): State<AppState>,
+    Path((team, plan_id)): Path<(String, uuid::Uuid)>,
+    Query(query): Query<TeardownRoutePlanQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<RoutePlanTeardownView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::teardown_plan(
+            &state.pool,
+            &ctx,
+            team,
+            RouteGenerationPlanId::from(plan_id),
+            query.dry_run,
+            rid,
+        )
+        .await
+    };
+    let (plan, deleted) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(RoutePlanTeardownView {
+        plan_id: plan.id.as_uuid(),
+        api_name: plan.plan.api_name,
+        dry_run: query.dry_run,
+        deleted: deleted
+            .into_iter()
+            .map(|r| PlanResourceView {
+                kind: r.kind.as_str().into(),
+                name: r.name,
+                revision: r.version,
+            })
+            .collect(),
+    }))
+}
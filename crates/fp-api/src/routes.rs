@@ -49,18 +49,32 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
     use crate::api_lifecycle_api;
     use crate::dataplanes_api;
     use crate::discovery_api;
+    use crate::filter_presets_api;
     use crate::identity_api;
     use crate::learning_api;
-    use crate::resources::{clusters, listeners, route_configs};
+    use crate::proto_descriptors_api;
+    use crate::resources::{
+        cluster_references, clusters, listener_maintenance, listeners, route_config_virtual_hosts,
+        route_configs,
+    };
+    use crate::retry_policies_api;
     use crate::route_generation_api;
+    use crate::runtime_api;
     use crate::secrets_api;
+    use crate::wasm_modules_api;
+    use crate::webhooks_api;
     use utoipa_axum::router::OpenApiRouter;
     use utoipa_axum::routes;
 
     OpenApiRouter::with_openapi(<ApiDoc as utoipa::OpenApi>::openapi())
         .routes(routes!(whoami))
         .routes(routes!(identity_api::list_teams, identity_api::create_team))
-        .routes(routes!(identity_api::delete_team))
+        .routes(routes!(
+            identity_api::get_team,
+            identity_api::update_team,
+            identity_api::delete_team
+        ))
+        .routes(routes!(identity_api::get_quotas, identity_api::set_quotas))
         .routes(routes!(
             identity_api::list_members,
             identity_api::add_member
@@ -88,7 +102,16 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
             crate::orgs_api::add_member
         ))
         .routes(routes!(crate::orgs_api::remove_member))
+        .routes(routes!(
+            crate::orgs_api::get_default_filters,
+            crate::orgs_api::set_default_filters
+        ))
         .routes(routes!(crate::rate_limit_api::force_repush))
+        .routes(routes!(crate::diagnostics_api::diagnostics))
+        .routes(routes!(crate::diagnostics_api::webhook_dead_letters))
+        .routes(routes!(crate::xds_api::reconcile))
+        .routes(routes!(crate::validate_api::validate_filters))
+        .routes(routes!(crate::validate_api::validate_listener))
         .routes(routes!(
             crate::rate_limit_api::list_domains,
             crate::rate_limit_api::create_domain
@@ -115,18 +138,32 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
         ))
         .routes(routes!(clusters::list, clusters::create))
         .routes(routes!(clusters::get, clusters::update, clusters::delete))
+        .routes(routes!(clusters::clone))
+        .routes(routes!(clusters::transfer))
+        .routes(routes!(cluster_references::list))
         .routes(routes!(listeners::list, listeners::create))
         .routes(routes!(
             listeners::get,
             listeners::update,
             listeners::delete
         ))
+        .routes(routes!(listeners::clone))
+        .routes(routes!(listeners::transfer))
         .routes(routes!(route_configs::list, route_configs::create))
         .routes(routes!(
             route_configs::get,
             route_configs::update,
             route_configs::delete
         ))
+        .routes(routes!(route_configs::clone))
+        .routes(routes!(route_configs::transfer))
+        .routes(routes!(route_config_virtual_hosts::add))
+        .routes(routes!(route_config_virtual_hosts::remove))
+        .routes(routes!(route_config_virtual_hosts::effective_filters))
+        .routes(routes!(route_config_virtual_hosts::canary))
+        .routes(routes!(route_config_virtual_hosts::canary_promote))
+        .routes(routes!(route_config_virtual_hosts::canary_abort))
+        .routes(routes!(listener_maintenance::set))
         .routes(routes!(
             api_lifecycle_api::list_apis,
             api_lifecycle_api::create_api
@@ -151,6 +188,7 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
         ))
         .routes(routes!(learning_api::stop_learning_session))
         .routes(routes!(learning_api::create_learned_spec_version))
+        .routes(routes!(learning_api::export_learned_openapi))
         .routes(routes!(
             discovery_api::list_discovery_sessions,
             discovery_api::start_discovery_session
@@ -164,8 +202,15 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
             crate::expose_api::expose,
             crate::expose_api::unexpose
         ))
-        .routes(routes!(route_generation_api::create_route_plan))
+        .routes(routes!(
+            route_generation_api::list_route_plans,
+            route_generation_api::create_route_plan
+        ))
         .routes(routes!(route_generation_api::apply_route_plan))
+        .routes(routes!(
+            route_generation_api::route_plan_resources,
+            route_generation_api::teardown_route_plan
+        ))
         .routes(routes!(
             ai_api::list_ai_providers,
             ai_api::create_ai_provider
@@ -197,7 +242,17 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
         .routes(routes!(dataplanes_api::get_dataplane))
         .routes(routes!(dataplanes_api::record_dataplane_telemetry))
         .routes(routes!(dataplanes_api::get_envoy_config))
+        .routes(routes!(dataplanes_api::get_standalone_bootstrap))
         .routes(routes!(dataplanes_api::stats_overview))
+        .routes(routes!(dataplanes_api::list_connected_proxies))
+        .routes(routes!(dataplanes_api::list_config_versions))
+        .routes(routes!(dataplanes_api::pin_dataplane))
+        .routes(routes!(dataplanes_api::promote_dataplane))
+        .routes(routes!(
+            dataplanes_api::get_config_rollout,
+            dataplanes_api::set_config_rollout
+        ))
+        .routes(routes!(dataplanes_api::promote_config_rollout))
         .routes(routes!(
             dataplanes_api::list_proxy_certificates,
             dataplanes_api::register_proxy_certificate
@@ -208,11 +263,63 @@ fn secured_api() -> (Router<AppState>, utoipa::openapi::OpenApi) {
             secrets_api::list_secrets,
             secrets_api::create_secret
         ))
+        .routes(routes!(secrets_api::create_secrets_batch))
         .routes(routes!(secrets_api::get_secret))
         .routes(routes!(secrets_api::rotate_secret))
+        .routes(routes!(
+            proto_descriptors_api::list_proto_descriptors,
+            proto_descriptors_api::create_proto_descriptor
+        ))
+        .routes(routes!(
+            proto_descriptors_api::get_proto_descriptor,
+            proto_descriptors_api::delete_proto_descriptor
+        ))
+        .routes(routes!(
+            wasm_modules_api::list_wasm_modules,
+            wasm_modules_api::create_wasm_module
+        ))
+        .routes(routes!(wasm_modules_api::list_wasm_module_versions))
+        .routes(routes!(
+            wasm_modules_api::get_wasm_module,
+            wasm_modules_api::delete_wasm_module
+        ))
+        .routes(routes!(
+            filter_presets_api::list_filter_presets,
+            filter_presets_api::create_filter_preset
+        ))
+        .routes(routes!(
+            filter_presets_api::get_filter_preset,
+            filter_presets_api::update_filter_preset,
+            filter_presets_api::delete_filter_preset
+        ))
+        .routes(routes!(
+            retry_policies_api::list_retry_policies,
+            retry_policies_api::create_retry_policy
+        ))
+        .routes(routes!(
+            retry_policies_api::get_retry_policy,
+            retry_policies_api::update_retry_policy,
+            retry_policies_api::delete_retry_policy
+        ))
+        .routes(routes!(runtime_api::list_runtime_values))
+        .routes(routes!(
+            runtime_api::get_runtime_value,
+            runtime_api::set_runtime_value,
+            runtime_api::delete_runtime_value
+        ))
+        .routes(routes!(
+            webhooks_api::list_webhooks,
+            webhooks_api::create_webhook
+        ))
+        .routes(routes!(
+            webhooks_api::get_webhook,
+            webhooks_api::delete_webhook
+        ))
         .routes(routes!(crate::xds_api::list_nacks))
         .routes(routes!(crate::xds_api::status))
         .routes(routes!(crate::xds_api::trace))
+        .routes(routes!(crate::xds_api::cluster_outliers))
+        .routes(routes!(crate::xds_api::cluster_stats))
         .split_for_parts()
 }
 
@@ -225,19 +332,33 @@ pub fn build_router(state: AppState) -> Router {
     let (api_router, openapi) = secured_api();
     let secured = api_router
         .route("/api/v1/mcp", post(crate::mcp_api::post))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::read_cache::flush_on_write,
+        ))
         // Throttle inside auth so the PrincipalCtx is available for tenant keying.
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             crate::throttle::tenant_write_throttle,
         ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::throttle::api_rate_limit,
+        ))
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             crate::auth::authenticate,
-        ));
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::throttle::unauthenticated_rate_limit,
+        ))
+        .layer(axum::middleware::from_fn(crate::middleware::yaml_responses));
 
     let openapi = std::sync::Arc::new(openapi);
     Router::new()
         .route("/healthz", get(healthz))
+        .route("/livez", get(healthz))
         .route("/readyz", get(readyz))
         .route("/metrics", get(metrics_endpoint))
         .route(
@@ -247,6 +368,7 @@ pub fn build_router(state: AppState) -> Router {
                 async move { Json(doc.as_ref().clone()) }
             }),
         )
+        .route("/api/v1/version", get(version))
         .route("/api/v1/bootstrap/status", get(bootstrap_status))
         .route(
             "/api/v1/bootstrap/initialize",
@@ -254,6 +376,14 @@ pub fn build_router(state: AppState) -> Router {
         )
         .merge(secured)
         .fallback(not_found)
+        .layer(axum::extract::DefaultBodyLimit::max(state.max_body_bytes))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::limit_request_body,
+        ))
+        .layer(axum::middleware::from_fn(
+            crate::middleware::problem_responses,
+        ))
         .layer(axum::middleware::from_fn(request_id))
         .with_state(state)
 }
@@ -342,7 +472,9 @@ struct Health {
     version: &'static str,
 }
 
-/// Liveness: the process is up and serving. No dependencies consulted.
+/// Liveness (`/livez`, and `/healthz` for existing probes): the process is up and the router
+/// answers. Never consults a dependency, so a database or xDS outage cannot get the pod
+/// restarted — that is `/readyz`'s job.
 async fn healthz(State(state): State<AppState>) -> Json<Health> {
     Json(Health {
         status: "ok",
@@ -364,7 +496,9 @@ struct ReadyCheck {
     detail: Option<String>,
 }
 
-/// Readiness: dependencies answer. Returns 503 with per-check detail when not ready.
+/// Readiness: dependencies answer (database, and when wired: snapshot cache primed, xDS
+/// server accepting connections, outbox consumer healthy and caught up). Returns 503 with
+/// per-check detail plus a flat `reasons` list when not ready.
 async fn readyz(
     State(state): State<AppState>,
     Extension(rid): Extension<RequestId>,
@@ -376,6 +510,20 @@ async fn readyz(
         detail: db.as_ref().err().map(|e| e.message.clone()),
     }];
     if let Some(xds) = &state.xds_readiness {
+        let primed = xds.primed.load(std::sync::atomic::Ordering::SeqCst);
+        checks.push(ReadyCheck {
+            name: "xds_snapshot_cache",
+            ok: primed,
+            detail: (!primed).then(|| "snapshot cache not yet primed from the database".into()),
+        });
+        if let Some(addr) = xds.server_addr {
+            let probe = probe_xds_listener(addr).await;
+            checks.push(ReadyCheck {
+                name: "xds_server",
+                ok: probe.is_ok(),
+                detail: probe.err(),
+            });
+        }
         let failed = xds.failed.load(std::sync::atomic::Ordering::SeqCst);
         checks.push(ReadyCheck {
             name: "xds_outbox_consumer",
@@ -404,19 +552,57 @@ async fn readyz(
             checks,
         }))
     } else {
+        let reasons: Vec<String> = checks
+            .iter()
+            .filter(|c| !c.ok)
+            .map(|c| match &c.detail {
+                Some(detail) => format!("{}: {detail}", c.name),
+                None => c.name.to_string(),
+            })
+            .collect();
         Err(ApiError::new(
             DomainError::unavailable("one or more readiness checks failed")
                 .with_hint("GET /readyz returns per-check detail; see `checks`")
-                .with_details(serde_json::json!({ "checks": checks })),
+                .with_details(serde_json::json!({ "checks": checks, "reasons": reasons })),
             rid,
         ))
     }
 }
 
+/// TCP-connect to the xDS listener (a wildcard bind is probed on loopback). Only proves the
+/// socket accepts; the stream itself is dropped before any TLS or gRPC exchange.
+async fn probe_xds_listener(addr: std::net::SocketAddr) -> Result<(), String> {
+    let mut target = addr;
+    if target.ip().is_unspecified() {
+        target.set_ip(match target {
+            std::net::SocketAddr::V4(_) => std::net::Ipv4Addr::LOCALHOST.into(),
+            std::net::SocketAddr::V6(_) => std::net::Ipv6Addr::LOCALHOST.into(),
+        });
+    }
+    match tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        tokio::net::TcpStream::connect(target),
+    )
+    .await
+    {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(format!(
+            "xDS listener {addr} is not accepting connections: {e}"
+        )),
+        Err(_) => Err(format!("xDS listener {addr} did not accept within 1s")),
+    }
+}
+
 async fn metrics_endpoint(State(state): State<AppState>) -> String {
     state.prometheus.render()
 }
 
+/// Public: the running build (name, version, git commit, build time), so a deploy pipeline can
+/// assert what rolled out.
+async fn version(State(state): State<AppState>) -> Json<crate::build_info::BuildInfo> {
+    Json(crate::build_info::BuildInfo::new(state.version))
+}
+
 #[derive(Serialize)]
 struct BootstrapStatus {
     initialized: bool,
@@ -0,0 +1,159 @@
+//! Runtime value endpoints. Values are served to the team's proxies as the `flowplane` RTDS
+//! layer, so a write changes what routes and filters read by `runtime_key` without touching
+//! the config that reads them.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::resources::{resolve_team, ListQuery, Page};
+use crate::state::AppState;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use fp_core::services::runtime as svc;
+use fp_core::PrincipalCtx;
+use fp_domain::gateway::RuntimeValue;
+use fp_domain::RequestId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RuntimeValueView {
+    /// Runtime key, as named by a `runtime_key` in a route or filter.
+    pub key: String,
+    /// A number, boolean or string.
+    #[schema(value_type = Object)]
+    pub value: serde_json::Value,
+    /// Counts writes to the key; 1 when it was last created.
+    pub revision: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<RuntimeValue> for RuntimeValueView {
+    fn from(value: RuntimeValue) -> Self {
+        Self {
+            key: value.key,
+            value: value.value,
+            revision: value.version,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SetRuntimeValueBody {
+    /// A number, boolean or string.
+    #[schema(value_type = Object)]
+    pub value: serde_json::Value,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/runtime",
+    tag = "Runtime",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, body = Page<RuntimeValueView>),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_runtime_values(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<RuntimeValueView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_runtime_values(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/runtime/{key}",
+    tag = "Runtime",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("key" = String, Path, description = "Runtime key"),
+    ),
+    responses(
+        (status = 200, body = RuntimeValueView),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_runtime_value(
+    State(state): State<AppState>,
+    Path((team, key)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<RuntimeValueView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_runtime_value(&state.pool, &ctx, team, &key, rid).await
+    };
+    run.await
+        .map(|value| Json(RuntimeValueView::from(value)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(put, path = "/api/v1/teams/{team}/runtime/{key}",
+    tag = "Runtime",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("key" = String, Path, description = "Runtime key"),
+    ),
+    request_body = SetRuntimeValueBody,
+    responses(
+        (status = 200, description = "Value replaced", body = RuntimeValueView),
+        (status = 201, description = "Key created", body = RuntimeValueView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 422, body = ErrorBody),
+    ))]
+pub async fn set_runtime_value(
+    State(state): State<AppState>,
+    Path((team, key)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<SetRuntimeValueBody>,
+) -> Result<(StatusCode, Json<RuntimeValueView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::set_runtime_value(&state.pool, &ctx, team, &key, body.value, rid).await
+    };
+    let value = run.await.map_err(|e| ApiError::new(e, rid))?;
+    let status = if value.version == 1 {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, Json(RuntimeValueView::from(value))))
+}
+
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/runtime/{key}",
+    tag = "Runtime",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("key" = String, Path, description = "Runtime key"),
+    ),
+    responses(
+        (status = 204),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn delete_runtime_value(
+    State(state): State<AppState>,
+    Path((team, key)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<StatusCode, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::delete_runtime_value(&state.pool, &ctx, team, &key, rid).await
+    };
+    run.await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| ApiError::new(e, rid))
+}
@@ -24,6 +24,8 @@ pub struct SecretView {
     pub revision: i64,
     pub encryption_key_id: String,
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Set once the expiry sweep has withdrawn the secret from SDS.
+    pub expired_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
     pub value_redacted: bool,
@@ -40,6 +42,7 @@ impl From<Secret> for SecretView {
             revision: value.version,
             encryption_key_id: value.encryption_key_id,
             expires_at: value.expires_at,
+            expired_at: value.expired_at,
             created_at: value.created_at,
             updated_at: value.updated_at,
             value_redacted: true,
@@ -57,6 +60,18 @@ pub struct CreateSecretBody {
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateSecretsBatchBody {
+    pub secrets: Vec<CreateSecretBody>,
+}
+
+/// Created secrets in request order; like every secret response, metadata only.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SecretBatchView {
+    pub items: Vec<SecretView>,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RotateSecretBody {
@@ -84,12 +99,7 @@ pub async fn list_secrets(
         svc::list_secrets(&state.pool, &ctx, team, query.limit, query.offset, rid).await
     };
     let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
-    Ok(Json(Page {
-        items: items.into_iter().map(SecretView::from).collect(),
-        total,
-        limit: query.limit.clamp(1, 500),
-        offset: query.offset.max(0),
-    }))
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
 }
 
 #[utoipa::path(post, path = "/api/v1/teams/{team}/secrets",
@@ -130,6 +140,51 @@ pub async fn create_secret(
     Ok((StatusCode::CREATED, Json(SecretView::from(secret))))
 }
 
+#[utoipa::path(post, path = "/api/v1/teams/{team}/secrets:batch",
+    tag = "Secrets",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = CreateSecretsBatchBody,
+    responses(
+        (status = 201, body = SecretBatchView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+        (status = 503, body = ErrorBody),
+    ))]
+pub async fn create_secrets_batch(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<CreateSecretsBatchBody>,
+) -> Result<(StatusCode, Json<SecretBatchView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        let writes = body
+            .secrets
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                Ok(svc::SecretWrite {
+                    name: &item.name,
+                    description: &item.description,
+                    spec: parse_spec(item.spec.clone())
+                        .map_err(|e| svc::batch_item_error(e, index, &item.name))?,
+                    expires_at: item.expires_at,
+                })
+            })
+            .collect::<Result<Vec<_>, fp_domain::DomainError>>()?;
+        svc::create_secrets_batch(&state.pool, &ctx, team, writes, rid).await
+    };
+    let created = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok((
+        StatusCode::CREATED,
+        Json(SecretBatchView {
+            items: created.into_iter().map(SecretView::from).collect(),
+        }),
+    ))
+}
+
 #[utoipa::path(get, path = "/api/v1/teams/{team}/secrets/{name}",
     tag = "Secrets",
     params(
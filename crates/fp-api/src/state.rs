@@ -3,11 +3,15 @@
 use fp_core::services::discovery::DiscoveryForwardingPolicy;
 use fp_core::services::egress_advisory::EgressAdvisoryPolicy;
 use fp_core::OidcValidator;
+use fp_domain::{DomainError, DomainResult};
 use metrics_exporter_prometheus::PrometheusHandle;
 use sqlx::PgPool;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+/// Request body limit when none is configured: axum's own default, kept as the documented one.
+pub const DEFAULT_MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
 #[derive(Clone)]
 pub struct AppState {
     pub pool: PgPool,
@@ -18,6 +22,11 @@ pub struct AppState {
     pub validator: Option<Arc<OidcValidator>>,
     /// Per-tenant write throttle (spec/10 §4a edge hardening).
     pub write_throttle: Arc<crate::throttle::WriteThrottle>,
+    /// Per-principal API request limit, with a peer-IP fallback for failed authentication.
+    pub request_rate_limit: Arc<crate::throttle::RequestRateLimit>,
+    /// Largest request body accepted, in bytes (`FLOWPLANE_API_MAX_BODY_BYTES`); larger ones
+    /// get a 413 before they are buffered.
+    pub max_body_bytes: usize,
     /// Optional xDS outbox consumer readiness. API-only tests and deployments can leave this off.
     pub xds_readiness: Option<XdsReadiness>,
     /// Runtime deny policy for S9 discovery forwarding.
@@ -33,6 +42,67 @@ pub struct AppState {
     /// `rate_limit_cluster` into CDS (S6). The listener service reads this to fail closed when a
     /// `global_rate_limit` filter points at the built-in cluster but injection is off (S7).
     pub rls_grpc_configured: bool,
+    /// Emit the `x-flowplane-team` debug response header (`FLOWPLANE_DEBUG_TEAM_HEADER`).
+    pub debug_team_header: bool,
+    /// Live ADS streams on this replica, shared with the xDS server. Tests and API-only
+    /// deployments get an empty inventory.
+    pub connected_proxies: Arc<fp_domain::connected::ConnectedProxies>,
+    /// `envoy --mode validate` runner (`FLOWPLANE_ENVOY_VALIDATE_BINARY`). `None` = callers
+    /// asking for Envoy validation get a 400 naming the setting.
+    pub envoy_validator: Option<Arc<fp_xds::validate::EnvoyValidator>>,
+    /// Cache for single-resource GETs (`FLOWPLANE_READ_CACHE_TTL_SECS`); the default is
+    /// disabled.
+    pub read_cache: Arc<crate::read_cache::ReadCache>,
+    /// The xDS snapshot cache this process serves, for the admin reconcile. `None` in
+    /// API-only tests and deployments.
+    pub xds_snapshots: Option<Arc<fp_xds::snapshot::SnapshotCache>>,
+}
+
+impl AppState {
+    /// The OIDC validator, or `not_configured` when the server runs without authentication.
+    pub fn oidc_validator(&self) -> DomainResult<&Arc<OidcValidator>> {
+        configured(
+            self.validator.as_ref(),
+            "authentication",
+            "FLOWPLANE_OIDC_ISSUER/FLOWPLANE_OIDC_AUDIENCE (or dev mode)",
+        )
+    }
+
+    /// The rate-limit sync worker's wake-up handle.
+    pub fn rls_sync(&self) -> DomainResult<&tokio::sync::Notify> {
+        configured(
+            self.rls_repush.as_deref(),
+            "rate-limit sync",
+            "FLOWPLANE_RLS_ADMIN_URL",
+        )
+    }
+
+    /// The `envoy --mode validate` runner.
+    pub fn envoy_validation(&self) -> DomainResult<&fp_xds::validate::EnvoyValidator> {
+        configured(
+            self.envoy_validator.as_deref(),
+            "envoy validation",
+            "FLOWPLANE_ENVOY_VALIDATE_BINARY to an envoy binary",
+        )
+    }
+
+    /// The xDS snapshot cache. Unlike the components above it is not switched by a setting:
+    /// it is absent only when the API runs without the xDS server in the same process.
+    pub fn snapshot_cache(&self) -> DomainResult<&fp_xds::snapshot::SnapshotCache> {
+        self.xds_snapshots
+            .as_deref()
+            .ok_or_else(|| DomainError::unavailable("this process does not serve xDS"))
+    }
+}
+
+/// Optional components are switched on by configuration, so a missing one is an operator
+/// action, never a retry: it is reported as `not_configured` rather than `unavailable`.
+fn configured<'a, T: ?Sized>(
+    component: Option<&'a T>,
+    feature: &str,
+    setting: &str,
+) -> DomainResult<&'a T> {
+    component.ok_or_else(|| DomainError::not_configured(feature, setting))
 }
 
 #[derive(Clone)]
@@ -40,4 +110,10 @@ pub struct XdsReadiness {
     pub consumer: &'static str,
     pub max_lag: i64,
     pub failed: Arc<AtomicBool>,
+    /// Starts `false` and is flipped once the startup prime of the snapshot cache from the
+    /// database succeeds; the xDS listener stays closed until then.
+    pub primed: Arc<AtomicBool>,
+    /// Where the xDS gRPC server listens; `/readyz` probes it with a TCP connect. `None` when
+    /// the xDS listener is disabled (no mTLS material outside dev mode).
+    pub server_addr: Option<std::net::SocketAddr>,
 }
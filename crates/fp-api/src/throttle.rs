@@ -1,15 +1,20 @@
 //! Per-tenant write throttle (S2.6; v1 finding B11 carried forward): one tenant flooding
 //! mutations must not degrade co-tenants. Fixed one-minute windows keyed by org (fallback:
 //! user, then a shared anonymous bucket — fail closed, never unthrottled). Reads pass free.
+//!
+//! In front of it sits the API request limit: a token bucket per principal over every call,
+//! reads included, so one runaway automation token cannot exhaust the DB pool. Requests that
+//! fail authentication have no principal and are charged to the peer IP instead.
 
 use crate::error::ApiError;
-use axum::extract::{Request, State};
-use axum::http::Method;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{Method, StatusCode};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
 use fp_core::PrincipalCtx;
 use fp_domain::{DomainError, ErrorCode, RequestId};
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Mutex;
 use std::time::Instant;
 
@@ -93,11 +98,170 @@ pub async fn tenant_write_throttle(
     next.run(request).await
 }
 
+/// Token buckets holding two seconds of budget, refilled continuously at the per-second rate.
+/// A rate of `0` leaves that class of caller unlimited.
+pub struct RequestRateLimit {
+    per_sec: u32,
+    admin_per_sec: u32,
+    buckets: Mutex<HashMap<String, (Instant, f64)>>,
+}
+
+impl RequestRateLimit {
+    pub fn new(per_sec: u32, admin_per_sec: u32) -> Self {
+        Self {
+            per_sec,
+            admin_per_sec,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// No limit for anyone (tests and embedded routers).
+    pub fn disabled() -> Self {
+        Self::new(0, 0)
+    }
+
+    /// Takes one token from `key`'s bucket; `Err(retry_after_seconds)` when it is empty.
+    fn check(&self, key: &str, rate: u32) -> Result<(), u32> {
+        self.take(key, rate, 1.0)
+    }
+
+    /// Takes `cost` tokens, allowing the bucket to go into debt so that callers charged after
+    /// the fact (failed authentication) are held off until the debt is repaid.
+    fn take(&self, key: &str, rate: u32, cost: f64) -> Result<(), u32> {
+        if rate == 0 {
+            return Ok(());
+        }
+        let rate = f64::from(rate);
+        let capacity = rate * 2.0;
+        let now = Instant::now();
+        let mut buckets = match self.buckets.lock() {
+            Ok(guard) => guard,
+            // Same trade-off as the write throttle: fail open on a poisoned lock.
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if buckets.len() > 10_000 {
+            buckets.retain(|_, (refilled, _)| refilled.elapsed().as_secs() < 60);
+        }
+        let (refilled, tokens) = buckets.entry(key.to_string()).or_insert((now, capacity));
+        *tokens = (*tokens + now.duration_since(*refilled).as_secs_f64() * rate).min(capacity);
+        *refilled = now;
+        if *tokens < 1.0 {
+            return Err(((1.0 - *tokens) / rate).ceil().max(1.0) as u32);
+        }
+        *tokens -= cost;
+        Ok(())
+    }
+
+    /// Whether `key` may still make a request, without charging it.
+    fn has_budget(&self, key: &str, rate: u32) -> Result<(), u32> {
+        self.take(key, rate, 0.0)
+    }
+}
+
+fn rate_limited(request: &Request, scope: &'static str, retry_after: u32) -> Response {
+    metrics::counter!("fp_api_rate_limited_total", "scope" => scope).increment(1);
+    let rid = request
+        .extensions()
+        .get::<RequestId>()
+        .copied()
+        .unwrap_or_else(RequestId::generate);
+    ApiError::new(
+        DomainError::new(ErrorCode::RateLimited, "API request rate limit exceeded")
+            .with_hint("slow down and retry after the Retry-After interval")
+            .with_retry_after(retry_after),
+        rid,
+    )
+    .into_response()
+}
+
+/// Per-principal request limit. Runs inside `authenticate`, so every request here has a
+/// principal; platform admins draw on the admin budget.
+pub async fn api_rate_limit(
+    State(state): State<crate::state::AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let limits = &state.request_rate_limit;
+    let (key, rate) = match request.extensions().get::<PrincipalCtx>() {
+        Some(ctx @ PrincipalCtx::User { user_id, .. }) => (
+            format!("user:{user_id}"),
+            if ctx.is_platform_admin() {
+                limits.admin_per_sec
+            } else {
+                limits.per_sec
+            },
+        ),
+        Some(PrincipalCtx::Agent { agent_id, .. }) => (format!("agent:{agent_id}"), limits.per_sec),
+        None => ("anonymous".to_string(), limits.per_sec),
+    };
+    if let Err(retry_after) = limits.check(&key, rate) {
+        return rate_limited(&request, "principal", retry_after);
+    }
+    next.run(request).await
+}
+
+/// Peer-IP fallback for callers without a usable token. Runs outside `authenticate`: a
+/// request is refused while its IP has no budget left, and only a `401` is charged, so
+/// authenticated clients sharing an address (NAT, a proxy) never spend each other's budget.
+pub async fn unauthenticated_rate_limit(
+    State(state): State<crate::state::AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let limits = &state.request_rate_limit;
+    let key = match request.extensions().get::<ConnectInfo<SocketAddr>>() {
+        Some(ConnectInfo(peer)) => format!("ip:{}", peer.ip()),
+        None => "ip:unknown".to_string(),
+    };
+    if let Err(retry_after) = limits.has_budget(&key, limits.per_sec) {
+        return rate_limited(&request, "peer", retry_after);
+    }
+    let response = next.run(request).await;
+    if response.status() == StatusCode::UNAUTHORIZED {
+        // The response is already decided; the debt only gates this IP's next requests.
+        let _ = limits.take(&key, limits.per_sec, 1.0);
+    }
+    response
+}
+
 #[cfg(test)]
 #[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn request_limit_allows_a_two_second_burst_per_key() {
+        let limits = RequestRateLimit::new(2, 10);
+        for _ in 0..4 {
+            assert!(limits.check("user:a", limits.per_sec).is_ok());
+        }
+        let retry = limits
+            .check("user:a", limits.per_sec)
+            .expect_err("5th request in the burst must trip");
+        assert_eq!(retry, 1);
+        // Keys are independent, admins draw on their own larger rate, and 0 is unlimited.
+        assert!(limits.check("user:b", limits.per_sec).is_ok());
+        for _ in 0..20 {
+            assert!(limits.check("user:admin", limits.admin_per_sec).is_ok());
+        }
+        assert!(limits.check("user:admin", limits.admin_per_sec).is_err());
+        let unlimited = RequestRateLimit::disabled();
+        for _ in 0..1000 {
+            assert!(unlimited.check("user:a", unlimited.per_sec).is_ok());
+        }
+    }
+
+    #[test]
+    fn budget_probe_does_not_charge() {
+        let limits = RequestRateLimit::new(1, 1);
+        for _ in 0..10 {
+            assert!(limits.has_budget("ip:192.0.2.1", 1).is_ok());
+        }
+        limits.take("ip:192.0.2.1", 1, 1.0).unwrap();
+        limits.take("ip:192.0.2.1", 1, 1.0).unwrap();
+        assert!(limits.has_budget("ip:192.0.2.1", 1).is_err());
+    }
+
     #[test]
     fn throttle_trips_at_limit_and_isolates_keys() {
         let throttle = WriteThrottle::new(3);
@@ -0,0 +1,91 @@
+//! Dry-run validation endpoints. Nothing is persisted and nothing team-owned is read, so
+//! these need an authenticated caller but no team scope.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::state::AppState;
+use axum::extract::{Extension, State};
+use axum::Json;
+use fp_domain::gateway::filters::HttpFilterEntry;
+use fp_domain::gateway::listener::ListenerSpec;
+use fp_domain::RequestId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidateFiltersBody {
+    /// The chain exactly as it would appear in a listener spec's `http_filters`.
+    pub http_filters: Vec<HttpFilterEntry>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FilterChainValidation {
+    /// Always `true`; an invalid chain is answered with a 400 error body instead.
+    pub valid: bool,
+    /// Filter types in execution order, ending with the implicit `router`.
+    pub chain: Vec<String>,
+}
+
+/// Check an HTTP filter chain the way a listener write would, without storing anything.
+#[utoipa::path(post, path = "/api/v1/validate/filters",
+    tag = "Validation",
+    request_body = ValidateFiltersBody,
+    responses(
+        (status = 200, body = FilterChainValidation),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+    ))]
+pub async fn validate_filters(
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<ValidateFiltersBody>,
+) -> Result<Json<FilterChainValidation>, ApiError> {
+    let chain = fp_core::services::gateway::validate_filter_chain(&body.http_filters)
+        .map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(FilterChainValidation {
+        valid: true,
+        chain: chain.into_iter().map(String::from).collect(),
+    }))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidateListenerBody {
+    pub name: String,
+    /// The listener spec exactly as it would be sent to create or update.
+    pub spec: ListenerSpec,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EnvoyValidation {
+    /// Always `true`; a config Envoy rejects is answered with a 400 carrying its errors.
+    pub valid: bool,
+}
+
+/// Translate a listener the way the xDS server would and have the configured Envoy binary
+/// validate it, without storing anything.
+#[utoipa::path(post, path = "/api/v1/validate/listener",
+    tag = "Validation",
+    request_body = ValidateListenerBody,
+    responses(
+        (status = 200, body = EnvoyValidation),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 503, body = ErrorBody),
+    ))]
+pub async fn validate_listener(
+    State(state): State<AppState>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<ValidateListenerBody>,
+) -> Result<Json<EnvoyValidation>, ApiError> {
+    let run = async {
+        let validator = state.envoy_validation()?;
+        // Also refuses `preset` entries (400): only a team write instantiates them, and
+        // translation has no team to look one up in.
+        fp_core::services::gateway::validate_filter_chain(&body.spec.http_filters)?;
+        validator.validate_listener(&body.name, &body.spec).await
+    };
+    run.await
+        .map(|()| Json(EnvoyValidation { valid: true }))
+        .map_err(|e| ApiError::new(e, rid))
+}
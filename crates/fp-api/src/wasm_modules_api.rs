@@ -0,0 +1,213 @@
+//! Wasm module endpoints (wasm HTTP filter). Uploads carry the base64-encoded `.wasm` binary;
+//! responses are metadata only — `name` and `version` are what a `wasm` filter's registry
+//! source references.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::resources::{resolve_team, ListQuery, Page};
+use crate::state::AppState;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use base64::Engine as _;
+use fp_core::services::wasm_modules as svc;
+use fp_core::PrincipalCtx;
+use fp_domain::gateway::WasmModule;
+use fp_domain::{DomainError, RequestId};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WasmModuleView {
+    pub id: uuid::Uuid,
+    pub team_id: uuid::Uuid,
+    pub name: String,
+    /// Referenced, with `name`, by `wasm` filters' registry source.
+    pub version: i64,
+    /// Lowercase hex SHA-256 of the module, computed on upload.
+    pub sha256: String,
+    pub size_bytes: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WasmModule> for WasmModuleView {
+    fn from(value: WasmModule) -> Self {
+        Self {
+            id: value.id.as_uuid(),
+            team_id: value.team_id.as_uuid(),
+            name: value.name,
+            version: value.version,
+            sha256: value.sha256,
+            size_bytes: value.size_bytes,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateWasmModuleBody {
+    /// A new name starts at version 1; an existing one gets its next version.
+    pub name: String,
+    /// Base64-encoded compiled WebAssembly module (`.wasm`).
+    pub module: String,
+}
+
+fn page(items: Vec<WasmModule>, total: i64, query: &ListQuery) -> Page<WasmModuleView> {
+    Page::new(items, total, query.limit, query.offset)
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/wasm-modules",
+    tag = "Wasm modules",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, body = Page<WasmModuleView>),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_wasm_modules(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<WasmModuleView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_wasm_modules(
+            &state.pool,
+            &ctx,
+            team,
+            None,
+            query.limit,
+            query.offset,
+            rid,
+        )
+        .await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(page(items, total, &query)))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/wasm-modules",
+    tag = "Wasm modules",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = CreateWasmModuleBody,
+    responses(
+        (status = 201, body = WasmModuleView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn create_wasm_module(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<CreateWasmModuleBody>,
+) -> Result<(StatusCode, Json<WasmModuleView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(body.module.trim())
+            .map_err(|e| {
+                DomainError::validation(format!("module is not valid base64: {e}"))
+                    .with_hint("send the .wasm bytes base64-encoded (standard alphabet)")
+            })?;
+        svc::create_wasm_module(&state.pool, &ctx, team, &body.name, &bytes, rid).await
+    };
+    let module = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok((StatusCode::CREATED, Json(WasmModuleView::from(module))))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/wasm-modules/{name}",
+    tag = "Wasm modules",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Wasm module name"),
+        ListQuery,
+    ),
+    responses(
+        (status = 200, body = Page<WasmModuleView>),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_wasm_module_versions(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<WasmModuleView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_wasm_modules(
+            &state.pool,
+            &ctx,
+            team,
+            Some(&name),
+            query.limit,
+            query.offset,
+            rid,
+        )
+        .await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(page(items, total, &query)))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/wasm-modules/{name}/versions/{version}",
+    tag = "Wasm modules",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Wasm module name"),
+        ("version" = i64, Path, description = "Module version"),
+    ),
+    responses(
+        (status = 200, body = WasmModuleView),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_wasm_module(
+    State(state): State<AppState>,
+    Path((team, name, version)): Path<(String, String, i64)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<WasmModuleView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_wasm_module(&state.pool, &ctx, team, &name, version, rid).await
+    };
+    run.await
+        .map(|module| Json(WasmModuleView::from(module)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+/// Versions are immutable, so `{name}/versions/{version}` already names exactly one upload
+/// and no `If-Match` revision is needed.
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/wasm-modules/{name}/versions/{version}",
+    tag = "Wasm modules",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Wasm module name"),
+        ("version" = i64, Path, description = "Module version"),
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn delete_wasm_module(
+    State(state): State<AppState>,
+    Path((team, name, version)): Path<(String, String, i64)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<StatusCode, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::delete_wasm_module(&state.pool, &ctx, team, &name, version, rid).await
+    };
+    run.await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| ApiError::new(e, rid))
+}
@@ -0,0 +1,167 @@
+//! Team webhook subscription endpoints. Each subscription receives the team's domain events
+//! that pass its event filter, optionally signed with one of the team's generic secrets.
+
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::ApiJson;
+use crate::resources::{resolve_team, ListQuery, Page};
+use crate::state::AppState;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use fp_core::services::webhooks as svc;
+use fp_core::PrincipalCtx;
+use fp_domain::{RequestId, SecretId, WebhookSubscription, WebhookSubscriptionSpec};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookView {
+    /// Stable identifier (UUID); dead letters name it.
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub url: String,
+    /// Event filters: an exact kind (`cluster.upserted`), a family (`cluster.*`), or `*`.
+    pub events: Vec<String>,
+    /// Generic secret keying the `x-flowplane-signature-256` header; absent when unsigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookSubscription> for WebhookView {
+    fn from(value: WebhookSubscription) -> Self {
+        Self {
+            id: value.id.as_uuid(),
+            name: value.name,
+            url: value.spec.url,
+            events: value.spec.events,
+            secret_id: value.spec.secret_id.map(|id| id.as_uuid()),
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateWebhookBody {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub secret_id: Option<uuid::Uuid>,
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/webhooks",
+    tag = "Webhooks",
+    params(("team" = String, Path, description = "Team name or UUID"), ListQuery),
+    responses(
+        (status = 200, body = Page<WebhookView>),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Query(query): Query<ListQuery>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<Page<WebhookView>>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::list_subscriptions(&state.pool, &ctx, team, query.limit, query.offset, rid).await
+    };
+    let (items, total) = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
+#[utoipa::path(post, path = "/api/v1/teams/{team}/webhooks",
+    tag = "Webhooks",
+    params(("team" = String, Path, description = "Team name or UUID")),
+    request_body = CreateWebhookBody,
+    responses(
+        (status = 201, body = WebhookView),
+        (status = 400, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+        (status = 409, body = ErrorBody),
+    ))]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    Path(team): Path<String>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+    ApiJson(body): ApiJson<CreateWebhookBody>,
+) -> Result<(StatusCode, Json<WebhookView>), ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        let spec = WebhookSubscriptionSpec {
+            url: body.url,
+            events: body.events,
+            secret_id: body.secret_id.map(SecretId::from),
+        };
+        svc::create_subscription(
+            &state.pool,
+            &ctx,
+            team,
+            &body.name,
+            spec,
+            rid,
+            state.egress_advisory.clone(),
+        )
+        .await
+    };
+    let subscription = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok((StatusCode::CREATED, Json(WebhookView::from(subscription))))
+}
+
+#[utoipa::path(get, path = "/api/v1/teams/{team}/webhooks/{name}",
+    tag = "Webhooks",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Webhook name"),
+    ),
+    responses(
+        (status = 200, body = WebhookView),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn get_webhook(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<WebhookView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::get_subscription(&state.pool, &ctx, team, &name, rid).await
+    };
+    run.await
+        .map(|subscription| Json(WebhookView::from(subscription)))
+        .map_err(|e| ApiError::new(e, rid))
+}
+
+#[utoipa::path(delete, path = "/api/v1/teams/{team}/webhooks/{name}",
+    tag = "Webhooks",
+    params(
+        ("team" = String, Path, description = "Team name or UUID"),
+        ("name" = String, Path, description = "Webhook name"),
+    ),
+    responses(
+        (status = 204),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ))]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<StatusCode, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        svc::delete_subscription(&state.pool, &ctx, team, &name, rid).await
+    };
+    run.await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| ApiError::new(e, rid))
+}
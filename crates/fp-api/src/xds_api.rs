@@ -1,5 +1,7 @@
 //! xDS health surface (S5.5): per-team NACK/quarantine history. Read-only — what a
-//! dataplane rejected, when, and which resources are degraded (serving last-good bytes).
+//! dataplane rejected, when, and which resources are degraded (serving last-good bytes) —
+//! plus per-cluster outlier-ejection status where a feed exists. The one write is the admin
+//! reconcile, which rebuilds the snapshot cache from the database.
 
 use crate::error::{ApiError, ErrorBody};
 use crate::resources::resolve_team;
@@ -232,3 +234,222 @@ pub async fn trace(
             .collect(),
     }))
 }
+
+#[derive(Serialize, ToSchema)]
+pub struct ClusterOutliersView {
+    pub cluster: String,
+    pub outlier_detection_configured: bool,
+    /// `false` when no ejection feed reaches the control plane; `ejections` is then empty
+    /// because the state is unknown, not because nothing is ejected.
+    pub available: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    pub ejections: Vec<OutlierEjectionView>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct OutlierEjectionView {
+    pub address: String,
+    pub node_id: String,
+    pub reason: String,
+    pub ejected_at: chrono::DateTime<chrono::Utc>,
+}
+
+const OUTLIERS_UNAVAILABLE_NOTE: &str = "ejection state is held by each Envoy and is not \
+    reported to the control plane; query the dataplane admin /clusters endpoint";
+
+impl From<fp_core::services::outliers::ClusterOutliers> for ClusterOutliersView {
+    fn from(outliers: fp_core::services::outliers::ClusterOutliers) -> Self {
+        let available = outliers.ejections.is_some();
+        Self {
+            cluster: outliers.cluster,
+            outlier_detection_configured: outliers.outlier_detection_configured,
+            available,
+            note: (!available).then(|| OUTLIERS_UNAVAILABLE_NOTE.to_string()),
+            ejections: outliers
+                .ejections
+                .unwrap_or_default()
+                .into_iter()
+                .map(|e| OutlierEjectionView {
+                    address: e.address,
+                    node_id: e.node_id,
+                    reason: e.reason,
+                    ejected_at: e.ejected_at,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Endpoints currently ejected by outlier detection, or `available: false` with a note when
+/// no ejection feed reaches the control plane.
+#[utoipa::path(get, path = "/api/v1/teams/{team}/clusters/{name}/outliers", tag = "Clusters",
+    params(("team" = String, Path, description = "Team name or UUID"),
+           ("name" = String, Path, description = "Cluster name")),
+    responses((status = 200, body = ClusterOutliersView), (status = 404, body = ErrorBody)))]
+pub async fn cluster_outliers(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ClusterOutliersView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        fp_core::services::outliers::cluster_outliers(
+            &state.pool,
+            &ctx,
+            team,
+            &name,
+            &fp_core::services::outliers::Unavailable,
+            rid,
+        )
+        .await
+    };
+    let outliers = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(outliers.into()))
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ClusterStatsView {
+    pub cluster: String,
+    /// Gauges from each dataplane that reported the cluster in the last 60 seconds.
+    pub dataplanes: Vec<DataplaneClusterStatsView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DataplaneClusterStatsView {
+    pub dataplane: String,
+    pub dataplane_id: uuid::Uuid,
+    /// Requests in flight to the cluster.
+    pub rq_active: i64,
+    /// Requests queued waiting for a connection.
+    pub rq_pending_active: i64,
+    pub cx_active: i64,
+    /// Hosts currently ejected by outlier detection.
+    pub ejections_active: i64,
+    pub healthy_hosts: i64,
+    pub total_hosts: i64,
+    /// Tripped circuit-breaker gauges, e.g. `default.rq_open`; empty when none is open.
+    pub open_circuit_breakers: Vec<String>,
+    pub observed_at: chrono::DateTime<chrono::Utc>,
+}
+
+const CLUSTER_STATS_EMPTY_NOTE: &str = "no dataplane reported this cluster in the last 60 \
+    seconds; stats come from flowplane-agent running beside each Envoy";
+
+impl From<fp_core::services::dataplanes::ClusterStats> for ClusterStatsView {
+    fn from(stats: fp_core::services::dataplanes::ClusterStats) -> Self {
+        Self {
+            cluster: stats.cluster,
+            note: stats
+                .dataplanes
+                .is_empty()
+                .then(|| CLUSTER_STATS_EMPTY_NOTE.to_string()),
+            dataplanes: stats
+                .dataplanes
+                .into_iter()
+                .map(|entry| DataplaneClusterStatsView {
+                    dataplane: entry.dataplane,
+                    dataplane_id: entry.dataplane_id.as_uuid(),
+                    rq_active: entry.stats.rq_active,
+                    rq_pending_active: entry.stats.rq_pending_active,
+                    cx_active: entry.stats.cx_active,
+                    ejections_active: entry.stats.ejections_active,
+                    healthy_hosts: entry.stats.healthy_hosts,
+                    total_hosts: entry.stats.total_hosts,
+                    open_circuit_breakers: entry.stats.open_circuit_breakers,
+                    observed_at: entry.observed_at,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Upstream gauges for a cluster as each of the team's dataplane agents last reported them.
+#[utoipa::path(get, path = "/api/v1/teams/{team}/clusters/{name}/stats", tag = "Clusters",
+    params(("team" = String, Path, description = "Team name or UUID"),
+           ("name" = String, Path, description = "Cluster name")),
+    responses((status = 200, body = ClusterStatsView), (status = 403, body = ErrorBody),
+              (status = 404, body = ErrorBody)))]
+pub async fn cluster_stats(
+    State(state): State<AppState>,
+    Path((team, name)): Path<(String, String)>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ClusterStatsView>, ApiError> {
+    let run = async {
+        let team = resolve_team(&state, &ctx, &team).await?;
+        fp_core::services::dataplanes::cluster_stats(&state.pool, &ctx, team, &name, rid).await
+    };
+    let stats = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(stats.into()))
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ReconcileView {
+    /// Teams rebuilt: every team owning gateway resources plus every team in the cache.
+    pub teams_checked: usize,
+    /// Cached resources that disagreed with the database and were corrected.
+    pub corrected: Vec<DiscrepancyView>,
+    /// Teams whose rebuild failed; their cached config is unchanged.
+    pub failed: Vec<ReconcileFailureView>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DiscrepancyView {
+    pub team_id: uuid::Uuid,
+    pub type_url: String,
+    pub name: String,
+    /// `missing_from_cache`, `extra_in_cache`, or `outdated`.
+    pub discrepancy: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ReconcileFailureView {
+    pub team_id: uuid::Uuid,
+    pub error: String,
+}
+
+/// Rebuild every team's xDS snapshot from the database and report what the cache had wrong.
+/// Platform admin only. Safe to repeat: a consistent cache reports nothing.
+#[utoipa::path(post, path = "/api/v1/admin/reconcile", tag = "Ops",
+    responses(
+        (status = 200, body = ReconcileView),
+        (status = 401, body = ErrorBody),
+        (status = 403, body = ErrorBody),
+        (status = 503, body = ErrorBody)
+    ))]
+pub async fn reconcile(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<PrincipalCtx>,
+    Extension(rid): Extension<RequestId>,
+) -> Result<Json<ReconcileView>, ApiError> {
+    let run = async {
+        fp_core::services::xds_status::authorize_reconcile(&state.pool, &ctx, rid).await?;
+        state.snapshot_cache()?.reconcile_all(&state.pool).await
+    };
+    let report = run.await.map_err(|e| ApiError::new(e, rid))?;
+    Ok(Json(ReconcileView {
+        teams_checked: report.teams_checked,
+        corrected: report
+            .discrepancies
+            .into_iter()
+            .map(|d| DiscrepancyView {
+                team_id: d.team_id.as_uuid(),
+                type_url: d.type_url.to_string(),
+                name: d.name,
+                discrepancy: d.kind.as_str().to_string(),
+            })
+            .collect(),
+        failed: report
+            .failures
+            .into_iter()
+            .map(|(team_id, error)| ReconcileFailureView {
+                team_id: team_id.as_uuid(),
+                error,
+            })
+            .collect(),
+    }))
+}
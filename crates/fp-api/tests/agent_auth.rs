@@ -65,11 +65,18 @@ async fn app_with_admin() -> Option<(axum::Router, String, String, uuid::Uuid, P
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
     Some((app, token, team.name, team.id.as_uuid(), pool))
 }
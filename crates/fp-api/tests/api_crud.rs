@@ -111,11 +111,41 @@ fn openapi_document_covers_every_registered_operation() {
     // + 2 AI retention operations (GET/PUT).
     // + 1 RLS force-repush admin operation.
     // + 14 rate-limit CRUD operations (5 domain + 5 policy + 4 override).
+    // + 3 resource clone operations (cluster, listener, route config).
+    // + 4 proto-descriptor operations (list, create, get, delete).
+    // + 5 retry-policy operations (list, create, get, update, delete).
+    // + 1 filter-chain dry-run validation operation.
+    // + 5 filter-preset operations (list, create, get, update, delete).
+    // + 1 admin diagnostics bundle operation.
+    // + 2 org default-filters operations (get/put).
+    // + 1 cluster outlier-ejection status operation.
+    // + 1 batch secret-create operation.
+    // + 2 route-config virtual-host operations (append, delete by name).
+    // + 2 team quota operations (get/put).
+    // + 2 team operations (get/put).
+    // + 3 resource transfer operations (cluster, listener, route config).
+    // + 1 connected-proxy inventory operation.
+    // + 6 staged rollout operations (config versions, dataplane pin/promote, rollout get/put/promote).
+    // + 1 Envoy listener validation operation.
+    // + 1 standalone bootstrap operation.
+    // + 1 admin xDS reconcile operation.
+    // + 1 route effective-filters preview operation.
+    // + 1 listener maintenance-mode operation.
+    // + 3 route canary operations (shift, promote, abort).
+    // + 2 route generation plan reads (list, resources).
+    // + 1 route generation plan teardown operation.
+    // + 5 wasm-module operations (list, create, list versions, get, delete).
+    // + 1 cluster references operation.
+    // + 4 runtime value operations (list, get, set, delete).
+    // + 1 learned OpenAPI export operation.
+    // + 1 admin webhook dead-letter listing.
+    // + 4 webhook subscription operations (list, create, get, delete).
+    // + 1 cluster upstream stats operation.
     // Updating this pin is a deliberate speed bump when the surface changes: the doc IS
     // the contract.
     assert_eq!(
-        operations, 113,
-        "expected 113 documented operations, got {operations}"
+        operations, 180,
+        "expected 180 documented operations, got {operations}"
     );
     assert!(json["components"]["securitySchemes"]["bearerAuth"].is_object());
     let schemas = json["components"]["schemas"].as_object().expect("schemas");
@@ -143,6 +173,11 @@ fn openapi_document_covers_every_registered_operation() {
         "/api/v1/teams/{team}/learning-sessions/{session}",
         "/api/v1/teams/{team}/learning-sessions/{session}/stop",
         "/api/v1/teams/{team}/learning-sessions/{session}/spec-version",
+        "/api/v1/teams/{team}/learning-sessions/{session}/openapi",
+        "/api/v1/admin/webhook-dead-letters",
+        "/api/v1/teams/{team}/webhooks",
+        "/api/v1/teams/{team}/webhooks/{name}",
+        "/api/v1/teams/{team}/clusters/{name}/stats",
         "/api/v1/teams/{team}/learning-discovery-sessions",
         "/api/v1/teams/{team}/learning-discovery-sessions/{session}",
         "/api/v1/teams/{team}/learning-discovery-sessions/{session}/stop",
@@ -174,6 +209,26 @@ fn grants_list_documents_forbidden_response() {
     }
 }
 
+#[test]
+fn resource_reads_document_yaml_responses() {
+    let doc = fp_api::routes::openapi_document();
+    let json = serde_json::to_value(&doc).expect("doc");
+    for kind in ["clusters", "listeners", "route-configs", "filter-presets"] {
+        for path in [
+            format!("/api/v1/teams/{{team}}/{kind}"),
+            format!("/api/v1/teams/{{team}}/{kind}/{{name}}"),
+        ] {
+            let content = &json["paths"][&path]["get"]["responses"]["200"]["content"];
+            for media in ["application/json", "application/yaml"] {
+                assert!(
+                    content[media].is_object(),
+                    "GET {path} must document {media}, got {content}"
+                );
+            }
+        }
+    }
+}
+
 #[tokio::test]
 async fn learning_session_lifecycle_over_http() {
     let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
@@ -213,11 +268,18 @@ async fn learning_session_lifecycle_over_http() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let request =
@@ -302,6 +364,7 @@ async fn learning_session_lifecycle_over_http() {
     assert_eq!(response.status(), StatusCode::OK);
     let body = json_of(response).await;
     assert_eq!(body["total"], 1);
+    assert_eq!(body["has_more"], false);
 
     let response = app
         .clone()
@@ -368,11 +431,18 @@ async fn api_definition_import_status_and_delete_over_http() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let request =
@@ -496,11 +566,18 @@ async fn full_crud_journey_over_http_with_bearer_auth() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let request =
@@ -789,6 +866,61 @@ async fn full_crud_journey_over_http_with_bearer_auth() {
         "request_headers"
     );
 
+    // Incremental virtual-host edits: append then delete-by-name restores the original spec.
+    let original_spec = body["spec"].clone();
+    let original_version = body["revision"].as_i64().expect("version");
+    let vhosts = format!("{route_base}/{rc_name}/virtual-hosts");
+    let internal = serde_json::json!({
+        "name": "internal",
+        "domains": ["internal.example.test"],
+        "routes": [{"name": "all", "match": {"prefix": {"prefix": "/"}}, "action": {"cluster": primary}}]
+    });
+    let response = app
+        .clone()
+        .oneshot(request("POST", &vhosts, Some(internal), None))
+        .await
+        .expect("append virtual host");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = json_of(response).await;
+    assert_eq!(body["spec"]["virtual_hosts"][1]["name"], "internal");
+    assert_eq!(body["revision"].as_i64(), Some(original_version + 1));
+    let overlapping = serde_json::json!({
+        "name": "shadow",
+        "domains": ["API.example.test"],
+        "routes": [{"name": "all", "match": {"prefix": {"prefix": "/"}}, "action": {"cluster": primary}}]
+    });
+    let response = app
+        .clone()
+        .oneshot(request("POST", &vhosts, Some(overlapping), None))
+        .await
+        .expect("append overlapping virtual host");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "DELETE",
+            &format!("{vhosts}/internal"),
+            None,
+            Some(original_version),
+        ))
+        .await
+        .expect("delete virtual host at a stale revision");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let response = app
+        .clone()
+        .oneshot(request("DELETE", &format!("{vhosts}/internal"), None, None))
+        .await
+        .expect("delete virtual host");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert_eq!(body["spec"], original_spec);
+    let response = app
+        .clone()
+        .oneshot(request("DELETE", &format!("{vhosts}/internal"), None, None))
+        .await
+        .expect("delete missing virtual host");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
     let listener_name = unique("edge");
     let listener_base = format!("/api/v1/teams/{}/listeners", team.name);
     let listener_spec = serde_json::json!({
@@ -850,6 +982,317 @@ async fn full_crud_journey_over_http_with_bearer_auth() {
         body["spec"]["http_filters"][0]["filter"]["service_cluster"],
         rls
     );
+
+    // Maintenance mode is toggled beside the spec, never inside it.
+    let maintenance = format!("{listener_base}/{listener_name}/maintenance");
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &maintenance,
+            Some(serde_json::json!({"enabled": true})),
+            None,
+        ))
+        .await
+        .expect("enable maintenance");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert_eq!(body["maintenance"]["status"], 503);
+    assert_eq!(body["spec"]["route_config"], rc_name);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &maintenance,
+            Some(serde_json::json!({"enabled": true, "status": 404})),
+            None,
+        ))
+        .await
+        .expect("maintenance with a client-error status");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &maintenance,
+            Some(serde_json::json!({"enabled": false})),
+            None,
+        ))
+        .await
+        .expect("disable maintenance");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert!(body["maintenance"].is_null());
+
+    // The route's effective filters, per listener serving its route config.
+    let effective = format!("{route_base}/{rc_name}/virtual-hosts/default/routes");
+    let response = app
+        .clone()
+        .oneshot(request(
+            "GET",
+            &format!("{effective}/items/effective-filters"),
+            None,
+            None,
+        ))
+        .await
+        .expect("preview effective filters");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert_eq!(body["listeners"][0]["listener"], listener_name);
+    let filter = &body["listeners"][0]["filters"][0];
+    assert_eq!(filter["filter_type"], "global_rate_limit");
+    assert_eq!(filter["enabled"], true);
+    assert_eq!(filter["source"], "listener");
+    let response = app
+        .clone()
+        .oneshot(request(
+            "GET",
+            &format!("{effective}/missing/effective-filters"),
+            None,
+            None,
+        ))
+        .await
+        .expect("preview effective filters of a missing route");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Canary shifts rewrite the route's weighted split; promote and abort collapse it.
+    let items = format!("{effective}/items");
+    let split = |weight: u32| serde_json::json!({"stable_cluster": primary, "canary_cluster": canary, "canary_weight": weight});
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{items}/canary"),
+            Some(split(30)),
+            None,
+        ))
+        .await
+        .expect("shift canary");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    let action = &body["spec"]["virtual_hosts"][0]["routes"][0]["action"];
+    assert_eq!(action["weighted_clusters"][0]["weight"], 70);
+    assert_eq!(action["weighted_clusters"][1]["cluster"], canary);
+    assert_eq!(action["weighted_clusters"][1]["weight"], 30);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{items}/canary"),
+            Some(split(101)),
+            None,
+        ))
+        .await
+        .expect("shift canary past 100");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{items}/canary:promote"),
+            None,
+            None,
+        ))
+        .await
+        .expect("promote canary");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    let action = &body["spec"]["virtual_hosts"][0]["routes"][0]["action"];
+    assert_eq!(action["cluster"], canary);
+    assert!(action["weighted_clusters"].is_null());
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{items}/canary:abort"),
+            None,
+            None,
+        ))
+        .await
+        .expect("abort without a split");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{items}/canary"),
+            Some(split(20)),
+            None,
+        ))
+        .await
+        .expect("restart canary");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{items}/canary:abort"),
+            None,
+            None,
+        ))
+        .await
+        .expect("abort canary");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert_eq!(
+        body["spec"]["virtual_hosts"][0]["routes"][0]["action"]["cluster"],
+        primary
+    );
+
+    // A known-bad filter order is a 400 unless the caller asks for auto-ordering, in which
+    // case the created listener shows the final order.
+    let ordered_spec = serde_json::json!({
+        "address": "0.0.0.0",
+        "port": 18082,
+        "http_filters": [
+            {"filter": {
+                "type": "rbac",
+                "action": "allow",
+                "policies": {"office": {
+                    "permissions": [{"kind": "any"}],
+                    "principals": [{"kind": "source_cidr", "cidr": "10.0.0.0/8"}]
+                }}
+            }},
+            {"filter": {"type": "cors", "allow_origin": [{"match": "exact", "value": "https://app.example"}]}}
+        ]
+    });
+    let ordered_name = unique("ordered");
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &listener_base,
+            Some(serde_json::json!({"name": ordered_name, "spec": ordered_spec})),
+            None,
+        ))
+        .await
+        .expect("create misordered listener");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = json_of(response).await;
+    assert_eq!(
+        body["message"],
+        "filter \"cors\" must come before \"rbac\" in the chain"
+    );
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("{listener_base}?auto_order=true"),
+            Some(serde_json::json!({"name": ordered_name, "spec": ordered_spec})),
+            None,
+        ))
+        .await
+        .expect("create auto-ordered listener");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = json_of(response).await;
+    assert_eq!(body["spec"]["http_filters"][0]["filter"]["type"], "cors");
+    assert_eq!(body["spec"]["http_filters"][1]["filter"]["type"], "rbac");
+
+    // Team CRUD: create with an admin port, read, replace, and a taken port is a 409.
+    let ops_team = unique("ops");
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            "/api/v1/teams",
+            Some(serde_json::json!({
+                "name": ops_team,
+                "description": "operations",
+                "envoy_admin_port": 9902
+            })),
+            None,
+        ))
+        .await
+        .expect("create team");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = json_of(response).await;
+    assert_eq!(body["envoy_admin_port"], 9902);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "PUT",
+            &format!("/api/v1/teams/{}", team.name),
+            Some(serde_json::json!({"display_name": "Crud", "envoy_admin_port": 9902})),
+            None,
+        ))
+        .await
+        .expect("taken admin port");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "PUT",
+            &format!("/api/v1/teams/{ops_team}"),
+            Some(
+                serde_json::json!({"description": "platform operations", "envoy_admin_port": 9903}),
+            ),
+            None,
+        ))
+        .await
+        .expect("update team");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "GET",
+            &format!("/api/v1/teams/{ops_team}"),
+            None,
+            None,
+        ))
+        .await
+        .expect("get team");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert_eq!(body["description"], "platform operations");
+    assert_eq!(body["envoy_admin_port"], 9903);
+    assert!(body["envoy_admin_access"].is_null());
+    let response = app
+        .clone()
+        .oneshot(request(
+            "PUT",
+            &format!("/api/v1/teams/{ops_team}"),
+            Some(serde_json::json!({"envoy_admin_access": "socket"})),
+            None,
+        ))
+        .await
+        .expect("lock down admin");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    assert_eq!(body["envoy_admin_access"], "socket");
+    let response = app
+        .clone()
+        .oneshot(request(
+            "PUT",
+            &format!("/api/v1/teams/{ops_team}"),
+            Some(serde_json::json!({"envoy_admin_access": "public"})),
+            None,
+        ))
+        .await
+        .expect("unknown admin access");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    // A team that still owns resources cannot be deleted; an empty one can.
+    let response = app
+        .clone()
+        .oneshot(request(
+            "DELETE",
+            &format!("/api/v1/teams/{}", team.name),
+            None,
+            None,
+        ))
+        .await
+        .expect("delete busy team");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let response = app
+        .clone()
+        .oneshot(request(
+            "DELETE",
+            &format!("/api/v1/teams/{ops_team}"),
+            None,
+            None,
+        ))
+        .await
+        .expect("delete empty team");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
 }
 
 #[tokio::test]
@@ -897,11 +1340,18 @@ async fn multi_org_user_selects_active_org_with_header() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let response = app
@@ -991,17 +1441,37 @@ async fn proxy_certificate_registry_flow_over_http() {
         .await
         .expect("member");
 
+    let connected = fp_domain::connected::ConnectedProxies::new();
+    let _stream = connected.register(fp_domain::connected::ProxyConnection {
+        node_id: "edge-node".into(),
+        team_id: team.id,
+        dataplane_id: None,
+        envoy_version: Some("envoy/1.31.2".into()),
+    });
+    let _other_team_stream = connected.register(fp_domain::connected::ProxyConnection {
+        node_id: "other-node".into(),
+        team_id: fp_domain::TeamId::generate(),
+        dataplane_id: None,
+        envoy_version: None,
+    });
     let app = fp_api::build_router(fp_api::AppState {
         pool,
         prometheus: PrometheusBuilder::new().build_recorder().handle(),
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: connected,
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let request = |method: &str, path: &str, body: Option<serde_json::Value>| {
@@ -1088,6 +1558,23 @@ async fn proxy_certificate_registry_flow_over_http() {
     assert_eq!(body["live_dataplanes"], 1);
     assert_eq!(body["total_requests"], 10);
 
+    let response = app
+        .clone()
+        .oneshot(request(
+            "GET",
+            &format!("/api/v1/teams/{}/connected-proxies", team.name),
+            None,
+        ))
+        .await
+        .expect("connected proxies");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_of(response).await;
+    let items = body["items"].as_array().expect("items");
+    assert_eq!(items.len(), 1, "only the team's own streams are listed");
+    assert_eq!(items[0]["node_id"], "edge-node");
+    assert_eq!(items[0]["envoy_version"], "envoy/1.31.2");
+    assert!(items[0]["dataplane_id"].is_null());
+
     let config_path = format!(
         "{dataplanes}/{dataplane}/envoy-config?cert_path=/certs/client.crt&key_path=/certs/client.key&ca_path=/certs/ca.crt&xds_host=cp.local&xds_port=18000"
     );
@@ -1144,6 +1631,82 @@ async fn proxy_certificate_registry_flow_over_http() {
     assert!(!body.contains("transport_socket:"));
     assert!(!body.contains("filename:"));
 
+    let standalone = |query: String| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(request("GET", &format!("/api/v1/bootstrap?{query}"), None))
+                .await
+                .expect("standalone bootstrap");
+            let status = response.status();
+            let body = response
+                .into_body()
+                .collect()
+                .await
+                .expect("body")
+                .to_bytes();
+            (status, String::from_utf8(body.to_vec()).expect("utf8"))
+        }
+    };
+    let (status, body) = standalone(format!(
+        "node_id=laptop&cluster=scratch&team={}&mode=dev&xds_host=127.0.0.1",
+        team.name
+    ))
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&body).expect("standalone YAML");
+    assert_eq!(
+        yaml["node"]["id"].as_str(),
+        Some(format!("team={}/laptop", team.id.as_uuid()).as_str())
+    );
+    assert_eq!(yaml["node"]["cluster"].as_str(), Some("scratch"));
+    assert!(!body.contains("transport_socket:"));
+    let (status, body) = standalone(
+        "node_id=laptop&cluster=scratch&cert_path=/c.crt&key_path=/c.key&ca_path=/ca.crt".into(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&body).expect("standalone YAML");
+    assert_eq!(yaml["node"]["id"].as_str(), Some("laptop"));
+    assert!(yaml["node"]["metadata"].is_null());
+    assert!(body.contains("filename: \"/ca.crt\""));
+    let (status, _) = standalone(format!(
+        "node_id=team={}/laptop&cluster=scratch&mode=dev",
+        team.id.as_uuid()
+    ))
+    .await;
+    assert_eq!(
+        status,
+        StatusCode::BAD_REQUEST,
+        "team goes in its own parameter"
+    );
+    let (status, body) = standalone(
+        "node_id=laptop&cluster=scratch&mode=dev&admin_access=socket&admin_socket_path=/run/envoy/admin.sock"
+            .into(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&body).expect("standalone YAML");
+    assert_eq!(
+        yaml["admin"]["address"]["pipe"]["path"].as_str(),
+        Some("/run/envoy/admin.sock")
+    );
+    assert!(yaml["admin"]["address"]["socket_address"].is_null());
+    let (status, body) =
+        standalone("node_id=laptop&cluster=scratch&mode=dev&admin_access=disabled".into()).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&body).expect("standalone YAML");
+    assert!(yaml["admin"].is_null());
+    let (status, _) = standalone(
+        "node_id=laptop&cluster=scratch&mode=dev&admin_socket_path=/run/envoy/admin.sock".into(),
+    )
+    .await;
+    assert_eq!(
+        status,
+        StatusCode::BAD_REQUEST,
+        "a socket path needs admin_access=socket"
+    );
+
     let certs = format!("/api/v1/teams/{}/proxy-certificates", team.name);
     let (ca_cert_path, ca_key_path) = write_test_ca("issue");
     std::env::set_var("FLOWPLANE_CERT_ISSUER_CA_CERT_PATH", &ca_cert_path);
@@ -1305,11 +1868,18 @@ async fn secret_values_are_write_only_over_http() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let request = |method: &str, path: &str, body: Option<serde_json::Value>| {
@@ -1383,6 +1953,71 @@ async fn secret_values_are_write_only_over_http() {
         .iter()
         .any(|secret| secret["name"] == name && secret["value_redacted"] == true));
 
+    let batch = format!("{base}:batch");
+    let (first, second) = (unique("batch-a"), unique("batch-b"));
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &batch,
+            Some(serde_json::json!({"secrets": [
+                {"name": first, "spec": {"type": "generic_secret", "secret": "YQ=="}},
+                {"name": second, "spec": {"type": "generic_secret", "secret": "Yg=="}}
+            ]})),
+        ))
+        .await
+        .expect("create secret batch");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = json_of(response).await;
+    let items = body["items"].as_array().expect("items");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["name"], first.as_str());
+    assert!(items
+        .iter()
+        .all(|secret| secret["value_redacted"] == true && secret.get("spec").is_none()));
+
+    // One invalid item rejects the whole batch, whether it fails validation up front or
+    // collides with an existing secret after earlier items were already inserted.
+    for (bad, status, index) in [
+        (
+            serde_json::json!({"name": "Not Valid", "spec": {"type": "generic_secret", "secret": "Yw=="}}),
+            StatusCode::BAD_REQUEST,
+            1,
+        ),
+        (
+            serde_json::json!({"name": first, "spec": {"type": "generic_secret", "secret": "Yw=="}}),
+            StatusCode::CONFLICT,
+            1,
+        ),
+    ] {
+        let survivor = unique("batch-c");
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                &batch,
+                Some(serde_json::json!({"secrets": [
+                    {"name": survivor, "spec": {"type": "generic_secret", "secret": "Yw=="}},
+                    bad
+                ]})),
+            ))
+            .await
+            .expect("rejected secret batch");
+        assert_eq!(response.status(), status);
+        let body = json_of(response).await;
+        assert_eq!(body["details"]["index"], index);
+        assert!(
+            !body.to_string().contains("Yw=="),
+            "error echoed a value: {body}"
+        );
+        let response = app
+            .clone()
+            .oneshot(request("GET", &format!("{base}/{survivor}"), None))
+            .await
+            .expect("get rolled-back secret");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     let response = app
         .clone()
         .oneshot(request_with_revision(
@@ -1799,11 +2434,18 @@ async fn malformed_json_body_returns_validation_envelope() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     // `port` typed as a string -> JSON deserialization failure.
@@ -1826,6 +2468,169 @@ async fn malformed_json_body_returns_validation_envelope() {
     );
 }
 
+#[tokio::test]
+async fn yaml_request_bodies_and_responses_over_http() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 4).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+
+    let issuer = DevIssuer::generate().expect("issuer");
+    let validator = fp_core::OidcValidator::new(issuer.oidc_config());
+    validator
+        .load_jwks_json(issuer.jwks_json())
+        .await
+        .expect("jwks");
+    let subject = unique("sub");
+    let token = issuer
+        .mint(&subject, "yaml@test", "Yaml", 600)
+        .expect("mint");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let team = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let user = identity::upsert_user_by_subject(&pool, &subject, "yaml@test", "Yaml")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user, org.id, OrgRole::Admin)
+        .await
+        .expect("member");
+
+    let app = fp_api::build_router(fp_api::AppState {
+        pool,
+        prometheus: PrometheusBuilder::new().build_recorder().handle(),
+        version: "test",
+        validator: Some(std::sync::Arc::new(validator)),
+        write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
+        xds_readiness: None,
+        discovery_forwarding_policy: Default::default(),
+        egress_advisory: Default::default(),
+        rls_repush: None,
+        rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
+    });
+    let send = |method: &str, uri: String, content_type: &str, accept: &str, body: &str| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", format!("Bearer {token}"))
+            .header("content-type", content_type)
+            .header("accept", accept)
+            .body(Body::from(body.to_string()))
+            .expect("request")
+    };
+    let clusters = format!("/api/v1/teams/{}/clusters", team.name);
+
+    // A YAML body deserializes into the same request type; the response stays JSON.
+    let cluster = unique("web");
+    let yaml =
+        format!("name: {cluster}\nspec:\n  endpoints:\n    - host: 10.0.0.1\n      port: 8080\n");
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            clusters.clone(),
+            "application/yaml",
+            "*/*",
+            &yaml,
+        ))
+        .await
+        .expect("send");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = json_of(response).await;
+    assert_eq!(created["spec"]["endpoints"][0]["port"], 8080);
+
+    // Accept: application/yaml renders the JSON view as YAML.
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{clusters}/{cluster}"),
+            "application/json",
+            "application/yaml",
+            "",
+        ))
+        .await
+        .expect("send");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/yaml");
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    let fetched: serde_json::Value = serde_yaml::from_slice(&bytes).expect("yaml view");
+    assert_eq!(fetched["name"], cluster.as_str());
+    assert_eq!(fetched["revision"], created["revision"]);
+
+    // Lists negotiate the same way; without a YAML Accept the default stays JSON, and
+    // either rendering names Accept in Vary.
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            clusters.clone(),
+            "application/json",
+            "text/yaml",
+            "",
+        ))
+        .await
+        .expect("send");
+    assert_eq!(response.headers()["content-type"], "application/yaml");
+    assert_eq!(response.headers()["vary"], "accept");
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    let page: serde_json::Value = serde_yaml::from_slice(&bytes).expect("yaml page");
+    assert_eq!(page["items"][0]["name"], cluster.as_str());
+    for accept in ["application/json", "*/*"] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "GET",
+                clusters.clone(),
+                "application/json",
+                accept,
+                "",
+            ))
+            .await
+            .expect("send");
+        assert_eq!(response.headers()["content-type"], "application/json");
+        assert_eq!(response.headers()["vary"], "accept");
+        assert_eq!(json_of(response).await["total"], 1);
+    }
+
+    // A YAML body that does not fit the type is the usual envelope, rendered as YAML here.
+    let response = app
+        .oneshot(send(
+            "POST",
+            clusters,
+            "application/yaml",
+            "application/yaml",
+            "name: x\nspec:\n  endpoints:\n    - host: 10.0.0.1\n      port: oops\n",
+        ))
+        .await
+        .expect("send");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    let envelope: serde_json::Value = serde_yaml::from_slice(&bytes).expect("yaml envelope");
+    assert_eq!(envelope["code"], "validation_failed");
+    assert!(envelope["message"]
+        .as_str()
+        .unwrap_or_default()
+        .starts_with("Failed to deserialize the YAML body"));
+}
+
 // Slice s4 (ai-gateway-e2e-trace): team-scoped AI trace retrieval over HTTP through the
 // real middleware stack — correlated hop timeline on a hit, a distinguishable miss with
 // the never-traced-classes hint, cross-org 404, and missing-grant 403.
@@ -1933,11 +2738,18 @@ async fn ai_trace_retrieval_over_http() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
     let request = |token: &str, path: &str| {
         Request::builder()
@@ -2170,11 +2982,18 @@ async fn ai_retention_crud_authz_and_audit_over_http() {
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
     let path = format!("/api/v1/teams/{}/ai/retention", team.name);
     let get = |token: &str| {
@@ -0,0 +1,129 @@
+//! `x-flowplane-team` debug header (`FLOWPLANE_DEBUG_TEAM_HEADER`): when enabled, a
+//! team-scoped request echoes the team it resolved to, that team's org, and the caller's
+//! scopes on it; when disabled (the default) the header is never sent.
+//!
+//! Skipped (with a notice) when FLOWPLANE_TEST_DATABASE_URL is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use fp_domain::authz::{Action, Resource};
+use fp_domain::OrgRole;
+use fp_storage::repos::identity;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn unique(prefix: &str) -> String {
+    format!("{prefix}-{}", &Uuid::now_v7().simple().to_string()[20..])
+}
+
+/// Router with the debug header switched `on`, plus a member token holding
+/// `clusters:read` on a fresh team. Returns (app, token, team name, team id, org id).
+async fn setup(on: bool) -> Option<(axum::Router, String, String, Uuid, Uuid)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 4).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+
+    let issuer = fp_core::dev::DevIssuer::generate().expect("issuer");
+    let validator = fp_core::OidcValidator::new(issuer.oidc_config());
+    validator
+        .load_jwks_json(issuer.jwks_json())
+        .await
+        .expect("jwks");
+
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let team = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let subject = unique("sub");
+    let user = identity::upsert_user_by_subject(&pool, &subject, "member@test", "Member")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user, org.id, OrgRole::Member)
+        .await
+        .expect("membership");
+    identity::add_grant(
+        &pool,
+        user,
+        org.id,
+        team.id,
+        Resource::Clusters,
+        Action::Read,
+        None,
+    )
+    .await
+    .expect("grant");
+    let token = issuer
+        .mint(&subject, "member@test", "Member", 600)
+        .expect("mint");
+
+    let app = fp_api::build_router(fp_api::AppState {
+        pool,
+        prometheus: PrometheusBuilder::new().build_recorder().handle(),
+        version: "test",
+        validator: Some(std::sync::Arc::new(validator)),
+        write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
+        xds_readiness: None,
+        discovery_forwarding_policy: Default::default(),
+        egress_advisory: Default::default(),
+        rls_repush: None,
+        rls_grpc_configured: false,
+        debug_team_header: on,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
+    });
+    Some((app, token, team.name, team.id.as_uuid(), org.id.as_uuid()))
+}
+
+async fn list_clusters(app: axum::Router, token: &str, team: &str) -> axum::response::Response {
+    app.oneshot(
+        Request::get(format!("/api/v1/teams/{team}/clusters"))
+            .header("authorization", format!("Bearer {token}"))
+            .body(Body::empty())
+            .expect("request"),
+    )
+    .await
+    .expect("response")
+}
+
+#[tokio::test]
+async fn enabled_header_reflects_the_resolved_team_org_and_scopes() {
+    let Some((app, token, team_name, team_id, org_id)) = setup(true).await else {
+        return;
+    };
+    let response = list_clusters(app, &token, &team_name).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let header = response
+        .headers()
+        .get(fp_api::auth::TEAM_DEBUG_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .expect("debug header present");
+    assert_eq!(
+        header,
+        format!("team={team_id}; org={org_id}; scopes=org:member,clusters:read")
+    );
+}
+
+#[tokio::test]
+async fn header_is_absent_by_default() {
+    let Some((app, token, team_name, _, _)) = setup(false).await else {
+        return;
+    };
+    let response = list_clusters(app, &token, &team_name).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response
+        .headers()
+        .get(fp_api::auth::TEAM_DEBUG_HEADER)
+        .is_none());
+}
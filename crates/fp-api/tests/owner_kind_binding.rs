@@ -77,9 +77,11 @@ async fn posting_user_route_config_referencing_ai_cluster_is_a_400_validation_en
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     };
     let mut tx = pool.begin().await.expect("begin");
     fp_storage::repos::clusters::create_ai_owned(
@@ -100,11 +102,18 @@ async fn posting_user_route_config_referencing_ai_cluster_is_a_400_validation_en
         version: "test",
         validator: Some(std::sync::Arc::new(validator)),
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
         xds_readiness: None,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     });
 
     let rc_name = unique("rc");
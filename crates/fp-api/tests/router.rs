@@ -18,6 +18,19 @@ async fn test_app() -> Option<axum::Router> {
 
 async fn test_app_with_xds_readiness(
     xds_readiness: Option<fp_api::state::XdsReadiness>,
+) -> Option<axum::Router> {
+    test_app_with(
+        xds_readiness,
+        fp_api::throttle::RequestRateLimit::disabled(),
+        fp_api::state::DEFAULT_MAX_BODY_BYTES,
+    )
+    .await
+}
+
+async fn test_app_with(
+    xds_readiness: Option<fp_api::state::XdsReadiness>,
+    request_rate_limit: fp_api::throttle::RequestRateLimit,
+    max_body_bytes: usize,
 ) -> Option<axum::Router> {
     let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
         eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
@@ -35,11 +48,18 @@ async fn test_app_with_xds_readiness(
         version: "test",
         validator: None,
         write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(120)),
+        request_rate_limit: std::sync::Arc::new(request_rate_limit),
+        max_body_bytes,
         xds_readiness,
         discovery_forwarding_policy: Default::default(),
         egress_advisory: Default::default(),
         rls_repush: None,
         rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: None,
+        read_cache: Default::default(),
+        xds_snapshots: None,
     }))
 }
 
@@ -70,6 +90,36 @@ async fn healthz_reports_ok_and_version() {
     assert_eq!(json["version"], "test");
 }
 
+#[tokio::test]
+async fn livez_reports_ok_without_consulting_dependencies() {
+    let Some(app) = test_app().await else { return };
+    let response = app
+        .oneshot(Request::get("/livez").body(Body::empty()).expect("request"))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_json(response).await["status"], "ok");
+}
+
+#[tokio::test]
+async fn version_reports_build_provenance_without_auth() {
+    let Some(app) = test_app().await else { return };
+    let response = app
+        .oneshot(
+            Request::get("/api/v1/version")
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = body_json(response).await;
+    assert_eq!(json["name"], "flowplane");
+    assert_eq!(json["version"], "test");
+    assert_eq!(json["git_commit"], fp_api::build_info::GIT_COMMIT);
+    assert!(json["build_timestamp"].as_str().is_some(), "{json}");
+}
+
 #[tokio::test]
 async fn readyz_passes_with_live_database() {
     let Some(app) = test_app().await else { return };
@@ -95,6 +145,8 @@ async fn readyz_fails_when_xds_consumer_failed() {
         consumer: "test-xds",
         max_lag: 0,
         failed,
+        primed: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true)),
+        server_addr: None,
     }))
     .await
     else {
@@ -118,6 +170,143 @@ async fn readyz_fails_when_xds_consumer_failed() {
     }));
 }
 
+#[tokio::test]
+async fn readyz_lists_reasons_when_xds_server_not_accepting() {
+    // Bind then drop to get a loopback port nothing is listening on.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .expect("bind")
+        .local_addr()
+        .expect("addr");
+    let Some(app) = test_app_with_xds_readiness(Some(fp_api::state::XdsReadiness {
+        consumer: "test-xds",
+        max_lag: i64::MAX,
+        failed: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        primed: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        server_addr: Some(addr),
+    }))
+    .await
+    else {
+        return;
+    };
+    let response = app
+        .oneshot(
+            Request::get("/readyz")
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let json = body_json(response).await;
+    let reasons: Vec<&str> = json["details"]["reasons"]
+        .as_array()
+        .expect("reasons")
+        .iter()
+        .map(|r| r.as_str().expect("reason is a string"))
+        .collect();
+    assert_eq!(reasons.len(), 2, "{reasons:?}");
+    assert!(
+        reasons[0].starts_with("xds_snapshot_cache: "),
+        "{reasons:?}"
+    );
+    assert!(reasons[1].starts_with("xds_server: "), "{reasons:?}");
+}
+
+#[tokio::test]
+async fn readyz_flips_to_ready_once_the_snapshot_cache_is_primed() {
+    let primed = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let Some(app) = test_app_with_xds_readiness(Some(fp_api::state::XdsReadiness {
+        consumer: "test-xds",
+        max_lag: i64::MAX,
+        failed: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        primed: primed.clone(),
+        server_addr: None,
+    }))
+    .await
+    else {
+        return;
+    };
+    let readyz = || {
+        Request::get("/readyz")
+            .body(Body::empty())
+            .expect("request")
+    };
+    let response = app.clone().oneshot(readyz()).await.expect("response");
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let json = body_json(response).await;
+    assert_eq!(
+        json["details"]["reasons"],
+        serde_json::json!(["xds_snapshot_cache: snapshot cache not yet primed from the database"])
+    );
+
+    primed.store(true, std::sync::atomic::Ordering::SeqCst);
+    let response = app.oneshot(readyz()).await.expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn repeated_failed_authentication_from_one_peer_is_rate_limited() {
+    let Some(app) = test_app_with(
+        None,
+        fp_api::throttle::RequestRateLimit::new(1, 1),
+        fp_api::state::DEFAULT_MAX_BODY_BYTES,
+    )
+    .await
+    else {
+        return;
+    };
+    let whoami = || {
+        Request::get("/api/v1/auth/whoami")
+            .body(Body::empty())
+            .expect("request")
+    };
+    // One token per second with a two-second burst: two 401s spend the peer's budget.
+    for _ in 0..2 {
+        let response = app.clone().oneshot(whoami()).await.expect("response");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let response = app.oneshot(whoami()).await.expect("response");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().get("retry-after").is_some());
+    let json = body_json(response).await;
+    assert_eq!(json["code"], "rate_limited");
+}
+
+#[tokio::test]
+async fn oversized_request_body_is_refused_with_413_before_it_is_read() {
+    let Some(app) = test_app_with(None, fp_api::throttle::RequestRateLimit::disabled(), 1024).await
+    else {
+        return;
+    };
+    let response = app
+        .oneshot(
+            Request::post("/api/v1/teams/payments/clusters")
+                .header("content-type", "application/json")
+                .header("content-length", "4096")
+                .body(Body::from(vec![b' '; 4096]))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let json = body_json(response).await;
+    assert_eq!(json["code"], "payload_too_large");
+    assert!(
+        json["message"]
+            .as_str()
+            .unwrap_or_default()
+            .contains("1024"),
+        "{json}"
+    );
+    assert!(
+        json["hint"]
+            .as_str()
+            .unwrap_or_default()
+            .contains("FLOWPLANE_API_MAX_BODY_BYTES"),
+        "{json}"
+    );
+}
+
 #[tokio::test]
 async fn unknown_path_returns_standard_envelope_with_request_id() {
     let Some(app) = test_app().await else { return };
@@ -142,6 +331,42 @@ async fn unknown_path_returns_standard_envelope_with_request_id() {
     assert_eq!(json["request_id"], rid_header, "envelope and header agree");
 }
 
+#[tokio::test]
+async fn errors_are_problem_details_when_the_caller_accepts_them() {
+    let Some(app) = test_app().await else { return };
+    let response = app
+        .oneshot(
+            Request::get("/definitely/not/a/route")
+                .header("accept", "application/problem+json, application/json;q=0.5")
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok()),
+        Some("application/problem+json")
+    );
+    let rid_header = response
+        .headers()
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned)
+        .expect("x-request-id header present");
+    let json = body_json(response).await;
+    assert_eq!(json["type"], "urn:flowplane:error:not_found");
+    assert_eq!(json["title"], "Not Found");
+    assert_eq!(json["status"], 404);
+    assert_eq!(json["instance"], "/definitely/not/a/route");
+    assert_eq!(json["detail"], json["message"]);
+    assert_eq!(json["code"], "not_found");
+    assert_eq!(json["request_id"], rid_header);
+}
+
 #[tokio::test]
 async fn valid_inbound_request_id_is_honored_and_echoed() {
     let Some(app) = test_app().await else { return };
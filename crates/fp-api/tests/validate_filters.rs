@@ -0,0 +1,259 @@
+//! `POST /api/v1/validate/filters`: a filter-chain dry run open to any authenticated caller
+//! (no team grant needed). A valid chain echoes its execution order, router last; an invalid
+//! one returns the same 400 a listener write would; nothing is stored either way.
+//! `POST /api/v1/validate/listener` hands the translated listener to the configured
+//! `envoy --mode validate` and returns Envoy's verdict the same way; a preset entry, which
+//! only a team-scoped write can instantiate, is a 400.
+//!
+//! Skipped (with a notice) when FLOWPLANE_TEST_DATABASE_URL is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use fp_domain::OrgRole;
+use fp_storage::repos::identity;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn unique(prefix: &str) -> String {
+    format!("{prefix}-{}", &Uuid::now_v7().simple().to_string()[20..])
+}
+
+/// Router plus a token for an org member holding no team grants at all.
+async fn setup() -> Option<(axum::Router, String)> {
+    setup_with(None).await
+}
+
+async fn setup_with(
+    envoy_validator: Option<fp_xds::validate::EnvoyValidator>,
+) -> Option<(axum::Router, String)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 4).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+
+    let issuer = fp_core::dev::DevIssuer::generate().expect("issuer");
+    let validator = fp_core::OidcValidator::new(issuer.oidc_config());
+    validator
+        .load_jwks_json(issuer.jwks_json())
+        .await
+        .expect("jwks");
+
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let subject = unique("sub");
+    let user = identity::upsert_user_by_subject(&pool, &subject, "member@test", "Member")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user, org.id, OrgRole::Member)
+        .await
+        .expect("membership");
+    let token = issuer
+        .mint(&subject, "member@test", "Member", 600)
+        .expect("mint");
+
+    let app = fp_api::build_router(fp_api::AppState {
+        pool,
+        prometheus: PrometheusBuilder::new().build_recorder().handle(),
+        version: "test",
+        validator: Some(std::sync::Arc::new(validator)),
+        write_throttle: std::sync::Arc::new(fp_api::throttle::WriteThrottle::new(1000)),
+        request_rate_limit: std::sync::Arc::new(fp_api::throttle::RequestRateLimit::disabled()),
+        max_body_bytes: fp_api::state::DEFAULT_MAX_BODY_BYTES,
+        xds_readiness: None,
+        discovery_forwarding_policy: Default::default(),
+        egress_advisory: Default::default(),
+        rls_repush: None,
+        rls_grpc_configured: false,
+        debug_team_header: false,
+        connected_proxies: Default::default(),
+        envoy_validator: envoy_validator.map(std::sync::Arc::new),
+        read_cache: Default::default(),
+        xds_snapshots: None,
+    });
+    Some((app, token))
+}
+
+async fn validate(app: axum::Router, token: Option<&str>, body: Value) -> (StatusCode, Value) {
+    post(app, "/api/v1/validate/filters", token, body).await
+}
+
+async fn post(
+    app: axum::Router,
+    path: &str,
+    token: Option<&str>,
+    body: Value,
+) -> (StatusCode, Value) {
+    let mut request = Request::post(path).header("content-type", "application/json");
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {token}"));
+    }
+    let response = app
+        .oneshot(request.body(Body::from(body.to_string())).expect("request"))
+        .await
+        .expect("response");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    (status, serde_json::from_slice(&bytes).expect("json"))
+}
+
+fn local_rate_limit() -> Value {
+    json!({"filter": {
+        "type": "local_rate_limit",
+        "stat_prefix": "edge",
+        "token_bucket": {"max_tokens": 10, "fill_interval_ms": 1000}
+    }})
+}
+
+#[tokio::test]
+async fn valid_chain_echoes_execution_order_with_router_last() {
+    let Some((app, token)) = setup().await else {
+        return;
+    };
+    let body = json!({"http_filters": [
+        {"filter": {"type": "health_check", "endpoint_path": "/healthz"}},
+        local_rate_limit(),
+    ]});
+    let (status, json) = validate(app, Some(&token), body).await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(
+        json,
+        json!({"valid": true, "chain": ["health_check", "local_rate_limit", "router"]})
+    );
+}
+
+#[tokio::test]
+async fn invalid_chains_return_the_listener_write_error() {
+    let Some((app, token)) = setup().await else {
+        return;
+    };
+    let (status, json) = validate(
+        app.clone(),
+        Some(&token),
+        json!({"http_filters": [local_rate_limit(), local_rate_limit()]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{json}");
+    assert_eq!(json["code"], "validation_failed");
+    assert_eq!(
+        json["message"],
+        "duplicate filter type \"local_rate_limit\" in the chain"
+    );
+
+    // A config that does not even deserialize (unknown field) is the same 400 envelope.
+    let (status, json) = validate(
+        app.clone(),
+        Some(&token),
+        json!({"http_filters": [{"filter": {"type": "health_check", "path": "/healthz"}}]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{json}");
+    assert_eq!(json["code"], "validation_failed");
+
+    let (status, _) = validate(app, None, json!({"http_filters": []})).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+/// A stand-in `envoy` that rejects any config mentioning `reject-me`, as the real binary
+/// would print a proto error for it.
+#[cfg(unix)]
+fn fake_envoy() -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = std::env::temp_dir().join(unique("fake-envoy"));
+    std::fs::create_dir_all(&dir).expect("dir");
+    let script = dir.join("envoy");
+    std::fs::write(
+        &script,
+        "#!/bin/sh\n\
+         while [ \"$1\" != \"-c\" ]; do shift; done\n\
+         if grep -q reject-me \"$2\"; then\n\
+           echo \"error initializing configuration '$2': Unknown field in: reject-me\" >&2\n\
+           exit 1\n\
+         fi\n",
+    )
+    .expect("script");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+    script
+}
+
+fn listener(name: &str) -> Value {
+    json!({"name": name, "spec": {
+        "address": "0.0.0.0",
+        "port": 18080,
+        "route_config": "edge-routes",
+        "http_filters": [local_rate_limit()],
+    }})
+}
+
+#[tokio::test]
+async fn listener_validation_needs_the_operator_to_configure_envoy() {
+    let Some((app, token)) = setup().await else {
+        return;
+    };
+    let (status, json) = post(
+        app,
+        "/api/v1/validate/listener",
+        Some(&token),
+        listener("edge"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED, "{json}");
+    assert_eq!(json["code"], "not_configured");
+    assert!(json["hint"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("FLOWPLANE_ENVOY_VALIDATE_BINARY"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn listener_validation_returns_envoys_verdict() {
+    let validator = fp_xds::validate::EnvoyValidator::new(fake_envoy());
+    let Some((app, token)) = setup_with(Some(validator)).await else {
+        return;
+    };
+    let path = "/api/v1/validate/listener";
+    let (status, json) = post(app.clone(), path, Some(&token), listener("edge")).await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(json, json!({"valid": true}));
+
+    let (status, json) = post(app.clone(), path, Some(&token), listener("reject-me")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{json}");
+    assert_eq!(json["code"], "validation_failed");
+    assert_eq!(
+        json["message"],
+        "envoy rejected the config: error initializing configuration '<config>': \
+         Unknown field in: reject-me"
+    );
+
+    // The chain checks of a listener write still run first.
+    let mut duplicate = listener("edge");
+    duplicate["spec"]["http_filters"] = json!([local_rate_limit(), local_rate_limit()]);
+    let (status, json) = post(app.clone(), path, Some(&token), duplicate).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{json}");
+    assert_eq!(
+        json["message"],
+        "duplicate filter type \"local_rate_limit\" in the chain"
+    );
+
+    // A preset needs a team to instantiate it: the chain checks refuse it before the
+    // listener is translated, so it is a 400 rather than a translation failure.
+    let mut preset = listener("edge");
+    preset["spec"]["http_filters"] =
+        json!([local_rate_limit(), {"filter": {"type": "preset", "name": "standard-cors"}}]);
+    let (status, json) = post(app, path, Some(&token), preset).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{json}");
+    assert_eq!(json["code"], "validation_failed");
+    assert_eq!(
+        json["message"],
+        "filter preset \"standard-cors\" can only be used in a team listener write"
+    );
+}
@@ -41,6 +41,14 @@ impl GrantSet {
             .any(|(r, a, _)| *r == resource && *a == action)
     }
 
+    /// The `(resource, action)` pairs granted on `team`, in no particular order.
+    pub fn for_team(&self, team: TeamId) -> impl Iterator<Item = (Resource, Action)> + '_ {
+        self.grants
+            .iter()
+            .filter(move |(_, _, t)| *t == team)
+            .map(|(r, a, _)| (*r, *a))
+    }
+
     pub fn is_empty(&self) -> bool {
         self.grants.is_empty()
     }
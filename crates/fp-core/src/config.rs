@@ -30,6 +30,23 @@ impl std::fmt::Debug for RlsAdminToken {
     }
 }
 
+/// HMAC key the secret-expiry webhook signs its notices with. Redacted from `Debug` like
+/// [`RlsAdminToken`].
+#[derive(Clone, PartialEq)]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    pub fn secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WebhookSecret(<redacted>)")
+    }
+}
+
 /// Literal loopback test on the RLS admin URL host, mirroring the CLI dev-token gate
 /// (`server_is_loopback` in the CLI): the host must be the literal `localhost`, a
 /// `127.0.0.0/8` IPv4 literal, `::1`, or an IPv4-mapped `::ffff:127.0.0.0/8` literal.
@@ -66,6 +83,15 @@ pub struct ServerConfig {
     pub database_url: String,
     /// Maximum DB pool connections.
     pub db_max_connections: u32,
+    /// Seconds a request waits for a free DB connection before the attempt times out
+    /// (1..=60). Env `FLOWPLANE_DB_ACQUIRE_TIMEOUT_SECS`.
+    pub db_acquire_timeout_secs: u64,
+    /// Seconds after which a pooled DB connection is recycled; `0` keeps connections
+    /// indefinitely. Env `FLOWPLANE_DB_MAX_LIFETIME_SECS`.
+    pub db_max_lifetime_secs: u64,
+    /// Seconds a single-resource GET (cluster, listener, route config) may be served from the
+    /// in-memory read cache; `0` (default) disables it. Env `FLOWPLANE_READ_CACHE_TTL_SECS`.
+    pub read_cache_ttl_secs: u64,
     /// TLS material for the API listener; `None` only when `api_insecure` is set (D-008).
     pub api_tls: Option<TlsConfig>,
     /// mTLS material for the xDS listener (server cert/key + client CA). xDS only serves
@@ -87,6 +113,15 @@ pub struct ServerConfig {
     pub oidc: Option<OidcSettings>,
     /// Per-tenant mutating-request budget per minute (spec/10 §4a).
     pub tenant_write_limit_per_minute: u32,
+    /// Per-principal request budget per second across all API calls; `0` disables the limit.
+    /// Env `FLOWPLANE_API_RATE_LIMIT_PER_SEC`.
+    pub api_rate_limit_per_sec: u32,
+    /// The same budget for platform admins (must not be below `api_rate_limit_per_sec`);
+    /// `0` leaves admins unlimited. Env `FLOWPLANE_API_ADMIN_RATE_LIMIT_PER_SEC`.
+    pub api_admin_rate_limit_per_sec: u32,
+    /// Largest request body the API accepts, in bytes; larger ones get a 413. Env
+    /// `FLOWPLANE_API_MAX_BODY_BYTES`.
+    pub api_max_body_bytes: usize,
     /// Local-only opt-in (#113): when true, an uninitialized non-dev instance with no
     /// operator-supplied bootstrap token falls back to generating one and logging it. Enabled
     /// only by the exact value `yes-this-is-local-only`; otherwise the instance fails closed.
@@ -131,6 +166,31 @@ pub struct ServerConfig {
     /// the CP/xDS routable ranges (listener binds are usually `0.0.0.0` and cannot provide
     /// them). Comma-separated in env `FLOWPLANE_EGRESS_ADVISORY_DENIED_CIDRS`.
     pub egress_advisory_denied_cidrs: Vec<crate::services::egress_advisory::Cidr>,
+    /// Debug aid: echo the resolved team, org, and the caller's scopes on that team in an
+    /// `x-flowplane-team` response header on team-scoped requests. Ids and scope names only;
+    /// default off. Env `FLOWPLANE_DEBUG_TEAM_HEADER`.
+    pub debug_team_header: bool,
+    /// Seconds live ADS streams are given on shutdown: they get a final snapshot push, keep
+    /// serving until the grace expires, then are closed with `UNAVAILABLE` so Envoy reconnects
+    /// elsewhere. Bounded to 0..=300. Env `FLOWPLANE_XDS_DRAIN_GRACE_SECS`.
+    pub xds_drain_grace_secs: u64,
+    /// Milliseconds the xDS consumer waits after a write notification before rebuilding, so a
+    /// burst of writes costs one rebuild per team. Bounded to 0..=1000; `0` rebuilds at once.
+    /// Env `FLOWPLANE_XDS_DEBOUNCE_MS`.
+    pub xds_debounce_ms: u64,
+    /// Optional URL the secret-expiry sweep POSTs a JSON notice to for each secret it
+    /// withdraws (names and timestamps, never values). Failed deliveries are retried with
+    /// backoff, then dead-lettered. Env `FLOWPLANE_SECRET_EXPIRY_WEBHOOK_URL`.
+    pub secret_expiry_webhook_url: Option<String>,
+    /// Optional HMAC-SHA256 key for those notices; each carries an
+    /// `x-flowplane-signature-256` header when set. Env-only:
+    /// `FLOWPLANE_SECRET_EXPIRY_WEBHOOK_SECRET`.
+    pub secret_expiry_webhook_secret: Option<WebhookSecret>,
+    /// Optional `envoy` binary the API runs in `--mode validate` when a caller asks for
+    /// generated config to be checked (`?validate=true` on the dataplane `envoy-config`,
+    /// `POST /api/v1/validate/listener`). `None` disables the check. Env
+    /// `FLOWPLANE_ENVOY_VALIDATE_BINARY`.
+    pub envoy_validate_binary: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -188,6 +248,12 @@ struct FileConfig {
     xds_addr: Option<String>,
     database_url: Option<String>,
     db_max_connections: Option<u32>,
+    db_acquire_timeout_secs: Option<u64>,
+    db_max_lifetime_secs: Option<u64>,
+    read_cache_ttl_secs: Option<u64>,
+    api_rate_limit_per_sec: Option<u32>,
+    api_admin_rate_limit_per_sec: Option<u32>,
+    api_max_body_bytes: Option<usize>,
     api_tls_cert: Option<String>,
     api_tls_key: Option<String>,
     xds_tls_cert: Option<String>,
@@ -209,6 +275,11 @@ struct FileConfig {
     dataplane_tls_key: Option<String>,
     dataplane_tls_client_ca: Option<String>,
     egress_advisory: Option<FileEgressAdvisory>,
+    debug_team_header: Option<bool>,
+    xds_drain_grace_secs: Option<u64>,
+    xds_debounce_ms: Option<u64>,
+    secret_expiry_webhook_url: Option<String>,
+    envoy_validate_binary: Option<String>,
 }
 
 /// `[egress_advisory]` TOML section (FP-DEC-0008 advisory knobs).
@@ -222,7 +293,20 @@ struct FileEgressAdvisory {
 
 const DEFAULT_API_ADDR: &str = "0.0.0.0:8080";
 const DEFAULT_DB_MAX_CONNECTIONS: u32 = 10;
+const DEFAULT_DB_ACQUIRE_TIMEOUT_SECS: u64 = 5;
+const MAX_DB_ACQUIRE_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_DB_MAX_LIFETIME_SECS: u64 = 1800;
+const MAX_READ_CACHE_TTL_SECS: u64 = 60;
+const DEFAULT_API_RATE_LIMIT_PER_SEC: u32 = 50;
+const DEFAULT_API_ADMIN_RATE_LIMIT_PER_SEC: u32 = 200;
+const DEFAULT_API_MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+const MIN_API_MAX_BODY_BYTES: usize = 1024;
+const MAX_API_MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
 const DEFAULT_LOG_FILTER: &str = "info";
+const DEFAULT_XDS_DRAIN_GRACE_SECS: u64 = 10;
+const MAX_XDS_DRAIN_GRACE_SECS: u64 = 300;
+const DEFAULT_XDS_DEBOUNCE_MS: u64 = 50;
+const MAX_XDS_DEBOUNCE_MS: u64 = 1000;
 
 impl ServerConfig {
     /// Load from process environment + optional `FLOWPLANE_CONFIG` file.
@@ -295,6 +379,47 @@ impl ServerConfig {
                 "FLOWPLANE_DB_MAX_CONNECTIONS must be >= 1",
             ));
         }
+        let db_acquire_timeout_secs = match get("FLOWPLANE_DB_ACQUIRE_TIMEOUT_SECS") {
+            Some(raw) => raw.parse::<u64>().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_DB_ACQUIRE_TIMEOUT_SECS \"{raw}\" is not a positive integer"
+                ))
+            })?,
+            None => file
+                .db_acquire_timeout_secs
+                .unwrap_or(DEFAULT_DB_ACQUIRE_TIMEOUT_SECS),
+        };
+        if !(1..=MAX_DB_ACQUIRE_TIMEOUT_SECS).contains(&db_acquire_timeout_secs) {
+            return Err(DomainError::invalid_config(format!(
+                "FLOWPLANE_DB_ACQUIRE_TIMEOUT_SECS must be between 1 and \
+                 {MAX_DB_ACQUIRE_TIMEOUT_SECS}"
+            )));
+        }
+        let db_max_lifetime_secs = match get("FLOWPLANE_DB_MAX_LIFETIME_SECS") {
+            Some(raw) => raw.parse::<u64>().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_DB_MAX_LIFETIME_SECS \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file
+                .db_max_lifetime_secs
+                .unwrap_or(DEFAULT_DB_MAX_LIFETIME_SECS),
+        };
+        let read_cache_ttl_secs = match get("FLOWPLANE_READ_CACHE_TTL_SECS") {
+            Some(raw) => raw.parse::<u64>().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_READ_CACHE_TTL_SECS \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file.read_cache_ttl_secs.unwrap_or(0),
+        };
+        if read_cache_ttl_secs > MAX_READ_CACHE_TTL_SECS {
+            return Err(DomainError::invalid_config(format!(
+                "FLOWPLANE_READ_CACHE_TTL_SECS {read_cache_ttl_secs} exceeds the \
+                 {MAX_READ_CACHE_TTL_SECS} s maximum"
+            ))
+            .with_hint("the TTL bounds how stale a read can be after a write on another replica"));
+        }
 
         let cert = get("FLOWPLANE_API_TLS_CERT")
             .map(str::to_owned)
@@ -417,6 +542,52 @@ impl ServerConfig {
             })?,
             None => 120,
         };
+        let api_rate_limit_per_sec = match get("FLOWPLANE_API_RATE_LIMIT_PER_SEC") {
+            Some(raw) => raw.parse().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_API_RATE_LIMIT_PER_SEC \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file
+                .api_rate_limit_per_sec
+                .unwrap_or(DEFAULT_API_RATE_LIMIT_PER_SEC),
+        };
+        let api_admin_rate_limit_per_sec = match get("FLOWPLANE_API_ADMIN_RATE_LIMIT_PER_SEC") {
+            Some(raw) => raw.parse().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_API_ADMIN_RATE_LIMIT_PER_SEC \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file
+                .api_admin_rate_limit_per_sec
+                .unwrap_or(DEFAULT_API_ADMIN_RATE_LIMIT_PER_SEC.max(api_rate_limit_per_sec)),
+        };
+        if api_rate_limit_per_sec > 0
+            && api_admin_rate_limit_per_sec > 0
+            && api_admin_rate_limit_per_sec < api_rate_limit_per_sec
+        {
+            return Err(DomainError::invalid_config(format!(
+                "FLOWPLANE_API_ADMIN_RATE_LIMIT_PER_SEC {api_admin_rate_limit_per_sec} is below \
+                 FLOWPLANE_API_RATE_LIMIT_PER_SEC {api_rate_limit_per_sec}"
+            ))
+            .with_hint("platform admins get at least the per-principal budget; 0 is unlimited"));
+        }
+        let api_max_body_bytes = match get("FLOWPLANE_API_MAX_BODY_BYTES") {
+            Some(raw) => raw.parse().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_API_MAX_BODY_BYTES \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file
+                .api_max_body_bytes
+                .unwrap_or(DEFAULT_API_MAX_BODY_BYTES),
+        };
+        if !(MIN_API_MAX_BODY_BYTES..=MAX_API_MAX_BODY_BYTES).contains(&api_max_body_bytes) {
+            return Err(DomainError::invalid_config(format!(
+                "FLOWPLANE_API_MAX_BODY_BYTES {api_max_body_bytes} must be in \
+                 {MIN_API_MAX_BODY_BYTES}..={MAX_API_MAX_BODY_BYTES}"
+            )));
+        }
 
         if dev_mode && oidc.is_some() {
             return Err(DomainError::invalid_config(
@@ -441,6 +612,47 @@ impl ServerConfig {
             .map(str::to_owned)
             .or(file.rls_grpc_url);
 
+        let secret_expiry_webhook_url = get("FLOWPLANE_SECRET_EXPIRY_WEBHOOK_URL")
+            .map(str::to_owned)
+            .or(file.secret_expiry_webhook_url);
+        if let Some(url) = &secret_expiry_webhook_url {
+            reqwest::Url::parse(url).map_err(|e| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_SECRET_EXPIRY_WEBHOOK_URL \"{url}\" is not a valid URL: {e}"
+                ))
+            })?;
+        }
+        // Secret material is env-only, like the RLS admin token.
+        let secret_expiry_webhook_secret = match get("FLOWPLANE_SECRET_EXPIRY_WEBHOOK_SECRET") {
+            Some(raw) if raw.trim().is_empty() => {
+                return Err(DomainError::invalid_config(
+                    "FLOWPLANE_SECRET_EXPIRY_WEBHOOK_SECRET is empty or whitespace-only",
+                ))
+            }
+            Some(_) if secret_expiry_webhook_url.is_none() => {
+                return Err(DomainError::invalid_config(
+                    "FLOWPLANE_SECRET_EXPIRY_WEBHOOK_SECRET is set but \
+                     FLOWPLANE_SECRET_EXPIRY_WEBHOOK_URL is not",
+                )
+                .with_hint("set the webhook URL, or unset the secret"))
+            }
+            Some(raw) => Some(WebhookSecret(raw.trim().to_string())),
+            None => None,
+        };
+
+        let envoy_validate_binary = get("FLOWPLANE_ENVOY_VALIDATE_BINARY")
+            .map(str::to_owned)
+            .or(file.envoy_validate_binary)
+            .map(|raw| {
+                if raw.trim().is_empty() {
+                    return Err(DomainError::invalid_config(
+                        "FLOWPLANE_ENVOY_VALIDATE_BINARY must name an envoy binary when set",
+                    ));
+                }
+                Ok(PathBuf::from(raw))
+            })
+            .transpose()?;
+
         // CP-side RLS admin push credential (fpv2-9sf S3). Env-only, like the bootstrap
         // token: secret material never comes from the TOML config file.
         let rls_admin_token = match (
@@ -586,11 +798,53 @@ impl ServerConfig {
             }
         };
 
+        let debug_team_header = match get("FLOWPLANE_DEBUG_TEAM_HEADER") {
+            Some(raw) => parse_bool("FLOWPLANE_DEBUG_TEAM_HEADER", raw)?,
+            None => file.debug_team_header.unwrap_or(false),
+        };
+
+        let xds_drain_grace_secs = match get("FLOWPLANE_XDS_DRAIN_GRACE_SECS") {
+            Some(raw) => raw.parse::<u64>().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_XDS_DRAIN_GRACE_SECS \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file
+                .xds_drain_grace_secs
+                .unwrap_or(DEFAULT_XDS_DRAIN_GRACE_SECS),
+        };
+        if xds_drain_grace_secs > MAX_XDS_DRAIN_GRACE_SECS {
+            return Err(DomainError::invalid_config(format!(
+                "FLOWPLANE_XDS_DRAIN_GRACE_SECS {xds_drain_grace_secs} exceeds the \
+                 {MAX_XDS_DRAIN_GRACE_SECS} s maximum"
+            ))
+            .with_hint("keep it below the orchestrator's termination grace period"));
+        }
+
+        let xds_debounce_ms = match get("FLOWPLANE_XDS_DEBOUNCE_MS") {
+            Some(raw) => raw.parse::<u64>().map_err(|_| {
+                DomainError::invalid_config(format!(
+                    "FLOWPLANE_XDS_DEBOUNCE_MS \"{raw}\" is not a non-negative integer"
+                ))
+            })?,
+            None => file.xds_debounce_ms.unwrap_or(DEFAULT_XDS_DEBOUNCE_MS),
+        };
+        if xds_debounce_ms > MAX_XDS_DEBOUNCE_MS {
+            return Err(DomainError::invalid_config(format!(
+                "FLOWPLANE_XDS_DEBOUNCE_MS {xds_debounce_ms} exceeds the \
+                 {MAX_XDS_DEBOUNCE_MS} ms maximum"
+            ))
+            .with_hint("the window delays every config push to Envoy by up to its length"));
+        }
+
         Ok(Self {
             api_addr,
             xds_addr,
             database_url,
             db_max_connections,
+            db_acquire_timeout_secs,
+            db_max_lifetime_secs,
+            read_cache_ttl_secs,
             api_tls,
             xds_tls,
             api_insecure,
@@ -600,6 +854,9 @@ impl ServerConfig {
             dev_mode,
             oidc,
             tenant_write_limit_per_minute,
+            api_rate_limit_per_sec,
+            api_admin_rate_limit_per_sec,
+            api_max_body_bytes,
             allow_logged_bootstrap_token,
             dev_token_path,
             rls_admin_url,
@@ -610,6 +867,12 @@ impl ServerConfig {
             dataplane_tls,
             egress_advisory_enabled,
             egress_advisory_denied_cidrs,
+            debug_team_header,
+            xds_drain_grace_secs,
+            xds_debounce_ms,
+            secret_expiry_webhook_url,
+            secret_expiry_webhook_secret,
+            envoy_validate_binary,
         })
     }
 }
@@ -814,6 +1077,201 @@ mod tests {
         }
     }
 
+    #[test]
+    fn xds_drain_grace_defaults_and_is_bounded() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.xds_drain_grace_secs, DEFAULT_XDS_DRAIN_GRACE_SECS);
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_XDS_DRAIN_GRACE_SECS".into(), "0".into());
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.xds_drain_grace_secs, 0);
+
+        for bad in ["301", "-1", "soon"] {
+            let mut env = base_env();
+            env.insert("FLOWPLANE_XDS_DRAIN_GRACE_SECS".into(), bad.into());
+            let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err(bad);
+            assert!(
+                err.message.contains("FLOWPLANE_XDS_DRAIN_GRACE_SECS"),
+                "{}",
+                err.message
+            );
+        }
+    }
+
+    #[test]
+    fn xds_debounce_defaults_to_a_short_window_and_is_bounded() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.xds_debounce_ms, DEFAULT_XDS_DEBOUNCE_MS);
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_XDS_DEBOUNCE_MS".into(), "0".into());
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.xds_debounce_ms, 0);
+
+        for bad in ["1001", "-1", "fast"] {
+            let mut env = base_env();
+            env.insert("FLOWPLANE_XDS_DEBOUNCE_MS".into(), bad.into());
+            let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err(bad);
+            assert!(
+                err.message.contains("FLOWPLANE_XDS_DEBOUNCE_MS"),
+                "{}",
+                err.message
+            );
+        }
+    }
+
+    #[test]
+    fn db_pool_timeouts_default_and_are_bounded() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.db_acquire_timeout_secs, DEFAULT_DB_ACQUIRE_TIMEOUT_SECS);
+        assert_eq!(cfg.db_max_lifetime_secs, DEFAULT_DB_MAX_LIFETIME_SECS);
+
+        let file = FileConfig {
+            db_acquire_timeout_secs: Some(2),
+            db_max_lifetime_secs: Some(0),
+            ..FileConfig::default()
+        };
+        let cfg = ServerConfig::resolve(&base_env(), file).expect("resolves");
+        assert_eq!(cfg.db_acquire_timeout_secs, 2);
+        assert_eq!(cfg.db_max_lifetime_secs, 0);
+
+        for (key, bad) in [
+            ("FLOWPLANE_DB_ACQUIRE_TIMEOUT_SECS", "0"),
+            ("FLOWPLANE_DB_ACQUIRE_TIMEOUT_SECS", "61"),
+            ("FLOWPLANE_DB_MAX_LIFETIME_SECS", "forever"),
+        ] {
+            let mut env = base_env();
+            env.insert(key.into(), bad.into());
+            let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err(bad);
+            assert!(err.message.contains(key), "{}", err.message);
+        }
+    }
+
+    #[test]
+    fn read_cache_is_off_by_default_and_its_ttl_is_bounded() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.read_cache_ttl_secs, 0);
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_READ_CACHE_TTL_SECS".into(), "5".into());
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.read_cache_ttl_secs, 5);
+
+        for bad in ["61", "soon"] {
+            let mut env = base_env();
+            env.insert("FLOWPLANE_READ_CACHE_TTL_SECS".into(), bad.into());
+            let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err(bad);
+            assert!(
+                err.message.contains("FLOWPLANE_READ_CACHE_TTL_SECS"),
+                "{}",
+                err.message
+            );
+        }
+    }
+
+    #[test]
+    fn api_rate_limits_default_on_and_admins_never_get_less() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(
+            (cfg.api_rate_limit_per_sec, cfg.api_admin_rate_limit_per_sec),
+            (50, 200)
+        );
+
+        // Raising the base limit past the admin default lifts the admin default with it.
+        let mut env = base_env();
+        env.insert("FLOWPLANE_API_RATE_LIMIT_PER_SEC".into(), "500".into());
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.api_admin_rate_limit_per_sec, 500);
+
+        let file = FileConfig {
+            api_rate_limit_per_sec: Some(20),
+            api_admin_rate_limit_per_sec: Some(40),
+            ..FileConfig::default()
+        };
+        let cfg = ServerConfig::resolve(&base_env(), file).expect("file keys");
+        assert_eq!(
+            (cfg.api_rate_limit_per_sec, cfg.api_admin_rate_limit_per_sec),
+            (20, 40)
+        );
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_API_RATE_LIMIT_PER_SEC".into(), "0".into());
+        env.insert("FLOWPLANE_API_ADMIN_RATE_LIMIT_PER_SEC".into(), "0".into());
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("disabled");
+        assert_eq!(cfg.api_rate_limit_per_sec, 0);
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_API_RATE_LIMIT_PER_SEC".into(), "100".into());
+        env.insert("FLOWPLANE_API_ADMIN_RATE_LIMIT_PER_SEC".into(), "10".into());
+        let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err("admin below");
+        assert!(err.message.contains("is below"), "{}", err.message);
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_API_RATE_LIMIT_PER_SEC".into(), "-1".into());
+        let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err("negative");
+        assert!(
+            err.message.contains("FLOWPLANE_API_RATE_LIMIT_PER_SEC"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn api_max_body_bytes_defaults_to_two_mib_and_is_bounded() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.api_max_body_bytes, 2 * 1024 * 1024);
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_API_MAX_BODY_BYTES".into(), "8388608".into());
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.api_max_body_bytes, 8 * 1024 * 1024);
+
+        let file = FileConfig {
+            api_max_body_bytes: Some(4 * 1024 * 1024),
+            ..FileConfig::default()
+        };
+        let cfg = ServerConfig::resolve(&base_env(), file).expect("file key");
+        assert_eq!(cfg.api_max_body_bytes, 4 * 1024 * 1024);
+        let file = FileConfig {
+            api_max_body_bytes: Some(512),
+            ..FileConfig::default()
+        };
+        ServerConfig::resolve(&base_env(), file).expect_err("file value is bounded too");
+
+        for bad in ["0", "512", "1073741824", "2MB"] {
+            let mut env = base_env();
+            env.insert("FLOWPLANE_API_MAX_BODY_BYTES".into(), bad.into());
+            let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err(bad);
+            assert!(
+                err.message.contains("FLOWPLANE_API_MAX_BODY_BYTES"),
+                "{}",
+                err.message
+            );
+        }
+    }
+
+    #[test]
+    fn envoy_validate_binary_is_optional_and_never_blank() {
+        let cfg = ServerConfig::resolve(&base_env(), FileConfig::default()).expect("resolves");
+        assert_eq!(cfg.envoy_validate_binary, None);
+
+        let file = FileConfig {
+            envoy_validate_binary: Some("/usr/local/bin/envoy".into()),
+            ..FileConfig::default()
+        };
+        let cfg = ServerConfig::resolve(&base_env(), file).expect("resolves");
+        assert_eq!(
+            cfg.envoy_validate_binary,
+            Some(PathBuf::from("/usr/local/bin/envoy"))
+        );
+
+        let mut env = base_env();
+        env.insert("FLOWPLANE_ENVOY_VALIDATE_BINARY".into(), " ".into());
+        let err = ServerConfig::resolve(&env, FileConfig::default()).expect_err("blank");
+        assert!(err.message.contains("FLOWPLANE_ENVOY_VALIDATE_BINARY"));
+    }
+
     fn oidc_env() -> HashMap<String, String> {
         let mut env = base_env();
         env.insert("FLOWPLANE_OIDC_ISSUER".into(), "https://idp.test".into());
@@ -1090,6 +1548,36 @@ mod tests {
         assert!(debug.contains("<redacted>"), "{debug}");
     }
 
+    // The webhook signing key needs a webhook to sign for and never appears through Debug.
+    #[test]
+    fn webhook_secret_requires_a_url_and_is_redacted() {
+        let mut env = base_env();
+        env.insert(
+            "FLOWPLANE_SECRET_EXPIRY_WEBHOOK_SECRET".into(),
+            "hook-sekrit".into(),
+        );
+        let err = ServerConfig::resolve(&env, FileConfig::default()).unwrap_err();
+        assert!(
+            err.message.contains("FLOWPLANE_SECRET_EXPIRY_WEBHOOK_URL"),
+            "{}",
+            err.message
+        );
+
+        env.insert(
+            "FLOWPLANE_SECRET_EXPIRY_WEBHOOK_URL".into(),
+            "https://hooks.example.test/expiry".into(),
+        );
+        let cfg = ServerConfig::resolve(&env, FileConfig::default()).expect("resolves");
+        assert_eq!(
+            cfg.secret_expiry_webhook_secret
+                .as_ref()
+                .map(|s| s.secret()),
+            Some("hook-sekrit")
+        );
+        let debug = format!("{cfg:?}");
+        assert!(!debug.contains("hook-sekrit"), "{debug}");
+    }
+
     // The CA path is carried verbatim (client construction validates the material).
     #[test]
     fn admin_tls_ca_path_is_carried() {
@@ -130,8 +130,7 @@ pub async fn create_agent(
     }
 
     let token = mint_agent_token();
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create agent: begin"))?;
     let agent = identity::create_agent_tx(
@@ -199,8 +198,7 @@ pub async fn rotate_agent_token(
     let current = get_agent(pool, ctx, agent_id).await?;
     require_same_org(&current, org_id)?;
     let token = mint_agent_token();
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("rotate agent token: begin"))?;
     let agent = identity::rotate_agent_token_tx(
@@ -234,8 +232,7 @@ pub async fn disable_agent(
     request_id: RequestId,
 ) -> DomainResult<Agent> {
     let (org_id, _) = require_org_admin(ctx)?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("disable agent: begin"))?;
     let agent = identity::disable_agent_tx(&mut tx, org_id, agent_id).await?;
@@ -74,8 +74,7 @@ pub async fn create_provider(
         .await?;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::AiProviders).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create AI provider: begin"))?;
     let provider = ai::create(&mut tx, team, name, &spec).await?;
@@ -174,8 +173,7 @@ pub async fn update_provider(
             ],
         )
         .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update AI provider: begin"))?;
     // Per-team AI-materialization lock; must be the first statement in the tx, before any
@@ -260,8 +258,7 @@ pub async fn delete_provider(
         request_id,
     )
     .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete AI provider: begin"))?;
     let provider = ai::get(pool, team.id, name)
@@ -354,8 +351,7 @@ pub async fn create_route(
     // Single tx (fpv2-8am): lock first, then every materialization-sensitive read and write.
     // Materialization, its outbox upserts, and the route row commit or roll back together —
     // no transient half-materialized state, no best-effort cleanup on failure.
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create AI route: begin"))?;
     ai::acquire_materialization_lock(&mut tx, team.id).await?;
@@ -434,8 +430,7 @@ pub async fn create_budget(
     validate_ai_budget_name(name)?;
     validate_budget_spec(pool, team, &spec).await?;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::AiBudgets).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create AI budget: begin"))?;
     let budget = ai::create_budget(&mut tx, team, name, &spec).await?;
@@ -517,8 +512,7 @@ pub async fn update_budget(
     )
     .await?;
     validate_budget_spec(pool, team, &spec).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update AI budget: begin"))?;
     let budget = ai::update_budget(&mut tx, team.id, name, &spec, expected_version).await?;
@@ -557,8 +551,7 @@ pub async fn delete_budget(
         request_id,
     )
     .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete AI budget: begin"))?;
     ai::delete_budget(&mut tx, team.id, name, expected_version).await?;
@@ -637,8 +630,7 @@ pub async fn set_retention_policy(
     )
     .await?;
     validate_trace_ttl_days(trace_ttl_days)?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("set AI retention policy: begin"))?;
     let policy =
@@ -713,8 +705,7 @@ pub async fn update_route(
     // provider loads — a pre-lock check could pass, lose to a provider update's version
     // bump, and strand a torn rebuild. Teardown, rebuild, upserts, and the route row commit
     // or roll back together.
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update AI route: begin"))?;
     ai::acquire_materialization_lock(&mut tx, team.id).await?;
@@ -773,8 +764,7 @@ pub async fn delete_route(
     authorize_materialized_cleanup(pool, ctx, team, request_id).await?;
     // Single tx (fpv2-8am): a failed teardown rolls the whole delete back — the route row
     // can no longer outlive its materialized resources (or vice versa).
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete AI route: begin"))?;
     ai::acquire_materialization_lock(&mut tx, team.id).await?;
@@ -1088,6 +1078,11 @@ async fn create_materialized(
         http_filters: Vec::new(),
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     };
     let owner_id = uuid::Uuid::now_v7();
     let mut cluster_events = Vec::with_capacity(cluster_specs.len());
@@ -1269,9 +1264,11 @@ fn provider_cluster_spec(provider: &AiProvider) -> DomainResult<ClusterSpec> {
             insecure_skip_verify: false,
         }),
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     })
 }
 
@@ -1288,9 +1285,11 @@ fn aggregate_cluster_spec(cluster_names: Vec<String>) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -1334,7 +1333,9 @@ fn no_eligible_backend_route(path: &str) -> RouteRule {
         name: "no-eligible-backend".into(),
         matcher: PathMatch::Exact { path: path.into() },
         headers: Vec::new(),
+        methods: Vec::new(),
         query_parameters: Vec::new(),
+        runtime_fraction: None,
         action: RouteAction {
             cluster: None,
             weighted_clusters: None,
@@ -1350,6 +1351,16 @@ fn no_eligible_backend_route(path: &str) -> RouteRule {
             template_rewrite: None,
             timeout_secs: DEFAULT_AI_ROUTE_TIMEOUT_SECS,
             retry_policy: None,
+            retry_policy_ref: None,
+            idle_timeout_secs: None,
+            max_stream_duration_secs: None,
+            regex_rewrite: None,
+            host_rewrite_literal: None,
+            auto_host_rewrite: false,
+            fallback_cluster: None,
+            request_mirror_policies: Vec::new(),
+            request_headers_to_remove: Vec::new(),
+            hash_policy: Vec::new(),
             rate_limits: Vec::new(),
         },
         filter_overrides: Vec::new(),
@@ -1407,7 +1418,9 @@ fn route_rule(
         name: name.into(),
         matcher: PathMatch::Exact { path: path.into() },
         headers,
+        methods: Vec::new(),
         query_parameters: Vec::new(),
+        runtime_fraction: None,
         action: RouteAction {
             cluster,
             weighted_clusters,
@@ -1417,6 +1430,16 @@ fn route_rule(
             template_rewrite: None,
             timeout_secs: DEFAULT_AI_ROUTE_TIMEOUT_SECS,
             retry_policy,
+            retry_policy_ref: None,
+            idle_timeout_secs: None,
+            max_stream_duration_secs: None,
+            regex_rewrite: None,
+            host_rewrite_literal: None,
+            auto_host_rewrite: false,
+            fallback_cluster: None,
+            request_mirror_policies: Vec::new(),
+            request_headers_to_remove: Vec::new(),
+            hash_policy: Vec::new(),
             rate_limits: Vec::new(),
         },
         filter_overrides: Vec::new(),
@@ -113,8 +113,7 @@ pub async fn create_api(
         .as_ref()
         .map(|spec| tools_from_openapi(&input.name, spec))
         .transpose()?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create api: begin"))?;
     let api =
@@ -299,8 +298,7 @@ pub async fn delete_api(
     request_id: RequestId,
 ) -> DomainResult<()> {
     authorize(pool, ctx, Action::Delete, team, request_id).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete api: begin"))?;
     let api_id =
@@ -340,8 +338,7 @@ pub async fn reject_spec_version(
     let api = api_lifecycle::get_api_definition(pool, team.id, &input.api)
         .await?
         .ok_or_else(|| DomainError::not_found("api", &input.api))?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("reject spec version: begin"))?;
     let spec =
@@ -403,8 +400,7 @@ pub async fn publish_spec_version(
     let api = api_lifecycle::get_api_definition(pool, team.id, &input.api)
         .await?
         .ok_or_else(|| DomainError::not_found("api", &input.api))?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("publish spec version: begin"))?;
     let spec =
@@ -8,7 +8,8 @@ use crate::services::{actor_of, deny_to_error, record_authz_denial, trace_contex
 use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::event::{DomainEvent, EventScope};
 use fp_domain::gateway::cluster::{validate_cluster_name, Cluster, ClusterSpec};
-use fp_domain::{DomainResult, RequestId};
+use fp_domain::gateway::ListSort;
+use fp_domain::{DomainError, DomainResult, RequestId, ResourceSource};
 use fp_storage::repos::{audit, clusters};
 use fp_storage::scope::TeamScope;
 use sqlx::PgPool;
@@ -63,10 +64,10 @@ pub async fn create_cluster(
         .await?;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Clusters).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create cluster: begin"))?;
+    check_aggregate_members(&mut tx, team.id, name, &spec).await?;
     let cluster = clusters::create(&mut tx, team, name, &spec).await?;
     fp_storage::outbox::append(
         &mut tx,
@@ -105,16 +106,53 @@ pub async fn get_cluster(
         .ok_or_else(|| fp_domain::DomainError::not_found("cluster", name))
 }
 
+/// Copy a stored cluster under `new_name`, optionally into another team of the same org. The
+/// source is read with the caller's read grant; the copy then goes through the ordinary create
+/// path on the target team (create grant, validation, egress advisory, quota, name collision),
+/// so a clone can never land anything a plain create would have refused. The target team is
+/// the one team a caller names in a request body, so its create grant is checked before the
+/// source is even read: a caller scoped to one team gets a 403 naming the target, not a
+/// downstream failure.
+#[allow(clippy::too_many_arguments)]
+pub async fn clone_cluster(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    target: TeamRef,
+    new_name: &str,
+    request_id: RequestId,
+    advisory: EgressAdvisoryPolicy,
+) -> DomainResult<Cluster> {
+    authorize(pool, ctx, Action::Create, target, request_id).await?;
+    let source = get_cluster(pool, ctx, team, name, request_id).await?;
+    create_cluster(
+        pool,
+        ctx,
+        target,
+        new_name,
+        source.spec,
+        request_id,
+        advisory,
+    )
+    .await
+}
+
+/// The team's clusters in `sort` order; `source` keeps only hand-written or only
+/// OpenAPI-imported ones.
+#[allow(clippy::too_many_arguments)]
 pub async fn list_clusters(
     pool: &PgPool,
     ctx: &PrincipalCtx,
     team: TeamRef,
+    source: Option<ResourceSource>,
+    sort: ListSort,
     limit: i64,
     offset: i64,
     request_id: RequestId,
 ) -> DomainResult<(Vec<Cluster>, i64)> {
     authorize(pool, ctx, Action::Read, team, request_id).await?;
-    clusters::list(pool, TeamScope::Team(team.id), limit, offset).await
+    clusters::list(pool, TeamScope::Team(team.id), source, sort, limit, offset).await
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -141,10 +179,10 @@ pub async fn update_cluster(
             spec.endpoints.iter().map(|e| e.host.clone()).collect(),
         )
         .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update cluster: begin"))?;
+    check_aggregate_members(&mut tx, team.id, name, &spec).await?;
     let cluster = clusters::update(&mut tx, team.id, name, &spec, expected_version).await?;
     fp_storage::outbox::append(
         &mut tx,
@@ -179,8 +217,7 @@ pub async fn delete_cluster(
     request_id: RequestId,
 ) -> DomainResult<()> {
     authorize(pool, ctx, Action::Delete, team, request_id).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete cluster: begin"))?;
     // Referenced clusters cannot be deleted (no silent cascade — spec/10 §3.4.1); the
@@ -195,6 +232,7 @@ pub async fn delete_cluster(
         ))
         .with_hint("update or delete those route configs first"));
     }
+    ensure_not_aggregate_member(&mut tx, team.id, name).await?;
     let cluster_id = clusters::delete(&mut tx, team.id, name, expected_version).await?;
     fp_storage::outbox::append(
         &mut tx,
@@ -220,6 +258,153 @@ pub async fn delete_cluster(
     Ok(())
 }
 
+/// Move a cluster to another team of the same org, keeping its id and spec — a reorganization,
+/// not a copy. The caller needs delete on the source team and create on the target (org admins
+/// hold both). The target's egress advisory and quota apply as on create; route configs still
+/// referencing the cluster in its old team block the move. The source team rebuilds without it
+/// and the target with it, and both teams' audit logs record the transfer.
+#[allow(clippy::too_many_arguments)]
+pub async fn transfer_cluster(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    target: TeamRef,
+    expected_version: i64,
+    request_id: RequestId,
+    advisory: EgressAdvisoryPolicy,
+) -> DomainResult<Cluster> {
+    authorize(pool, ctx, Action::Delete, team, request_id).await?;
+    authorize(pool, ctx, Action::Create, target, request_id).await?;
+    crate::services::ensure_distinct_teams(team, target)?;
+    let source = get_cluster(pool, ctx, team, name, request_id).await?;
+    advisory
+        .enforce_hosts(
+            pool,
+            ctx,
+            request_id,
+            target,
+            "cluster.transfer",
+            &format!("clusters/{name}"),
+            source
+                .spec
+                .endpoints
+                .iter()
+                .map(|e| e.host.clone())
+                .collect(),
+        )
+        .await?;
+    crate::services::quota::check_team_resource_quota(pool, target.id, Resource::Clusters).await?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("transfer cluster: begin"))?;
+    let dependents =
+        fp_storage::repos::gateway::route_configs_referencing_cluster(&mut tx, team.id, name)
+            .await?;
+    if !dependents.is_empty() {
+        return Err(fp_domain::DomainError::conflict(format!(
+            "cluster \"{name}\" is referenced by route configs: {}",
+            dependents.join(", ")
+        ))
+        .with_hint("move or update those route configs first"));
+    }
+    ensure_not_aggregate_member(&mut tx, team.id, name).await?;
+    check_aggregate_members(&mut tx, target.id, name, &source.spec).await?;
+    let cluster = clusters::transfer(&mut tx, team.id, target, name, expected_version).await?;
+    crate::services::record_transfer_in_tx(
+        &mut tx,
+        team,
+        target,
+        DomainEvent::ClusterDeleted {
+            cluster_id: cluster.id.as_uuid(),
+            name: name.into(),
+        },
+        DomainEvent::ClusterUpserted {
+            cluster_id: cluster.id.as_uuid(),
+            name: name.into(),
+        },
+        |scope| mutation_audit(ctx, request_id, scope, "cluster.transfer", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("transfer cluster: commit"))?;
+    Ok(cluster)
+}
+
+/// Aggregate members must be existing user clusters of the team that are not aggregates
+/// themselves, and an aggregate cannot list itself: failover walks a flat, ordered list. A
+/// cluster that other aggregates already list cannot become an aggregate either.
+async fn check_aggregate_members(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    team_id: fp_domain::TeamId,
+    name: &str,
+    spec: &ClusterSpec,
+) -> DomainResult<()> {
+    if spec.aggregate_clusters.is_empty() {
+        return Ok(());
+    }
+    if spec.aggregate_clusters.iter().any(|member| member == name) {
+        return Err(DomainError::validation(format!(
+            "aggregate cluster \"{name}\" cannot list itself as a member"
+        )));
+    }
+    let parents = clusters::aggregates_referencing(tx, team_id, name).await?;
+    if !parents.is_empty() {
+        return Err(DomainError::validation(format!(
+            "cluster \"{name}\" is a member of aggregate clusters ({}) and cannot be an aggregate itself",
+            parents.join(", ")
+        ))
+        .with_hint("aggregates cannot be nested; list the members directly instead"));
+    }
+    let found = clusters::aggregate_member_kinds(tx, team_id, &spec.aggregate_clusters).await?;
+    let missing: Vec<&str> = spec
+        .aggregate_clusters
+        .iter()
+        .filter(|member| !found.iter().any(|(name, _)| name == *member))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(DomainError::validation(format!(
+            "aggregate members do not exist in this team: {}",
+            missing.join(", ")
+        ))
+        .with_hint("create the member clusters first, then the aggregate"));
+    }
+    let nested: Vec<&str> = found
+        .iter()
+        .filter(|(_, is_aggregate)| *is_aggregate)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !nested.is_empty() {
+        return Err(DomainError::validation(format!(
+            "aggregate members cannot be aggregate clusters themselves: {}",
+            nested.join(", ")
+        ))
+        .with_hint("aggregates cannot be nested; list the members directly instead"));
+    }
+    Ok(())
+}
+
+/// A cluster listed by an aggregate cannot leave the team (same no-cascade rule as route
+/// config references).
+async fn ensure_not_aggregate_member(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    team_id: fp_domain::TeamId,
+    name: &str,
+) -> DomainResult<()> {
+    let parents = clusters::aggregates_referencing(tx, team_id, name).await?;
+    if parents.is_empty() {
+        return Ok(());
+    }
+    Err(DomainError::conflict(format!(
+        "cluster \"{name}\" is a member of aggregate clusters: {}",
+        parents.join(", ")
+    ))
+    .with_hint("update or delete those aggregate clusters first"))
+}
+
 fn mutation_audit(
     ctx: &PrincipalCtx,
     request_id: RequestId,
@@ -5,10 +5,17 @@
 use crate::authz::{check_resource_access, Decision, PrincipalCtx};
 use crate::services::{actor_of, deny_to_error, record_authz_denial, trace_context_json};
 use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::connected::{ConnectedProxies, ConnectedProxy};
 use fp_domain::dataplane::{validate_spiffe_uri, Dataplane, ProxyCertificate};
 use fp_domain::event::{DomainEvent, EventScope};
-use fp_domain::{validate_name, DomainError, DomainResult, RequestId, TeamStatsOverview, UserId};
-use fp_storage::repos::{audit, dataplanes};
+use fp_domain::rollout::{
+    validate_canary_percent, validate_config_version, ConfigRollout, ConfigVersion,
+};
+use fp_domain::{
+    validate_name, DataplaneClusterStats, DomainError, DomainResult, ErrorCode, RequestId,
+    TeamStatsOverview, UserId,
+};
+use fp_storage::repos::{audit, config_rollout, dataplanes};
 use openssl::asn1::Asn1Time;
 use openssl::bn::BigNum;
 use openssl::hash::MessageDigest;
@@ -55,8 +62,7 @@ pub async fn create_dataplane(
     .await?;
     validate_name(name)?;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Dataplanes).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create dataplane: begin"))?;
     let dataplane = dataplanes::create_dataplane(&mut tx, team, name, description).await?;
@@ -131,6 +137,281 @@ pub async fn list_dataplanes(
     dataplanes::list_dataplanes(pool, team.id, limit, offset).await
 }
 
+/// Gate for rendering a standalone bootstrap into the team: the same read access that
+/// fetching a registered dataplane's bootstrap needs. Nothing is stored.
+pub async fn check_bootstrap_access(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await
+}
+
+/// Envoys streaming ADS from this replica for the team right now. The inventory is process
+/// memory owned by the xDS server, so each replica only reports its own streams.
+pub async fn list_connected_proxies(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    proxies: &ConnectedProxies,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<Vec<ConnectedProxy>> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    Ok(proxies.for_team(team.id))
+}
+
+/// Config versions the xDS server has recorded for the team, newest first. These are the
+/// versions a dataplane pin or a rollout can hold proxies on.
+pub async fn list_config_versions(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<Vec<ConfigVersion>> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    config_rollout::list_versions(pool, team.id).await
+}
+
+/// Hold one dataplane on a recorded config version. Its proxies keep being served that
+/// snapshot, whatever the team's latest config, until [`promote_dataplane`].
+pub async fn pin_dataplane(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    config_version: &str,
+    request_id: RequestId,
+) -> DomainResult<Dataplane> {
+    set_dataplane_pin(pool, ctx, team, name, Some(config_version), request_id).await
+}
+
+/// Release a pinned dataplane to the team's latest config.
+pub async fn promote_dataplane(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<Dataplane> {
+    set_dataplane_pin(pool, ctx, team, name, None, request_id).await
+}
+
+async fn set_dataplane_pin(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    config_version: Option<&str>,
+    request_id: RequestId,
+) -> DomainResult<Dataplane> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Update,
+        team,
+        request_id,
+    )
+    .await?;
+    if let Some(version) = config_version {
+        validate_config_version(version)?;
+    }
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("pin dataplane: begin"))?;
+    let dataplane =
+        dataplanes::set_pinned_config_version(&mut tx, team.id, name, config_version).await?;
+    fp_storage::outbox::append(
+        &mut tx,
+        &DomainEvent::DataplaneConfigPinned {
+            dataplane_id: dataplane.id.as_uuid(),
+            name: name.into(),
+            config_version: config_version.map(str::to_string),
+        },
+        EventScope {
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+        },
+        trace_context_json(),
+    )
+    .await?;
+    let mut entry = mutation_audit(
+        ctx,
+        request_id,
+        team,
+        if config_version.is_some() {
+            "dataplane.pin"
+        } else {
+            "dataplane.promote"
+        },
+        &format!("dataplanes/{name}"),
+    );
+    entry.detail = serde_json::json!({ "config_version": config_version });
+    audit::record_in_tx(&mut tx, &entry).await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("pin dataplane: commit"))?;
+    Ok(dataplane)
+}
+
+pub async fn get_config_rollout(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<ConfigRollout> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    config_rollout::get_rollout(pool, team.id)
+        .await?
+        .ok_or_else(no_rollout)
+}
+
+/// Start or retarget the team's staged rollout: `canary_percent` of its proxies receive the
+/// latest config and the rest stay on `stable_version`. Dataplane pins still win.
+pub async fn set_config_rollout(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    stable_version: &str,
+    canary_percent: i64,
+    request_id: RequestId,
+) -> DomainResult<ConfigRollout> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Update,
+        team,
+        request_id,
+    )
+    .await?;
+    validate_config_version(stable_version)?;
+    let canary_percent = validate_canary_percent(canary_percent)?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("set config rollout: begin"))?;
+    let rollout =
+        config_rollout::upsert_rollout(&mut tx, team.id, stable_version, canary_percent).await?;
+    fp_storage::outbox::append(
+        &mut tx,
+        &DomainEvent::ConfigRolloutUpdated {
+            stable_version: stable_version.into(),
+            canary_percent,
+        },
+        EventScope {
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+        },
+        trace_context_json(),
+    )
+    .await?;
+    let mut entry = mutation_audit(
+        ctx,
+        request_id,
+        team,
+        "config-rollout.set",
+        "config-rollout",
+    );
+    entry.detail = serde_json::json!({
+        "stable_version": stable_version,
+        "canary_percent": canary_percent,
+    });
+    audit::record_in_tx(&mut tx, &entry).await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("set config rollout: commit"))?;
+    Ok(rollout)
+}
+
+/// End the team's rollout: every proxy not pinned individually moves to latest.
+pub async fn promote_config_rollout(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<ConfigRollout> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Dataplanes,
+        Action::Update,
+        team,
+        request_id,
+    )
+    .await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("promote config rollout: begin"))?;
+    let rollout = config_rollout::delete_rollout(&mut tx, team.id)
+        .await?
+        .ok_or_else(no_rollout)?;
+    fp_storage::outbox::append(
+        &mut tx,
+        &DomainEvent::ConfigRolloutPromoted {
+            stable_version: rollout.stable_version.clone(),
+        },
+        EventScope {
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+        },
+        trace_context_json(),
+    )
+    .await?;
+    let mut entry = mutation_audit(
+        ctx,
+        request_id,
+        team,
+        "config-rollout.promote",
+        "config-rollout",
+    );
+    entry.detail = serde_json::json!({ "stable_version": rollout.stable_version });
+    audit::record_in_tx(&mut tx, &entry).await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("promote config rollout: commit"))?;
+    Ok(rollout)
+}
+
+fn no_rollout() -> DomainError {
+    DomainError::new(
+        ErrorCode::NotFound,
+        "the team has no config rollout in progress",
+    )
+}
+
 pub async fn list_certificates(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -213,8 +494,7 @@ pub async fn register_certificate(
         PrincipalCtx::Agent { .. } => None,
     };
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("register certificate: begin"))?;
     let cert = dataplanes::register_certificate(
@@ -301,8 +581,7 @@ pub async fn issue_certificate(
         PrincipalCtx::User { user_id, .. } => Some(*user_id),
         PrincipalCtx::Agent { .. } => None,
     };
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("issue certificate: begin"))?;
     let cert = dataplanes::register_certificate(
@@ -370,8 +649,7 @@ pub async fn revoke_certificate(
         request_id,
     )
     .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("revoke certificate: begin"))?;
     let cert = dataplanes::revoke_certificate(&mut tx, team.id, serial_number, reason).await?;
@@ -461,6 +739,37 @@ pub async fn stats_overview(
     dataplanes::stats_overview(pool, team.id, chrono::Utc::now()).await
 }
 
+/// How long a dataplane's cluster gauges count as current: the live window of
+/// [`stats_overview`].
+pub const CLUSTER_STATS_FRESH_SECS: i64 = 60;
+
+#[derive(Debug, Clone)]
+pub struct ClusterStats {
+    pub cluster: String,
+    /// One entry per dataplane that reported the cluster within [`CLUSTER_STATS_FRESH_SECS`].
+    pub dataplanes: Vec<DataplaneClusterStats>,
+}
+
+/// Upstream gauges for one cluster as the team's dataplane agents last reported them. The
+/// cluster is resolved like a cluster read, so an unknown one is the same 404 as
+/// `GET …/clusters/{name}`.
+pub async fn cluster_stats(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<ClusterStats> {
+    authorize(pool, ctx, Resource::Stats, Action::Read, team, request_id).await?;
+    let cluster = crate::services::clusters::get_cluster(pool, ctx, team, name, request_id).await?;
+    let since = chrono::Utc::now() - chrono::Duration::seconds(CLUSTER_STATS_FRESH_SECS);
+    let dataplanes = dataplanes::cluster_stats(pool, team.id, &cluster.name, since).await?;
+    Ok(ClusterStats {
+        cluster: cluster.name,
+        dataplanes,
+    })
+}
+
 fn mutation_audit(
     ctx: &PrincipalCtx,
     request_id: RequestId,
@@ -0,0 +1,119 @@
+//! Platform diagnostics bundle for support tickets. Platform-admin only (the same
+//! `platform:execute` gate as the RLS force-repush), read-only, and redacted: it crosses
+//! every tenant, so it carries ids, counts, and timestamps but no tenant-authored strings
+//! (dataplane names, NACK error text, quarantined resource names) — invariant 1 keeps tenant
+//! content invisible to a platform admin even here.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::xds_status::is_live;
+use crate::services::{deny_to_error, record_authz_denial};
+use fp_domain::authz::{Action, Resource};
+use fp_domain::{DataplaneId, DomainResult, RequestId, TeamId};
+use sqlx::PgPool;
+
+const MAX_DATAPLANES: i64 = 500;
+const MAX_RECENT_NACKS: i64 = 50;
+
+#[derive(Debug, Clone)]
+pub struct Diagnostics {
+    /// `(resource, rows)` across every org and team.
+    pub resource_counts: Vec<(&'static str, i64)>,
+    /// Dataplanes most recently heard from first, at most [`MAX_DATAPLANES`].
+    pub connections: Vec<ConnectionDiagnostics>,
+    /// Newest NACKs across every team, at most [`MAX_RECENT_NACKS`].
+    pub recent_nacks: Vec<NackDiagnostics>,
+    pub database: DatabaseDiagnostics,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectionDiagnostics {
+    pub dataplane_id: DataplaneId,
+    pub team_id: TeamId,
+    pub live: bool,
+    pub version: i64,
+    pub last_heartbeat_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_config_verify_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub warming_failures: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NackDiagnostics {
+    pub id: uuid::Uuid,
+    pub team_id: TeamId,
+    pub type_url: String,
+    pub version_rejected: String,
+    pub quarantined_count: usize,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseDiagnostics {
+    pub reachable: bool,
+    pub pool_size: u32,
+    pub pool_idle: usize,
+    pub pool_max: u32,
+}
+
+pub async fn diagnostics(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+) -> DomainResult<Diagnostics> {
+    match check_resource_access(ctx, Resource::Platform, Action::Execute, None) {
+        Decision::Allow(_) => {}
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::Platform,
+                Action::Execute,
+                None,
+                reason,
+            )
+            .await;
+            return Err(deny_to_error(Resource::Platform, Action::Execute, reason));
+        }
+    }
+
+    let resource_counts = fp_storage::repos::diagnostics::resource_counts(pool).await?;
+    let now = chrono::Utc::now();
+    let connections = fp_storage::repos::dataplanes::list_all_by_heartbeat(pool, MAX_DATAPLANES)
+        .await?
+        .into_iter()
+        .map(|dataplane| ConnectionDiagnostics {
+            live: is_live(&dataplane, now),
+            dataplane_id: dataplane.id,
+            team_id: dataplane.team_id,
+            version: dataplane.version,
+            last_heartbeat_at: dataplane.last_heartbeat_at,
+            last_config_verify_at: dataplane.last_config_verify_at,
+            warming_failures: dataplane.warming_failures,
+        })
+        .collect();
+    let recent_nacks = fp_storage::repos::xds_nacks::list_recent_all(pool, MAX_RECENT_NACKS)
+        .await?
+        .into_iter()
+        .map(|nack| NackDiagnostics {
+            id: nack.id,
+            team_id: nack.team_id,
+            type_url: nack.type_url,
+            version_rejected: nack.version_rejected,
+            quarantined_count: nack.quarantined_resources.len(),
+            created_at: nack.created_at,
+        })
+        .collect();
+    let database = DatabaseDiagnostics {
+        reachable: fp_storage::ping(pool).await.is_ok(),
+        pool_size: pool.size(),
+        pool_idle: pool.num_idle(),
+        pool_max: pool.options().get_max_connections(),
+    };
+
+    Ok(Diagnostics {
+        resource_counts,
+        connections,
+        recent_nacks,
+        database,
+    })
+}
@@ -158,8 +158,7 @@ pub async fn start_session_with_policy(
     let route_config_name = format!("discovery-{short}-routes");
     let listener_name = format!("discovery-{short}");
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("start discovery: begin"))?;
 
@@ -268,8 +267,7 @@ pub async fn stop_session(
     let current = discovery::get(pool, team.id, session)
         .await?
         .ok_or_else(|| DomainError::not_found("discovery session", session))?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("stop discovery: begin"))?;
     let stopped = discovery::complete(&mut tx, team.id, session).await?;
@@ -349,9 +347,11 @@ fn cluster_spec(ip: &IpAddr, port: u16, spec: &DiscoverySessionSpec) -> ClusterS
             insecure_skip_verify: false,
         }),
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -364,7 +364,9 @@ fn route_config_spec(cluster_name: &str) -> RouteConfigSpec {
                 name: "catch-all".into(),
                 matcher: PathMatch::Prefix { prefix: "/".into() },
                 headers: Vec::new(),
+                methods: Vec::new(),
                 query_parameters: Vec::new(),
+                runtime_fraction: None,
                 action: RouteAction {
                     cluster: Some(cluster_name.into()),
                     weighted_clusters: None,
@@ -374,6 +376,16 @@ fn route_config_spec(cluster_name: &str) -> RouteConfigSpec {
                     template_rewrite: None,
                     timeout_secs: 15,
                     retry_policy: None,
+                    retry_policy_ref: None,
+                    idle_timeout_secs: None,
+                    max_stream_duration_secs: None,
+                    regex_rewrite: None,
+                    host_rewrite_literal: None,
+                    auto_host_rewrite: false,
+                    fallback_cluster: None,
+                    request_mirror_policies: Vec::new(),
+                    request_headers_to_remove: Vec::new(),
+                    hash_policy: Vec::new(),
                     rate_limits: Vec::new(),
                 },
                 filter_overrides: Vec::new(),
@@ -394,6 +406,11 @@ fn listener_spec(port: u16, route_config_name: &str) -> ListenerSpec {
         http_filters: Vec::new(),
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     }
 }
 
@@ -141,9 +141,11 @@ pub async fn expose(
             insecure_skip_verify: false,
         }),
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     };
     let route_config_spec = RouteConfigSpec {
         virtual_hosts: vec![VirtualHost {
@@ -155,7 +157,9 @@ pub async fn expose(
                     prefix: path.clone(),
                 },
                 headers: Vec::new(),
+                methods: Vec::new(),
                 query_parameters: Vec::new(),
+                runtime_fraction: None,
                 action: RouteAction {
                     cluster: Some(names.cluster.clone()),
                     weighted_clusters: None,
@@ -165,6 +169,16 @@ pub async fn expose(
                     template_rewrite: None,
                     timeout_secs: 15,
                     retry_policy: None,
+                    retry_policy_ref: None,
+                    idle_timeout_secs: None,
+                    max_stream_duration_secs: None,
+                    regex_rewrite: None,
+                    host_rewrite_literal: None,
+                    auto_host_rewrite: false,
+                    fallback_cluster: None,
+                    request_mirror_policies: Vec::new(),
+                    request_headers_to_remove: Vec::new(),
+                    hash_policy: Vec::new(),
                     rate_limits: Vec::new(),
                 },
                 filter_overrides: Vec::new(),
@@ -245,6 +259,11 @@ async fn create_resources_for_port(
         http_filters: Vec::new(),
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     };
 
     let cluster = clusters::create_cluster(
@@ -367,7 +386,17 @@ async fn allocate_port(
     team: TeamRef,
     request_id: RequestId,
 ) -> DomainResult<u16> {
-    let (listeners, _) = gateway::list_listeners(pool, ctx, team, 500, 0, request_id).await?;
+    let (listeners, _) = gateway::list_listeners(
+        pool,
+        ctx,
+        team,
+        None,
+        Default::default(),
+        500,
+        0,
+        request_id,
+    )
+    .await?;
     let used = listeners
         .into_iter()
         .map(|listener| listener.spec.port)
@@ -0,0 +1,195 @@
+//! Filter preset services. Presets hold a chain filter, so they use the `filters` grants and
+//! quota, like proto descriptors and wasm modules. A listener write copies the preset's filter
+//! into its chain ([`instantiate_presets`]), so preset mutations never change deployed config:
+//! there is no outbox event to emit and no reference to guard on delete.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::{actor_of, deny_to_error, record_authz_denial};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::gateway::filters::HttpFilterSpec;
+use fp_domain::gateway::listener::ListenerSpec;
+use fp_domain::gateway::FilterPreset;
+use fp_domain::{validate_name, DomainError, DomainResult, RequestId};
+use fp_storage::repos::{audit, filter_presets};
+use sqlx::PgPool;
+
+async fn authorize(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    action: Action,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, Resource::Filters, action, Some(team)) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::Filters,
+                action,
+                Some(team),
+                reason,
+            )
+            .await;
+            Err(deny_to_error(Resource::Filters, action, reason))
+        }
+    }
+}
+
+/// A preset holds a concrete filter; a preset of a preset would only add indirection.
+fn validate_preset_spec(spec: &HttpFilterSpec) -> DomainResult<()> {
+    if matches!(spec, HttpFilterSpec::Preset(_)) {
+        return Err(DomainError::validation(
+            "a filter preset cannot reference another preset",
+        ));
+    }
+    spec.validate()
+}
+
+pub async fn create_filter_preset(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: HttpFilterSpec,
+    request_id: RequestId,
+) -> DomainResult<FilterPreset> {
+    authorize(pool, ctx, Action::Create, team, request_id).await?;
+    validate_name(name)?;
+    validate_preset_spec(&spec)?;
+    crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Filters).await?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("create filter preset: begin"))?;
+    let preset = filter_presets::create(&mut tx, team, name, &spec).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "filter_preset.create", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create filter preset: commit"))?;
+    Ok(preset)
+}
+
+pub async fn list_filter_presets(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<FilterPreset>, i64)> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    filter_presets::list(pool, team.id, limit, offset).await
+}
+
+pub async fn get_filter_preset(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<FilterPreset> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    filter_presets::get(pool, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("filter preset", name))
+}
+
+pub async fn update_filter_preset(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: HttpFilterSpec,
+    expected_version: i64,
+    request_id: RequestId,
+) -> DomainResult<FilterPreset> {
+    authorize(pool, ctx, Action::Update, team, request_id).await?;
+    validate_preset_spec(&spec)?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("update filter preset: begin"))?;
+    let preset = filter_presets::update(&mut tx, team.id, name, &spec, expected_version).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "filter_preset.update", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("update filter preset: commit"))?;
+    Ok(preset)
+}
+
+pub async fn delete_filter_preset(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    expected_version: i64,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(pool, ctx, Action::Delete, team, request_id).await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("delete filter preset: begin"))?;
+    filter_presets::delete(&mut tx, team.id, name, expected_version).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "filter_preset.delete", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("delete filter preset: commit"))?;
+    Ok(())
+}
+
+/// Listener write step (before validation): replace every `preset` chain entry with a copy
+/// of the named same-team preset's filter, keeping the entry's `disabled` flag. An unknown
+/// name is a 404, matching the cross-tenant disclosure rule.
+pub(crate) async fn instantiate_presets(
+    pool: &PgPool,
+    team: TeamRef,
+    spec: &mut ListenerSpec,
+) -> DomainResult<()> {
+    for entry in spec.http_filters.iter_mut() {
+        let HttpFilterSpec::Preset(preset_ref) = &entry.filter else {
+            continue;
+        };
+        let preset = filter_presets::get(pool, team.id, &preset_ref.name)
+            .await?
+            .ok_or_else(|| DomainError::not_found("filter preset", &preset_ref.name))?;
+        entry.filter = preset.spec;
+    }
+    Ok(())
+}
+
+fn mutation_audit(
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+    team: TeamRef,
+    action: &str,
+    name: &str,
+) -> audit::AuditEntry {
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::AuditEntry {
+        request_id: Some(request_id),
+        actor_type,
+        actor_id,
+        actor_label: String::new(),
+        surface: audit::Surface::Rest,
+        action: action.into(),
+        resource: format!("filter-presets/{name}"),
+        org_id: Some(team.org_id),
+        team_id: Some(team.id),
+        outcome: audit::Outcome::Success,
+        detail: serde_json::json!({}),
+    }
+}
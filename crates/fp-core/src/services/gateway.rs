@@ -6,14 +6,19 @@ use crate::services::rls_sync::{compose_domain, namespace_uuid};
 use crate::services::{actor_of, deny_to_error, record_authz_denial, trace_context_json};
 use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::event::{DomainEvent, EventScope};
-use fp_domain::gateway::cluster::RESERVED_RATE_LIMIT_CLUSTER;
-use fp_domain::gateway::filters::HttpFilterSpec;
-use fp_domain::gateway::listener::{Listener, ListenerSpec};
-use fp_domain::gateway::route_config::{RouteConfig, RouteConfigSpec};
-use fp_domain::{validate_name, DomainError, DomainResult, RequestId};
+use fp_domain::gateway::cluster::{LbPolicy, RESERVED_RATE_LIMIT_CLUSTER};
+use fp_domain::gateway::filters::{
+    effective_filters, EffectiveFilter, FilterOverride, HttpFilterEntry, HttpFilterSpec,
+    WasmConfig, WasmModuleSource,
+};
+use fp_domain::gateway::listener::{Listener, ListenerMaintenance, ListenerSpec};
+use fp_domain::gateway::route_config::{RouteConfig, RouteConfigSpec, VirtualHost};
+use fp_domain::gateway::ListSort;
+use fp_domain::{validate_name, DomainError, DomainResult, RequestId, ResourceSource};
 use fp_storage::repos::{audit, clusters, gateway};
 use fp_storage::scope::TeamScope;
 use sqlx::PgPool;
+use std::collections::BTreeSet;
 
 async fn authorize(
     pool: &PgPool,
@@ -76,10 +81,11 @@ pub async fn create_route_config(
     .await?;
     validate_name(name)?;
     spec.validate()?;
+    crate::services::retry_policies::check_route_references(pool, team, &spec).await?;
+    warn_unhashed_affinity_clusters(pool, team, &spec).await;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::RouteConfigs)
         .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create rc: begin"))?;
     let rc = gateway::create_route_config(&mut tx, team, name, &spec).await?;
@@ -134,10 +140,41 @@ pub async fn get_route_config(
         .ok_or_else(|| fp_domain::DomainError::not_found("route config", name))
 }
 
+/// Copy a stored route config under `new_name`, optionally into another team of the same org,
+/// via the ordinary create path on the target team. Cluster and retry-policy references are by
+/// name, so a cross-team copy requires the target team to already hold resources of those names
+/// (else 404). As for clusters, the target's create grant is checked before the source is read.
+pub async fn clone_route_config(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    target: TeamRef,
+    new_name: &str,
+    request_id: RequestId,
+) -> DomainResult<RouteConfig> {
+    authorize(
+        pool,
+        ctx,
+        Resource::RouteConfigs,
+        Action::Create,
+        target,
+        request_id,
+    )
+    .await?;
+    let source = get_route_config(pool, ctx, team, name, request_id).await?;
+    create_route_config(pool, ctx, target, new_name, source.spec, request_id).await
+}
+
+/// The team's route configs in `sort` order; `source` keeps only hand-written or only
+/// OpenAPI-imported ones.
+#[allow(clippy::too_many_arguments)]
 pub async fn list_route_configs(
     pool: &PgPool,
     ctx: &PrincipalCtx,
     team: TeamRef,
+    source: Option<ResourceSource>,
+    sort: ListSort,
     limit: i64,
     offset: i64,
     request_id: RequestId,
@@ -151,7 +188,7 @@ pub async fn list_route_configs(
         request_id,
     )
     .await?;
-    gateway::list_route_configs(pool, team.id, limit, offset).await
+    gateway::list_route_configs(pool, team.id, source, sort, limit, offset).await
 }
 
 pub async fn update_route_config(
@@ -173,11 +210,353 @@ pub async fn update_route_config(
     )
     .await?;
     spec.validate()?;
-    let mut tx = pool
-        .begin()
+    crate::services::retry_policies::check_route_references(pool, team, &spec).await?;
+    warn_unhashed_affinity_clusters(pool, team, &spec).await;
+    write_route_config_update(
+        pool,
+        ctx,
+        team,
+        name,
+        &spec,
+        expected_version,
+        request_id,
+        "route_config.update",
+    )
+    .await
+}
+
+/// Append one virtual host to a stored route config. The whole config is re-validated, so a
+/// name or domain already used by another virtual host is rejected. `expected_version`, when
+/// given, must match; without it the revision read here guards the write, so a concurrent
+/// update in between is a revision mismatch, never a lost update.
+pub async fn add_virtual_host(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    vhost: VirtualHost,
+    expected_version: Option<i64>,
+    request_id: RequestId,
+) -> DomainResult<RouteConfig> {
+    let current = current_route_config_for_update(pool, ctx, team, name, request_id).await?;
+    let mut spec = current.spec;
+    spec.add_virtual_host(vhost)?;
+    crate::services::retry_policies::check_route_references(pool, team, &spec).await?;
+    warn_unhashed_affinity_clusters(pool, team, &spec).await;
+    write_route_config_update(
+        pool,
+        ctx,
+        team,
+        name,
+        &spec,
+        expected_version.unwrap_or(current.version),
+        request_id,
+        "route_config.virtual_host.add",
+    )
+    .await
+}
+
+/// Remove one virtual host by name from a stored route config (the last one cannot go).
+pub async fn remove_virtual_host(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    vhost_name: &str,
+    expected_version: Option<i64>,
+    request_id: RequestId,
+) -> DomainResult<RouteConfig> {
+    let current = current_route_config_for_update(pool, ctx, team, name, request_id).await?;
+    let mut spec = current.spec;
+    spec.remove_virtual_host(vhost_name)?;
+    write_route_config_update(
+        pool,
+        ctx,
+        team,
+        name,
+        &spec,
+        expected_version.unwrap_or(current.version),
+        request_id,
+        "route_config.virtual_host.remove",
+    )
+    .await
+}
+
+/// Where a canary endpoint moves a route's traffic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanaryShift {
+    /// Split between `stable_cluster` and `canary_cluster`, `canary_weight` percent to the
+    /// canary (0 and 100 collapse to a single cluster).
+    Split {
+        stable_cluster: String,
+        canary_cluster: String,
+        canary_weight: u32,
+    },
+    /// Send all traffic to the canary of the current split.
+    Promote,
+    /// Send all traffic back to the stable cluster of the current split.
+    Abort,
+}
+
+/// Rewrite one route's action for progressive delivery, via the guarded route-config update
+/// path (so xDS refreshes from the same event). Promote and abort need the route to be in a
+/// split written by [`CanaryShift::Split`]; afterwards it targets a single cluster.
+#[allow(clippy::too_many_arguments)]
+pub async fn shift_route_canary(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    vhost_name: &str,
+    route_name: &str,
+    shift: CanaryShift,
+    expected_version: Option<i64>,
+    request_id: RequestId,
+) -> DomainResult<RouteConfig> {
+    let current = current_route_config_for_update(pool, ctx, team, name, request_id).await?;
+    let mut spec = current.spec;
+    let (stable, canary, weight, audit_action) = match shift {
+        CanaryShift::Split {
+            stable_cluster,
+            canary_cluster,
+            canary_weight,
+        } => (
+            stable_cluster,
+            canary_cluster,
+            canary_weight,
+            "route_config.route.canary",
+        ),
+        CanaryShift::Promote => {
+            let (stable, canary) = spec.canary_split(vhost_name, route_name)?;
+            (stable, canary, 100, "route_config.route.canary.promote")
+        }
+        CanaryShift::Abort => {
+            let (stable, canary) = spec.canary_split(vhost_name, route_name)?;
+            (stable, canary, 0, "route_config.route.canary.abort")
+        }
+    };
+    spec.set_canary_split(vhost_name, route_name, &stable, &canary, weight)?;
+    crate::services::retry_policies::check_route_references(pool, team, &spec).await?;
+    write_route_config_update(
+        pool,
+        ctx,
+        team,
+        name,
+        &spec,
+        expected_version.unwrap_or(current.version),
+        request_id,
+        audit_action,
+    )
+    .await
+}
+
+/// The filters one listener runs for a route, resolved by
+/// [`fp_domain::gateway::filters::effective_filters`].
+#[derive(Debug, Clone)]
+pub struct ListenerEffectiveFilters {
+    pub listener: String,
+    pub filters: Vec<EffectiveFilter>,
+    /// Vhost/route overrides naming a filter type this listener's chain lacks.
+    pub unmatched_overrides: Vec<FilterOverride>,
+}
+
+/// Preview the effective filter set of one route, once per listener serving its route
+/// config (the chain lives on the listener, so two listeners can differ). Needs read on both
+/// route configs and listeners; an unbound route config yields an empty list.
+pub async fn effective_route_filters(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    vhost_name: &str,
+    route_name: &str,
+    request_id: RequestId,
+) -> DomainResult<Vec<ListenerEffectiveFilters>> {
+    let route_config = get_route_config(pool, ctx, team, name, request_id).await?;
+    authorize(
+        pool,
+        ctx,
+        Resource::Listeners,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    let vhost = route_config
+        .spec
+        .virtual_hosts
+        .iter()
+        .find(|vhost| vhost.name == vhost_name)
+        .ok_or_else(|| DomainError::not_found("virtual host", vhost_name))?;
+    let route = vhost
+        .routes
+        .iter()
+        .find(|route| route.name == route_name)
+        .ok_or_else(|| DomainError::not_found("route", route_name))?;
+
+    let mut serving = Vec::new();
+    let mut offset = 0;
+    loop {
+        let (page, total) =
+            gateway::list_listeners(pool, team.id, None, ListSort::default(), 500, offset).await?;
+        offset += page.len() as i64;
+        let done = page.is_empty() || offset >= total;
+        serving.extend(
+            page.into_iter()
+                .filter(|listener| listener.spec.route_config.as_deref() == Some(name)),
+        );
+        if done {
+            break;
+        }
+    }
+    Ok(serving
+        .into_iter()
+        .map(|listener| {
+            let (filters, unmatched_overrides) = effective_filters(
+                &listener.spec.http_filters,
+                &vhost.filter_overrides,
+                &route.filter_overrides,
+            );
+            ListenerEffectiveFilters {
+                listener: listener.name,
+                filters,
+                unmatched_overrides,
+            }
+        })
+        .collect())
+}
+
+/// One route whose action sends traffic to a cluster: as its target, in its weighted list, as
+/// its fallback, or as a mirror.
+#[derive(Debug, Clone)]
+pub struct ClusterRouteReference {
+    pub route_config: String,
+    pub virtual_host: String,
+    pub route: String,
+}
+
+/// Reverse dependencies of one cluster within its team.
+#[derive(Debug, Clone, Default)]
+pub struct ClusterReferences {
+    pub route_configs: Vec<String>,
+    pub routes: Vec<ClusterRouteReference>,
+    /// Listeners serving one of `route_configs`.
+    pub listeners: Vec<String>,
+    /// Listeners naming the cluster themselves: an `ext_authz` or `global_rate_limit` service,
+    /// an `otel` access-log or tracing collector, or a remote `wasm` module host.
+    pub listener_services: Vec<String>,
+    /// Aggregate clusters listing it as a member.
+    pub aggregates: Vec<String>,
+}
+
+/// What would be affected by changing or deleting a cluster. Needs read on clusters, route
+/// configs and listeners; a missing cluster is a 404.
+pub async fn cluster_references(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<ClusterReferences> {
+    crate::services::clusters::get_cluster(pool, ctx, team, name, request_id).await?;
+    for resource in [Resource::RouteConfigs, Resource::Listeners] {
+        authorize(pool, ctx, resource, Action::Read, team, request_id).await?;
+    }
+
+    let mut refs = ClusterReferences::default();
+    for route_config in gateway::route_configs_using_cluster(pool, team.id, name).await? {
+        for vhost in &route_config.spec.virtual_hosts {
+            for route in &vhost.routes {
+                if route.action.referenced_clusters().any(|c| c == name) {
+                    refs.routes.push(ClusterRouteReference {
+                        route_config: route_config.name.clone(),
+                        virtual_host: vhost.name.clone(),
+                        route: route.name.clone(),
+                    });
+                }
+            }
+        }
+        refs.route_configs.push(route_config.name);
+    }
+
+    let mut offset = 0;
+    loop {
+        let (page, total) =
+            gateway::list_listeners(pool, team.id, None, ListSort::default(), 500, offset).await?;
+        offset += page.len() as i64;
+        let done = page.is_empty() || offset >= total;
+        for listener in page {
+            let serves = listener
+                .spec
+                .route_config
+                .as_ref()
+                .is_some_and(|rc| refs.route_configs.contains(rc));
+            let rate_limit_services =
+                listener
+                    .spec
+                    .http_filters
+                    .iter()
+                    .filter_map(|entry| match &entry.filter {
+                        HttpFilterSpec::GlobalRateLimit(cfg) => Some(cfg.service_cluster.as_str()),
+                        _ => None,
+                    });
+            let names_it = listener_cluster_refs(&listener.spec)
+                .chain(rate_limit_services)
+                .any(|c| c == name);
+            if serves {
+                refs.listeners.push(listener.name.clone());
+            }
+            if names_it {
+                refs.listener_services.push(listener.name);
+            }
+        }
+        if done {
+            break;
+        }
+    }
+
+    refs.aggregates = clusters::aggregates_listing(pool, team.id, name).await?;
+    Ok(refs)
+}
+
+async fn current_route_config_for_update(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<RouteConfig> {
+    authorize(
+        pool,
+        ctx,
+        Resource::RouteConfigs,
+        Action::Update,
+        team,
+        request_id,
+    )
+    .await?;
+    gateway::get_route_config(pool, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("route config", name))
+}
+
+/// The update transaction shared by full and incremental route-config writes: row, event,
+/// audit. The spec must already be validated and its references checked.
+#[allow(clippy::too_many_arguments)]
+async fn write_route_config_update(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: &RouteConfigSpec,
+    expected_version: i64,
+    request_id: RequestId,
+    audit_action: &str,
+) -> DomainResult<RouteConfig> {
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update rc: begin"))?;
-    let rc = gateway::update_route_config(&mut tx, team, name, &spec, expected_version).await?;
+    let rc = gateway::update_route_config(&mut tx, team, name, spec, expected_version).await?;
     fp_storage::outbox::append(
         &mut tx,
         &DomainEvent::RouteConfigUpserted {
@@ -197,7 +576,7 @@ pub async fn update_route_config(
             ctx,
             request_id,
             team,
-            "route_config.update",
+            audit_action,
             format!("route-configs/{name}"),
         ),
     )
@@ -225,8 +604,7 @@ pub async fn delete_route_config(
         request_id,
     )
     .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete rc: begin"))?;
     let rc_id = gateway::delete_route_config(&mut tx, team.id, name, expected_version).await?;
@@ -260,8 +638,92 @@ pub async fn delete_route_config(
     Ok(())
 }
 
+/// Move a route config to another team of the same org, keeping its id and spec. Needs delete
+/// on the source team and create on the target. As on create, the clusters and retry policies
+/// it names must already exist in the target team; listeners or AI budgets still bound to it in
+/// the source team block the move.
+#[allow(clippy::too_many_arguments)]
+pub async fn transfer_route_config(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    target: TeamRef,
+    expected_version: i64,
+    request_id: RequestId,
+) -> DomainResult<RouteConfig> {
+    authorize(
+        pool,
+        ctx,
+        Resource::RouteConfigs,
+        Action::Delete,
+        team,
+        request_id,
+    )
+    .await?;
+    authorize(
+        pool,
+        ctx,
+        Resource::RouteConfigs,
+        Action::Create,
+        target,
+        request_id,
+    )
+    .await?;
+    crate::services::ensure_distinct_teams(team, target)?;
+    let source = get_route_config(pool, ctx, team, name, request_id).await?;
+    crate::services::retry_policies::check_route_references(pool, target, &source.spec).await?;
+    crate::services::quota::check_team_resource_quota(pool, target.id, Resource::RouteConfigs)
+        .await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("transfer rc: begin"))?;
+    let rc =
+        gateway::transfer_route_config(&mut tx, team.id, target, name, expected_version).await?;
+    crate::services::record_transfer_in_tx(
+        &mut tx,
+        team,
+        target,
+        DomainEvent::RouteConfigDeleted {
+            route_config_id: rc.id.as_uuid(),
+            name: name.into(),
+        },
+        DomainEvent::RouteConfigUpserted {
+            route_config_id: rc.id.as_uuid(),
+            name: name.into(),
+        },
+        |scope| {
+            mutation_audit(
+                ctx,
+                request_id,
+                scope,
+                "route_config.transfer",
+                format!("route-configs/{name}"),
+            )
+        },
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("transfer rc: commit"))?;
+    Ok(rc)
+}
+
 // ---------------- listeners ----------------
 
+/// Dry-run a listener filter chain: the per-filter config and chain-shape checks a listener
+/// write runs, with nothing read or written — so any authenticated caller may use it.
+/// Team-owned references (proto descriptors, rate-limit domains) only resolve on a real write.
+/// Returns the filter types in execution order; the router is always appended last.
+pub fn validate_filter_chain(entries: &[HttpFilterEntry]) -> DomainResult<Vec<&'static str>> {
+    fp_domain::gateway::filters::validate_filter_chain(entries)?;
+    Ok(entries
+        .iter()
+        .map(|entry| entry.filter.kind())
+        .chain(["router"])
+        .collect())
+}
+
 /// Fail-closed config-time handling of any `global_rate_limit` filter the listener carries
 /// (design Security L242 "fail closed at config time" + L223 "the domain value the filter is
 /// configured with"). Runs *after* `spec.validate()` and *before* the write tx; mutates `spec`
@@ -299,13 +761,8 @@ async fn resolve_global_rate_limit_filters(
             // and counters bind. Idempotent: a GET returns the composed value, so a GET→PATCH
             // round-trip resubmits it — strip this team's own prefix first so we re-compose the
             // base domain instead of double-namespacing (which would silently break enforcement).
-            let prefix = format!(
-                "{}|{}|",
-                namespace_uuid(team.org_id.as_uuid()),
-                namespace_uuid(team.id.as_uuid()),
-            );
-            let base = cfg.domain.strip_prefix(&prefix).unwrap_or(&cfg.domain);
-            cfg.domain = compose_domain(team.org_id, team.id, base);
+            let base = strip_team_domain_prefix(team, &cfg.domain).to_string();
+            cfg.domain = compose_domain(team.org_id, team.id, &base);
             // Re-validate — the composed value must still fit the (S6-raised) domain ceiling even
             // if the user supplied an over-long base domain.
             cfg.validate()?;
@@ -319,7 +776,132 @@ async fn resolve_global_rate_limit_filters(
     Ok(())
 }
 
+/// An `ext_authz` filter must reference an existing same-team cluster in either mode — else
+/// 404, as for a user-supplied `global_rate_limit` cluster. Translation would otherwise emit a
+/// filter Envoy cannot route checks through, failing every request closed. The collectors of
+/// `otel` access logs and of the tracer are held to the same rule, since Envoy would drop
+/// their logs and spans silently, and so is the host cluster of a remote `wasm` module, whose
+/// code Envoy could never fetch.
+async fn check_listener_clusters(
+    pool: &PgPool,
+    team: TeamRef,
+    spec: &ListenerSpec,
+) -> DomainResult<()> {
+    for cluster in listener_cluster_refs(spec) {
+        if clusters::get(pool, TeamScope::Team(team.id), cluster)
+            .await?
+            .is_none()
+        {
+            return Err(DomainError::not_found("cluster", cluster));
+        }
+    }
+    Ok(())
+}
+
+/// Clusters a listener names outside its route config and rate-limit filters: `ext_authz`
+/// services, `otel` access-log and tracing collectors, and remote `wasm` module hosts.
+fn listener_cluster_refs(spec: &ListenerSpec) -> impl Iterator<Item = &str> {
+    let ext_authz = spec
+        .http_filters
+        .iter()
+        .filter_map(|entry| match &entry.filter {
+            HttpFilterSpec::ExtAuthz(cfg) => Some(cfg.cluster.as_str()),
+            _ => None,
+        });
+    let collectors = spec
+        .access_logs
+        .iter()
+        .filter_map(|log| log.otel.as_ref().map(|otel| otel.cluster.as_str()))
+        .chain(spec.tracing.as_ref().map(|tracing| tracing.cluster()));
+    let wasm_hosts = spec
+        .http_filters
+        .iter()
+        .filter_map(|entry| match &entry.filter {
+            HttpFilterSpec::Wasm(WasmConfig {
+                module: WasmModuleSource::Remote { cluster, .. },
+                ..
+            }) => Some(cluster.as_str()),
+            _ => None,
+        });
+    ext_authz.chain(collectors).chain(wasm_hosts)
+}
+
+/// Route-config write advisory: a `hash_policy` only steers clusters that hash (`ring-hash` or
+/// `maglev`); on any other lb policy Envoy ignores it silently. Logged rather than rejected,
+/// since the cluster's policy may change after the route is written.
+async fn warn_unhashed_affinity_clusters(pool: &PgPool, team: TeamRef, spec: &RouteConfigSpec) {
+    let hashed_targets: BTreeSet<(&str, &str)> = spec
+        .virtual_hosts
+        .iter()
+        .flat_map(|vhost| &vhost.routes)
+        .filter(|rule| !rule.action.hash_policy.is_empty())
+        .flat_map(|rule| {
+            let action = &rule.action;
+            action
+                .cluster
+                .iter()
+                .chain(action.fallback_cluster.iter())
+                .chain(
+                    action
+                        .weighted_clusters
+                        .iter()
+                        .flatten()
+                        .map(|t| &t.cluster),
+                )
+                .map(move |cluster| (rule.name.as_str(), cluster.as_str()))
+        })
+        .collect();
+    for (route, cluster) in hashed_targets {
+        let lb_policy = match clusters::get(pool, TeamScope::Team(team.id), cluster).await {
+            Ok(Some(found)) => found.spec.lb_policy,
+            // A missing cluster is a dangling ref reported elsewhere; never fail the write here.
+            Ok(None) | Err(_) => continue,
+        };
+        if !matches!(lb_policy, LbPolicy::RingHash | LbPolicy::Maglev) {
+            tracing::warn!(team = %team.id, route, cluster, ?lb_policy,
+                "route hash_policy has no effect: cluster lb_policy is not ring-hash or maglev");
+        }
+    }
+}
+
+/// The base rate-limit domain of a CP-composed `{org}|{team}|{domain}` value, or the input
+/// unchanged when it does not carry `team`'s namespace.
+fn strip_team_domain_prefix(team: TeamRef, domain: &str) -> &str {
+    let prefix = format!(
+        "{}|{}|",
+        namespace_uuid(team.org_id.as_uuid()),
+        namespace_uuid(team.id.as_uuid()),
+    );
+    domain.strip_prefix(&prefix).unwrap_or(domain)
+}
+
 pub async fn create_listener(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: ListenerSpec,
+    request_id: RequestId,
+    rls_grpc_configured: bool,
+) -> DomainResult<Listener> {
+    create_listener_with_order(
+        pool,
+        ctx,
+        team,
+        name,
+        spec,
+        request_id,
+        rls_grpc_configured,
+        false,
+    )
+    .await
+}
+
+/// [`create_listener`] that, with `auto_order`, first sorts the HTTP filter chain into the
+/// recommended order (after presets are instantiated, so they sort by their real type). The
+/// returned listener carries the final order.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_listener_with_order(
     pool: &PgPool,
     ctx: &PrincipalCtx,
     team: TeamRef,
@@ -327,6 +909,7 @@ pub async fn create_listener(
     mut spec: ListenerSpec,
     request_id: RequestId,
     rls_grpc_configured: bool,
+    auto_order: bool,
 ) -> DomainResult<Listener> {
     authorize(
         pool,
@@ -338,11 +921,18 @@ pub async fn create_listener(
     )
     .await?;
     validate_user_listener_name(name)?;
+    crate::services::filter_presets::instantiate_presets(pool, team, &mut spec).await?;
+    crate::services::orgs::apply_default_listener_filters(pool, team, &mut spec).await?;
+    if auto_order {
+        fp_domain::gateway::filters::auto_order_filter_chain(&mut spec.http_filters);
+    }
     spec.validate()?;
     resolve_global_rate_limit_filters(pool, team, &mut spec, rls_grpc_configured).await?;
+    check_listener_clusters(pool, team, &spec).await?;
+    crate::services::proto_descriptors::check_transcoder_references(pool, team, &spec).await?;
+    crate::services::wasm_modules::check_wasm_references(pool, team, &spec).await?;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Listeners).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create listener: begin"))?;
     let listener = gateway::create_listener(&mut tx, team, name, &spec).await?;
@@ -397,10 +987,62 @@ pub async fn get_listener(
         .ok_or_else(|| fp_domain::DomainError::not_found("listener", name))
 }
 
+/// Copy a stored listener under `new_name`, optionally into another team of the same org, via
+/// the ordinary create path on the target team. Built-in global rate-limit filters carry the
+/// SOURCE team's composed domain; it is reduced back to the base domain here so the create path
+/// re-namespaces it for the target instead of nesting two team prefixes. Listener ports are
+/// unique per team, so a same-team clone collides (409) until the copy's port is changed — clone
+/// into another team, or create from the fetched spec with a new port. The target's create grant
+/// is checked before the source is read.
+#[allow(clippy::too_many_arguments)]
+pub async fn clone_listener(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    target: TeamRef,
+    new_name: &str,
+    request_id: RequestId,
+    rls_grpc_configured: bool,
+) -> DomainResult<Listener> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Listeners,
+        Action::Create,
+        target,
+        request_id,
+    )
+    .await?;
+    let mut spec = get_listener(pool, ctx, team, name, request_id).await?.spec;
+    for entry in spec.http_filters.iter_mut() {
+        if let HttpFilterSpec::GlobalRateLimit(cfg) = &mut entry.filter {
+            if cfg.service_cluster == RESERVED_RATE_LIMIT_CLUSTER {
+                cfg.domain = strip_team_domain_prefix(team, &cfg.domain).to_string();
+            }
+        }
+    }
+    create_listener(
+        pool,
+        ctx,
+        target,
+        new_name,
+        spec,
+        request_id,
+        rls_grpc_configured,
+    )
+    .await
+}
+
+/// The team's listeners in `sort` order; `source` keeps only hand-written or only
+/// OpenAPI-imported ones.
+#[allow(clippy::too_many_arguments)]
 pub async fn list_listeners(
     pool: &PgPool,
     ctx: &PrincipalCtx,
     team: TeamRef,
+    source: Option<ResourceSource>,
+    sort: ListSort,
     limit: i64,
     offset: i64,
     request_id: RequestId,
@@ -414,7 +1056,7 @@ pub async fn list_listeners(
         request_id,
     )
     .await?;
-    gateway::list_listeners(pool, team.id, limit, offset).await
+    gateway::list_listeners(pool, team.id, source, sort, limit, offset).await
 }
 
 // The listener update path carries one argument past clippy's threshold (the `rls_grpc_configured`
@@ -440,10 +1082,13 @@ pub async fn update_listener(
         request_id,
     )
     .await?;
+    crate::services::filter_presets::instantiate_presets(pool, team, &mut spec).await?;
     spec.validate()?;
     resolve_global_rate_limit_filters(pool, team, &mut spec, rls_grpc_configured).await?;
-    let mut tx = pool
-        .begin()
+    check_listener_clusters(pool, team, &spec).await?;
+    crate::services::proto_descriptors::check_transcoder_references(pool, team, &spec).await?;
+    crate::services::wasm_modules::check_wasm_references(pool, team, &spec).await?;
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update listener: begin"))?;
     let listener = gateway::update_listener(&mut tx, team, name, &spec, expected_version).await?;
@@ -477,6 +1122,80 @@ pub async fn update_listener(
     Ok(listener)
 }
 
+/// Put a listener into maintenance mode (`Some`) or take it out (`None`). The spec is not
+/// touched, so leaving maintenance serves exactly the configuration from before. Like the
+/// incremental route-config edits, `expected_version` is optional: without it the revision
+/// read here guards the write.
+pub async fn set_listener_maintenance(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    maintenance: Option<ListenerMaintenance>,
+    expected_version: Option<i64>,
+    request_id: RequestId,
+) -> DomainResult<Listener> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Listeners,
+        Action::Update,
+        team,
+        request_id,
+    )
+    .await?;
+    if let Some(maintenance) = &maintenance {
+        maintenance.validate()?;
+    }
+    let expected_version = match expected_version {
+        Some(version) => version,
+        None => {
+            gateway::get_listener(pool, team.id, name)
+                .await?
+                .ok_or_else(|| DomainError::not_found("listener", name))?
+                .version
+        }
+    };
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("set listener maintenance: begin"))?;
+    let listener = gateway::set_listener_maintenance(
+        &mut tx,
+        team.id,
+        name,
+        maintenance.as_ref(),
+        expected_version,
+    )
+    .await?;
+    fp_storage::outbox::append(
+        &mut tx,
+        &DomainEvent::ListenerUpserted {
+            listener_id: listener.id.as_uuid(),
+            name: name.into(),
+        },
+        EventScope {
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+        },
+        trace_context_json(),
+    )
+    .await?;
+    let action = if maintenance.is_some() {
+        "listener.maintenance.enable"
+    } else {
+        "listener.maintenance.disable"
+    };
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, action, format!("listeners/{name}")),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("set listener maintenance: commit"))?;
+    Ok(listener)
+}
+
 pub async fn delete_listener(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -494,8 +1213,7 @@ pub async fn delete_listener(
         request_id,
     )
     .await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete listener: begin"))?;
     let listener_id = gateway::delete_listener(&mut tx, team.id, name, expected_version).await?;
@@ -529,6 +1247,89 @@ pub async fn delete_listener(
     Ok(())
 }
 
+/// Move a listener to another team of the same org, keeping its id. Needs delete on the source
+/// team and create on the target. Built-in global rate-limit domains are re-namespaced for the
+/// target (as on clone), and the filter references a create would check — ext_authz clusters,
+/// transcoder descriptors, the route config — must resolve in the target team. The port must be
+/// free there.
+#[allow(clippy::too_many_arguments)]
+pub async fn transfer_listener(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    target: TeamRef,
+    expected_version: i64,
+    request_id: RequestId,
+    rls_grpc_configured: bool,
+) -> DomainResult<Listener> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Listeners,
+        Action::Delete,
+        team,
+        request_id,
+    )
+    .await?;
+    authorize(
+        pool,
+        ctx,
+        Resource::Listeners,
+        Action::Create,
+        target,
+        request_id,
+    )
+    .await?;
+    crate::services::ensure_distinct_teams(team, target)?;
+    let mut spec = get_listener(pool, ctx, team, name, request_id).await?.spec;
+    for entry in spec.http_filters.iter_mut() {
+        if let HttpFilterSpec::GlobalRateLimit(cfg) = &mut entry.filter {
+            if cfg.service_cluster == RESERVED_RATE_LIMIT_CLUSTER {
+                cfg.domain = strip_team_domain_prefix(team, &cfg.domain).to_string();
+            }
+        }
+    }
+    spec.validate()?;
+    resolve_global_rate_limit_filters(pool, target, &mut spec, rls_grpc_configured).await?;
+    check_listener_clusters(pool, target, &spec).await?;
+    crate::services::proto_descriptors::check_transcoder_references(pool, target, &spec).await?;
+    crate::services::wasm_modules::check_wasm_references(pool, target, &spec).await?;
+    crate::services::quota::check_team_resource_quota(pool, target.id, Resource::Listeners).await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("transfer listener: begin"))?;
+    let listener =
+        gateway::transfer_listener(&mut tx, team.id, target, name, &spec, expected_version).await?;
+    crate::services::record_transfer_in_tx(
+        &mut tx,
+        team,
+        target,
+        DomainEvent::ListenerDeleted {
+            listener_id: listener.id.as_uuid(),
+            name: name.into(),
+        },
+        DomainEvent::ListenerUpserted {
+            listener_id: listener.id.as_uuid(),
+            name: name.into(),
+        },
+        |scope| {
+            mutation_audit(
+                ctx,
+                request_id,
+                scope,
+                "listener.transfer",
+                format!("listeners/{name}"),
+            )
+        },
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("transfer listener: commit"))?;
+    Ok(listener)
+}
+
 fn validate_user_listener_name(name: &str) -> DomainResult<()> {
     validate_name(name)?;
     if name.starts_with("ai-") {
@@ -82,8 +82,7 @@ pub async fn start_session(
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::LearningSessions)
         .await?;
     let spec = resolve_api_name(pool, team, input.api, input.spec).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("start learning session: begin"))?;
     let session = api_lifecycle::create_capture_session(&mut tx, team, &input.name, &spec).await?;
@@ -195,8 +194,7 @@ pub async fn create_spec_version_from_session(
     request_id: RequestId,
 ) -> DomainResult<SpecVersion> {
     authorize(pool, ctx, Action::Execute, team, request_id).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("learn spec version: begin"))?;
     let (session_row, observations) =
@@ -212,14 +210,7 @@ pub async fn create_spec_version_from_session(
         ));
     }
 
-    let endpoints =
-        group_observations_by_endpoint(&observations, EndpointGroupingConfig::default())?;
-    let candidate = LearnedSpecCandidate {
-        title: format!("Learned {}", session_row.name),
-        version: format!("learned-{}", session_row.id),
-        endpoints,
-    };
-    let mut input = candidate.spec_version_input()?;
+    let mut input = learned_candidate(&session_row, &observations)?.spec_version_input()?;
     add_source_metadata(&mut input.spec, &session_row, api_id);
     input.validate()?;
 
@@ -266,6 +257,51 @@ pub async fn create_spec_version_from_session(
     Ok(spec_version)
 }
 
+/// Render what a session has learned so far as an OpenAPI document without storing it.
+/// Unlike `create_spec_version_from_session`, a session that is still capturing can be
+/// previewed, and the session need not be attached to an API definition.
+pub async fn learned_openapi(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    session: &str,
+    request_id: RequestId,
+) -> DomainResult<serde_json::Value> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    let session_row = api_lifecycle::get_capture_session(pool, team.id, session)
+        .await?
+        .ok_or_else(|| DomainError::not_found("learning session", session))?;
+    if !matches!(
+        session_row.status,
+        CaptureSessionStatus::Capturing | CaptureSessionStatus::Completed
+    ) {
+        return Err(DomainError::conflict(format!(
+            "learning session \"{}\" is {}",
+            session_row.name,
+            session_row.status.as_str()
+        )));
+    }
+    let observations = api_lifecycle::list_raw_observations(pool, team.id, session_row.id).await?;
+    if observations.is_empty() {
+        return Err(DomainError::validation(
+            "learning session has no raw observations to aggregate",
+        )
+        .with_hint("send traffic through the captured route, then try again"));
+    }
+    learned_candidate(&session_row, &observations)?.canonical_openapi()
+}
+
+fn learned_candidate(
+    session: &CaptureSession,
+    observations: &[fp_domain::api_lifecycle::RawObservation],
+) -> DomainResult<LearnedSpecCandidate> {
+    Ok(LearnedSpecCandidate {
+        title: format!("Learned {}", session.name),
+        version: format!("learned-{}", session.id),
+        endpoints: group_observations_by_endpoint(observations, EndpointGroupingConfig::default())?,
+    })
+}
+
 pub async fn create_spec_versions_from_discovery_session(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -274,8 +310,7 @@ pub async fn create_spec_versions_from_discovery_session(
     request_id: RequestId,
 ) -> DomainResult<Vec<SpecVersion>> {
     authorize(pool, ctx, Action::Execute, team, request_id).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("learn discovery specs: begin"))?;
     let (session_row, observations) =
@@ -433,9 +468,11 @@ async fn transition_session(
     request_id: RequestId,
 ) -> DomainResult<CaptureSession> {
     authorize(pool, ctx, transition.action, team, request_id).await?;
-    let mut tx = pool.begin().await.map_err(crate::services::db_err(
-        "transition learning session: begin",
-    ))?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err(
+            "transition learning session: begin",
+        ))?;
     let updated =
         api_lifecycle::transition_capture_session(&mut tx, team.id, session, transition.status)
             .await?;
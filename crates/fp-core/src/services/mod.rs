@@ -6,18 +6,26 @@ pub mod ai;
 pub mod api_lifecycle;
 pub mod clusters;
 pub mod dataplanes;
+pub mod diagnostics;
 pub mod discovery;
 pub mod egress_advisory;
 pub mod expose;
+pub mod filter_presets;
 pub mod gateway;
 pub mod learning;
 pub mod orgs;
+pub mod outliers;
+pub mod proto_descriptors;
 pub mod quota;
 pub mod rate_limit;
+pub mod retry_policies;
 pub mod rls_sync;
 pub mod route_generation;
+pub mod runtime;
 pub mod secrets;
 pub mod teams;
+pub mod wasm_modules;
+pub mod webhooks;
 pub mod xds_status;
 
 use crate::authz::{PrincipalCtx, Reason};
@@ -121,5 +129,51 @@ pub fn trace_context_json() -> serde_json::Value {
 }
 
 pub(crate) fn db_err(label: &'static str) -> impl Fn(sqlx::Error) -> DomainError {
-    move |e| DomainError::internal(format!("{label}: {e}"))
+    move |e| fp_storage::db_error(label, e)
+}
+
+/// Resource transfers move a row between two teams; naming the owning team as the target is a
+/// caller mistake, not a no-op.
+pub(crate) fn ensure_distinct_teams(team: TeamRef, target: TeamRef) -> Result<(), DomainError> {
+    if team.id == target.id {
+        return Err(
+            DomainError::validation("the resource already belongs to the target team")
+                .with_hint("name a different team to transfer to"),
+        );
+    }
+    Ok(())
+}
+
+/// The write side of a transfer, inside the caller's transaction: the source team sees the
+/// resource deleted and the target sees it upserted (so xDS rebuilds both), and each team's
+/// audit log records the move with both team ids.
+pub(crate) async fn record_transfer_in_tx(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    team: TeamRef,
+    target: TeamRef,
+    removed: fp_domain::event::DomainEvent,
+    added: fp_domain::event::DomainEvent,
+    audit_for: impl Fn(TeamRef) -> audit::AuditEntry,
+) -> Result<(), DomainError> {
+    for (event, scope) in [(removed, team), (added, target)] {
+        fp_storage::outbox::append(
+            tx,
+            &event,
+            fp_domain::event::EventScope {
+                org_id: Some(scope.org_id),
+                team_id: Some(scope.id),
+            },
+            trace_context_json(),
+        )
+        .await?;
+    }
+    for scope in [team, target] {
+        let mut entry = audit_for(scope);
+        entry.detail = serde_json::json!({
+            "from_team_id": team.id.as_uuid(),
+            "to_team_id": target.id.as_uuid(),
+        });
+        audit::record_in_tx(tx, &entry).await?;
+    }
+    Ok(())
 }
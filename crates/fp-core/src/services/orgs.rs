@@ -2,15 +2,20 @@
 //! Resource::Organizations). Member management within an org is org-admin territory —
 //! platform admins are NOT admitted (spec/05 §3.2 invariant 1), with one carve-out: the
 //! platform admin may add the FIRST owner to an org they just created (provisioning).
+//! Org default listener filters are org-admin territory too, with no carve-out.
 
 use crate::authz::{check_resource_access, Decision, PrincipalCtx};
 use crate::services::{actor_of, deny_to_error, record_authz_denial};
-use fp_domain::authz::{Action, Resource};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::gateway::filters::{
+    merge_default_filters, validate_org_default_filters, HttpFilterEntry,
+};
+use fp_domain::gateway::listener::ListenerSpec;
 use fp_domain::{
     DomainError, DomainResult, EntityStatus, ErrorCode, OrgId, OrgRole, Organization, RequestId,
     UserId,
 };
-use fp_storage::repos::{audit, identity};
+use fp_storage::repos::{audit, identity, org_defaults};
 use sqlx::PgPool;
 
 async fn authorize_governance(
@@ -266,8 +271,7 @@ async fn add_org_member_resolved(
     role: OrgRole,
     request_id: RequestId,
 ) -> DomainResult<()> {
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("add org member: begin"))?;
     identity::add_org_membership_in_tx(&mut tx, user, org_id, role).await?;
@@ -334,8 +338,7 @@ pub async fn remove_org_member(
                 .with_hint("promote another member to owner first"),
         );
     }
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("remove org member: begin"))?;
     if !identity::remove_org_membership_in_tx(&mut tx, user_id, org_id).await? {
@@ -360,3 +363,89 @@ pub async fn remove_org_member(
         .map_err(crate::services::db_err("remove org member: commit"))?;
     Ok(())
 }
+
+/// The org's default listener filters. Readable by any member of the org; a platform admin
+/// is not admitted — the chain is tenant config (invariant 1). Non-members get the same
+/// generic 404 as a missing org.
+pub async fn get_default_listener_filters(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    org_id: OrgId,
+) -> DomainResult<Vec<HttpFilterEntry>> {
+    let member = match ctx {
+        PrincipalCtx::User { user_id, .. } => {
+            identity::get_org_membership_role(pool, *user_id, org_id)
+                .await?
+                .is_some()
+        }
+        _ => false,
+    };
+    if !member {
+        return Err(org_not_found());
+    }
+    org_defaults::get_default_listener_filters(pool, org_id)
+        .await?
+        .ok_or_else(org_not_found)
+}
+
+/// Replace the org's default listener filters (org admins only). Teams that already have a
+/// listener are unaffected; each team's first listener picks up the set current at the time.
+pub async fn set_default_listener_filters(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    org_id: OrgId,
+    filters: Vec<HttpFilterEntry>,
+    request_id: RequestId,
+) -> DomainResult<Vec<HttpFilterEntry>> {
+    let admin = match ctx {
+        PrincipalCtx::User { user_id, .. } => {
+            identity::get_org_membership_role(pool, *user_id, org_id)
+                .await?
+                .is_some_and(|role| role.is_org_admin())
+        }
+        _ => false,
+    };
+    if !admin {
+        return Err(DomainError::new(
+            ErrorCode::Forbidden,
+            "org default filters require an org admin role in this organization",
+        ));
+    }
+    validate_org_default_filters(&filters)?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("set org default filters: begin"))?;
+    org_defaults::set_default_listener_filters(&mut tx, org_id, &filters).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &org_audit(
+            ctx,
+            request_id,
+            Some(org_id),
+            "org.default_filters.update",
+            format!("organizations/{org_id}/default-filters"),
+        ),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("set org default filters: commit"))?;
+    Ok(filters)
+}
+
+/// Merge the org's default filters into a team's FIRST user listener (see
+/// [`merge_default_filters`]). Later listeners are left exactly as written.
+pub(crate) async fn apply_default_listener_filters(
+    pool: &PgPool,
+    team: TeamRef,
+    spec: &mut ListenerSpec,
+) -> DomainResult<()> {
+    if fp_storage::repos::gateway::count_listeners(pool, team.id).await? > 0 {
+        return Ok(());
+    }
+    let defaults = org_defaults::get_default_listener_filters(pool, team.org_id)
+        .await?
+        .unwrap_or_default();
+    merge_default_filters(&mut spec.http_filters, &defaults);
+    Ok(())
+}
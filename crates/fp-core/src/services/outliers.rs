@@ -0,0 +1,65 @@
+//! Live outlier-ejection status for a cluster. Envoy keeps ejection state on the dataplane
+//! (admin `/clusters`, the outlier event log); nothing in the control plane receives it yet,
+//! so the server answers through [`Unavailable`] and the response says so explicitly rather
+//! than presenting an empty list as "no host ejected". A feed (event-log shipping, dataplane
+//! telemetry) plugs in as another [`OutlierSource`].
+
+use crate::authz::PrincipalCtx;
+use crate::services::clusters::get_cluster;
+use fp_domain::authz::TeamRef;
+use fp_domain::{DomainResult, RequestId, TeamId};
+use sqlx::PgPool;
+
+/// One endpoint currently ejected from a cluster's load-balancing set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlierEjection {
+    /// `host:port` of the ejected endpoint.
+    pub address: String,
+    /// Envoy node id of the dataplane that ejected it (ejection is per-Envoy state).
+    pub node_id: String,
+    /// Envoy detector, e.g. `consecutive_5xx` or `success_rate`.
+    pub reason: String,
+    pub ejected_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Where ejection state comes from. `None` means the source has no data for the cluster
+/// (unavailable), which is distinct from `Some(vec![])` (known, nothing ejected).
+pub trait OutlierSource: Send + Sync {
+    fn ejections(&self, team: TeamId, cluster: &str) -> Option<Vec<OutlierEjection>>;
+}
+
+/// The source the server wires today: the control plane has no ejection feed.
+pub struct Unavailable;
+
+impl OutlierSource for Unavailable {
+    fn ejections(&self, _team: TeamId, _cluster: &str) -> Option<Vec<OutlierEjection>> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClusterOutliers {
+    pub cluster: String,
+    /// Whether the cluster spec enables outlier detection at all.
+    pub outlier_detection_configured: bool,
+    /// `None` when no source reports ejection state for this cluster.
+    pub ejections: Option<Vec<OutlierEjection>>,
+}
+
+/// Ejection status of one cluster. Authorized and resolved like a cluster read, so an
+/// unknown or invisible cluster is the same 404 as `GET …/clusters/{name}`.
+pub async fn cluster_outliers(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    source: &dyn OutlierSource,
+    request_id: RequestId,
+) -> DomainResult<ClusterOutliers> {
+    let cluster = get_cluster(pool, ctx, team, name, request_id).await?;
+    Ok(ClusterOutliers {
+        outlier_detection_configured: cluster.spec.outlier_detection.is_some(),
+        ejections: source.ejections(team.id, &cluster.name),
+        cluster: cluster.name,
+    })
+}
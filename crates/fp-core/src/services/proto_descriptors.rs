@@ -0,0 +1,236 @@
+//! Proto descriptor services. An upload must decode as a `google.protobuf.FileDescriptorSet`
+//! declaring at least one service; listeners then reference it by id from a
+//! `grpc_json_transcoder` chain filter. Descriptor sets are immutable — replace by uploading
+//! under a new name and repointing the listener.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::{actor_of, deny_to_error, record_authz_denial};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::gateway::filters::HttpFilterSpec;
+use fp_domain::gateway::listener::ListenerSpec;
+use fp_domain::gateway::proto_descriptor::MAX_DESCRIPTOR_SET_BYTES;
+use fp_domain::gateway::ProtoDescriptor;
+use fp_domain::{validate_name, DomainError, DomainResult, RequestId};
+use fp_storage::repos::{audit, proto_descriptors};
+use prost::Message;
+use sqlx::PgPool;
+
+async fn authorize(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    action: Action,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, Resource::Filters, action, Some(team)) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::Filters,
+                action,
+                Some(team),
+                reason,
+            )
+            .await;
+            Err(deny_to_error(Resource::Filters, action, reason))
+        }
+    }
+}
+
+/// Decode `bytes` as a `FileDescriptorSet` and return its fully-qualified service names,
+/// sorted. Rejects anything that does not decode, and sets with no files or no services
+/// (nothing a transcoder could reference).
+pub fn parse_descriptor_set(bytes: &[u8]) -> DomainResult<Vec<String>> {
+    if bytes.len() > MAX_DESCRIPTOR_SET_BYTES {
+        return Err(DomainError::validation(format!(
+            "descriptor set is {} bytes; the limit is {MAX_DESCRIPTOR_SET_BYTES}",
+            bytes.len()
+        )));
+    }
+    let hint = "generate one with `protoc --include_imports --descriptor_set_out=api.pb api.proto`";
+    let set = prost_types::FileDescriptorSet::decode(bytes).map_err(|e| {
+        DomainError::validation(format!(
+            "descriptor_set does not parse as a FileDescriptorSet: {e}"
+        ))
+        .with_hint(hint)
+    })?;
+    if set.file.is_empty() || set.file.iter().any(|file| file.name().is_empty()) {
+        return Err(DomainError::validation(
+            "descriptor_set must contain at least one named proto file",
+        )
+        .with_hint(hint));
+    }
+    let mut services: Vec<String> = set
+        .file
+        .iter()
+        .flat_map(|file| {
+            file.service
+                .iter()
+                .map(move |service| match file.package() {
+                    "" => service.name().to_string(),
+                    package => format!("{package}.{}", service.name()),
+                })
+        })
+        .collect();
+    if services.is_empty() {
+        return Err(
+            DomainError::validation("descriptor_set declares no gRPC services").with_hint(hint),
+        );
+    }
+    services.sort();
+    services.dedup();
+    Ok(services)
+}
+
+pub async fn create_proto_descriptor(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    descriptor_set: &[u8],
+    request_id: RequestId,
+) -> DomainResult<ProtoDescriptor> {
+    authorize(pool, ctx, Action::Create, team, request_id).await?;
+    validate_name(name)?;
+    let services = parse_descriptor_set(descriptor_set)?;
+    crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Filters).await?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("create proto descriptor: begin"))?;
+    let descriptor =
+        proto_descriptors::create(&mut tx, team, name, descriptor_set, &services).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "proto_descriptor.create", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create proto descriptor: commit"))?;
+    Ok(descriptor)
+}
+
+pub async fn list_proto_descriptors(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<ProtoDescriptor>, i64)> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    proto_descriptors::list(pool, team.id, limit, offset).await
+}
+
+pub async fn get_proto_descriptor(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<ProtoDescriptor> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    proto_descriptors::get(pool, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("proto descriptor", name))
+}
+
+pub async fn delete_proto_descriptor(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    expected_version: i64,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(pool, ctx, Action::Delete, team, request_id).await?;
+    let descriptor = proto_descriptors::get(pool, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("proto descriptor", name))?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("delete proto descriptor: begin"))?;
+    // Same rule as clusters: no silent cascade out from under a serving listener.
+    let dependents =
+        proto_descriptors::listeners_referencing(&mut tx, team.id, descriptor.id).await?;
+    if !dependents.is_empty() {
+        return Err(DomainError::conflict(format!(
+            "proto descriptor \"{name}\" is referenced by listeners: {}",
+            dependents.join(", ")
+        ))
+        .with_hint("remove the grpc_json_transcoder filter from those listeners first"));
+    }
+    proto_descriptors::delete(&mut tx, team.id, name, expected_version).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "proto_descriptor.delete", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("delete proto descriptor: commit"))?;
+    Ok(())
+}
+
+/// Listener write check: every `grpc_json_transcoder` must reference a descriptor set in the
+/// same team (else 404, matching the cross-tenant disclosure rule) that declares each of its
+/// `services`.
+pub(crate) async fn check_transcoder_references(
+    pool: &PgPool,
+    team: TeamRef,
+    spec: &ListenerSpec,
+) -> DomainResult<()> {
+    for entry in &spec.http_filters {
+        let HttpFilterSpec::GrpcJsonTranscoder(cfg) = &entry.filter else {
+            continue;
+        };
+        let descriptor = proto_descriptors::get_by_id(pool, team.id, cfg.proto_descriptor_id)
+            .await?
+            .ok_or_else(|| {
+                DomainError::not_found("proto descriptor", &cfg.proto_descriptor_id.to_string())
+            })?;
+        if let Some(missing) = cfg
+            .services
+            .iter()
+            .find(|service| !descriptor.services.contains(service))
+        {
+            return Err(DomainError::validation(format!(
+                "grpc_json_transcoder: service \"{missing}\" is not declared by proto \
+                 descriptor \"{}\"",
+                descriptor.name
+            ))
+            .with_hint(format!(
+                "declared services: {}",
+                descriptor.services.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn mutation_audit(
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+    team: TeamRef,
+    action: &str,
+    name: &str,
+) -> audit::AuditEntry {
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::AuditEntry {
+        request_id: Some(request_id),
+        actor_type,
+        actor_id,
+        actor_label: String::new(),
+        surface: audit::Surface::Rest,
+        action: action.into(),
+        resource: format!("proto-descriptors/{name}"),
+        org_id: Some(team.org_id),
+        team_id: Some(team.id),
+        outcome: audit::Outcome::Success,
+        detail: serde_json::json!({}),
+    }
+}
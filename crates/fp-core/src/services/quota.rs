@@ -1,17 +1,58 @@
-//! Per-tenant resource quotas (spec/10 §4): defaults here, per-team overrides land with
-//! the platform-admin surface. Enforced in the service layer before any write.
+//! Per-tenant resource quotas (spec/10 §4): defaults here, per-team overrides set by platform
+//! admins. Enforced in the service layer before any write.
 
-use fp_domain::authz::Resource;
-use fp_domain::{DomainError, DomainResult, ErrorCode, TeamId};
+use crate::authz::PrincipalCtx;
+use crate::services::actor_of;
+use fp_domain::authz::{Resource, TeamRef};
+use fp_domain::gateway::MAX_XDS_RESOURCES_PER_TYPE;
+use fp_domain::{DomainError, DomainResult, ErrorCode, RequestId, TeamId};
+use fp_storage::repos::{audit, quotas};
 use sqlx::PgPool;
+use std::collections::BTreeMap;
 
-/// Conservative defaults (Q-003 founder defaults); platform-admin overrides come with S4.
+/// Resource kinds counted against a team quota, in display order.
+pub const QUOTA_RESOURCES: &[Resource] = &[
+    Resource::Clusters,
+    Resource::RouteConfigs,
+    Resource::Listeners,
+    Resource::Filters,
+    Resource::Secrets,
+    Resource::Dataplanes,
+    Resource::ApiDefinitions,
+    Resource::LearningSessions,
+    Resource::RateLimits,
+    Resource::AiProviders,
+    Resource::AiRoutes,
+    Resource::AiBudgets,
+    Resource::RuntimeValues,
+    Resource::Webhooks,
+    Resource::RetryPolicies,
+];
+
+/// Upper bound a platform admin may set; keeps a typo from disabling the guardrail.
+pub const MAX_QUOTA_OVERRIDE: i64 = 100_000;
+
+/// Highest override for `resource`. Kinds served over xDS stop at the snapshot's read
+/// ceiling, so every resource a team can create also reaches its proxies.
+pub fn max_override(resource: Resource) -> i64 {
+    match resource {
+        Resource::Clusters | Resource::RouteConfigs | Resource::Listeners => {
+            MAX_XDS_RESOURCES_PER_TYPE
+        }
+        _ => MAX_QUOTA_OVERRIDE,
+    }
+}
+
+/// Conservative defaults (Q-003 founder defaults); a per-team override replaces one.
 pub fn default_limit(resource: Resource) -> i64 {
     match resource {
         Resource::Clusters => 50,
         Resource::Listeners => 25,
         Resource::RouteConfigs => 100,
         Resource::LearningSessions => 5,
+        Resource::RuntimeValues => 500,
+        Resource::Webhooks => 20,
+        Resource::RetryPolicies => 200,
         Resource::ApiDefinitions | Resource::Secrets | Resource::Dataplanes => 200,
         _ => 200,
     }
@@ -22,6 +63,49 @@ pub async fn check_team_resource_quota(
     team_id: TeamId,
     resource: Resource,
 ) -> DomainResult<()> {
+    check_team_resource_quota_for(pool, team_id, resource, 1).await
+}
+
+/// Quota check for a write that adds `additional` resources at once (batch creates).
+pub async fn check_team_resource_quota_for(
+    pool: &PgPool,
+    team_id: TeamId,
+    resource: Resource,
+    additional: i64,
+) -> DomainResult<()> {
+    let Some(used) = count_used(pool, team_id, resource).await? else {
+        return Ok(());
+    };
+    let limit = effective_limit(pool, team_id, resource).await?;
+    if used + additional > limit {
+        return Err(quota_exceeded(resource, used, limit));
+    }
+    Ok(())
+}
+
+/// The team's override for `resource`, else the default. An override stored before
+/// [`max_override`] applied to its kind is read as that maximum.
+pub async fn effective_limit(
+    pool: &PgPool,
+    team_id: TeamId,
+    resource: Resource,
+) -> DomainResult<i64> {
+    Ok(
+        quotas::team_quota_override(pool, team_id, resource.as_str())
+            .await?
+            .map_or_else(
+                || default_limit(resource),
+                |limit| limit.min(max_override(resource)),
+            ),
+    )
+}
+
+/// Current count of `resource` for the team; `None` for kinds without a quota.
+async fn count_used(
+    pool: &PgPool,
+    team_id: TeamId,
+    resource: Resource,
+) -> DomainResult<Option<i64>> {
     let used = match resource {
         Resource::Clusters => fp_storage::repos::clusters::count_for_team(pool, team_id).await?,
         Resource::RouteConfigs => {
@@ -41,16 +125,26 @@ pub async fn check_team_resource_quota(
         Resource::Dataplanes => {
             fp_storage::repos::dataplanes::count_for_team(pool, team_id).await?
         }
+        Resource::Filters => {
+            fp_storage::repos::proto_descriptors::count_for_team(pool, team_id).await?
+                + fp_storage::repos::wasm_modules::count_for_team(pool, team_id).await?
+                + fp_storage::repos::filter_presets::count_for_team(pool, team_id).await?
+        }
         Resource::RateLimits => {
             fp_storage::repos::rate_limit::count_policies_for_team(pool, team_id).await?
         }
-        _ => return Ok(()),
+        Resource::RuntimeValues => {
+            fp_storage::repos::runtime::count_for_team(pool, team_id).await?
+        }
+        Resource::Webhooks => {
+            fp_storage::repos::webhooks::count_subscriptions(pool, team_id).await?
+        }
+        Resource::RetryPolicies => {
+            fp_storage::repos::retry_policies::count_for_team(pool, team_id).await?
+        }
+        _ => return Ok(None),
     };
-    let limit = default_limit(resource);
-    if used >= limit {
-        return Err(quota_exceeded(resource, used, limit));
-    }
-    Ok(())
+    Ok(Some(used))
 }
 
 pub fn quota_exceeded(resource: Resource, used: i64, limit: i64) -> DomainError {
@@ -58,5 +152,132 @@ pub fn quota_exceeded(resource: Resource, used: i64, limit: i64) -> DomainError
         ErrorCode::QuotaExceeded,
         format!("team quota reached: {used}/{limit} {}", resource.as_str()),
     )
+    .with_details(serde_json::json!({
+        "resource": resource.as_str(),
+        "used": used,
+        "limit": limit,
+    }))
     .with_hint("delete unused resources or ask a platform admin to raise the quota")
 }
+
+/// One row of a team's quota report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamQuota {
+    pub resource: Resource,
+    pub limit: i64,
+    pub default_limit: i64,
+    pub used: i64,
+}
+
+/// The team's limits and usage for every quota'd kind. Readable by a platform admin and by
+/// members of the team's org; anyone else gets the generic team 404.
+pub async fn get_team_quotas(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+) -> DomainResult<Vec<TeamQuota>> {
+    let member = match ctx {
+        PrincipalCtx::User {
+            org: Some((org_id, _)),
+            ..
+        } => *org_id == team.org_id,
+        PrincipalCtx::Agent { org_id, .. } => *org_id == team.org_id,
+        PrincipalCtx::User { .. } => false,
+    };
+    if !member && !ctx.is_platform_admin() {
+        return Err(DomainError::new(ErrorCode::NotFound, "team not found"));
+    }
+    let overrides: BTreeMap<String, i64> = quotas::list_team_quota_overrides(pool, team.id)
+        .await?
+        .into_iter()
+        .collect();
+    let mut report = Vec::with_capacity(QUOTA_RESOURCES.len());
+    for &resource in QUOTA_RESOURCES {
+        let default_limit = default_limit(resource);
+        report.push(TeamQuota {
+            resource,
+            limit: overrides
+                .get(resource.as_str())
+                .map_or(default_limit, |&limit| limit.min(max_override(resource))),
+            default_limit,
+            used: count_used(pool, team.id, resource).await?.unwrap_or(0),
+        });
+    }
+    Ok(report)
+}
+
+/// Replace the team's quota overrides (platform admins only). `limits` maps resource kinds
+/// (`clusters`, `route-configs`, ...) to a limit; kinds left out revert to the default.
+/// Lowering a limit below current usage is allowed: it blocks further creates only.
+pub async fn set_team_quotas(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limits: &BTreeMap<String, i64>,
+    request_id: RequestId,
+) -> DomainResult<Vec<TeamQuota>> {
+    if !ctx.is_platform_admin() {
+        return Err(DomainError::new(
+            ErrorCode::Forbidden,
+            "team quotas can only be changed by a platform admin",
+        ));
+    }
+    let mut overrides = Vec::with_capacity(limits.len());
+    for (raw, &limit) in limits {
+        // Quota-only kinds are not grantable, so they do not parse as a `Resource`.
+        let Some(resource) = QUOTA_RESOURCES.iter().copied().find(|r| r.as_str() == raw) else {
+            Resource::parse(raw)?;
+            return Err(
+                DomainError::validation(format!("\"{raw}\" has no team quota")).with_hint(format!(
+                    "quota'd kinds: {}",
+                    QUOTA_RESOURCES
+                        .iter()
+                        .map(|r| r.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            );
+        };
+        let max = max_override(resource);
+        if !(0..=max).contains(&limit) {
+            let err = DomainError::validation(format!(
+                "quota for {raw} must be in 0..={max}, got {limit}"
+            ));
+            return Err(if max == MAX_XDS_RESOURCES_PER_TYPE {
+                err.with_hint(format!(
+                    "a team's xDS snapshot serves at most {MAX_XDS_RESOURCES_PER_TYPE} {raw}; \
+                     split the workload across teams"
+                ))
+            } else {
+                err
+            });
+        }
+        overrides.push((resource.as_str(), limit));
+    }
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("set team quotas: begin"))?;
+    quotas::replace_team_quota_overrides(&mut tx, team.id, &overrides).await?;
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::record_in_tx(
+        &mut tx,
+        &audit::AuditEntry {
+            request_id: Some(request_id),
+            actor_type,
+            actor_id,
+            actor_label: String::new(),
+            surface: audit::Surface::Rest,
+            action: "team.quotas.update".into(),
+            resource: format!("teams/{}/quotas", team.id),
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+            outcome: audit::Outcome::Success,
+            detail: serde_json::json!({ "limits": limits }),
+        },
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("set team quotas: commit"))?;
+    get_team_quotas(pool, ctx, team).await
+}
@@ -78,8 +78,7 @@ pub async fn create_domain(
     authorize(pool, ctx, Action::Create, team, request_id).await?;
     validate_rate_limit_domain_name(name)?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create rate-limit domain: begin"))?;
     let domain = rate_limit::create_domain(&mut tx, team, name).await?;
@@ -136,8 +135,7 @@ pub async fn update_domain(
     authorize(pool, ctx, Action::Update, team, request_id).await?;
     validate_rate_limit_domain_name(new_name)?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update rate-limit domain: begin"))?;
     let domain =
@@ -169,8 +167,7 @@ pub async fn delete_domain(
     request_id: RequestId,
 ) -> DomainResult<()> {
     authorize(pool, ctx, Action::Delete, team, request_id).await?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete rate-limit domain: begin"))?;
     // The repo cascades the soft-delete to the domain's policies and overrides; the 60 s
@@ -211,8 +208,7 @@ pub async fn create_policy(
     let domain_row = resolve_domain(pool, team, domain).await?;
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::RateLimits).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create rate-limit policy: begin"))?;
     let policy = rate_limit::create_policy(&mut tx, team, domain_row.id, name, &spec).await?;
@@ -277,8 +273,7 @@ pub async fn update_policy(
     spec.validate()?;
     let domain_row = resolve_domain(pool, team, domain).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update rate-limit policy: begin"))?;
     let policy = rate_limit::update_policy(
@@ -321,8 +316,7 @@ pub async fn delete_policy(
     authorize(pool, ctx, Action::Delete, team, request_id).await?;
     let domain_row = resolve_domain(pool, team, domain).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete rate-limit policy: begin"))?;
     let policy_id =
@@ -374,8 +368,7 @@ pub async fn create_override(
     let domain_row = resolve_domain(pool, team, domain).await?;
     let policy_row = resolve_policy(pool, team, domain_row.id, policy).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create rate-limit override: begin"))?;
     let overridden = rate_limit::create_override(&mut tx, team, policy_row.id, &spec).await?;
@@ -431,8 +424,7 @@ pub async fn update_override(
     let domain_row = resolve_domain(pool, team, domain).await?;
     let policy_row = resolve_policy(pool, team, domain_row.id, policy).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("update rate-limit override: begin"))?;
     let overridden =
@@ -470,8 +462,7 @@ pub async fn delete_override(
     let domain_row = resolve_domain(pool, team, domain).await?;
     let policy_row = resolve_policy(pool, team, domain_row.id, policy).await?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete rate-limit override: begin"))?;
     rate_limit::delete_override(&mut tx, team.id, policy_row.id, expected_version).await?;
@@ -0,0 +1,279 @@
+//! Named retry policy services. Policies are route-config building blocks, so they share the
+//! `route-configs` grants; every mutation appends a team-scoped event so the xDS rebuilder
+//! re-expands the references. A policy cannot be deleted while any route still names it.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::{actor_of, deny_to_error, record_authz_denial, trace_context_json};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::event::{DomainEvent, EventScope};
+use fp_domain::gateway::route_config::{RetryPolicy, RouteConfigSpec, MAX_ROUTE_TIMEOUT_SECS};
+use fp_domain::gateway::NamedRetryPolicy;
+use fp_domain::{validate_name, DomainError, DomainResult, RequestId};
+use fp_storage::repos::{audit, retry_policies};
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+async fn authorize(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    action: Action,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, Resource::RouteConfigs, action, Some(team)) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::RouteConfigs,
+                action,
+                Some(team),
+                reason,
+            )
+            .await;
+            Err(deny_to_error(Resource::RouteConfigs, action, reason))
+        }
+    }
+}
+
+pub async fn create_retry_policy(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: RetryPolicy,
+    request_id: RequestId,
+) -> DomainResult<NamedRetryPolicy> {
+    authorize(pool, ctx, Action::Create, team, request_id).await?;
+    validate_name(name)?;
+    spec.validate(MAX_ROUTE_TIMEOUT_SECS)?;
+    crate::services::quota::check_team_resource_quota(pool, team.id, Resource::RetryPolicies)
+        .await?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("create retry policy: begin"))?;
+    let policy = retry_policies::create(&mut tx, team, name, &spec).await?;
+    append_event(
+        &mut tx,
+        team,
+        DomainEvent::RetryPolicyUpserted {
+            retry_policy_id: policy.id.as_uuid(),
+            name: name.into(),
+        },
+    )
+    .await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "retry_policy.create", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create retry policy: commit"))?;
+    Ok(policy)
+}
+
+pub async fn list_retry_policies(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<NamedRetryPolicy>, i64)> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    retry_policies::list(pool, team.id, limit, offset).await
+}
+
+pub async fn get_retry_policy(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<NamedRetryPolicy> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    retry_policies::get(pool, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("retry policy", name))
+}
+
+pub async fn update_retry_policy(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: RetryPolicy,
+    expected_version: i64,
+    request_id: RequestId,
+) -> DomainResult<NamedRetryPolicy> {
+    authorize(pool, ctx, Action::Update, team, request_id).await?;
+    spec.validate(MAX_ROUTE_TIMEOUT_SECS)?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("update retry policy: begin"))?;
+    // The new per-try timeout must still fit every route that already references the policy.
+    let policies = HashMap::from([(name.to_string(), &spec)]);
+    for (route_config, rc_spec) in
+        retry_policies::route_configs_referencing(&mut tx, team.id, name).await?
+    {
+        check_references_against(&rc_spec, &policies).map_err(|e| {
+            DomainError::validation(format!("route config \"{route_config}\": {}", e.message))
+        })?;
+    }
+    let policy = retry_policies::update(&mut tx, team.id, name, &spec, expected_version).await?;
+    append_event(
+        &mut tx,
+        team,
+        DomainEvent::RetryPolicyUpserted {
+            retry_policy_id: policy.id.as_uuid(),
+            name: name.into(),
+        },
+    )
+    .await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "retry_policy.update", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("update retry policy: commit"))?;
+    Ok(policy)
+}
+
+pub async fn delete_retry_policy(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    expected_version: i64,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(pool, ctx, Action::Delete, team, request_id).await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("delete retry policy: begin"))?;
+    // Same rule as clusters: no silent cascade out from under a serving route.
+    let dependents: Vec<String> = retry_policies::route_configs_referencing(&mut tx, team.id, name)
+        .await?
+        .into_iter()
+        .map(|(route_config, _)| route_config)
+        .collect();
+    if !dependents.is_empty() {
+        return Err(DomainError::conflict(format!(
+            "retry policy \"{name}\" is referenced by route configs: {}",
+            dependents.join(", ")
+        ))
+        .with_hint("remove retry_policy_ref from those routes first"));
+    }
+    let id = retry_policies::delete(&mut tx, team.id, name, expected_version).await?;
+    append_event(
+        &mut tx,
+        team,
+        DomainEvent::RetryPolicyDeleted {
+            retry_policy_id: id.as_uuid(),
+            name: name.into(),
+        },
+    )
+    .await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "retry_policy.delete", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("delete retry policy: commit"))?;
+    Ok(())
+}
+
+/// Route-config write check: every `retry_policy_ref` must name a policy in the same team
+/// (else 404) whose per-try timeout fits the referencing route's `timeout_secs`.
+pub(crate) async fn check_route_references(
+    pool: &PgPool,
+    team: TeamRef,
+    spec: &RouteConfigSpec,
+) -> DomainResult<()> {
+    let referenced = spec
+        .virtual_hosts
+        .iter()
+        .flat_map(|vhost| &vhost.routes)
+        .any(|rule| rule.action.retry_policy_ref.is_some());
+    if !referenced {
+        return Ok(());
+    }
+    let policies = retry_policies::list_for_team(pool, team.id).await?;
+    let policies: HashMap<String, &RetryPolicy> = policies
+        .iter()
+        .map(|policy| (policy.name.clone(), &policy.spec))
+        .collect();
+    check_references_against(spec, &policies)
+}
+
+fn check_references_against(
+    spec: &RouteConfigSpec,
+    policies: &HashMap<String, &RetryPolicy>,
+) -> DomainResult<()> {
+    for rule in spec.virtual_hosts.iter().flat_map(|vhost| &vhost.routes) {
+        let Some(policy_name) = &rule.action.retry_policy_ref else {
+            continue;
+        };
+        let policy = policies
+            .get(policy_name)
+            .ok_or_else(|| DomainError::not_found("retry policy", policy_name))?;
+        if let Some(per_try) = policy.per_try_timeout_secs {
+            if per_try > rule.action.timeout_secs {
+                return Err(DomainError::validation(format!(
+                    "route \"{}\": retry policy \"{policy_name}\" per_try_timeout_secs ({per_try}) \
+                     exceeds the route's timeout_secs ({})",
+                    rule.name, rule.action.timeout_secs
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn append_event(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    team: TeamRef,
+    event: DomainEvent,
+) -> DomainResult<()> {
+    fp_storage::outbox::append(
+        tx,
+        &event,
+        EventScope {
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+        },
+        trace_context_json(),
+    )
+    .await
+}
+
+fn mutation_audit(
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+    team: TeamRef,
+    action: &str,
+    name: &str,
+) -> audit::AuditEntry {
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::AuditEntry {
+        request_id: Some(request_id),
+        actor_type,
+        actor_id,
+        actor_label: String::new(),
+        surface: audit::Surface::Rest,
+        action: action.into(),
+        resource: format!("retry-policies/{name}"),
+        org_id: Some(team.org_id),
+        team_id: Some(team.id),
+        outcome: audit::Outcome::Success,
+        detail: serde_json::json!({}),
+    }
+}
@@ -7,12 +7,13 @@ use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::gateway::cluster::{Cluster, ClusterSpec, Endpoint, UpstreamTlsConfig};
 use fp_domain::gateway::listener::{Listener, ListenerProtocol, ListenerSpec};
 use fp_domain::gateway::route_config::{
-    HeaderMatch, HeaderValueMatch, PathMatch, RouteAction, RouteConfig, RouteConfigSpec, RouteRule,
-    VirtualHost,
+    PathMatch, RouteAction, RouteConfig, RouteConfigSpec, RouteRule, VirtualHost,
 };
+use fp_domain::route_generation::diff_routes;
 use fp_domain::{
-    ApiDefinitionId, DomainError, DomainResult, RequestId, RouteGenerationPlan,
-    RouteGenerationPlanId, RouteGenerationPlanSpec, RouteGenerationPlanStatus, SpecVersionId,
+    ApiDefinitionId, DomainError, DomainResult, ErrorCode, PlanSync, RequestId,
+    RouteGenerationPlan, RouteGenerationPlanId, RouteGenerationPlanSpec, RouteGenerationPlanStatus,
+    SpecVersionId, SyncAction,
 };
 use fp_storage::repos::{api_lifecycle, route_generation};
 use sqlx::PgPool;
@@ -22,6 +23,11 @@ use std::collections::{BTreeMap, BTreeSet};
 pub struct CreateRoutePlanInput {
     pub spec_version_id: SpecVersionId,
     pub listener_port: u16,
+    /// Emit one rule per path listing all of its methods, instead of one rule per operation.
+    pub coalesce_methods: bool,
+    /// Diff against the resources of the API's last applied plan and update them on apply,
+    /// instead of creating new ones (which would collide on their names).
+    pub sync: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +38,33 @@ pub struct AppliedRoutePlan {
     pub listener: Listener,
 }
 
+/// Kind of a resource a route generation plan produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanResourceKind {
+    Cluster,
+    RouteConfig,
+    Listener,
+}
+
+impl PlanResourceKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cluster => "cluster",
+            Self::RouteConfig => "route_config",
+            Self::Listener => "listener",
+        }
+    }
+}
+
+/// One resource an applied plan produced, as it stands now.
+#[derive(Debug, Clone)]
+pub struct PlanResource {
+    pub kind: PlanResourceKind,
+    pub name: String,
+    /// Current revision; `None` once the resource has been deleted.
+    pub version: Option<i64>,
+}
+
 async fn authorize(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -83,9 +116,11 @@ pub async fn create_plan(
         request_id,
     )
     .await?;
-    let mut tx = pool.begin().await.map_err(crate::services::db_err(
-        "create route generation plan: begin",
-    ))?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err(
+            "create route generation plan: begin",
+        ))?;
     let spec =
         api_lifecycle::get_spec_version_by_id(&mut tx, team.id, input.spec_version_id).await?;
     if spec.source_kind != SpecSourceKind::Learned {
@@ -100,7 +135,18 @@ pub async fn create_plan(
         spec.api_definition_id,
         &spec.spec,
         input.listener_port,
+        input.coalesce_methods,
     )?;
+    if input.sync {
+        let previous =
+            route_generation::latest_applied_for_api(pool, team.id, spec.api_definition_id)
+                .await?
+                .ok_or_else(|| {
+                    DomainError::conflict("no applied route generation plan to sync with")
+                        .with_hint("create and apply a plan without sync first")
+                })?;
+        plan.sync = Some(sync_diff(pool, ctx, team, &plan, previous.id, request_id).await?);
+    }
     plan.conflicts = detect_conflicts(pool, ctx, team, &plan, request_id).await?;
     let persisted = route_generation::create(&mut tx, team, spec.id, &plan).await?;
     tx.commit().await.map_err(crate::services::db_err(
@@ -129,6 +175,24 @@ pub async fn apply_plan(
     let plan = route_generation::get(pool, team.id, plan_id)
         .await?
         .ok_or_else(|| DomainError::not_found("route generation plan", &plan_id.to_string()))?;
+    if let Some(sync) = &plan.plan.sync {
+        for resource in [
+            Resource::Clusters,
+            Resource::RouteConfigs,
+            Resource::Listeners,
+        ] {
+            authorize(pool, ctx, resource, Action::Update, team, request_id).await?;
+        }
+        let latest =
+            route_generation::latest_applied_for_api(pool, team.id, plan.plan.api_definition_id)
+                .await?;
+        if latest.map(|p| p.id) != Some(sync.previous_plan_id) {
+            return Err(DomainError::conflict(
+                "another plan of this API was applied after this sync plan was created",
+            )
+            .with_hint("create a new sync plan"));
+        }
+    }
     if plan.status != RouteGenerationPlanStatus::DryRun {
         return Err(DomainError::conflict(
             "route generation plan has already been applied",
@@ -159,9 +223,11 @@ pub async fn apply_plan(
                 .collect(),
         )
         .await?;
-    let mut tx = pool.begin().await.map_err(crate::services::db_err(
-        "validate route generation plan approval: begin",
-    ))?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err(
+            "validate route generation plan approval: begin",
+        ))?;
     ensure_spec_still_approved(
         pool,
         &mut tx,
@@ -174,6 +240,10 @@ pub async fn apply_plan(
         "validate route generation plan approval: commit",
     ))?;
 
+    if plan.plan.sync.is_some() {
+        return apply_sync(pool, ctx, team, plan, request_id, advisory).await;
+    }
+
     let cluster = clusters::create_cluster(
         pool,
         ctx,
@@ -245,9 +315,131 @@ pub async fn apply_plan(
         }
     };
 
-    let mut tx = pool.begin().await.map_err(crate::services::db_err(
-        "mark route generation plan applied: begin",
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err(
+            "mark route generation plan applied: begin",
+        ))?;
+    let applied = route_generation::mark_applied(&mut tx, team.id, plan.id).await?;
+    tx.commit().await.map_err(crate::services::db_err(
+        "mark route generation plan applied: commit",
     ))?;
+    Ok(AppliedRoutePlan {
+        plan: applied,
+        cluster,
+        route_config,
+        listener,
+    })
+}
+
+/// Apply a sync plan: each generated resource is created if missing, updated if its spec
+/// differs, and left alone otherwise. The current state is re-read here, so the recorded
+/// diff is a preview, not a precondition. The three writes are not one transaction; if one
+/// fails, the earlier ones stay and a fresh sync plan converges the rest.
+async fn apply_sync(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    plan: RouteGenerationPlan,
+    request_id: RequestId,
+    advisory: crate::services::egress_advisory::EgressAdvisoryPolicy,
+) -> DomainResult<AppliedRoutePlan> {
+    let spec = &plan.plan;
+    let cluster = match found(
+        clusters::get_cluster(pool, ctx, team, &spec.cluster_name, request_id).await,
+    )? {
+        Some(current) if current.spec == spec.cluster_spec => current,
+        Some(current) => {
+            clusters::update_cluster(
+                pool,
+                ctx,
+                team,
+                &spec.cluster_name,
+                spec.cluster_spec.clone(),
+                current.version,
+                request_id,
+                advisory,
+            )
+            .await?
+        }
+        None => {
+            clusters::create_cluster(
+                pool,
+                ctx,
+                team,
+                &spec.cluster_name,
+                spec.cluster_spec.clone(),
+                request_id,
+                advisory,
+            )
+            .await?
+        }
+    };
+    let route_config = match found(
+        gateway::get_route_config(pool, ctx, team, &spec.route_config_name, request_id).await,
+    )? {
+        Some(current) if current.spec == spec.route_config_spec => current,
+        Some(current) => {
+            gateway::update_route_config(
+                pool,
+                ctx,
+                team,
+                &spec.route_config_name,
+                spec.route_config_spec.clone(),
+                current.version,
+                request_id,
+            )
+            .await?
+        }
+        None => {
+            gateway::create_route_config(
+                pool,
+                ctx,
+                team,
+                &spec.route_config_name,
+                spec.route_config_spec.clone(),
+                request_id,
+            )
+            .await?
+        }
+    };
+    // Generated listeners carry no http_filters, so the RLS reference check is a no-op.
+    let listener =
+        match found(gateway::get_listener(pool, ctx, team, &spec.listener_name, request_id).await)?
+        {
+            Some(current) if current.spec == spec.listener_spec => current,
+            Some(current) => {
+                gateway::update_listener(
+                    pool,
+                    ctx,
+                    team,
+                    &spec.listener_name,
+                    spec.listener_spec.clone(),
+                    current.version,
+                    request_id,
+                    false,
+                )
+                .await?
+            }
+            None => {
+                gateway::create_listener(
+                    pool,
+                    ctx,
+                    team,
+                    &spec.listener_name,
+                    spec.listener_spec.clone(),
+                    request_id,
+                    false,
+                )
+                .await?
+            }
+        };
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err(
+            "mark route generation plan applied: begin",
+        ))?;
     let applied = route_generation::mark_applied(&mut tx, team.id, plan.id).await?;
     tx.commit().await.map_err(crate::services::db_err(
         "mark route generation plan applied: commit",
@@ -260,6 +452,240 @@ pub async fn apply_plan(
     })
 }
 
+/// What applying `plan` would do to the resources `previous_plan_id` left behind.
+async fn sync_diff(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    plan: &RouteGenerationPlanSpec,
+    previous_plan_id: RouteGenerationPlanId,
+    request_id: RequestId,
+) -> DomainResult<PlanSync> {
+    let action = |current: Option<bool>| match current {
+        None => SyncAction::Create,
+        Some(true) => SyncAction::Unchanged,
+        Some(false) => SyncAction::Update,
+    };
+    let cluster =
+        found(clusters::get_cluster(pool, ctx, team, &plan.cluster_name, request_id).await)?;
+    let route_config = found(
+        gateway::get_route_config(pool, ctx, team, &plan.route_config_name, request_id).await,
+    )?;
+    let listener =
+        found(gateway::get_listener(pool, ctx, team, &plan.listener_name, request_id).await)?;
+    let none = RouteConfigSpec {
+        virtual_hosts: Vec::new(),
+    };
+    let (routes_added, routes_updated, routes_removed) = diff_routes(
+        route_config.as_ref().map_or(&none, |current| &current.spec),
+        &plan.route_config_spec,
+    );
+    Ok(PlanSync {
+        previous_plan_id,
+        cluster: action(cluster.map(|c| c.spec == plan.cluster_spec)),
+        route_config: action(route_config.map(|r| r.spec == plan.route_config_spec)),
+        listener: action(listener.map(|l| l.spec == plan.listener_spec)),
+        routes_added,
+        routes_updated,
+        routes_removed,
+    })
+}
+
+/// A lookup result with "not found" as `None`; any other error still fails.
+fn found<T>(result: DomainResult<T>) -> DomainResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.code == ErrorCode::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// A team's route generation plans, newest first. Plans are previews of gateway resources,
+/// so reading them needs read on route configs.
+pub async fn list_plans(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<RouteGenerationPlan>, i64)> {
+    authorize(
+        pool,
+        ctx,
+        Resource::RouteConfigs,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    route_generation::list(pool, team.id, limit, offset).await
+}
+
+/// The manifest of an applied plan: the cluster, route config and listener it created or
+/// synced, each with its current revision (`None` once deleted). Needs read on all three
+/// kinds. A later sync plan of the same API lists the same names; `previous_plan_id` in its
+/// `sync` links the two.
+pub async fn plan_resources(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    plan_id: RouteGenerationPlanId,
+    request_id: RequestId,
+) -> DomainResult<(RouteGenerationPlan, Vec<PlanResource>)> {
+    for resource in [
+        Resource::Clusters,
+        Resource::RouteConfigs,
+        Resource::Listeners,
+    ] {
+        authorize(pool, ctx, resource, Action::Read, team, request_id).await?;
+    }
+    let plan = route_generation::get(pool, team.id, plan_id)
+        .await?
+        .ok_or_else(|| DomainError::not_found("route generation plan", &plan_id.to_string()))?;
+    if plan.status != RouteGenerationPlanStatus::Applied {
+        return Err(
+            DomainError::conflict("route generation plan has not been applied")
+                .with_hint("a dry-run plan has produced no resources; apply it first"),
+        );
+    }
+    let spec = &plan.plan;
+    let cluster =
+        found(clusters::get_cluster(pool, ctx, team, &spec.cluster_name, request_id).await)?;
+    let route_config = found(
+        gateway::get_route_config(pool, ctx, team, &spec.route_config_name, request_id).await,
+    )?;
+    let listener =
+        found(gateway::get_listener(pool, ctx, team, &spec.listener_name, request_id).await)?;
+    let resources = vec![
+        PlanResource {
+            kind: PlanResourceKind::Cluster,
+            name: spec.cluster_name.clone(),
+            version: cluster.map(|c| c.version),
+        },
+        PlanResource {
+            kind: PlanResourceKind::RouteConfig,
+            name: spec.route_config_name.clone(),
+            version: route_config.map(|r| r.version),
+        },
+        PlanResource {
+            kind: PlanResourceKind::Listener,
+            name: spec.listener_name.clone(),
+            version: listener.map(|l| l.version),
+        },
+    ];
+    Ok((plan, resources))
+}
+
+/// Tear down what an applied plan produced, dependents first: listener, route config, then
+/// cluster, each at its current revision through the ordinary delete paths. Resources already
+/// gone are skipped. If anything outside the plan still uses one of them (another listener on
+/// the route config, another route config on the cluster) nothing is deleted. Only the team's
+/// own resources are reachable by these names, so platform-owned gateway resources are never
+/// touched. With `dry_run` the same checks run and the would-be deletions are returned.
+pub async fn teardown_plan(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    plan_id: RouteGenerationPlanId,
+    dry_run: bool,
+    request_id: RequestId,
+) -> DomainResult<(RouteGenerationPlan, Vec<PlanResource>)> {
+    for resource in [
+        Resource::Clusters,
+        Resource::RouteConfigs,
+        Resource::Listeners,
+    ] {
+        authorize(pool, ctx, resource, Action::Delete, team, request_id).await?;
+    }
+    let (plan, resources) = plan_resources(pool, ctx, team, plan_id, request_id).await?;
+    let spec = &plan.plan;
+    let live = |kind: PlanResourceKind| {
+        resources
+            .iter()
+            .any(|r| r.kind == kind && r.version.is_some())
+    };
+    let mut dependents = Vec::new();
+    if live(PlanResourceKind::RouteConfig) {
+        let (listeners, _) = gateway::list_listeners(
+            pool,
+            ctx,
+            team,
+            None,
+            Default::default(),
+            500,
+            0,
+            request_id,
+        )
+        .await?;
+        dependents.extend(
+            listeners
+                .iter()
+                .filter(|l| {
+                    l.name != spec.listener_name
+                        && l.spec.route_config.as_deref() == Some(spec.route_config_name.as_str())
+                })
+                .map(|l| format!("listener \"{}\"", l.name)),
+        );
+    }
+    if live(PlanResourceKind::Cluster) {
+        let mut tx = fp_storage::begin(pool)
+            .await
+            .map_err(crate::services::db_err("plan teardown: begin"))?;
+        let referencing = fp_storage::repos::gateway::route_configs_referencing_cluster(
+            &mut tx,
+            team.id,
+            &spec.cluster_name,
+        )
+        .await?;
+        dependents.extend(
+            referencing
+                .into_iter()
+                .filter(|name| *name != spec.route_config_name)
+                .map(|name| format!("route config \"{name}\"")),
+        );
+    }
+    if !dependents.is_empty() {
+        return Err(DomainError::conflict(format!(
+            "resources outside the plan still use what it produced: {}",
+            dependents.join(", ")
+        ))
+        .with_hint("unbind or delete those first"));
+    }
+    let doomed: Vec<PlanResource> = resources
+        .into_iter()
+        .rev()
+        .filter(|r| r.version.is_some())
+        .collect();
+    if !dry_run {
+        for resource in &doomed {
+            let version = resource.version.unwrap_or_default();
+            match resource.kind {
+                PlanResourceKind::Listener => {
+                    gateway::delete_listener(pool, ctx, team, &resource.name, version, request_id)
+                        .await?
+                }
+                PlanResourceKind::RouteConfig => {
+                    gateway::delete_route_config(
+                        pool,
+                        ctx,
+                        team,
+                        &resource.name,
+                        version,
+                        request_id,
+                    )
+                    .await?
+                }
+                PlanResourceKind::Cluster => {
+                    clusters::delete_cluster(pool, ctx, team, &resource.name, version, request_id)
+                        .await?
+                }
+            }
+        }
+    }
+    Ok((plan, doomed))
+}
+
 async fn ensure_spec_still_approved(
     pool: &PgPool,
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
@@ -286,6 +712,7 @@ fn build_plan(
     api_definition_id: ApiDefinitionId,
     spec: &serde_json::Value,
     listener_port: u16,
+    coalesce_methods: bool,
 ) -> DomainResult<RouteGenerationPlanSpec> {
     let source = spec
         .get("x-flowplane-learning-source")
@@ -323,9 +750,11 @@ fn build_plan(
             insecure_skip_verify: false,
         }),
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     };
     let route_config_spec = RouteConfigSpec {
         virtual_hosts: vec![VirtualHost {
@@ -335,7 +764,7 @@ fn build_plan(
                 observed_host
             }),
             domains: vec![observed_host.to_string()],
-            routes: openapi_routes(spec, &cluster_name)?,
+            routes: openapi_routes(spec, &cluster_name, coalesce_methods)?,
             rate_limits: Vec::new(),
             filter_overrides: Vec::new(),
         }],
@@ -349,6 +778,11 @@ fn build_plan(
         http_filters: Vec::new(),
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     };
     cluster_spec.validate()?;
     route_config_spec.validate()?;
@@ -364,6 +798,7 @@ fn build_plan(
         route_config_spec,
         listener_spec,
         conflicts: Vec::new(),
+        sync: None,
         metadata: serde_json::json!({
             "observed_host": observed_host,
             "forwarded_upstream_host": upstream_host,
@@ -373,6 +808,8 @@ fn build_plan(
     })
 }
 
+/// Reasons a plan cannot apply. A sync plan owns its generated names (it updates them), so
+/// only another listener on its port conflicts.
 async fn detect_conflicts(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -381,35 +818,51 @@ async fn detect_conflicts(
     request_id: RequestId,
 ) -> DomainResult<Vec<String>> {
     let mut conflicts = Vec::new();
-    if clusters::get_cluster(pool, ctx, team, &plan.cluster_name, request_id)
-        .await
-        .is_ok()
+    let sync = plan.sync.is_some();
+    if !sync
+        && clusters::get_cluster(pool, ctx, team, &plan.cluster_name, request_id)
+            .await
+            .is_ok()
     {
         conflicts.push(format!("cluster \"{}\" already exists", plan.cluster_name));
     }
-    if gateway::get_route_config(pool, ctx, team, &plan.route_config_name, request_id)
-        .await
-        .is_ok()
+    if !sync
+        && gateway::get_route_config(pool, ctx, team, &plan.route_config_name, request_id)
+            .await
+            .is_ok()
     {
         conflicts.push(format!(
             "route config \"{}\" already exists",
             plan.route_config_name
         ));
     }
-    if gateway::get_listener(pool, ctx, team, &plan.listener_name, request_id)
-        .await
-        .is_ok()
+    if !sync
+        && gateway::get_listener(pool, ctx, team, &plan.listener_name, request_id)
+            .await
+            .is_ok()
     {
         conflicts.push(format!(
             "listener \"{}\" already exists",
             plan.listener_name
         ));
     }
-    let (listeners, _) = gateway::list_listeners(pool, ctx, team, 500, 0, request_id).await?;
-    if listeners
-        .iter()
-        .any(|listener| listener.spec.port == plan.listener_port)
-    {
+    let (listeners, _) = gateway::list_listeners(
+        pool,
+        ctx,
+        team,
+        None,
+        Default::default(),
+        500,
+        0,
+        request_id,
+    )
+    .await?;
+    if listeners.iter().any(|listener| {
+        // The plan's listener is TCP; an HTTP/3 listener on the same port binds UDP.
+        listener.spec.port == plan.listener_port
+            && listener.spec.protocol != ListenerProtocol::Http3
+            && !(sync && listener.name == plan.listener_name)
+    }) {
         conflicts.push(format!(
             "listener port {} is already in use",
             plan.listener_port
@@ -418,7 +871,11 @@ async fn detect_conflicts(
     Ok(conflicts)
 }
 
-fn openapi_routes(spec: &serde_json::Value, cluster_name: &str) -> DomainResult<Vec<RouteRule>> {
+fn openapi_routes(
+    spec: &serde_json::Value,
+    cluster_name: &str,
+    coalesce_methods: bool,
+) -> DomainResult<Vec<RouteRule>> {
     let paths = spec
         .get("paths")
         .and_then(serde_json::Value::as_object)
@@ -445,25 +902,49 @@ fn openapi_routes(spec: &serde_json::Value, cluster_name: &str) -> DomainResult<
             "OpenAPI spec must contain at least one operation",
         ));
     }
-    let mut names = BTreeSet::new();
-    let mut routes = Vec::new();
-    for ((path, method), operation_id) in operations {
-        let base = if operation_id.is_empty() {
+    let name_of = |method: &str, path: &str, operation_id: &str| {
+        if operation_id.is_empty() {
             normalize_name(&format!("{method}-{path}"))
         } else {
-            normalize_name(&operation_id)
-        };
+            normalize_name(operation_id)
+        }
+    };
+    // (name, path, upper-case methods) per emitted rule. Coalescing groups a path's
+    // operations, which differ only by method, into one rule; a lone operation keeps its
+    // operation-based name.
+    let mut rules: Vec<(String, String, Vec<String>)> = Vec::new();
+    if coalesce_methods {
+        let mut by_path: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+        for ((path, method), operation_id) in operations {
+            by_path
+                .entry(path)
+                .or_default()
+                .push((method, operation_id));
+        }
+        for (path, ops) in by_path {
+            let base = match ops.as_slice() {
+                [(method, operation_id)] => name_of(method, &path, operation_id),
+                _ => normalize_name(&path),
+            };
+            let methods = ops.iter().map(|(m, _)| m.to_ascii_uppercase()).collect();
+            rules.push((base, path, methods));
+        }
+    } else {
+        for ((path, method), operation_id) in operations {
+            let base = name_of(&method, &path, &operation_id);
+            rules.push((base, path, vec![method.to_ascii_uppercase()]));
+        }
+    }
+    let mut names = BTreeSet::new();
+    let mut routes = Vec::new();
+    for (base, path, methods) in rules {
         routes.push(RouteRule {
             name: unique_name(base, &mut names),
             matcher: PathMatch::Template { template: path },
-            headers: vec![HeaderMatch {
-                name: ":method".into(),
-                invert_match: false,
-                matcher: HeaderValueMatch::Exact {
-                    value: method.to_ascii_uppercase(),
-                },
-            }],
+            headers: Vec::new(),
+            methods,
             query_parameters: Vec::new(),
+            runtime_fraction: None,
             action: RouteAction {
                 cluster: Some(cluster_name.into()),
                 weighted_clusters: None,
@@ -473,6 +954,16 @@ fn openapi_routes(spec: &serde_json::Value, cluster_name: &str) -> DomainResult<
                 template_rewrite: None,
                 timeout_secs: 15,
                 retry_policy: None,
+                retry_policy_ref: None,
+                idle_timeout_secs: None,
+                max_stream_duration_secs: None,
+                regex_rewrite: None,
+                host_rewrite_literal: None,
+                auto_host_rewrite: false,
+                fallback_cluster: None,
+                request_mirror_policies: Vec::new(),
+                request_headers_to_remove: Vec::new(),
+                hash_policy: Vec::new(),
                 rate_limits: Vec::new(),
             },
             filter_overrides: Vec::new(),
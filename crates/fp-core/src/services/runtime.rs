@@ -0,0 +1,187 @@
+//! Runtime value services. Values steer routes and filters that are already deployed (a
+//! `runtime_fraction` share, a feature gate), so they share the `route-configs` grants; every
+//! write appends a team-scoped event so the xDS rebuilder re-serves the team's RTDS layer.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::{actor_of, deny_to_error, record_authz_denial, trace_context_json};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::event::{DomainEvent, EventScope};
+use fp_domain::gateway::runtime::{validate_runtime_key, validate_runtime_value};
+use fp_domain::gateway::RuntimeValue;
+use fp_domain::{DomainError, DomainResult, RequestId};
+use fp_storage::repos::{audit, runtime};
+use sqlx::PgPool;
+
+async fn authorize(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    action: Action,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, Resource::RouteConfigs, action, Some(team)) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::RouteConfigs,
+                action,
+                Some(team),
+                reason,
+            )
+            .await;
+            Err(deny_to_error(Resource::RouteConfigs, action, reason))
+        }
+    }
+}
+
+pub async fn list_runtime_values(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<RuntimeValue>, i64)> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    runtime::list(pool, team.id, limit, offset).await
+}
+
+pub async fn get_runtime_value(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    key: &str,
+    request_id: RequestId,
+) -> DomainResult<RuntimeValue> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    runtime::get(pool, team.id, key)
+        .await?
+        .ok_or_else(|| DomainError::not_found("runtime value", key))
+}
+
+/// Set `key` to `value`, creating it when absent. The result's version is 1 for a new key.
+pub async fn set_runtime_value(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    key: &str,
+    value: serde_json::Value,
+    request_id: RequestId,
+) -> DomainResult<RuntimeValue> {
+    authorize(pool, ctx, Action::Update, team, request_id).await?;
+    validate_runtime_key(key)?;
+    validate_runtime_value(key, &value)?;
+    if runtime::get(pool, team.id, key).await?.is_none() {
+        crate::services::quota::check_team_resource_quota(pool, team.id, Resource::RuntimeValues)
+            .await?;
+    }
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("set runtime value: begin"))?;
+    let stored = runtime::upsert(&mut tx, team, key, &value).await?;
+    append_event(
+        &mut tx,
+        team,
+        DomainEvent::RuntimeValueSet { key: key.into() },
+    )
+    .await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(
+            ctx,
+            request_id,
+            team,
+            "runtime_value.set",
+            key,
+            serde_json::json!({ "value": value }),
+        ),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("set runtime value: commit"))?;
+    Ok(stored)
+}
+
+pub async fn delete_runtime_value(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    key: &str,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(pool, ctx, Action::Update, team, request_id).await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("delete runtime value: begin"))?;
+    if !runtime::delete(&mut tx, team.id, key).await? {
+        return Err(DomainError::not_found("runtime value", key));
+    }
+    append_event(
+        &mut tx,
+        team,
+        DomainEvent::RuntimeValueDeleted { key: key.into() },
+    )
+    .await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(
+            ctx,
+            request_id,
+            team,
+            "runtime_value.delete",
+            key,
+            serde_json::json!({}),
+        ),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("delete runtime value: commit"))?;
+    Ok(())
+}
+
+async fn append_event(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    team: TeamRef,
+    event: DomainEvent,
+) -> DomainResult<()> {
+    fp_storage::outbox::append(
+        tx,
+        &event,
+        EventScope {
+            org_id: Some(team.org_id),
+            team_id: Some(team.id),
+        },
+        trace_context_json(),
+    )
+    .await
+}
+
+fn mutation_audit(
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+    team: TeamRef,
+    action: &str,
+    key: &str,
+    detail: serde_json::Value,
+) -> audit::AuditEntry {
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::AuditEntry {
+        request_id: Some(request_id),
+        actor_type,
+        actor_id,
+        actor_label: String::new(),
+        surface: audit::Surface::Rest,
+        action: action.into(),
+        resource: format!("runtime/{key}"),
+        org_id: Some(team.org_id),
+        team_id: Some(team.id),
+        outcome: audit::Outcome::Success,
+        detail,
+    }
+}
@@ -15,6 +15,10 @@ use sqlx::PgPool;
 const DEFAULT_KEY_ID: &str = "default";
 const ACTIVE_KEY_ID_ENV: &str = "FLOWPLANE_SECRET_ENCRYPTION_KEY_ID";
 const ACTIVE_KEY_ENV: &str = "FLOWPLANE_SECRET_ENCRYPTION_KEY";
+const KEYRING_ENV: &str = "FLOWPLANE_SECRET_ENCRYPTION_KEYS";
+
+/// Upper bound on one batch create; larger bootstraps split across several requests.
+pub const MAX_SECRET_BATCH: usize = 50;
 
 async fn authorize(
     pool: &PgPool,
@@ -71,12 +75,104 @@ pub async fn create_secret(
     crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Secrets).await?;
     let encrypted = encrypt_spec(&write.spec)?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create secret: begin"))?;
+    let secret = insert_secret(&mut tx, ctx, team, &write, &encrypted, request_id).await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create secret: commit"))?;
+    Ok(secret)
+}
+
+/// Create several secrets at once: every item is validated and encrypted before the
+/// transaction opens, then all rows are written or none are. A failure carries the
+/// offending item's position and name in `details` (`{"index", "name"}`); values are
+/// never part of an error.
+pub async fn create_secrets_batch(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    writes: Vec<SecretWrite<'_>>,
+    request_id: RequestId,
+) -> DomainResult<Vec<Secret>> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Secrets,
+        Action::Create,
+        team,
+        request_id,
+    )
+    .await?;
+    if writes.is_empty() || writes.len() > MAX_SECRET_BATCH {
+        return Err(DomainError::validation(format!(
+            "a secret batch must contain 1-{MAX_SECRET_BATCH} secrets"
+        )));
+    }
+    let mut names = std::collections::HashSet::new();
+    for (index, write) in writes.iter().enumerate() {
+        let checked = validate_name(write.name)
+            .and_then(|()| write.spec.validate())
+            .and_then(|()| validate_expiry(write.expires_at))
+            .and_then(|()| {
+                if names.insert(write.name) {
+                    Ok(())
+                } else {
+                    Err(DomainError::validation(format!(
+                        "secret \"{}\" appears more than once in the batch",
+                        write.name
+                    )))
+                }
+            });
+        checked.map_err(|e| batch_item_error(e, index, write.name))?;
+    }
+    crate::services::quota::check_team_resource_quota_for(
+        pool,
+        team.id,
+        Resource::Secrets,
+        writes.len() as i64,
+    )
+    .await?;
+    let encrypted = writes
+        .iter()
+        .map(|write| encrypt_spec(&write.spec))
+        .collect::<DomainResult<Vec<_>>>()?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("create secret batch: begin"))?;
+    let mut created = Vec::with_capacity(writes.len());
+    for (index, (write, encrypted)) in writes.iter().zip(&encrypted).enumerate() {
+        // An early return drops `tx`, rolling back the items already inserted.
+        let secret = insert_secret(&mut tx, ctx, team, write, encrypted, request_id)
+            .await
+            .map_err(|e| batch_item_error(e, index, write.name))?;
+        created.push(secret);
+    }
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create secret batch: commit"))?;
+    Ok(created)
+}
+
+/// Pin an error to one batch item: prefix the message and set `details` to `{index, name}`.
+pub fn batch_item_error(err: DomainError, index: usize, name: &str) -> DomainError {
+    let message = format!("secrets[{index}] (\"{name}\"): {}", err.message);
+    DomainError { message, ..err }.with_details(serde_json::json!({ "index": index, "name": name }))
+}
+
+/// Row + outbox event + audit entry for one new secret, inside the caller's transaction.
+async fn insert_secret(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    write: &SecretWrite<'_>,
+    encrypted: &EncryptedSpec,
+    request_id: RequestId,
+) -> DomainResult<Secret> {
     let secret = secrets::create_secret(
-        &mut tx,
+        tx,
         team,
         write.name,
         write.description,
@@ -88,7 +184,7 @@ pub async fn create_secret(
     )
     .await?;
     fp_storage::outbox::append(
-        &mut tx,
+        tx,
         &DomainEvent::SecretUpserted {
             secret_id: secret.id.as_uuid(),
             name: secret.name.clone(),
@@ -101,7 +197,7 @@ pub async fn create_secret(
     )
     .await?;
     audit::record_in_tx(
-        &mut tx,
+        tx,
         &mutation_audit(
             ctx,
             request_id,
@@ -111,9 +207,6 @@ pub async fn create_secret(
         ),
     )
     .await?;
-    tx.commit()
-        .await
-        .map_err(crate::services::db_err("create secret: commit"))?;
     Ok(secret)
 }
 
@@ -162,8 +255,7 @@ pub async fn rotate_secret(
     validate_expiry(rotate.expires_at)?;
     let encrypted = encrypt_spec(&rotate.spec)?;
 
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("rotate secret: begin"))?;
     let secret = secrets::rotate_secret(
@@ -208,6 +300,60 @@ pub async fn rotate_secret(
     Ok(secret)
 }
 
+/// Most secrets one sweep pass withdraws; any remainder waits for the next tick.
+pub const SECRET_EXPIRY_BATCH: i64 = 500;
+
+/// Expiry sweep (system task, no principal): mark every secret whose `expires_at` is at or
+/// before `as_of` and append a `secret.expired` event per secret, in one transaction. The
+/// xDS consumer rebuilds each affected team, which drops the secret from SDS (the encrypted
+/// read path already filters expired rows) under a new Secret type version. Returns what
+/// was withdrawn so the caller can notify; names and timestamps only.
+pub async fn expire_due_secrets(
+    pool: &PgPool,
+    as_of: chrono::DateTime<chrono::Utc>,
+) -> DomainResult<Vec<secrets::ExpiredSecret>> {
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("expire secrets: begin"))?;
+    let expired = secrets::mark_expired_secrets(&mut tx, as_of, SECRET_EXPIRY_BATCH).await?;
+    for secret in &expired {
+        fp_storage::outbox::append(
+            &mut tx,
+            &DomainEvent::SecretExpired {
+                secret_id: secret.id.as_uuid(),
+                name: secret.name.clone(),
+            },
+            EventScope {
+                org_id: Some(secret.team.org_id),
+                team_id: Some(secret.team.id),
+            },
+            serde_json::json!({}),
+        )
+        .await?;
+        audit::record_in_tx(
+            &mut tx,
+            &audit::AuditEntry {
+                request_id: None,
+                actor_type: audit::ActorType::System,
+                actor_id: None,
+                actor_label: "secret-expiry-sweep".into(),
+                surface: audit::Surface::System,
+                action: "secret.expire".into(),
+                resource: format!("secrets/{}", secret.name),
+                org_id: Some(secret.team.org_id),
+                team_id: Some(secret.team.id),
+                outcome: audit::Outcome::Success,
+                detail: serde_json::json!({ "expires_at": secret.expires_at }),
+            },
+        )
+        .await?;
+    }
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("expire secrets: commit"))?;
+    Ok(expired)
+}
+
 struct EncryptedSpec {
     key_id: String,
     ciphertext: Vec<u8>,
@@ -233,6 +379,72 @@ fn encrypt_spec(spec: &SecretSpec) -> DomainResult<EncryptedSpec> {
     })
 }
 
+/// The decoded value of a live team `generic_secret`, for CP-side use (webhook signing).
+/// Missing, expired, and non-generic secrets are all errors; the value never leaves the caller.
+pub(crate) async fn generic_secret_value(
+    pool: &PgPool,
+    team_id: fp_domain::TeamId,
+    id: fp_domain::SecretId,
+) -> DomainResult<Vec<u8>> {
+    let encrypted = secrets::get_encrypted_secret_by_id(pool, team_id, id)
+        .await?
+        .ok_or_else(|| DomainError::not_found("secret", &id.to_string()))?;
+    let spec = decrypt_spec(
+        &encrypted.ciphertext,
+        &encrypted.nonce,
+        &encrypted.metadata.encryption_key_id,
+    )?;
+    let SecretSpec::GenericSecret { secret } = spec else {
+        return Err(DomainError::validation(format!(
+            "secret {id} is not a generic_secret"
+        )));
+    };
+    base64::engine::general_purpose::STANDARD
+        .decode(secret)
+        .map_err(|_| DomainError::internal(format!("secret {id} value is not base64")))
+}
+
+/// Same keyring rules as the fp-xds SDS reader: the active key, else a retired key from
+/// `FLOWPLANE_SECRET_ENCRYPTION_KEYS` for secrets not yet rotated onto the active one.
+fn decrypt_spec(ciphertext: &[u8], nonce: &[u8], key_id: &str) -> DomainResult<SecretSpec> {
+    let key = keyring_secret_key(key_id)?;
+    let nonce = <[u8; 12]>::try_from(nonce)
+        .map_err(|_| DomainError::internal("secret nonce must be 12 bytes"))?;
+    let cipher = Aes256Gcm::new_from_slice(&key)
+        .map_err(|_| DomainError::invalid_config("FLOWPLANE_SECRET_ENCRYPTION_KEY is invalid"))?;
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext)
+        .map_err(|_| DomainError::internal("decrypt secret spec"))?;
+    serde_json::from_slice(&plaintext)
+        .map_err(|e| DomainError::internal(format!("parse decrypted secret spec: {e}")))
+}
+
+fn keyring_secret_key(key_id: &str) -> DomainResult<[u8; 32]> {
+    validate_key_id(key_id)?;
+    let active = active_secret_key()?;
+    if active.id == key_id {
+        return Ok(active.bytes);
+    }
+    let unconfigured = || {
+        DomainError::unavailable(format!(
+            "secret encryption key \"{key_id}\" is not configured"
+        ))
+        .with_hint(
+            "keep retired keys in FLOWPLANE_SECRET_ENCRYPTION_KEYS until old secrets are rotated",
+        )
+    };
+    let raw = std::env::var(KEYRING_ENV).map_err(|_| unconfigured())?;
+    let values: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&raw).map_err(|e| {
+            DomainError::invalid_config(format!("{KEYRING_ENV} must be a JSON object: {e}"))
+        })?;
+    let value = values
+        .get(key_id)
+        .and_then(|value| value.as_str())
+        .ok_or_else(unconfigured)?;
+    parse_secret_key(&format!("{KEYRING_ENV}.{key_id}"), value)
+}
+
 struct SecretKey {
     id: String,
     bytes: [u8; 32],
@@ -241,8 +453,10 @@ struct SecretKey {
 fn active_secret_key() -> DomainResult<SecretKey> {
     let id = active_key_id()?;
     let raw = std::env::var(ACTIVE_KEY_ENV).map_err(|_| {
-        DomainError::unavailable("secret encryption key is not configured")
-            .with_hint("set FLOWPLANE_SECRET_ENCRYPTION_KEY to a 32-byte or base64-encoded key")
+        DomainError::not_configured(
+            "secret encryption",
+            "FLOWPLANE_SECRET_ENCRYPTION_KEY to a 32-byte or base64-encoded key",
+        )
     })?;
     Ok(SecretKey {
         id,
@@ -10,7 +10,9 @@ use crate::authz::{check_resource_access, Decision, PrincipalCtx};
 use crate::services::{actor_of, deny_to_error, record_authz_denial, trace_context_json};
 use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::event::{DomainEvent, EventScope};
-use fp_domain::{DomainError, DomainResult, ErrorCode, OrgId, RequestId, Team, TeamId, UserId};
+use fp_domain::{
+    DomainError, DomainResult, ErrorCode, OrgId, RequestId, Team, TeamId, TeamProfile, UserId,
+};
 use fp_storage::repos::{audit, identity};
 use sqlx::PgPool;
 
@@ -92,17 +94,16 @@ pub async fn create_team(
     pool: &PgPool,
     ctx: &PrincipalCtx,
     name: &str,
-    display_name: &str,
+    profile: &TeamProfile,
     request_id: RequestId,
 ) -> DomainResult<Team> {
     let org_id = require_org_admin(ctx)?;
     // Row + event + audit in ONE transaction (transactional-outbox invariant): a team must
     // never exist without its TeamCreated event and audit row, even on a mid-call crash.
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("create team: begin"))?;
-    let team = identity::create_team_tx(&mut tx, org_id, name, display_name).await?;
+    let team = identity::create_team_tx(&mut tx, org_id, name, profile).await?;
     fp_storage::outbox::append(
         &mut tx,
         &DomainEvent::TeamCreated {
@@ -139,6 +140,48 @@ pub async fn list_teams(pool: &PgPool, ctx: &PrincipalCtx) -> DomainResult<Vec<T
     identity::list_teams_for_org(pool, org_id).await
 }
 
+pub async fn get_team(pool: &PgPool, ctx: &PrincipalCtx, team: TeamRef) -> DomainResult<Team> {
+    let org_id = member_org(ctx)?;
+    require_same_org(team, org_id)?;
+    identity::get_team(pool, team.id)
+        .await?
+        .ok_or_else(|| DomainError::new(ErrorCode::NotFound, "team not found"))
+}
+
+/// Replace a team's display name, description, and Envoy admin port (org admins).
+pub async fn update_team(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    profile: &TeamProfile,
+    request_id: RequestId,
+) -> DomainResult<Team> {
+    let org_id = require_org_admin(ctx)?;
+    require_same_org(team, org_id)?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("update team: begin"))?;
+    let updated = identity::update_team_tx(&mut tx, team.id, profile)
+        .await?
+        .ok_or_else(|| DomainError::new(ErrorCode::NotFound, "team not found"))?;
+    audit::record_in_tx(
+        &mut tx,
+        &admin_audit(
+            ctx,
+            request_id,
+            org_id,
+            Some(team.id),
+            "team.update",
+            format!("teams/{}", updated.name),
+        ),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("update team: commit"))?;
+    Ok(updated)
+}
+
 pub async fn delete_team(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -148,8 +191,7 @@ pub async fn delete_team(
     let org_id = require_org_admin(ctx)?;
     require_same_org(team, org_id)?;
     // Guard + DELETE + event + audit in ONE transaction (see create_team).
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("delete team: begin"))?;
     identity::delete_team_tx(&mut tx, team.id).await?;
@@ -199,8 +241,7 @@ pub async fn add_member(
             DomainError::not_found("user", email)
                 .with_hint("add the user to this organization before adding them to a team")
         })?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("add team member: begin"))?;
     identity::add_team_membership_in_tx(&mut tx, user, team.id).await?;
@@ -241,8 +282,7 @@ pub async fn remove_member(
 ) -> DomainResult<()> {
     let org_id = require_org_admin(ctx)?;
     require_same_org(team, org_id)?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("remove team member: begin"))?;
     if !identity::remove_team_membership_in_tx(&mut tx, user_id, team.id).await? {
@@ -295,8 +335,7 @@ pub async fn add_grant(
         PrincipalCtx::User { user_id, .. } => Some(*user_id),
         PrincipalCtx::Agent { .. } => None,
     };
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("add grant: begin"))?;
     identity::add_grant_in_tx(&mut tx, user, org_id, team.id, resource, action, granter).await?;
@@ -356,8 +395,7 @@ pub async fn remove_grant(
 ) -> DomainResult<()> {
     let org_id = require_org_admin(ctx)?;
     require_same_org(team, org_id)?;
-    let mut tx = pool
-        .begin()
+    let mut tx = fp_storage::begin(pool)
         .await
         .map_err(crate::services::db_err("remove grant: begin"))?;
     if !identity::delete_grant_in_tx(&mut tx, team.id, grant_id).await? {
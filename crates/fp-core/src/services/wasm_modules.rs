@@ -0,0 +1,209 @@
+//! Wasm module services. An upload must be a WebAssembly binary; each upload under a name
+//! becomes its next immutable version, and listeners reference one by name and version from
+//! a `wasm` chain filter. Roll a module forward by uploading again and repointing the filter.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::{actor_of, deny_to_error, record_authz_denial};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::gateway::filters::{HttpFilterSpec, WasmModuleSource};
+use fp_domain::gateway::listener::ListenerSpec;
+use fp_domain::gateway::wasm_module::MAX_WASM_MODULE_BYTES;
+use fp_domain::gateway::WasmModule;
+use fp_domain::{validate_name, DomainError, DomainResult, RequestId};
+use fp_storage::repos::{audit, wasm_modules};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+async fn authorize(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    action: Action,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, Resource::Filters, action, Some(team)) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::Filters,
+                action,
+                Some(team),
+                reason,
+            )
+            .await;
+            Err(deny_to_error(Resource::Filters, action, reason))
+        }
+    }
+}
+
+/// Check that `bytes` is a WebAssembly binary within the size limit and return its
+/// lowercase hex SHA-256.
+pub fn check_module(bytes: &[u8]) -> DomainResult<String> {
+    if bytes.len() > MAX_WASM_MODULE_BYTES {
+        return Err(DomainError::validation(format!(
+            "wasm module is {} bytes; the limit is {MAX_WASM_MODULE_BYTES}",
+            bytes.len()
+        ))
+        .with_hint("build with --release and strip debug info (e.g. wasm-opt -Oz)"));
+    }
+    if !bytes.starts_with(b"\0asm") {
+        return Err(
+            DomainError::validation("module is not WebAssembly (missing \\0asm header)")
+                .with_hint("upload the compiled .wasm file, not its source or text format"),
+        );
+    }
+    Ok(Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+pub async fn create_wasm_module(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    module: &[u8],
+    request_id: RequestId,
+) -> DomainResult<WasmModule> {
+    authorize(pool, ctx, Action::Create, team, request_id).await?;
+    validate_name(name)?;
+    let sha256 = check_module(module)?;
+    crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Filters).await?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("create wasm module: begin"))?;
+    let created = wasm_modules::create(&mut tx, team, name, module, &sha256).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(
+            ctx,
+            request_id,
+            team,
+            "wasm_module.create",
+            name,
+            created.version,
+        ),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create wasm module: commit"))?;
+    Ok(created)
+}
+
+/// Every module version in the team; `name` narrows to the versions of one module.
+pub async fn list_wasm_modules(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: Option<&str>,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<WasmModule>, i64)> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    wasm_modules::list(pool, team.id, name, limit, offset).await
+}
+
+pub async fn get_wasm_module(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    version: i64,
+    request_id: RequestId,
+) -> DomainResult<WasmModule> {
+    authorize(pool, ctx, Action::Read, team, request_id).await?;
+    wasm_modules::get(pool, team.id, name, version)
+        .await?
+        .ok_or_else(|| DomainError::not_found("wasm module", &format!("{name} v{version}")))
+}
+
+pub async fn delete_wasm_module(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    version: i64,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(pool, ctx, Action::Delete, team, request_id).await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("delete wasm module: begin"))?;
+    // Same rule as proto descriptors: no silent cascade out from under a serving listener.
+    let dependents = wasm_modules::listeners_referencing(&mut tx, team.id, name, version).await?;
+    if !dependents.is_empty() {
+        return Err(DomainError::conflict(format!(
+            "wasm module \"{name}\" v{version} is referenced by listeners: {}",
+            dependents.join(", ")
+        ))
+        .with_hint("point those listeners' wasm filters at another version first"));
+    }
+    wasm_modules::delete(&mut tx, team.id, name, version).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "wasm_module.delete", name, version),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("delete wasm module: commit"))?;
+    Ok(())
+}
+
+/// Listener write check: every registry-sourced `wasm` filter must name a module version in
+/// the same team (else 404, matching the cross-tenant disclosure rule).
+pub(crate) async fn check_wasm_references(
+    pool: &PgPool,
+    team: TeamRef,
+    spec: &ListenerSpec,
+) -> DomainResult<()> {
+    for entry in &spec.http_filters {
+        let HttpFilterSpec::Wasm(cfg) = &entry.filter else {
+            continue;
+        };
+        let WasmModuleSource::Registry { name, version } = &cfg.module else {
+            continue;
+        };
+        if wasm_modules::get(pool, team.id, name, *version)
+            .await?
+            .is_none()
+        {
+            return Err(
+                DomainError::not_found("wasm module", &format!("{name} v{version}"))
+                    .with_hint("list uploaded versions with GET …/wasm-modules/<name>"),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn mutation_audit(
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+    team: TeamRef,
+    action: &str,
+    name: &str,
+    version: i64,
+) -> audit::AuditEntry {
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::AuditEntry {
+        request_id: Some(request_id),
+        actor_type,
+        actor_id,
+        actor_label: String::new(),
+        surface: audit::Surface::Rest,
+        action: action.into(),
+        resource: format!("wasm-modules/{name}/versions/{version}"),
+        org_id: Some(team.org_id),
+        team_id: Some(team.id),
+        outcome: audit::Outcome::Success,
+        detail: serde_json::json!({}),
+    }
+}
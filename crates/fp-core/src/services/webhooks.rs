@@ -0,0 +1,683 @@
+//! Outbound webhook delivery. A notice is POSTed with bounded exponential-backoff retries
+//! and, when a secret is configured, an HMAC-SHA256 signature over the exact body bytes. A
+//! notice that fails every attempt is dead-lettered. Dead letters are listed to platform
+//! admins by id and delivery status only: the payload carries tenant-authored names, which
+//! invariant 1 keeps away from a platform admin.
+//!
+//! Team subscriptions receive the team's domain events from their own outbox consumer. The
+//! URL is tenant-authored and the control plane makes the request, so every delivery resolves
+//! the host, refuses protected destinations, and pins the connection to the checked addresses.
+
+use crate::authz::{check_resource_access, Decision, PrincipalCtx};
+use crate::services::egress_advisory::EgressAdvisoryPolicy;
+use crate::services::{actor_of, deny_to_error, record_authz_denial};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::{
+    validate_name, DomainError, DomainResult, RequestId, TeamId, WebhookSubscription,
+    WebhookSubscriptionId, WebhookSubscriptionSpec,
+};
+use fp_storage::outbox::StoredEvent;
+use fp_storage::repos::{audit, webhooks};
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, Mutex};
+
+/// `sha256=<hex HMAC of the body>`; absent when no secret is configured.
+pub const SIGNATURE_HEADER: &str = "x-flowplane-signature-256";
+/// The notice's `event` field, so receivers can route before parsing the body.
+pub const EVENT_HEADER: &str = "x-flowplane-event";
+const MAX_DEAD_LETTERS: i64 = 200;
+/// Outbox consumer that fans team events out to subscriptions.
+pub const SUBSCRIPTION_CONSUMER: &str = "webhooks";
+/// Notices one subscription may have waiting; past this, new ones are dead-lettered.
+pub const SUBSCRIPTION_QUEUE_CAP: usize = 64;
+/// A subscription worker with nothing queued for this long exits; the next notice starts one.
+const SUBSCRIPTION_WORKER_IDLE: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    /// Wait before the second attempt; each later wait doubles.
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Four attempts over about seven seconds of backoff.
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// One configured webhook endpoint.
+#[derive(Clone)]
+pub struct Webhook {
+    pub url: String,
+    pub secret: Option<String>,
+    pub client: reqwest::Client,
+    pub retry: RetryPolicy,
+}
+
+/// Why a notice was abandoned. `last_error` is a short classification, never response text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryFailure {
+    pub attempts: u32,
+    pub last_status: Option<u16>,
+    pub last_error: String,
+}
+
+/// `sha256=<hex>` HMAC-SHA256 of `body` under `secret`.
+pub fn signature(secret: &str, body: &[u8]) -> DomainResult<String> {
+    let sign = || -> Result<Vec<u8>, openssl::error::ErrorStack> {
+        let key = PKey::hmac(secret.as_bytes())?;
+        let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+        signer.update(body)?;
+        signer.sign_to_vec()
+    };
+    let mac = sign().map_err(|e| DomainError::internal(format!("sign webhook body: {e}")))?;
+    Ok(format!(
+        "sha256={}",
+        mac.iter().map(|b| format!("{b:02x}")).collect::<String>()
+    ))
+}
+
+/// Whether a response status is worth retrying: timeouts, throttling, and server errors.
+/// Any other non-success status is the receiver rejecting the notice, which retries won't fix.
+fn retryable(status: reqwest::StatusCode) -> bool {
+    status.is_server_error()
+        || status == reqwest::StatusCode::REQUEST_TIMEOUT
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+fn classify(error: &reqwest::Error) -> &'static str {
+    if error.is_timeout() {
+        "timeout"
+    } else if error.is_connect() {
+        "connect failed"
+    } else {
+        "request failed"
+    }
+}
+
+/// POST `payload` to the webhook, retrying per its [`RetryPolicy`]. Returns the attempt that
+/// succeeded.
+pub async fn deliver(
+    webhook: &Webhook,
+    event: &str,
+    payload: &serde_json::Value,
+) -> Result<u32, DeliveryFailure> {
+    let failure = |attempts, last_status, last_error: &str| DeliveryFailure {
+        attempts,
+        last_status,
+        last_error: last_error.into(),
+    };
+    let body = serde_json::to_vec(payload).map_err(|_| failure(0, None, "serialize failed"))?;
+    let signed = match &webhook.secret {
+        Some(secret) => {
+            Some(signature(secret, &body).map_err(|_| failure(0, None, "sign failed"))?)
+        }
+        None => None,
+    };
+    let mut backoff = webhook.retry.initial_backoff;
+    let mut last = failure(0, None, "not attempted");
+    for attempt in 1..=webhook.retry.max_attempts.max(1) {
+        if attempt > 1 {
+            tokio::time::sleep(backoff).await;
+            backoff = backoff.saturating_mul(2);
+        }
+        let mut request = webhook
+            .client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, event)
+            .body(body.clone());
+        if let Some(signed) = &signed {
+            request = request.header(SIGNATURE_HEADER, signed);
+        }
+        match request.send().await {
+            Ok(response) if response.status().is_success() => return Ok(attempt),
+            Ok(response) => {
+                let status = response.status();
+                last = failure(attempt, Some(status.as_u16()), "rejected");
+                if !retryable(status) {
+                    return Err(last);
+                }
+            }
+            Err(e) => last = failure(attempt, None, classify(&e)),
+        }
+    }
+    Err(last)
+}
+
+/// Record an abandoned notice. Best-effort: a failed insert is logged, never raised, so a
+/// database hiccup cannot stall the loop that was delivering.
+pub async fn dead_letter(
+    pool: &PgPool,
+    team_id: TeamId,
+    subscription_id: Option<WebhookSubscriptionId>,
+    event: &str,
+    payload: &serde_json::Value,
+    failure: &DeliveryFailure,
+) {
+    let record = webhooks::DeadLetterRecord {
+        team_id,
+        subscription_id,
+        event: event.into(),
+        payload: payload.clone(),
+        attempts: i32::try_from(failure.attempts).unwrap_or(i32::MAX).max(1),
+        last_status: failure.last_status.map(i32::from),
+        last_error: failure.last_error.clone(),
+    };
+    if let Err(e) = webhooks::record_dead_letter(pool, &record).await {
+        tracing::warn!(%event, "cannot record webhook dead letter: {e}");
+    }
+}
+
+/// A dead letter as platform admins see it: ids and delivery status, no payload.
+#[derive(Debug, Clone)]
+pub struct DeadLetterSummary {
+    pub id: uuid::Uuid,
+    pub team_id: TeamId,
+    pub subscription_id: Option<WebhookSubscriptionId>,
+    pub event: String,
+    pub attempts: i32,
+    pub last_status: Option<i32>,
+    pub last_error: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Newest dead letters across every team, at most [`MAX_DEAD_LETTERS`]. Platform admin only.
+pub async fn list_dead_letters(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+) -> DomainResult<Vec<DeadLetterSummary>> {
+    match check_resource_access(ctx, Resource::Platform, Action::Execute, None) {
+        Decision::Allow(_) => {}
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::Platform,
+                Action::Execute,
+                None,
+                reason,
+            )
+            .await;
+            return Err(deny_to_error(Resource::Platform, Action::Execute, reason));
+        }
+    }
+    Ok(webhooks::list_dead_letters(pool, MAX_DEAD_LETTERS)
+        .await?
+        .into_iter()
+        .map(|row| DeadLetterSummary {
+            id: row.id,
+            team_id: row.team_id,
+            subscription_id: row.subscription_id,
+            event: row.event,
+            attempts: row.attempts,
+            last_status: row.last_status,
+            last_error: row.last_error,
+            created_at: row.created_at,
+        })
+        .collect())
+}
+
+async fn authorize(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    resource: Resource,
+    action: Action,
+    team: TeamRef,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, resource, action, Some(team)) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(pool, ctx, request_id, resource, action, Some(team), reason).await;
+            Err(deny_to_error(resource, action, reason))
+        }
+    }
+}
+
+pub async fn create_subscription(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    spec: WebhookSubscriptionSpec,
+    request_id: RequestId,
+    advisory: EgressAdvisoryPolicy,
+) -> DomainResult<WebhookSubscription> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Webhooks,
+        Action::Create,
+        team,
+        request_id,
+    )
+    .await?;
+    validate_name(name)?;
+    spec.validate()?;
+    if let Some(secret_id) = spec.secret_id {
+        // Naming a secret lets its value key every signature: same grant as reading it.
+        authorize(pool, ctx, Resource::Secrets, Action::Read, team, request_id).await?;
+        let secret = fp_storage::repos::secrets::get_secret_by_id(pool, team.id, secret_id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("secret", &secret_id.to_string()))?;
+        if !matches!(secret.secret_type, fp_domain::SecretType::GenericSecret) {
+            return Err(DomainError::validation(
+                "webhook secret_id must reference a generic_secret",
+            ));
+        }
+    }
+    advisory
+        .enforce_hosts(
+            pool,
+            ctx,
+            request_id,
+            team,
+            "webhook.create",
+            &format!("webhooks/{name}"),
+            vec![spec.host()?],
+        )
+        .await?;
+    crate::services::quota::check_team_resource_quota(pool, team.id, Resource::Webhooks).await?;
+
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("create webhook: begin"))?;
+    let subscription = webhooks::create_subscription(&mut tx, team, name, &spec).await?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "webhook.create", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("create webhook: commit"))?;
+    Ok(subscription)
+}
+
+pub async fn list_subscriptions(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    limit: i64,
+    offset: i64,
+    request_id: RequestId,
+) -> DomainResult<(Vec<WebhookSubscription>, i64)> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Webhooks,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    webhooks::list_subscriptions(pool, team.id, limit, offset).await
+}
+
+pub async fn get_subscription(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<WebhookSubscription> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Webhooks,
+        Action::Read,
+        team,
+        request_id,
+    )
+    .await?;
+    webhooks::get_subscription(pool, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("webhook", name))
+}
+
+pub async fn delete_subscription(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    team: TeamRef,
+    name: &str,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    authorize(
+        pool,
+        ctx,
+        Resource::Webhooks,
+        Action::Delete,
+        team,
+        request_id,
+    )
+    .await?;
+    let mut tx = fp_storage::begin(pool)
+        .await
+        .map_err(crate::services::db_err("delete webhook: begin"))?;
+    webhooks::delete_subscription(&mut tx, team.id, name)
+        .await?
+        .ok_or_else(|| DomainError::not_found("webhook", name))?;
+    audit::record_in_tx(
+        &mut tx,
+        &mutation_audit(ctx, request_id, team, "webhook.delete", name),
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(crate::services::db_err("delete webhook: commit"))?;
+    Ok(())
+}
+
+fn mutation_audit(
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+    team: TeamRef,
+    action: &str,
+    name: &str,
+) -> audit::AuditEntry {
+    let (actor_type, actor_id) = actor_of(ctx);
+    audit::AuditEntry {
+        request_id: Some(request_id),
+        actor_type,
+        actor_id,
+        actor_label: String::new(),
+        surface: audit::Surface::Rest,
+        action: action.into(),
+        resource: format!("webhooks/{name}"),
+        org_id: Some(team.org_id),
+        team_id: Some(team.id),
+        outcome: audit::Outcome::Success,
+        detail: serde_json::json!({}),
+    }
+}
+
+/// How subscription deliveries are made: which destinations are refused, the per-request
+/// timeout, the retry budget, and the per-subscription delivery queues.
+#[derive(Debug, Clone)]
+pub struct SubscriptionDelivery {
+    pub advisory: EgressAdvisoryPolicy,
+    pub timeout: Duration,
+    pub retry: RetryPolicy,
+    pub workers: SubscriptionWorkers,
+}
+
+/// One delivery worker per subscription, each fed by a queue of at most
+/// [`SUBSCRIPTION_QUEUE_CAP`] notices. Clones share the workers.
+#[derive(Clone, Default)]
+pub struct SubscriptionWorkers(Arc<Mutex<WorkerQueues>>);
+
+#[derive(Default)]
+struct WorkerQueues {
+    next_generation: u64,
+    /// The live worker's generation and queue; a worker only removes its own entry.
+    queues: HashMap<WebhookSubscriptionId, (u64, mpsc::Sender<QueuedNotice>)>,
+}
+
+impl std::fmt::Debug for SubscriptionWorkers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscriptionWorkers")
+            .finish_non_exhaustive()
+    }
+}
+
+struct QueuedNotice {
+    subscription: WebhookSubscription,
+    event: &'static str,
+    notice: serde_json::Value,
+}
+
+/// The [`SUBSCRIPTION_CONSUMER`] handler. Matching events are queued to each subscription's
+/// single worker, which delivers them one at a time in `seq` order, so a slow receiver never
+/// holds the cursor. A receiver that falls [`SUBSCRIPTION_QUEUE_CAP`] notices behind has new
+/// ones dead-lettered instead of queued. Delivery is at-most-once past a crash, and receivers
+/// dedupe on `seq`. Events without a team are not delivered.
+pub async fn handle_events(
+    pool: &PgPool,
+    delivery: &SubscriptionDelivery,
+    events: Vec<StoredEvent>,
+) -> DomainResult<()> {
+    let mut by_team: BTreeMap<TeamId, Vec<StoredEvent>> = BTreeMap::new();
+    for event in events {
+        if let Some(team_id) = event.scope.team_id {
+            by_team.entry(team_id).or_default().push(event);
+        }
+    }
+    for (team_id, events) in by_team {
+        for subscription in webhooks::subscriptions_for_team(pool, team_id).await? {
+            for event in events
+                .iter()
+                .filter(|event| subscription.spec.wants(event.event.kind()))
+            {
+                let queued = QueuedNotice {
+                    subscription: subscription.clone(),
+                    event: event.event.kind(),
+                    notice: notice(&subscription, event),
+                };
+                enqueue(pool, delivery, queued).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Hand a notice to its subscription's worker, starting one if none is running.
+async fn enqueue(pool: &PgPool, delivery: &SubscriptionDelivery, queued: QueuedNotice) {
+    let id = queued.subscription.id;
+    let mut workers = delivery.workers.0.lock().await;
+    let queued = match workers.queues.get(&id) {
+        Some((_, queue)) => match queue.try_send(queued) {
+            Ok(()) => return,
+            Err(TrySendError::Full(queued)) => {
+                drop(workers);
+                let failure = DeliveryFailure {
+                    attempts: 0,
+                    last_status: None,
+                    last_error: "queue full".into(),
+                };
+                tracing::warn!(team = %queued.subscription.team_id,
+                    webhook = %queued.subscription.name, event = queued.event,
+                    "webhook receiver is too far behind; dead-lettered");
+                dead_letter(
+                    pool,
+                    queued.subscription.team_id,
+                    Some(id),
+                    queued.event,
+                    &queued.notice,
+                    &failure,
+                )
+                .await;
+                return;
+            }
+            Err(TrySendError::Closed(queued)) => queued,
+        },
+        None => queued,
+    };
+    let (queue, rx) = mpsc::channel(SUBSCRIPTION_QUEUE_CAP);
+    // A new queue always has room.
+    let _ = queue.try_send(queued);
+    workers.next_generation += 1;
+    let generation = workers.next_generation;
+    workers.queues.insert(id, (generation, queue));
+    tokio::spawn(run_subscription_worker(
+        pool.clone(),
+        delivery.clone(),
+        id,
+        generation,
+        rx,
+    ));
+}
+
+/// Deliver one subscription's notices in order until it has been idle for
+/// [`SUBSCRIPTION_WORKER_IDLE`]. The idle check and removal happen under the queue lock, so
+/// no notice is left behind in a queue nothing reads.
+async fn run_subscription_worker(
+    pool: PgPool,
+    delivery: SubscriptionDelivery,
+    id: WebhookSubscriptionId,
+    generation: u64,
+    mut rx: mpsc::Receiver<QueuedNotice>,
+) {
+    loop {
+        match tokio::time::timeout(SUBSCRIPTION_WORKER_IDLE, rx.recv()).await {
+            Ok(Some(queued)) => {
+                let _ = notify_subscription(
+                    &pool,
+                    &delivery,
+                    &queued.subscription,
+                    queued.event,
+                    &queued.notice,
+                )
+                .await;
+            }
+            Ok(None) => return,
+            Err(_) => {
+                let mut workers = delivery.workers.0.lock().await;
+                if rx.is_empty() {
+                    if workers
+                        .queues
+                        .get(&id)
+                        .is_some_and(|(live, _)| *live == generation)
+                    {
+                        workers.queues.remove(&id);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn notice(subscription: &WebhookSubscription, event: &StoredEvent) -> serde_json::Value {
+    serde_json::json!({
+        "event": event.event.kind(),
+        "seq": event.seq,
+        "org_id": event.scope.org_id,
+        "team_id": event.scope.team_id,
+        "webhook": subscription.name,
+        "data": event.event,
+    })
+}
+
+/// Deliver one notice to a subscription, dead-lettering it on failure. A destination that is
+/// refused, does not resolve, or whose signing secret is unusable fails without a request.
+pub async fn notify_subscription(
+    pool: &PgPool,
+    delivery: &SubscriptionDelivery,
+    subscription: &WebhookSubscription,
+    event: &str,
+    notice: &serde_json::Value,
+) -> Result<u32, DeliveryFailure> {
+    let result = match subscription_webhook(pool, delivery, subscription).await {
+        Ok(webhook) => deliver(&webhook, event, notice).await,
+        Err(last_error) => Err(DeliveryFailure {
+            attempts: 0,
+            last_status: None,
+            last_error: last_error.into(),
+        }),
+    };
+    if let Err(failure) = &result {
+        tracing::warn!(team = %subscription.team_id, webhook = %subscription.name, %event,
+            attempts = failure.attempts, status = ?failure.last_status,
+            error = %failure.last_error, "webhook delivery failed; dead-lettered");
+        dead_letter(
+            pool,
+            subscription.team_id,
+            Some(subscription.id),
+            event,
+            notice,
+            failure,
+        )
+        .await;
+    }
+    result
+}
+
+/// A [`Webhook`] whose client can only reach the addresses checked here.
+async fn subscription_webhook(
+    pool: &PgPool,
+    delivery: &SubscriptionDelivery,
+    subscription: &WebhookSubscription,
+) -> Result<Webhook, &'static str> {
+    let url = reqwest::Url::parse(&subscription.spec.url).map_err(|_| "invalid url")?;
+    let host = subscription.spec.host().map_err(|_| "invalid url")?;
+    let port = url.port_or_known_default().ok_or("invalid url")?;
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .map_err(|_| "resolve failed")?
+        .collect();
+    if addrs.is_empty() {
+        return Err("resolve failed");
+    }
+    if let Some(addr) = addrs
+        .iter()
+        .find(|addr| delivery.advisory.denial_reason(addr.ip()).is_some())
+    {
+        tracing::warn!(webhook = %subscription.name, ip = %addr.ip(),
+            "webhook destination is protected; not delivering");
+        return Err("denied destination");
+    }
+    let secret = match subscription.spec.secret_id {
+        Some(secret_id) => {
+            let value = crate::services::secrets::generic_secret_value(
+                pool,
+                subscription.team_id,
+                secret_id,
+            )
+            .await
+            .map_err(|_| "secret unavailable")?;
+            Some(String::from_utf8(value).map_err(|_| "secret unavailable")?)
+        }
+        None => None,
+    };
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(delivery.timeout)
+        .resolve_to_addrs(&host, &addrs)
+        .build()
+        .map_err(|_| "client failed")?;
+    Ok(Webhook {
+        url: subscription.spec.url.clone(),
+        secret,
+        client,
+        retry: delivery.retry,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    // RFC 4231 test case 2.
+    #[test]
+    fn signature_is_hex_hmac_sha256_of_the_body() {
+        assert_eq!(
+            signature("Jefe", b"what do ya want for nothing?").unwrap(),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn only_transient_statuses_are_retried() {
+        assert!(retryable(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert!(retryable(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(!retryable(reqwest::StatusCode::BAD_REQUEST));
+        assert!(!retryable(reqwest::StatusCode::UNAUTHORIZED));
+    }
+}
@@ -69,6 +69,13 @@ pub struct OpsTrace {
     pub events: Vec<EventTraceRow>,
 }
 
+/// A dataplane is live when it heartbeated within [`LIVE_HEARTBEAT_SECONDS`].
+pub(crate) fn is_live(dataplane: &Dataplane, now: chrono::DateTime<chrono::Utc>) -> bool {
+    dataplane
+        .last_heartbeat_at
+        .is_some_and(|ts| (now - ts).num_seconds() <= LIVE_HEARTBEAT_SECONDS)
+}
+
 pub async fn list_nack_events(
     pool: &PgPool,
     ctx: &PrincipalCtx,
@@ -118,10 +125,7 @@ pub async fn status(
     let dataplanes = dataplanes
         .into_iter()
         .map(|dataplane| {
-            let live = dataplane
-                .last_heartbeat_at
-                .map(|ts| (now - ts).num_seconds() <= LIVE_HEARTBEAT_SECONDS)
-                .unwrap_or(false);
+            let live = is_live(&dataplane, now);
             if live {
                 live_dataplanes += 1;
             }
@@ -180,6 +184,31 @@ pub async fn trace(
     Ok(OpsTrace { audit, events })
 }
 
+/// Gate for the admin xDS reconcile (`POST /api/v1/admin/reconcile`): platform admins only.
+/// The rebuild itself runs in the xDS snapshot cache, which this crate does not own.
+pub async fn authorize_reconcile(
+    pool: &PgPool,
+    ctx: &PrincipalCtx,
+    request_id: RequestId,
+) -> DomainResult<()> {
+    match check_resource_access(ctx, Resource::Platform, Action::Execute, None) {
+        Decision::Allow(_) => Ok(()),
+        Decision::Deny(reason) => {
+            record_authz_denial(
+                pool,
+                ctx,
+                request_id,
+                Resource::Platform,
+                Action::Execute,
+                None,
+                reason,
+            )
+            .await;
+            Err(deny_to_error(Resource::Platform, Action::Execute, reason))
+        }
+    }
+}
+
 fn bounded_trace_path(path: Option<&str>) -> DomainResult<Option<String>> {
     let Some(path) = path else {
         return Ok(None);
@@ -264,6 +293,7 @@ mod tests {
             total_requests,
             total_errors,
             warming_failures,
+            pinned_config_version: None,
             created_at: now,
             updated_at: now,
         }
@@ -0,0 +1,135 @@
+//! Cluster outlier-ejection status: the unavailable path (no ejection feed — the server's
+//! default) is distinguishable from "nothing ejected", a stubbed source populates the list,
+//! and an unknown cluster is the same 404 as a cluster read.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::clusters as cluster_svc;
+use fp_core::services::outliers::{cluster_outliers, OutlierEjection, OutlierSource, Unavailable};
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy, OutlierDetection};
+use fp_domain::{ErrorCode, OrgRole, RequestId, TeamId};
+use fp_storage::repos::identity;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+/// Reports one ejected endpoint for a single (team, cluster).
+struct Stub {
+    team: TeamId,
+    cluster: &'static str,
+    ejection: OutlierEjection,
+}
+
+impl OutlierSource for Stub {
+    fn ejections(&self, team: TeamId, cluster: &str) -> Option<Vec<OutlierEjection>> {
+        (team == self.team && cluster == self.cluster).then(|| vec![self.ejection.clone()])
+    }
+}
+
+#[tokio::test]
+async fn outliers_are_unavailable_by_default_and_populated_by_a_source() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: row.id,
+        org_id: org.id,
+    };
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    let rid = RequestId::generate();
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        team,
+        "orders",
+        ClusterSpec {
+            aggregate_clusters: Vec::new(),
+            endpoints: vec![Endpoint {
+                host: "10.0.0.7".into(),
+                port: 8080,
+                weight: None,
+            }],
+            lb_policy: LbPolicy::RoundRobin,
+            least_request: None,
+            ring_hash: None,
+            maglev: None,
+            dns_lookup_family: None,
+            connect_timeout_secs: 5,
+            use_tls: false,
+            upstream_tls: None,
+            protocol: None,
+            use_downstream_protocol: false,
+            health_checks: None,
+            circuit_breakers: None,
+            outlier_detection: Some(OutlierDetection {
+                consecutive_5xx: 5,
+                interval_seconds: 10,
+                base_ejection_seconds: 30,
+                max_ejection_percent: 50,
+                min_hosts: None,
+            }),
+            connection_pool: None,
+        },
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create cluster");
+
+    let outliers = cluster_outliers(&pool, &admin, team, "orders", &Unavailable, rid)
+        .await
+        .expect("unavailable path");
+    assert_eq!(outliers.cluster, "orders");
+    assert!(outliers.outlier_detection_configured);
+    assert!(outliers.ejections.is_none(), "unknown, not empty");
+
+    let ejection = OutlierEjection {
+        address: "10.0.0.7:8080".into(),
+        node_id: "edge-1".into(),
+        reason: "consecutive_5xx".into(),
+        ejected_at: chrono::Utc::now(),
+    };
+    let stub = Stub {
+        team: team.id,
+        cluster: "orders",
+        ejection: ejection.clone(),
+    };
+    let outliers = cluster_outliers(&pool, &admin, team, "orders", &stub, rid)
+        .await
+        .expect("populated path");
+    assert_eq!(outliers.ejections, Some(vec![ejection]));
+
+    let err = cluster_outliers(&pool, &admin, team, "missing", &stub, rid)
+        .await
+        .expect_err("unknown cluster");
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
@@ -0,0 +1,173 @@
+//! Cluster upstream stats: a dataplane's report replaces its previous one, clusters the team
+//! does not own are dropped, stale reports are left out, and an unknown cluster is the same
+//! 404 as a cluster read.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::clusters as cluster_svc;
+use fp_core::services::dataplanes::cluster_stats;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy};
+use fp_domain::{ErrorCode, OrgRole, RequestId, UpstreamClusterStats};
+use fp_storage::repos::{dataplanes, identity};
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+fn spec() -> ClusterSpec {
+    ClusterSpec {
+        aggregate_clusters: Vec::new(),
+        endpoints: vec![Endpoint {
+            host: "10.0.0.7".into(),
+            port: 8080,
+            weight: None,
+        }],
+        lb_policy: LbPolicy::RoundRobin,
+        least_request: None,
+        ring_hash: None,
+        maglev: None,
+        dns_lookup_family: None,
+        connect_timeout_secs: 5,
+        use_tls: false,
+        upstream_tls: None,
+        protocol: None,
+        use_downstream_protocol: false,
+        health_checks: None,
+        circuit_breakers: None,
+        outlier_detection: None,
+        connection_pool: None,
+    }
+}
+
+fn gauges(cluster: &str, rq_active: i64, open: &[&str]) -> UpstreamClusterStats {
+    UpstreamClusterStats {
+        cluster: cluster.into(),
+        rq_active,
+        rq_pending_active: 0,
+        cx_active: 2,
+        ejections_active: 1,
+        healthy_hosts: 1,
+        total_hosts: 2,
+        open_circuit_breakers: open.iter().map(|b| b.to_string()).collect(),
+    }
+}
+
+#[tokio::test]
+async fn reports_replace_per_dataplane_and_only_owned_fresh_clusters_are_listed() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: row.id,
+        org_id: org.id,
+    };
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    let rid = RequestId::generate();
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        team,
+        "orders",
+        spec(),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create cluster");
+    let mut tx = pool.begin().await.expect("begin");
+    let edge_a = dataplanes::create_dataplane(&mut tx, team, "edge-a", "")
+        .await
+        .expect("dataplane");
+    let edge_b = dataplanes::create_dataplane(&mut tx, team, "edge-b", "")
+        .await
+        .expect("dataplane");
+    tx.commit().await.expect("commit");
+
+    let stats = cluster_stats(&pool, &admin, team, "orders", rid)
+        .await
+        .expect("no reports yet");
+    assert_eq!(stats.cluster, "orders");
+    assert!(stats.dataplanes.is_empty());
+
+    dataplanes::replace_cluster_stats(
+        &pool,
+        team.id,
+        edge_a.id,
+        &[gauges("orders", 9, &[]), gauges("flowplane_xds", 1, &[])],
+    )
+    .await
+    .expect("first report");
+    dataplanes::replace_cluster_stats(
+        &pool,
+        team.id,
+        edge_a.id,
+        &[gauges("orders", 4, &["default.rq_open"])],
+    )
+    .await
+    .expect("second report");
+    dataplanes::replace_cluster_stats(&pool, team.id, edge_b.id, &[gauges("orders", 1, &[])])
+        .await
+        .expect("other dataplane");
+    sqlx::query(
+        "UPDATE dataplane_cluster_stats SET observed_at = now() - interval '10 minutes' \
+         WHERE dataplane_id = $1",
+    )
+    .bind(edge_b.id.as_uuid())
+    .execute(&pool)
+    .await
+    .expect("age edge-b");
+
+    let stats = cluster_stats(&pool, &admin, team, "orders", rid)
+        .await
+        .expect("stats");
+    assert_eq!(stats.dataplanes.len(), 1, "stale edge-b is left out");
+    let entry = &stats.dataplanes[0];
+    assert_eq!(entry.dataplane, "edge-a");
+    assert_eq!(
+        entry.stats.rq_active, 4,
+        "the newer report replaced the older"
+    );
+    assert_eq!(entry.stats.open_circuit_breakers, vec!["default.rq_open"]);
+    let foreign: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM dataplane_cluster_stats WHERE team_id = $1 \
+         AND cluster_name = 'flowplane_xds'",
+    )
+    .bind(team.id.as_uuid())
+    .fetch_one(&pool)
+    .await
+    .expect("count");
+    assert_eq!(foreign, 0, "clusters the team does not own are dropped");
+
+    let err = cluster_stats(&pool, &admin, team, "missing", rid)
+        .await
+        .expect_err("unknown cluster");
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
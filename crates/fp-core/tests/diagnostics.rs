@@ -0,0 +1,130 @@
+//! Admin diagnostics bundle: platform-admin only, carries platform-wide resource counts,
+//! the dataplane connection list, and recent NACKs (redacted to ids and counts).
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::diagnostics::diagnostics;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::{dataplanes, identity, xds_nacks};
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+#[tokio::test]
+async fn bundle_has_counts_connections_and_redacted_nacks_for_platform_admin_only() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: row.id,
+        org_id: org.id,
+    };
+    let mut tx = pool.begin().await.expect("begin");
+    let dataplane = dataplanes::create_dataplane(&mut tx, team, "edge-1", "")
+        .await
+        .expect("dataplane");
+    tx.commit().await.expect("commit");
+    dataplanes::record_telemetry_by_id(
+        &pool,
+        team.id,
+        dataplane.id,
+        dataplanes::TelemetryDelta {
+            idempotency_key: &unique("hb"),
+            requests_delta: 0,
+            errors_delta: 0,
+            warming_failures_delta: 0,
+            config_verified: true,
+        },
+    )
+    .await
+    .expect("heartbeat");
+    xds_nacks::record(
+        &pool,
+        &xds_nacks::NackRecord {
+            team_id: team.id,
+            node_id: "edge-1".into(),
+            type_url: "type.googleapis.com/envoy.config.cluster.v3.Cluster".into(),
+            version_rejected: "7".into(),
+            error_message: "secret tenant detail".into(),
+            quarantined_resources: vec!["orders".into(), "billing".into()],
+        },
+    )
+    .await
+    .expect("nack");
+
+    let rid = RequestId::generate();
+    let admin = PrincipalCtx::User {
+        user_id: identity::upsert_user_by_subject(&pool, &unique("sub"), "p@t.test", "P")
+            .await
+            .expect("user"),
+        platform_admin: true,
+        org_selector_required: false,
+        org: None,
+        grants: GrantSet::default(),
+    };
+    let bundle = diagnostics(&pool, &admin, rid).await.expect("bundle");
+
+    let count = |kind: &str| {
+        bundle
+            .resource_counts
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, n)| *n)
+            .unwrap_or_else(|| panic!("{kind} counted"))
+    };
+    assert!(count("teams") >= 1);
+    assert!(count("dataplanes") >= 1);
+    let connection = bundle
+        .connections
+        .iter()
+        .find(|c| c.dataplane_id == dataplane.id)
+        .expect("fresh heartbeat is listed");
+    assert!(connection.live);
+    assert_eq!(connection.team_id, team.id);
+    assert!(connection.last_config_verify_at.is_some());
+    let nack = bundle
+        .recent_nacks
+        .iter()
+        .find(|n| n.team_id == team.id)
+        .expect("nack listed");
+    assert_eq!(nack.version_rejected, "7");
+    assert_eq!(nack.quarantined_count, 2);
+    assert!(bundle.database.reachable);
+    assert!(bundle.database.pool_max >= bundle.database.pool_size);
+
+    // An org owner is not a platform admin: the cross-tenant bundle is denied.
+    let owner_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "o@t.test", "O")
+        .await
+        .expect("owner");
+    identity::add_org_membership(&pool, owner_id, org.id, OrgRole::Owner)
+        .await
+        .expect("membership");
+    let owner = PrincipalCtx::User {
+        user_id: owner_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Owner)),
+        grants: GrantSet::default(),
+    };
+    let err = diagnostics(&pool, &owner, rid)
+        .await
+        .expect_err("org owner denied");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+}
@@ -0,0 +1,244 @@
+//! Filter presets: a listener chain entry `{"type": "preset", "name": ...}` is replaced on
+//! write by a copy of the same-team preset's filter; another team's preset is invisible
+//! (404), later preset edits do not reach an already-stored listener, and presets are
+//! authorized under the `filters` grants.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::filter_presets as svc;
+use fp_core::services::gateway as gw;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::{Action, Resource, TeamRef};
+use fp_domain::gateway::filters::{
+    CorsConfig, FilterPresetRef, HttpFilterEntry, HttpFilterSpec, OriginMatcher,
+};
+use fp_domain::gateway::listener::{ListenerProtocol, ListenerSpec};
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::identity;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+fn cors(origin: &str) -> HttpFilterSpec {
+    HttpFilterSpec::Cors(CorsConfig {
+        allow_origin: vec![OriginMatcher::Exact {
+            value: origin.into(),
+        }],
+        allow_methods: vec!["GET".into(), "POST".into()],
+        allow_headers: Vec::new(),
+        expose_headers: Vec::new(),
+        max_age_seconds: Some(600),
+        allow_credentials: false,
+    })
+}
+
+fn listener(port: u16, preset: &str) -> ListenerSpec {
+    ListenerSpec {
+        address: "0.0.0.0".into(),
+        port,
+        public_base_url: None,
+        protocol: ListenerProtocol::Http,
+        route_config: None,
+        http_filters: vec![HttpFilterEntry {
+            filter: HttpFilterSpec::Preset(FilterPresetRef {
+                name: preset.into(),
+            }),
+            disabled: false,
+        }],
+        access_logs: Vec::new(),
+        tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
+    }
+}
+
+/// Two teams in one org and an org admin.
+async fn world() -> Option<(PgPool, TeamRef, TeamRef, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let mut teams = Vec::new();
+    for _ in 0..2 {
+        let row = identity::create_team(&pool, org.id, &unique("team"), "")
+            .await
+            .expect("team");
+        teams.push(TeamRef {
+            id: row.id,
+            org_id: org.id,
+        });
+    }
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    Some((pool, teams[0], teams[1], admin))
+}
+
+#[tokio::test]
+async fn listener_instantiates_a_cors_preset_as_a_copy() {
+    let Some((pool, team, _, admin)) = world().await else {
+        return;
+    };
+    let rid = RequestId::generate();
+    let preset = svc::create_filter_preset(
+        &pool,
+        &admin,
+        team,
+        "web-cors",
+        cors("https://app.example"),
+        rid,
+    )
+    .await
+    .expect("create preset");
+
+    let created = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        "edge",
+        listener(18080, "web-cors"),
+        rid,
+        false,
+    )
+    .await
+    .expect("listener from preset");
+    assert_eq!(created.spec.http_filters.len(), 1);
+    assert_eq!(
+        created.spec.http_filters[0].filter,
+        cors("https://app.example")
+    );
+
+    // Editing the preset afterwards leaves the stored listener untouched.
+    svc::update_filter_preset(
+        &pool,
+        &admin,
+        team,
+        "web-cors",
+        cors("https://other.example"),
+        preset.version,
+        rid,
+    )
+    .await
+    .expect("update preset");
+    let stored = gw::get_listener(&pool, &admin, team, "edge", rid)
+        .await
+        .expect("get listener");
+    assert_eq!(
+        stored.spec.http_filters[0].filter,
+        cors("https://app.example")
+    );
+}
+
+#[tokio::test]
+async fn presets_are_team_isolated_and_cannot_nest() {
+    let Some((pool, team, other, admin)) = world().await else {
+        return;
+    };
+    let rid = RequestId::generate();
+    svc::create_filter_preset(
+        &pool,
+        &admin,
+        team,
+        "web-cors",
+        cors("https://app.example"),
+        rid,
+    )
+    .await
+    .expect("create preset");
+
+    let err = svc::get_filter_preset(&pool, &admin, other, "web-cors", rid)
+        .await
+        .expect_err("other team cannot read it");
+    assert_eq!(err.code, ErrorCode::NotFound);
+    let err = gw::create_listener(
+        &pool,
+        &admin,
+        other,
+        "edge",
+        listener(18081, "web-cors"),
+        rid,
+        false,
+    )
+    .await
+    .expect_err("other team cannot instantiate it");
+    assert_eq!(err.code, ErrorCode::NotFound);
+
+    let err = svc::create_filter_preset(
+        &pool,
+        &admin,
+        team,
+        "nested",
+        HttpFilterSpec::Preset(FilterPresetRef {
+            name: "web-cors".into(),
+        }),
+        rid,
+    )
+    .await
+    .expect_err("preset of a preset");
+    assert_eq!(err.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn presets_take_the_filters_grants_not_the_listeners_grants() {
+    let Some((pool, team, _, admin)) = world().await else {
+        return;
+    };
+    let PrincipalCtx::User { user_id, org, .. } = admin else {
+        unreachable!("world returns a user");
+    };
+    let member = |resource: Resource| PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: org.map(|(id, _)| (id, OrgRole::Member)),
+        grants: GrantSet::new([(resource, Action::Create, team.id)]),
+    };
+    let rid = RequestId::generate();
+
+    let err = svc::create_filter_preset(
+        &pool,
+        &member(Resource::Listeners),
+        team,
+        "web-cors",
+        cors("https://app.example"),
+        rid,
+    )
+    .await
+    .expect_err("listener grants do not cover presets");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+    svc::create_filter_preset(
+        &pool,
+        &member(Resource::Filters),
+        team,
+        "web-cors",
+        cors("https://app.example"),
+        rid,
+    )
+    .await
+    .expect("filters create grant");
+}
@@ -5,9 +5,10 @@
 
 use fp_core::services::clusters as svc;
 use fp_core::{GrantSet, PrincipalCtx};
-use fp_domain::authz::TeamRef;
+use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::event::DomainEvent;
 use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy};
+use fp_domain::gateway::ListSort;
 use fp_domain::{ErrorCode, OrgRole, RequestId};
 use fp_storage::repos::identity;
 use sqlx::PgPool;
@@ -36,9 +37,11 @@ fn spec(host: &str) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -291,6 +294,321 @@ async fn delete_requires_current_revision_and_emits_deletion_event() {
     assert!(matches!(event, DomainEvent::ClusterDeleted { name: n, .. } if n == name));
 }
 
+#[tokio::test]
+async fn aggregate_members_must_exist_and_stay_while_listed() {
+    let Some(w) = world().await else { return };
+    let primary = unique("primary");
+    let failover = unique("failover");
+    let aggregate = unique("failover-group");
+    let aggregate_spec = |members: &[&str]| ClusterSpec {
+        endpoints: Vec::new(),
+        aggregate_clusters: members.iter().map(|m| (*m).to_string()).collect(),
+        ..spec("unused")
+    };
+    let create = |name: String, spec: ClusterSpec| {
+        let w = &w;
+        async move {
+            svc::create_cluster(
+                &w.pool,
+                &w.admin,
+                w.team,
+                &name,
+                spec,
+                RequestId::generate(),
+                Default::default(),
+            )
+            .await
+        }
+    };
+    create(primary.clone(), spec("a")).await.expect("primary");
+
+    let err = create(aggregate.clone(), aggregate_spec(&[&primary, &failover]))
+        .await
+        .expect_err("missing member");
+    assert_eq!(err.code, ErrorCode::ValidationFailed);
+    assert!(err.message.contains(&failover), "{}", err.message);
+
+    create(failover.clone(), spec("b")).await.expect("failover");
+    let created = create(aggregate.clone(), aggregate_spec(&[&primary, &failover]))
+        .await
+        .expect("aggregate over existing members");
+    assert_eq!(
+        created.spec.aggregate_clusters,
+        vec![primary.clone(), failover.clone()]
+    );
+
+    let err = create(unique("nested"), aggregate_spec(&[&aggregate, &primary]))
+        .await
+        .expect_err("nested aggregate");
+    assert!(
+        err.message.contains("cannot be aggregate"),
+        "{}",
+        err.message
+    );
+    let err = svc::update_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &aggregate,
+        aggregate_spec(&[&aggregate, &primary]),
+        1,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("self member");
+    assert!(
+        err.message.contains("cannot list itself"),
+        "{}",
+        err.message
+    );
+    let err = svc::update_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &primary,
+        aggregate_spec(&[&failover]),
+        1,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("a member cannot become an aggregate");
+    assert!(err.message.contains(&aggregate), "{}", err.message);
+
+    let err = svc::delete_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &failover,
+        1,
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("member still listed");
+    assert_eq!(err.code, ErrorCode::Conflict);
+    assert!(err.message.contains(&aggregate), "{}", err.message);
+
+    svc::delete_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &aggregate,
+        1,
+        RequestId::generate(),
+    )
+    .await
+    .expect("delete the aggregate");
+    svc::delete_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &failover,
+        1,
+        RequestId::generate(),
+    )
+    .await
+    .expect("member is free once the aggregate is gone");
+}
+
+#[tokio::test]
+async fn clone_copies_the_stored_spec_under_the_new_name() {
+    let Some(w) = world().await else { return };
+    let name = unique("source");
+    let mut source_spec = spec("10.0.0.7");
+    source_spec.connect_timeout_secs = 9;
+    let source = svc::create_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &name,
+        source_spec,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("create");
+
+    let copy_name = unique("copy");
+    let copy = svc::clone_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &name,
+        w.team,
+        &copy_name,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("clone");
+    assert_eq!(copy.name, copy_name);
+    assert_ne!(copy.id, source.id);
+    assert_eq!(copy.version, 1);
+    assert_eq!(
+        copy.spec, source.spec,
+        "the clone carries the source config verbatim"
+    );
+
+    // Into another team of the same org: ownership moves with the copy.
+    let other = identity::create_team(&w.pool, w.team.org_id, &unique("team"), "")
+        .await
+        .expect("team");
+    let other = TeamRef {
+        id: other.id,
+        org_id: w.team.org_id,
+    };
+    svc::clone_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &name,
+        other,
+        &name,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("cross-team clone");
+    let moved = svc::get_cluster(&w.pool, &w.admin, other, &name, RequestId::generate())
+        .await
+        .expect("copy lives in the target team");
+    assert_eq!(moved.spec.connect_timeout_secs, 9);
+
+    // A name already taken in the target team is a collision, not an overwrite.
+    let err = svc::clone_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &name,
+        w.team,
+        &copy_name,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("collision");
+    assert_eq!(err.code, ErrorCode::Conflict);
+
+    // Cross-org callers cannot read the source, so they cannot clone it either.
+    let err = svc::clone_cluster(
+        &w.pool,
+        &w.outsider,
+        w.team,
+        &name,
+        w.team,
+        &unique("stolen"),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("outsider clone");
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn clone_into_a_team_outside_the_callers_grants_is_forbidden_before_any_read() {
+    let Some(w) = world().await else { return };
+    let name = unique("source");
+    svc::create_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &name,
+        spec("10.0.0.8"),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("create");
+    let other = identity::create_team(&w.pool, w.team.org_id, &unique("team"), "")
+        .await
+        .expect("team");
+    let other = TeamRef {
+        id: other.id,
+        org_id: w.team.org_id,
+    };
+
+    // A plain member holding read + create on the source team only.
+    let sub = unique("sub");
+    let member_id = identity::upsert_user_by_subject(&w.pool, &sub, "m@t.test", "M")
+        .await
+        .expect("u");
+    identity::add_org_membership(&w.pool, member_id, w.team.org_id, OrgRole::Member)
+        .await
+        .expect("m");
+    for action in [Action::Read, Action::Create] {
+        identity::add_grant(
+            &w.pool,
+            member_id,
+            w.team.org_id,
+            w.team.id,
+            Resource::Clusters,
+            action,
+            None,
+        )
+        .await
+        .expect("grant");
+    }
+    let loaded = identity::load_principal(&w.pool, &sub)
+        .await
+        .expect("load")
+        .expect("principal");
+    let member = PrincipalCtx::User {
+        user_id: member_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((w.team.org_id, OrgRole::Member)),
+        grants: GrantSet::new(loaded.grants),
+    };
+
+    let err = svc::clone_cluster(
+        &w.pool,
+        &member,
+        w.team,
+        &name,
+        other,
+        &name,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("target team is out of scope");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+    // The guard runs before the source read, so even a missing source reports the target denial.
+    let err = svc::clone_cluster(
+        &w.pool,
+        &member,
+        w.team,
+        &unique("missing"),
+        other,
+        &name,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("target team is out of scope");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+    let err = svc::get_cluster(&w.pool, &w.admin, other, &name, RequestId::generate())
+        .await
+        .expect_err("nothing was written to the target team");
+    assert_eq!(err.code, ErrorCode::NotFound);
+
+    // Within the granted team the same member clones normally.
+    svc::clone_cluster(
+        &w.pool,
+        &member,
+        w.team,
+        &name,
+        w.team,
+        &unique("copy"),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("same-team clone");
+}
+
 #[tokio::test]
 async fn quota_caps_cluster_count_per_team() {
     let Some(w) = world().await else { return };
@@ -361,7 +679,7 @@ mod referential {
     use fp_core::services::gateway as gw;
     use fp_domain::gateway::listener::ListenerSpec;
     use fp_domain::gateway::route_config::{
-        PathMatch, RouteAction, RouteConfigSpec, RouteRule, VirtualHost,
+        PathMatch, RouteAction, RouteConfigSpec, RouteRule, VirtualHost, WeightedClusterTarget,
     };
 
     fn rc_spec(cluster: &str) -> RouteConfigSpec {
@@ -373,7 +691,9 @@ mod referential {
                     name: "all".into(),
                     matcher: PathMatch::Prefix { prefix: "/".into() },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: RouteAction {
                         cluster: Some(cluster.into()),
                         weighted_clusters: None,
@@ -383,6 +703,16 @@ mod referential {
                         template_rewrite: None,
                         timeout_secs: 15,
                         retry_policy: None,
+                        retry_policy_ref: None,
+                        idle_timeout_secs: None,
+                        max_stream_duration_secs: None,
+                        regex_rewrite: None,
+                        host_rewrite_literal: None,
+                        auto_host_rewrite: false,
+                        fallback_cluster: None,
+                        request_mirror_policies: Vec::new(),
+                        request_headers_to_remove: Vec::new(),
+                        hash_policy: Vec::new(),
                         rate_limits: Vec::new(),
                     },
                     filter_overrides: Vec::new(),
@@ -451,6 +781,11 @@ mod referential {
                 http_filters: Vec::new(),
                 access_logs: Vec::new(),
                 tls_context: None,
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             },
             rid(),
             false,
@@ -458,6 +793,73 @@ mod referential {
         .await
         .expect("listener");
 
+        // A second route config splits traffic between the cluster and another one.
+        let other_cluster = unique("canary");
+        svc::create_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &other_cluster,
+            spec("10.0.0.10"),
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect("other cluster");
+        let split_name = unique("split");
+        let mut split = rc_spec(&cluster_name);
+        let action = &mut split.virtual_hosts[0].routes[0].action;
+        action.cluster = None;
+        action.weighted_clusters = Some(vec![
+            WeightedClusterTarget {
+                cluster: cluster_name.clone(),
+                weight: 90,
+            },
+            WeightedClusterTarget {
+                cluster: other_cluster.clone(),
+                weight: 10,
+            },
+        ]);
+        gw::create_route_config(&w.pool, &w.admin, w.team, &split_name, split, rid())
+            .await
+            .expect("weighted route config");
+
+        // The reverse lookup finds both route configs, their routes, and the serving listener.
+        let refs = gw::cluster_references(&w.pool, &w.admin, w.team, &cluster_name, rid())
+            .await
+            .expect("references");
+        assert_eq!(refs.route_configs, [rc_name.clone(), split_name.clone()]);
+        let routes: Vec<_> = refs
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.route_config.as_str(),
+                    r.virtual_host.as_str(),
+                    r.route.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            routes,
+            [
+                (rc_name.as_str(), "default", "all"),
+                (split_name.as_str(), "default", "all")
+            ]
+        );
+        assert_eq!(refs.listeners, std::slice::from_ref(&listener_name));
+        assert!(refs.listener_services.is_empty());
+        assert!(refs.aggregates.is_empty());
+        let other = gw::cluster_references(&w.pool, &w.admin, w.team, &other_cluster, rid())
+            .await
+            .expect("references");
+        assert_eq!(other.route_configs, std::slice::from_ref(&split_name));
+        assert!(other.listeners.is_empty(), "the split config is unbound");
+        let err = gw::cluster_references(&w.pool, &w.outsider, w.team, &cluster_name, rid())
+            .await
+            .expect_err("outsider");
+        assert_eq!(err.code, ErrorCode::NotFound);
+
         // Deleting the referenced cluster: conflict naming the dependent route config.
         let err = svc::delete_cluster(&w.pool, &w.admin, w.team, &cluster_name, 1, rid())
             .await
@@ -483,6 +885,9 @@ mod referential {
         gw::delete_route_config(&w.pool, &w.admin, w.team, &rc_name, 1, rid())
             .await
             .expect("delete route config");
+        gw::delete_route_config(&w.pool, &w.admin, w.team, &split_name, 1, rid())
+            .await
+            .expect("delete weighted route config");
         svc::delete_cluster(&w.pool, &w.admin, w.team, &cluster_name, 1, rid())
             .await
             .expect("delete cluster");
@@ -497,6 +902,297 @@ mod referential {
         assert_eq!(refs, 0, "no orphaned reference rows");
     }
 
+    #[tokio::test]
+    async fn transfer_moves_resources_between_teams_and_guards_references() {
+        let Some(w) = world().await else { return };
+        let rid = RequestId::generate;
+        let other = identity::create_team(&w.pool, w.team.org_id, &unique("team"), "")
+            .await
+            .expect("team");
+        let other = TeamRef {
+            id: other.id,
+            org_id: w.team.org_id,
+        };
+
+        // A standalone cluster moves outright: same id, next revision, new owner.
+        let lone = unique("lone");
+        let created = svc::create_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &lone,
+            spec("10.0.0.10"),
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect("cluster");
+        let err = svc::transfer_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &lone,
+            w.team,
+            1,
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect_err("same team");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
+        let request_id = rid();
+        let moved = svc::transfer_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &lone,
+            other,
+            1,
+            request_id,
+            Default::default(),
+        )
+        .await
+        .expect("transfer");
+        assert_eq!(moved.id, created.id);
+        assert_eq!(moved.team_id, other.id);
+        assert_eq!(moved.version, 2);
+        let err = svc::get_cluster(&w.pool, &w.admin, w.team, &lone, rid())
+            .await
+            .expect_err("gone from the source team");
+        assert_eq!(err.code, ErrorCode::NotFound);
+        let teams: Vec<uuid::Uuid> = sqlx::query_scalar(
+            "SELECT team_id FROM audit_log WHERE request_id = $1 AND action = 'cluster.transfer' \
+             ORDER BY team_id",
+        )
+        .bind(request_id.as_uuid())
+        .fetch_all(&w.pool)
+        .await
+        .expect("audit");
+        let mut expected = vec![w.team.id.as_uuid(), other.id.as_uuid()];
+        expected.sort();
+        assert_eq!(teams, expected, "both teams' audit logs record the move");
+        let kinds: Vec<(String, uuid::Uuid)> = sqlx::query_as(
+            "SELECT event_type, team_id FROM events \
+             WHERE event_type LIKE 'cluster.%' AND payload->>'cluster_id' = $1 ORDER BY seq",
+        )
+        .bind(created.id.as_uuid().to_string())
+        .fetch_all(&w.pool)
+        .await
+        .expect("events");
+        assert_eq!(
+            kinds[kinds.len() - 2..],
+            [
+                ("cluster.deleted".to_string(), w.team.id.as_uuid()),
+                ("cluster.upserted".to_string(), other.id.as_uuid()),
+            ],
+            "the source team rebuilds without it and the target with it"
+        );
+
+        // Chain: cluster -> route config -> listener. Each link blocks moving what it uses.
+        let cluster_name = unique("upstream");
+        svc::create_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &cluster_name,
+            spec("10.0.0.11"),
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect("cluster");
+        let rc_name = unique("routes");
+        gw::create_route_config(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &rc_name,
+            rc_spec(&cluster_name),
+            rid(),
+        )
+        .await
+        .expect("route config");
+        let listener_name = unique("edge");
+        gw::create_listener(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &listener_name,
+            ListenerSpec {
+                address: "0.0.0.0".into(),
+                port: 18445,
+                public_base_url: None,
+                protocol: fp_domain::gateway::listener::ListenerProtocol::Http,
+                route_config: Some(rc_name.clone()),
+                http_filters: Vec::new(),
+                access_logs: Vec::new(),
+                tls_context: None,
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
+            },
+            rid(),
+            false,
+        )
+        .await
+        .expect("listener");
+
+        let err = svc::transfer_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &cluster_name,
+            other,
+            1,
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect_err("referenced cluster stays");
+        assert_eq!(err.code, ErrorCode::Conflict);
+        assert!(err.message.contains(&rc_name));
+        let err = gw::transfer_route_config(&w.pool, &w.admin, w.team, &rc_name, other, 1, rid())
+            .await
+            .expect_err("bound route config stays");
+        assert_eq!(err.code, ErrorCode::Conflict);
+        assert!(err.message.contains(&listener_name));
+        let err = gw::transfer_listener(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &listener_name,
+            other,
+            1,
+            rid(),
+            false,
+        )
+        .await
+        .expect_err("the target lacks the route config");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
+
+        // Once the target holds what the listener names, the listener moves with its id.
+        svc::clone_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &cluster_name,
+            other,
+            &cluster_name,
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect("clone cluster");
+        gw::clone_route_config(&w.pool, &w.admin, w.team, &rc_name, other, &rc_name, rid())
+            .await
+            .expect("clone route config");
+        let listener = gw::transfer_listener(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &listener_name,
+            other,
+            1,
+            rid(),
+            false,
+        )
+        .await
+        .expect("transfer listener");
+        assert_eq!(listener.team_id, other.id);
+        let bound: uuid::Uuid = sqlx::query_scalar(
+            "SELECT rc.team_id FROM listener_route_config_refs r \
+             JOIN route_configs rc ON rc.id = r.route_config_id WHERE r.listener_id = $1",
+        )
+        .bind(listener.id.as_uuid())
+        .fetch_one(&w.pool)
+        .await
+        .expect("listener ref");
+        assert_eq!(
+            bound,
+            other.id.as_uuid(),
+            "the listener binds the target's route config"
+        );
+
+        // The source route config is free now, but its name is taken in the target.
+        let err = gw::transfer_route_config(&w.pool, &w.admin, w.team, &rc_name, other, 1, rid())
+            .await
+            .expect_err("name collision");
+        assert_eq!(err.code, ErrorCode::Conflict);
+
+        // A second route config moves and re-resolves its cluster in the target team.
+        let rc2 = unique("routes");
+        gw::create_route_config(
+            &w.pool,
+            &w.admin,
+            w.team,
+            &rc2,
+            rc_spec(&cluster_name),
+            rid(),
+        )
+        .await
+        .expect("route config");
+        let moved = gw::transfer_route_config(&w.pool, &w.admin, w.team, &rc2, other, 1, rid())
+            .await
+            .expect("transfer route config");
+        let target_cluster: uuid::Uuid = sqlx::query_scalar(
+            "SELECT c.team_id FROM route_config_cluster_refs r \
+             JOIN clusters c ON c.id = r.cluster_id WHERE r.route_config_id = $1",
+        )
+        .bind(moved.id.as_uuid())
+        .fetch_one(&w.pool)
+        .await
+        .expect("cluster ref");
+        assert_eq!(target_cluster, other.id.as_uuid());
+
+        // A member holding grants in the source team only cannot move anything out of it.
+        let sub = unique("sub");
+        let member_id = identity::upsert_user_by_subject(&w.pool, &sub, "m@t.test", "M")
+            .await
+            .expect("u");
+        identity::add_org_membership(&w.pool, member_id, w.team.org_id, OrgRole::Member)
+            .await
+            .expect("m");
+        for action in [Action::Read, Action::Create, Action::Delete] {
+            identity::add_grant(
+                &w.pool,
+                member_id,
+                w.team.org_id,
+                w.team.id,
+                Resource::Clusters,
+                action,
+                None,
+            )
+            .await
+            .expect("grant");
+        }
+        let loaded = identity::load_principal(&w.pool, &sub)
+            .await
+            .expect("load")
+            .expect("principal");
+        let member = PrincipalCtx::User {
+            user_id: member_id,
+            platform_admin: false,
+            org_selector_required: false,
+            org: Some((w.team.org_id, OrgRole::Member)),
+            grants: GrantSet::new(loaded.grants),
+        };
+        let err = svc::transfer_cluster(
+            &w.pool,
+            &member,
+            w.team,
+            &cluster_name,
+            other,
+            1,
+            rid(),
+            Default::default(),
+        )
+        .await
+        .expect_err("no create grant in the target team");
+        assert_eq!(err.code, ErrorCode::Forbidden);
+    }
+
     #[tokio::test]
     async fn manual_listener_names_cannot_use_ai_prefix() {
         let Some(w) = world().await else { return };
@@ -540,6 +1236,11 @@ mod referential {
                 http_filters: Vec::new(),
                 access_logs: Vec::new(),
                 tls_context: None,
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             },
             rid(),
             false,
@@ -571,6 +1272,11 @@ mod referential {
                     http_filters: Vec::new(),
                     access_logs: Vec::new(),
                     tls_context: None,
+                    network_filters: Vec::new(),
+                    tracing: None,
+                    listener_filters: Vec::new(),
+                    http_limits: None,
+                    exact_balance: false,
                 },
                 rid(),
                 false,
@@ -583,6 +1289,55 @@ mod referential {
         assert_eq!(err.code, ErrorCode::Conflict);
         assert!(err.hint.is_some());
     }
+
+    #[tokio::test]
+    async fn http3_listener_shares_a_port_with_a_tcp_listener() {
+        use fp_domain::gateway::listener::{ListenerProtocol, ListenerTlsConfig};
+        let Some(w) = world().await else { return };
+        let rid = RequestId::generate;
+        let make = |name: String, protocol: ListenerProtocol| {
+            gw::create_listener(
+                &w.pool,
+                &w.admin,
+                w.team,
+                Box::leak(name.into_boxed_str()),
+                ListenerSpec {
+                    address: "0.0.0.0".into(),
+                    port: 19443,
+                    public_base_url: None,
+                    protocol,
+                    route_config: None,
+                    http_filters: Vec::new(),
+                    access_logs: Vec::new(),
+                    tls_context: Some(ListenerTlsConfig {
+                        cert_chain_file: None,
+                        private_key_file: None,
+                        ca_cert_file: None,
+                        require_client_certificate: false,
+                        tls_certificate_sds_secret_name: Some("edge-cert".into()),
+                        validation_context_sds_secret_name: None,
+                    }),
+                    network_filters: Vec::new(),
+                    tracing: None,
+                    listener_filters: Vec::new(),
+                    http_limits: None,
+                    exact_balance: false,
+                },
+                rid(),
+                false,
+            )
+        };
+        make(unique("tcp"), ListenerProtocol::Https)
+            .await
+            .expect("tcp listener");
+        make(unique("quic"), ListenerProtocol::Http3)
+            .await
+            .expect("udp listener on the same port");
+        let err = make(unique("quic2"), ListenerProtocol::Http3)
+            .await
+            .expect_err("second udp listener on the port");
+        assert_eq!(err.code, ErrorCode::Conflict);
+    }
 }
 
 mod expose_shortcut {
@@ -664,3 +1419,70 @@ mod expose_shortcut {
         assert_eq!(route_config_count, count as i64);
     }
 }
+
+#[tokio::test]
+async fn list_sorts_by_name_or_timestamps_in_either_direction() {
+    let Some(w) = world().await else { return };
+    let first = unique("b-first");
+    let second = unique("a-second");
+    for name in [&first, &second] {
+        svc::create_cluster(
+            &w.pool,
+            &w.admin,
+            w.team,
+            name,
+            spec("a"),
+            RequestId::generate(),
+            Default::default(),
+        )
+        .await
+        .expect("create");
+    }
+    // Touch the older one so it becomes the most recently updated.
+    svc::update_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &first,
+        spec("b"),
+        1,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("update");
+
+    let names = |sort: &str, order: &str| {
+        let pool = w.pool.clone();
+        let admin = w.admin.clone();
+        let team = w.team;
+        let sort = ListSort::parse(Some(sort), Some(order)).expect("sort");
+        async move {
+            let (items, total) = svc::list_clusters(
+                &pool,
+                &admin,
+                team,
+                None,
+                sort,
+                50,
+                0,
+                RequestId::generate(),
+            )
+            .await
+            .expect("list");
+            assert_eq!(total, 2);
+            items.into_iter().map(|c| c.name).collect::<Vec<_>>()
+        }
+    };
+    assert_eq!(names("name", "asc").await, [second.clone(), first.clone()]);
+    assert_eq!(names("name", "desc").await, [first.clone(), second.clone()]);
+    assert_eq!(
+        names("created_at", "asc").await,
+        [first.clone(), second.clone()]
+    );
+    assert_eq!(
+        names("updated_at", "desc").await,
+        [first.clone(), second.clone()]
+    );
+    assert_eq!(names("updated_at", "asc").await, [second, first]);
+}
@@ -43,9 +43,11 @@ fn cluster_spec(host: &str) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -58,7 +60,9 @@ fn rc_spec(cluster: &str) -> RouteConfigSpec {
                 name: "all".into(),
                 matcher: PathMatch::Prefix { prefix: "/".into() },
                 headers: Vec::new(),
+                methods: Vec::new(),
                 query_parameters: Vec::new(),
+                runtime_fraction: None,
                 action: RouteAction {
                     cluster: Some(cluster.into()),
                     weighted_clusters: None,
@@ -68,6 +72,16 @@ fn rc_spec(cluster: &str) -> RouteConfigSpec {
                     template_rewrite: None,
                     timeout_secs: 15,
                     retry_policy: None,
+                    retry_policy_ref: None,
+                    idle_timeout_secs: None,
+                    max_stream_duration_secs: None,
+                    regex_rewrite: None,
+                    host_rewrite_literal: None,
+                    auto_host_rewrite: false,
+                    fallback_cluster: None,
+                    request_mirror_policies: Vec::new(),
+                    request_headers_to_remove: Vec::new(),
+                    hash_policy: Vec::new(),
                     rate_limits: Vec::new(),
                 },
                 filter_overrides: Vec::new(),
@@ -88,6 +102,11 @@ fn listener_spec(route_config: Option<String>, port: u16) -> ListenerSpec {
         http_filters: Vec::new(),
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     }
 }
 
@@ -674,3 +693,57 @@ async fn same_kind_references_work_across_create_and_update() {
     assert_eq!(updated.spec.route_config.as_deref(), Some(rc_name.as_str()));
     assert_eq!(updated.spec.port, 28531);
 }
+
+// AC7: a route config name that does not exist at all (a typo) is rejected on listener
+// create AND update with the same error, so a listener never ships bound to nothing.
+#[tokio::test]
+async fn user_listener_naming_a_missing_route_config_is_rejected() {
+    let Some(w) = world().await else { return };
+    let missing_rc = unique("typo-rc");
+    let listener_name = unique("edge");
+    let err = gw::create_listener(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &listener_name,
+        listener_spec(Some(missing_rc.clone()), 28540),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect_err("create must not bind a missing route config");
+    assert_eq!(err.code, ErrorCode::ValidationFailed, "err: {err:?}");
+    assert!(
+        err.message.contains(&missing_rc),
+        "error must name the missing route config: {}",
+        err.message
+    );
+    assert!(!listener_exists(&w, &listener_name).await);
+
+    gw::create_listener(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &listener_name,
+        listener_spec(None, 28540),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect("an unbound listener is allowed");
+    let err = gw::update_listener(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &listener_name,
+        listener_spec(Some(missing_rc.clone()), 28540),
+        1,
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect_err("update must not bind a missing route config");
+    assert_eq!(err.code, ErrorCode::ValidationFailed, "err: {err:?}");
+    assert!(err.message.contains(&missing_rc), "{}", err.message);
+    assert!(listener_bound_rc_names(&w, &listener_name).await.is_empty());
+}
@@ -0,0 +1,176 @@
+//! Learned OpenAPI export: a capturing session can be previewed without an API definition,
+//! nothing is stored, and a cancelled session is a conflict.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::learning as svc;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::api_lifecycle::{CaptureSessionSpec, ObservationIngest};
+use fp_domain::authz::TeamRef;
+use fp_domain::{ErrorCode, ListenerId, OrgRole, RequestId, RouteConfigId};
+use fp_storage::repos::{api_lifecycle, identity};
+use sqlx::types::chrono::Utc;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+/// A team with a route config and listener to capture on, and an org admin.
+async fn world() -> Option<(PgPool, TeamRef, RouteConfigId, ListenerId, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: row.id,
+        org_id: org.id,
+    };
+    let route = RouteConfigId::generate();
+    sqlx::query(
+        "INSERT INTO route_configs (id, team_id, org_id, name, spec) \
+         VALUES ($1, $2, $3, $4, '{\"virtual_hosts\":[]}'::jsonb)",
+    )
+    .bind(route.as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(unique("rc"))
+    .execute(&pool)
+    .await
+    .expect("route config");
+    let listener = ListenerId::generate();
+    sqlx::query(
+        "INSERT INTO listeners (id, team_id, org_id, name, spec) \
+         VALUES ($1, $2, $3, $4, '{\"address\":\"0.0.0.0\",\"port\":18080}'::jsonb)",
+    )
+    .bind(listener.as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(unique("listener"))
+    .execute(&pool)
+    .await
+    .expect("listener");
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    Some((pool, team, route, listener, admin))
+}
+
+fn observation(request_id: &str, response_body: &str) -> ObservationIngest {
+    ObservationIngest {
+        request_id: request_id.into(),
+        method: "GET".into(),
+        path: "/orders".into(),
+        response_status: Some(200),
+        request_headers: serde_json::Map::new(),
+        response_headers: serde_json::Map::new(),
+        request_body: None,
+        response_body: Some(response_body.into()),
+        request_body_truncated: false,
+        response_body_truncated: false,
+        request_body_bytes: None,
+        response_body_bytes: None,
+        metadata_seen: true,
+        body_seen: true,
+        observed_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn capturing_session_exports_inferred_openapi_until_cancelled() {
+    let Some((pool, team, route, listener, admin)) = world().await else {
+        return;
+    };
+    let mut tx = pool.begin().await.expect("tx");
+    let session = api_lifecycle::create_capture_session(
+        &mut tx,
+        team,
+        &unique("capture"),
+        &CaptureSessionSpec {
+            api_definition_id: None,
+            route_config_id: Some(route),
+            listener_id: Some(listener),
+            virtual_host: Some("default".into()),
+            route: Some("all".into()),
+            target_sample_count: 10,
+            max_duration_seconds: Some(60),
+            max_bytes: 4096,
+            max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
+        },
+    )
+    .await
+    .expect("session");
+    tx.commit().await.expect("commit session");
+
+    let err = svc::learned_openapi(&pool, &admin, team, &session.name, RequestId::generate())
+        .await
+        .expect_err("nothing observed yet");
+    assert_eq!(err.code, ErrorCode::ValidationFailed);
+
+    for (request_id, body) in [("req-1", r#"{"id":1}"#), ("req-2", r#"{"id":2}"#)] {
+        let mut tx = pool.begin().await.expect("ingest tx");
+        api_lifecycle::ingest_raw_observation(
+            &mut tx,
+            team,
+            session.id,
+            None,
+            route,
+            Some(listener),
+            &observation(request_id, body),
+        )
+        .await
+        .expect("ingest");
+        tx.commit().await.expect("commit ingest");
+    }
+
+    let doc = svc::learned_openapi(&pool, &admin, team, &session.name, RequestId::generate())
+        .await
+        .expect("export");
+    assert_eq!(doc["openapi"], "3.1.0");
+    let operation = &doc["paths"]["/orders"]["get"];
+    assert!(operation["responses"]["200"].is_object(), "{doc}");
+    let spec_versions: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM spec_versions s \
+         JOIN api_definitions a ON a.id = s.api_definition_id WHERE a.team_id = $1",
+    )
+    .bind(team.id.as_uuid())
+    .fetch_one(&pool)
+    .await
+    .expect("count");
+    assert_eq!(spec_versions, 0, "export stores nothing");
+
+    svc::cancel_session(&pool, &admin, team, &session.name, RequestId::generate())
+        .await
+        .expect("cancel");
+    let err = svc::learned_openapi(&pool, &admin, team, &session.name, RequestId::generate())
+        .await
+        .expect_err("cancelled");
+    assert_eq!(err.code, ErrorCode::Conflict);
+}
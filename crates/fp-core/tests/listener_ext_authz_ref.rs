@@ -0,0 +1,278 @@
+//! An `ext_authz` filter must reference an existing same-team cluster, in gRPC and HTTP
+//! mode alike; a missing reference is rejected 404 before anything is stored. The collector
+//! clusters of an `otel` access log and of OpenTelemetry tracing are checked the same way.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::clusters as cluster_svc;
+use fp_core::services::gateway as gw;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy};
+use fp_domain::gateway::filters::{
+    ExtAuthzConfig, ExtAuthzHttpService, HttpFilterEntry, HttpFilterSpec,
+};
+use fp_domain::gateway::listener::{
+    AccessLogConfig, ListenerProtocol, ListenerSpec, ListenerTracingConfig, OtelAccessLogConfig,
+    OtelTracingProvider, TracingProvider,
+};
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::identity;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+fn listener(port: u16, cluster: &str, http_service: Option<ExtAuthzHttpService>) -> ListenerSpec {
+    ListenerSpec {
+        address: "0.0.0.0".into(),
+        port,
+        public_base_url: None,
+        protocol: ListenerProtocol::Http,
+        route_config: None,
+        http_filters: vec![HttpFilterEntry {
+            filter: HttpFilterSpec::ExtAuthz(ExtAuthzConfig {
+                cluster: cluster.into(),
+                timeout_ms: 200,
+                failure_mode_allow: false,
+                include_peer_certificate: false,
+                http_service,
+            }),
+            disabled: false,
+        }],
+        access_logs: Vec::new(),
+        tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
+    }
+}
+
+fn cluster_spec() -> ClusterSpec {
+    ClusterSpec {
+        aggregate_clusters: Vec::new(),
+        endpoints: vec![Endpoint {
+            host: "10.0.0.7".into(),
+            port: 9000,
+            weight: None,
+        }],
+        lb_policy: LbPolicy::RoundRobin,
+        least_request: None,
+        ring_hash: None,
+        maglev: None,
+        dns_lookup_family: None,
+        connect_timeout_secs: 5,
+        use_tls: false,
+        upstream_tls: None,
+        protocol: None,
+        use_downstream_protocol: false,
+        health_checks: None,
+        circuit_breakers: None,
+        outlier_detection: None,
+        connection_pool: None,
+    }
+}
+
+async fn world() -> Option<(PgPool, TeamRef, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    Some((
+        pool,
+        TeamRef {
+            id: row.id,
+            org_id: org.id,
+        },
+        admin,
+    ))
+}
+
+#[tokio::test]
+async fn ext_authz_cluster_must_exist_in_the_team() {
+    let Some((pool, team, admin)) = world().await else {
+        return;
+    };
+    let rid = RequestId::generate();
+
+    let err = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        "grpc-authz",
+        listener(18090, "authz", None),
+        rid,
+        false,
+    )
+    .await
+    .expect_err("unknown authz cluster");
+    assert_eq!(err.code, ErrorCode::NotFound);
+    let err = gw::get_listener(&pool, &admin, team, "grpc-authz", rid)
+        .await
+        .expect_err("rejected listener must not exist");
+    assert_eq!(err.code, ErrorCode::NotFound);
+
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        team,
+        "authz",
+        cluster_spec(),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create authz cluster");
+
+    gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        "grpc-authz",
+        listener(18090, "authz", None),
+        rid,
+        false,
+    )
+    .await
+    .expect("gRPC mode with an existing cluster");
+    let created = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        "http-authz",
+        listener(
+            18091,
+            "authz",
+            Some(ExtAuthzHttpService {
+                uri: "http://authz.internal:9000/check".into(),
+                allowed_headers: vec!["x-user".into()],
+            }),
+        ),
+        rid,
+        false,
+    )
+    .await
+    .expect("HTTP mode with an existing cluster");
+    let HttpFilterSpec::ExtAuthz(cfg) = &created.spec.http_filters[0].filter else {
+        panic!("ext_authz filter");
+    };
+    assert_eq!(
+        cfg.http_service.as_ref().map(|h| h.path_prefix()),
+        Some("/check")
+    );
+}
+
+#[tokio::test]
+async fn otel_access_log_collector_must_exist_in_the_team() {
+    let Some((pool, team, admin)) = world().await else {
+        return;
+    };
+    let rid = RequestId::generate();
+    let mut spec = listener(18092, "unused", None);
+    spec.http_filters.clear();
+    spec.access_logs = vec![AccessLogConfig {
+        path: String::new(),
+        text_format: None,
+        json_format: None,
+        otel: Some(OtelAccessLogConfig {
+            cluster: "otel-collector".into(),
+            resource_attributes: [("service.name".to_string(), "edge".to_string())].into(),
+        }),
+    }];
+
+    let err = gw::create_listener(&pool, &admin, team, "otel-logs", spec.clone(), rid, false)
+        .await
+        .expect_err("unknown collector cluster");
+    assert_eq!(err.code, ErrorCode::NotFound);
+    assert!(err.message.contains("otel-collector"), "{}", err.message);
+
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        team,
+        "otel-collector",
+        cluster_spec(),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create collector cluster");
+    let created = gw::create_listener(&pool, &admin, team, "otel-logs", spec, rid, false)
+        .await
+        .expect("collector cluster exists");
+    assert_eq!(
+        created.spec.access_logs[0]
+            .otel
+            .as_ref()
+            .map(|otel| otel.cluster.as_str()),
+        Some("otel-collector")
+    );
+}
+
+#[tokio::test]
+async fn tracing_collector_must_exist_in_the_team() {
+    let Some((pool, team, admin)) = world().await else {
+        return;
+    };
+    let rid = RequestId::generate();
+    let mut spec = listener(18093, "unused", None);
+    spec.http_filters.clear();
+    spec.tracing = Some(ListenerTracingConfig {
+        provider: TracingProvider::Opentelemetry(OtelTracingProvider {
+            cluster: "trace-collector".into(),
+            service_name: "edge-gateway".into(),
+        }),
+        sampling_percent: Some(5.0),
+    });
+
+    let err = gw::create_listener(&pool, &admin, team, "traced", spec.clone(), rid, false)
+        .await
+        .expect_err("unknown collector cluster");
+    assert_eq!(err.code, ErrorCode::NotFound);
+    assert!(err.message.contains("trace-collector"), "{}", err.message);
+
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        team,
+        "trace-collector",
+        cluster_spec(),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create collector cluster");
+    let created = gw::create_listener(&pool, &admin, team, "traced", spec.clone(), rid, false)
+        .await
+        .expect("collector cluster exists");
+    assert_eq!(created.spec.tracing, spec.tracing);
+}
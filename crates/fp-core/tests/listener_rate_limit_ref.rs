@@ -60,9 +60,11 @@ fn cluster_spec(host: &str) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -97,6 +99,11 @@ fn listener_spec(filters: Vec<HttpFilterEntry>) -> ListenerSpec {
         http_filters: filters,
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     }
 }
 
@@ -226,10 +233,18 @@ async fn builtin_path_unconfigured_is_rejected_and_not_persisted() {
     assert_eq!(get_err.code, ErrorCode::NotFound);
 
     // And it must not show up in the team's listing.
-    let (listed, _total) =
-        gw::list_listeners(&w.pool, &w.admin, w.team, 100, 0, RequestId::generate())
-            .await
-            .expect("list");
+    let (listed, _total) = gw::list_listeners(
+        &w.pool,
+        &w.admin,
+        w.team,
+        None,
+        Default::default(),
+        100,
+        0,
+        RequestId::generate(),
+    )
+    .await
+    .expect("list");
     assert!(
         !listed.iter().any(|l| l.name == name),
         "rejected listener leaked into list_listeners"
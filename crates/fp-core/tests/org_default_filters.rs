@@ -0,0 +1,152 @@
+//! Org default listener filters: an org admin stores org-standard filters on the org, and a
+//! team's FIRST listener inherits them (later listeners are left as written). Kinds that
+//! name team-owned resources are rejected, and only org admins may change the set.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::gateway as gw;
+use fp_core::services::orgs as svc;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::filters::{
+    ExtAuthzConfig, HeaderMutationConfig, HttpFilterEntry, HttpFilterSpec,
+};
+use fp_domain::gateway::listener::{ListenerProtocol, ListenerSpec};
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::identity;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+fn listener(port: u16) -> ListenerSpec {
+    ListenerSpec {
+        address: "0.0.0.0".into(),
+        port,
+        public_base_url: None,
+        protocol: ListenerProtocol::Http,
+        route_config: None,
+        http_filters: Vec::new(),
+        access_logs: Vec::new(),
+        tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
+    }
+}
+
+fn sanitize_headers() -> HttpFilterEntry {
+    HttpFilterEntry {
+        filter: HttpFilterSpec::HeaderMutation(HeaderMutationConfig {
+            request_headers_to_add: Vec::new(),
+            request_headers_to_remove: vec!["x-internal-debug".into()],
+            response_headers_to_add: Vec::new(),
+            response_headers_to_remove: vec!["server".into()],
+        }),
+        disabled: false,
+    }
+}
+
+fn user(user_id: fp_domain::UserId, org: fp_domain::OrgId, role: OrgRole) -> PrincipalCtx {
+    PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org, role)),
+        grants: GrantSet::default(),
+    }
+}
+
+#[tokio::test]
+async fn new_teams_first_listener_gets_the_org_default_filter() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let admin_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("admin");
+    identity::add_org_membership(&pool, admin_id, org.id, OrgRole::Admin)
+        .await
+        .expect("admin membership");
+    let admin = user(admin_id, org.id, OrgRole::Admin);
+    let rid = RequestId::generate();
+
+    assert!(svc::get_default_listener_filters(&pool, &admin, org.id)
+        .await
+        .expect("read defaults")
+        .is_empty());
+    svc::set_default_listener_filters(&pool, &admin, org.id, vec![sanitize_headers()], rid)
+        .await
+        .expect("set defaults");
+
+    // The team is created after the defaults; its first listener inherits them.
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: row.id,
+        org_id: org.id,
+    };
+    let first = gw::create_listener(&pool, &admin, team, "edge", listener(18081), rid, false)
+        .await
+        .expect("first listener");
+    assert_eq!(first.spec.http_filters, vec![sanitize_headers()]);
+
+    let second = gw::create_listener(&pool, &admin, team, "internal", listener(18082), rid, false)
+        .await
+        .expect("second listener");
+    assert!(
+        second.spec.http_filters.is_empty(),
+        "only the first listener inherits org defaults"
+    );
+
+    // Defaults that name team resources cannot be org-wide.
+    let err = svc::set_default_listener_filters(
+        &pool,
+        &admin,
+        org.id,
+        vec![HttpFilterEntry {
+            filter: HttpFilterSpec::ExtAuthz(
+                serde_json::from_value::<ExtAuthzConfig>(serde_json::json!({"cluster": "authz"}))
+                    .expect("ext_authz config"),
+            ),
+            disabled: false,
+        }],
+        rid,
+    )
+    .await
+    .expect_err("ext_authz names a team cluster");
+    assert_eq!(err.code, ErrorCode::ValidationFailed);
+
+    // A plain member reads the defaults but cannot change them.
+    let member_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "m@t.test", "M")
+        .await
+        .expect("member");
+    identity::add_org_membership(&pool, member_id, org.id, OrgRole::Member)
+        .await
+        .expect("member membership");
+    let member = user(member_id, org.id, OrgRole::Member);
+    assert_eq!(
+        svc::get_default_listener_filters(&pool, &member, org.id)
+            .await
+            .expect("member reads"),
+        vec![sanitize_headers()]
+    );
+    let err = svc::set_default_listener_filters(&pool, &member, org.id, Vec::new(), rid)
+        .await
+        .expect_err("member cannot set");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+}
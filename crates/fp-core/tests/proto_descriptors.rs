@@ -0,0 +1,226 @@
+//! Proto descriptor uploads for the gRPC-JSON transcoder: an upload that does not decode as a
+//! `FileDescriptorSet` is rejected before anything is stored, and a valid one is referenceable
+//! by id from a listener's `grpc_json_transcoder` filter (same team only, declared services
+//! only, and pinned against deletion while referenced).
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::gateway as gw;
+use fp_core::services::proto_descriptors as svc;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::filters::{GrpcJsonTranscoderConfig, HttpFilterEntry, HttpFilterSpec};
+use fp_domain::gateway::listener::{ListenerProtocol, ListenerSpec};
+use fp_domain::{ErrorCode, OrgRole, ProtoDescriptorId, RequestId};
+use fp_storage::repos::identity;
+use prost::Message;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+/// A one-file descriptor set declaring `bookstore.Bookstore`.
+fn bookstore_descriptor_set() -> Vec<u8> {
+    use prost_types::{FileDescriptorProto, FileDescriptorSet, ServiceDescriptorProto};
+    FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            name: Some("bookstore.proto".into()),
+            package: Some("bookstore".into()),
+            service: vec![ServiceDescriptorProto {
+                name: Some("Bookstore".into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+    }
+    .encode_to_vec()
+}
+
+fn transcoder_listener(descriptor: ProtoDescriptorId, service: &str) -> ListenerSpec {
+    ListenerSpec {
+        address: "0.0.0.0".into(),
+        port: 20000 + (uuid::Uuid::now_v7().as_u128() as u32 % 40000) as u16,
+        public_base_url: None,
+        protocol: ListenerProtocol::Http,
+        route_config: None,
+        http_filters: vec![HttpFilterEntry {
+            filter: HttpFilterSpec::GrpcJsonTranscoder(GrpcJsonTranscoderConfig {
+                proto_descriptor_id: descriptor,
+                services: vec![service.into()],
+                ignore_unknown_query_parameters: false,
+                convert_grpc_status: true,
+            }),
+            disabled: false,
+        }],
+        access_logs: Vec::new(),
+        tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
+    }
+}
+
+async fn world() -> Option<(PgPool, TeamRef, TeamRef, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let mut teams = Vec::new();
+    for _ in 0..2 {
+        let row = identity::create_team(&pool, org.id, &unique("team"), "")
+            .await
+            .expect("team");
+        teams.push(TeamRef {
+            id: row.id,
+            org_id: org.id,
+        });
+    }
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    Some((pool, teams[0], teams[1], admin))
+}
+
+#[tokio::test]
+async fn invalid_descriptor_upload_is_rejected_and_nothing_is_stored() {
+    let Some((pool, team, _, admin)) = world().await else {
+        return;
+    };
+    let empty_set = prost_types::FileDescriptorSet::default().encode_to_vec();
+    for bytes in [
+        b"definitely not a protobuf \xff\xff\xff".to_vec(),
+        empty_set,
+    ] {
+        let name = unique("bad");
+        let err =
+            svc::create_proto_descriptor(&pool, &admin, team, &name, &bytes, RequestId::generate())
+                .await
+                .expect_err("invalid descriptor set must be rejected");
+        assert_eq!(err.code, ErrorCode::ValidationFailed, "{err:?}");
+        let lookup =
+            svc::get_proto_descriptor(&pool, &admin, team, &name, RequestId::generate()).await;
+        assert_eq!(lookup.expect_err("not stored").code, ErrorCode::NotFound);
+    }
+}
+
+#[tokio::test]
+async fn valid_descriptor_is_referenceable_from_a_transcoder_filter() {
+    let Some((pool, team, other_team, admin)) = world().await else {
+        return;
+    };
+    let name = unique("bookstore");
+    let descriptor = svc::create_proto_descriptor(
+        &pool,
+        &admin,
+        team,
+        &name,
+        &bookstore_descriptor_set(),
+        RequestId::generate(),
+    )
+    .await
+    .expect("valid descriptor set");
+    assert_eq!(descriptor.services, vec!["bookstore.Bookstore".to_string()]);
+
+    let listener_name = unique("grpc");
+    let listener = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        &listener_name,
+        transcoder_listener(descriptor.id, "bookstore.Bookstore"),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect("transcoder referencing the uploaded descriptor");
+    assert!(matches!(
+        &listener.spec.http_filters[0].filter,
+        HttpFilterSpec::GrpcJsonTranscoder(c) if c.proto_descriptor_id == descriptor.id
+    ));
+
+    // A service the set does not declare is a 400, not an Envoy NACK.
+    let err = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        &unique("grpc"),
+        transcoder_listener(descriptor.id, "bookstore.Library"),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect_err("undeclared service");
+    assert_eq!(err.code, ErrorCode::ValidationFailed, "{err:?}");
+
+    // Another team cannot reference it (404, no cross-tenant disclosure).
+    let err = gw::create_listener(
+        &pool,
+        &admin,
+        other_team,
+        &unique("grpc"),
+        transcoder_listener(descriptor.id, "bookstore.Bookstore"),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect_err("cross-team reference");
+    assert_eq!(err.code, ErrorCode::NotFound, "{err:?}");
+
+    // Referenced descriptors cannot be deleted out from under the listener.
+    let err = svc::delete_proto_descriptor(
+        &pool,
+        &admin,
+        team,
+        &name,
+        descriptor.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("referenced descriptor");
+    assert_eq!(err.code, ErrorCode::Conflict, "{err:?}");
+    assert!(err.message.contains(&listener_name), "{}", err.message);
+
+    gw::delete_listener(
+        &pool,
+        &admin,
+        team,
+        &listener_name,
+        listener.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("delete listener");
+    svc::delete_proto_descriptor(
+        &pool,
+        &admin,
+        team,
+        &name,
+        descriptor.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("unreferenced descriptor deletes");
+}
@@ -7,21 +7,27 @@ use fp_core::services::api_lifecycle::{self as api_svc, CreateApiInput};
 use fp_core::services::clusters as cluster_svc;
 use fp_core::services::dataplanes as dataplane_svc;
 use fp_core::services::learning::{self as learning_svc, StartLearningSessionInput};
+use fp_core::services::quota as quota_svc;
+use fp_core::services::retry_policies as retry_svc;
+use fp_core::services::runtime as runtime_svc;
 use fp_core::services::secrets::{self as secret_svc, SecretWrite};
+use fp_core::services::webhooks as webhook_svc;
 use fp_core::{GrantSet, PrincipalCtx};
 use fp_domain::api_lifecycle::{
     ApiDefinitionSpec, ApiToolSpec, CaptureSessionSpec, HttpMethod, SpecFormat, SpecSourceKind,
     SpecVersionInput,
 };
-use fp_domain::authz::TeamRef;
+use fp_domain::authz::{Resource, TeamRef};
 use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy};
+use fp_domain::gateway::route_config::RetryPolicy;
 use fp_domain::{
     AiProviderKind, AiProviderSpec, AiRouteBackend, AiRouteSpec, ErrorCode, OrgRole, RequestId,
-    SecretSpec,
+    SecretSpec, WebhookSubscriptionSpec,
 };
 use fp_storage::repos::{api_lifecycle as storage_api_lifecycle, identity};
 use serde_json::json;
 use sqlx::PgPool;
+use std::collections::BTreeMap;
 
 fn unique(prefix: &str) -> String {
     format!(
@@ -77,9 +83,11 @@ fn cluster_spec(host: &str) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -166,6 +174,10 @@ async fn learning_session_create_path_enforces_default_quota() {
                     max_duration_seconds: Some(60),
                     max_bytes: 4096,
                     max_distinct_paths: 10,
+                    sample_percent: None,
+                    max_body_bytes: None,
+                    redact_headers: Vec::new(),
+                    redact_body_fields: Vec::new(),
                 },
             },
             RequestId::generate(),
@@ -191,6 +203,10 @@ async fn learning_session_create_path_enforces_default_quota() {
                 max_duration_seconds: Some(60),
                 max_bytes: 4096,
                 max_distinct_paths: 10,
+                sample_percent: None,
+                max_body_bytes: None,
+                redact_headers: Vec::new(),
+                redact_body_fields: Vec::new(),
             },
         },
         RequestId::generate(),
@@ -229,6 +245,10 @@ async fn learned_spec_generation_persists_deterministic_candidate() {
                 max_duration_seconds: Some(60),
                 max_bytes: 4096,
                 max_distinct_paths: 20,
+                sample_percent: None,
+                max_body_bytes: None,
+                redact_headers: Vec::new(),
+                redact_body_fields: Vec::new(),
             },
         },
         RequestId::generate(),
@@ -652,3 +672,209 @@ async fn dataplane_create_path_enforces_default_quota() {
     .expect_err("201st dataplane must trip quota");
     assert_eq!(err.code, ErrorCode::QuotaExceeded);
 }
+
+#[tokio::test]
+async fn team_quota_override_is_enforced_and_admin_only() {
+    let Some(w) = world().await else { return };
+    let platform = PrincipalCtx::User {
+        user_id: identity::upsert_user_by_subject(
+            &w.pool,
+            &unique("sub"),
+            "root@example.test",
+            "R",
+        )
+        .await
+        .expect("platform user"),
+        platform_admin: true,
+        org_selector_required: false,
+        org: None,
+        grants: GrantSet::default(),
+    };
+    let limits = BTreeMap::from([("clusters".to_string(), 1)]);
+
+    let err = quota_svc::set_team_quotas(&w.pool, &w.admin, w.team, &limits, RequestId::generate())
+        .await
+        .expect_err("org admins cannot raise their own ceiling");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+
+    for bad in [
+        ("widgets", 1),
+        ("teams", 1),
+        ("clusters", -1),
+        ("clusters", 501),
+        ("route-configs", 501),
+        ("listeners", 501),
+    ] {
+        let limits = BTreeMap::from([(bad.0.to_string(), bad.1)]);
+        let err =
+            quota_svc::set_team_quotas(&w.pool, &platform, w.team, &limits, RequestId::generate())
+                .await
+                .expect_err("invalid override");
+        assert_eq!(err.code, ErrorCode::ValidationFailed, "{bad:?}");
+    }
+
+    let at_ceiling = BTreeMap::from([
+        ("clusters".to_string(), 500),
+        ("route-configs".to_string(), 500),
+        ("listeners".to_string(), 500),
+        ("secrets".to_string(), 501),
+    ]);
+    quota_svc::set_team_quotas(
+        &w.pool,
+        &platform,
+        w.team,
+        &at_ceiling,
+        RequestId::generate(),
+    )
+    .await
+    .expect("xDS kinds take overrides up to the snapshot read ceiling");
+
+    quota_svc::set_team_quotas(&w.pool, &platform, w.team, &limits, RequestId::generate())
+        .await
+        .expect("platform admin sets override");
+
+    cluster_svc::create_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &unique("only"),
+        cluster_spec("only.example"),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("within override");
+    let err = cluster_svc::create_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &unique("over"),
+        cluster_spec("over.example"),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("second cluster exceeds the override");
+    assert_eq!(err.code, ErrorCode::QuotaExceeded);
+    let details = err.details.expect("quota details");
+    assert_eq!(details["resource"], "clusters");
+    assert_eq!(details["limit"], 1);
+    assert_eq!(details["used"], 1);
+
+    let report = quota_svc::get_team_quotas(&w.pool, &w.admin, w.team)
+        .await
+        .expect("org member reads quotas");
+    let clusters = report
+        .iter()
+        .find(|q| q.resource == Resource::Clusters)
+        .expect("clusters row");
+    assert_eq!((clusters.limit, clusters.used), (1, 1));
+    assert!(clusters.default_limit > 1);
+    let listeners = report
+        .iter()
+        .find(|q| q.resource == Resource::Listeners)
+        .expect("listeners row");
+    assert_eq!(listeners.limit, listeners.default_limit);
+}
+
+#[tokio::test]
+async fn route_building_block_and_webhook_quotas_take_overrides() {
+    let Some(w) = world().await else { return };
+    let platform = PrincipalCtx::User {
+        user_id: identity::upsert_user_by_subject(
+            &w.pool,
+            &unique("sub"),
+            "root@example.test",
+            "R",
+        )
+        .await
+        .expect("platform user"),
+        platform_admin: true,
+        org_selector_required: false,
+        org: None,
+        grants: GrantSet::default(),
+    };
+    let limits = BTreeMap::from([
+        ("runtime-values".to_string(), 1),
+        ("retry-policies".to_string(), 0),
+        ("webhooks".to_string(), 0),
+    ]);
+    quota_svc::set_team_quotas(&w.pool, &platform, w.team, &limits, RequestId::generate())
+        .await
+        .expect("quota-only kinds and webhooks take overrides");
+
+    runtime_svc::set_runtime_value(
+        &w.pool,
+        &w.admin,
+        w.team,
+        "orders.canary",
+        json!(10),
+        RequestId::generate(),
+    )
+    .await
+    .expect("within override");
+    runtime_svc::set_runtime_value(
+        &w.pool,
+        &w.admin,
+        w.team,
+        "orders.canary",
+        json!(20),
+        RequestId::generate(),
+    )
+    .await
+    .expect("replacing a key does not count as a create");
+    let err = runtime_svc::set_runtime_value(
+        &w.pool,
+        &w.admin,
+        w.team,
+        "orders.shadow",
+        json!(true),
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("second key exceeds the override");
+    assert_eq!(err.code, ErrorCode::QuotaExceeded);
+    let details = err.details.expect("quota details");
+    assert_eq!(details["resource"], "runtime-values");
+    assert_eq!(details["used"], 1);
+
+    let err = retry_svc::create_retry_policy(
+        &w.pool,
+        &w.admin,
+        w.team,
+        "standard",
+        RetryPolicy {
+            retry_on: "5xx".into(),
+            num_retries: Some(2),
+            per_try_timeout_secs: Some(3),
+            retriable_status_codes: Vec::new(),
+            previous_priorities_retry: false,
+        },
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("retry policies are capped at zero");
+    assert_eq!(err.code, ErrorCode::QuotaExceeded);
+    assert_eq!(
+        err.details.expect("quota details")["resource"],
+        "retry-policies"
+    );
+
+    let err = webhook_svc::create_subscription(
+        &w.pool,
+        &w.admin,
+        w.team,
+        "deploys",
+        WebhookSubscriptionSpec {
+            url: "http://127.0.0.1:9/hook".into(),
+            events: vec!["cluster.*".into()],
+            secret_id: None,
+        },
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect_err("webhooks are capped at zero");
+    assert_eq!(err.code, ErrorCode::QuotaExceeded);
+    assert_eq!(err.details.expect("quota details")["resource"], "webhooks");
+}
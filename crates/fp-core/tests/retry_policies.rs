@@ -0,0 +1,300 @@
+//! Named retry policies: a route forward action referencing one by `retry_policy_ref` must
+//! name a policy in its own team whose per-try timeout fits the route, a policy update must
+//! keep fitting every referencing route, and a referenced policy cannot be deleted.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::clusters as cluster_svc;
+use fp_core::services::gateway as gw;
+use fp_core::services::retry_policies as svc;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy};
+use fp_domain::gateway::route_config::{
+    PathMatch, RetryPolicy, RouteAction, RouteConfigSpec, RouteRule, VirtualHost,
+};
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::identity;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+fn retry(per_try_timeout_secs: u32) -> RetryPolicy {
+    RetryPolicy {
+        retry_on: "5xx,connect-failure".into(),
+        num_retries: Some(2),
+        per_try_timeout_secs: Some(per_try_timeout_secs),
+        retriable_status_codes: Vec::new(),
+        previous_priorities_retry: false,
+    }
+}
+
+fn routes(cluster: &str, policy: &str, timeout_secs: u32) -> RouteConfigSpec {
+    RouteConfigSpec {
+        virtual_hosts: vec![VirtualHost {
+            name: "default".into(),
+            domains: vec!["*".into()],
+            routes: vec![RouteRule {
+                name: "orders".into(),
+                matcher: PathMatch::Prefix {
+                    prefix: "/orders".into(),
+                },
+                headers: Vec::new(),
+                methods: Vec::new(),
+                query_parameters: Vec::new(),
+                runtime_fraction: None,
+                action: RouteAction {
+                    cluster: Some(cluster.into()),
+                    weighted_clusters: None,
+                    redirect: None,
+                    direct_response: None,
+                    prefix_rewrite: None,
+                    template_rewrite: None,
+                    timeout_secs,
+                    retry_policy: None,
+                    retry_policy_ref: Some(policy.into()),
+                    idle_timeout_secs: None,
+                    max_stream_duration_secs: None,
+                    regex_rewrite: None,
+                    host_rewrite_literal: None,
+                    auto_host_rewrite: false,
+                    fallback_cluster: None,
+                    request_mirror_policies: Vec::new(),
+                    request_headers_to_remove: Vec::new(),
+                    hash_policy: Vec::new(),
+                    rate_limits: Vec::new(),
+                },
+                filter_overrides: Vec::new(),
+            }],
+            rate_limits: Vec::new(),
+            filter_overrides: Vec::new(),
+        }],
+    }
+}
+
+/// Two teams in one org, an org admin, and a cluster named `backend` in the first team.
+async fn world() -> Option<(PgPool, TeamRef, TeamRef, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let mut teams = Vec::new();
+    for _ in 0..2 {
+        let row = identity::create_team(&pool, org.id, &unique("team"), "")
+            .await
+            .expect("team");
+        teams.push(TeamRef {
+            id: row.id,
+            org_id: org.id,
+        });
+    }
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        teams[0],
+        "backend",
+        ClusterSpec {
+            aggregate_clusters: Vec::new(),
+            endpoints: vec![Endpoint {
+                host: "orders.internal".into(),
+                port: 8080,
+                weight: None,
+            }],
+            lb_policy: LbPolicy::RoundRobin,
+            least_request: None,
+            ring_hash: None,
+            maglev: None,
+            dns_lookup_family: None,
+            connect_timeout_secs: 5,
+            use_tls: false,
+            upstream_tls: None,
+            protocol: None,
+            use_downstream_protocol: false,
+            health_checks: None,
+            circuit_breakers: None,
+            outlier_detection: None,
+            connection_pool: None,
+        },
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("cluster");
+    Some((pool, teams[0], teams[1], admin))
+}
+
+#[tokio::test]
+async fn route_references_resolve_within_the_team_and_respect_the_route_timeout() {
+    let Some((pool, team, other_team, admin)) = world().await else {
+        return;
+    };
+    svc::create_retry_policy(
+        &pool,
+        &admin,
+        team,
+        "standard",
+        retry(3),
+        RequestId::generate(),
+    )
+    .await
+    .expect("create policy");
+    svc::create_retry_policy(
+        &pool,
+        &admin,
+        other_team,
+        "elsewhere",
+        retry(3),
+        RequestId::generate(),
+    )
+    .await
+    .expect("create other team's policy");
+
+    let rc_name = unique("rc");
+    gw::create_route_config(
+        &pool,
+        &admin,
+        team,
+        &rc_name,
+        routes("backend", "standard", 10),
+        RequestId::generate(),
+    )
+    .await
+    .expect("route referencing a team policy");
+
+    for (policy, timeout, code) in [
+        ("missing", 10, ErrorCode::NotFound),
+        ("elsewhere", 10, ErrorCode::NotFound),
+        ("standard", 2, ErrorCode::ValidationFailed),
+    ] {
+        let err = gw::create_route_config(
+            &pool,
+            &admin,
+            team,
+            &unique("rc"),
+            routes("backend", policy, timeout),
+            RequestId::generate(),
+        )
+        .await
+        .expect_err("invalid reference must be rejected");
+        assert_eq!(err.code, code, "{policy}/{timeout}: {err:?}");
+    }
+
+    // Widening the per-try timeout past the referencing route's timeout is refused.
+    let err = svc::update_retry_policy(
+        &pool,
+        &admin,
+        team,
+        "standard",
+        retry(30),
+        1,
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("per-try timeout must fit referencing routes");
+    assert_eq!(err.code, ErrorCode::ValidationFailed, "{err:?}");
+    assert!(err.message.contains(&rc_name), "{err:?}");
+    let updated = svc::update_retry_policy(
+        &pool,
+        &admin,
+        team,
+        "standard",
+        retry(5),
+        1,
+        RequestId::generate(),
+    )
+    .await
+    .expect("update within the route timeout");
+    assert_eq!(updated.version, 2);
+    assert_eq!(updated.spec.per_try_timeout_secs, Some(5));
+}
+
+#[tokio::test]
+async fn referenced_policy_cannot_be_deleted_until_the_route_drops_it() {
+    let Some((pool, team, _, admin)) = world().await else {
+        return;
+    };
+    let policy = svc::create_retry_policy(
+        &pool,
+        &admin,
+        team,
+        "pinned",
+        retry(3),
+        RequestId::generate(),
+    )
+    .await
+    .expect("create policy");
+    let rc_name = unique("rc");
+    let rc = gw::create_route_config(
+        &pool,
+        &admin,
+        team,
+        &rc_name,
+        routes("backend", "pinned", 10),
+        RequestId::generate(),
+    )
+    .await
+    .expect("route config");
+
+    let err = svc::delete_retry_policy(
+        &pool,
+        &admin,
+        team,
+        "pinned",
+        policy.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("referenced policy must not be deleted");
+    assert_eq!(err.code, ErrorCode::Conflict, "{err:?}");
+    assert!(err.message.contains(&rc_name), "{err:?}");
+
+    gw::delete_route_config(
+        &pool,
+        &admin,
+        team,
+        &rc_name,
+        rc.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("delete route config");
+    svc::delete_retry_policy(
+        &pool,
+        &admin,
+        team,
+        "pinned",
+        policy.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("unreferenced policy deletes");
+    let err = svc::get_retry_policy(&pool, &admin, team, "pinned", RequestId::generate())
+        .await
+        .expect_err("deleted");
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
@@ -7,7 +7,7 @@ use fp_domain::api_lifecycle::{
 };
 use fp_domain::authz::TeamRef;
 use fp_domain::gateway::listener::{ListenerProtocol, ListenerSpec};
-use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_domain::{ErrorCode, OrgRole, RequestId, ResourceSource, SyncAction};
 use fp_storage::repos::{api_lifecycle, identity};
 use sqlx::PgPool;
 
@@ -70,6 +70,8 @@ async fn route_plan_apply_replays_persisted_preview() {
         route_generation::CreateRoutePlanInput {
             spec_version_id: spec_id,
             listener_port: 19190,
+            coalesce_methods: false,
+            sync: false,
         },
         RequestId::generate(),
     )
@@ -94,6 +96,39 @@ async fn route_plan_apply_replays_persisted_preview() {
     assert_eq!(applied.plan.status.as_str(), "applied");
 }
 
+#[tokio::test]
+async fn route_plan_can_coalesce_operations_by_method() {
+    let Some(w) = world().await else { return };
+    let spec_id = reviewed_spec(&w, &unique("learned-api")).await;
+    let plan_for = |coalesce_methods, listener_port| {
+        route_generation::create_plan(
+            &w.pool,
+            &w.admin,
+            w.team,
+            route_generation::CreateRoutePlanInput {
+                spec_version_id: spec_id,
+                listener_port,
+                coalesce_methods,
+                sync: false,
+            },
+            RequestId::generate(),
+        )
+    };
+
+    let split = plan_for(false, 19196).await.expect("per-operation plan");
+    let routes = &split.plan.route_config_spec.virtual_hosts[0].routes;
+    let names: Vec<_> = routes.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["getitem", "updateitem"]);
+    assert_eq!(routes[0].methods, ["GET"]);
+
+    let merged = plan_for(true, 19197).await.expect("coalesced plan");
+    let routes = &merged.plan.route_config_spec.virtual_hosts[0].routes;
+    assert_eq!(routes.len(), 1, "GET and POST share one rule");
+    assert_eq!(routes[0].name, "v1-items-id");
+    assert_eq!(routes[0].methods, ["GET", "POST"]);
+    assert!(routes[0].headers.is_empty());
+}
+
 #[tokio::test]
 async fn route_plan_apply_fails_on_intervening_conflict() {
     let Some(w) = world().await else { return };
@@ -106,6 +141,8 @@ async fn route_plan_apply_fails_on_intervening_conflict() {
         route_generation::CreateRoutePlanInput {
             spec_version_id: spec_id,
             listener_port: 19191,
+            coalesce_methods: false,
+            sync: false,
         },
         RequestId::generate(),
     )
@@ -126,6 +163,11 @@ async fn route_plan_apply_fails_on_intervening_conflict() {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         },
         RequestId::generate(),
         false,
@@ -172,6 +214,8 @@ async fn route_plan_create_rejects_unreviewed_spec() {
         route_generation::CreateRoutePlanInput {
             spec_version_id: spec_id,
             listener_port: 19193,
+            coalesce_methods: false,
+            sync: false,
         },
         RequestId::generate(),
     )
@@ -198,6 +242,8 @@ async fn route_plan_create_rejects_rejected_spec() {
         route_generation::CreateRoutePlanInput {
             spec_version_id: spec_id,
             listener_port: 19194,
+            coalesce_methods: false,
+            sync: false,
         },
         RequestId::generate(),
     )
@@ -219,6 +265,8 @@ async fn route_plan_apply_rechecks_review_state() {
         route_generation::CreateRoutePlanInput {
             spec_version_id: spec_id,
             listener_port: 19195,
+            coalesce_methods: false,
+            sync: false,
         },
         RequestId::generate(),
     )
@@ -254,6 +302,364 @@ async fn route_plan_apply_rechecks_review_state() {
     );
 }
 
+#[tokio::test]
+async fn route_plan_sync_updates_the_previous_plan_in_place() {
+    let Some(w) = world().await else { return };
+    let plan_for = |spec_version_id, sync| {
+        route_generation::create_plan(
+            &w.pool,
+            &w.admin,
+            w.team,
+            route_generation::CreateRoutePlanInput {
+                spec_version_id,
+                listener_port: 19198,
+                coalesce_methods: false,
+                sync,
+            },
+            RequestId::generate(),
+        )
+    };
+    let apply = |plan_id| {
+        route_generation::apply_plan(
+            &w.pool,
+            &w.admin,
+            w.team,
+            plan_id,
+            RequestId::generate(),
+            Default::default(),
+        )
+    };
+
+    let first_spec = reviewed_spec(&w, &unique("learned-api")).await;
+    let err = plan_for(first_spec, true)
+        .await
+        .expect_err("nothing applied yet");
+    assert_eq!(err.code, ErrorCode::Conflict);
+    let first = apply(plan_for(first_spec, false).await.expect("plan").id)
+        .await
+        .expect("apply first");
+
+    // The next version drops updateItem and adds listOrders.
+    let next_spec = next_reviewed_version(
+        &w,
+        first_spec,
+        serde_json::json!({
+            "/v1/items/{id}": {
+                "get": {"operationId": "getItem", "responses": {"200": {"description": "ok"}}}
+            },
+            "/v1/orders": {
+                "get": {"operationId": "listOrders", "responses": {"200": {"description": "ok"}}}
+            }
+        }),
+    )
+    .await;
+    let plain = plan_for(next_spec, false).await.expect("plain plan");
+    assert_eq!(plain.plan.conflicts.len(), 4, "{:?}", plain.plan.conflicts);
+
+    let synced = plan_for(next_spec, true).await.expect("sync plan");
+    let stale = plan_for(next_spec, true).await.expect("second sync plan");
+    assert!(
+        synced.plan.conflicts.is_empty(),
+        "{:?}",
+        synced.plan.conflicts
+    );
+    let vhost = &first.route_config.spec.virtual_hosts[0].name;
+    let sync = synced.plan.sync.clone().expect("sync diff");
+    assert_eq!(sync.previous_plan_id, first.plan.id);
+    assert_eq!(sync.cluster, SyncAction::Unchanged);
+    assert_eq!(sync.route_config, SyncAction::Update);
+    assert_eq!(sync.listener, SyncAction::Unchanged);
+    assert_eq!(sync.routes_added, [format!("{vhost}/listorders")]);
+    assert!(sync.routes_updated.is_empty());
+    assert_eq!(sync.routes_removed, [format!("{vhost}/updateitem")]);
+
+    let applied = apply(synced.id).await.expect("apply sync");
+    let routes: Vec<_> = applied.route_config.spec.virtual_hosts[0]
+        .routes
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect();
+    assert_eq!(routes, ["getitem", "listorders"]);
+    assert_eq!(applied.route_config.version, first.route_config.version + 1);
+    assert_eq!(applied.cluster.version, first.cluster.version);
+    assert_eq!(applied.listener.version, first.listener.version);
+
+    let err = apply(stale.id).await.expect_err("superseded sync plan");
+    assert_eq!(err.code, ErrorCode::Conflict);
+}
+
+#[tokio::test]
+async fn route_plan_resources_list_what_an_applied_plan_produced() {
+    let Some(w) = world().await else { return };
+    let spec_id = reviewed_spec(&w, &unique("learned-api")).await;
+    let plan = route_generation::create_plan(
+        &w.pool,
+        &w.admin,
+        w.team,
+        route_generation::CreateRoutePlanInput {
+            spec_version_id: spec_id,
+            listener_port: 19199,
+            coalesce_methods: false,
+            sync: false,
+        },
+        RequestId::generate(),
+    )
+    .await
+    .expect("plan");
+    let manifest = |plan_id| {
+        route_generation::plan_resources(&w.pool, &w.admin, w.team, plan_id, RequestId::generate())
+    };
+    let err = manifest(plan.id).await.expect_err("dry run");
+    assert_eq!(err.code, ErrorCode::Conflict);
+
+    let applied = route_generation::apply_plan(
+        &w.pool,
+        &w.admin,
+        w.team,
+        plan.id,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("apply");
+    fp_core::services::gateway::delete_listener(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &applied.listener.name,
+        applied.listener.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("delete listener");
+
+    let (_, resources) = manifest(plan.id).await.expect("manifest");
+    let got: Vec<_> = resources
+        .iter()
+        .map(|r| (r.kind.as_str(), r.name.as_str(), r.version))
+        .collect();
+    assert_eq!(
+        got,
+        [
+            (
+                "cluster",
+                applied.cluster.name.as_str(),
+                Some(applied.cluster.version)
+            ),
+            (
+                "route_config",
+                applied.route_config.name.as_str(),
+                Some(applied.route_config.version)
+            ),
+            ("listener", applied.listener.name.as_str(), None),
+        ]
+    );
+
+    let (plans, total) =
+        route_generation::list_plans(&w.pool, &w.admin, w.team, 50, 0, RequestId::generate())
+            .await
+            .expect("list");
+    assert_eq!(total, 1);
+    assert_eq!(plans[0].id, plan.id);
+}
+
+#[tokio::test]
+async fn gateway_lists_filter_by_whether_a_route_plan_produced_the_resource() {
+    let Some(w) = world().await else { return };
+    let spec_id = reviewed_spec(&w, &unique("learned-api")).await;
+    let plan = route_generation::create_plan(
+        &w.pool,
+        &w.admin,
+        w.team,
+        route_generation::CreateRoutePlanInput {
+            spec_version_id: spec_id,
+            listener_port: 19203,
+            coalesce_methods: false,
+            sync: false,
+        },
+        RequestId::generate(),
+    )
+    .await
+    .expect("plan");
+    let applied = route_generation::apply_plan(
+        &w.pool,
+        &w.admin,
+        w.team,
+        plan.id,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("apply");
+    let hand_written = unique("hand-written");
+    fp_core::services::clusters::create_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &hand_written,
+        applied.cluster.spec.clone(),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("hand-written cluster");
+
+    let clusters = |source| {
+        fp_core::services::clusters::list_clusters(
+            &w.pool,
+            &w.admin,
+            w.team,
+            source,
+            Default::default(),
+            50,
+            0,
+            RequestId::generate(),
+        )
+    };
+    let names = |page: (Vec<fp_domain::gateway::Cluster>, i64)| {
+        (
+            page.0.into_iter().map(|c| c.name).collect::<Vec<_>>(),
+            page.1,
+        )
+    };
+    let (all, total) = names(clusters(None).await.expect("all"));
+    assert_eq!(total, 2, "{all:?}");
+    assert_eq!(
+        names(
+            clusters(Some(ResourceSource::OpenapiImport))
+                .await
+                .expect("imported")
+        ),
+        (vec![applied.cluster.name.clone()], 1)
+    );
+    assert_eq!(
+        names(
+            clusters(Some(ResourceSource::NativeApi))
+                .await
+                .expect("native")
+        ),
+        (vec![hand_written], 1)
+    );
+
+    let (listeners, total) = fp_core::services::gateway::list_listeners(
+        &w.pool,
+        &w.admin,
+        w.team,
+        Some(ResourceSource::NativeApi),
+        Default::default(),
+        50,
+        0,
+        RequestId::generate(),
+    )
+    .await
+    .expect("native listeners");
+    assert_eq!((listeners.len(), total), (0, 0));
+    let (route_configs, _) = fp_core::services::gateway::list_route_configs(
+        &w.pool,
+        &w.admin,
+        w.team,
+        Some(ResourceSource::OpenapiImport),
+        Default::default(),
+        50,
+        0,
+        RequestId::generate(),
+    )
+    .await
+    .expect("imported route configs");
+    assert_eq!(route_configs[0].name, applied.route_config.name);
+}
+
+#[tokio::test]
+async fn route_plan_teardown_deletes_dependents_first_and_guards_outside_users() {
+    let Some(w) = world().await else { return };
+    let spec_id = reviewed_spec(&w, &unique("teardown-api")).await;
+    let plan = route_generation::create_plan(
+        &w.pool,
+        &w.admin,
+        w.team,
+        route_generation::CreateRoutePlanInput {
+            spec_version_id: spec_id,
+            listener_port: 19201,
+            coalesce_methods: false,
+            sync: false,
+        },
+        RequestId::generate(),
+    )
+    .await
+    .expect("plan");
+    let applied = route_generation::apply_plan(
+        &w.pool,
+        &w.admin,
+        w.team,
+        plan.id,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("apply");
+    let teardown = |dry_run| {
+        route_generation::teardown_plan(
+            &w.pool,
+            &w.admin,
+            w.team,
+            plan.id,
+            dry_run,
+            RequestId::generate(),
+        )
+    };
+
+    // A listener outside the plan on the plan's route config blocks even the dry run.
+    let mut outside_spec = applied.listener.spec.clone();
+    outside_spec.port = 19202;
+    let outside = fp_core::services::gateway::create_listener(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &unique("outside"),
+        outside_spec,
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect("outside listener");
+    let err = teardown(true).await.expect_err("outside listener blocks");
+    assert_eq!(err.code, ErrorCode::Conflict);
+    assert!(err.message.contains(&outside.name), "{}", err.message);
+    fp_core::services::gateway::delete_listener(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &outside.name,
+        outside.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("delete outside listener");
+
+    let (_, planned) = teardown(true).await.expect("dry run");
+    let order: Vec<_> = planned.iter().map(|r| r.kind.as_str()).collect();
+    assert_eq!(order, ["listener", "route_config", "cluster"]);
+    fp_core::services::clusters::get_cluster(
+        &w.pool,
+        &w.admin,
+        w.team,
+        &applied.cluster.name,
+        RequestId::generate(),
+    )
+    .await
+    .expect("dry run deletes nothing");
+
+    let (_, deleted) = teardown(false).await.expect("teardown");
+    assert_eq!(deleted.len(), 3);
+    let (_, resources) =
+        route_generation::plan_resources(&w.pool, &w.admin, w.team, plan.id, RequestId::generate())
+            .await
+            .expect("manifest");
+    assert!(resources.iter().all(|r| r.version.is_none()));
+    let (_, again) = teardown(false).await.expect("second teardown is a no-op");
+    assert!(again.is_empty());
+}
+
 async fn reviewed_spec(w: &World, api_name: &str) -> fp_domain::SpecVersionId {
     learned_spec(w, api_name, Some(SpecReviewDecision::Reviewed)).await
 }
@@ -282,21 +688,15 @@ async fn learned_spec(
         &SpecVersionInput {
             source_kind: SpecSourceKind::Learned,
             format: SpecFormat::OpenApi3,
-            spec: serde_json::json!({
-                "openapi": "3.1.0",
-                "info": {"title": api_name, "version": "1.0.0"},
-                "x-flowplane-learning-source": {
-                    "observed_host": "api.example.test",
-                    "forwarded_upstream_host": "upstream.example.test",
-                    "forwarded_upstream_port": 443,
-                    "forwarded_upstream_tls": true
-                },
-                "paths": {
+            spec: learned_document(
+                api_name,
+                serde_json::json!({
                     "/v1/items/{id}": {
-                        "get": {"operationId": "getItem", "responses": {"200": {"description": "ok"}}}
+                        "get": {"operationId": "getItem", "responses": {"200": {"description": "ok"}}},
+                        "post": {"operationId": "updateItem", "responses": {"200": {"description": "ok"}}}
                     }
-                }
-            }),
+                }),
+            ),
         },
     )
     .await
@@ -322,6 +722,48 @@ async fn learned_spec(
     spec.id
 }
 
+fn learned_document(api_name: &str, paths: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "openapi": "3.1.0",
+        "info": {"title": api_name, "version": "1.0.0"},
+        "x-flowplane-learning-source": {
+            "observed_host": "api.example.test",
+            "forwarded_upstream_host": "upstream.example.test",
+            "forwarded_upstream_port": 443,
+            "forwarded_upstream_tls": true
+        },
+        "paths": paths
+    })
+}
+
+/// A further reviewed learned version of the API that `spec_id` belongs to.
+async fn next_reviewed_version(
+    w: &World,
+    spec_id: fp_domain::SpecVersionId,
+    paths: serde_json::Value,
+) -> fp_domain::SpecVersionId {
+    let mut tx = w.pool.begin().await.expect("tx");
+    let previous = api_lifecycle::get_spec_version_by_id(&mut tx, w.team.id, spec_id)
+        .await
+        .expect("spec");
+    let api_name = previous.spec["info"]["title"].as_str().expect("title");
+    let spec = api_lifecycle::create_spec_version(
+        &mut tx,
+        w.team,
+        previous.api_definition_id,
+        &SpecVersionInput {
+            source_kind: SpecSourceKind::Learned,
+            format: SpecFormat::OpenApi3,
+            spec: learned_document(api_name, paths),
+        },
+    )
+    .await
+    .expect("next spec");
+    tx.commit().await.expect("commit");
+    append_decision(w, spec.id, SpecReviewDecision::Reviewed).await;
+    spec.id
+}
+
 async fn append_decision(
     w: &World,
     spec_id: fp_domain::SpecVersionId,
@@ -0,0 +1,123 @@
+//! Runtime values: a write creates or replaces one team's key, values are scalars under a
+//! dotted key, and a delete of a key that was never set is a 404.
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::runtime as svc;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::identity;
+use serde_json::json;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+/// Two teams in one org and an org admin.
+async fn world() -> Option<(PgPool, TeamRef, TeamRef, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let mut teams = Vec::new();
+    for _ in 0..2 {
+        let row = identity::create_team(&pool, org.id, &unique("team"), "")
+            .await
+            .expect("team");
+        teams.push(TeamRef {
+            id: row.id,
+            org_id: org.id,
+        });
+    }
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    Some((pool, teams[0], teams[1], admin))
+}
+
+#[tokio::test]
+async fn set_replaces_per_team_and_rejects_bad_keys_and_values() {
+    let Some((pool, team, other_team, admin)) = world().await else {
+        return;
+    };
+    let key = "routes.checkout.canary";
+    let created =
+        svc::set_runtime_value(&pool, &admin, team, key, json!(10), RequestId::generate())
+            .await
+            .expect("create");
+    assert_eq!(created.version, 1);
+    let replaced =
+        svc::set_runtime_value(&pool, &admin, team, key, json!(25), RequestId::generate())
+            .await
+            .expect("replace");
+    assert_eq!(replaced.version, 2);
+    assert_eq!(replaced.value, json!(25));
+    assert_eq!(replaced.created_at, created.created_at);
+
+    // The same key in another team is a separate value.
+    svc::set_runtime_value(
+        &pool,
+        &admin,
+        other_team,
+        key,
+        json!(true),
+        RequestId::generate(),
+    )
+    .await
+    .expect("other team");
+    let (items, total) =
+        svc::list_runtime_values(&pool, &admin, team, 50, 0, RequestId::generate())
+            .await
+            .expect("list");
+    assert_eq!(total, 1);
+    assert_eq!(items[0].value, json!(25));
+
+    for (key, value) in [
+        ("has space", json!(1)),
+        (".leading", json!(1)),
+        ("routes.split", json!({"numerator": 1})),
+        ("routes.split", json!(null)),
+    ] {
+        let err = svc::set_runtime_value(&pool, &admin, team, key, value, RequestId::generate())
+            .await
+            .expect_err("rejected");
+        assert_eq!(err.code, ErrorCode::ValidationFailed, "{key}");
+    }
+
+    svc::delete_runtime_value(&pool, &admin, team, key, RequestId::generate())
+        .await
+        .expect("delete");
+    for result in [
+        svc::delete_runtime_value(&pool, &admin, team, key, RequestId::generate()).await,
+        svc::get_runtime_value(&pool, &admin, team, key, RequestId::generate())
+            .await
+            .map(|_| ()),
+    ] {
+        assert_eq!(result.expect_err("gone").code, ErrorCode::NotFound);
+    }
+    svc::get_runtime_value(&pool, &admin, other_team, key, RequestId::generate())
+        .await
+        .expect("other team's value is untouched");
+}
@@ -0,0 +1,236 @@
+//! Wasm module registry: an upload that is not a WebAssembly binary is rejected before
+//! anything is stored, uploads under one name become successive versions, and a version is
+//! referenceable by name from a listener's `wasm` filter (same team only, and pinned against
+//! deletion while referenced).
+//!
+//! DB-backed; each test self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::gateway as gw;
+use fp_core::services::wasm_modules as svc;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::filters::{
+    HttpFilterEntry, HttpFilterSpec, WasmConfig, WasmModuleSource, WasmRuntime,
+};
+use fp_domain::gateway::listener::{ListenerProtocol, ListenerSpec};
+use fp_domain::{ErrorCode, OrgRole, RequestId};
+use fp_storage::repos::identity;
+use sqlx::PgPool;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+/// The smallest valid module: magic plus version 1, no sections.
+const EMPTY_MODULE: &[u8] = b"\0asm\x01\0\0\0";
+
+fn wasm_listener(name: &str, version: i64) -> ListenerSpec {
+    ListenerSpec {
+        address: "0.0.0.0".into(),
+        port: 20000 + (uuid::Uuid::now_v7().as_u128() as u32 % 40000) as u16,
+        public_base_url: None,
+        protocol: ListenerProtocol::Http,
+        route_config: None,
+        http_filters: vec![HttpFilterEntry {
+            filter: HttpFilterSpec::Wasm(WasmConfig {
+                module: WasmModuleSource::Registry {
+                    name: name.into(),
+                    version,
+                },
+                runtime: WasmRuntime::V8,
+                config: None,
+                root_id: None,
+                fail_open: false,
+            }),
+            disabled: false,
+        }],
+        access_logs: Vec::new(),
+        tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
+    }
+}
+
+async fn world() -> Option<(PgPool, TeamRef, TeamRef, PrincipalCtx)> {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return None;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let mut teams = Vec::new();
+    for _ in 0..2 {
+        let row = identity::create_team(&pool, org.id, &unique("team"), "")
+            .await
+            .expect("team");
+        teams.push(TeamRef {
+            id: row.id,
+            org_id: org.id,
+        });
+    }
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    Some((pool, teams[0], teams[1], admin))
+}
+
+#[test]
+fn module_check_requires_the_wasm_header_and_hashes_the_bytes() {
+    assert_eq!(
+        svc::check_module(EMPTY_MODULE).expect("empty module"),
+        "93a44bbb96c751218e4c00d479e4c14358122a389acca16205b1e4d0dc5f9476"
+    );
+    let err = svc::check_module(b"(module)").expect_err("text format");
+    assert_eq!(err.code, ErrorCode::ValidationFailed, "{err:?}");
+}
+
+#[tokio::test]
+async fn invalid_module_upload_is_rejected_and_nothing_is_stored() {
+    let Some((pool, team, _, admin)) = world().await else {
+        return;
+    };
+    let name = unique("bad");
+    let err = svc::create_wasm_module(
+        &pool,
+        &admin,
+        team,
+        &name,
+        b"(module)",
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("text-format module must be rejected");
+    assert_eq!(err.code, ErrorCode::ValidationFailed, "{err:?}");
+    let (versions, total) = svc::list_wasm_modules(
+        &pool,
+        &admin,
+        team,
+        Some(&name),
+        50,
+        0,
+        RequestId::generate(),
+    )
+    .await
+    .expect("list");
+    assert!(versions.is_empty());
+    assert_eq!(total, 0);
+}
+
+#[tokio::test]
+async fn uploads_are_versioned_and_referenceable_from_a_wasm_filter() {
+    let Some((pool, team, other_team, admin)) = world().await else {
+        return;
+    };
+    let name = unique("authz");
+    let mut uploaded = Vec::new();
+    for _ in 0..2 {
+        uploaded.push(
+            svc::create_wasm_module(
+                &pool,
+                &admin,
+                team,
+                &name,
+                EMPTY_MODULE,
+                RequestId::generate(),
+            )
+            .await
+            .expect("valid module"),
+        );
+    }
+    assert_eq!(
+        uploaded.iter().map(|m| m.version).collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(uploaded[1].sha256, svc::check_module(EMPTY_MODULE).unwrap());
+    assert_eq!(uploaded[1].size_bytes, EMPTY_MODULE.len() as i64);
+
+    let listener_name = unique("wasm");
+    let listener = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        &listener_name,
+        wasm_listener(&name, 2),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect("wasm filter referencing an uploaded version");
+
+    // A version that was never uploaded is a 404 at write time, not an Envoy NACK.
+    let err = gw::create_listener(
+        &pool,
+        &admin,
+        team,
+        &unique("wasm"),
+        wasm_listener(&name, 3),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect_err("missing version");
+    assert_eq!(err.code, ErrorCode::NotFound, "{err:?}");
+
+    // Another team cannot reference it (404, no cross-tenant disclosure).
+    let err = gw::create_listener(
+        &pool,
+        &admin,
+        other_team,
+        &unique("wasm"),
+        wasm_listener(&name, 2),
+        RequestId::generate(),
+        false,
+    )
+    .await
+    .expect_err("cross-team reference");
+    assert_eq!(err.code, ErrorCode::NotFound, "{err:?}");
+
+    // The referenced version is pinned; the unreferenced one is not.
+    let err = svc::delete_wasm_module(&pool, &admin, team, &name, 2, RequestId::generate())
+        .await
+        .expect_err("referenced version");
+    assert_eq!(err.code, ErrorCode::Conflict, "{err:?}");
+    assert!(err.message.contains(&listener_name), "{}", err.message);
+    svc::delete_wasm_module(&pool, &admin, team, &name, 1, RequestId::generate())
+        .await
+        .expect("unreferenced version deletes");
+
+    gw::delete_listener(
+        &pool,
+        &admin,
+        team,
+        &listener_name,
+        listener.version,
+        RequestId::generate(),
+    )
+    .await
+    .expect("delete listener");
+    svc::delete_wasm_module(&pool, &admin, team, &name, 2, RequestId::generate())
+        .await
+        .expect("version deletes once unreferenced");
+    let err = svc::get_wasm_module(&pool, &admin, team, &name, 2, RequestId::generate())
+        .await
+        .expect_err("deleted");
+    assert_eq!(err.code, ErrorCode::NotFound, "{err:?}");
+}
@@ -0,0 +1,376 @@
+//! Webhook delivery: transient failures are retried up to the policy's attempt budget, a
+//! rejection is not, bodies are HMAC-signed, and abandoned notices are dead-lettered and
+//! listed to platform admins without their payload. Team subscriptions reject unknown event
+//! filters, never deliver to protected destinations, and get their notices in `seq` order.
+//!
+//! The dead-letter and subscription halves are DB-backed and self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::secrets::{self as secret_svc, SecretWrite};
+use fp_core::services::webhooks::{self, RetryPolicy, SubscriptionDelivery, Webhook};
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::event::{DomainEvent, EventScope};
+use fp_domain::{ErrorCode, OrgRole, RequestId, SecretSpec, WebhookSubscriptionSpec};
+use fp_storage::outbox::StoredEvent;
+use fp_storage::repos::identity;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+/// `(signature header, raw body)` of one request the stub received.
+type Seen = (Option<String>, Vec<u8>);
+
+#[derive(Clone)]
+struct Stub {
+    status: Arc<Mutex<axum::http::StatusCode>>,
+    seen: Arc<Mutex<Vec<Seen>>>,
+}
+
+async fn stub_handler(
+    axum::extract::State(stub): axum::extract::State<Stub>,
+    headers: axum::http::HeaderMap,
+    body: axum::body::Bytes,
+) -> axum::http::StatusCode {
+    let signature = headers
+        .get(webhooks::SIGNATURE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned);
+    stub.seen
+        .lock()
+        .expect("seen lock")
+        .push((signature, body.to_vec()));
+    *stub.status.lock().expect("status lock")
+}
+
+async fn spawn_stub(status: axum::http::StatusCode) -> (SocketAddr, Stub) {
+    let stub = Stub {
+        status: Arc::new(Mutex::new(status)),
+        seen: Arc::new(Mutex::new(Vec::new())),
+    };
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind stub");
+    let addr = listener.local_addr().expect("stub addr");
+    let app = axum::Router::new()
+        .route("/hook", axum::routing::post(stub_handler))
+        .with_state(stub.clone());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.expect("serve stub");
+    });
+    (addr, stub)
+}
+
+fn webhook(addr: SocketAddr) -> Webhook {
+    Webhook {
+        url: format!("http://{addr}/hook"),
+        secret: Some("hook-secret".into()),
+        client: reqwest::Client::new(),
+        retry: RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(5),
+        },
+    }
+}
+
+#[tokio::test]
+async fn transient_failures_retry_rejections_do_not_and_bodies_are_signed() {
+    let (addr, stub) = spawn_stub(axum::http::StatusCode::SERVICE_UNAVAILABLE).await;
+    let hook = webhook(addr);
+    let notice = serde_json::json!({ "event": "secret.expired", "name": "db-password" });
+
+    let failure = webhooks::deliver(&hook, "secret.expired", &notice)
+        .await
+        .expect_err("503 every time");
+    assert_eq!(failure.attempts, 3);
+    assert_eq!(failure.last_status, Some(503));
+    assert_eq!(stub.seen.lock().unwrap().len(), 3);
+
+    *stub.status.lock().unwrap() = axum::http::StatusCode::BAD_REQUEST;
+    let failure = webhooks::deliver(&hook, "secret.expired", &notice)
+        .await
+        .expect_err("400 is final");
+    assert_eq!(failure.attempts, 1);
+    assert_eq!(failure.last_error, "rejected");
+
+    *stub.status.lock().unwrap() = axum::http::StatusCode::OK;
+    let attempt = webhooks::deliver(&hook, "secret.expired", &notice)
+        .await
+        .expect("delivered");
+    assert_eq!(attempt, 1);
+    let (signature, body) = stub.seen.lock().unwrap().last().cloned().expect("request");
+    assert_eq!(
+        signature.as_deref(),
+        Some(webhooks::signature("hook-secret", &body).unwrap().as_str())
+    );
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+        notice
+    );
+}
+
+#[tokio::test]
+async fn dead_letters_are_listed_to_platform_admins_without_payload() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let team = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let failure = webhooks::DeliveryFailure {
+        attempts: 4,
+        last_status: Some(502),
+        last_error: "rejected".into(),
+    };
+    webhooks::dead_letter(
+        &pool,
+        team.id,
+        None,
+        "secret.expired",
+        &serde_json::json!({ "name": "db-password" }),
+        &failure,
+    )
+    .await;
+
+    let rid = RequestId::generate();
+    let admin = PrincipalCtx::User {
+        user_id: identity::upsert_user_by_subject(&pool, &unique("sub"), "p@t.test", "P")
+            .await
+            .expect("user"),
+        platform_admin: true,
+        org_selector_required: false,
+        org: None,
+        grants: GrantSet::default(),
+    };
+    let letters = webhooks::list_dead_letters(&pool, &admin, rid)
+        .await
+        .expect("list");
+    let letter = letters
+        .iter()
+        .find(|l| l.team_id == team.id)
+        .expect("dead letter listed");
+    assert_eq!(letter.event, "secret.expired");
+    assert_eq!(letter.attempts, 4);
+    assert_eq!(letter.last_status, Some(502));
+
+    let owner_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "o@t.test", "O")
+        .await
+        .expect("owner");
+    identity::add_org_membership(&pool, owner_id, org.id, OrgRole::Owner)
+        .await
+        .expect("membership");
+    let owner = PrincipalCtx::User {
+        user_id: owner_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Owner)),
+        grants: GrantSet::default(),
+    };
+    let err = webhooks::list_dead_letters(&pool, &owner, rid)
+        .await
+        .expect_err("org owner denied");
+    assert_eq!(err.code, ErrorCode::Forbidden);
+}
+
+#[tokio::test]
+async fn subscriptions_filter_events_and_refuse_protected_destinations() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let team = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: team.id,
+        org_id: org.id,
+    };
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    let rid = RequestId::generate();
+    let secret = secret_svc::create_secret(
+        &pool,
+        &admin,
+        team,
+        SecretWrite {
+            name: &unique("hook-key"),
+            description: "",
+            spec: SecretSpec::GenericSecret {
+                secret: "aG9vay1zZWNyZXQ=".into(),
+            },
+            expires_at: None,
+        },
+        rid,
+    )
+    .await
+    .expect("secret");
+    let spec = |events: &[&str]| WebhookSubscriptionSpec {
+        url: "http://127.0.0.1:9/hook".into(),
+        events: events.iter().map(|e| e.to_string()).collect(),
+        secret_id: Some(secret.id),
+    };
+
+    let err = webhooks::create_subscription(
+        &pool,
+        &admin,
+        team,
+        "deploys",
+        spec(&["cluster.*", "learning_session_completed"]),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect_err("unknown filter");
+    assert_eq!(err.code, ErrorCode::ValidationFailed);
+
+    let subscription = webhooks::create_subscription(
+        &pool,
+        &admin,
+        team,
+        "deploys",
+        spec(&["cluster.*"]),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create");
+    assert!(subscription.spec.wants("cluster.upserted"));
+    assert!(!subscription.spec.wants("secret.expired"));
+    let err = webhooks::create_subscription(
+        &pool,
+        &admin,
+        team,
+        "deploys",
+        spec(&["*"]),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect_err("duplicate name");
+    assert_eq!(err.code, ErrorCode::Conflict);
+    let (listed, total) = webhooks::list_subscriptions(&pool, &admin, team, 50, 0, rid)
+        .await
+        .expect("list");
+    assert_eq!(total, 1);
+    assert_eq!(listed[0].id, subscription.id);
+
+    let delivery = SubscriptionDelivery {
+        advisory: Default::default(),
+        timeout: Duration::from_secs(1),
+        retry: RetryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(5),
+        },
+        workers: Default::default(),
+    };
+    let notice = serde_json::json!({ "event": "cluster.upserted", "seq": 1 });
+    let failure =
+        webhooks::notify_subscription(&pool, &delivery, &subscription, "cluster.upserted", &notice)
+            .await
+            .expect_err("loopback is refused");
+    assert_eq!(failure.attempts, 0);
+    assert_eq!(failure.last_error, "denied destination");
+
+    let platform = PrincipalCtx::User {
+        user_id,
+        platform_admin: true,
+        org_selector_required: false,
+        org: None,
+        grants: GrantSet::default(),
+    };
+    let letters = webhooks::list_dead_letters(&pool, &platform, rid)
+        .await
+        .expect("dead letters");
+    let letter = letters
+        .iter()
+        .find(|l| l.subscription_id == Some(subscription.id))
+        .expect("dead letter names the subscription");
+    assert_eq!(letter.event, "cluster.upserted");
+    assert_eq!(letter.team_id, team.id);
+
+    // Events fanned out by the consumer handler go through the subscription's one worker,
+    // in `seq` order, across batches; only matching kinds are queued.
+    let events = |seqs: std::ops::Range<i64>| -> Vec<StoredEvent> {
+        seqs.map(|seq| StoredEvent {
+            seq,
+            event: if seq % 2 == 0 {
+                DomainEvent::ClusterUpserted {
+                    cluster_id: uuid::Uuid::now_v7(),
+                    name: format!("c{seq}"),
+                }
+            } else {
+                DomainEvent::SecretExpired {
+                    secret_id: uuid::Uuid::now_v7(),
+                    name: format!("s{seq}"),
+                }
+            },
+            scope: EventScope {
+                org_id: Some(team.org_id),
+                team_id: Some(team.id),
+            },
+            trace_context: serde_json::Value::Null,
+        })
+        .collect()
+    };
+    webhooks::handle_events(&pool, &delivery, events(10..20))
+        .await
+        .expect("first batch");
+    webhooks::handle_events(&pool, &delivery, events(20..30))
+        .await
+        .expect("second batch");
+    let expected: Vec<i64> = (10..30).filter(|seq| seq % 2 == 0).collect();
+    let mut delivered = Vec::new();
+    for _ in 0..100 {
+        delivered = sqlx::query_scalar::<_, i64>(
+            "SELECT (payload->>'seq')::bigint FROM webhook_dead_letters \
+             WHERE subscription_id = $1 AND payload ? 'webhook' ORDER BY created_at, id",
+        )
+        .bind(subscription.id.as_uuid())
+        .fetch_all(&pool)
+        .await
+        .expect("dead letters by seq");
+        if delivered.len() >= expected.len() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(delivered, expected);
+
+    webhooks::delete_subscription(&pool, &admin, team, "deploys", rid)
+        .await
+        .expect("delete");
+    let err = webhooks::get_subscription(&pool, &admin, team, "deploys", rid)
+        .await
+        .expect_err("gone");
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
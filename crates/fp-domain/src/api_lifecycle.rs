@@ -19,6 +19,10 @@ pub const DEFAULT_MAX_SPEC_VERSIONS: i32 = 50;
 pub const DEFAULT_CAPTURE_TARGET_SAMPLE_COUNT: i32 = 1000;
 pub const DEFAULT_CAPTURE_MAX_BYTES: i64 = 10 * 1024 * 1024;
 pub const DEFAULT_CAPTURE_MAX_DISTINCT_PATHS: i32 = 500;
+/// Upper bound for a session's `max_body_bytes`; the capture filter never forwards more.
+pub const MAX_CAPTURE_BODY_BYTES: i32 = 64 * 1024;
+const MAX_CAPTURE_REDACTION_RULES: usize = 64;
+const REDACTED: &str = "[REDACTED]";
 pub const MAX_API_SPEC_BYTES: usize = 512 * 1024;
 pub const MAX_API_TOOL_SCHEMA_BYTES: usize = 64 * 1024;
 const MAX_API_JSON_DEPTH: usize = 64;
@@ -460,6 +464,10 @@ pub struct CaptureSession {
     pub max_duration_seconds: Option<i32>,
     pub max_bytes: i64,
     pub max_distinct_paths: i32,
+    pub sample_percent: Option<i32>,
+    pub max_body_bytes: Option<i32>,
+    pub redact_headers: Vec<String>,
+    pub redact_body_fields: Vec<String>,
     pub sample_count: i64,
     pub byte_count: i64,
     pub path_count: i64,
@@ -492,6 +500,18 @@ pub struct CaptureSessionSpec {
     pub max_bytes: i64,
     #[serde(default = "default_capture_max_distinct_paths")]
     pub max_distinct_paths: i32,
+    /// Percentage of requests (1-100) to capture; `None` captures every request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_percent: Option<i32>,
+    /// Per-body byte cap below the capture filter's 64 KiB; longer bodies are truncated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_body_bytes: Option<i32>,
+    /// Header names masked in addition to the built-in credential headers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redact_headers: Vec<String>,
+    /// JSON object keys whose values are masked at any depth of a captured body.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redact_body_fields: Vec<String>,
 }
 
 fn default_capture_target_sample_count() -> i32 {
@@ -546,10 +566,124 @@ impl CaptureSessionSpec {
                 "max_distinct_paths must be between 1 and 10000",
             ));
         }
+        if let Some(percent) = self.sample_percent {
+            if !(1..=100).contains(&percent) {
+                return Err(DomainError::validation(
+                    "sample_percent must be between 1 and 100",
+                ));
+            }
+        }
+        if let Some(bytes) = self.max_body_bytes {
+            if !(1..=MAX_CAPTURE_BODY_BYTES).contains(&bytes) {
+                return Err(DomainError::validation(format!(
+                    "max_body_bytes must be between 1 and {MAX_CAPTURE_BODY_BYTES}"
+                )));
+            }
+        }
+        validate_redaction_rules("redact_headers", &self.redact_headers, |name| {
+            !name.is_empty()
+                && name.len() <= 200
+                && name
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+        })?;
+        validate_redaction_rules("redact_body_fields", &self.redact_body_fields, |field| {
+            !field.is_empty() && field.len() <= 200 && !field.chars().any(char::is_control)
+        })?;
         Ok(())
     }
 }
 
+fn validate_redaction_rules(
+    label: &str,
+    rules: &[String],
+    valid: impl Fn(&str) -> bool,
+) -> DomainResult<()> {
+    if rules.len() > MAX_CAPTURE_REDACTION_RULES {
+        return Err(DomainError::validation(format!(
+            "{label} allows at most {MAX_CAPTURE_REDACTION_RULES} entries"
+        )));
+    }
+    if let Some(bad) = rules.iter().find(|rule| !valid(rule)) {
+        return Err(DomainError::validation(format!(
+            "{label} entry \"{bad}\" must be 1-200 characters with no control characters"
+        ))
+        .with_hint("header names are HTTP tokens; body fields are JSON object keys"));
+    }
+    Ok(())
+}
+
+impl CaptureSession {
+    /// Whether this request falls inside the session's `sample_percent`. Keyed on the request
+    /// id (FNV-1a, stable across restarts) so the header and body halves of one request agree.
+    pub fn samples(&self, request_id: &str) -> bool {
+        let Some(percent) = self.sample_percent else {
+            return true;
+        };
+        let hash = request_id
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+                (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+            });
+        hash % 100 < percent.clamp(0, 100) as u64
+    }
+
+    /// Whether a header name is masked by this session's `redact_headers`.
+    pub fn redacts_header(&self, name: &str) -> bool {
+        self.redact_headers
+            .iter()
+            .any(|rule| rule.eq_ignore_ascii_case(name))
+    }
+
+    /// Applies `redact_body_fields` and `max_body_bytes` to a captured body, returning the body
+    /// to persist and whether it is now truncated. With field rules configured a body that is
+    /// not complete JSON cannot be checked, so it is dropped rather than stored unmasked.
+    pub fn redact_body(&self, body: Option<String>, truncated: bool) -> (Option<String>, bool) {
+        let Some(body) = body else {
+            return (None, truncated);
+        };
+        let body = if self.redact_body_fields.is_empty() {
+            body
+        } else {
+            match serde_json::from_str::<serde_json::Value>(&body) {
+                Ok(mut value) if !truncated => {
+                    self.mask_fields(&mut value);
+                    value.to_string()
+                }
+                _ => return (None, true),
+            }
+        };
+        match self.max_body_bytes.map(|max| max.max(0) as usize) {
+            Some(max) if body.len() > max => {
+                let mut end = max;
+                while !body.is_char_boundary(end) {
+                    end -= 1;
+                }
+                (Some(body[..end].to_string()), true)
+            }
+            _ => (Some(body), truncated),
+        }
+    }
+
+    fn mask_fields(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, field) in map.iter_mut() {
+                    if self.redact_body_fields.iter().any(|rule| rule == key) {
+                        *field = serde_json::Value::String(REDACTED.into());
+                    } else {
+                        self.mask_fields(field);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                items.iter_mut().for_each(|item| self.mask_fields(item));
+            }
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RawObservation {
     pub id: RawObservationId,
@@ -908,4 +1042,93 @@ mod tests {
         };
         assert!(bad.validate().is_err());
     }
+
+    fn session(spec: serde_json::Value) -> CaptureSession {
+        let spec: CaptureSessionSpec = serde_json::from_value(spec).unwrap();
+        let now = Utc::now();
+        CaptureSession {
+            id: CaptureSessionId::generate(),
+            team_id: TeamId::generate(),
+            name: "capture".into(),
+            status: CaptureSessionStatus::Capturing,
+            api_definition_id: spec.api_definition_id,
+            route_config_id: spec.route_config_id,
+            listener_id: spec.listener_id,
+            virtual_host: spec.virtual_host,
+            route: spec.route,
+            target_sample_count: spec.target_sample_count,
+            max_duration_seconds: spec.max_duration_seconds,
+            max_bytes: spec.max_bytes,
+            max_distinct_paths: spec.max_distinct_paths,
+            sample_percent: spec.sample_percent,
+            max_body_bytes: spec.max_body_bytes,
+            redact_headers: spec.redact_headers,
+            redact_body_fields: spec.redact_body_fields,
+            sample_count: 0,
+            byte_count: 0,
+            path_count: 0,
+            drop_count: 0,
+            started_at: now,
+            completed_at: None,
+            cancelled_at: None,
+            updated_at: now,
+            created_at: now,
+        }
+    }
+
+    #[test]
+    fn capture_sampling_is_stable_per_request_and_roughly_proportional() {
+        let half = session(serde_json::json!({ "sample_percent": 50 }));
+        let kept = (0..1000)
+            .filter(|i| half.samples(&format!("req-{i}")))
+            .count();
+        assert!((400..=600).contains(&kept), "kept {kept} of 1000");
+        assert_eq!(half.samples("req-7"), half.samples("req-7"));
+        let all = session(serde_json::json!({}));
+        assert!((0..100).all(|i| all.samples(&format!("req-{i}"))));
+
+        let route = RouteConfigId::generate();
+        for bad in [
+            serde_json::json!({ "route_config_id": route, "sample_percent": 0 }),
+            serde_json::json!({ "route_config_id": route, "max_body_bytes": 65537 }),
+            serde_json::json!({ "route_config_id": route, "redact_headers": ["x secret"] }),
+            serde_json::json!({ "route_config_id": route, "redact_body_fields": [""] }),
+        ] {
+            let spec: CaptureSessionSpec = serde_json::from_value(bad.clone()).unwrap();
+            assert!(spec.validate().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn capture_body_redaction_masks_fields_and_drops_unparseable_bodies() {
+        let session = session(serde_json::json!({
+            "redact_headers": ["X-Customer-Ssn"],
+            "redact_body_fields": ["ssn", "card"],
+            "max_body_bytes": 64,
+        }));
+        assert!(session.redacts_header("x-customer-ssn"));
+        let (body, truncated) = session.redact_body(
+            Some(r#"{"name":"a","ssn":"123","items":[{"card":"4111"}]}"#.into()),
+            false,
+        );
+        let body: serde_json::Value = serde_json::from_str(&body.unwrap()).unwrap();
+        assert_eq!(body["ssn"], REDACTED);
+        assert_eq!(body["items"][0]["card"], REDACTED);
+        assert_eq!(body["name"], "a");
+        assert!(!truncated);
+
+        assert_eq!(
+            session.redact_body(Some("ssn=123".into()), false),
+            (None, true)
+        );
+        assert_eq!(
+            session.redact_body(Some(r#"{"a":1}"#.into()), true),
+            (None, true)
+        );
+
+        let (body, truncated) =
+            session.redact_body(Some(format!(r#"{{"note":"{}"}}"#, "é".repeat(40))), false);
+        assert!(truncated);
+        assert!(body.unwrap().len() <= 64);
+    }
 }
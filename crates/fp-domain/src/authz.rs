@@ -48,6 +48,13 @@ pub enum Resource {
     AiBudgets,
     AiUsage,
     Stats,
+    Webhooks,
+    // -- Quota-only kinds: counted against a team cap but authorized under another
+    // resource's grants, so they are not grantable and are absent from ALL_RESOURCES --
+    /// Runtime values; authorized as `route-configs`.
+    RuntimeValues,
+    /// Named retry policies; authorized as `route-configs`.
+    RetryPolicies,
 }
 
 impl Resource {
@@ -86,6 +93,9 @@ impl Resource {
             Self::AiBudgets => "ai-budgets",
             Self::AiUsage => "ai-usage",
             Self::Stats => "stats",
+            Self::Webhooks => "webhooks",
+            Self::RuntimeValues => "runtime-values",
+            Self::RetryPolicies => "retry-policies",
         }
     }
 
@@ -98,7 +108,7 @@ impl Resource {
     }
 }
 
-/// Complete list, used by parsers, OpenAPI docs, and exhaustive property tests.
+/// Every grantable resource, used by parsers, OpenAPI docs, and exhaustive property tests.
 pub const ALL_RESOURCES: &[Resource] = &[
     Resource::Organizations,
     Resource::Users,
@@ -123,6 +133,7 @@ pub const ALL_RESOURCES: &[Resource] = &[
     Resource::AiBudgets,
     Resource::AiUsage,
     Resource::Stats,
+    Resource::Webhooks,
 ];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -0,0 +1,186 @@
+//! Live ADS stream inventory: which Envoys are connected to THIS control-plane replica right
+//! now. The xDS server registers a stream once its node is authenticated and the returned
+//! guard removes it when the stream ends, so the inventory can never outlive a connection.
+//! It is process memory shared between the xDS server and the REST surface (plain data behind a
+//! lock, no IO); the persisted dataplane registry and heartbeats remain the durable view.
+
+use crate::id::{DataplaneId, TeamId};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// One live ADS stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectedProxy {
+    /// Replica-local stream number, unique for the process lifetime.
+    pub stream_id: u64,
+    pub node_id: String,
+    pub team_id: TeamId,
+    /// Dataplane bound by the certificate registry; `None` under the dev node-id resolver.
+    pub dataplane_id: Option<DataplaneId>,
+    /// Envoy's self-reported build, e.g. `envoy/1.31.2`, when the node carries one.
+    pub envoy_version: Option<String>,
+    pub connected_at: DateTime<Utc>,
+    pub last_request_at: DateTime<Utc>,
+    /// Last version the proxy ACKed per type URL. A NACK leaves the previous entry.
+    pub acked_versions: BTreeMap<String, String>,
+    /// Config version of the snapshot last read for the proxy: latest, or the version a
+    /// pin or rollout holds it on.
+    pub config_version: Option<String>,
+}
+
+/// Identity of a stream at registration.
+#[derive(Debug, Clone)]
+pub struct ProxyConnection {
+    pub node_id: String,
+    pub team_id: TeamId,
+    pub dataplane_id: Option<DataplaneId>,
+    pub envoy_version: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ConnectedProxies {
+    next_stream: AtomicU64,
+    streams: Mutex<HashMap<u64, ConnectedProxy>>,
+}
+
+impl ConnectedProxies {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Record a newly authenticated stream. Dropping the guard removes it.
+    pub fn register(self: &Arc<Self>, connection: ProxyConnection) -> ProxyStreamGuard {
+        let stream_id = self.next_stream.fetch_add(1, Ordering::Relaxed) + 1;
+        let now = Utc::now();
+        self.lock().insert(
+            stream_id,
+            ConnectedProxy {
+                stream_id,
+                node_id: connection.node_id,
+                team_id: connection.team_id,
+                dataplane_id: connection.dataplane_id,
+                envoy_version: connection.envoy_version,
+                connected_at: now,
+                last_request_at: now,
+                acked_versions: BTreeMap::new(),
+                config_version: None,
+            },
+        );
+        ProxyStreamGuard {
+            registry: Arc::clone(self),
+            stream_id,
+        }
+    }
+
+    /// The team's live streams, oldest connection first.
+    pub fn for_team(&self, team_id: TeamId) -> Vec<ConnectedProxy> {
+        let mut proxies: Vec<ConnectedProxy> = self
+            .lock()
+            .values()
+            .filter(|proxy| proxy.team_id == team_id)
+            .cloned()
+            .collect();
+        proxies.sort_by_key(|proxy| (proxy.connected_at, proxy.stream_id));
+        proxies
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<u64, ConnectedProxy>> {
+        // The map holds plain data and every critical section is a single insert, update, or
+        // copy, so a poisoned lock still guards a consistent map.
+        self.streams
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Handle the xDS stream task keeps for its lifetime.
+#[derive(Debug)]
+pub struct ProxyStreamGuard {
+    registry: Arc<ConnectedProxies>,
+    stream_id: u64,
+}
+
+impl ProxyStreamGuard {
+    /// Any discovery request from the proxy.
+    pub fn touch(&self) {
+        if let Some(proxy) = self.registry.lock().get_mut(&self.stream_id) {
+            proxy.last_request_at = Utc::now();
+        }
+    }
+
+    /// The proxy ACKed `version` of `type_url`.
+    pub fn acked(&self, type_url: &str, version: &str) {
+        if let Some(proxy) = self.registry.lock().get_mut(&self.stream_id) {
+            proxy
+                .acked_versions
+                .insert(type_url.to_string(), version.to_string());
+        }
+    }
+
+    /// The stream is now serving the snapshot recorded as `config_version`.
+    pub fn serving(&self, config_version: &str) {
+        if let Some(proxy) = self.registry.lock().get_mut(&self.stream_id) {
+            if proxy.config_version.as_deref() != Some(config_version) {
+                proxy.config_version = Some(config_version.to_string());
+            }
+        }
+    }
+}
+
+impl Drop for ProxyStreamGuard {
+    fn drop(&mut self) {
+        self.registry.lock().remove(&self.stream_id);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn connection(node_id: &str, team_id: TeamId) -> ProxyConnection {
+        ProxyConnection {
+            node_id: node_id.into(),
+            team_id,
+            dataplane_id: None,
+            envoy_version: Some("envoy/1.31.2".into()),
+        }
+    }
+
+    #[test]
+    fn streams_are_listed_per_team_until_their_guard_drops() {
+        let registry = ConnectedProxies::new();
+        let team = TeamId::generate();
+        let other = TeamId::generate();
+        let first = registry.register(connection("edge-1", team));
+        let second = registry.register(connection("edge-2", team));
+        let _foreign = registry.register(connection("edge-3", other));
+
+        first.acked("type.googleapis.com/envoy.config.cluster.v3.Cluster", "7");
+        second.touch();
+        let listed = registry.for_team(team);
+        assert_eq!(
+            listed
+                .iter()
+                .map(|p| p.node_id.as_str())
+                .collect::<Vec<_>>(),
+            ["edge-1", "edge-2"],
+            "another team's streams never appear"
+        );
+        assert_eq!(
+            listed[0]
+                .acked_versions
+                .get("type.googleapis.com/envoy.config.cluster.v3.Cluster")
+                .map(String::as_str),
+            Some("7")
+        );
+        assert!(listed[1].last_request_at >= listed[1].connected_at);
+
+        drop(first);
+        let listed = registry.for_team(team);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].node_id, "edge-2");
+    }
+}
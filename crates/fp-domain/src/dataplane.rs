@@ -22,6 +22,8 @@ pub struct Dataplane {
     pub total_requests: i64,
     pub total_errors: i64,
     pub warming_failures: i64,
+    /// Config version the dataplane is held on (staged rollout); `None` follows latest.
+    pub pinned_config_version: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -36,6 +38,74 @@ pub struct TeamStatsOverview {
     pub warming_failures: i64,
 }
 
+/// Upper bound on clusters in one agent stats report.
+pub const MAX_REPORTED_CLUSTERS: usize = 4096;
+const MAX_OPEN_CIRCUIT_BREAKERS: usize = 16;
+
+/// One dataplane's gauges for an upstream cluster, as its agent read them from Envoy's
+/// `/stats`. Gauges are point-in-time values, not counters; a newer report replaces them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpstreamClusterStats {
+    pub cluster: String,
+    pub rq_active: i64,
+    pub rq_pending_active: i64,
+    pub cx_active: i64,
+    /// Hosts currently ejected by outlier detection.
+    pub ejections_active: i64,
+    pub healthy_hosts: i64,
+    pub total_hosts: i64,
+    /// Tripped circuit-breaker gauges as `<priority>.<gauge>`, e.g. `default.rq_open`.
+    pub open_circuit_breakers: Vec<String>,
+}
+
+impl UpstreamClusterStats {
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.cluster.is_empty() || self.cluster.len() > 256 {
+            return Err(DomainError::validation(
+                "cluster stats name must be 1-256 characters",
+            ));
+        }
+        let gauges = [
+            self.rq_active,
+            self.rq_pending_active,
+            self.cx_active,
+            self.ejections_active,
+            self.healthy_hosts,
+            self.total_hosts,
+        ];
+        if gauges.iter().any(|gauge| *gauge < 0) {
+            return Err(DomainError::validation(format!(
+                "cluster stats for {} carry a negative gauge",
+                self.cluster
+            )));
+        }
+        if self.open_circuit_breakers.len() > MAX_OPEN_CIRCUIT_BREAKERS
+            || self.open_circuit_breakers.iter().any(|breaker| {
+                breaker.is_empty()
+                    || breaker.len() > 64
+                    || !breaker
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+            })
+        {
+            return Err(DomainError::validation(format!(
+                "cluster stats for {} carry a malformed circuit-breaker name",
+                self.cluster
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The latest [`UpstreamClusterStats`] one dataplane reported, and when it arrived.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataplaneClusterStats {
+    pub dataplane_id: DataplaneId,
+    pub dataplane: String,
+    pub stats: UpstreamClusterStats,
+    pub observed_at: DateTime<Utc>,
+}
+
 /// One issued client certificate. Private keys are never stored — this is the binding and
 /// revocation record, keyed by the globally-unique SPIFFE URI.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -104,4 +174,34 @@ mod tests {
         let long = format!("spiffe://x/{}", "a".repeat(3000));
         assert!(validate_spiffe_uri(&long).is_err());
     }
+
+    #[test]
+    fn upstream_cluster_stats_validation() {
+        let stats = UpstreamClusterStats {
+            cluster: "orders".into(),
+            rq_active: 3,
+            rq_pending_active: 0,
+            cx_active: 2,
+            ejections_active: 1,
+            healthy_hosts: 2,
+            total_hosts: 3,
+            open_circuit_breakers: vec!["default.rq_open".into()],
+        };
+        assert!(stats.validate().is_ok());
+        let negative = UpstreamClusterStats {
+            rq_active: -1,
+            ..stats.clone()
+        };
+        assert!(negative.validate().is_err());
+        let unnamed = UpstreamClusterStats {
+            cluster: String::new(),
+            ..stats.clone()
+        };
+        assert!(unnamed.validate().is_err());
+        let breaker = UpstreamClusterStats {
+            open_circuit_breakers: vec!["default rq_open".into()],
+            ..stats
+        };
+        assert!(breaker.validate().is_err());
+    }
 }
@@ -39,6 +39,9 @@ pub enum ErrorCode {
     InvalidConfig,
     /// A dependency (database, IdP, provider) is unavailable; safe to retry.
     Unavailable,
+    /// An optional server feature the request needs is switched off on this deployment.
+    /// Retrying will not help until an operator sets the named configuration.
+    NotConfigured,
     /// Unexpected internal failure. Details are logged, never returned.
     Internal,
 }
@@ -59,6 +62,7 @@ impl ErrorCode {
             Self::PayloadTooLarge => "payload_too_large",
             Self::InvalidConfig => "invalid_config",
             Self::Unavailable => "unavailable",
+            Self::NotConfigured => "not_configured",
             Self::Internal => "internal",
         }
     }
@@ -144,6 +148,16 @@ impl DomainError {
         Self::new(ErrorCode::Unavailable, message)
     }
 
+    /// `feature` is switched off on this server; the hint names the `setting` that turns it
+    /// on. Distinct from [`Self::unavailable`]: the same request fails until an operator acts.
+    pub fn not_configured(feature: &str, setting: &str) -> Self {
+        Self::new(
+            ErrorCode::NotConfigured,
+            format!("{feature} is not configured on this server"),
+        )
+        .with_hint(format!("the operator sets {setting} to enable it"))
+    }
+
     /// Internal failure: `message` is for logs/operators; the API layer replaces it with a
     /// generic message so internals never leak (spec/01 baseline kept in v2).
     pub fn internal(message: impl Into<String>) -> Self {
@@ -172,6 +186,7 @@ mod tests {
             ErrorCode::PayloadTooLarge,
             ErrorCode::InvalidConfig,
             ErrorCode::Unavailable,
+            ErrorCode::NotConfigured,
             ErrorCode::Internal,
         ] {
             let json = serde_json::to_value(code).unwrap_or_default();
@@ -196,4 +211,20 @@ mod tests {
         assert_eq!(err.message, "cluster \"payments-db\" not found");
         assert!(!err.code.is_retryable());
     }
+
+    #[test]
+    fn not_configured_is_terminal_and_names_the_setting() {
+        let err =
+            DomainError::not_configured("secret encryption", "FLOWPLANE_SECRET_ENCRYPTION_KEY");
+        assert_eq!(err.code, ErrorCode::NotConfigured);
+        assert_eq!(
+            err.message,
+            "secret encryption is not configured on this server"
+        );
+        assert!(err
+            .hint
+            .as_deref()
+            .is_some_and(|hint| hint.contains("FLOWPLANE_SECRET_ENCRYPTION_KEY")));
+        assert!(!err.code.is_retryable());
+    }
 }
@@ -32,6 +32,20 @@ pub enum DomainEvent {
     // Dataplanes / mTLS certificate registry (S5.4)
     #[serde(rename = "dataplane.created", alias = "dataplane_created")]
     DataplaneCreated { dataplane_id: Uuid, name: String },
+    /// `config_version: None` = promoted back to latest.
+    #[serde(rename = "dataplane.config_pinned", alias = "dataplane_config_pinned")]
+    DataplaneConfigPinned {
+        dataplane_id: Uuid,
+        name: String,
+        config_version: Option<String>,
+    },
+    #[serde(rename = "config_rollout.updated", alias = "config_rollout_updated")]
+    ConfigRolloutUpdated {
+        stable_version: String,
+        canary_percent: u8,
+    },
+    #[serde(rename = "config_rollout.promoted", alias = "config_rollout_promoted")]
+    ConfigRolloutPromoted { stable_version: String },
     #[serde(
         rename = "proxy_certificate.registered",
         alias = "proxy_certificate_registered"
@@ -50,6 +64,16 @@ pub enum DomainEvent {
     },
     #[serde(rename = "secret.upserted", alias = "secret_upserted")]
     SecretUpserted { secret_id: Uuid, name: String },
+    #[serde(rename = "secret.expired", alias = "secret_expired")]
+    SecretExpired { secret_id: Uuid, name: String },
+    #[serde(rename = "retry_policy.upserted", alias = "retry_policy_upserted")]
+    RetryPolicyUpserted { retry_policy_id: Uuid, name: String },
+    #[serde(rename = "retry_policy.deleted", alias = "retry_policy_deleted")]
+    RetryPolicyDeleted { retry_policy_id: Uuid, name: String },
+    #[serde(rename = "runtime_value.set", alias = "runtime_value_set")]
+    RuntimeValueSet { key: String },
+    #[serde(rename = "runtime_value.deleted", alias = "runtime_value_deleted")]
+    RuntimeValueDeleted { key: String },
     // API lifecycle / learning config-first spine (S8)
     #[serde(rename = "api_definition.created", alias = "api_definition_created")]
     ApiDefinitionCreated {
@@ -119,9 +143,17 @@ impl DomainEvent {
             Self::TeamCreated { .. } => "team.created",
             Self::TeamDeleted { .. } => "team.deleted",
             Self::DataplaneCreated { .. } => "dataplane.created",
+            Self::DataplaneConfigPinned { .. } => "dataplane.config_pinned",
+            Self::ConfigRolloutUpdated { .. } => "config_rollout.updated",
+            Self::ConfigRolloutPromoted { .. } => "config_rollout.promoted",
             Self::ProxyCertificateRegistered { .. } => "proxy_certificate.registered",
             Self::ProxyCertificateRevoked { .. } => "proxy_certificate.revoked",
             Self::SecretUpserted { .. } => "secret.upserted",
+            Self::SecretExpired { .. } => "secret.expired",
+            Self::RetryPolicyUpserted { .. } => "retry_policy.upserted",
+            Self::RetryPolicyDeleted { .. } => "retry_policy.deleted",
+            Self::RuntimeValueSet { .. } => "runtime_value.set",
+            Self::RuntimeValueDeleted { .. } => "runtime_value.deleted",
             Self::ApiDefinitionCreated { .. } => "api_definition.created",
             Self::ApiDefinitionDeleted { .. } => "api_definition.deleted",
             Self::SpecVersionCreated { .. } => "spec_version.created",
@@ -135,6 +167,40 @@ impl DomainEvent {
     }
 }
 
+/// Every [`DomainEvent::kind`], in declaration order. Webhook subscription filters are
+/// checked against this list.
+pub const EVENT_KINDS: &[&str] = &[
+    "cluster.upserted",
+    "cluster.deleted",
+    "route_config.upserted",
+    "route_config.deleted",
+    "listener.upserted",
+    "listener.deleted",
+    "team.created",
+    "team.deleted",
+    "dataplane.created",
+    "dataplane.config_pinned",
+    "config_rollout.updated",
+    "config_rollout.promoted",
+    "proxy_certificate.registered",
+    "proxy_certificate.revoked",
+    "secret.upserted",
+    "secret.expired",
+    "retry_policy.upserted",
+    "retry_policy.deleted",
+    "runtime_value.set",
+    "runtime_value.deleted",
+    "api_definition.created",
+    "api_definition.deleted",
+    "spec_version.created",
+    "api_tools.generated",
+    "capture_session.started",
+    "capture_session.stopped",
+    "capture_session.cancelled",
+    "rate_limit_policy.upserted",
+    "rate_limit_policy.deleted",
+];
+
 /// Tenancy scope attached to every event row (consumers rebuild per team).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EventScope {
@@ -187,6 +253,18 @@ mod tests {
                 dataplane_id: uuid,
                 name: "x".into(),
             },
+            DomainEvent::DataplaneConfigPinned {
+                dataplane_id: uuid,
+                name: "x".into(),
+                config_version: Some("0123456789abcdef".into()),
+            },
+            DomainEvent::ConfigRolloutUpdated {
+                stable_version: "0123456789abcdef".into(),
+                canary_percent: 10,
+            },
+            DomainEvent::ConfigRolloutPromoted {
+                stable_version: "0123456789abcdef".into(),
+            },
             DomainEvent::ProxyCertificateRegistered {
                 certificate_id: uuid,
                 spiffe_uri: "spiffe://flowplane.local/org/o/team/t/dataplane/d".into(),
@@ -199,6 +277,20 @@ mod tests {
                 secret_id: uuid,
                 name: "x".into(),
             },
+            DomainEvent::SecretExpired {
+                secret_id: uuid,
+                name: "x".into(),
+            },
+            DomainEvent::RetryPolicyUpserted {
+                retry_policy_id: uuid,
+                name: "x".into(),
+            },
+            DomainEvent::RetryPolicyDeleted {
+                retry_policy_id: uuid,
+                name: "x".into(),
+            },
+            DomainEvent::RuntimeValueSet { key: "x".into() },
+            DomainEvent::RuntimeValueDeleted { key: "x".into() },
             DomainEvent::ApiDefinitionCreated {
                 api_definition_id: uuid,
                 name: "x".into(),
@@ -239,9 +331,11 @@ mod tests {
             },
         ];
 
+        assert_eq!(events.len(), EVENT_KINDS.len());
         for event in events {
             let json = serde_json::to_value(&event).expect("serialize");
             assert_eq!(json["type"], event.kind());
+            assert!(EVENT_KINDS.contains(&event.kind()), "{}", event.kind());
             let back: DomainEvent = serde_json::from_value(json).expect("deserialize");
             assert_eq!(back, event);
         }
@@ -46,7 +46,10 @@ pub struct Cluster {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ClusterSpec {
+    #[serde(default)]
     pub endpoints: Vec<Endpoint>,
+    /// Member clusters of an aggregate, in priority order: traffic goes to the first member
+    /// with healthy hosts and fails over down the list. An aggregate has no endpoints.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub aggregate_clusters: Vec<String>,
     #[serde(default)]
@@ -69,15 +72,23 @@ pub struct ClusterSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub upstream_tls: Option<UpstreamTlsConfig>,
     /// Optional upstream protocol selection. `Http2`/`Grpc` force Envoy's HTTP/2
-    /// upstream protocol options.
+    /// upstream protocol options; `Auto` negotiates HTTP/2 or HTTP/1.1 per connection over
+    /// TLS ALPN. Omitted means HTTP/1.1.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protocol: Option<UpstreamProtocol>,
+    /// Speak to the upstream in whatever protocol the downstream used (HTTP/1.1 in, HTTP/1.1
+    /// out; HTTP/2 in, HTTP/2 out). Exclusive with `protocol`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub use_downstream_protocol: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub health_checks: Option<Vec<HealthCheck>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub circuit_breakers: Option<CircuitBreakers>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub outlier_detection: Option<OutlierDetection>,
+    /// How long Envoy reuses an upstream connection and how much it multiplexes on it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_pool: Option<ConnectionPoolLimits>,
 }
 
 fn default_connect_timeout() -> u32 {
@@ -175,6 +186,8 @@ pub enum UpstreamProtocol {
     Http1,
     Http2,
     Grpc,
+    /// HTTP/2 when the upstream's ALPN offers `h2`, HTTP/1.1 otherwise. Needs upstream TLS.
+    Auto,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
@@ -258,6 +271,10 @@ pub struct CircuitBreakerThresholds {
     /// ≤10.
     #[serde(default)]
     pub max_retries: u32,
+    /// 1–10000. Caps the connection pools Envoy may create for the cluster
+    /// (one per subset / upstream-option combination). Omitted = unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connection_pools: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
@@ -276,6 +293,19 @@ pub struct OutlierDetection {
     pub min_hosts: Option<u32>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectionPoolLimits {
+    /// 1–1000000. Close an upstream connection after this many requests, so traffic moves
+    /// onto hosts added since it opened. Omitted = unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_requests_per_connection: Option<u32>,
+    /// 1–2147483647. Concurrent streams Envoy opens on one HTTP/2 upstream connection.
+    /// Only for clusters that can speak HTTP/2. Omitted = Envoy's default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_streams: Option<u32>,
+}
+
 fn range(label: &str, value: u32, min: u32, max: u32) -> DomainResult<()> {
     if value < min || value > max {
         return Err(DomainError::validation(format!(
@@ -330,8 +360,14 @@ impl ClusterSpec {
                     self.aggregate_clusters.len()
                 )));
             }
+            let mut seen = std::collections::HashSet::new();
             for cluster in &self.aggregate_clusters {
                 crate::identity::validate_name(cluster)?;
+                if !seen.insert(cluster.as_str()) {
+                    return Err(DomainError::validation(format!(
+                        "aggregate member cluster \"{cluster}\" is listed more than once"
+                    )));
+                }
             }
             range("connect_timeout_secs", self.connect_timeout_secs, 1, 300)?;
             return Ok(());
@@ -371,6 +407,21 @@ impl ClusterSpec {
             ));
         }
         range("connect_timeout_secs", self.connect_timeout_secs, 1, 300)?;
+        if self.use_downstream_protocol && self.protocol.is_some() {
+            return Err(DomainError::validation(
+                "use_downstream_protocol and protocol are mutually exclusive",
+            )
+            .with_hint("drop protocol to mirror the downstream protocol"));
+        }
+        if self.protocol == Some(UpstreamProtocol::Auto)
+            && !self.use_tls
+            && self.upstream_tls.is_none()
+        {
+            return Err(DomainError::validation(
+                "protocol auto negotiates over TLS ALPN and requires upstream TLS",
+            )
+            .with_hint("set use_tls, or use use_downstream_protocol for a plaintext upstream"));
+        }
 
         if self.lb_policy != LbPolicy::LeastRequest && self.least_request.is_some() {
             return Err(DomainError::validation(
@@ -463,6 +514,34 @@ impl ClusterSpec {
                 range("min_hosts", min_hosts, 1, 100)?;
             }
         }
+        if let Some(pool) = &self.connection_pool {
+            if pool.max_requests_per_connection.is_none() && pool.max_concurrent_streams.is_none() {
+                return Err(DomainError::validation(
+                    "connection_pool must set max_requests_per_connection or max_concurrent_streams",
+                ));
+            }
+            if let Some(max) = pool.max_requests_per_connection {
+                range("max_requests_per_connection", max, 1, 1_000_000)?;
+            }
+            if let Some(max) = pool.max_concurrent_streams {
+                range("max_concurrent_streams", max, 1, i32::MAX as u32)?;
+                let speaks_http2 = self.use_downstream_protocol
+                    || matches!(
+                        self.protocol,
+                        Some(
+                            UpstreamProtocol::Http2
+                                | UpstreamProtocol::Grpc
+                                | UpstreamProtocol::Auto
+                        )
+                    );
+                if !speaks_http2 {
+                    return Err(DomainError::validation(
+                        "max_concurrent_streams only applies to HTTP/2 upstreams",
+                    )
+                    .with_hint("set protocol to http2, grpc or auto, or use_downstream_protocol"));
+                }
+            }
+        }
         Ok(())
     }
 }
@@ -537,6 +616,9 @@ fn validate_circuit_breaker(label: &str, cb: &CircuitBreakerThresholds) -> Domai
     )?;
     range(&format!("{label}.max_requests"), cb.max_requests, 1, 10_000)?;
     range(&format!("{label}.max_retries"), cb.max_retries, 0, 10)?;
+    if let Some(pools) = cb.max_connection_pools {
+        range(&format!("{label}.max_connection_pools"), pools, 1, 10_000)?;
+    }
     Ok(())
 }
 
@@ -562,9 +644,11 @@ mod tests {
             use_tls: false,
             upstream_tls: None,
             protocol: None,
+            use_downstream_protocol: false,
             health_checks: None,
             circuit_breakers: None,
             outlier_detection: None,
+            connection_pool: None,
         }
     }
 
@@ -748,6 +832,23 @@ mod tests {
                             max_pending_requests: 10,
                             max_requests: 10,
                             max_retries: 11,
+                            max_connection_pools: None,
+                        }),
+                        high: None,
+                    }),
+                    ..minimal()
+                },
+            ),
+            (
+                "zero connection pools",
+                ClusterSpec {
+                    circuit_breakers: Some(CircuitBreakers {
+                        default: Some(CircuitBreakerThresholds {
+                            max_connections: 10,
+                            max_pending_requests: 10,
+                            max_requests: 10,
+                            max_retries: 3,
+                            max_connection_pools: Some(0),
                         }),
                         high: None,
                     }),
@@ -808,6 +909,7 @@ mod tests {
                 insecure_skip_verify: false,
             }),
             protocol: Some(UpstreamProtocol::Grpc),
+            use_downstream_protocol: false,
             health_checks: Some(vec![
                 HealthCheck::Http(HttpHealthCheck {
                     path: "/healthz".into(),
@@ -832,12 +934,14 @@ mod tests {
                     max_pending_requests: 200,
                     max_requests: 300,
                     max_retries: 3,
+                    max_connection_pools: Some(64),
                 }),
                 high: Some(CircuitBreakerThresholds {
                     max_connections: 1000,
                     max_pending_requests: 2000,
                     max_requests: 3000,
                     max_retries: 5,
+                    max_connection_pools: None,
                 }),
             }),
             outlier_detection: Some(OutlierDetection {
@@ -852,6 +956,60 @@ mod tests {
         assert!(spec.validate().is_ok());
     }
 
+    #[test]
+    fn connection_pool_limits_validate() {
+        let pool = |requests: Option<u32>, streams: Option<u32>| ConnectionPoolLimits {
+            max_requests_per_connection: requests,
+            max_concurrent_streams: streams,
+        };
+        let mut spec = ClusterSpec {
+            connection_pool: Some(pool(Some(1000), None)),
+            ..minimal()
+        };
+        assert!(spec.validate().is_ok(), "request cap works on HTTP/1.1");
+
+        spec.connection_pool = Some(pool(None, None));
+        assert!(spec.validate().is_err(), "empty block rejected");
+        spec.connection_pool = Some(pool(Some(0), None));
+        assert!(spec.validate().is_err(), "zero requests rejected");
+
+        spec.connection_pool = Some(pool(None, Some(100)));
+        let err = spec.validate().expect_err("streams need HTTP/2");
+        assert!(err.message.contains("HTTP/2"));
+        spec.protocol = Some(UpstreamProtocol::Http2);
+        assert!(spec.validate().is_ok());
+        spec.connection_pool = Some(pool(None, Some(0)));
+        assert!(spec.validate().is_err(), "zero streams rejected");
+    }
+
+    #[test]
+    fn aggregate_members_are_an_ordered_set() {
+        let spec: ClusterSpec = serde_json::from_value(serde_json::json!({
+            "aggregate_clusters": ["primary", "failover"]
+        }))
+        .expect("an aggregate needs no endpoints key");
+        assert!(spec.validate().is_ok());
+
+        let repeated = ClusterSpec {
+            aggregate_clusters: vec!["primary".into(), "failover".into(), "primary".into()],
+            ..spec
+        };
+        let err = repeated.validate().expect_err("duplicate members rejected");
+        assert!(err.message.contains("more than once"));
+    }
+
+    #[test]
+    fn auto_protocol_requires_upstream_tls() {
+        let mut spec = ClusterSpec {
+            protocol: Some(UpstreamProtocol::Auto),
+            ..minimal()
+        };
+        let err = spec.validate().expect_err("plaintext ALPN is impossible");
+        assert!(err.message.contains("requires upstream TLS"));
+        spec.use_tls = true;
+        assert!(spec.validate().is_ok());
+    }
+
     #[test]
     fn unknown_spec_fields_rejected_at_deserialization() {
         let err = serde_json::from_value::<ClusterSpec>(serde_json::json!({
@@ -0,0 +1,19 @@
+//! Filter presets: a named, team-owned [`HttpFilterSpec`] that listener chains instantiate
+//! with `{"type": "preset", "name": ...}`. The listener write path copies the preset's
+//! filter into the chain, so a stored listener never carries a reference.
+
+use crate::gateway::filters::HttpFilterSpec;
+use crate::id::{FilterPresetId, TeamId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterPreset {
+    pub id: FilterPresetId,
+    pub team_id: TeamId,
+    pub name: String,
+    pub spec: HttpFilterSpec,
+    pub version: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -29,10 +29,16 @@ enum HttpFilterKind {
     ExtAuthz,
     Rbac,
     GlobalRateLimit,
+    GrpcJsonTranscoder,
+    CustomResponse,
+    Wasm,
+    /// Placeholder for a team filter preset; replaced by the preset's filter on listener
+    /// write, so it is never persisted and never a per-route target (not in `ALL`).
+    Preset,
 }
 
 impl HttpFilterKind {
-    const ALL: [Self; 9] = [
+    const ALL: [Self; 12] = [
         Self::Cors,
         Self::LocalRateLimit,
         Self::HeaderMutation,
@@ -42,6 +48,9 @@ impl HttpFilterKind {
         Self::ExtAuthz,
         Self::Rbac,
         Self::GlobalRateLimit,
+        Self::GrpcJsonTranscoder,
+        Self::CustomResponse,
+        Self::Wasm,
     ];
 
     fn as_str(self) -> &'static str {
@@ -55,6 +64,10 @@ impl HttpFilterKind {
             Self::ExtAuthz => "ext_authz",
             Self::Rbac => "rbac",
             Self::GlobalRateLimit => "global_rate_limit",
+            Self::GrpcJsonTranscoder => "grpc_json_transcoder",
+            Self::CustomResponse => "custom_response",
+            Self::Wasm => "wasm",
+            Self::Preset => "preset",
         }
     }
 
@@ -66,6 +79,29 @@ impl HttpFilterKind {
         !matches!(self, Self::HealthCheck)
     }
 
+    /// Position in the recommended chain order (`auto_order`): health checks answer before
+    /// anything else runs, custom responses sit next so they see (encode last) every response
+    /// including later filters' local replies, CORS preflights before authentication,
+    /// authentication before the authorization that may read its result, then rate limiting
+    /// and request shaping (header mutation, then wasm modules, which see the mutated request).
+    fn canonical_rank(self) -> u8 {
+        match self {
+            Self::HealthCheck => 0,
+            Self::CustomResponse => 1,
+            Self::Cors => 2,
+            Self::JwtAuth => 3,
+            Self::ExtAuthz => 4,
+            Self::Rbac => 5,
+            Self::LocalRateLimit => 6,
+            Self::GlobalRateLimit => 7,
+            Self::HeaderMutation => 8,
+            Self::Wasm => 9,
+            Self::GrpcJsonTranscoder => 10,
+            Self::Compressor => 11,
+            Self::Preset => 12,
+        }
+    }
+
     fn disablable_hint() -> String {
         Self::ALL
             .iter()
@@ -103,6 +139,12 @@ pub enum HttpFilterSpec {
     ExtAuthz(ExtAuthzConfig),
     Rbac(RbacConfig),
     GlobalRateLimit(GlobalRateLimitConfig),
+    GrpcJsonTranscoder(GrpcJsonTranscoderConfig),
+    CustomResponse(CustomResponseConfig),
+    Wasm(WasmConfig),
+    /// Reference to a team filter preset by name. The listener write path replaces it with
+    /// a copy of the preset's filter; later edits to the preset do not propagate.
+    Preset(FilterPresetRef),
 }
 
 impl HttpFilterSpec {
@@ -121,6 +163,10 @@ impl HttpFilterSpec {
             Self::ExtAuthz(_) => HttpFilterKind::ExtAuthz,
             Self::Rbac(_) => HttpFilterKind::Rbac,
             Self::GlobalRateLimit(_) => HttpFilterKind::GlobalRateLimit,
+            Self::GrpcJsonTranscoder(_) => HttpFilterKind::GrpcJsonTranscoder,
+            Self::CustomResponse(_) => HttpFilterKind::CustomResponse,
+            Self::Wasm(_) => HttpFilterKind::Wasm,
+            Self::Preset(_) => HttpFilterKind::Preset,
         }
     }
 
@@ -135,6 +181,14 @@ impl HttpFilterSpec {
             Self::ExtAuthz(c) => c.validate(),
             Self::Rbac(c) => c.validate(),
             Self::GlobalRateLimit(c) => c.validate(),
+            Self::GrpcJsonTranscoder(c) => c.validate(),
+            Self::CustomResponse(c) => c.validate(),
+            Self::Wasm(c) => c.validate(),
+            Self::Preset(r) => Err(DomainError::validation(format!(
+                "filter preset \"{}\" can only be used in a team listener write",
+                r.name
+            ))
+            .with_hint("presets are expanded when a listener is created or updated")),
         }
     }
 }
@@ -154,6 +208,8 @@ pub enum FilterOverride {
     /// JWT requirement for this scope, by name from the chain config's `requirement_map`
     /// (reference-only per spec/04 §4.1; disabling goes through `Disable`).
     JwtAuth { requirement_name: String },
+    /// ext_authz for this scope: skip the check, or adjust what is sent with it.
+    ExtAuthz(ExtAuthzOverride),
 }
 
 impl FilterOverride {
@@ -185,6 +241,7 @@ impl FilterOverride {
             Self::Cors(_) => Ok("cors"),
             Self::LocalRateLimit(_) => Ok("local_rate_limit"),
             Self::JwtAuth { .. } => Ok("jwt_auth"),
+            Self::ExtAuthz(_) => Ok("ext_authz"),
         }
     }
 
@@ -201,6 +258,7 @@ impl FilterOverride {
                 }
                 Ok(())
             }
+            Self::ExtAuthz(c) => c.validate(),
         }
     }
 }
@@ -225,10 +283,22 @@ pub fn validate_filter_overrides(overrides: &[FilterOverride]) -> DomainResult<(
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(tag = "match", rename_all = "snake_case", deny_unknown_fields)]
 pub enum OriginMatcher {
-    Exact { value: String },
-    Prefix { value: String },
-    Suffix { value: String },
-    Contains { value: String },
+    Exact {
+        value: String,
+    },
+    Prefix {
+        value: String,
+    },
+    Suffix {
+        value: String,
+    },
+    Contains {
+        value: String,
+    },
+    /// RE2 pattern matched against the whole origin, e.g. `https://.*\.example\.com`.
+    SafeRegex {
+        regex: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
@@ -288,8 +358,10 @@ impl CorsConfig {
                 if value == "*")
         });
         if wildcard && self.allow_credentials {
+            // Browsers refuse a credentialed response carrying `Access-Control-Allow-Origin: *`
+            // and report only a generic CORS failure, so catch it at config time.
             return Err(DomainError::validation(
-                "cors: allow_credentials cannot be combined with a wildcard origin",
+                "cors: allow_credentials cannot be combined with a wildcard origin: browsers reject a credentialed response whose Access-Control-Allow-Origin is \"*\"",
             )
             .with_hint("list explicit origins, or drop allow_credentials"));
         }
@@ -323,6 +395,47 @@ pub struct LocalRateLimitConfig {
     /// 400–599; Envoy default 429 when omitted.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status_code: Option<u16>,
+    /// Per-key buckets. A request whose route `rate_limits` actions produce exactly a
+    /// descriptor's `entries` draws from that descriptor's bucket; any other request
+    /// falls back to `token_bucket`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub descriptors: Vec<LocalRateLimitDescriptor>,
+}
+
+/// Descriptors one local_rate_limit filter may declare.
+pub const MAX_LOCAL_RATE_LIMIT_DESCRIPTORS: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LocalRateLimitDescriptor {
+    /// Ordered `(key, value)` pairs, as emitted by the route's `rate_limits` actions.
+    pub entries: Vec<DescriptorEntry>,
+    /// Required; optional in the type only so a missing bucket gets a precise error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_bucket: Option<TokenBucket>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DescriptorEntry {
+    pub key: String,
+    pub value: String,
+}
+
+impl TokenBucket {
+    fn validate(&self, label: &str) -> DomainResult<()> {
+        if self.max_tokens == 0 {
+            return Err(DomainError::validation(format!(
+                "local_rate_limit: {label}.max_tokens must be >= 1"
+            )));
+        }
+        if self.fill_interval_ms == 0 {
+            return Err(DomainError::validation(format!(
+                "local_rate_limit: {label}.fill_interval_ms must be > 0"
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl LocalRateLimitConfig {
@@ -332,16 +445,7 @@ impl LocalRateLimitConfig {
                 "local_rate_limit: stat_prefix is required",
             ));
         }
-        if self.token_bucket.max_tokens == 0 {
-            return Err(DomainError::validation(
-                "local_rate_limit: token_bucket.max_tokens must be >= 1",
-            ));
-        }
-        if self.token_bucket.fill_interval_ms == 0 {
-            return Err(DomainError::validation(
-                "local_rate_limit: token_bucket.fill_interval_ms must be > 0",
-            ));
-        }
+        self.token_bucket.validate("token_bucket")?;
         if let Some(code) = self.status_code {
             if !(400..=599).contains(&code) {
                 return Err(DomainError::validation(
@@ -349,6 +453,38 @@ impl LocalRateLimitConfig {
                 ));
             }
         }
+        if self.descriptors.len() > MAX_LOCAL_RATE_LIMIT_DESCRIPTORS {
+            return Err(DomainError::validation(format!(
+                "local_rate_limit: at most {MAX_LOCAL_RATE_LIMIT_DESCRIPTORS} descriptors are allowed"
+            )));
+        }
+        let mut seen = std::collections::HashSet::new();
+        for (i, descriptor) in self.descriptors.iter().enumerate() {
+            if descriptor.entries.is_empty() || descriptor.entries.len() > 8 {
+                return Err(DomainError::validation(format!(
+                    "local_rate_limit: descriptors[{i}].entries must contain 1-8 entries"
+                )));
+            }
+            for entry in &descriptor.entries {
+                if entry.key.is_empty() || entry.value.is_empty() {
+                    return Err(DomainError::validation(format!(
+                        "local_rate_limit: descriptors[{i}] entries need a non-empty key and value"
+                    )));
+                }
+            }
+            let Some(bucket) = &descriptor.token_bucket else {
+                return Err(DomainError::validation(format!(
+                    "local_rate_limit: descriptors[{i}] has no token_bucket"
+                ))
+                .with_hint("give every descriptor its own token_bucket; requests that match no descriptor use the filter's token_bucket"));
+            };
+            bucket.validate(&format!("descriptors[{i}].token_bucket"))?;
+            if !seen.insert(&descriptor.entries) {
+                return Err(DomainError::validation(format!(
+                    "local_rate_limit: descriptors[{i}] repeats the entries of an earlier descriptor"
+                )));
+            }
+        }
         Ok(())
     }
 }
@@ -411,8 +547,38 @@ fn is_default_rate_limit_request_type(value: &RateLimitRequestType) -> bool {
 }
 
 impl GlobalRateLimitConfig {
+    /// The Envoy `stat_prefix`: the explicit value, else one derived from the domain, so each
+    /// domain's `ratelimit.*` counters land under their own prefix instead of merging into one
+    /// unprefixed set. The CP-composed `{org}|{team}|` namespace is dropped (the operator thinks
+    /// in base domains), and anything outside `[A-Za-z0-9_-]` — notably `.`, which Envoy reads
+    /// as a stat-name separator — becomes `_`.
+    pub fn effective_stat_prefix(&self) -> String {
+        if let Some(prefix) = &self.stat_prefix {
+            return prefix.clone();
+        }
+        let mut parts = self.domain.splitn(3, '|');
+        let base = match (parts.next(), parts.next(), parts.next()) {
+            (Some(org), Some(team), Some(base))
+                if uuid::Uuid::parse_str(org).is_ok() && uuid::Uuid::parse_str(team).is_ok() =>
+            {
+                base
+            }
+            _ => self.domain.as_str(),
+        };
+        base.chars()
+            .take(128)
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
     pub fn validate(&self) -> DomainResult<()> {
-        if self.domain.is_empty()
+        if self.domain.trim().is_empty()
             || self.domain.len() > MAX_GLOBAL_RATE_LIMIT_DOMAIN_LEN
             || self.domain.contains('\0')
         {
@@ -468,6 +634,54 @@ pub struct HeaderValue {
     pub value: String,
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub append: bool,
+    /// Add the header only when this matches; unconditional when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<HeaderCondition>,
+}
+
+/// When a conditional header is added. Every set field must match. Emitted as a `%CEL(...)%`
+/// value that is empty on a miss, and Envoy drops empty header values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HeaderCondition {
+    /// Upstream response status (`response_headers_to_add` only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_code: Option<StatusCodeMatch>,
+    /// Request header that must be present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_header_present: Option<String>,
+}
+
+impl HeaderCondition {
+    fn validate(&self, label: &str) -> DomainResult<()> {
+        if self.response_code.is_none() && self.request_header_present.is_none() {
+            return Err(DomainError::validation(format!(
+                "{label}: when must set response_code or request_header_present"
+            )));
+        }
+        if let Some(status) = self.response_code {
+            let (start, end) = status.bounds();
+            if !(100..=599).contains(&start) || !(100..=599).contains(&end) || start > end {
+                return Err(DomainError::validation(format!(
+                    "{label}: when.response_code must be a 100-599 code or ascending range"
+                )));
+            }
+        }
+        if let Some(name) = &self.request_header_present {
+            if name.is_empty()
+                || name.len() > MAX_HEADER_NAME_LEN
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            {
+                return Err(DomainError::validation(format!(
+                    "{label}: when.request_header_present must be a header name of letters, \
+                     digits, '-', '_' or '.'"
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
@@ -501,6 +715,16 @@ impl HeaderMutationConfig {
             "header_mutation: response_headers_to_remove",
             &self.response_headers_to_remove,
         )?;
+        if self
+            .request_headers_to_add
+            .iter()
+            .any(|hv| hv.when.as_ref().is_some_and(|w| w.response_code.is_some()))
+        {
+            return Err(DomainError::validation(
+                "header_mutation: request_headers_to_add cannot be conditioned on response_code",
+            )
+            .with_hint("request headers are sent before there is a response"));
+        }
         for hv in self
             .request_headers_to_add
             .iter()
@@ -522,6 +746,19 @@ fn validate_origin_matcher(matcher: &OriginMatcher) -> DomainResult<()> {
         | OriginMatcher::Prefix { value }
         | OriginMatcher::Suffix { value }
         | OriginMatcher::Contains { value } => value,
+        OriginMatcher::SafeRegex { regex } => {
+            validate_bounded_string(
+                "cors: allow_origin matcher regex",
+                regex,
+                MAX_CORS_ORIGIN_VALUE_LEN,
+            )?;
+            // Envoy NACKs the whole route config on a pattern it cannot compile.
+            return regex_syntax::parse(regex).map(|_| ()).map_err(|e| {
+                DomainError::validation(format!(
+                    "cors: allow_origin regex \"{regex}\" does not compile: {e}"
+                ))
+            });
+        }
     };
     validate_bounded_string(
         "cors: allow_origin matcher value",
@@ -569,6 +806,18 @@ fn validate_header_values(label: &str, values: &[HeaderValue]) -> DomainResult<(
             &hv.value,
             MAX_HEADER_VALUE_LEN,
         )?;
+        if let Some(when) = &hv.when {
+            when.validate(label)?;
+            // The value becomes a CEL string literal, where a command operator would not
+            // be expanded.
+            if hv.value.contains('%') {
+                return Err(DomainError::validation(format!(
+                    "{label}: header \"{}\" has a condition, so its value must be a literal \
+                     without '%'",
+                    hv.key
+                )));
+            }
+        }
     }
     Ok(())
 }
@@ -824,7 +1073,8 @@ impl JwtAuthConfig {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ExtAuthzConfig {
-    /// gRPC authorization service, by same-team cluster name.
+    /// Authorization service, by same-team cluster name. Called over gRPC unless
+    /// `http_service` is set, in which case it is the HTTP upstream.
     pub cluster: String,
     #[serde(default = "default_ext_authz_timeout_ms")]
     pub timeout_ms: u64,
@@ -833,6 +1083,63 @@ pub struct ExtAuthzConfig {
     pub failure_mode_allow: bool,
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub include_peer_certificate: bool,
+    /// Call a raw HTTP authorization service instead of the gRPC `Authorization` API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_service: Option<ExtAuthzHttpService>,
+}
+
+/// HTTP-mode ext_authz: the check is an HTTP request to `uri` on the filter's `cluster`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExtAuthzHttpService {
+    /// Full service URI; its path (if any) prefixes the forwarded request path.
+    pub uri: String,
+    /// Request headers forwarded to the service besides Envoy's defaults (`Host`,
+    /// `Method`, `Path`, `Content-Length`, `Authorization`). Matched case-insensitively.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_headers: Vec<String>,
+}
+
+const MAX_EXT_AUTHZ_URI_LEN: usize = 2048;
+const MAX_EXT_AUTHZ_ALLOWED_HEADERS: usize = 64;
+
+impl ExtAuthzHttpService {
+    /// The URI path (without a trailing `/`), sent as Envoy's `path_prefix`.
+    pub fn path_prefix(&self) -> &str {
+        let rest = self.uri.split_once("://").map_or("", |(_, r)| r);
+        rest.find('/')
+            .map_or("", |i| &rest[i..])
+            .trim_end_matches('/')
+    }
+
+    fn validate(&self) -> DomainResult<()> {
+        let host = self
+            .uri
+            .strip_prefix("https://")
+            .or_else(|| self.uri.strip_prefix("http://"));
+        if host.is_none_or(|h| h.is_empty() || h.starts_with('/')) {
+            return Err(DomainError::validation(
+                "ext_authz: http_service.uri must be an http(s) URI with a host",
+            ));
+        }
+        if self.uri.len() > MAX_EXT_AUTHZ_URI_LEN
+            || self
+                .uri
+                .chars()
+                .any(|c| c.is_control() || c == '?' || c == '#')
+        {
+            return Err(DomainError::validation(format!(
+                "ext_authz: http_service.uri must be at most {MAX_EXT_AUTHZ_URI_LEN} characters \
+                 with no query, fragment, or control characters"
+            )));
+        }
+        validate_bounded_string_list(
+            "ext_authz: http_service.allowed_headers",
+            &self.allowed_headers,
+            MAX_EXT_AUTHZ_ALLOWED_HEADERS,
+            MAX_HEADER_NAME_LEN,
+        )
+    }
 }
 
 fn default_ext_authz_timeout_ms() -> u64 {
@@ -847,6 +1154,74 @@ impl ExtAuthzConfig {
                 "ext_authz: timeout_ms must be 1..=60000",
             ));
         }
+        if let Some(http) = &self.http_service {
+            http.validate()?;
+        }
+        Ok(())
+    }
+}
+
+const MAX_EXT_AUTHZ_CONTEXT_EXTENSIONS: usize = 32;
+const MAX_EXT_AUTHZ_CONTEXT_KEY_LEN: usize = 256;
+const MAX_EXT_AUTHZ_CONTEXT_VALUE_LEN: usize = 4096;
+
+/// Per-scope ext_authz config (Envoy `ExtAuthzPerRoute`): exactly one of `disabled` or
+/// `check_settings`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExtAuthzOverride {
+    /// Skip the authorization check on this scope (e.g. health or metrics routes).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_settings: Option<ExtAuthzCheckSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExtAuthzCheckSettings {
+    /// Extra key/value context sent to the authorization service with each check.
+    /// BTreeMap: deterministic encoding.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub context_extensions: std::collections::BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disable_request_body_buffering: bool,
+}
+
+impl ExtAuthzOverride {
+    pub fn validate(&self) -> DomainResult<()> {
+        let Some(settings) = &self.check_settings else {
+            if self.disabled {
+                return Ok(());
+            }
+            return Err(DomainError::validation(
+                "ext_authz override: set disabled: true or check_settings",
+            ));
+        };
+        if self.disabled {
+            return Err(DomainError::validation(
+                "ext_authz override: disabled and check_settings are mutually exclusive",
+            ));
+        }
+        if settings.context_extensions.len() > MAX_EXT_AUTHZ_CONTEXT_EXTENSIONS {
+            return Err(DomainError::validation(format!(
+                "ext_authz override: at most {MAX_EXT_AUTHZ_CONTEXT_EXTENSIONS} context_extensions"
+            )));
+        }
+        for (key, value) in &settings.context_extensions {
+            if key.is_empty()
+                || key.len() > MAX_EXT_AUTHZ_CONTEXT_KEY_LEN
+                || value.len() > MAX_EXT_AUTHZ_CONTEXT_VALUE_LEN
+                || key.chars().chain(value.chars()).any(char::is_control)
+            {
+                return Err(DomainError::validation(format!(
+                    "ext_authz override: context extension \"{}\" must have a 1-{MAX_EXT_AUTHZ_CONTEXT_KEY_LEN} \
+                     character key and a value of at most {MAX_EXT_AUTHZ_CONTEXT_VALUE_LEN} characters, \
+                     without control characters",
+                    key.chars().filter(|c| !c.is_control()).take(64).collect::<String>()
+                )));
+            }
+        }
         Ok(())
     }
 }
@@ -972,6 +1347,333 @@ impl RbacConfig {
     }
 }
 
+// ---------------- grpc_json_transcoder ----------------
+
+const MAX_TRANSCODER_SERVICES: usize = 64;
+const MAX_PROTO_SERVICE_NAME_LEN: usize = 512;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GrpcJsonTranscoderConfig {
+    /// Uploaded descriptor set (`/proto-descriptors`) in the same team, by id.
+    #[schema(value_type = uuid::Uuid)]
+    pub proto_descriptor_id: crate::id::ProtoDescriptorId,
+    /// Fully-qualified `{package}.{Service}` names to transcode; each must be declared by
+    /// the referenced descriptor set (checked on listener write).
+    pub services: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_unknown_query_parameters: bool,
+    /// Map a gRPC status in trailers to a JSON error body with the matching HTTP status.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub convert_grpc_status: bool,
+}
+
+impl GrpcJsonTranscoderConfig {
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.services.is_empty() {
+            return Err(DomainError::validation(
+                "grpc_json_transcoder: services must list at least one gRPC service",
+            )
+            .with_hint("use the fully-qualified name, e.g. \"bookstore.Bookstore\""));
+        }
+        validate_bounded_string_list(
+            "grpc_json_transcoder: services",
+            &self.services,
+            MAX_TRANSCODER_SERVICES,
+            MAX_PROTO_SERVICE_NAME_LEN,
+        )?;
+        let mut seen = std::collections::HashSet::new();
+        for service in &self.services {
+            if !seen.insert(service) {
+                return Err(DomainError::validation(format!(
+                    "grpc_json_transcoder: service \"{service}\" is listed twice"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------- custom_response ----------------
+
+const MAX_CUSTOM_RESPONSE_MATCHERS: usize = 32;
+const MAX_CUSTOM_RESPONSE_BODY_LEN: usize = 4096;
+
+/// Upstream status codes a custom response applies to (tagged by `match`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "match", rename_all = "snake_case", deny_unknown_fields)]
+pub enum StatusCodeMatch {
+    Code {
+        code: u16,
+    },
+    /// Inclusive on both ends, e.g. `{500, 599}` for every 5xx.
+    Range {
+        start: u16,
+        end: u16,
+    },
+}
+
+impl StatusCodeMatch {
+    /// Inclusive `(start, end)` bounds; a single code is a one-code range.
+    pub fn bounds(self) -> (u16, u16) {
+        match self {
+            Self::Code { code } => (code, code),
+            Self::Range { start, end } => (start, end),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomResponseMatcher {
+    pub status: StatusCodeMatch,
+    /// Status sent to the client; the upstream's status when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    /// Replacement body (≤4096 bytes); the upstream body is discarded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response_headers_to_add: Vec<HeaderValue>,
+}
+
+/// Replace upstream responses by status with local ones (branded error pages, maintenance
+/// JSON). Matchers may not overlap, so which one applies never depends on list order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomResponseConfig {
+    pub matchers: Vec<CustomResponseMatcher>,
+}
+
+impl CustomResponseConfig {
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.matchers.is_empty() || self.matchers.len() > MAX_CUSTOM_RESPONSE_MATCHERS {
+            return Err(DomainError::validation(format!(
+                "custom_response: matchers must contain 1-{MAX_CUSTOM_RESPONSE_MATCHERS} entries"
+            )));
+        }
+        let mut ranges = Vec::with_capacity(self.matchers.len());
+        for matcher in &self.matchers {
+            let (start, end) = matcher.status.bounds();
+            if !(100..=599).contains(&start) || !(100..=599).contains(&end) {
+                return Err(DomainError::validation(
+                    "custom_response: status codes must be 100-599",
+                ));
+            }
+            if start > end {
+                return Err(DomainError::validation(format!(
+                    "custom_response: status range {start}-{end} has start after end"
+                )));
+            }
+            if matcher
+                .status_code
+                .is_some_and(|code| !(100..=599).contains(&code))
+            {
+                return Err(DomainError::validation(
+                    "custom_response: status_code must be 100-599",
+                ));
+            }
+            if matcher
+                .body
+                .as_ref()
+                .is_some_and(|body| body.len() > MAX_CUSTOM_RESPONSE_BODY_LEN)
+            {
+                return Err(DomainError::validation(format!(
+                    "custom_response: body must be <= {MAX_CUSTOM_RESPONSE_BODY_LEN} bytes"
+                )));
+            }
+            validate_header_values(
+                "custom_response: response_headers_to_add",
+                &matcher.response_headers_to_add,
+            )?;
+            if matcher
+                .response_headers_to_add
+                .iter()
+                .any(|hv| hv.when.is_some())
+            {
+                return Err(DomainError::validation(
+                    "custom_response: response_headers_to_add does not take a when condition",
+                )
+                .with_hint("the matcher's status already selects the response"));
+            }
+            ranges.push((start, end));
+        }
+        ranges.sort_unstable();
+        for pair in ranges.windows(2) {
+            let ((a_start, a_end), (b_start, b_end)) = (pair[0], pair[1]);
+            if b_start <= a_end {
+                return Err(DomainError::validation(format!(
+                    "custom_response: status ranges {a_start}-{a_end} and {b_start}-{b_end} overlap"
+                ))
+                .with_hint("each upstream status may be matched by at most one matcher"));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------- wasm ----------------
+
+/// Upper bound on an inline module once decoded; base64 keeps the request under the API's
+/// default 2 MiB body limit.
+const MAX_WASM_INLINE_BYTES: usize = 1_048_576;
+const MAX_WASM_CONFIG_LEN: usize = 65_536;
+const MAX_WASM_ROOT_ID_LEN: usize = 128;
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// The proxy-wasm engine Envoy runs the module in. The official Envoy build ships only V8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WasmRuntime {
+    #[default]
+    V8,
+    Wasmtime,
+}
+
+impl WasmRuntime {
+    /// Envoy's registered extension name for the runtime.
+    pub fn envoy_name(self) -> &'static str {
+        match self {
+            Self::V8 => "envoy.wasm.runtime.v8",
+            Self::Wasmtime => "envoy.wasm.runtime.wasmtime",
+        }
+    }
+}
+
+/// Where the module's bytecode comes from. Remote sources, like remote JWKS, name a
+/// same-team cluster the proxy fetches through; Envoy refuses a fetched module whose digest
+/// differs from `sha256`. Registry sources name an uploaded module version
+/// (`/wasm-modules`), which the CP inlines into the listener.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "source", rename_all = "snake_case", deny_unknown_fields)]
+pub enum WasmModuleSource {
+    Inline {
+        /// The compiled module, base64 (standard alphabet), at most 1 MiB decoded.
+        wasm_base64: String,
+    },
+    Remote {
+        /// Full module URI, e.g. `https://artifacts.example/add-header.wasm`.
+        uri: String,
+        /// Cluster (same team) used to reach the module host.
+        cluster: String,
+        /// Lowercase hex SHA-256 of the module.
+        sha256: String,
+        #[serde(default = "default_wasm_fetch_timeout_ms")]
+        timeout_ms: u64,
+    },
+    Registry {
+        /// Module name in the same team (checked on listener write).
+        name: String,
+        version: i64,
+    },
+}
+
+fn default_wasm_fetch_timeout_ms() -> u64 {
+    5000
+}
+
+/// A proxy-wasm HTTP filter (`envoy.filters.http.wasm`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WasmConfig {
+    pub module: WasmModuleSource,
+    #[serde(default)]
+    pub runtime: WasmRuntime,
+    /// Plugin configuration handed to the module's `on_configure` verbatim (often JSON).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+    /// Root context the module registers; empty selects its default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_id: Option<String>,
+    /// Let requests through while the module is failing instead of answering 503.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fail_open: bool,
+}
+
+impl WasmConfig {
+    pub fn validate(&self) -> DomainResult<()> {
+        match &self.module {
+            WasmModuleSource::Inline { wasm_base64 } => {
+                let module =
+                    base64::Engine::decode(&base64::engine::general_purpose::STANDARD, wasm_base64)
+                        .map_err(|_| {
+                            DomainError::validation("wasm: wasm_base64 is not valid base64")
+                        })?;
+                if module.len() > MAX_WASM_INLINE_BYTES {
+                    return Err(DomainError::validation(format!(
+                        "wasm: inline module must be <= {MAX_WASM_INLINE_BYTES} bytes"
+                    ))
+                    .with_hint("serve larger modules from a remote source"));
+                }
+                if !module.starts_with(WASM_MAGIC) {
+                    return Err(DomainError::validation(
+                        "wasm: inline module is not WebAssembly (missing \\0asm header)",
+                    ));
+                }
+            }
+            WasmModuleSource::Remote {
+                uri,
+                cluster,
+                sha256,
+                timeout_ms,
+            } => {
+                if !uri.starts_with("https://") && !uri.starts_with("http://") {
+                    return Err(DomainError::validation("wasm: module uri must be http(s)"));
+                }
+                crate::identity::validate_name(cluster)?;
+                if sha256.len() != 64
+                    || !sha256
+                        .bytes()
+                        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+                {
+                    return Err(DomainError::validation(
+                        "wasm: sha256 must be 64 lowercase hex characters",
+                    )
+                    .with_hint("e.g. the output of `sha256sum module.wasm`"));
+                }
+                if *timeout_ms == 0 || *timeout_ms > 60_000 {
+                    return Err(DomainError::validation(
+                        "wasm: timeout_ms must be 1..=60000",
+                    ));
+                }
+            }
+            WasmModuleSource::Registry { name, version } => {
+                crate::identity::validate_name(name)?;
+                if *version < 1 {
+                    return Err(DomainError::validation("wasm: module version must be >= 1"));
+                }
+            }
+        }
+        if self
+            .config
+            .as_ref()
+            .is_some_and(|config| config.len() > MAX_WASM_CONFIG_LEN)
+        {
+            return Err(DomainError::validation(format!(
+                "wasm: config must be <= {MAX_WASM_CONFIG_LEN} bytes"
+            )));
+        }
+        if let Some(root_id) = &self.root_id {
+            if root_id.is_empty()
+                || root_id.len() > MAX_WASM_ROOT_ID_LEN
+                || root_id.chars().any(char::is_control)
+            {
+                return Err(DomainError::validation(format!(
+                    "wasm: root_id must be 1..={MAX_WASM_ROOT_ID_LEN} characters without control characters"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `{"type": "preset", "name": ...}` chain entry: instantiate the named team preset here.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FilterPresetRef {
+    pub name: String,
+}
+
 fn valid_cidr(cidr: &str) -> bool {
     let Some((ip, len)) = cidr.split_once('/') else {
         return false;
@@ -986,8 +1688,19 @@ fn valid_cidr(cidr: &str) -> bool {
     }
 }
 
+/// Pairs `(earlier, later)` that misbehave when `later` runs first: an authenticating or
+/// authorizing filter rejects CORS preflights (which carry no credentials), and ext_authz /
+/// rbac decide before the JWT they may depend on has been verified.
+const REQUIRED_ORDER: [(HttpFilterKind, HttpFilterKind); 5] = [
+    (HttpFilterKind::Cors, HttpFilterKind::JwtAuth),
+    (HttpFilterKind::Cors, HttpFilterKind::ExtAuthz),
+    (HttpFilterKind::Cors, HttpFilterKind::Rbac),
+    (HttpFilterKind::JwtAuth, HttpFilterKind::ExtAuthz),
+    (HttpFilterKind::JwtAuth, HttpFilterKind::Rbac),
+];
+
 /// Validate a whole chain: per-filter rules plus chain-level invariants (one filter of
-/// each type — duplicates make per-scope overrides ambiguous).
+/// each type — duplicates make per-scope overrides ambiguous — and no known-bad ordering).
 pub fn validate_filter_chain(entries: &[HttpFilterEntry]) -> DomainResult<()> {
     let mut seen = std::collections::HashSet::new();
     for entry in entries {
@@ -1000,9 +1713,166 @@ pub fn validate_filter_chain(entries: &[HttpFilterEntry]) -> DomainResult<()> {
             .with_hint("each filter type may appear at most once per listener"));
         }
     }
+    validate_filter_order(entries)
+}
+
+fn validate_filter_order(entries: &[HttpFilterEntry]) -> DomainResult<()> {
+    let position = |kind: HttpFilterKind| {
+        entries
+            .iter()
+            .position(|entry| entry.filter.kind_value() == kind)
+    };
+    for (earlier, later) in REQUIRED_ORDER {
+        if let (Some(e), Some(l)) = (position(earlier), position(later)) {
+            if l < e {
+                return Err(DomainError::validation(format!(
+                    "filter \"{}\" must come before \"{}\" in the chain",
+                    earlier.as_str(),
+                    later.as_str()
+                ))
+                .with_hint("reorder http_filters, or create the listener with ?auto_order=true"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Stable-sort a chain into the recommended order (the router stays implicit and last).
+/// Filters of equal rank cannot occur (types are unique), so the result is deterministic.
+pub fn auto_order_filter_chain(entries: &mut [HttpFilterEntry]) {
+    entries.sort_by_key(|entry| entry.filter.kind_value().canonical_rank());
+}
+
+/// An org's default listener filters must form a valid chain on their own and may not use
+/// kinds that name team-owned resources (an authz cluster, an RLS domain, a descriptor set,
+/// a wasm module or its host cluster, a JWKS host cluster, a preset) — a brand-new team has
+/// none of them.
+pub fn validate_org_default_filters(entries: &[HttpFilterEntry]) -> DomainResult<()> {
+    validate_filter_chain(entries)?;
+    for entry in entries {
+        let team_owned = match &entry.filter {
+            HttpFilterSpec::ExtAuthz(_)
+            | HttpFilterSpec::GlobalRateLimit(_)
+            | HttpFilterSpec::GrpcJsonTranscoder(_)
+            | HttpFilterSpec::Wasm(WasmConfig {
+                module: WasmModuleSource::Remote { .. } | WasmModuleSource::Registry { .. },
+                ..
+            }) => true,
+            HttpFilterSpec::JwtAuth(config) => config
+                .providers
+                .values()
+                .any(|provider| matches!(provider.jwks, JwksSource::Remote { .. })),
+            _ => false,
+        };
+        if team_owned {
+            return Err(DomainError::validation(format!(
+                "filter \"{}\" references team resources and cannot be an org default",
+                entry.filter.kind()
+            ))
+            .with_hint("org defaults may use cors, local_rate_limit, header_mutation, health_check, compressor, jwt_auth with inline JWKS, rbac, custom_response, or wasm with an inline module"));
+        }
+    }
     Ok(())
 }
 
+/// Add each default whose type the chain lacks, just before the first existing entry that
+/// ranks after it in the recommended order — the team's own entries keep their relative
+/// order, and a filter the team already configured wins over the org default. Returns how
+/// many defaults were added.
+pub fn merge_default_filters(
+    chain: &mut Vec<HttpFilterEntry>,
+    defaults: &[HttpFilterEntry],
+) -> usize {
+    let mut added = 0;
+    for default in defaults {
+        let kind = default.filter.kind_value();
+        if chain.iter().any(|entry| entry.filter.kind_value() == kind) {
+            continue;
+        }
+        let at = chain
+            .iter()
+            .position(|entry| entry.filter.kind_value().canonical_rank() > kind.canonical_rank())
+            .unwrap_or(chain.len());
+        chain.insert(at, default.clone());
+        added += 1;
+    }
+    added
+}
+
+/// The scope whose configuration decides how a chain filter behaves on one route. Envoy
+/// applies the most specific per-filter config and does not merge scopes: a route-level
+/// override replaces the virtual host's, which replaces the listener chain entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterScope {
+    Listener,
+    VirtualHost,
+    Route,
+}
+
+/// One chain filter as it applies to a single route.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct EffectiveFilter {
+    pub filter_type: String,
+    /// False when the chain entry is `disabled` or the winning override is `disable`.
+    pub enabled: bool,
+    pub source: FilterScope,
+    /// The listener chain entry's configuration.
+    pub filter: HttpFilterSpec,
+    /// The override that wins on this route, when a vhost or route one targets the filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_override: Option<FilterOverride>,
+}
+
+/// Resolve the filters a route runs, in chain order (the router, always last, is implicit).
+/// Returns the effective filters plus the vhost/route overrides that target a filter type
+/// the chain lacks — Envoy ignores those, which is usually a configuration mistake.
+pub fn effective_filters(
+    chain: &[HttpFilterEntry],
+    vhost_overrides: &[FilterOverride],
+    route_overrides: &[FilterOverride],
+) -> (Vec<EffectiveFilter>, Vec<FilterOverride>) {
+    let targeting = |overrides: &[FilterOverride], kind: &str| {
+        overrides
+            .iter()
+            .find(|ov| ov.target_kind().is_ok_and(|target| target == kind))
+            .cloned()
+    };
+    let filters = chain
+        .iter()
+        .map(|entry| {
+            let kind = entry.filter.kind();
+            let (source, applied_override) = match targeting(route_overrides, kind) {
+                Some(ov) => (FilterScope::Route, Some(ov)),
+                None => match targeting(vhost_overrides, kind) {
+                    Some(ov) => (FilterScope::VirtualHost, Some(ov)),
+                    None => (FilterScope::Listener, None),
+                },
+            };
+            // A disabled chain entry stays off: overrides can disable a filter, never enable it.
+            let enabled = !entry.disabled
+                && !matches!(applied_override, Some(FilterOverride::Disable { .. }));
+            EffectiveFilter {
+                filter_type: kind.to_string(),
+                enabled,
+                source,
+                filter: entry.filter.clone(),
+                applied_override,
+            }
+        })
+        .collect();
+    let unmatched = vhost_overrides
+        .iter()
+        .chain(route_overrides)
+        .filter(|ov| {
+            !ov.target_kind()
+                .is_ok_and(|target| chain.iter().any(|entry| entry.filter.kind() == target))
+        })
+        .cloned()
+        .collect();
+    (filters, unmatched)
+}
+
 #[cfg(test)]
 #[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -1044,7 +1914,10 @@ mod tests {
             max_age_seconds: None,
             allow_credentials: true,
         };
-        assert!(bad.validate().is_err());
+        let err = bad.validate().expect_err("wildcard + credentials");
+        assert_eq!(err.code, crate::ErrorCode::ValidationFailed);
+        assert!(err.message.contains("browsers reject"), "{}", err.message);
+        assert!(err.hint.is_some());
 
         let bad = LocalRateLimitConfig {
             stat_prefix: "x".into(),
@@ -1054,6 +1927,7 @@ mod tests {
                 fill_interval_ms: 0,
             },
             status_code: None,
+            descriptors: Vec::new(),
         };
         assert!(bad.validate().is_err());
 
@@ -1065,6 +1939,7 @@ mod tests {
                 fill_interval_ms: 1000,
             },
             status_code: Some(200),
+            descriptors: Vec::new(),
         };
         assert!(bad.validate().is_err(), "2xx rate-limit status");
 
@@ -1128,6 +2003,20 @@ mod tests {
         assert!(bad.validate().is_err(), "too many header entries");
     }
 
+    #[test]
+    fn cors_origin_regex_must_compile() {
+        let mut cors: CorsConfig = serde_json::from_value(serde_json::json!({
+            "allow_origin": [{"match": "safe_regex", "regex": "https://.*\\.example\\.com"}]
+        }))
+        .expect("cors");
+        assert!(cors.validate().is_ok(), "{:?}", cors.validate());
+
+        for bad in ["https://(unclosed", ""] {
+            cors.allow_origin = vec![OriginMatcher::SafeRegex { regex: bad.into() }];
+            assert!(cors.validate().is_err(), "{bad:?} rejected");
+        }
+    }
+
     #[test]
     fn header_mutation_rejects_unbounded_lists_and_values() {
         let too_many = HeaderMutationConfig {
@@ -1136,6 +2025,7 @@ mod tests {
                     key: format!("x-header-{i}"),
                     value: "ok".into(),
                     append: false,
+                    when: None,
                 })
                 .collect(),
             request_headers_to_remove: vec![],
@@ -1149,6 +2039,7 @@ mod tests {
                 key: "x-test".into(),
                 value: "bad\nvalue".into(),
                 append: false,
+                when: None,
             }],
             request_headers_to_remove: vec![],
             response_headers_to_add: vec![],
@@ -1168,6 +2059,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn conditional_headers_are_literal_and_response_codes_are_response_only() {
+        let mut config: HeaderMutationConfig = serde_json::from_value(serde_json::json!({
+            "response_headers_to_add": [{
+                "key": "cache-control",
+                "value": "public, max-age=60",
+                "when": {"response_code": {"match": "code", "code": 200}, "request_header_present": "x-cacheable"}
+            }],
+            "request_headers_to_add": [{
+                "key": "x-debug", "value": "1", "when": {"request_header_present": "x-trace"}
+            }]
+        }))
+        .expect("header mutation");
+        assert!(config.validate().is_ok(), "{:?}", config.validate());
+
+        let ok = config.clone();
+        config.request_headers_to_add[0].when = Some(HeaderCondition {
+            response_code: Some(StatusCodeMatch::Code { code: 200 }),
+            request_header_present: None,
+        });
+        assert!(
+            config.validate().is_err(),
+            "request side has no response code"
+        );
+
+        for when in [
+            HeaderCondition {
+                response_code: None,
+                request_header_present: None,
+            },
+            HeaderCondition {
+                response_code: Some(StatusCodeMatch::Range {
+                    start: 299,
+                    end: 200,
+                }),
+                request_header_present: None,
+            },
+            HeaderCondition {
+                response_code: None,
+                request_header_present: Some("x-'quote".into()),
+            },
+        ] {
+            let mut bad = ok.clone();
+            bad.response_headers_to_add[0].when = Some(when.clone());
+            assert!(bad.validate().is_err(), "{when:?} rejected");
+        }
+
+        let mut formatted = ok;
+        formatted.response_headers_to_add[0].value = "%RESPONSE_CODE%".into();
+        assert!(
+            formatted.validate().is_err(),
+            "conditional values are literal"
+        );
+    }
+
     #[test]
     fn duplicate_filter_types_rejected() {
         let chain = vec![
@@ -1183,6 +2129,45 @@ mod tests {
         assert!(validate_filter_chain(&chain).is_err());
     }
 
+    #[test]
+    fn effective_filters_prefer_the_most_specific_scope() {
+        let route_cors = match cors() {
+            HttpFilterSpec::Cors(c) => FilterOverride::Cors(c),
+            _ => unreachable!(),
+        };
+        let chain: Vec<HttpFilterEntry> = serde_json::from_value(serde_json::json!([
+            {"filter": {"type": "cors", "allow_origin": [{"match": "exact", "value": "https://app.example"}]}},
+            {"filter": {"type": "local_rate_limit", "stat_prefix": "lrl",
+                "token_bucket": {"max_tokens": 10, "fill_interval_ms": 1000}}},
+            {"filter": {"type": "health_check", "endpoint_path": "/healthz"}, "disabled": true},
+        ]))
+        .expect("chain");
+        let disable = |filter_type: &str| FilterOverride::Disable {
+            filter_type: filter_type.into(),
+        };
+        let jwt = FilterOverride::JwtAuth {
+            requirement_name: "strict".into(),
+        };
+        let vhost = vec![disable("cors"), disable("local_rate_limit"), jwt.clone()];
+        let route = vec![route_cors.clone()];
+
+        let (filters, unmatched) = effective_filters(&chain, &vhost, &route);
+        let summary: Vec<_> = filters
+            .iter()
+            .map(|f| (f.filter_type.as_str(), f.enabled, f.source))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("cors", true, FilterScope::Route),
+                ("local_rate_limit", false, FilterScope::VirtualHost),
+                ("health_check", false, FilterScope::Listener),
+            ]
+        );
+        assert_eq!(filters[0].applied_override, Some(route_cors));
+        assert_eq!(unmatched, [jwt], "no jwt_auth filter in the chain");
+    }
+
     #[test]
     fn jwt_auth_validates_providers_requirements_and_rules() {
         use std::collections::BTreeMap;
@@ -1266,6 +2251,7 @@ mod tests {
             timeout_ms: 200,
             failure_mode_allow: false,
             include_peer_certificate: true,
+            http_service: None,
         }
         .validate()
         .is_ok());
@@ -1274,10 +2260,46 @@ mod tests {
             timeout_ms: 0,
             failure_mode_allow: false,
             include_peer_certificate: false,
+            http_service: None,
         }
         .validate()
         .is_err());
 
+        let http = |uri: &str, allowed_headers: Vec<String>| ExtAuthzConfig {
+            cluster: "authz".into(),
+            timeout_ms: 200,
+            failure_mode_allow: true,
+            include_peer_certificate: false,
+            http_service: Some(ExtAuthzHttpService {
+                uri: uri.into(),
+                allowed_headers,
+            }),
+        };
+        let ok = http("http://authz.internal:9000/check/", vec!["x-user".into()]);
+        assert!(ok.validate().is_ok());
+        assert_eq!(
+            ok.http_service
+                .as_ref()
+                .map(ExtAuthzHttpService::path_prefix),
+            Some("/check")
+        );
+        assert_eq!(
+            http("https://authz", vec![])
+                .http_service
+                .unwrap()
+                .path_prefix(),
+            ""
+        );
+        for bad in [
+            http("grpc://authz", vec![]),
+            http("http:///check", vec![]),
+            http("http://authz/check?x=1", vec![]),
+            http("http://authz", vec![String::new()]),
+            http("http://authz", vec!["x-h".into(); 65]),
+        ] {
+            assert!(bad.validate().is_err(), "{bad:?} must be rejected");
+        }
+
         let mut policies = std::collections::BTreeMap::new();
         policies.insert(
             "admins".to_string(),
@@ -1456,6 +2478,29 @@ mod tests {
     fn empty_domain_fails() {
         let cfg = grl(String::new(), "rls-cluster".into());
         assert!(cfg.validate().is_err(), "empty domain must be rejected");
+        let cfg = grl("   ".into(), "rls-cluster".into());
+        assert!(cfg.validate().is_err(), "blank domain must be rejected");
+    }
+
+    #[test]
+    fn stat_prefix_defaults_to_the_sanitized_base_domain() {
+        let composed = format!(
+            "{}|{}|api.orders",
+            uuid::Uuid::now_v7(),
+            uuid::Uuid::now_v7()
+        );
+        let cfg = grl(composed, RESERVED_RATE_LIMIT_CLUSTER.into());
+        assert_eq!(cfg.effective_stat_prefix(), "api_orders");
+
+        // A user-owned domain that merely contains '|' is not mistaken for a composed one.
+        let cfg = grl("edge|v2".into(), "rls-cluster".into());
+        assert_eq!(cfg.effective_stat_prefix(), "edge_v2");
+
+        let cfg = GlobalRateLimitConfig {
+            stat_prefix: Some("edge_rls".into()),
+            ..cfg
+        };
+        assert_eq!(cfg.effective_stat_prefix(), "edge_rls", "explicit wins");
     }
 
     #[test]
@@ -1499,4 +2544,312 @@ mod tests {
         let ok = grl("edge".into(), "team-rls".into());
         assert!(ok.validate().is_ok());
     }
+
+    #[test]
+    fn chain_order_rules_and_auto_order() {
+        let rbac = HttpFilterSpec::Rbac(RbacConfig {
+            action: RbacAction::Allow,
+            policies: std::collections::BTreeMap::from([(
+                "office".to_string(),
+                RbacPolicy {
+                    permissions: vec![RbacPermission::Any],
+                    principals: vec![RbacPrincipal::SourceCidr {
+                        cidr: "10.0.0.0/8".into(),
+                    }],
+                },
+            )]),
+        });
+        let health = HttpFilterSpec::HealthCheck(HealthCheckConfig {
+            endpoint_path: "/healthz".into(),
+            pass_through_mode: false,
+            cache_time_ms: None,
+        });
+        let entry = |filter: &HttpFilterSpec| HttpFilterEntry {
+            filter: filter.clone(),
+            disabled: false,
+        };
+
+        let mut chain = vec![entry(&rbac), entry(&cors()), entry(&health)];
+        let err = validate_filter_chain(&chain).expect_err("rbac before cors");
+        assert_eq!(
+            err.message,
+            "filter \"cors\" must come before \"rbac\" in the chain"
+        );
+
+        auto_order_filter_chain(&mut chain);
+        let kinds: Vec<_> = chain.iter().map(|e| e.filter.kind()).collect();
+        assert_eq!(kinds, ["health_check", "cors", "rbac"]);
+        assert!(validate_filter_chain(&chain).is_ok());
+    }
+
+    #[test]
+    fn wasm_modules_are_checked_before_they_reach_envoy() {
+        let b64 = |bytes: &[u8]| {
+            base64::Engine::encode(&base64::engine::general_purpose::STANDARD, bytes)
+        };
+        let mut config: WasmConfig = serde_json::from_value(serde_json::json!({
+            "module": {"source": "inline", "wasm_base64": b64(b"\0asm\x01\0\0\0")},
+            "config": "{\"header\": \"x-wasm\"}"
+        }))
+        .unwrap();
+        assert_eq!(config.runtime, WasmRuntime::V8);
+        config.validate().expect("inline module validates");
+
+        config.module = WasmModuleSource::Inline {
+            wasm_base64: b64(b"not wasm"),
+        };
+        let err = config.validate().expect_err("missing magic");
+        assert!(err.message.contains("not WebAssembly"), "{}", err.message);
+
+        config.module = WasmModuleSource::Remote {
+            uri: "https://artifacts.example/add-header.wasm".into(),
+            cluster: "artifacts".into(),
+            sha256: "ab".repeat(32),
+            timeout_ms: 5000,
+        };
+        config.validate().expect("remote module validates");
+        let WasmModuleSource::Remote { sha256, .. } = &mut config.module else {
+            unreachable!()
+        };
+        *sha256 = "AB".repeat(32);
+        let err = config.validate().expect_err("uppercase digest");
+        assert!(err.message.contains("sha256"), "{}", err.message);
+
+        let remote = HttpFilterEntry {
+            filter: HttpFilterSpec::Wasm(config),
+            disabled: false,
+        };
+        assert!(
+            validate_org_default_filters(&[remote]).is_err(),
+            "a remote module names a team cluster"
+        );
+    }
+
+    #[test]
+    fn custom_response_matchers_are_bounded_and_disjoint() {
+        let matcher = |status: StatusCodeMatch| CustomResponseMatcher {
+            status,
+            status_code: None,
+            body: Some("{}".into()),
+            response_headers_to_add: Vec::new(),
+        };
+        let mut config = CustomResponseConfig {
+            matchers: vec![
+                matcher(StatusCodeMatch::Range {
+                    start: 500,
+                    end: 599,
+                }),
+                matcher(StatusCodeMatch::Code { code: 404 }),
+            ],
+        };
+        config.validate().expect("5xx and 404 are disjoint");
+        let json = serde_json::to_value(config.matchers[1].status).unwrap();
+        assert_eq!(json, serde_json::json!({"match": "code", "code": 404}));
+
+        config
+            .matchers
+            .push(matcher(StatusCodeMatch::Code { code: 503 }));
+        let err = config.validate().expect_err("503 is inside 5xx");
+        assert!(
+            err.message.contains("500-599 and 503-503 overlap"),
+            "{}",
+            err.message
+        );
+
+        config.matchers.pop();
+        config.matchers.push(matcher(StatusCodeMatch::Range {
+            start: 420,
+            end: 410,
+        }));
+        assert!(config.validate().is_err(), "inverted range");
+
+        config.matchers.pop();
+        config.matchers[1].status_code = Some(700);
+        assert!(config.validate().is_err(), "status_code out of range");
+
+        assert!(CustomResponseConfig {
+            matchers: Vec::new()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn local_rate_limit_descriptors_each_need_a_bucket() {
+        let mut config: LocalRateLimitConfig = serde_json::from_value(serde_json::json!({
+            "stat_prefix": "edge",
+            "token_bucket": {"max_tokens": 100, "fill_interval_ms": 1000},
+            "descriptors": [
+                {
+                    "entries": [{"key": "client_id", "value": "batch"}],
+                    "token_bucket": {"max_tokens": 5, "fill_interval_ms": 60000}
+                },
+                {"entries": [{"key": "client_id", "value": "web"}]}
+            ]
+        }))
+        .expect("descriptors deserialize");
+        let err = config
+            .validate()
+            .expect_err("second descriptor has no bucket");
+        assert_eq!(
+            err.message,
+            "local_rate_limit: descriptors[1] has no token_bucket"
+        );
+        assert!(err.hint.is_some());
+
+        config.descriptors[1].token_bucket = Some(TokenBucket {
+            max_tokens: 0,
+            tokens_per_fill: None,
+            fill_interval_ms: 1000,
+        });
+        let err = config.validate().expect_err("empty bucket");
+        assert!(err
+            .message
+            .contains("descriptors[1].token_bucket.max_tokens"));
+
+        config.descriptors[1].token_bucket = config.descriptors[0].token_bucket.clone();
+        config
+            .validate()
+            .expect("distinct descriptors with buckets");
+
+        config.descriptors[1].entries = config.descriptors[0].entries.clone();
+        assert!(config.validate().is_err(), "duplicate entry set");
+
+        config.descriptors[1].entries.clear();
+        assert!(config.validate().is_err(), "descriptor without entries");
+
+        config.descriptors.truncate(1);
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(json["descriptors"][0]["token_bucket"].is_object());
+        config.descriptors.clear();
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(json.get("descriptors").is_none(), "empty list is omitted");
+    }
+
+    #[test]
+    fn org_defaults_merge_by_rank_and_never_replace_team_filters() {
+        let entry = |filter: HttpFilterSpec| HttpFilterEntry {
+            filter,
+            disabled: false,
+        };
+        let sanitize = entry(HttpFilterSpec::HeaderMutation(HeaderMutationConfig {
+            request_headers_to_add: vec![],
+            request_headers_to_remove: vec!["x-internal-debug".into()],
+            response_headers_to_add: vec![],
+            response_headers_to_remove: vec!["server".into()],
+        }));
+        let health = entry(HttpFilterSpec::HealthCheck(HealthCheckConfig {
+            endpoint_path: "/healthz".into(),
+            pass_through_mode: false,
+            cache_time_ms: None,
+        }));
+        let defaults = vec![sanitize.clone(), entry(cors())];
+        validate_org_default_filters(&defaults).expect("org-neutral kinds");
+
+        let team_cors = entry(HttpFilterSpec::Cors(CorsConfig {
+            allow_origin: vec![OriginMatcher::Exact {
+                value: "https://team.example".into(),
+            }],
+            allow_methods: vec!["POST".into()],
+            allow_headers: vec![],
+            expose_headers: vec![],
+            max_age_seconds: None,
+            allow_credentials: false,
+        }));
+        let mut chain = vec![health, team_cors.clone()];
+        assert_eq!(merge_default_filters(&mut chain, &defaults), 1);
+        let kinds: Vec<_> = chain.iter().map(|e| e.filter.kind()).collect();
+        assert_eq!(kinds, ["health_check", "cors", "header_mutation"]);
+        assert_eq!(chain[1], team_cors, "the team's own cors wins");
+        validate_filter_chain(&chain).expect("merged chain is valid");
+
+        let err = validate_org_default_filters(&[entry(HttpFilterSpec::Preset(FilterPresetRef {
+            name: "standard".into(),
+        }))])
+        .expect_err("presets are team-owned");
+        assert!(err.message.contains("preset"));
+
+        let jwt = |jwks: JwksSource| {
+            entry(HttpFilterSpec::JwtAuth(JwtAuthConfig {
+                providers: std::collections::BTreeMap::from([(
+                    "idp".to_string(),
+                    JwtProvider {
+                        issuer: Some("https://issuer.example".into()),
+                        audiences: Vec::new(),
+                        jwks,
+                        clock_skew_seconds: 60,
+                        forward: false,
+                    },
+                )]),
+                requirement_map: std::collections::BTreeMap::new(),
+                rules: Vec::new(),
+                bypass_cors_preflight: false,
+            }))
+        };
+        let err = validate_org_default_filters(&[jwt(JwksSource::Remote {
+            uri: "https://issuer.example/jwks".into(),
+            cluster: "jwks".into(),
+            timeout_ms: 5000,
+            cache_duration_secs: None,
+        })])
+        .expect_err("a remote JWKS names a team cluster");
+        assert!(err.message.contains("jwt_auth"));
+        validate_org_default_filters(&[jwt(JwksSource::Inline {
+            jwks: r#"{"keys":[]}"#.into(),
+        })])
+        .expect("inline JWKS needs nothing from the team");
+    }
+
+    #[test]
+    fn ext_authz_override_round_trips_and_is_exclusive() {
+        let disable: FilterOverride =
+            serde_json::from_value(serde_json::json!({"type": "ext_authz", "disabled": true}))
+                .expect("deserialize");
+        assert_eq!(
+            disable,
+            FilterOverride::ExtAuthz(ExtAuthzOverride {
+                disabled: true,
+                check_settings: None,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&disable).expect("serialize"),
+            serde_json::json!({"type": "ext_authz", "disabled": true})
+        );
+        assert!(disable.validate().is_ok());
+        assert_eq!(disable.target_kind().expect("kind"), "ext_authz");
+
+        let settings = ExtAuthzCheckSettings {
+            context_extensions: std::collections::BTreeMap::from([(
+                "route".to_string(),
+                "metrics".to_string(),
+            )]),
+            disable_request_body_buffering: false,
+        };
+        let both = FilterOverride::ExtAuthz(ExtAuthzOverride {
+            disabled: true,
+            check_settings: Some(settings.clone()),
+        });
+        assert!(both.validate().is_err(), "disabled and check_settings");
+        let neither = FilterOverride::ExtAuthz(ExtAuthzOverride {
+            disabled: false,
+            check_settings: None,
+        });
+        assert!(neither.validate().is_err(), "empty override");
+        assert!(FilterOverride::ExtAuthz(ExtAuthzOverride {
+            disabled: false,
+            check_settings: Some(settings),
+        })
+        .validate()
+        .is_ok());
+
+        // One override per filter type per scope, whichever form targets it.
+        assert!(validate_filter_overrides(&[
+            disable,
+            FilterOverride::Disable {
+                filter_type: "ext_authz".into(),
+            },
+        ])
+        .is_err());
+    }
 }
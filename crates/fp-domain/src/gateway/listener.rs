@@ -14,11 +14,62 @@ pub struct Listener {
     pub team_id: TeamId,
     pub name: String,
     pub spec: ListenerSpec,
+    /// Set while the listener is in maintenance mode; kept apart from the spec so that
+    /// turning maintenance off restores the listener exactly.
+    pub maintenance: Option<ListenerMaintenance>,
     pub version: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Maintenance mode for a listener: every request it accepts gets this direct response
+/// instead of its route config. The filter chain still runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListenerMaintenance {
+    /// 500–599; 503 when omitted.
+    #[serde(default = "default_maintenance_status")]
+    pub status: u16,
+    /// Response body, at most 4096 bytes.
+    #[serde(default = "default_maintenance_body")]
+    pub body: String,
+}
+
+fn default_maintenance_status() -> u16 {
+    503
+}
+
+fn default_maintenance_body() -> String {
+    "Service temporarily unavailable for maintenance.\n".into()
+}
+
+impl Default for ListenerMaintenance {
+    fn default() -> Self {
+        Self {
+            status: default_maintenance_status(),
+            body: default_maintenance_body(),
+        }
+    }
+}
+
+impl ListenerMaintenance {
+    pub fn validate(&self) -> DomainResult<()> {
+        if !(500..=599).contains(&self.status) {
+            return Err(DomainError::validation(format!(
+                "maintenance status must be 500-599, got {}",
+                self.status
+            ))
+            .with_hint("503 tells clients and load balancers to retry later"));
+        }
+        if self.body.len() > 4096 {
+            return Err(DomainError::validation(
+                "maintenance body must be <= 4096 bytes",
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ListenerSpec {
@@ -30,8 +81,9 @@ pub struct ListenerSpec {
     /// This is product metadata for invocation descriptors, not an Envoy bind address.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub public_base_url: Option<String>,
-    /// Downstream HTTP protocol mode. `https` is HTTP over downstream TLS; TCP listener support
-    /// remains intentionally deferred until V2 has a first-class TCP route action.
+    /// Downstream HTTP protocol mode. `https` is HTTP over downstream TLS and `http3` is HTTP/3
+    /// over QUIC on a UDP socket; TCP listener support remains intentionally deferred until V2
+    /// has a first-class TCP route action.
     #[serde(default, skip_serializing_if = "is_default_listener_protocol")]
     pub protocol: ListenerProtocol,
     /// Route configuration served by this listener, by name (same team). Optional until
@@ -48,16 +100,316 @@ pub struct ListenerSpec {
     /// may be inline file paths or SDS secret names delivered over ADS.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tls_context: Option<ListenerTlsConfig>,
+    /// L4 filters, in order. The HTTP connection manager is always appended after them, so
+    /// each one acts on the connection before any HTTP parsing.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub network_filters: Vec<NetworkFilterSpec>,
+    /// Request tracing from the HTTP connection manager. Without it the listener does not
+    /// start traces (the bootstrap may still configure a tracer).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracing: Option<ListenerTracingConfig>,
+    /// Listener filters, in order. They run on each accepted connection before any network
+    /// filter, e.g. to read the PROXY protocol header a load balancer prepends.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub listener_filters: Vec<ListenerFilterSpec>,
+    /// Downstream request and connection limits on the HTTP connection manager. Unset limits
+    /// keep Envoy's defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_limits: Option<HttpLimits>,
+    /// Hand each accepted connection to the worker thread with the fewest connections. Costs
+    /// accept throughput, so it suits listeners with few, long-lived connections that would
+    /// otherwise pile onto one worker. TCP listeners only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub exact_balance: bool,
+}
+
+/// Hardening limits for the HTTP connection manager.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HttpLimits {
+    /// Largest request header block accepted, in KiB (1-8192); larger requests get a 431.
+    /// Envoy's default is 60.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_request_headers_kb: Option<u32>,
+    /// Requests served over one downstream connection before Envoy closes it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_requests_per_connection: Option<u32>,
+    /// Seconds a downstream connection may sit with no active requests before Envoy closes
+    /// it. Envoy's default is one hour.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_secs: Option<u32>,
+}
+
+/// Envoy's own ceiling on `max_request_headers_kb`.
+const MAX_REQUEST_HEADERS_KB: u32 = 8192;
+
+impl HttpLimits {
+    pub fn validate(&self) -> DomainResult<()> {
+        if let Some(kb) = self.max_request_headers_kb {
+            if !(1..=MAX_REQUEST_HEADERS_KB).contains(&kb) {
+                return Err(DomainError::validation(format!(
+                    "http_limits.max_request_headers_kb must be 1-{MAX_REQUEST_HEADERS_KB}, got {kb}"
+                )));
+            }
+        }
+        if self.max_requests_per_connection == Some(0) {
+            return Err(DomainError::validation(
+                "http_limits.max_requests_per_connection must be positive",
+            )
+            .with_hint("omit it to leave connections unlimited"));
+        }
+        if self.idle_timeout_secs == Some(0) {
+            return Err(
+                DomainError::validation("http_limits.idle_timeout_secs must be positive")
+                    .with_hint("omit it to keep Envoy's one-hour default"),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Where a listener sends traces and which share of requests it samples.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListenerTracingConfig {
+    pub provider: TracingProvider,
+    /// Percentage (0-100, fractions allowed) of requests without an upstream sampling
+    /// decision that start a trace. Defaults to 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling_percent: Option<f64>,
+}
+
+/// Tracer the listener reports to. Tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TracingProvider {
+    /// `envoy.tracers.opentelemetry`, exporting spans over OTLP/gRPC.
+    Opentelemetry(OtelTracingProvider),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OtelTracingProvider {
+    /// Same-team cluster of the collector; it must speak HTTP/2 (OTLP/gRPC).
+    pub cluster: String,
+    /// `service.name` on every span, e.g. `edge-gateway`.
+    pub service_name: String,
+}
+
+impl ListenerTracingConfig {
+    pub fn validate(&self) -> DomainResult<()> {
+        let TracingProvider::Opentelemetry(otel) = &self.provider;
+        crate::identity::validate_name(&otel.cluster)?;
+        if otel.service_name.is_empty()
+            || otel.service_name.len() > 128
+            || otel.service_name.chars().any(char::is_control)
+        {
+            return Err(DomainError::validation(
+                "tracing.provider.service_name must be 1-128 chars with no control characters",
+            ));
+        }
+        if let Some(percent) = self.sampling_percent {
+            if !(0.0..=100.0).contains(&percent) {
+                return Err(DomainError::validation(
+                    "tracing.sampling_percent must be between 0 and 100",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The collector cluster the tracer exports to.
+    pub fn cluster(&self) -> &str {
+        let TracingProvider::Opentelemetry(otel) = &self.provider;
+        &otel.cluster
+    }
+}
+
+/// Network filters a listener may run ahead of the HTTP connection manager. Tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum NetworkFilterSpec {
+    /// `envoy.filters.network.local_ratelimit`: each accepted connection takes one token; with
+    /// the bucket empty, new connections are closed immediately.
+    LocalRateLimit(NetworkLocalRateLimitConfig),
+    /// The HTTP connection manager. Never needed in input: it is appended last automatically,
+    /// and naming it is only accepted as the final entry.
+    HttpConnectionManager,
+}
+
+impl NetworkFilterSpec {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::LocalRateLimit(_) => "local_rate_limit",
+            Self::HttpConnectionManager => "http_connection_manager",
+        }
+    }
+}
+
+/// Listener filters a listener may run on accepted connections. Tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ListenerFilterSpec {
+    /// `envoy.filters.listener.proxy_protocol`: reads the PROXY protocol header and uses the
+    /// client address it carries as the connection's remote address, so logs, rate limits and
+    /// `x-forwarded-for` see the client instead of the load balancer.
+    ProxyProtocol(ProxyProtocolConfig),
+    /// `envoy.filters.listener.tls_inspector`: reads SNI and ALPN from the ClientHello without
+    /// terminating TLS, for filter chain matching and `%REQUESTED_SERVER_NAME%`.
+    TlsInspector,
+    /// `envoy.filters.listener.original_dst`: restores the destination a transparently
+    /// redirected (iptables `REDIRECT`/`TPROXY`) connection was originally sent to.
+    OriginalDst,
+}
+
+impl ListenerFilterSpec {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ProxyProtocol(_) => "proxy_protocol",
+            Self::TlsInspector => "tls_inspector",
+            Self::OriginalDst => "original_dst",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyProtocolConfig {
+    /// PROXY protocol versions accepted; empty accepts both. A header of any other version
+    /// closes the connection.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<ProxyProtocolVersion>,
+    /// Accept connections that carry no PROXY header, keeping their peer address. Useful
+    /// while moving a listener behind the load balancer; otherwise they are closed.
+    #[serde(default)]
+    pub allow_requests_without_proxy_protocol: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyProtocolVersion {
+    /// Human-readable header.
+    V1,
+    /// Binary header, as sent by AWS NLB.
+    V2,
+}
+
+/// Envoy refuses network token buckets that refill faster than this.
+pub const NETWORK_RATE_LIMIT_MIN_FILL_INTERVAL_MS: u64 = 50;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkLocalRateLimitConfig {
+    pub stat_prefix: String,
+    /// Connection budget shared by every client of this listener.
+    pub token_bucket: crate::gateway::filters::TokenBucket,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AccessLogConfig {
+    /// File Envoy appends to; `/dev/stdout` sends the log to the proxy's stdout. Omitted
+    /// when the entry ships to an OTLP collector instead (`otel`).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub path: String,
+    /// Line format; for `otel` entries it becomes the log record body.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<String>,
+    /// Structured JSON lines: output key -> Envoy command operator, e.g.
+    /// `{"method": "%REQ(:METHOD)%", "status": "%RESPONSE_CODE%"}`. Exclusive with
+    /// `text_format`; every operator must be one Envoy knows (see [`ACCESS_LOG_OPERATORS`]).
+    /// For `otel` entries the keys become log record attributes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_format: Option<std::collections::BTreeMap<String, String>>,
+    /// Send the log to an OpenTelemetry collector over OTLP/gRPC instead of a file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub otel: Option<OtelAccessLogConfig>,
 }
 
+/// OTLP log export from Envoy's `open_telemetry` access logger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OtelAccessLogConfig {
+    /// Same-team cluster of the collector; it must speak HTTP/2 (OTLP/gRPC).
+    pub cluster: String,
+    /// Resource attributes stamped on every exported log, e.g.
+    /// `{"service.name": "edge-gateway", "deployment.environment": "prod"}`.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub resource_attributes: std::collections::BTreeMap<String, String>,
+}
+
+/// Resource attributes allowed on one `otel` access log.
+pub const MAX_OTEL_RESOURCE_ATTRIBUTES: usize = 32;
+
+/// Envoy access-log command operators accepted in `json_format` values, written without
+/// their `%` delimiters. Operators that take an argument (`REQ(:METHOD)`) are in
+/// [`ACCESS_LOG_ARG_OPERATORS`].
+pub const ACCESS_LOG_OPERATORS: &[&str] = &[
+    "BYTES_RECEIVED",
+    "BYTES_SENT",
+    "CONNECTION_ID",
+    "CONNECTION_TERMINATION_DETAILS",
+    "DOWNSTREAM_DIRECT_REMOTE_ADDRESS",
+    "DOWNSTREAM_DIRECT_REMOTE_ADDRESS_WITHOUT_PORT",
+    "DOWNSTREAM_LOCAL_ADDRESS",
+    "DOWNSTREAM_LOCAL_ADDRESS_WITHOUT_PORT",
+    "DOWNSTREAM_LOCAL_PORT",
+    "DOWNSTREAM_LOCAL_URI_SAN",
+    "DOWNSTREAM_PEER_FINGERPRINT_256",
+    "DOWNSTREAM_PEER_SUBJECT",
+    "DOWNSTREAM_PEER_URI_SAN",
+    "DOWNSTREAM_REMOTE_ADDRESS",
+    "DOWNSTREAM_REMOTE_ADDRESS_WITHOUT_PORT",
+    "DOWNSTREAM_REMOTE_PORT",
+    "DOWNSTREAM_TLS_CIPHER",
+    "DOWNSTREAM_TLS_VERSION",
+    "DURATION",
+    "GRPC_STATUS",
+    "GRPC_STATUS_NUMBER",
+    "HOSTNAME",
+    "PROTOCOL",
+    "REQUESTED_SERVER_NAME",
+    "REQUEST_DURATION",
+    "REQUEST_HEADERS_BYTES",
+    "REQUEST_TX_DURATION",
+    "RESPONSE_CODE",
+    "RESPONSE_CODE_DETAILS",
+    "RESPONSE_DURATION",
+    "RESPONSE_FLAGS",
+    "RESPONSE_FLAGS_LONG",
+    "RESPONSE_HEADERS_BYTES",
+    "RESPONSE_TX_DURATION",
+    "ROUTE_NAME",
+    "START_TIME",
+    "STREAM_ID",
+    "TRACE_ID",
+    "UPSTREAM_CLUSTER",
+    "UPSTREAM_CONNECTION_ID",
+    "UPSTREAM_HOST",
+    "UPSTREAM_LOCAL_ADDRESS",
+    "UPSTREAM_PROTOCOL",
+    "UPSTREAM_REMOTE_ADDRESS",
+    "UPSTREAM_REQUEST_ATTEMPT_COUNT",
+    "UPSTREAM_TRANSPORT_FAILURE_REASON",
+    "VIRTUAL_CLUSTER_NAME",
+];
+
+/// Command operators written `NAME(argument)`, optionally followed by `:<max length>`.
+pub const ACCESS_LOG_ARG_OPERATORS: &[&str] = &[
+    "CLUSTER_METADATA",
+    "DYNAMIC_METADATA",
+    "ENVIRONMENT",
+    "FILTER_STATE",
+    "REQ",
+    "RESP",
+    "START_TIME",
+    "TRAILER",
+    "UPSTREAM_FILTER_STATE",
+    "UPSTREAM_METADATA",
+];
+
+const MAX_JSON_FORMAT_KEYS: usize = 64;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ListenerProtocol {
@@ -65,6 +417,10 @@ pub enum ListenerProtocol {
     Http,
     Http2,
     Https,
+    /// HTTP/3 over QUIC. The listener binds UDP and terminates TLS 1.3 inside QUIC, so it
+    /// needs `tls_context`; a TCP listener on the same port can serve the same clients over
+    /// HTTP/1.1 and HTTP/2.
+    Http3,
 }
 
 fn is_default_listener_protocol(protocol: &ListenerProtocol) -> bool {
@@ -154,6 +510,31 @@ impl ListenerSpec {
                 "https listener protocol requires tls_context",
             ));
         }
+        if self.protocol == ListenerProtocol::Http3 {
+            if self.tls_context.is_none() {
+                return Err(DomainError::validation(
+                    "http3 listener protocol requires tls_context",
+                )
+                .with_hint("QUIC always encrypts; supply the same certificates as for https"));
+            }
+            if !self.network_filters.is_empty() {
+                return Err(DomainError::validation(
+                    "http3 listeners do not support network_filters",
+                )
+                .with_hint("QUIC filter chains run only the HTTP connection manager"));
+            }
+            if !self.listener_filters.is_empty() {
+                return Err(DomainError::validation(
+                    "http3 listeners do not support listener_filters",
+                ));
+            }
+            if self.exact_balance {
+                return Err(DomainError::validation(
+                    "http3 listeners do not support exact_balance",
+                )
+                .with_hint("Envoy balances only TCP connections across workers"));
+            }
+        }
         if let Some(tls) = &self.tls_context {
             tls.validate()?;
         }
@@ -165,7 +546,95 @@ impl ListenerSpec {
         for log in &self.access_logs {
             log.validate()?;
         }
+        if let Some(tracing) = &self.tracing {
+            tracing.validate()?;
+        }
+        if let Some(limits) = &self.http_limits {
+            limits.validate()?;
+        }
         crate::gateway::filters::validate_filter_chain(&self.http_filters)?;
+        validate_network_filters(&self.network_filters)?;
+        validate_listener_filters(&self.listener_filters)?;
+        Ok(())
+    }
+}
+
+fn validate_listener_filters(filters: &[ListenerFilterSpec]) -> DomainResult<()> {
+    let mut seen = std::collections::HashSet::new();
+    for filter in filters {
+        if !seen.insert(filter.kind()) {
+            return Err(DomainError::validation(format!(
+                "duplicate listener filter type \"{}\"",
+                filter.kind()
+            )));
+        }
+        match filter {
+            ListenerFilterSpec::ProxyProtocol(config) => {
+                let mut versions = std::collections::HashSet::new();
+                if !config.versions.iter().all(|v| versions.insert(*v)) {
+                    return Err(DomainError::validation(
+                        "proxy_protocol: versions must not repeat",
+                    ));
+                }
+            }
+            ListenerFilterSpec::TlsInspector | ListenerFilterSpec::OriginalDst => {}
+        }
+    }
+    Ok(())
+}
+
+fn validate_network_filters(filters: &[NetworkFilterSpec]) -> DomainResult<()> {
+    let mut seen = std::collections::HashSet::new();
+    for (i, filter) in filters.iter().enumerate() {
+        if !seen.insert(filter.kind()) {
+            return Err(DomainError::validation(format!(
+                "duplicate network filter type \"{}\"",
+                filter.kind()
+            )));
+        }
+        match filter {
+            NetworkFilterSpec::HttpConnectionManager => {
+                if i + 1 != filters.len() {
+                    return Err(DomainError::validation(
+                        "network filters must come before the http_connection_manager",
+                    )
+                    .with_hint("move http_connection_manager last, or omit it — it is appended automatically"));
+                }
+            }
+            NetworkFilterSpec::LocalRateLimit(config) => config.validate()?,
+        }
+    }
+    Ok(())
+}
+
+impl NetworkLocalRateLimitConfig {
+    fn validate(&self) -> DomainResult<()> {
+        if self.stat_prefix.is_empty()
+            || self.stat_prefix.len() > 128
+            || !self
+                .stat_prefix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            return Err(DomainError::validation(
+                "network local_rate_limit: stat_prefix must be 1-128 of [A-Za-z0-9_.-]",
+            ));
+        }
+        if self.token_bucket.max_tokens == 0 {
+            return Err(DomainError::validation(
+                "network local_rate_limit: token_bucket.max_tokens must be >= 1",
+            ));
+        }
+        if self.token_bucket.tokens_per_fill == Some(0) {
+            return Err(DomainError::validation(
+                "network local_rate_limit: token_bucket.tokens_per_fill must be >= 1",
+            ));
+        }
+        if self.token_bucket.fill_interval_ms < NETWORK_RATE_LIMIT_MIN_FILL_INTERVAL_MS {
+            return Err(DomainError::validation(format!(
+                "network local_rate_limit: token_bucket.fill_interval_ms must be >= {NETWORK_RATE_LIMIT_MIN_FILL_INTERVAL_MS}"
+            )));
+        }
         Ok(())
     }
 }
@@ -209,7 +678,15 @@ fn validate_public_base_url(value: &str) -> DomainResult<()> {
 
 impl AccessLogConfig {
     pub fn validate(&self) -> DomainResult<()> {
-        validate_path("access_log.path", &self.path)?;
+        match &self.otel {
+            Some(_) if !self.path.is_empty() => {
+                return Err(DomainError::validation(
+                    "access_log takes path or otel, not both",
+                ))
+            }
+            Some(otel) => otel.validate()?,
+            None => validate_path("access_log.path", &self.path)?,
+        }
         if let Some(format) = &self.text_format {
             if format.is_empty() || format.len() > 4096 || format.contains('\0') {
                 return Err(DomainError::validation(
@@ -217,10 +694,115 @@ impl AccessLogConfig {
                 ));
             }
         }
+        if let Some(fields) = &self.json_format {
+            if self.text_format.is_some() {
+                return Err(DomainError::validation(
+                    "access_log takes text_format or json_format, not both",
+                ));
+            }
+            if fields.is_empty() || fields.len() > MAX_JSON_FORMAT_KEYS {
+                return Err(DomainError::validation(format!(
+                    "access_log.json_format must have 1-{MAX_JSON_FORMAT_KEYS} keys"
+                )));
+            }
+            for (key, value) in fields {
+                if key.is_empty() || key.len() > 128 || key.chars().any(char::is_control) {
+                    return Err(DomainError::validation(format!(
+                        "access_log.json_format key \"{key}\" must be 1-128 chars with no \
+                         control characters"
+                    )));
+                }
+                if value.is_empty() || value.len() > 1024 || value.contains('\0') {
+                    return Err(DomainError::validation(format!(
+                        "access_log.json_format.{key} must be 1-1024 chars and contain no NUL"
+                    )));
+                }
+                validate_command_operators(key, value)?;
+            }
+        }
         Ok(())
     }
 }
 
+impl OtelAccessLogConfig {
+    pub fn validate(&self) -> DomainResult<()> {
+        crate::identity::validate_name(&self.cluster)?;
+        if self.resource_attributes.len() > MAX_OTEL_RESOURCE_ATTRIBUTES {
+            return Err(DomainError::validation(format!(
+                "access_log.otel.resource_attributes must have at most \
+                 {MAX_OTEL_RESOURCE_ATTRIBUTES} entries"
+            )));
+        }
+        for (key, value) in &self.resource_attributes {
+            if key.is_empty() || key.len() > 128 || key.chars().any(char::is_control) {
+                return Err(DomainError::validation(format!(
+                    "access_log.otel.resource_attributes key \"{key}\" must be 1-128 chars \
+                     with no control characters"
+                )));
+            }
+            if value.is_empty() || value.len() > 256 || value.chars().any(char::is_control) {
+                return Err(DomainError::validation(format!(
+                    "access_log.otel.resource_attributes.{key} must be 1-256 chars with no \
+                     control characters"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every `%...%` in a `json_format` value must name a known command operator; literal text
+/// around them is kept as is. An argument may itself contain `%` (`%START_TIME(%s)%`), so
+/// it is skipped up to its closing parenthesis.
+fn validate_command_operators(key: &str, value: &str) -> DomainResult<()> {
+    let invalid = |operator: &str| {
+        DomainError::validation(format!(
+            "access_log.json_format.{key}: unknown command operator {operator}"
+        ))
+        .with_hint(
+            "use an Envoy command operator such as %REQ(:METHOD)%, %RESPONSE_CODE% or %DURATION%",
+        )
+    };
+    let mut rest = value;
+    while let Some(start) = rest.find('%') {
+        let token = &rest[start..];
+        let body = &token[1..];
+        let name_len = body
+            .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'))
+            .unwrap_or(body.len());
+        let name = &body[..name_len];
+        let mut tail = &body[name_len..];
+        let known = if let Some(argument_and_more) = tail.strip_prefix('(') {
+            let Some(close) = argument_and_more.find(')') else {
+                return Err(invalid(token));
+            };
+            let argument = &argument_and_more[..close];
+            tail = &argument_and_more[close + 1..];
+            if let Some(max_length) = tail.strip_prefix(':') {
+                let digits = max_length
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(max_length.len());
+                if digits == 0 {
+                    return Err(invalid(token));
+                }
+                tail = &max_length[digits..];
+            }
+            ACCESS_LOG_ARG_OPERATORS.contains(&name)
+                && (name == "START_TIME" || !argument.is_empty())
+        } else {
+            ACCESS_LOG_OPERATORS.contains(&name)
+        };
+        let Some(after) = tail.strip_prefix('%') else {
+            return Err(invalid(token));
+        };
+        if !known {
+            return Err(invalid(&token[..token.len() - after.len()]));
+        }
+        rest = after;
+    }
+    Ok(())
+}
+
 impl ListenerTlsConfig {
     pub fn validate(&self) -> DomainResult<()> {
         let inline_cert = self.cert_chain_file.is_some() || self.private_key_file.is_some();
@@ -301,6 +883,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         }
     }
 
@@ -421,16 +1008,269 @@ mod tests {
         assert!(spec.validate().is_ok(), "https with SDS TLS is valid");
     }
 
+    #[test]
+    fn http3_protocol_requires_tls_and_no_network_filters() {
+        let mut spec = spec("0.0.0.0", 8443);
+        spec.protocol = ListenerProtocol::Http3;
+        assert!(spec.validate().is_err(), "QUIC without TLS rejected");
+
+        spec.tls_context = Some(ListenerTlsConfig {
+            cert_chain_file: Some("/etc/envoy/tls/edge.crt".into()),
+            private_key_file: Some("/etc/envoy/tls/edge.key".into()),
+            ca_cert_file: None,
+            require_client_certificate: false,
+            tls_certificate_sds_secret_name: None,
+            validation_context_sds_secret_name: None,
+        });
+        assert!(spec.validate().is_ok(), "http3 with TLS is valid");
+
+        spec.exact_balance = true;
+        assert!(spec.validate().is_err(), "UDP listeners are not balanced");
+        spec.exact_balance = false;
+
+        spec.network_filters = vec![NetworkFilterSpec::HttpConnectionManager];
+        assert!(spec.validate().is_err(), "QUIC chains take no L4 filters");
+    }
+
     #[test]
     fn access_log_validation_is_bounded() {
         let mut spec = spec("0.0.0.0", 8080);
         spec.access_logs = vec![AccessLogConfig {
             path: "/var/log/envoy/access.log".into(),
             text_format: Some("%REQ(:METHOD)% %RESPONSE_CODE%\n".into()),
+            json_format: None,
+            otel: None,
         }];
         assert!(spec.validate().is_ok());
 
         spec.access_logs[0].path = "".into();
         assert!(spec.validate().is_err(), "empty access log path rejected");
     }
+
+    #[test]
+    fn otel_access_logs_replace_the_path() {
+        let mut spec = spec("0.0.0.0", 8080);
+        spec.access_logs = serde_json::from_value(serde_json::json!([{
+            "otel": {
+                "cluster": "otel-collector",
+                "resource_attributes": {"service.name": "edge"}
+            },
+            "json_format": {"status": "%RESPONSE_CODE%"}
+        }]))
+        .expect("otel access log");
+        assert!(spec.validate().is_ok(), "{:?}", spec.validate());
+        assert_eq!(
+            serde_json::to_value(&spec.access_logs[0]).expect("serialize")["path"],
+            serde_json::Value::Null,
+            "an otel entry has no path"
+        );
+
+        spec.access_logs[0].path = "/dev/stdout".into();
+        assert!(spec.validate().is_err(), "path and otel are exclusive");
+        spec.access_logs[0].path.clear();
+
+        let otel = spec.access_logs[0].otel.as_mut().expect("otel");
+        otel.cluster = "Not A Cluster".into();
+        assert!(spec.validate().is_err(), "cluster must be a resource name");
+        let otel = spec.access_logs[0].otel.as_mut().expect("otel");
+        otel.cluster = "otel-collector".into();
+        otel.resource_attributes
+            .insert("region".into(), String::new());
+        assert!(spec.validate().is_err(), "empty attribute value rejected");
+    }
+
+    #[test]
+    fn json_access_log_operators_are_checked() {
+        let mut spec = spec("0.0.0.0", 8080);
+        let fields = |pairs: &[(&str, &str)]| {
+            Some(
+                pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            )
+        };
+        spec.access_logs = vec![AccessLogConfig {
+            path: "/dev/stdout".into(),
+            text_format: None,
+            otel: None,
+            json_format: fields(&[
+                ("method", "%REQ(:METHOD)%"),
+                ("path", "%REQ(X-ENVOY-ORIGINAL-PATH?:PATH):256%"),
+                ("status", "%RESPONSE_CODE%"),
+                ("duration_ms", "%DURATION%"),
+                ("at", "%START_TIME(%s.%3f)%"),
+                ("route", "route=%ROUTE_NAME%"),
+            ]),
+        }];
+        assert!(spec.validate().is_ok(), "{:?}", spec.validate());
+
+        for bad in [
+            "%RESPONSE_CODES%",
+            "%REQ()%",
+            "%REQ(:PATH):x%",
+            "%DURATION",
+            "%BOGUS(x)%",
+        ] {
+            spec.access_logs[0].json_format = fields(&[("field", bad)]);
+            let err = spec.validate().expect_err(bad);
+            assert!(
+                err.message.contains("json_format.field"),
+                "{bad}: {}",
+                err.message
+            );
+        }
+
+        spec.access_logs[0].json_format = fields(&[("status", "%RESPONSE_CODE%")]);
+        spec.access_logs[0].text_format = Some("%RESPONSE_CODE%\n".into());
+        assert!(
+            spec.validate().is_err(),
+            "text_format and json_format are exclusive"
+        );
+    }
+
+    #[test]
+    fn tracing_sampling_and_service_name_are_bounded() {
+        let mut spec = spec("0.0.0.0", 8080);
+        spec.tracing = serde_json::from_value(serde_json::json!({
+            "provider": {"type": "opentelemetry", "cluster": "otel-collector", "service_name": "edge"},
+            "sampling_percent": 0.5
+        }))
+        .expect("tracing");
+        assert!(spec.validate().is_ok(), "{:?}", spec.validate());
+
+        for bad in [-1.0, 100.5, f64::NAN] {
+            spec.tracing.as_mut().expect("tracing").sampling_percent = Some(bad);
+            assert!(spec.validate().is_err(), "sampling {bad} rejected");
+        }
+        let tracing = spec.tracing.as_mut().expect("tracing");
+        tracing.sampling_percent = None;
+        let TracingProvider::Opentelemetry(otel) = &mut tracing.provider;
+        otel.service_name.clear();
+        assert!(spec.validate().is_err(), "empty service name rejected");
+        assert!(
+            serde_json::from_value::<ListenerTracingConfig>(serde_json::json!({
+                "provider": {"type": "zipkin", "cluster": "c", "service_name": "s"}
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn http_limits_must_be_positive_and_headers_at_most_8192_kb() {
+        let mut spec = spec("0.0.0.0", 8080);
+        spec.http_limits = serde_json::from_value(serde_json::json!({
+            "max_request_headers_kb": 8192,
+            "max_requests_per_connection": 1,
+            "idle_timeout_secs": 1
+        }))
+        .expect("limits");
+        assert!(spec.validate().is_ok(), "{:?}", spec.validate());
+
+        for bad in [
+            serde_json::json!({"max_request_headers_kb": 0}),
+            serde_json::json!({"max_request_headers_kb": 8193}),
+            serde_json::json!({"max_requests_per_connection": 0}),
+            serde_json::json!({"idle_timeout_secs": 0}),
+        ] {
+            spec.http_limits = serde_json::from_value(bad.clone()).expect("limits");
+            assert!(spec.validate().is_err(), "{bad} rejected");
+        }
+        assert!(
+            serde_json::from_value::<HttpLimits>(serde_json::json!({"idle_timeout": 30})).is_err()
+        );
+    }
+
+    #[test]
+    fn network_filters_come_before_the_hcm() {
+        let mut spec = spec("0.0.0.0", 8080);
+        spec.network_filters = serde_json::from_value(serde_json::json!([
+            {"type": "local_rate_limit", "stat_prefix": "conn",
+             "token_bucket": {"max_tokens": 50, "fill_interval_ms": 1000}},
+            {"type": "http_connection_manager"}
+        ]))
+        .expect("network filters deserialize");
+        assert!(spec.validate().is_ok());
+
+        spec.network_filters.reverse();
+        let err = spec.validate().expect_err("rate limit after the HCM");
+        assert_eq!(
+            err.message,
+            "network filters must come before the http_connection_manager"
+        );
+
+        spec.network_filters.remove(0);
+        assert!(spec.validate().is_ok(), "the HCM marker is optional");
+
+        let NetworkFilterSpec::LocalRateLimit(config) = &mut spec.network_filters[0] else {
+            panic!("local_rate_limit");
+        };
+        config.token_bucket.fill_interval_ms = 10;
+        let err = spec
+            .validate()
+            .expect_err("refill faster than Envoy allows");
+        assert!(err.message.contains("fill_interval_ms must be >= 50"));
+
+        let duplicate = spec.network_filters[0].clone();
+        spec.network_filters.push(duplicate);
+        assert!(spec.validate().is_err(), "one filter per type");
+    }
+
+    #[test]
+    fn proxy_protocol_listener_filter_parses_and_validates() {
+        let mut spec = spec("0.0.0.0", 8080);
+        spec.listener_filters = serde_json::from_value(serde_json::json!([
+            {"type": "proxy_protocol", "versions": ["v2"]}
+        ]))
+        .expect("parse");
+        assert!(spec.validate().is_ok());
+
+        let mut repeated = spec.clone();
+        let ListenerFilterSpec::ProxyProtocol(config) = &mut repeated.listener_filters[0] else {
+            panic!("expected proxy_protocol");
+        };
+        config.versions.push(ProxyProtocolVersion::V2);
+        assert!(repeated.validate().is_err(), "repeated version");
+
+        let duplicate = spec.listener_filters[0].clone();
+        spec.listener_filters.push(duplicate);
+        assert!(spec.validate().is_err(), "one filter per type");
+    }
+
+    #[test]
+    fn tls_inspector_and_original_dst_take_no_config() {
+        let mut spec = spec("0.0.0.0", 8080);
+        spec.listener_filters = serde_json::from_value(serde_json::json!([
+            {"type": "original_dst"},
+            {"type": "tls_inspector"}
+        ]))
+        .expect("parse");
+        assert!(spec.validate().is_ok());
+        assert_eq!(
+            spec.listener_filters,
+            [
+                ListenerFilterSpec::OriginalDst,
+                ListenerFilterSpec::TlsInspector
+            ]
+        );
+    }
+
+    #[test]
+    fn maintenance_defaults_to_a_503_notice_and_stays_a_server_error() {
+        let maintenance: ListenerMaintenance =
+            serde_json::from_value(serde_json::json!({})).expect("defaults");
+        assert_eq!(maintenance, ListenerMaintenance::default());
+        assert_eq!(maintenance.status, 503);
+        assert!(maintenance.validate().is_ok());
+
+        let healthy: ListenerMaintenance =
+            serde_json::from_value(serde_json::json!({"status": 200})).expect("deserialize");
+        let err = healthy.validate().expect_err("a 200 would hide the outage");
+        assert_eq!(err.message, "maintenance status must be 500-599, got 200");
+        let long = ListenerMaintenance {
+            body: "x".repeat(4097),
+            ..ListenerMaintenance::default()
+        };
+        assert!(long.validate().is_err());
+    }
 }
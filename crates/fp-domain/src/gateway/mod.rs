@@ -1,9 +1,90 @@
 //! Gateway resources (the entity chain, spec/00): cluster now; listener, route-config
 //! follow the same vertical pattern.
 
+use crate::{DomainError, DomainResult};
+
 pub mod cluster;
+pub mod filter_preset;
 pub mod filters;
 pub mod listener;
+pub mod proto_descriptor;
+pub mod retry_policy;
 pub mod route_config;
+pub mod runtime;
+pub mod wasm_module;
 
 pub use cluster::{Cluster, ClusterSpec, Endpoint, LbPolicy};
+pub use filter_preset::FilterPreset;
+pub use proto_descriptor::ProtoDescriptor;
+pub use retry_policy::NamedRetryPolicy;
+pub use runtime::RuntimeValue;
+pub use wasm_module::WasmModule;
+
+/// Most clusters, route configs, or listeners a team's xDS snapshot reads per type. Team
+/// quotas for those kinds cannot be raised past it.
+pub const MAX_XDS_RESOURCES_PER_TYPE: i64 = 500;
+
+/// Column a cluster, listener or route-config list is ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortField {
+    #[default]
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+/// List ordering from `?sort=&order=`; the default is by name, ascending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListSort {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+impl ListSort {
+    /// Parse caller-supplied `sort` (`name`, `created_at`, `updated_at`) and `order`
+    /// (`asc`, `desc`); either may be omitted.
+    pub fn parse(sort: Option<&str>, order: Option<&str>) -> DomainResult<Self> {
+        let field = match sort.unwrap_or("name") {
+            "name" => SortField::Name,
+            "created_at" => SortField::CreatedAt,
+            "updated_at" => SortField::UpdatedAt,
+            other => {
+                return Err(
+                    DomainError::validation(format!("unknown sort field \"{other}\""))
+                        .with_hint("sort by name, created_at or updated_at"),
+                )
+            }
+        };
+        let descending = match order.unwrap_or("asc") {
+            "asc" => false,
+            "desc" => true,
+            other => {
+                return Err(
+                    DomainError::validation(format!("unknown sort order \"{other}\""))
+                        .with_hint("use order=asc or order=desc"),
+                )
+            }
+        };
+        Ok(Self { field, descending })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{ListSort, SortField};
+
+    #[test]
+    fn list_sort_defaults_to_name_ascending_and_rejects_unknown_values() {
+        assert_eq!(ListSort::parse(None, None).unwrap(), ListSort::default());
+        assert_eq!(
+            ListSort::parse(Some("updated_at"), Some("desc")).unwrap(),
+            ListSort {
+                field: SortField::UpdatedAt,
+                descending: true,
+            }
+        );
+        assert!(ListSort::parse(Some("revision"), None).is_err());
+        assert!(ListSort::parse(None, Some("down")).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+//! Uploaded protobuf descriptor sets. A `grpc_json_transcoder` chain filter references one by
+//! id; the raw `FileDescriptorSet` bytes are only read back at snapshot build time.
+
+use crate::id::{ProtoDescriptorId, TeamId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Upper bound on an uploaded descriptor set. Envoy inlines the bytes into the listener
+/// resource, so this keeps a single upload from blowing up every LDS push for the team.
+pub const MAX_DESCRIPTOR_SET_BYTES: usize = 4 * 1024 * 1024;
+
+/// Descriptor metadata. The bytes themselves are never part of a read path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtoDescriptor {
+    pub id: ProtoDescriptorId,
+    pub team_id: TeamId,
+    pub name: String,
+    /// Fully-qualified `{package}.{Service}` names declared by the set, sorted.
+    pub services: Vec<String>,
+    pub size_bytes: i64,
+    pub version: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
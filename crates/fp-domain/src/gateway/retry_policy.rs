@@ -0,0 +1,19 @@
+//! Named retry policies: a team-owned [`RetryPolicy`] that route forward actions reference by
+//! name (`retry_policy_ref`) instead of repeating it inline. The xDS translator expands the
+//! reference into the same Envoy retry policy an inline one would produce.
+
+use crate::gateway::route_config::RetryPolicy;
+use crate::id::{RetryPolicyId, TeamId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedRetryPolicy {
+    pub id: RetryPolicyId,
+    pub team_id: TeamId,
+    pub name: String,
+    pub spec: RetryPolicy,
+    pub version: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -13,6 +13,9 @@ use std::collections::HashSet;
 pub const MAX_DOMAINS_PER_VHOST: usize = 50;
 pub const MAX_VHOSTS: usize = 50;
 pub const MAX_ROUTES_PER_VHOST: usize = 200;
+pub const MAX_ROUTE_METHODS: usize = 16;
+/// Upper bound on a route's `timeout_secs`.
+pub const MAX_ROUTE_TIMEOUT_SECS: u32 = 300;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RouteConfig {
@@ -54,14 +57,43 @@ pub struct RouteRule {
     pub matcher: PathMatch,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub headers: Vec<HeaderMatch>,
+    /// HTTP methods the rule matches, e.g. `["GET", "POST"]`, compiled into one `:method`
+    /// matcher. Empty matches every method.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub query_parameters: Vec<QueryParameterMatch>,
+    /// Match only this share of the requests the other conditions match, e.g. to gate a new
+    /// route behind a runtime flag. Requests it skips fall through to later routes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_fraction: Option<RuntimeFractionMatch>,
     pub action: RouteAction,
     /// Per-route filter behavior (S5.8); wins over the vhost-level override.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub filter_overrides: Vec<crate::gateway::filters::FilterOverride>,
 }
 
+impl RouteRule {
+    /// The `:method` header matcher implied by `methods`: an exact match for one method, an
+    /// anchored alternation for several, `None` when the rule matches every method.
+    pub fn method_matcher(&self) -> Option<HeaderMatch> {
+        let matcher = match self.methods.as_slice() {
+            [] => return None,
+            [method] => HeaderValueMatch::Exact {
+                value: method.clone(),
+            },
+            methods => HeaderValueMatch::Regex {
+                pattern: format!("^(?:{})$", methods.join("|")),
+            },
+        };
+        Some(HeaderMatch {
+            name: ":method".into(),
+            invert_match: false,
+            matcher,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PathMatch {
@@ -90,6 +122,23 @@ pub struct RouteAction {
     /// Weighted target clusters, by name, same team.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub weighted_clusters: Option<Vec<WeightedClusterTarget>>,
+    /// Cluster tried when `cluster` fails the retry condition (5xx by default). Only with
+    /// `cluster`; see [`fallback_aggregate_name`] for how it reaches Envoy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_cluster: Option<String>,
+    /// Shadow clusters that receive a fire-and-forget copy of a share of the requests. The
+    /// mirrored responses are discarded, so the client never sees them. Forward actions only.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub request_mirror_policies: Vec<RequestMirrorPolicy>,
+    /// Request headers the router strips just before the upstream request is sent: after
+    /// every HTTP filter, so they win over a listener `header_mutation` add of the same
+    /// header. Forward actions only.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub request_headers_to_remove: Vec<String>,
+    /// Inputs of the consistent-hash key, in order, for session affinity. Only clusters with
+    /// `lb_policy` `ring-hash` or `maglev` hash on it. Forward actions only.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hash_policy: Vec<HashPolicy>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub redirect: Option<RedirectAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -100,11 +149,34 @@ pub struct RouteAction {
     /// Rewrite using template captures. Only with a Template match (v1 rule).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_rewrite: Option<String>,
+    /// Rewrite the path by RE2 regex substitution (`\1`-style capture references).
+    /// Exclusive with `prefix_rewrite` and `template_rewrite`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regex_rewrite: Option<RegexRewrite>,
+    /// Replace the `Host`/`:authority` header with this value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_rewrite_literal: Option<String>,
+    /// Replace the `Host`/`:authority` header with the selected upstream host's hostname.
+    /// Only effective for hostname (STRICT_DNS) endpoints. Exclusive with `host_rewrite_literal`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_host_rewrite: bool,
     /// Upstream request timeout in seconds (1–300; default 15).
     #[serde(default = "default_route_timeout")]
     pub timeout_secs: u32,
+    /// Stream idle timeout in seconds (0–86400); overrides the listener's. `0` disables it
+    /// (infinite), for long-polling and streaming endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_secs: Option<u32>,
+    /// Cap on a stream's total lifetime in seconds (0–86400), e.g. for SSE. `0` lifts any
+    /// listener-level cap for this route.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_stream_duration_secs: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retry_policy: Option<RetryPolicy>,
+    /// Named team retry policy (`/retry-policies`), resolved at translation time. Mutually
+    /// exclusive with an inline `retry_policy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_policy_ref: Option<String>,
     /// Route descriptor generators for the global RLS filter.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rate_limits: Vec<RateLimitDefinition>,
@@ -114,6 +186,71 @@ fn default_route_timeout() -> u32 {
     15
 }
 
+/// Upper bound (one day) for the per-route stream timeouts.
+const MAX_STREAM_TIMEOUT_SECS: u32 = 86_400;
+
+/// Upper bound on shadow clusters per route; each mirror multiplies upstream load.
+const MAX_REQUEST_MIRRORS: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RequestMirrorPolicy {
+    /// Shadow cluster, by name, same team.
+    pub cluster: String,
+    /// Percentage of requests mirrored (0–100; default 100).
+    #[serde(default = "default_mirror_fraction")]
+    pub runtime_fraction: u32,
+}
+
+fn default_mirror_fraction() -> u32 {
+    100
+}
+
+/// Upper bound on hash policy entries per route.
+const MAX_HASH_POLICIES: usize = 8;
+
+/// Upper bound (one year) on a generated affinity cookie's lifetime.
+const MAX_HASH_COOKIE_TTL_SECS: u32 = 31_536_000;
+
+/// One input of a route's consistent-hash key. Envoy hashes the entries in order and skips an
+/// entry the request does not carry; `terminal` stops at the first entry that produced a hash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum HashPolicy {
+    /// The value of a request header.
+    Header {
+        header_name: String,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        terminal: bool,
+    },
+    /// The value of a cookie. With `ttl_secs`, Envoy sets the cookie on the response when the
+    /// request lacks it, so a new client sticks to the host it first reached; `0` makes it a
+    /// session cookie.
+    Cookie {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ttl_secs: Option<u32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        terminal: bool,
+    },
+    /// The downstream connection's source IP.
+    SourceIp {
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        terminal: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RegexRewrite {
+    /// RE2 pattern matched against the path (query string excluded).
+    pub pattern: String,
+    /// Replacement; `\N` inserts capture group N.
+    pub substitution: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WeightedClusterTarget {
@@ -169,8 +306,30 @@ pub enum RateLimitAction {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         descriptor_key: Option<String>,
     },
+    /// Client address (trusted `x-forwarded-for` hop or the peer) as `remote_address`.
+    RemoteAddress,
+    /// Emits `descriptor_value` when the request does (or, with `expect_match: false`, does
+    /// not) satisfy every header matcher.
+    HeaderValueMatch {
+        descriptor_value: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        descriptor_key: Option<String>,
+        #[serde(default = "default_expect_match")]
+        expect_match: bool,
+        headers: Vec<HeaderMatch>,
+    },
 }
 
+fn default_expect_match() -> bool {
+    true
+}
+
+/// Route-level request header removals per route.
+pub const MAX_ROUTE_HEADER_REMOVALS: usize = 64;
+
+/// Header matchers one `header_value_match` rate-limit action may carry.
+pub const MAX_RATE_LIMIT_HEADER_MATCHERS: usize = 8;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RedirectAction {
@@ -238,6 +397,71 @@ pub enum QueryValueMatch {
     Regex { pattern: String },
 }
 
+/// Envoy's route `runtime_fraction`: `numerator` out of `denominator` requests match, unless
+/// the runtime value at `runtime_key` overrides the numerator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeFractionMatch {
+    pub numerator: u32,
+    #[serde(default)]
+    pub denominator: FractionDenominator,
+    /// Runtime key read on each request, e.g. `routes.checkout_v2.enabled`. Without it the
+    /// share is fixed at `numerator`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FractionDenominator {
+    #[default]
+    Hundred,
+    TenThousand,
+    Million,
+}
+
+impl FractionDenominator {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FractionDenominator::Hundred => "hundred",
+            FractionDenominator::TenThousand => "ten_thousand",
+            FractionDenominator::Million => "million",
+        }
+    }
+
+    pub fn value(self) -> u32 {
+        match self {
+            FractionDenominator::Hundred => 100,
+            FractionDenominator::TenThousand => 10_000,
+            FractionDenominator::Million => 1_000_000,
+        }
+    }
+}
+
+fn validate_runtime_fraction(
+    route_name: &str,
+    fraction: &RuntimeFractionMatch,
+) -> DomainResult<()> {
+    let denominator = fraction.denominator.value();
+    if fraction.numerator > denominator {
+        return Err(DomainError::validation(format!(
+            "route \"{route_name}\": runtime_fraction numerator must be 0-{denominator} for \
+             denominator {}",
+            fraction.denominator.as_str()
+        ))
+        .with_hint("use ten_thousand or million for finer-grained shares"));
+    }
+    if let Some(key) = &fraction.runtime_key {
+        if !crate::gateway::runtime::valid_runtime_key(key) {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": runtime_fraction runtime_key must be 1-256 letters, \
+                 digits, '_', '-' or '.', not starting or ending with '.'"
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn valid_domain(domain: &str) -> bool {
     if domain == "*" {
         return true;
@@ -296,6 +520,35 @@ fn valid_regex(label: &str, pattern: &str) -> DomainResult<()> {
     Ok(())
 }
 
+/// The pattern must parse as a regex and every `\N` in the substitution must name one of
+/// its capture groups (Envoy would otherwise NACK the route config).
+fn validate_regex_rewrite(rewrite: &RegexRewrite) -> DomainResult<()> {
+    valid_regex("regex_rewrite.pattern", &rewrite.pattern)?;
+    let hir = regex_syntax::parse(&rewrite.pattern).map_err(|e| {
+        DomainError::validation(format!("regex_rewrite.pattern does not compile: {e}"))
+    })?;
+    let groups = hir.properties().explicit_captures_len();
+    if rewrite.substitution.len() > 500 || rewrite.substitution.chars().any(char::is_control) {
+        return Err(DomainError::validation(
+            "regex_rewrite.substitution must be <= 500 chars with no control characters",
+        ));
+    }
+    let mut chars = rewrite.substitution.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+        if let Some(group) = chars.next().and_then(|d| d.to_digit(10)) {
+            if group as usize > groups {
+                return Err(DomainError::validation(format!(
+                    "regex_rewrite.substitution references \\{group} but the pattern has {groups} capture group(s)"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 impl RouteConfigSpec {
     pub fn validate(&self) -> DomainResult<()> {
         if self.virtual_hosts.is_empty() {
@@ -310,6 +563,7 @@ impl RouteConfigSpec {
             )));
         }
         let mut vhost_names = HashSet::new();
+        let mut domain_owners = std::collections::HashMap::new();
         for vhost in &self.virtual_hosts {
             validate_name(&vhost.name)?;
             if !vhost_names.insert(vhost.name.as_str()) {
@@ -342,6 +596,16 @@ impl RouteConfigSpec {
                         vhost.name
                     )));
                 }
+                // Envoy NACKs a route config whose virtual hosts share a domain.
+                if let Some(owner) =
+                    domain_owners.insert(domain.to_ascii_lowercase(), vhost.name.as_str())
+                {
+                    return Err(DomainError::validation(format!(
+                        "domain \"{domain}\" is served by both virtual hosts \"{owner}\" and \"{}\"",
+                        vhost.name
+                    ))
+                    .with_hint("each domain may appear in only one virtual host of a route config"));
+                }
             }
             validate_rate_limits(&vhost.rate_limits)?;
             crate::gateway::filters::validate_filter_overrides(&vhost.filter_overrides)?;
@@ -358,7 +622,8 @@ impl RouteConfigSpec {
                     return Err(DomainError::validation(format!(
                         "duplicate route \"{}\" in virtual host \"{}\"",
                         rule.name, vhost.name
-                    )));
+                    ))
+                    .with_hint("route names must be unique within a virtual host"));
                 }
                 match &rule.matcher {
                     PathMatch::Prefix { prefix } => valid_path("route prefix", prefix)?,
@@ -376,6 +641,7 @@ impl RouteConfigSpec {
                         )));
                     }
                 }
+                validate_methods(rule)?;
                 let mut query_names = HashSet::new();
                 for query in &rule.query_parameters {
                     validate_query_match(query)?;
@@ -386,6 +652,9 @@ impl RouteConfigSpec {
                         )));
                     }
                 }
+                if let Some(fraction) = &rule.runtime_fraction {
+                    validate_runtime_fraction(&rule.name, fraction)?;
+                }
                 validate_action(&rule.action, &rule.matcher, &rule.name)?;
                 crate::gateway::filters::validate_filter_overrides(&rule.filter_overrides)?;
             }
@@ -393,6 +662,131 @@ impl RouteConfigSpec {
         Ok(())
     }
 
+    /// Append `vhost` and re-validate the whole config, so a name or domain already used by
+    /// another virtual host is rejected. On error `self` is unchanged.
+    pub fn add_virtual_host(&mut self, vhost: VirtualHost) -> DomainResult<()> {
+        if self.virtual_hosts.iter().any(|v| v.name == vhost.name) {
+            return Err(DomainError::conflict(format!(
+                "virtual host \"{}\" already exists",
+                vhost.name
+            )));
+        }
+        self.virtual_hosts.push(vhost);
+        if let Err(e) = self.validate() {
+            self.virtual_hosts.pop();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Remove the virtual host named `name`. The last virtual host cannot be removed.
+    pub fn remove_virtual_host(&mut self, name: &str) -> DomainResult<VirtualHost> {
+        let index = self
+            .virtual_hosts
+            .iter()
+            .position(|v| v.name == name)
+            .ok_or_else(|| DomainError::not_found("virtual host", name))?;
+        if self.virtual_hosts.len() == 1 {
+            return Err(
+                DomainError::validation("a route config needs at least one virtual host")
+                    .with_hint("delete the route config instead"),
+            );
+        }
+        Ok(self.virtual_hosts.remove(index))
+    }
+
+    /// Point one forwarding route at a stable/canary pair: `canary_weight` is the canary's
+    /// percentage (0-100). 0 and 100 collapse to a plain `cluster` (stable or canary); anything
+    /// between becomes a two-target `weighted_clusters` split, stable first. The whole config
+    /// is re-validated; on error `self` is unchanged.
+    pub fn set_canary_split(
+        &mut self,
+        vhost_name: &str,
+        route_name: &str,
+        stable_cluster: &str,
+        canary_cluster: &str,
+        canary_weight: u32,
+    ) -> DomainResult<()> {
+        if canary_weight > 100 {
+            return Err(DomainError::validation(format!(
+                "canary_weight must be 0-100, got {canary_weight}"
+            )));
+        }
+        validate_name(stable_cluster)?;
+        validate_name(canary_cluster)?;
+        if stable_cluster == canary_cluster {
+            return Err(DomainError::validation(
+                "canary_cluster must differ from stable_cluster",
+            ));
+        }
+        let previous = self.clone();
+        let action = &mut self.route_mut(vhost_name, route_name)?.action;
+        if action.redirect.is_some() || action.direct_response.is_some() {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\" does not forward to a cluster"
+            ))
+            .with_hint("only cluster or weighted_clusters routes can take a canary"));
+        }
+        (action.cluster, action.weighted_clusters) = match canary_weight {
+            0 => (Some(stable_cluster.to_owned()), None),
+            100 => (Some(canary_cluster.to_owned()), None),
+            weight => (
+                None,
+                Some(vec![
+                    WeightedClusterTarget {
+                        cluster: stable_cluster.to_owned(),
+                        weight: 100 - weight,
+                    },
+                    WeightedClusterTarget {
+                        cluster: canary_cluster.to_owned(),
+                        weight,
+                    },
+                ]),
+            ),
+        };
+        if let Err(e) = self.validate() {
+            *self = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// The `(stable, canary)` clusters of a route currently in a canary split: a two-target
+    /// `weighted_clusters` action, stable first (the shape [`Self::set_canary_split`] writes).
+    pub fn canary_split(
+        &self,
+        vhost_name: &str,
+        route_name: &str,
+    ) -> DomainResult<(String, String)> {
+        let route = self
+            .virtual_hosts
+            .iter()
+            .find(|v| v.name == vhost_name)
+            .ok_or_else(|| DomainError::not_found("virtual host", vhost_name))?
+            .routes
+            .iter()
+            .find(|r| r.name == route_name)
+            .ok_or_else(|| DomainError::not_found("route", route_name))?;
+        match route.action.weighted_clusters.as_deref() {
+            Some([stable, canary]) => Ok((stable.cluster.clone(), canary.cluster.clone())),
+            _ => Err(DomainError::validation(format!(
+                "route \"{route_name}\" is not in a canary split"
+            ))
+            .with_hint("start one with POST .../canary")),
+        }
+    }
+
+    fn route_mut(&mut self, vhost_name: &str, route_name: &str) -> DomainResult<&mut RouteRule> {
+        self.virtual_hosts
+            .iter_mut()
+            .find(|v| v.name == vhost_name)
+            .ok_or_else(|| DomainError::not_found("virtual host", vhost_name))?
+            .routes
+            .iter_mut()
+            .find(|r| r.name == route_name)
+            .ok_or_else(|| DomainError::not_found("route", route_name))
+    }
+
     /// Distinct cluster names referenced by any route action (for reference tracking).
     pub fn referenced_clusters(&self) -> HashSet<&str> {
         self.virtual_hosts
@@ -416,6 +810,46 @@ fn validate_header_match(header: &HeaderMatch) -> DomainResult<()> {
     Ok(())
 }
 
+fn validate_methods(rule: &RouteRule) -> DomainResult<()> {
+    if rule.methods.is_empty() {
+        return Ok(());
+    }
+    if rule.methods.len() > MAX_ROUTE_METHODS {
+        return Err(DomainError::validation(format!(
+            "route \"{}\" lists more than {MAX_ROUTE_METHODS} methods",
+            rule.name
+        )));
+    }
+    let mut seen = HashSet::new();
+    for method in &rule.methods {
+        if method.is_empty() || method.len() > 32 || !method.bytes().all(|b| b.is_ascii_uppercase())
+        {
+            return Err(DomainError::validation(format!(
+                "route \"{}\" method \"{method}\" must be an upper-case HTTP method name",
+                rule.name
+            )));
+        }
+        if !seen.insert(method.as_str()) {
+            return Err(DomainError::validation(format!(
+                "duplicate method \"{method}\" in route \"{}\"",
+                rule.name
+            )));
+        }
+    }
+    if rule
+        .headers
+        .iter()
+        .any(|h| h.name.eq_ignore_ascii_case(":method"))
+    {
+        return Err(DomainError::validation(format!(
+            "route \"{}\" sets both methods and a :method header matcher",
+            rule.name
+        ))
+        .with_hint("list every method in `methods` and drop the header matcher"));
+    }
+    Ok(())
+}
+
 fn validate_query_match(query: &QueryParameterMatch) -> DomainResult<()> {
     valid_token("query matcher name", &query.name)?;
     match &query.matcher {
@@ -429,6 +863,56 @@ fn validate_query_match(query: &QueryParameterMatch) -> DomainResult<()> {
     Ok(())
 }
 
+fn validate_hash_policy(route_name: &str, policies: &[HashPolicy]) -> DomainResult<()> {
+    if policies.len() > MAX_HASH_POLICIES {
+        return Err(DomainError::validation(format!(
+            "route \"{route_name}\": at most {MAX_HASH_POLICIES} hash_policy entries are allowed"
+        )));
+    }
+    let mut source_ip = false;
+    for policy in policies {
+        match policy {
+            HashPolicy::Header { header_name, .. } => {
+                valid_token("hash_policy header_name", header_name)?;
+            }
+            HashPolicy::Cookie {
+                name,
+                ttl_secs,
+                path,
+                ..
+            } => {
+                valid_token("hash_policy cookie name", name)?;
+                if name.contains(['=', ';', ',']) {
+                    return Err(DomainError::validation(format!(
+                        "route \"{route_name}\": hash_policy cookie name cannot contain '=', ';' or ','"
+                    )));
+                }
+                if ttl_secs.is_some_and(|ttl| ttl > MAX_HASH_COOKIE_TTL_SECS) {
+                    return Err(DomainError::validation(format!(
+                        "route \"{route_name}\": hash_policy cookie ttl_secs must be 0-{MAX_HASH_COOKIE_TTL_SECS}"
+                    )));
+                }
+                if let Some(path) = path {
+                    valid_path("hash_policy cookie path", path)?;
+                    if path.contains(';') {
+                        return Err(DomainError::validation(format!(
+                            "route \"{route_name}\": hash_policy cookie path cannot contain ';'"
+                        )));
+                    }
+                }
+            }
+            HashPolicy::SourceIp { .. } => {
+                if std::mem::replace(&mut source_ip, true) {
+                    return Err(DomainError::validation(format!(
+                        "route \"{route_name}\": hash_policy lists source_ip twice"
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 fn validate_action(
     action: &RouteAction,
     matcher: &PathMatch,
@@ -449,15 +933,59 @@ fn validate_action(
     if let Some(weighted) = &action.weighted_clusters {
         validate_weighted_clusters(weighted)?;
     }
+    if let Some(fallback) = &action.fallback_cluster {
+        validate_name(fallback)?;
+        if action.cluster.is_none() {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": fallback_cluster requires cluster"
+            )));
+        }
+        if action.cluster.as_deref() == Some(fallback.as_str()) {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": fallback_cluster must differ from cluster"
+            )));
+        }
+    }
+    if action.request_mirror_policies.len() > MAX_REQUEST_MIRRORS {
+        return Err(DomainError::validation(format!(
+            "route \"{route_name}\": at most {MAX_REQUEST_MIRRORS} request_mirror_policies are allowed"
+        )));
+    }
+    let mut mirrored = HashSet::new();
+    for mirror in &action.request_mirror_policies {
+        validate_name(&mirror.cluster)?;
+        if !mirrored.insert(mirror.cluster.as_str()) {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": request_mirror_policies cluster \"{}\" is listed twice",
+                mirror.cluster
+            )));
+        }
+        if mirror.runtime_fraction > 100 {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": request_mirror_policies runtime_fraction must be 0-100"
+            )));
+        }
+    }
+    validate_route_header_removals(route_name, &action.request_headers_to_remove)?;
+    validate_hash_policy(route_name, &action.hash_policy)?;
     if let Some(redirect) = &action.redirect {
         validate_redirect(redirect)?;
         if action.prefix_rewrite.is_some()
             || action.template_rewrite.is_some()
+            || action.regex_rewrite.is_some()
+            || action.host_rewrite_literal.is_some()
+            || action.auto_host_rewrite
             || action.retry_policy.is_some()
+            || action.retry_policy_ref.is_some()
+            || action.idle_timeout_secs.is_some()
+            || action.max_stream_duration_secs.is_some()
+            || !action.request_mirror_policies.is_empty()
+            || !action.request_headers_to_remove.is_empty()
+            || !action.hash_policy.is_empty()
             || !action.rate_limits.is_empty()
         {
             return Err(DomainError::validation(format!(
-                "route \"{route_name}\": redirect cannot combine with route rewrites, retry_policy, or rate_limits"
+                "route \"{route_name}\": redirect cannot combine with route rewrites, stream timeouts, request mirrors, request_headers_to_remove, hash_policy, retry_policy, retry_policy_ref, or rate_limits"
             )));
         }
     }
@@ -465,11 +993,20 @@ fn validate_action(
         validate_direct_response(direct)?;
         if action.prefix_rewrite.is_some()
             || action.template_rewrite.is_some()
+            || action.regex_rewrite.is_some()
+            || action.host_rewrite_literal.is_some()
+            || action.auto_host_rewrite
             || action.retry_policy.is_some()
+            || action.retry_policy_ref.is_some()
+            || action.idle_timeout_secs.is_some()
+            || action.max_stream_duration_secs.is_some()
+            || !action.request_mirror_policies.is_empty()
+            || !action.request_headers_to_remove.is_empty()
+            || !action.hash_policy.is_empty()
             || !action.rate_limits.is_empty()
         {
             return Err(DomainError::validation(format!(
-                "route \"{route_name}\": direct_response cannot combine with route rewrites, retry_policy, or rate_limits"
+                "route \"{route_name}\": direct_response cannot combine with route rewrites, stream timeouts, request mirrors, request_headers_to_remove, hash_policy, retry_policy, retry_policy_ref, or rate_limits"
             )));
         }
     }
@@ -498,13 +1035,46 @@ fn validate_action(
         }
         (None, None) => {}
     }
-    if action.timeout_secs < 1 || action.timeout_secs > 300 {
+    if let Some(rewrite) = &action.regex_rewrite {
+        if action.prefix_rewrite.is_some() || action.template_rewrite.is_some() {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": regex_rewrite is mutually exclusive with prefix_rewrite and template_rewrite",
+            )));
+        }
+        validate_regex_rewrite(rewrite)?;
+    }
+    if let Some(host) = &action.host_rewrite_literal {
+        if action.auto_host_rewrite {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": host_rewrite_literal and auto_host_rewrite are mutually exclusive",
+            )));
+        }
+        valid_token("host_rewrite_literal", host)?;
+    }
+    if action.timeout_secs < 1 || action.timeout_secs > MAX_ROUTE_TIMEOUT_SECS {
         return Err(DomainError::validation(format!(
-            "route \"{route_name}\": timeout_secs must be 1-300",
+            "route \"{route_name}\": timeout_secs must be 1-{MAX_ROUTE_TIMEOUT_SECS}",
         )));
     }
-    if let Some(retry) = &action.retry_policy {
-        validate_retry_policy(retry, action.timeout_secs)?;
+    for (field, value) in [
+        ("idle_timeout_secs", action.idle_timeout_secs),
+        ("max_stream_duration_secs", action.max_stream_duration_secs),
+    ] {
+        if value.is_some_and(|secs| secs > MAX_STREAM_TIMEOUT_SECS) {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": {field} must be 0-{MAX_STREAM_TIMEOUT_SECS}",
+            )));
+        }
+    }
+    match (&action.retry_policy, &action.retry_policy_ref) {
+        (Some(_), Some(_)) => {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": retry_policy and retry_policy_ref are mutually exclusive",
+            )))
+        }
+        (Some(retry), None) => retry.validate(action.timeout_secs)?,
+        (None, Some(policy)) => validate_name(policy)?,
+        (None, None) => {}
     }
     validate_rate_limits(&action.rate_limits)?;
     Ok(())
@@ -560,7 +1130,7 @@ fn validate_rate_limit_action(action: &RateLimitAction) -> DomainResult<()> {
             descriptor_key,
             ..
         } => {
-            valid_token("rate_limits.request_headers.header_name", header_name)?;
+            valid_header_name("rate_limits.request_headers.header_name", header_name)?;
             valid_token("rate_limits.request_headers.descriptor_key", descriptor_key)?;
         }
         RateLimitAction::GenericKey {
@@ -572,6 +1142,74 @@ fn validate_rate_limit_action(action: &RateLimitAction) -> DomainResult<()> {
                 valid_token("rate_limits.generic_key.descriptor_key", descriptor_key)?;
             }
         }
+        RateLimitAction::RemoteAddress => {}
+        RateLimitAction::HeaderValueMatch {
+            descriptor_value,
+            descriptor_key,
+            headers,
+            ..
+        } => {
+            valid_match_value(
+                "rate_limits.header_value_match.descriptor_value",
+                descriptor_value,
+            )?;
+            if let Some(descriptor_key) = descriptor_key {
+                valid_token(
+                    "rate_limits.header_value_match.descriptor_key",
+                    descriptor_key,
+                )?;
+            }
+            if headers.is_empty() || headers.len() > MAX_RATE_LIMIT_HEADER_MATCHERS {
+                return Err(DomainError::validation(format!(
+                    "rate_limits.header_value_match.headers must contain 1-{MAX_RATE_LIMIT_HEADER_MATCHERS} header matchers"
+                ))
+                .with_hint("use generic_key for a descriptor that applies to every request"));
+            }
+            for header in headers {
+                valid_header_name("rate_limits.header_value_match.headers.name", &header.name)?;
+                validate_header_match(header)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_route_header_removals(route_name: &str, names: &[String]) -> DomainResult<()> {
+    if names.len() > MAX_ROUTE_HEADER_REMOVALS {
+        return Err(DomainError::validation(format!(
+            "route \"{route_name}\": at most {MAX_ROUTE_HEADER_REMOVALS} request_headers_to_remove are allowed"
+        )));
+    }
+    let mut seen = HashSet::new();
+    for name in names {
+        valid_header_name("request_headers_to_remove", name)?;
+        // Envoy rejects removing pseudo-headers and Host at config load.
+        if name.starts_with(':') || name.eq_ignore_ascii_case("host") {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": request_headers_to_remove cannot remove \"{name}\""
+            ))
+            .with_hint(
+                "use host_rewrite_literal or auto_host_rewrite to change the upstream Host",
+            ));
+        }
+        if !seen.insert(name.to_ascii_lowercase()) {
+            return Err(DomainError::validation(format!(
+                "route \"{route_name}\": request_headers_to_remove lists \"{name}\" twice"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// A descriptor built from a header must name one: an RFC 9110 token, optionally
+/// prefixed with `:` for pseudo-headers such as `:authority`.
+fn valid_header_name(label: &str, name: &str) -> DomainResult<()> {
+    let token = name.strip_prefix(':').unwrap_or(name);
+    let is_tchar = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c);
+    if token.is_empty() || name.len() > 128 || !token.chars().all(is_tchar) {
+        return Err(DomainError::validation(format!(
+            "{label} must name an HTTP header (token characters, optional leading ':'), got \"{name}\""
+        )));
     }
     Ok(())
 }
@@ -607,6 +1245,15 @@ fn validate_weighted_clusters(weighted: &[WeightedClusterTarget]) -> DomainResul
     Ok(())
 }
 
+impl RetryPolicy {
+    /// Field checks; `per_try_timeout_secs` must also fit within the route's
+    /// `route_timeout_secs`. Named policies are checked against [`MAX_ROUTE_TIMEOUT_SECS`] here
+    /// and against each referencing route when that route is written.
+    pub fn validate(&self, route_timeout_secs: u32) -> DomainResult<()> {
+        validate_retry_policy(self, route_timeout_secs)
+    }
+}
+
 fn validate_retry_policy(retry: &RetryPolicy, route_timeout_secs: u32) -> DomainResult<()> {
     if retry.retry_on.is_empty()
         || retry.retry_on.len() > 200
@@ -687,15 +1334,41 @@ fn validate_redirect(redirect: &RedirectAction) -> DomainResult<()> {
 
 impl RouteAction {
     pub fn referenced_clusters(&self) -> impl Iterator<Item = &str> {
-        self.cluster.iter().map(String::as_str).chain(
-            self.weighted_clusters
-                .iter()
-                .flatten()
-                .map(|target| target.cluster.as_str()),
-        )
+        self.cluster
+            .iter()
+            .chain(self.fallback_cluster.iter())
+            .map(String::as_str)
+            .chain(
+                self.weighted_clusters
+                    .iter()
+                    .flatten()
+                    .map(|target| target.cluster.as_str()),
+            )
+            .chain(
+                self.request_mirror_policies
+                    .iter()
+                    .map(|mirror| mirror.cluster.as_str()),
+            )
+    }
+
+    /// `(primary, fallback)` when the route fails over to a second cluster.
+    pub fn fallback_pair(&self) -> Option<(&str, &str)> {
+        Some((self.cluster.as_deref()?, self.fallback_cluster.as_deref()?))
     }
 }
 
+/// Name of the CP-synthesized aggregate cluster behind a `fallback_cluster` route.
+///
+/// Envoy retries never switch clusters, so failover is expressed as an aggregate cluster
+/// `[primary, fallback]` (priority 0 and 1 of one flattened priority set). The route targets
+/// the aggregate, and its retry policy carries the `previous_priorities` retry-priority
+/// plugin: the first attempt goes to the primary, and a retry (on `5xx` by default) skips
+/// the priority already tried, landing on the fallback. `--` cannot occur in a resource
+/// name, so the synthesized name never collides with a team's own cluster.
+pub fn fallback_aggregate_name(primary: &str, fallback: &str) -> String {
+    format!("{primary}--fallback--{fallback}")
+}
+
 #[cfg(test)]
 #[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -710,7 +1383,9 @@ mod tests {
                     name: "all".into(),
                     matcher: PathMatch::Prefix { prefix: "/".into() },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: RouteAction {
                         cluster: Some(cluster.into()),
                         weighted_clusters: None,
@@ -720,6 +1395,16 @@ mod tests {
                         template_rewrite: None,
                         timeout_secs: 15,
                         retry_policy: None,
+                        retry_policy_ref: None,
+                        idle_timeout_secs: None,
+                        max_stream_duration_secs: None,
+                        regex_rewrite: None,
+                        host_rewrite_literal: None,
+                        auto_host_rewrite: false,
+                        fallback_cluster: None,
+                        request_mirror_policies: Vec::new(),
+                        request_headers_to_remove: Vec::new(),
+                        hash_policy: Vec::new(),
                         rate_limits: Vec::new(),
                     },
                     filter_overrides: Vec::new(),
@@ -730,6 +1415,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn route_names_are_unique_per_virtual_host() {
+        let mut spec = minimal("c");
+        let mut twin = spec.virtual_hosts[0].routes[0].clone();
+        twin.matcher = PathMatch::Prefix {
+            prefix: "/other".into(),
+        };
+        spec.virtual_hosts[0].routes.push(twin);
+        let err = spec.validate().expect_err("duplicate route name");
+        assert_eq!(err.code, crate::error::ErrorCode::ValidationFailed);
+        assert_eq!(
+            err.message,
+            "duplicate route \"all\" in virtual host \"default\""
+        );
+
+        // The same route name in a different virtual host is fine.
+        let mut spec = minimal("c");
+        let mut other = spec.virtual_hosts[0].clone();
+        other.name = "internal".into();
+        other.domains = vec!["internal.example".into()];
+        spec.virtual_hosts.push(other);
+        assert!(spec.validate().is_ok());
+    }
+
     #[test]
     fn filter_override_rules_enforced() {
         use crate::gateway::filters::FilterOverride;
@@ -793,6 +1502,150 @@ mod tests {
         assert!(spec.validate().is_err(), "both rewrites at once");
     }
 
+    #[test]
+    fn methods_compile_to_one_method_matcher() {
+        let mut spec = minimal("c");
+        assert!(spec.virtual_hosts[0].routes[0].method_matcher().is_none());
+
+        spec.virtual_hosts[0].routes[0].methods = vec!["GET".into(), "POST".into()];
+        spec.validate().expect("multi-method route");
+        let matcher = spec.virtual_hosts[0].routes[0]
+            .method_matcher()
+            .expect("matcher");
+        assert_eq!(matcher.name, ":method");
+        assert_eq!(
+            matcher.matcher,
+            HeaderValueMatch::Regex {
+                pattern: "^(?:GET|POST)$".into()
+            }
+        );
+
+        for bad in [vec!["get"], vec!["GET", "GET"], vec!["G ET"]] {
+            let mut spec = minimal("c");
+            spec.virtual_hosts[0].routes[0].methods = bad.iter().map(|m| m.to_string()).collect();
+            assert!(spec.validate().is_err(), "{bad:?}");
+        }
+
+        let mut spec = minimal("c");
+        let rule = &mut spec.virtual_hosts[0].routes[0];
+        rule.methods = vec!["GET".into()];
+        rule.headers = vec![HeaderMatch {
+            name: ":method".into(),
+            invert_match: false,
+            matcher: HeaderValueMatch::Exact {
+                value: "POST".into(),
+            },
+        }];
+        let err = spec.validate().expect_err("methods and :method header");
+        assert!(err.message.contains("both methods and a :method"));
+    }
+
+    #[test]
+    fn virtual_hosts_append_and_remove_without_domain_overlap() {
+        let original = minimal("c");
+        let mut spec = original.clone();
+        let mut api = spec.virtual_hosts[0].clone();
+        api.name = "api".into();
+        api.domains = vec!["api.example.com".into(), "API.example.org".into()];
+        spec.add_virtual_host(api.clone())
+            .expect("disjoint domains");
+        assert_eq!(spec.virtual_hosts.len(), 2);
+
+        let mut clash = api.clone();
+        clash.name = "api2".into();
+        clash.domains = vec!["api.example.org".into()];
+        let err = spec.add_virtual_host(clash).expect_err("domain overlap");
+        assert_eq!(
+            err.message,
+            "domain \"api.example.org\" is served by both virtual hosts \"api\" and \"api2\""
+        );
+        assert_eq!(
+            spec.virtual_hosts.len(),
+            2,
+            "failed append leaves the spec as it was"
+        );
+        let err = spec.add_virtual_host(api).expect_err("name taken");
+        assert_eq!(err.code, crate::error::ErrorCode::Conflict);
+
+        let removed = spec.remove_virtual_host("api").expect("remove");
+        assert_eq!(removed.name, "api");
+        assert_eq!(spec, original);
+        assert_eq!(
+            spec.remove_virtual_host("api").unwrap_err().code,
+            crate::error::ErrorCode::NotFound
+        );
+        assert!(
+            spec.remove_virtual_host("default").is_err(),
+            "the last virtual host stays"
+        );
+    }
+
+    #[test]
+    fn canary_split_shifts_promotes_and_aborts() {
+        let mut spec = minimal("v1");
+        spec.set_canary_split("default", "all", "v1", "v2", 10)
+            .expect("split");
+        assert_eq!(spec.virtual_hosts[0].routes[0].action.cluster, None);
+        assert_eq!(
+            spec.virtual_hosts[0].routes[0].action.weighted_clusters,
+            Some(vec![
+                WeightedClusterTarget {
+                    cluster: "v1".into(),
+                    weight: 90,
+                },
+                WeightedClusterTarget {
+                    cluster: "v2".into(),
+                    weight: 10,
+                },
+            ])
+        );
+        assert_eq!(
+            spec.canary_split("default", "all").expect("in a split"),
+            ("v1".to_owned(), "v2".to_owned())
+        );
+
+        let before = spec.clone();
+        for (stable, canary, weight) in [("v1", "v2", 101), ("v1", "v1", 50)] {
+            assert!(spec
+                .set_canary_split("default", "all", stable, canary, weight)
+                .is_err());
+        }
+        assert_eq!(
+            spec.set_canary_split("default", "missing", "v1", "v2", 5)
+                .unwrap_err()
+                .code,
+            crate::error::ErrorCode::NotFound
+        );
+        assert_eq!(spec, before, "failed shifts leave the spec as it was");
+
+        // Promote (100) and abort (0) collapse to one cluster; a plain route has no split.
+        let mut promoted = spec.clone();
+        promoted
+            .set_canary_split("default", "all", "v1", "v2", 100)
+            .expect("promote");
+        assert_eq!(
+            promoted.virtual_hosts[0].routes[0]
+                .action
+                .cluster
+                .as_deref(),
+            Some("v2")
+        );
+        assert!(promoted.canary_split("default", "all").is_err());
+        spec.set_canary_split("default", "all", "v1", "v2", 0)
+            .expect("abort");
+        assert_eq!(spec, minimal("v1"));
+
+        let mut fixed = minimal("v1");
+        fixed.virtual_hosts[0].routes[0].action.cluster = None;
+        fixed.virtual_hosts[0].routes[0].action.direct_response = Some(DirectResponseAction {
+            status: 200,
+            body: None,
+        });
+        assert!(fixed
+            .set_canary_split("default", "all", "v1", "v2", 10)
+            .is_err());
+    }
+
     #[test]
     fn adversarial_domains_and_paths_rejected() {
         let mut spec = minimal("c");
@@ -858,6 +1711,14 @@ mod tests {
         let refs = spec.referenced_clusters();
         assert!(refs.contains("primary"));
         assert!(refs.contains("canary"));
+
+        spec.virtual_hosts[0].routes[0].action.retry_policy_ref = Some("standard".into());
+        assert!(
+            spec.validate().is_err(),
+            "inline retry_policy and retry_policy_ref at once"
+        );
+        spec.virtual_hosts[0].routes[0].action.retry_policy = None;
+        assert!(spec.validate().is_ok());
     }
 
     #[test]
@@ -888,6 +1749,54 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn runtime_fraction_bounds_the_numerator_by_its_denominator() {
+        let mut spec: RouteConfigSpec = serde_json::from_value(serde_json::json!({
+            "virtual_hosts": [{
+                "name": "default",
+                "domains": ["*"],
+                "routes": [{
+                    "name": "checkout-v2",
+                    "match": {"prefix": {"prefix": "/checkout"}},
+                    "runtime_fraction": {"numerator": 25, "runtime_key": "routes.checkout_v2"},
+                    "action": {"cluster": "primary"}
+                }]
+            }]
+        }))
+        .expect("deserialize");
+        spec.validate().expect("25 out of a hundred");
+        let fraction = spec.virtual_hosts[0].routes[0]
+            .runtime_fraction
+            .as_mut()
+            .expect("fraction");
+        assert_eq!(fraction.denominator, FractionDenominator::Hundred);
+
+        fraction.numerator = 101;
+        let err = spec.validate().expect_err("over a hundred");
+        assert!(err.message.contains("0-100"), "{}", err.message);
+
+        let fraction = spec.virtual_hosts[0].routes[0]
+            .runtime_fraction
+            .as_mut()
+            .expect("fraction");
+        fraction.denominator = FractionDenominator::TenThousand;
+        spec.validate().expect("101 out of ten thousand");
+
+        for key in ["", ".leading", "has space", "routes/slash"] {
+            spec.virtual_hosts[0].routes[0]
+                .runtime_fraction
+                .as_mut()
+                .expect("fraction")
+                .runtime_key = Some(key.into());
+            assert!(spec.validate().is_err(), "runtime_key {key:?}");
+        }
+
+        let unknown = serde_json::from_value::<RuntimeFractionMatch>(
+            serde_json::json!({"numerator": 1, "denominator": "thousand"}),
+        );
+        assert!(unknown.is_err(), "unknown denominators are rejected");
+    }
+
     #[test]
     fn ambiguous_or_lossy_route_options_rejected() {
         let mut spec = minimal("c");
@@ -931,6 +1840,16 @@ mod tests {
             template_rewrite: None,
             timeout_secs: 15,
             retry_policy: None,
+            retry_policy_ref: None,
+            idle_timeout_secs: None,
+            max_stream_duration_secs: None,
+            regex_rewrite: None,
+            host_rewrite_literal: None,
+            auto_host_rewrite: false,
+            fallback_cluster: None,
+            request_mirror_policies: Vec::new(),
+            request_headers_to_remove: Vec::new(),
+            hash_policy: Vec::new(),
             rate_limits: Vec::new(),
         };
         assert!(spec.validate().is_err(), "invalid redirect scheme");
@@ -953,8 +1872,309 @@ mod tests {
             template_rewrite: None,
             timeout_secs: 15,
             retry_policy: None,
+            retry_policy_ref: None,
+            idle_timeout_secs: None,
+            max_stream_duration_secs: None,
+            regex_rewrite: None,
+            host_rewrite_literal: None,
+            auto_host_rewrite: false,
+            fallback_cluster: None,
+            request_mirror_policies: Vec::new(),
+            request_headers_to_remove: Vec::new(),
+            hash_policy: Vec::new(),
             rate_limits: Vec::new(),
         };
         assert!(spec.validate().is_err(), "no-op redirect");
     }
+
+    #[test]
+    fn stream_timeouts_are_bounded_and_forward_only() {
+        let mut spec = minimal("c");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.idle_timeout_secs = Some(0);
+        action.max_stream_duration_secs = Some(3600);
+        spec.validate().expect("0 disables the idle timeout");
+        let json = serde_json::to_value(&spec.virtual_hosts[0].routes[0].action).unwrap();
+        assert_eq!(json["idle_timeout_secs"], 0);
+        assert_eq!(json["max_stream_duration_secs"], 3600);
+
+        spec.virtual_hosts[0].routes[0].action.idle_timeout_secs = Some(86_401);
+        let err = spec.validate().expect_err("over a day");
+        assert!(err.message.contains("idle_timeout_secs must be 0-86400"));
+
+        let mut spec = minimal("c");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.cluster = None;
+        action.direct_response = Some(DirectResponseAction {
+            status: 200,
+            body: None,
+        });
+        action.max_stream_duration_secs = Some(60);
+        assert!(spec.validate().is_err(), "direct_response has no stream");
+    }
+
+    #[test]
+    fn regex_rewrite_must_compile_and_reference_existing_groups() {
+        let mut spec = minimal("c");
+        spec.virtual_hosts[0].routes[0].action.regex_rewrite = Some(RegexRewrite {
+            pattern: "^/legacy/(\\w+)/(\\d+)$".into(),
+            substitution: "/v2/\\2/\\1".into(),
+        });
+        spec.validate().expect("two groups, two references");
+
+        spec.virtual_hosts[0].routes[0].action.regex_rewrite = Some(RegexRewrite {
+            pattern: "^/legacy/(\\w+)$".into(),
+            substitution: "/v2/\\2".into(),
+        });
+        let err = spec.validate().expect_err("group 2 does not exist");
+        assert!(err.message.contains("references \\2"), "{}", err.message);
+
+        spec.virtual_hosts[0].routes[0].action.regex_rewrite = Some(RegexRewrite {
+            pattern: "^/legacy/(".into(),
+            substitution: "/".into(),
+        });
+        let err = spec.validate().expect_err("unbalanced group");
+        assert!(err.message.contains("does not compile"), "{}", err.message);
+
+        let mut spec = minimal("c");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.prefix_rewrite = Some("/v2".into());
+        action.regex_rewrite = Some(RegexRewrite {
+            pattern: "^/(.*)$".into(),
+            substitution: "/\\1".into(),
+        });
+        let err = spec.validate().expect_err("one path rewrite at a time");
+        assert!(err.message.contains("mutually exclusive"));
+    }
+
+    #[test]
+    fn fallback_cluster_needs_a_distinct_single_primary() {
+        let mut spec = minimal("payments");
+        spec.virtual_hosts[0].routes[0].action.fallback_cluster = Some("payments-dr".into());
+        spec.validate().expect("primary plus fallback");
+        let refs = spec.referenced_clusters();
+        assert!(refs.contains("payments") && refs.contains("payments-dr"));
+        assert_eq!(
+            spec.virtual_hosts[0].routes[0].action.fallback_pair(),
+            Some(("payments", "payments-dr"))
+        );
+
+        spec.virtual_hosts[0].routes[0].action.fallback_cluster = Some("payments".into());
+        let err = spec.validate().expect_err("fallback to itself");
+        assert!(err.message.contains("must differ from cluster"));
+
+        let mut spec = minimal("payments");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.cluster = None;
+        action.weighted_clusters = Some(vec![WeightedClusterTarget {
+            cluster: "payments".into(),
+            weight: 100,
+        }]);
+        action.fallback_cluster = Some("payments-dr".into());
+        let err = spec.validate().expect_err("weighted has no single primary");
+        assert!(err.message.contains("fallback_cluster requires cluster"));
+    }
+
+    #[test]
+    fn request_mirrors_are_bounded_percentages_on_forward_routes() {
+        let mut spec = minimal("orders");
+        spec.virtual_hosts[0].routes[0]
+            .action
+            .request_mirror_policies =
+            serde_json::from_value(serde_json::json!([{ "cluster": "orders-shadow" }])).unwrap();
+        assert_eq!(
+            spec.virtual_hosts[0].routes[0]
+                .action
+                .request_mirror_policies[0]
+                .runtime_fraction,
+            100,
+            "fraction defaults to all requests"
+        );
+        spec.validate().expect("mirror validates");
+        assert!(spec.referenced_clusters().contains("orders-shadow"));
+
+        spec.virtual_hosts[0].routes[0]
+            .action
+            .request_mirror_policies[0]
+            .runtime_fraction = 101;
+        let err = spec.validate().expect_err("over 100 percent");
+        assert!(err.message.contains("runtime_fraction must be 0-100"));
+
+        let mut spec = minimal("orders");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.cluster = None;
+        action.direct_response = Some(DirectResponseAction {
+            status: 200,
+            body: None,
+        });
+        action.request_mirror_policies = vec![RequestMirrorPolicy {
+            cluster: "orders-shadow".into(),
+            runtime_fraction: 10,
+        }];
+        let err = spec.validate().expect_err("nothing to mirror");
+        assert!(err.message.contains("request mirrors"));
+    }
+
+    #[test]
+    fn hash_policies_validate_their_inputs_on_forward_routes() {
+        let mut spec = minimal("carts");
+        spec.virtual_hosts[0].routes[0].action.hash_policy =
+            serde_json::from_value(serde_json::json!([
+                { "type": "cookie", "name": "route", "ttl_secs": 3600, "path": "/", "terminal": true },
+                { "type": "header", "header_name": "x-user-id" },
+                { "type": "source_ip" }
+            ]))
+            .unwrap();
+        spec.validate().expect("hash policy validates");
+
+        let policies = &mut spec.virtual_hosts[0].routes[0].action.hash_policy;
+        policies.push(HashPolicy::SourceIp { terminal: false });
+        let err = spec.validate().expect_err("source_ip twice");
+        assert!(err.message.contains("source_ip twice"));
+
+        spec.virtual_hosts[0].routes[0].action.hash_policy = vec![HashPolicy::Cookie {
+            name: "a=b".into(),
+            ttl_secs: None,
+            path: None,
+            terminal: false,
+        }];
+        let err = spec.validate().expect_err("cookie name with '='");
+        assert!(err.message.contains("cookie name"));
+
+        spec.virtual_hosts[0].routes[0].action.hash_policy = vec![HashPolicy::Cookie {
+            name: "route".into(),
+            ttl_secs: Some(MAX_HASH_COOKIE_TTL_SECS + 1),
+            path: None,
+            terminal: false,
+        }];
+        assert!(spec.validate().is_err(), "ttl over a year");
+
+        let mut spec = minimal("carts");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.cluster = None;
+        action.direct_response = Some(DirectResponseAction {
+            status: 200,
+            body: None,
+        });
+        action.hash_policy = vec![HashPolicy::SourceIp { terminal: false }];
+        let err = spec.validate().expect_err("nothing to hash for");
+        assert!(err.message.contains("hash_policy"));
+    }
+
+    #[test]
+    fn structured_rate_limit_descriptors_deserialize_and_require_headers() {
+        let mut spec = minimal("c");
+        spec.virtual_hosts[0].routes[0].action.rate_limits =
+            serde_json::from_value(serde_json::json!([{
+                "actions": [
+                    { "type": "remote_address" },
+                    { "type": "request_headers", "header_name": ":authority", "descriptor_key": "host" },
+                    {
+                        "type": "header_value_match",
+                        "descriptor_value": "premium",
+                        "headers": [{ "name": "x-plan", "type": "exact", "value": "premium" }]
+                    }
+                ]
+            }]))
+            .unwrap();
+        let actions = &spec.virtual_hosts[0].routes[0].action.rate_limits[0].actions;
+        assert_eq!(actions[0], RateLimitAction::RemoteAddress);
+        assert!(matches!(
+            &actions[2],
+            RateLimitAction::HeaderValueMatch { expect_match: true, headers, .. } if headers.len() == 1
+        ));
+        spec.validate().expect("descriptors validate");
+
+        let set_action = |spec: &mut RouteConfigSpec, action: RateLimitAction| {
+            spec.virtual_hosts[0].routes[0].action.rate_limits = vec![RateLimitDefinition {
+                stage: None,
+                disable_key: None,
+                actions: vec![action],
+            }];
+        };
+        let mut spec = minimal("c");
+        set_action(
+            &mut spec,
+            RateLimitAction::HeaderValueMatch {
+                descriptor_value: "premium".into(),
+                descriptor_key: None,
+                expect_match: true,
+                headers: Vec::new(),
+            },
+        );
+        let err = spec
+            .validate()
+            .expect_err("header_value_match without headers");
+        assert!(err
+            .message
+            .contains("header_value_match.headers must contain 1-8"));
+
+        for bad in ["", "x user", ":", "x-plan\n"] {
+            let mut spec = minimal("c");
+            set_action(
+                &mut spec,
+                RateLimitAction::RequestHeaders {
+                    header_name: bad.into(),
+                    descriptor_key: "plan".into(),
+                    skip_if_absent: false,
+                },
+            );
+            let err = spec
+                .validate()
+                .expect_err("request_headers must name a header");
+            assert!(err.message.contains("must name an HTTP header"), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn route_header_removals_are_unique_real_headers_on_forward_routes() {
+        let mut spec = minimal("c");
+        spec.virtual_hosts[0].routes[0]
+            .action
+            .request_headers_to_remove = vec!["x-debug".into(), "x-trace".into()];
+        spec.validate().expect("removals validate");
+
+        for (names, needle) in [
+            (vec!["x-debug", "X-Debug"], "lists \"X-Debug\" twice"),
+            (vec![":authority"], "cannot remove \":authority\""),
+            (vec!["Host"], "cannot remove \"Host\""),
+            (vec!["x debug"], "must name an HTTP header"),
+        ] {
+            let mut spec = minimal("c");
+            spec.virtual_hosts[0].routes[0]
+                .action
+                .request_headers_to_remove = names.into_iter().map(String::from).collect();
+            let err = spec.validate().expect_err(needle);
+            assert!(err.message.contains(needle), "{}", err.message);
+        }
+
+        let mut spec = minimal("c");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.cluster = None;
+        action.direct_response = Some(DirectResponseAction {
+            status: 204,
+            body: None,
+        });
+        action.request_headers_to_remove = vec!["x-debug".into()];
+        let err = spec.validate().expect_err("no upstream request to strip");
+        assert!(err.message.contains("request_headers_to_remove"));
+    }
+
+    #[test]
+    fn host_rewrite_literal_and_auto_are_exclusive() {
+        let mut spec = minimal("c");
+        let action = &mut spec.virtual_hosts[0].routes[0].action;
+        action.host_rewrite_literal = Some("api.internal".into());
+        spec.validate().expect("literal host");
+        spec.virtual_hosts[0].routes[0].action.auto_host_rewrite = true;
+        let err = spec.validate().expect_err("both host rewrites");
+        assert!(err.message.contains("mutually exclusive"));
+
+        let mut spec = minimal("c");
+        spec.virtual_hosts[0].routes[0].action.auto_host_rewrite = true;
+        spec.validate().expect("auto host rewrite");
+        let json = serde_json::to_value(&spec.virtual_hosts[0].routes[0].action).unwrap();
+        assert_eq!(json["auto_host_rewrite"], true);
+        assert!(json.get("host_rewrite_literal").is_none());
+    }
 }
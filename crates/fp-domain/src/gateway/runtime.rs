@@ -0,0 +1,99 @@
+//! Runtime values: team-owned key/value overrides served to proxies over RTDS. Routes and
+//! filters read them by key (`runtime_key`), so an operator can turn a knob — a traffic
+//! fraction, a feature gate — without changing the config that reads it.
+
+use crate::id::TeamId;
+use crate::{DomainError, DomainResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Name of the RTDS layer the control plane serves; the bootstrap subscribes to it.
+pub const RUNTIME_LAYER_NAME: &str = "flowplane";
+
+/// Upper bound on a string value; runtime strings are knobs, not payloads.
+pub const MAX_RUNTIME_STRING_LEN: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeValue {
+    pub team_id: TeamId,
+    pub key: String,
+    /// A JSON number, boolean or string.
+    pub value: serde_json::Value,
+    pub version: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Envoy runtime keys are dotted paths: 1-256 letters, digits, `_`, `-` or `.`, not starting
+/// or ending with a dot.
+pub fn valid_runtime_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= 256
+        && !key.starts_with('.')
+        && !key.ends_with('.')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+pub fn validate_runtime_key(key: &str) -> DomainResult<()> {
+    if valid_runtime_key(key) {
+        Ok(())
+    } else {
+        Err(DomainError::validation(format!(
+            "runtime key \"{key}\" must be 1-256 letters, digits, '_', '-' or '.', not starting \
+             or ending with '.'"
+        )))
+    }
+}
+
+/// Only scalars map onto a single runtime key; objects and arrays would be flattened by
+/// Envoy into keys nobody set.
+pub fn validate_runtime_value(key: &str, value: &serde_json::Value) -> DomainResult<()> {
+    match value {
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) => Ok(()),
+        serde_json::Value::String(s) if s.chars().count() <= MAX_RUNTIME_STRING_LEN => Ok(()),
+        serde_json::Value::String(_) => Err(DomainError::validation(format!(
+            "runtime value for \"{key}\" is longer than {MAX_RUNTIME_STRING_LEN} characters"
+        ))),
+        _ => Err(DomainError::validation(format!(
+            "runtime value for \"{key}\" must be a number, boolean or string"
+        ))
+        .with_hint("set each nested key separately, e.g. routes.split.enabled")),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn keys_are_dotted_paths_and_values_are_scalars() {
+        for key in ["routes.split.enabled", "health_check.min_interval", "a-b_c"] {
+            assert!(validate_runtime_key(key).is_ok(), "{key}");
+        }
+        for key in [
+            "",
+            ".leading",
+            "trailing.",
+            "has space",
+            "slash/key",
+            &"k".repeat(257),
+        ] {
+            assert!(validate_runtime_key(key).is_err(), "{key:?}");
+        }
+        for value in [json!(25), json!(0.5), json!(true), json!("on")] {
+            assert!(validate_runtime_value("k", &value).is_ok(), "{value}");
+        }
+        for value in [
+            json!(null),
+            json!([1]),
+            json!({"numerator": 1}),
+            json!("x".repeat(MAX_RUNTIME_STRING_LEN + 1)),
+        ] {
+            assert!(validate_runtime_value("k", &value).is_err(), "{value}");
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Uploaded proxy-wasm modules. Each upload under a name is a new immutable version; a `wasm`
+//! chain filter references one by name and version, and the bytes are only read back at
+//! snapshot build time, when they are inlined into the listener.
+
+use crate::id::{TeamId, WasmModuleId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Upper bound on an uploaded module, the same as an inline one: the base64 upload must fit
+/// the API's default 2 MiB body limit, and Envoy receives the bytes inline in every LDS push.
+pub const MAX_WASM_MODULE_BYTES: usize = 1_048_576;
+
+/// Module version metadata. The bytes themselves are never part of a read path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WasmModule {
+    pub id: WasmModuleId,
+    pub team_id: TeamId,
+    pub name: String,
+    /// 1 for the first upload under `name`, then one more per upload.
+    pub version: i64,
+    /// Lowercase hex SHA-256 of the module bytes.
+    pub sha256: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
@@ -110,6 +110,26 @@ domain_id!(
     /// Identifies an SDS secret.
     SecretId
 );
+domain_id!(
+    /// Identifies an uploaded protobuf descriptor set (gRPC-JSON transcoding).
+    ProtoDescriptorId
+);
+domain_id!(
+    /// Identifies a named, team-owned HTTP filter preset.
+    FilterPresetId
+);
+domain_id!(
+    /// Identifies one uploaded version of a team's wasm module.
+    WasmModuleId
+);
+domain_id!(
+    /// Identifies a named, team-owned route retry policy.
+    RetryPolicyId
+);
+domain_id!(
+    /// Identifies a team's webhook subscription.
+    WebhookSubscriptionId
+);
 domain_id!(
     /// Identifies an AI provider.
     AiProviderId
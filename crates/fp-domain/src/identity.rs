@@ -158,11 +158,82 @@ pub struct Team {
     pub org_id: OrgId,
     pub name: String,
     pub display_name: String,
+    pub description: String,
+    /// Loopback admin port the team's Envoy bootstraps default to; unique within the org.
+    pub envoy_admin_port: Option<u16>,
+    /// How the team's Envoy bootstraps expose the admin interface; `None` is localhost.
+    pub envoy_admin_access: Option<EnvoyAdminAccess>,
     pub status: EntityStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+pub const TEAM_DESCRIPTION_MAX_LEN: usize = 1000;
+
+/// The editable part of a team: everything but its name, which is immutable once created.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamProfile {
+    pub display_name: String,
+    pub description: String,
+    pub envoy_admin_port: Option<u16>,
+    pub envoy_admin_access: Option<EnvoyAdminAccess>,
+}
+
+/// Exposure of Envoy's admin interface in generated bootstraps. The admin API has no
+/// authentication of its own, so it is never bound to a routable address.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvoyAdminAccess {
+    /// Bound to `127.0.0.1` on the admin port: reachable from the proxy's own host only.
+    #[default]
+    Localhost,
+    /// Bound to a unix socket (mode 0600): reachable only by the proxy's OS user.
+    Socket,
+    /// No admin interface at all.
+    Disabled,
+}
+
+impl EnvoyAdminAccess {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Localhost => "localhost",
+            Self::Socket => "socket",
+            Self::Disabled => "disabled",
+        }
+    }
+
+    pub fn parse(raw: &str) -> DomainResult<Self> {
+        match raw {
+            "localhost" => Ok(Self::Localhost),
+            "socket" => Ok(Self::Socket),
+            "disabled" => Ok(Self::Disabled),
+            other => Err(DomainError::validation(format!(
+                "\"{other}\" is not an envoy admin access mode (localhost, socket, disabled)"
+            ))),
+        }
+    }
+}
+
+impl TeamProfile {
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.description.chars().count() > TEAM_DESCRIPTION_MAX_LEN
+            || self
+                .description
+                .chars()
+                .any(|c| c.is_control() && c != '\n')
+        {
+            return Err(DomainError::validation(format!(
+                "team description must be at most {TEAM_DESCRIPTION_MAX_LEN} characters without control characters"
+            )));
+        }
+        if self.envoy_admin_port == Some(0) {
+            return Err(DomainError::validation("envoy_admin_port must be 1-65535")
+                .with_hint("omit envoy_admin_port to use the bootstrap default (9901)"));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct User {
     pub id: UserId,
@@ -226,6 +297,45 @@ mod tests {
         assert!(!err.message.contains('\x07'));
     }
 
+    #[test]
+    fn team_profile_rejects_port_zero_and_control_chars() {
+        let ok = TeamProfile {
+            display_name: "Payments".into(),
+            description: "Owns checkout.\nOn call: #payments".into(),
+            envoy_admin_port: Some(9902),
+            envoy_admin_access: Some(EnvoyAdminAccess::Socket),
+        };
+        assert!(ok.validate().is_ok());
+        for bad in [
+            TeamProfile {
+                envoy_admin_port: Some(0),
+                ..ok.clone()
+            },
+            TeamProfile {
+                description: "bell\x07".into(),
+                ..ok.clone()
+            },
+            TeamProfile {
+                description: "a".repeat(TEAM_DESCRIPTION_MAX_LEN + 1),
+                ..ok.clone()
+            },
+        ] {
+            assert!(bad.validate().is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn envoy_admin_access_round_trips() {
+        for access in [
+            EnvoyAdminAccess::Localhost,
+            EnvoyAdminAccess::Socket,
+            EnvoyAdminAccess::Disabled,
+        ] {
+            assert_eq!(EnvoyAdminAccess::parse(access.as_str()).ok(), Some(access));
+        }
+        assert!(EnvoyAdminAccess::parse("public").is_err());
+    }
+
     #[test]
     fn org_role_ordering_reflects_power() {
         assert!(OrgRole::Owner > OrgRole::Admin);
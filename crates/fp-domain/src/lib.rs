@@ -7,6 +7,7 @@
 pub mod ai;
 pub mod api_lifecycle;
 pub mod authz;
+pub mod connected;
 pub mod dataplane;
 pub mod discovery;
 pub mod error;
@@ -16,8 +17,10 @@ pub mod id;
 pub mod identity;
 pub mod learning;
 pub mod rate_limit;
+pub mod rollout;
 pub mod route_generation;
 pub mod secret;
+pub mod webhook;
 
 pub use ai::{
     ai_error_envelope, complete_sse_events_end, openai_usage_from_json,
@@ -29,7 +32,10 @@ pub use ai::{
     OpenAiChatRequest, OpenAiTokenUsage, AI_MODEL_HEADER, DEFAULT_AI_ROUTE_TIMEOUT_SECS,
     MAX_AI_REQUEST_BODY_BYTES, MAX_AI_TRACE_TTL_DAYS,
 };
-pub use dataplane::{validate_spiffe_uri, Dataplane, ProxyCertificate, TeamStatsOverview};
+pub use dataplane::{
+    validate_spiffe_uri, Dataplane, DataplaneClusterStats, ProxyCertificate, TeamStatsOverview,
+    UpstreamClusterStats,
+};
 pub use discovery::{
     cluster_discovery_observations, DiscoveryCandidateCluster, DiscoveryObservation,
     DiscoveryObservationKey, DiscoveryObservationProvenance, DiscoverySession,
@@ -38,13 +44,15 @@ pub use discovery::{
 pub use error::{DomainError, DomainResult, ErrorCode};
 pub use id::{
     AgentId, AiBudgetId, AiProviderId, AiRouteId, ApiDefinitionId, ApiRouteBindingId, ApiToolId,
-    AuditEntryId, CaptureSessionId, ClusterId, DataplaneId, DiscoverySessionId, GrantId,
-    ListenerId, MembershipId, OrgId, ProxyCertificateId, RateLimitDomainId, RateLimitPolicyId,
-    RateLimitTeamOverrideId, RawObservationId, RequestId, RetentionPolicyId, RouteConfigId,
-    RouteGenerationPlanId, SecretId, SpecVersionId, SpecVersionReviewEventId, TeamId, UserId,
+    AuditEntryId, CaptureSessionId, ClusterId, DataplaneId, DiscoverySessionId, FilterPresetId,
+    GrantId, ListenerId, MembershipId, OrgId, ProtoDescriptorId, ProxyCertificateId,
+    RateLimitDomainId, RateLimitPolicyId, RateLimitTeamOverrideId, RawObservationId, RequestId,
+    RetentionPolicyId, RetryPolicyId, RouteConfigId, RouteGenerationPlanId, SecretId,
+    SpecVersionId, SpecVersionReviewEventId, TeamId, UserId, WasmModuleId, WebhookSubscriptionId,
 };
 pub use identity::{
-    validate_name, Agent, AgentKind, EntityStatus, OrgRole, Organization, Team, User,
+    validate_name, Agent, AgentKind, EntityStatus, EnvoyAdminAccess, OrgRole, Organization, Team,
+    TeamProfile, User,
 };
 pub use rate_limit::{
     descriptors_canonical, validate_rate_limit_domain_name, validate_rate_limit_policy_name,
@@ -52,6 +60,8 @@ pub use rate_limit::{
     RateLimitTeamOverrideSpec, RateLimitUnit,
 };
 pub use route_generation::{
-    RouteGenerationPlan, RouteGenerationPlanSpec, RouteGenerationPlanStatus,
+    PlanSync, ResourceSource, RouteGenerationPlan, RouteGenerationPlanSpec,
+    RouteGenerationPlanStatus, SyncAction,
 };
 pub use secret::{Secret, SecretSpec, SecretType};
+pub use webhook::{WebhookSubscription, WebhookSubscriptionSpec};
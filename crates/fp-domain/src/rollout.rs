@@ -0,0 +1,114 @@
+//! Staged config rollout: holding proxies on an earlier xDS config version.
+//!
+//! Every distinct snapshot the xDS server builds for a team is recorded under a config
+//! version, a content digest of the served resources. A dataplane pin holds one dataplane on
+//! a recorded version; a team rollout holds every proxy outside the canary share on its
+//! stable version. Promoting clears the pin or the rollout and the proxies move to latest.
+
+use crate::error::{DomainError, DomainResult};
+use crate::id::TeamId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Hex digits in a config version.
+pub const CONFIG_VERSION_LEN: usize = 16;
+
+/// A team's rollout: proxies outside the canary share stay on `stable_version`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigRollout {
+    pub team_id: TeamId,
+    pub stable_version: String,
+    /// Share of the team's proxies (0-100) that receive the latest config.
+    pub canary_percent: u8,
+    pub version: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One recorded config version of a team.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigVersion {
+    pub config_version: String,
+    pub created_at: DateTime<Utc>,
+}
+
+pub fn validate_config_version(version: &str) -> DomainResult<()> {
+    if version.len() != CONFIG_VERSION_LEN
+        || !version
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    {
+        return Err(DomainError::validation(format!(
+            "config version must be {CONFIG_VERSION_LEN} lowercase hex characters"
+        ))
+        .with_hint("list recorded versions with GET /api/v1/teams/{team}/config-versions"));
+    }
+    Ok(())
+}
+
+pub fn validate_canary_percent(percent: i64) -> DomainResult<u8> {
+    u8::try_from(percent)
+        .ok()
+        .filter(|percent| *percent <= 100)
+        .ok_or_else(|| DomainError::validation("canary_percent must be between 0 and 100"))
+}
+
+/// Stable 0-99 bucket for a node id (FNV-1a), so a proxy keeps its canary membership across
+/// reconnects and replicas.
+pub fn canary_bucket(node_id: &str) -> u8 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in node_id.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    (hash % 100) as u8
+}
+
+/// The version a proxy is held on, or `None` for latest. A dataplane pin wins over the
+/// team rollout; under a rollout the proxies in the canary share get latest.
+pub fn held_version<'a>(
+    dataplane_pin: Option<&'a str>,
+    rollout: Option<(&'a str, u8)>,
+    node_id: &str,
+) -> Option<&'a str> {
+    if let Some(pin) = dataplane_pin {
+        return Some(pin);
+    }
+    let (stable_version, canary_percent) = rollout?;
+    (canary_bucket(node_id) >= canary_percent).then_some(stable_version)
+}
+
+#[cfg(test)]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dataplane_pin_wins_and_the_canary_share_gets_latest() {
+        assert_eq!(held_version(Some("a"), Some(("b", 100)), "n"), Some("a"));
+        assert_eq!(held_version(None, None, "n"), None);
+        assert_eq!(held_version(None, Some(("b", 0)), "n"), Some("b"));
+        assert_eq!(held_version(None, Some(("b", 100)), "n"), None);
+
+        let nodes: Vec<String> = (0..1000).map(|i| format!("node-{i}")).collect();
+        let canaries = nodes
+            .iter()
+            .filter(|node| held_version(None, Some(("b", 25)), node).is_none())
+            .count();
+        assert!(
+            (150..350).contains(&canaries),
+            "{canaries} of 1000 in a 25% canary"
+        );
+        assert_eq!(canary_bucket("node-7"), canary_bucket("node-7"));
+    }
+
+    #[test]
+    fn config_versions_and_percentages_are_validated() {
+        assert!(validate_config_version("0123456789abcdef").is_ok());
+        assert!(validate_config_version("0123456789ABCDEF").is_err());
+        assert!(validate_config_version("0123").is_err());
+        assert_eq!(validate_canary_percent(40).expect("valid"), 40);
+        assert!(validate_canary_percent(101).is_err());
+        assert!(validate_canary_percent(-1).is_err());
+    }
+}
@@ -2,11 +2,12 @@
 
 use crate::gateway::cluster::ClusterSpec;
 use crate::gateway::listener::ListenerSpec;
-use crate::gateway::route_config::RouteConfigSpec;
+use crate::gateway::route_config::{RouteConfigSpec, RouteRule};
 use crate::id::{ApiDefinitionId, RouteGenerationPlanId, SpecVersionId, TeamId};
 use crate::{DomainError, DomainResult};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RouteGenerationPlan {
@@ -45,6 +46,37 @@ impl RouteGenerationPlanStatus {
     }
 }
 
+/// How a cluster, route config or listener came to exist, for list filtering: written
+/// directly through the API, or produced by an applied route generation plan (an OpenAPI
+/// import). Resources are matched to plans by name, so a resource recreated by hand under a
+/// name an applied plan once used still counts as imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceSource {
+    NativeApi,
+    OpenapiImport,
+}
+
+impl ResourceSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NativeApi => "native_api",
+            Self::OpenapiImport => "openapi_import",
+        }
+    }
+
+    /// Parse a caller-supplied `?source=` value.
+    pub fn parse(raw: &str) -> DomainResult<Self> {
+        match raw {
+            "native_api" => Ok(Self::NativeApi),
+            "openapi_import" => Ok(Self::OpenapiImport),
+            other => Err(
+                DomainError::validation(format!("unknown resource source \"{other}\""))
+                    .with_hint("use source=native_api or source=openapi_import"),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct RouteGenerationPlanSpec {
@@ -61,4 +93,72 @@ pub struct RouteGenerationPlanSpec {
     pub conflicts: Vec<String>,
     #[serde(default)]
     pub metadata: serde_json::Value,
+    /// Set on a sync plan: it updates the resources of the API's last applied plan in place.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync: Option<PlanSync>,
+}
+
+/// What a sync plan does to one generated resource on apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncAction {
+    Create,
+    Update,
+    Unchanged,
+}
+
+/// Diff of a sync plan against the resources the API's last applied plan produced. Apply
+/// updates those resources in place instead of creating new ones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlanSync {
+    /// The applied plan this one supersedes (the lineage link).
+    pub previous_plan_id: RouteGenerationPlanId,
+    pub cluster: SyncAction,
+    pub route_config: SyncAction,
+    pub listener: SyncAction,
+    /// Route names, per [`diff_routes`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes_added: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes_updated: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes_removed: Vec<String>,
+}
+
+/// Route-level diff of two route configs, by `vhost/route` name: added, updated (same name,
+/// different rule), removed. Each list is sorted.
+pub fn diff_routes(
+    current: &RouteConfigSpec,
+    desired: &RouteConfigSpec,
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let (current, desired) = (routes_by_name(current), routes_by_name(desired));
+    let added = desired
+        .keys()
+        .filter(|name| !current.contains_key(*name))
+        .cloned()
+        .collect();
+    let updated = desired
+        .iter()
+        .filter(|(name, rule)| current.get(*name).is_some_and(|old| old != *rule))
+        .map(|(name, _)| name.clone())
+        .collect();
+    let removed = current
+        .keys()
+        .filter(|name| !desired.contains_key(*name))
+        .cloned()
+        .collect();
+    (added, updated, removed)
+}
+
+fn routes_by_name(spec: &RouteConfigSpec) -> BTreeMap<String, &RouteRule> {
+    spec.virtual_hosts
+        .iter()
+        .flat_map(|vhost| {
+            vhost
+                .routes
+                .iter()
+                .map(move |route| (format!("{}/{}", vhost.name, route.name), route))
+        })
+        .collect()
 }
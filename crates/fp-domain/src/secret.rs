@@ -17,6 +17,8 @@ pub struct Secret {
     pub version: i64,
     pub encryption_key_id: String,
     pub expires_at: Option<DateTime<Utc>>,
+    /// When the expiry sweep withdrew the secret from SDS; cleared by a rotation.
+    pub expired_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,145 @@
+//! Team webhook subscriptions: an HTTP(S) endpoint that receives the team's domain events,
+//! narrowed by an event filter. The signing key is a reference to one of the team's
+//! `generic_secret`s, never a value stored here.
+
+use crate::error::{DomainError, DomainResult};
+use crate::event::EVENT_KINDS;
+use crate::id::{SecretId, TeamId, WebhookSubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Upper bound on filters per subscription.
+pub const MAX_WEBHOOK_EVENT_FILTERS: usize = 32;
+const MAX_WEBHOOK_URL_LEN: usize = 2048;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookSubscription {
+    pub id: WebhookSubscriptionId,
+    pub team_id: TeamId,
+    pub name: String,
+    pub spec: WebhookSubscriptionSpec,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookSubscriptionSpec {
+    pub url: String,
+    /// Event filters: an exact kind (`cluster.upserted`), a family (`cluster.*`), or `*`.
+    pub events: Vec<String>,
+    /// Team `generic_secret` whose decoded value keys the body signature; unsigned when absent.
+    pub secret_id: Option<SecretId>,
+}
+
+impl WebhookSubscriptionSpec {
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.url.len() > MAX_WEBHOOK_URL_LEN {
+            return Err(DomainError::validation(format!(
+                "webhook url must be at most {MAX_WEBHOOK_URL_LEN} characters"
+            )));
+        }
+        self.host()?;
+        if self.events.is_empty() {
+            return Err(DomainError::validation("webhook events must not be empty")
+                .with_hint("use \"*\" to receive every event"));
+        }
+        if self.events.len() > MAX_WEBHOOK_EVENT_FILTERS {
+            return Err(DomainError::validation(format!(
+                "webhook events may list at most {MAX_WEBHOOK_EVENT_FILTERS} filters"
+            )));
+        }
+        for filter in &self.events {
+            if !EVENT_KINDS.iter().any(|kind| filter_matches(filter, kind)) {
+                return Err(DomainError::validation(format!(
+                    "\"{filter}\" is not a known event kind or family"
+                ))
+                .with_hint("e.g. \"cluster.upserted\", \"capture_session.*\", or \"*\""));
+            }
+        }
+        Ok(())
+    }
+
+    /// The URL's host: `http` or `https`, no userinfo.
+    pub fn host(&self) -> DomainResult<String> {
+        let url = url::Url::parse(&self.url).map_err(|_| {
+            DomainError::validation("webhook url must be a valid http:// or https:// URL")
+        })?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(DomainError::validation(
+                "webhook url must start with http:// or https://",
+            ));
+        }
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err(DomainError::validation(
+                "webhook url must not include userinfo; sign deliveries with secret_id instead",
+            ));
+        }
+        url.host_str()
+            .filter(|host| !host.is_empty())
+            .map(|host| {
+                host.trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_string()
+            })
+            .ok_or_else(|| DomainError::validation("webhook url must include a host"))
+    }
+
+    /// Whether an event of `kind` passes this subscription's filters.
+    pub fn wants(&self, kind: &str) -> bool {
+        self.events
+            .iter()
+            .any(|filter| filter_matches(filter, kind))
+    }
+}
+
+fn filter_matches(filter: &str, kind: &str) -> bool {
+    match filter.strip_suffix(".*") {
+        Some(family) => kind
+            .strip_prefix(family)
+            .is_some_and(|rest| rest.starts_with('.')),
+        None => filter == "*" || filter == kind,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn spec(events: &[&str]) -> WebhookSubscriptionSpec {
+        WebhookSubscriptionSpec {
+            url: "https://hooks.example.com/flowplane".into(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            secret_id: None,
+        }
+    }
+
+    #[test]
+    fn filters_match_exact_kinds_families_and_everything() {
+        let exact = spec(&["capture_session.stopped"]);
+        assert!(exact.wants("capture_session.stopped"));
+        assert!(!exact.wants("capture_session.started"));
+
+        let family = spec(&["cluster.*"]);
+        assert!(family.wants("cluster.upserted"));
+        assert!(family.wants("cluster.deleted"));
+        assert!(!family.wants("route_config.upserted"));
+
+        assert!(spec(&["*"]).wants("secret.expired"));
+    }
+
+    #[test]
+    fn unknown_filters_and_non_http_urls_are_rejected() {
+        assert!(spec(&["cluster.*", "secret.expired", "*"])
+            .validate()
+            .is_ok());
+        assert!(spec(&["learning_session_completed"]).validate().is_err());
+        assert!(spec(&["clus.*"]).validate().is_err());
+        assert!(spec(&[]).validate().is_err());
+
+        let mut bad = spec(&["*"]);
+        bad.url = "ftp://hooks.example.com/".into();
+        assert!(bad.validate().is_err());
+        bad.url = "https://user:pw@hooks.example.com/".into();
+        assert!(bad.validate().is_err());
+    }
+}
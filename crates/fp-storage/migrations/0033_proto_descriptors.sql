@@ -0,0 +1,21 @@
+-- 0033: uploaded protobuf FileDescriptorSets for the gRPC-JSON transcoder filter. The raw
+-- bytes are validated (they must decode as a FileDescriptorSet) before they land here, and
+-- the declared `{package}.{Service}` names are kept alongside so listener writes can check
+-- a transcoder's `services` without re-decoding.
+
+CREATE TABLE proto_descriptors (
+    id              UUID PRIMARY KEY,
+    team_id         UUID NOT NULL,
+    org_id          UUID NOT NULL,
+    name            TEXT NOT NULL,
+    descriptor_set  BYTEA NOT NULL,
+    services        JSONB NOT NULL DEFAULT '[]'::jsonb,
+    version         BIGINT NOT NULL DEFAULT 1,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (team_id, name),
+    UNIQUE (id, team_id),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_proto_descriptors_team ON proto_descriptors(team_id);
@@ -0,0 +1,19 @@
+-- 0034: named, team-owned route retry policies. Route forward actions reference one by name
+-- (`retry_policy_ref` inside route_configs.spec); the xDS translator expands it inline, so
+-- there is no FK — the service layer guards deletes against live references instead.
+
+CREATE TABLE retry_policies (
+    id              UUID PRIMARY KEY,
+    team_id         UUID NOT NULL,
+    org_id          UUID NOT NULL,
+    name            TEXT NOT NULL,
+    spec            JSONB NOT NULL,
+    version         BIGINT NOT NULL DEFAULT 1,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (team_id, name),
+    UNIQUE (id, team_id),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_retry_policies_team ON retry_policies(team_id);
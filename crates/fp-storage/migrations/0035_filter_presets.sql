@@ -0,0 +1,19 @@
+-- 0035: named, team-owned HTTP filter presets. A listener chain entry names one and the
+-- listener write path copies the preset's filter into listeners.spec, so nothing stored
+-- references a preset afterwards — no FK and no delete guard.
+
+CREATE TABLE filter_presets (
+    id              UUID PRIMARY KEY,
+    team_id         UUID NOT NULL,
+    org_id          UUID NOT NULL,
+    name            TEXT NOT NULL,
+    spec            JSONB NOT NULL,
+    version         BIGINT NOT NULL DEFAULT 1,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (team_id, name),
+    UNIQUE (id, team_id),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_filter_presets_team ON filter_presets(team_id);
@@ -0,0 +1,6 @@
+-- 0036: org-standard HTTP filters (JSON array of HttpFilterEntry). The listener write path
+-- merges them into a team's FIRST listener; nothing references the column afterwards, so
+-- edits apply to teams that have not yet created a listener.
+
+ALTER TABLE organizations
+    ADD COLUMN default_listener_filters JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,8 @@
+-- 0037: expiry enforcement for SDS secrets. `expired_at` is stamped by the CP sweep when a
+-- secret's `expires_at` passes and its SDS resource is withdrawn; a rotation clears it.
+
+ALTER TABLE secrets
+    ADD COLUMN expired_at TIMESTAMPTZ;
+
+CREATE INDEX idx_secrets_pending_expiry ON secrets(expires_at)
+    WHERE expires_at IS NOT NULL AND expired_at IS NULL;
@@ -0,0 +1,11 @@
+-- 0038: per-team quota overrides. A row replaces the built-in default limit for one
+-- resource kind (`Resource::as_str`); no row means the default applies. Set by platform
+-- admins; removed with the team.
+
+CREATE TABLE team_quotas (
+    team_id    UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    resource   TEXT NOT NULL,
+    max_count  BIGINT NOT NULL CHECK (max_count >= 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (team_id, resource)
+);
@@ -0,0 +1,11 @@
+-- 0039: team profile fields. `description` is free text; `envoy_admin_port` is the loopback
+-- admin port the team's Envoy bootstraps default to. An assigned port is unique within the
+-- org so co-hosted dataplanes of different teams never collide.
+
+ALTER TABLE teams
+    ADD COLUMN description      TEXT NOT NULL DEFAULT '',
+    ADD COLUMN envoy_admin_port INTEGER CHECK (envoy_admin_port BETWEEN 1 AND 65535);
+
+CREATE UNIQUE INDEX idx_teams_org_envoy_admin_port
+    ON teams (org_id, envoy_admin_port)
+    WHERE envoy_admin_port IS NOT NULL;
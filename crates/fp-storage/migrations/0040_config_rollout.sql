@@ -0,0 +1,29 @@
+-- 0040: staged config rollout. The xDS server records each distinct snapshot it builds for
+-- a team under a content-derived config version. A dataplane pin or a team rollout holds
+-- proxies on one recorded version until it is promoted; the composite FKs keep a held
+-- version from being pruned and from naming another team's snapshot.
+
+CREATE TABLE xds_config_versions (
+    team_id        UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    config_version TEXT NOT NULL,
+    -- Served resources per type URL: {"<type_url>": ["<base64 Any>", ...]}.
+    snapshot       JSONB NOT NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (team_id, config_version)
+);
+CREATE INDEX idx_xds_config_versions_recent ON xds_config_versions(team_id, created_at DESC);
+
+ALTER TABLE dataplanes
+    ADD COLUMN pinned_config_version TEXT,
+    ADD FOREIGN KEY (team_id, pinned_config_version)
+        REFERENCES xds_config_versions(team_id, config_version);
+
+CREATE TABLE config_rollouts (
+    team_id        UUID PRIMARY KEY REFERENCES teams(id) ON DELETE CASCADE,
+    stable_version TEXT NOT NULL,
+    canary_percent SMALLINT NOT NULL CHECK (canary_percent BETWEEN 0 AND 100),
+    version        BIGINT NOT NULL DEFAULT 1,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (team_id, stable_version) REFERENCES xds_config_versions(team_id, config_version)
+);
@@ -0,0 +1,6 @@
+-- 0041: how the team's generated Envoy bootstraps expose the admin interface. NULL keeps
+-- the default, a loopback-only admin listener.
+
+ALTER TABLE teams
+    ADD COLUMN envoy_admin_access TEXT
+        CHECK (envoy_admin_access IN ('localhost', 'socket', 'disabled'));
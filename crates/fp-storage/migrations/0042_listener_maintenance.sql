@@ -0,0 +1,6 @@
+-- 0042: listener maintenance mode. NULL is normal serving; a value is the direct response
+-- ({"status", "body"}) the listener answers every request with instead of its route config.
+-- Kept out of `spec` so a spec PATCH never toggles it and turning it off restores the
+-- listener exactly.
+
+ALTER TABLE listeners ADD COLUMN maintenance JSONB;
@@ -0,0 +1,6 @@
+-- 0043: HTTP/3 listeners bind UDP, so a team may hold one TCP and one QUIC listener on the
+-- same port (the usual 443/tcp + 443/udp edge pair). Port uniqueness is now per transport.
+
+DROP INDEX idx_listeners_team_port;
+CREATE UNIQUE INDEX idx_listeners_team_port
+    ON listeners(team_id, ((spec->>'port')::int), (coalesce(spec->>'protocol', '') = 'http3'));
@@ -0,0 +1,18 @@
+-- 0044: uploaded proxy-wasm modules for the wasm HTTP filter. Every upload under a name is a
+-- new immutable version; the SHA-256 is computed on upload and kept alongside the bytes so
+-- metadata reads never touch the module itself.
+
+CREATE TABLE wasm_modules (
+    id          UUID PRIMARY KEY,
+    team_id     UUID NOT NULL,
+    org_id      UUID NOT NULL,
+    name        TEXT NOT NULL,
+    version     BIGINT NOT NULL CHECK (version >= 1),
+    module      BYTEA NOT NULL,
+    sha256      TEXT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (team_id, name, version),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_wasm_modules_team ON wasm_modules(team_id);
@@ -0,0 +1,14 @@
+-- 0045: runtime values served to a team's proxies over RTDS. One row per key; the value is a
+-- JSON scalar, and the version counts writes so a change is visible in list output.
+
+CREATE TABLE runtime_values (
+    team_id     UUID NOT NULL,
+    org_id      UUID NOT NULL,
+    key         TEXT NOT NULL,
+    value       JSONB NOT NULL,
+    version     BIGINT NOT NULL DEFAULT 1 CHECK (version >= 1),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (team_id, key),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE
+);
@@ -0,0 +1,9 @@
+-- 0046: per-session capture controls for learning sessions. sample_percent keeps a stable
+-- share of requests (NULL keeps all); max_body_bytes caps each stored body; the redaction
+-- lists mask extra headers and JSON body fields before an observation is persisted.
+
+ALTER TABLE capture_sessions
+    ADD COLUMN sample_percent INTEGER CHECK (sample_percent BETWEEN 1 AND 100),
+    ADD COLUMN max_body_bytes INTEGER CHECK (max_body_bytes BETWEEN 1 AND 65536),
+    ADD COLUMN redact_headers TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN redact_body_fields TEXT[] NOT NULL DEFAULT '{}';
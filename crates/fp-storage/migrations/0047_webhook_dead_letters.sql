@@ -0,0 +1,17 @@
+-- 0047: webhook deliveries that failed every retry. The payload is kept for replay; the
+-- platform-admin listing shows ids and delivery status only. Rows go with their team.
+
+CREATE TABLE webhook_dead_letters (
+    id           UUID PRIMARY KEY,
+    team_id      UUID NOT NULL,
+    org_id       UUID NOT NULL,
+    event        TEXT NOT NULL,
+    payload      JSONB NOT NULL,
+    attempts     INTEGER NOT NULL CHECK (attempts >= 1),
+    last_status  INTEGER,
+    last_error   TEXT NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE
+);
+
+CREATE INDEX webhook_dead_letters_created_idx ON webhook_dead_letters (created_at DESC);
@@ -0,0 +1,21 @@
+-- 0048: team webhook subscriptions. The signing key stays in secrets; this table stores the
+-- reference. Dead letters from a subscription name it, and outlive it.
+
+CREATE TABLE webhook_subscriptions (
+    id          UUID PRIMARY KEY,
+    team_id     UUID NOT NULL,
+    org_id      UUID NOT NULL,
+    name        TEXT NOT NULL,
+    url         TEXT NOT NULL,
+    events      TEXT[] NOT NULL,
+    secret_id   UUID,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (team_id, name),
+    FOREIGN KEY (team_id, org_id) REFERENCES teams(id, org_id) ON DELETE CASCADE,
+    FOREIGN KEY (secret_id, team_id) REFERENCES secrets(id, team_id)
+);
+
+CREATE INDEX idx_webhook_subscriptions_team ON webhook_subscriptions(team_id);
+
+ALTER TABLE webhook_dead_letters
+    ADD COLUMN subscription_id UUID REFERENCES webhook_subscriptions(id) ON DELETE SET NULL;
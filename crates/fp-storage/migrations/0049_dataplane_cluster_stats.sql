@@ -0,0 +1,22 @@
+-- 0049: latest upstream cluster gauges per dataplane, as its agent reported them. Each report
+-- replaces the dataplane's rows; only clusters the team owns are kept. Rows go with their
+-- dataplane.
+
+CREATE TABLE dataplane_cluster_stats (
+    dataplane_id           UUID NOT NULL,
+    team_id                UUID NOT NULL,
+    cluster_name           TEXT NOT NULL,
+    rq_active              BIGINT NOT NULL,
+    rq_pending_active      BIGINT NOT NULL,
+    cx_active              BIGINT NOT NULL,
+    ejections_active       BIGINT NOT NULL,
+    healthy_hosts          BIGINT NOT NULL,
+    total_hosts            BIGINT NOT NULL,
+    open_circuit_breakers  TEXT[] NOT NULL DEFAULT '{}',
+    observed_at            TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (dataplane_id, cluster_name),
+    FOREIGN KEY (dataplane_id, team_id) REFERENCES dataplanes(id, team_id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_dataplane_cluster_stats_cluster
+    ON dataplane_cluster_stats(team_id, cluster_name);
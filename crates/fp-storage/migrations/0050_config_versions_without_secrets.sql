@@ -0,0 +1,6 @@
+-- 0050: recorded config versions no longer carry secrets. Held proxies are served the team's
+-- current SDS set, so the decrypted secret resources earlier versions stored are dropped.
+
+UPDATE xds_config_versions
+SET snapshot = snapshot - 'type.googleapis.com/envoy.extensions.transport_sockets.tls.v3.Secret'
+WHERE snapshot ? 'type.googleapis.com/envoy.extensions.transport_sockets.tls.v3.Secret';
@@ -27,12 +27,42 @@ pub fn ensure_crypto_provider() {
     }
 }
 
+/// Pool sizing and connection lifetimes. The server fills this from `ServerConfig`; tests and
+/// one-shot commands use [`connect`] with the defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolSettings {
+    pub max_connections: u32,
+    /// How long a caller waits for a free connection before the attempt times out.
+    pub acquire_timeout: Duration,
+    /// Connections older than this are closed and replaced; `None` keeps them indefinitely.
+    pub max_lifetime: Option<Duration>,
+}
+
+impl PoolSettings {
+    pub const DEFAULT_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(5);
+    pub const DEFAULT_MAX_LIFETIME: Duration = Duration::from_secs(30 * 60);
+
+    pub fn new(max_connections: u32) -> Self {
+        Self {
+            max_connections,
+            acquire_timeout: Self::DEFAULT_ACQUIRE_TIMEOUT,
+            max_lifetime: Some(Self::DEFAULT_MAX_LIFETIME),
+        }
+    }
+}
+
 /// Connect a pool with sane timeouts. Fails with `unavailable` + hint, never hangs forever.
 pub async fn connect(database_url: &str, max_connections: u32) -> DomainResult<PgPool> {
+    connect_with(database_url, PoolSettings::new(max_connections)).await
+}
+
+/// [`connect`] with explicit pool settings.
+pub async fn connect_with(database_url: &str, settings: PoolSettings) -> DomainResult<PgPool> {
     ensure_crypto_provider();
     PgPoolOptions::new()
-        .max_connections(max_connections)
-        .acquire_timeout(Duration::from_secs(5))
+        .max_connections(settings.max_connections)
+        .acquire_timeout(settings.acquire_timeout)
+        .max_lifetime(settings.max_lifetime)
         .connect(database_url)
         .await
         .map_err(|e| {
@@ -41,6 +71,55 @@ pub async fn connect(database_url: &str, max_connections: u32) -> DomainResult<P
         })
 }
 
+/// Retries after a failed connection attempt.
+const ACQUIRE_RETRIES: u32 = 2;
+const ACQUIRE_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Start a transaction, retrying a bounded number of times with exponential backoff when
+/// opening a connection fails. Nothing has run on the connection yet, so a retry is always
+/// safe. An exhausted pool is not retried: the attempt already waited the full acquire
+/// timeout, and waiting it out again would only deepen the pile-up.
+pub async fn begin(
+    pool: &PgPool,
+) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, sqlx::Error> {
+    let mut attempt = 0;
+    loop {
+        match pool.begin().await {
+            Err(e) if is_transient_acquire_error(&e) && attempt < ACQUIRE_RETRIES => {
+                tokio::time::sleep(ACQUIRE_BACKOFF * 2u32.pow(attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_transient_acquire_error(e: &sqlx::Error) -> bool {
+    if matches!(e, sqlx::Error::PoolTimedOut) {
+        metrics::counter!("fp_db_acquire_timeouts_total").increment(1);
+        return false;
+    }
+    matches!(e, sqlx::Error::Io(_))
+}
+
+/// Map a database error to the domain taxonomy. An exhausted or unreachable pool is
+/// `unavailable` (retryable, with a short `Retry-After`); anything else is an internal failure
+/// whose `label` and detail go to the logs only.
+pub fn db_error(label: &str, e: sqlx::Error) -> DomainError {
+    match e {
+        sqlx::Error::PoolTimedOut => {
+            DomainError::unavailable(format!("{label}: no database connection available"))
+                .with_hint("the control plane is under load; retry shortly")
+                .with_retry_after(1)
+        }
+        sqlx::Error::PoolClosed | sqlx::Error::Io(_) => {
+            DomainError::unavailable(format!("{label}: database is unreachable"))
+                .with_retry_after(1)
+        }
+        e => DomainError::internal(format!("{label}: {e}")),
+    }
+}
+
 /// Apply pending migrations. Forward-only; safe to run on every boot.
 pub async fn migrate(pool: &PgPool) -> DomainResult<()> {
     MIGRATOR
@@ -88,6 +167,45 @@ mod tests {
         assert!(ping(&pool).await.is_ok());
     }
 
+    #[test]
+    fn exhausted_pool_is_retryable_and_other_errors_are_internal() {
+        let err = db_error("create cluster: begin", sqlx::Error::PoolTimedOut);
+        assert_eq!(err.code, fp_domain::ErrorCode::Unavailable);
+        assert_eq!(err.retry_after_seconds, Some(1));
+        let err = db_error("create cluster", sqlx::Error::RowNotFound);
+        assert_eq!(err.code, fp_domain::ErrorCode::Internal);
+        assert!(err.message.starts_with("create cluster: "));
+    }
+
+    #[tokio::test]
+    async fn begin_reports_an_exhausted_pool_after_one_acquire_timeout() {
+        let Some(url) = test_db_url() else {
+            eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+            return;
+        };
+        let pool = connect_with(
+            &url,
+            PoolSettings {
+                max_connections: 1,
+                acquire_timeout: Duration::from_millis(200),
+                max_lifetime: None,
+            },
+        )
+        .await
+        .expect("test database must be reachable");
+        let held = pool.acquire().await.expect("the only connection");
+        let started = std::time::Instant::now();
+        let err = begin(&pool).await.expect_err("the pool is exhausted");
+        assert!(matches!(err, sqlx::Error::PoolTimedOut), "{err}");
+        // One acquire timeout; retries would take at least 3 × 200 ms plus backoff.
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(200), "{elapsed:?}");
+        assert!(elapsed < Duration::from_millis(700), "{elapsed:?}");
+        drop(held);
+        let tx = begin(&pool).await.expect("a connection is free again");
+        tx.rollback().await.expect("rollback");
+    }
+
     #[tokio::test]
     async fn connect_to_unreachable_host_fails_with_unavailable() {
         let err = connect("postgres://nobody@127.0.0.1:1/none", 1)
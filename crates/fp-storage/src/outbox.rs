@@ -94,6 +94,24 @@ pub async fn register_consumer_at_head(pool: &PgPool, consumer: &str) -> DomainR
     Ok(())
 }
 
+/// Register a consumer cursor at the current event head if it does not exist yet; an existing
+/// cursor keeps its position.
+///
+/// For consumers added to a running deployment that should start with new events rather than
+/// replay the whole history (outbound notifications). Call before `run_consumer`.
+pub async fn register_consumer_from_head(pool: &PgPool, consumer: &str) -> DomainResult<()> {
+    sqlx::query(
+        "INSERT INTO event_cursors (consumer, last_seq) \
+         VALUES ($1, (SELECT coalesce(max(seq), 0) FROM events)) \
+         ON CONFLICT (consumer) DO NOTHING",
+    )
+    .bind(consumer)
+    .execute(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("outbox: register consumer from head: {e}")))?;
+    Ok(())
+}
+
 /// Process at most one batch for `consumer`. Returns the number of events handled.
 ///
 /// The cursor row is locked (SKIP LOCKED) for the duration: if another replica holds it,
@@ -109,8 +127,7 @@ where
     F: FnOnce(Vec<StoredEvent>) -> Fut,
     Fut: Future<Output = DomainResult<()>>,
 {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("outbox: begin: {e}")))?;
 
@@ -288,9 +305,14 @@ fn like_contains_pattern(raw: &str) -> String {
 
 /// Long-running consumer loop: LISTEN for wakeups with a poll fallback, drain batches,
 /// stop when `shutdown` flips. Handler errors back off and retry (at-least-once).
+///
+/// After a wakeup the loop waits `debounce` before draining, so a burst of writes lands in
+/// one batch and the handler does its work once for all of them. `Duration::ZERO` drains
+/// immediately.
 pub async fn run_consumer<F, Fut>(
     pool: PgPool,
     consumer: &str,
+    debounce: Duration,
     handler: F,
     mut shutdown: tokio::sync::watch::Receiver<bool>,
 ) -> DomainResult<()>
@@ -328,10 +350,18 @@ where
             }
         }
         // Sleep until notified, polled (covers missed notifications), or shut down.
-        tokio::select! {
-            _ = listener.recv() => {},
-            _ = tokio::time::sleep(Duration::from_secs(5)) => {},
-            _ = shutdown.changed() => {},
+        let notified = tokio::select! {
+            _ = listener.recv() => true,
+            _ = tokio::time::sleep(Duration::from_secs(5)) => false,
+            _ = shutdown.changed() => false,
+        };
+        // Notifications that arrive during the window stay queued on the listener; the next
+        // wakeup then finds little or nothing to drain, which costs one cursor query.
+        if notified && !debounce.is_zero() {
+            tokio::select! {
+                _ = tokio::time::sleep(debounce) => {},
+                _ = shutdown.changed() => {},
+            }
         }
     }
 }
@@ -593,6 +623,80 @@ mod tests {
         assert!(caught_up, "consumer A catches up to zero lag");
     }
 
+    #[tokio::test]
+    async fn debounce_window_coalesces_a_burst_of_writes() {
+        let Some(pool) = pool().await else { return };
+        let consumer = unique_consumer();
+        register_consumer_at_head(&pool, &consumer)
+            .await
+            .expect("register at head");
+        let prefix = format!("burst-{}", unique_consumer());
+
+        // Which handler call (batch number) each of our events arrived in.
+        let batches: Arc<std::sync::Mutex<Vec<(usize, String)>>> = Arc::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+        let task = {
+            let (pool, consumer, prefix) = (pool.clone(), consumer.clone(), prefix.clone());
+            let (batches, calls) = (batches.clone(), calls.clone());
+            tokio::spawn(async move {
+                run_consumer(
+                    pool,
+                    &consumer,
+                    Duration::from_secs(1),
+                    move |events| {
+                        let call = calls.fetch_add(1, Ordering::SeqCst);
+                        let (batches, prefix) = (batches.clone(), prefix.clone());
+                        async move {
+                            let mut guard = batches.lock().unwrap_or_else(|p| p.into_inner());
+                            for e in events {
+                                if let DomainEvent::ClusterUpserted { name, .. } = e.event {
+                                    if name.starts_with(&prefix) {
+                                        guard.push((call, name));
+                                    }
+                                }
+                            }
+                            Ok(())
+                        }
+                    },
+                    shutdown_rx,
+                )
+                .await
+            })
+        };
+
+        let seen = |count: usize| {
+            let batches = batches.clone();
+            async move {
+                for _ in 0..100 {
+                    if batches.lock().unwrap_or_else(|p| p.into_inner()).len() >= count {
+                        return;
+                    }
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                }
+                panic!("consumer never delivered {count} events");
+            }
+        };
+        // The first event proves the consumer is parked on LISTEN; the burst follows.
+        append_one(&pool, &format!("{prefix}-warmup")).await;
+        seen(1).await;
+        // Spaced well past the time a drain takes, so without a window each append would get
+        // its own handler call.
+        for i in 0..3 {
+            append_one(&pool, &format!("{prefix}-{i}")).await;
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        seen(4).await;
+
+        shutdown_tx.send(true).expect("shutdown");
+        task.await.expect("join").expect("consumer");
+        let batches = batches.lock().unwrap_or_else(|p| p.into_inner()).clone();
+        let mut burst: Vec<usize> = batches[1..].iter().map(|(call, _)| *call).collect();
+        burst.dedup();
+        // One call, or two when a window opened by a sibling test's event closed mid-burst.
+        assert!(burst.len() <= 2, "burst not coalesced: {batches:?}");
+    }
+
     #[test]
     fn trace_like_pattern_escapes_wildcards() {
         assert_eq!(
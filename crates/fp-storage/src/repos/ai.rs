@@ -136,8 +136,7 @@ pub async fn record_usage_event_and_settle_budgets(
     pool: &PgPool,
     event: AiUsageEventInsert,
 ) -> DomainResult<()> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("record AI usage event: begin: {e}")))?;
     insert_usage_event_in_tx(&mut tx, &event).await?;
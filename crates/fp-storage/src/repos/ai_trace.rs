@@ -94,8 +94,7 @@ pub async fn upsert_trace_event(
     pool: &PgPool,
     event: &AiTraceEventUpsert,
 ) -> DomainResult<AiTraceUpsertOutcome> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("upsert AI trace event: begin: {e}")))?;
     let ttl_days: Option<i32> =
@@ -34,7 +34,8 @@ const RETENTION_COLUMNS: &str = "id, team_id, api_definition_id, name, raw_obser
     max_spec_versions, created_at, updated_at";
 const CAPTURE_SESSION_COLUMNS: &str = "id, team_id, name, status, api_definition_id, \
     route_config_id, listener_id, virtual_host, route, target_sample_count, max_duration_seconds, \
-    max_bytes, max_distinct_paths, sample_count, byte_count, path_count, drop_count, started_at, \
+    max_bytes, max_distinct_paths, sample_percent, max_body_bytes, redact_headers, \
+    redact_body_fields, sample_count, byte_count, path_count, drop_count, started_at, \
     completed_at, cancelled_at, updated_at, created_at";
 const RAW_OBSERVATION_COLUMNS: &str = "id, team_id, capture_session_id, request_id, method, path, \
     response_status, request_headers, response_headers, request_body, response_body, \
@@ -221,6 +222,10 @@ fn capture_session_from_row(row: &PgRow) -> DomainResult<CaptureSession> {
         max_duration_seconds: row.get("max_duration_seconds"),
         max_bytes: row.get("max_bytes"),
         max_distinct_paths: row.get("max_distinct_paths"),
+        sample_percent: row.get("sample_percent"),
+        max_body_bytes: row.get("max_body_bytes"),
+        redact_headers: row.get("redact_headers"),
+        redact_body_fields: row.get("redact_body_fields"),
         sample_count: row.get("sample_count"),
         byte_count: row.get("byte_count"),
         path_count: row.get("path_count"),
@@ -283,7 +288,7 @@ fn merged_body_bytes(
         .unwrap_or(0)
 }
 
-fn sanitize_headers(headers: &Map<String, Value>) -> Value {
+fn sanitize_headers(headers: &Map<String, Value>, session: &CaptureSession) -> Value {
     const REDACTED_HEADERS: &[&str] = &[
         "authorization",
         "proxy-authorization",
@@ -318,7 +323,7 @@ fn sanitize_headers(headers: &Map<String, Value>) -> Value {
         if DROPPED_HEADERS.contains(&lower.as_str()) {
             continue;
         }
-        if REDACTED_HEADERS.contains(&lower.as_str()) {
+        if REDACTED_HEADERS.contains(&lower.as_str()) || session.redacts_header(&lower) {
             out.insert(name.clone(), Value::String("[REDACTED]".to_string()));
         } else {
             out.insert(name.clone(), value.clone());
@@ -1086,8 +1091,10 @@ pub async fn create_capture_session(
         "INSERT INTO capture_sessions \
          (id, team_id, org_id, name, status, api_definition_id, route_config_id, listener_id, \
           virtual_host, route, target_sample_count, max_duration_seconds, max_bytes, \
-          max_distinct_paths) \
-         VALUES ($1, $2, $3, $4, 'capturing', $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+          max_distinct_paths, sample_percent, max_body_bytes, redact_headers, \
+          redact_body_fields) \
+         VALUES ($1, $2, $3, $4, 'capturing', $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, \
+                 $16, $17) \
          RETURNING {CAPTURE_SESSION_COLUMNS}"
     ))
     .bind(CaptureSessionId::generate().as_uuid())
@@ -1103,6 +1110,10 @@ pub async fn create_capture_session(
     .bind(spec.max_duration_seconds)
     .bind(spec.max_bytes)
     .bind(spec.max_distinct_paths)
+    .bind(spec.sample_percent)
+    .bind(spec.max_body_bytes)
+    .bind(&spec.redact_headers)
+    .bind(&spec.redact_body_fields)
     .fetch_one(&mut **tx)
     .await
     .map_err(|e| map_unique(e, "learning session", name))?;
@@ -1319,6 +1330,26 @@ pub async fn validate_capture_ingest_binding(
     Ok(session)
 }
 
+/// A session's raw observations without locking it, for read-only exports.
+pub async fn list_raw_observations(
+    pool: &PgPool,
+    team_id: TeamId,
+    session_id: CaptureSessionId,
+) -> DomainResult<Vec<RawObservation>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {RAW_OBSERVATION_COLUMNS} FROM raw_observations \
+         WHERE team_id = $1 AND capture_session_id = $2 ORDER BY observed_at, id"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(session_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list raw observations: {e}")))?;
+    Ok(rows.iter().map(raw_observation_from_row).collect())
+}
+
+/// Records one capture message for a request. `None` means the session's `sample_percent`
+/// skipped the request, so nothing was stored or counted.
 pub async fn ingest_raw_observation(
     tx: &mut Transaction<'_, Postgres>,
     team: TeamRef,
@@ -1327,7 +1358,7 @@ pub async fn ingest_raw_observation(
     route_config_id: RouteConfigId,
     listener_id: Option<ListenerId>,
     input: &ObservationIngest,
-) -> DomainResult<RawObservation> {
+) -> DomainResult<Option<RawObservation>> {
     input.validate()?;
     let session = get_capture_session_for_update(tx, team.id, &session_id.to_string()).await?;
     let existing =
@@ -1344,6 +1375,9 @@ pub async fn ingest_raw_observation(
     .await?;
     if existing.is_none() {
         reject_expired_session(tx, &session).await?;
+        if !session.samples(&input.request_id) {
+            return Ok(None);
+        }
     }
     if let Some(existing) = &existing {
         if existing.method != input.method || existing.path != input.path {
@@ -1361,8 +1395,12 @@ pub async fn ingest_raw_observation(
         .with_hint("start a new learning session for additional samples"));
     }
 
-    let incoming_request_headers = sanitize_headers(&input.request_headers);
-    let incoming_response_headers = sanitize_headers(&input.response_headers);
+    let incoming_request_headers = sanitize_headers(&input.request_headers, &session);
+    let incoming_response_headers = sanitize_headers(&input.response_headers, &session);
+    let (request_body, request_body_truncated) =
+        session.redact_body(input.request_body.clone(), input.request_body_truncated);
+    let (response_body, response_body_truncated) =
+        session.redact_body(input.response_body.clone(), input.response_body_truncated);
     let merged = match existing.as_ref() {
         Some(existing) => RawObservation {
             response_status: input.response_status.or(existing.response_status),
@@ -1374,17 +1412,10 @@ pub async fn ingest_raw_observation(
                 existing.response_headers.clone(),
                 incoming_response_headers,
             ),
-            request_body: input
-                .request_body
-                .clone()
-                .or_else(|| existing.request_body.clone()),
-            response_body: input
-                .response_body
-                .clone()
-                .or_else(|| existing.response_body.clone()),
-            request_body_truncated: existing.request_body_truncated || input.request_body_truncated,
-            response_body_truncated: existing.response_body_truncated
-                || input.response_body_truncated,
+            request_body: request_body.or_else(|| existing.request_body.clone()),
+            response_body: response_body.or_else(|| existing.response_body.clone()),
+            request_body_truncated: existing.request_body_truncated || request_body_truncated,
+            response_body_truncated: existing.response_body_truncated || response_body_truncated,
             metadata_seen: existing.metadata_seen || input.metadata_seen,
             body_seen: existing.body_seen || input.body_seen,
             observed_at: existing.observed_at.min(input.observed_at),
@@ -1400,10 +1431,10 @@ pub async fn ingest_raw_observation(
             response_status: input.response_status,
             request_headers: incoming_request_headers,
             response_headers: incoming_response_headers,
-            request_body: input.request_body.clone(),
-            response_body: input.response_body.clone(),
-            request_body_truncated: input.request_body_truncated,
-            response_body_truncated: input.response_body_truncated,
+            request_body,
+            response_body,
+            request_body_truncated,
+            response_body_truncated,
             request_body_bytes: 0,
             response_body_bytes: 0,
             metadata_seen: input.metadata_seen,
@@ -1490,7 +1521,7 @@ pub async fn ingest_raw_observation(
         &input.request_id,
     )
     .await?;
-    Ok(raw_observation_from_row(&row))
+    Ok(Some(raw_observation_from_row(&row)))
 }
 
 pub async fn delete_expired_raw_observations_for_team(
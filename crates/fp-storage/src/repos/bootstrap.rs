@@ -91,8 +91,7 @@ pub async fn seed_token_hash_if_uninitialized(
     pool: &PgPool,
     token: &str,
 ) -> DomainResult<SeedOutcome> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("bootstrap seed: begin: {e}")))?;
 
@@ -196,8 +195,7 @@ pub async fn initialize(
         .with_hint("supply a valid bootstrap token to an uninitialized instance and retry")
     };
 
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("bootstrap: begin: {e}")))?;
 
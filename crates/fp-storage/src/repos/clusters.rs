@@ -4,7 +4,8 @@
 
 use crate::scope::TeamScope;
 use fp_domain::gateway::cluster::{Cluster, ClusterSpec};
-use fp_domain::{ClusterId, DomainError, DomainResult, ErrorCode, TeamId};
+use fp_domain::gateway::ListSort;
+use fp_domain::{ClusterId, DomainError, DomainResult, ErrorCode, ResourceSource, TeamId};
 use sqlx::postgres::PgRow;
 use sqlx::{PgPool, Postgres, Row, Transaction};
 use uuid::Uuid;
@@ -110,6 +111,8 @@ pub async fn get(pool: &PgPool, scope: TeamScope, name: &str) -> DomainResult<Op
 pub async fn list(
     pool: &PgPool,
     scope: TeamScope,
+    source: Option<ResourceSource>,
+    sort: ListSort,
     limit: i64,
     offset: i64,
 ) -> DomainResult<(Vec<Cluster>, i64)> {
@@ -118,8 +121,10 @@ pub async fn list(
             "platform-admin cluster reads are not a supported path (tenant resource)",
         ));
     };
+    let filter = super::route_generation::source_filter(source, "clusters", "cluster_name");
+    let order = super::gateway::order_by(sort);
     let rows = sqlx::query(&format!(
-        "SELECT {COLUMNS} FROM clusters WHERE team_id = $1 AND owner_kind = 'user' ORDER BY name LIMIT $2 OFFSET $3"
+        "SELECT {COLUMNS} FROM clusters WHERE team_id = $1 AND owner_kind = 'user'{filter} ORDER BY {order} LIMIT $2 OFFSET $3"
     ))
     .bind(team_id.as_uuid())
     .bind(limit.clamp(1, 500))
@@ -127,9 +132,9 @@ pub async fn list(
     .fetch_all(pool)
     .await
     .map_err(|e| DomainError::internal(format!("list clusters: {e}")))?;
-    let total: i64 = sqlx::query_scalar(
-        "SELECT count(*) FROM clusters WHERE team_id = $1 AND owner_kind = 'user'",
-    )
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT count(*) FROM clusters WHERE team_id = $1 AND owner_kind = 'user'{filter}"
+    ))
     .bind(team_id.as_uuid())
     .fetch_one(pool)
     .await
@@ -261,6 +266,66 @@ pub async fn delete(
     }
 }
 
+/// Move a user cluster to another team of the same org, keeping its id, under the usual
+/// revision contract. A cluster still referenced by route configs in its team cannot move
+/// (the composite foreign key refuses); the caller checks that first for a named error.
+pub async fn transfer(
+    tx: &mut Transaction<'_, Postgres>,
+    source: TeamId,
+    target: fp_domain::authz::TeamRef,
+    name: &str,
+    expected_version: i64,
+) -> DomainResult<Cluster> {
+    if is_discovery_owned(tx, source, name).await? {
+        return Err(DomainError::conflict(format!(
+            "cluster \"{name}\" is owned by a discovery session"
+        ))
+        .with_hint("stop the discovery session to release it"));
+    }
+    let row = sqlx::query(&format!(
+        "UPDATE clusters SET team_id = $1, version = version + 1, updated_at = now() \
+         WHERE team_id = $2 AND name = $3 AND version = $4 AND owner_kind = 'user' RETURNING {COLUMNS}"
+    ))
+    .bind(target.id.as_uuid())
+    .bind(source.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+            DomainError::conflict(format!("cluster \"{name}\" already exists in the target team"))
+                .with_hint("rename or delete the target team's cluster first")
+        }
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23503") => {
+            DomainError::conflict(format!(
+                "cluster \"{name}\" is still referenced by other resources in its team"
+            ))
+        }
+        _ => DomainError::internal(format!("transfer cluster: {e}")),
+    })?;
+    match row {
+        Some(row) => from_row(&row),
+        None => {
+            let current: Option<i64> =
+                sqlx::query_scalar("SELECT version FROM clusters WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'")
+                    .bind(source.as_uuid())
+                    .bind(name)
+                    .fetch_optional(&mut **tx)
+                    .await
+                    .map_err(|e| DomainError::internal(format!("transfer cluster: recheck: {e}")))?;
+            Err(match current {
+                Some(version) => DomainError::new(
+                    ErrorCode::RevisionMismatch,
+                    format!("cluster \"{name}\" is at revision {version}, you supplied {expected_version}"),
+                )
+                .with_hint("re-read the resource and retry with the current revision"),
+                None => DomainError::not_found("cluster", name),
+            })
+        }
+    }
+}
+
 async fn is_discovery_owned(
     tx: &mut Transaction<'_, Postgres>,
     team_id: TeamId,
@@ -335,3 +400,63 @@ pub async fn count_for_team_in_tx(
         .await
         .map_err(|e| DomainError::internal(format!("count clusters: {e}")))
 }
+
+/// The named user clusters of a team, each paired with whether it is itself an aggregate.
+/// Names that do not exist are simply absent from the result.
+pub async fn aggregate_member_kinds(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    names: &[String],
+) -> DomainResult<Vec<(String, bool)>> {
+    let rows = sqlx::query(
+        "SELECT name, jsonb_array_length(COALESCE(spec->'aggregate_clusters', '[]'::jsonb)) > 0 \
+         AS is_aggregate FROM clusters \
+         WHERE team_id = $1 AND name = ANY($2) AND owner_kind = 'user'",
+    )
+    .bind(team_id.as_uuid())
+    .bind(names)
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("aggregate members: {e}")))?;
+    Ok(rows
+        .iter()
+        .map(|row| (row.get("name"), row.get("is_aggregate")))
+        .collect())
+}
+
+/// User aggregate clusters of a team that list `cluster_name` as a member.
+pub async fn aggregates_referencing(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    cluster_name: &str,
+) -> DomainResult<Vec<String>> {
+    sqlx::query_scalar(
+        "SELECT name FROM clusters \
+         WHERE team_id = $1 AND owner_kind = 'user' AND spec->'aggregate_clusters' ? $2 \
+         ORDER BY name LIMIT 10",
+    )
+    .bind(team_id.as_uuid())
+    .bind(cluster_name)
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("aggregate dependents: {e}")))
+}
+
+/// Every user aggregate cluster of the team listing `cluster_name` as a member (uncapped
+/// read-side variant of [`aggregates_referencing`]).
+pub async fn aggregates_listing(
+    pool: &PgPool,
+    team_id: TeamId,
+    cluster_name: &str,
+) -> DomainResult<Vec<String>> {
+    sqlx::query_scalar(
+        "SELECT name FROM clusters \
+         WHERE team_id = $1 AND owner_kind = 'user' AND spec->'aggregate_clusters' ? $2 \
+         ORDER BY name",
+    )
+    .bind(team_id.as_uuid())
+    .bind(cluster_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("aggregate dependents: {e}")))
+}
@@ -0,0 +1,187 @@
+//! Recorded xDS config versions and the team rollouts and dataplane pins that hold proxies
+//! on them. The xDS server writes versions; the REST surface reads them and manages holds.
+
+use fp_domain::rollout::{ConfigRollout, ConfigVersion};
+use fp_domain::{DataplaneId, DomainError, DomainResult, TeamId};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+/// Recorded versions kept per team beyond the ones still held by a pin or rollout.
+pub const RETAINED_CONFIG_VERSIONS: i64 = 20;
+
+const ROLLOUT_COLUMNS: &str =
+    "team_id, stable_version, canary_percent, version, created_at, updated_at";
+
+/// Everything the xDS server needs to serve held proxies of one team.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigHolds {
+    pub dataplanes: Vec<(DataplaneId, String)>,
+    /// `(stable_version, canary_percent)`.
+    pub rollout: Option<(String, u8)>,
+    /// The recorded snapshot of every held version.
+    pub snapshots: Vec<(String, serde_json::Value)>,
+}
+
+fn rollout_from_row(row: &PgRow) -> ConfigRollout {
+    ConfigRollout {
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        stable_version: row.get("stable_version"),
+        canary_percent: u8::try_from(row.get::<i16, _>("canary_percent")).unwrap_or(100),
+        version: row.get("version"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+/// Map a dangling held version onto the not-found the caller can act on.
+pub(crate) fn map_held_version(
+    version: &str,
+    context: &'static str,
+) -> impl Fn(sqlx::Error) -> DomainError {
+    let version = version.to_string();
+    move |e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23503") => {
+            DomainError::not_found("config version", &version)
+                .with_hint("list recorded versions with GET /api/v1/teams/{team}/config-versions")
+        }
+        _ => DomainError::internal(format!("{context}: {e}")),
+    }
+}
+
+/// Record a served snapshot (idempotent: replicas building the same config agree on its
+/// version) and prune the team's oldest versions that nothing holds.
+pub async fn record_version(
+    pool: &PgPool,
+    team_id: TeamId,
+    config_version: &str,
+    snapshot: &serde_json::Value,
+) -> DomainResult<()> {
+    sqlx::query(
+        "INSERT INTO xds_config_versions (team_id, config_version, snapshot) \
+         VALUES ($1, $2, $3) ON CONFLICT (team_id, config_version) DO NOTHING",
+    )
+    .bind(team_id.as_uuid())
+    .bind(config_version)
+    .bind(snapshot)
+    .execute(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("record config version: {e}")))?;
+    sqlx::query(
+        "DELETE FROM xds_config_versions v WHERE v.team_id = $1 \
+           AND v.config_version NOT IN ( \
+               SELECT config_version FROM xds_config_versions WHERE team_id = $1 \
+               ORDER BY created_at DESC, config_version LIMIT $2) \
+           AND NOT EXISTS (SELECT 1 FROM dataplanes d \
+               WHERE d.team_id = v.team_id AND d.pinned_config_version = v.config_version) \
+           AND NOT EXISTS (SELECT 1 FROM config_rollouts r \
+               WHERE r.team_id = v.team_id AND r.stable_version = v.config_version)",
+    )
+    .bind(team_id.as_uuid())
+    .bind(RETAINED_CONFIG_VERSIONS)
+    .execute(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("prune config versions: {e}")))?;
+    Ok(())
+}
+
+/// Recorded versions, newest first.
+pub async fn list_versions(pool: &PgPool, team_id: TeamId) -> DomainResult<Vec<ConfigVersion>> {
+    let rows = sqlx::query(
+        "SELECT config_version, created_at FROM xds_config_versions WHERE team_id = $1 \
+         ORDER BY created_at DESC, config_version",
+    )
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list config versions: {e}")))?;
+    Ok(rows
+        .iter()
+        .map(|row| ConfigVersion {
+            config_version: row.get("config_version"),
+            created_at: row.get("created_at"),
+        })
+        .collect())
+}
+
+pub async fn get_rollout(pool: &PgPool, team_id: TeamId) -> DomainResult<Option<ConfigRollout>> {
+    let row = sqlx::query(&format!(
+        "SELECT {ROLLOUT_COLUMNS} FROM config_rollouts WHERE team_id = $1"
+    ))
+    .bind(team_id.as_uuid())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get config rollout: {e}")))?;
+    Ok(row.as_ref().map(rollout_from_row))
+}
+
+/// Start or retarget the team's rollout.
+pub async fn upsert_rollout(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    stable_version: &str,
+    canary_percent: u8,
+) -> DomainResult<ConfigRollout> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO config_rollouts (team_id, stable_version, canary_percent) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (team_id) DO UPDATE SET stable_version = EXCLUDED.stable_version, \
+             canary_percent = EXCLUDED.canary_percent, \
+             version = config_rollouts.version + 1, updated_at = now() \
+         RETURNING {ROLLOUT_COLUMNS}"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(stable_version)
+    .bind(i16::from(canary_percent))
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(map_held_version(stable_version, "set config rollout"))?;
+    Ok(rollout_from_row(&row))
+}
+
+/// End the team's rollout; `None` when there was none.
+pub async fn delete_rollout(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+) -> DomainResult<Option<ConfigRollout>> {
+    let row = sqlx::query(&format!(
+        "DELETE FROM config_rollouts WHERE team_id = $1 RETURNING {ROLLOUT_COLUMNS}"
+    ))
+    .bind(team_id.as_uuid())
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("promote config rollout: {e}")))?;
+    Ok(row.as_ref().map(rollout_from_row))
+}
+
+/// Pins, rollout, and the snapshots they hold, for the xDS rebuild.
+pub async fn load_holds(pool: &PgPool, team_id: TeamId) -> DomainResult<ConfigHolds> {
+    let dataplanes: Vec<(Uuid, String)> = sqlx::query_as(
+        "SELECT id, pinned_config_version FROM dataplanes \
+         WHERE team_id = $1 AND pinned_config_version IS NOT NULL",
+    )
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("load dataplane pins: {e}")))?;
+    let rollout = get_rollout(pool, team_id).await?;
+    let snapshots: Vec<(String, serde_json::Value)> = sqlx::query_as(
+        "SELECT config_version, snapshot FROM xds_config_versions v WHERE team_id = $1 \
+           AND (EXISTS (SELECT 1 FROM dataplanes d \
+                    WHERE d.team_id = v.team_id AND d.pinned_config_version = v.config_version) \
+                OR EXISTS (SELECT 1 FROM config_rollouts r \
+                    WHERE r.team_id = v.team_id AND r.stable_version = v.config_version))",
+    )
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("load held config versions: {e}")))?;
+    Ok(ConfigHolds {
+        dataplanes: dataplanes
+            .into_iter()
+            .map(|(id, version)| (DataplaneId::from(id), version))
+            .collect(),
+        rollout: rollout.map(|r| (r.stable_version, r.canary_percent)),
+        snapshots,
+    })
+}
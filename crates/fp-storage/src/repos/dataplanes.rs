@@ -7,7 +7,8 @@
 use fp_domain::authz::TeamRef;
 use fp_domain::dataplane::{Dataplane, ProxyCertificate};
 use fp_domain::{
-    DataplaneId, DomainError, DomainResult, ProxyCertificateId, TeamId, TeamStatsOverview, UserId,
+    DataplaneClusterStats, DataplaneId, DomainError, DomainResult, ProxyCertificateId, TeamId,
+    TeamStatsOverview, UpstreamClusterStats, UserId,
 };
 use sqlx::postgres::PgRow;
 use sqlx::types::chrono;
@@ -16,7 +17,7 @@ use uuid::Uuid;
 
 const DP_COLUMNS: &str = "id, team_id, name, description, version, last_heartbeat_at, \
 	                          last_config_verify_at, total_requests, total_errors, \
-	                          warming_failures, created_at, updated_at";
+	                          warming_failures, pinned_config_version, created_at, updated_at";
 const CERT_COLUMNS: &str = "id, team_id, dataplane_id, spiffe_uri, serial_number, issued_at, \
 	                            expires_at, revoked_at, revoked_reason, created_at";
 
@@ -41,6 +42,7 @@ fn dataplane_from_row(row: &PgRow) -> Dataplane {
         total_requests: row.get("total_requests"),
         total_errors: row.get("total_errors"),
         warming_failures: row.get("warming_failures"),
+        pinned_config_version: row.get("pinned_config_version"),
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
     }
@@ -52,8 +54,7 @@ pub async fn record_telemetry(
     name: &str,
     telemetry: TelemetryDelta<'_>,
 ) -> DomainResult<Dataplane> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("record dataplane telemetry: begin: {e}")))?;
     let row = sqlx::query(&format!(
@@ -81,8 +82,7 @@ pub async fn record_telemetry_by_id(
     dataplane_id: DataplaneId,
     telemetry: TelemetryDelta<'_>,
 ) -> DomainResult<Dataplane> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("record dataplane telemetry: begin: {e}")))?;
     let row = sqlx::query(&format!(
@@ -191,6 +191,108 @@ pub async fn stats_overview(
     })
 }
 
+/// Replace a dataplane's cluster gauges with one report. Clusters the team does not own
+/// (Envoy's own xDS and rate-limit clusters, deleted clusters) are dropped; a name repeated in
+/// the report keeps its last entry.
+pub async fn replace_cluster_stats(
+    pool: &PgPool,
+    team_id: TeamId,
+    dataplane_id: DataplaneId,
+    stats: &[UpstreamClusterStats],
+) -> DomainResult<()> {
+    let latest: std::collections::BTreeMap<&str, &UpstreamClusterStats> = stats
+        .iter()
+        .map(|entry| (entry.cluster.as_str(), entry))
+        .collect();
+    let names: Vec<&str> = latest.keys().copied().collect();
+    let gauge = |read: fn(&UpstreamClusterStats) -> i64| -> Vec<i64> {
+        latest.values().map(|entry| read(entry)).collect()
+    };
+    let breakers: Vec<String> = latest
+        .values()
+        .map(|entry| entry.open_circuit_breakers.join(","))
+        .collect();
+    let mut tx = crate::begin(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("record cluster stats: begin: {e}")))?;
+    sqlx::query("DELETE FROM dataplane_cluster_stats WHERE team_id = $1 AND dataplane_id = $2")
+        .bind(team_id.as_uuid())
+        .bind(dataplane_id.as_uuid())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::internal(format!("clear cluster stats: {e}")))?;
+    sqlx::query(
+        "INSERT INTO dataplane_cluster_stats \
+         (dataplane_id, team_id, cluster_name, rq_active, rq_pending_active, cx_active, \
+          ejections_active, healthy_hosts, total_hosts, open_circuit_breakers) \
+         SELECT $1, $2, u.name, u.rq, u.pending, u.cx, u.ejections, u.healthy, u.total, \
+                string_to_array(u.breakers, ',') \
+         FROM unnest($3::text[], $4::bigint[], $5::bigint[], $6::bigint[], $7::bigint[], \
+                     $8::bigint[], $9::bigint[], $10::text[]) \
+              AS u(name, rq, pending, cx, ejections, healthy, total, breakers) \
+         WHERE EXISTS (SELECT 1 FROM clusters c WHERE c.team_id = $2 AND c.name = u.name)",
+    )
+    .bind(dataplane_id.as_uuid())
+    .bind(team_id.as_uuid())
+    .bind(&names)
+    .bind(gauge(|s| s.rq_active))
+    .bind(gauge(|s| s.rq_pending_active))
+    .bind(gauge(|s| s.cx_active))
+    .bind(gauge(|s| s.ejections_active))
+    .bind(gauge(|s| s.healthy_hosts))
+    .bind(gauge(|s| s.total_hosts))
+    .bind(&breakers)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("record cluster stats: {e}")))?;
+    tx.commit()
+        .await
+        .map_err(|e| DomainError::internal(format!("record cluster stats: commit: {e}")))
+}
+
+/// Each dataplane's latest gauges for one cluster, reported at or after `since`, by
+/// dataplane name.
+pub async fn cluster_stats(
+    pool: &PgPool,
+    team_id: TeamId,
+    cluster: &str,
+    since: chrono::DateTime<chrono::Utc>,
+) -> DomainResult<Vec<DataplaneClusterStats>> {
+    let rows = sqlx::query(
+        "SELECT s.dataplane_id, d.name AS dataplane, s.cluster_name, s.rq_active, \
+                s.rq_pending_active, s.cx_active, s.ejections_active, s.healthy_hosts, \
+                s.total_hosts, s.open_circuit_breakers, s.observed_at \
+         FROM dataplane_cluster_stats s \
+         JOIN dataplanes d ON d.id = s.dataplane_id AND d.team_id = s.team_id \
+         WHERE s.team_id = $1 AND s.cluster_name = $2 AND s.observed_at >= $3 \
+         ORDER BY d.name",
+    )
+    .bind(team_id.as_uuid())
+    .bind(cluster)
+    .bind(since)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("read cluster stats: {e}")))?;
+    Ok(rows
+        .iter()
+        .map(|row| DataplaneClusterStats {
+            dataplane_id: DataplaneId::from(row.get::<Uuid, _>("dataplane_id")),
+            dataplane: row.get("dataplane"),
+            stats: UpstreamClusterStats {
+                cluster: row.get("cluster_name"),
+                rq_active: row.get("rq_active"),
+                rq_pending_active: row.get("rq_pending_active"),
+                cx_active: row.get("cx_active"),
+                ejections_active: row.get("ejections_active"),
+                healthy_hosts: row.get("healthy_hosts"),
+                total_hosts: row.get("total_hosts"),
+                open_circuit_breakers: row.get("open_circuit_breakers"),
+            },
+            observed_at: row.get("observed_at"),
+        })
+        .collect())
+}
+
 fn cert_from_row(row: &PgRow) -> ProxyCertificate {
     ProxyCertificate {
         id: ProxyCertificateId::from(row.get::<Uuid, _>("id")),
@@ -249,6 +351,31 @@ pub async fn get_dataplane(
     Ok(row.as_ref().map(dataplane_from_row))
 }
 
+/// Hold the dataplane on a recorded config version, or release it to latest with `None`.
+pub async fn set_pinned_config_version(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    config_version: Option<&str>,
+) -> DomainResult<Dataplane> {
+    let row = sqlx::query(&format!(
+        "UPDATE dataplanes SET pinned_config_version = $3, version = version + 1, \
+             updated_at = now() \
+         WHERE team_id = $1 AND name = $2 RETURNING {DP_COLUMNS}"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(config_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(crate::repos::config_rollout::map_held_version(
+        config_version.unwrap_or_default(),
+        "pin dataplane",
+    ))?
+    .ok_or_else(|| DomainError::not_found("dataplane", name))?;
+    Ok(dataplane_from_row(&row))
+}
+
 pub async fn list_dataplanes(
     pool: &PgPool,
     team_id: TeamId,
@@ -272,6 +399,19 @@ pub async fn list_dataplanes(
     Ok((rows.iter().map(dataplane_from_row).collect(), total))
 }
 
+/// Every team's dataplanes, most recently heard-from first (platform diagnostics only).
+pub async fn list_all_by_heartbeat(pool: &PgPool, limit: i64) -> DomainResult<Vec<Dataplane>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {DP_COLUMNS} FROM dataplanes \
+         ORDER BY last_heartbeat_at DESC NULLS LAST, name LIMIT $1"
+    ))
+    .bind(limit.clamp(1, 500))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list all dataplanes: {e}")))?;
+    Ok(rows.iter().map(dataplane_from_row).collect())
+}
+
 pub async fn count_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
     sqlx::query_scalar("SELECT count(*) FROM dataplanes WHERE team_id = $1")
         .bind(team_id.as_uuid())
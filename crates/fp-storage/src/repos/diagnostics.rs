@@ -0,0 +1,45 @@
+//! Platform-wide row counts for the admin diagnostics bundle. Deliberately unscoped: the
+//! only caller is the platform-admin diagnostics service, which never returns row contents.
+
+use fp_domain::{DomainError, DomainResult};
+use sqlx::{PgPool, Row};
+
+/// `(label, table)` pairs counted by [`resource_counts`], in output order. Table names are
+/// compile-time constants, never caller input.
+const COUNTED_TABLES: &[(&str, &str)] = &[
+    ("organizations", "organizations"),
+    ("teams", "teams"),
+    ("users", "users"),
+    ("clusters", "clusters"),
+    ("route_configs", "route_configs"),
+    ("listeners", "listeners"),
+    ("secrets", "secrets"),
+    ("filter_presets", "filter_presets"),
+    ("retry_policies", "retry_policies"),
+    ("dataplanes", "dataplanes"),
+    ("proxy_certificates", "proxy_certificates"),
+    ("agents", "agents"),
+    ("api_definitions", "api_definitions"),
+    ("rate_limit_policies", "rate_limit_policies"),
+    ("ai_providers", "ai_providers"),
+    ("ai_routes", "ai_routes"),
+];
+
+/// Row count per resource table, in [`COUNTED_TABLES`] order, from one round trip.
+pub async fn resource_counts(pool: &PgPool) -> DomainResult<Vec<(&'static str, i64)>> {
+    let sql = COUNTED_TABLES
+        .iter()
+        .enumerate()
+        .map(|(i, (_, table))| format!("SELECT {i} AS ord, count(*)::bigint AS n FROM {table}"))
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ");
+    let rows = sqlx::query(&format!("{sql} ORDER BY ord"))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count resources: {e}")))?;
+    Ok(rows
+        .iter()
+        .zip(COUNTED_TABLES)
+        .map(|(row, (label, _))| (*label, row.get::<i64, _>("n")))
+        .collect())
+}
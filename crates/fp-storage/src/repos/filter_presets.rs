@@ -0,0 +1,178 @@
+//! Filter presets. Listener writes copy a preset's filter into the chain, so unlike retry
+//! policies there are no stored dependents to query.
+
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::filters::HttpFilterSpec;
+use fp_domain::gateway::FilterPreset;
+use fp_domain::{DomainError, DomainResult, ErrorCode, FilterPresetId, TeamId};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+const COLUMNS: &str = "id, team_id, name, spec, version, created_at, updated_at";
+
+fn preset_from_row(row: &PgRow) -> DomainResult<FilterPreset> {
+    Ok(FilterPreset {
+        id: FilterPresetId::from(row.get::<Uuid, _>("id")),
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        name: row.get("name"),
+        spec: serde_json::from_value(row.get::<serde_json::Value, _>("spec")).map_err(|e| {
+            DomainError::internal(format!("filter preset spec in DB does not parse: {e}"))
+        })?,
+        version: row.get("version"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+fn spec_json(spec: &HttpFilterSpec) -> DomainResult<serde_json::Value> {
+    serde_json::to_value(spec)
+        .map_err(|e| DomainError::internal(format!("serialize filter preset spec: {e}")))
+}
+
+fn stale_or_missing(name: &str, current: Option<i64>, expected: i64) -> DomainError {
+    match current {
+        Some(version) => DomainError::new(
+            ErrorCode::RevisionMismatch,
+            format!("filter preset \"{name}\" is at revision {version}, you supplied {expected}"),
+        )
+        .with_hint("re-read the resource and retry with the current revision"),
+        None => DomainError::not_found("filter preset", name),
+    }
+}
+
+async fn current_version(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Option<i64>> {
+    sqlx::query_scalar("SELECT version FROM filter_presets WHERE team_id = $1 AND name = $2")
+        .bind(team_id.as_uuid())
+        .bind(name)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| DomainError::internal(format!("filter preset: recheck: {e}")))
+}
+
+pub async fn create(
+    tx: &mut Transaction<'_, Postgres>,
+    team: TeamRef,
+    name: &str,
+    spec: &HttpFilterSpec,
+) -> DomainResult<FilterPreset> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO filter_presets (id, team_id, org_id, name, spec) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLUMNS}"
+    ))
+    .bind(FilterPresetId::generate().as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(name)
+    .bind(spec_json(spec)?)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+            DomainError::conflict(format!(
+                "filter preset \"{name}\" already exists in this team"
+            ))
+            .with_hint("choose a different name, or update the existing preset")
+        }
+        _ => DomainError::internal(format!("create filter preset: {e}")),
+    })?;
+    preset_from_row(&row)
+}
+
+pub async fn list(
+    pool: &PgPool,
+    team_id: TeamId,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<FilterPreset>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM filter_presets WHERE team_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list filter presets: {e}")))?;
+    let total = count_for_team(pool, team_id).await?;
+    let mut items = Vec::with_capacity(rows.len());
+    for row in rows {
+        items.push(preset_from_row(&row)?);
+    }
+    Ok((items, total))
+}
+
+pub async fn count_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
+    sqlx::query_scalar("SELECT count(*) FROM filter_presets WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count filter presets: {e}")))
+}
+
+pub async fn get(pool: &PgPool, team_id: TeamId, name: &str) -> DomainResult<Option<FilterPreset>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM filter_presets WHERE team_id = $1 AND name = $2"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get filter preset: {e}")))?;
+    row.as_ref().map(preset_from_row).transpose()
+}
+
+pub async fn update(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    spec: &HttpFilterSpec,
+    expected_version: i64,
+) -> DomainResult<FilterPreset> {
+    let row = sqlx::query(&format!(
+        "UPDATE filter_presets SET spec = $1, version = version + 1, updated_at = now() \
+         WHERE team_id = $2 AND name = $3 AND version = $4 RETURNING {COLUMNS}"
+    ))
+    .bind(spec_json(spec)?)
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("update filter preset: {e}")))?;
+    match row {
+        Some(row) => preset_from_row(&row),
+        None => {
+            let current = current_version(tx, team_id, name).await?;
+            Err(stale_or_missing(name, current, expected_version))
+        }
+    }
+}
+
+pub async fn delete(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    expected_version: i64,
+) -> DomainResult<FilterPresetId> {
+    let row = sqlx::query(
+        "DELETE FROM filter_presets WHERE team_id = $1 AND name = $2 AND version = $3 RETURNING id",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("delete filter preset: {e}")))?;
+    match row {
+        Some(row) => Ok(FilterPresetId::from(row.get::<Uuid, _>("id"))),
+        None => {
+            let current = current_version(tx, team_id, name).await?;
+            Err(stale_or_missing(name, current, expected_version))
+        }
+    }
+}
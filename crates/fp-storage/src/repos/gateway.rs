@@ -2,14 +2,19 @@
 //! revision-checked writes) plus normalized reference tracking.
 
 use fp_domain::authz::TeamRef;
-use fp_domain::gateway::listener::{Listener, ListenerSpec};
+use fp_domain::gateway::listener::{Listener, ListenerMaintenance, ListenerSpec};
 use fp_domain::gateway::route_config::{RouteConfig, RouteConfigSpec};
-use fp_domain::{DomainError, DomainResult, ErrorCode, ListenerId, RouteConfigId, TeamId};
+use fp_domain::gateway::{ListSort, SortField};
+use fp_domain::{
+    DomainError, DomainResult, ErrorCode, ListenerId, ResourceSource, RouteConfigId, TeamId,
+};
 use sqlx::postgres::PgRow;
 use sqlx::{PgPool, Postgres, Row, Transaction};
 use uuid::Uuid;
 
 const COLUMNS: &str = "id, team_id, name, spec, version, created_at, updated_at";
+const LISTENER_COLUMNS: &str =
+    "id, team_id, name, spec, maintenance, version, created_at, updated_at";
 
 fn map_unique(e: sqlx::Error, kind: &str, name: &str) -> DomainError {
     if let sqlx::Error::Database(db) = &e {
@@ -28,6 +33,38 @@ fn map_unique(e: sqlx::Error, kind: &str, name: &str) -> DomainError {
     DomainError::internal(format!("write {kind}: {e}"))
 }
 
+/// Transfer write errors: a name or port already taken in the target team is a conflict, and so
+/// is a row still referenced from its source team (the composite `(id, team_id)` foreign keys
+/// refuse to let a referenced row change team).
+fn map_transfer(e: sqlx::Error, kind: &str, name: &str) -> DomainError {
+    if let sqlx::Error::Database(db) = &e {
+        match db.code().as_deref() {
+            Some("23505") if db.constraint().unwrap_or_default().contains("port") => {
+                return DomainError::conflict(
+                    "the listener port is already bound by another listener in the target team",
+                )
+                .with_hint("change the listener's port first, or free it in the target team");
+            }
+            Some("23505") => {
+                return DomainError::conflict(format!(
+                    "{kind} \"{name}\" already exists in the target team"
+                ))
+                .with_hint("rename or delete the target team's resource first");
+            }
+            Some("23503") => {
+                return DomainError::conflict(format!(
+                    "{kind} \"{name}\" is still referenced by other resources in its team"
+                ))
+                .with_hint(
+                    "detach it from the API definitions or capture sessions using it first",
+                );
+            }
+            _ => {}
+        }
+    }
+    DomainError::internal(format!("transfer {kind}: {e}"))
+}
+
 fn stale_or_missing(kind: &str, name: &str, current: Option<i64>, expected: i64) -> DomainError {
     match current {
         Some(version) => DomainError::new(
@@ -196,14 +233,29 @@ pub async fn get_route_config(
     row.as_ref().map(rc_from_row).transpose()
 }
 
+/// `ORDER BY` clause for a gateway resource list; name breaks ties so paging is stable.
+pub(crate) fn order_by(sort: ListSort) -> String {
+    let direction = if sort.descending { "DESC" } else { "ASC" };
+    match sort.field {
+        SortField::Name => format!("name {direction}"),
+        SortField::CreatedAt => format!("created_at {direction}, name"),
+        SortField::UpdatedAt => format!("updated_at {direction}, name"),
+    }
+}
+
 pub async fn list_route_configs(
     pool: &PgPool,
     team_id: TeamId,
+    source: Option<ResourceSource>,
+    sort: ListSort,
     limit: i64,
     offset: i64,
 ) -> DomainResult<(Vec<RouteConfig>, i64)> {
+    let filter =
+        super::route_generation::source_filter(source, "route_configs", "route_config_name");
+    let order = order_by(sort);
     let rows = sqlx::query(&format!(
-        "SELECT {COLUMNS} FROM route_configs WHERE team_id = $1 AND owner_kind = 'user' ORDER BY name LIMIT $2 OFFSET $3"
+        "SELECT {COLUMNS} FROM route_configs WHERE team_id = $1 AND owner_kind = 'user'{filter} ORDER BY {order} LIMIT $2 OFFSET $3"
     ))
     .bind(team_id.as_uuid())
     .bind(limit.clamp(1, 500))
@@ -211,9 +263,9 @@ pub async fn list_route_configs(
     .fetch_all(pool)
     .await
     .map_err(|e| DomainError::internal(format!("list route configs: {e}")))?;
-    let total: i64 = sqlx::query_scalar(
-        "SELECT count(*) FROM route_configs WHERE team_id = $1 AND owner_kind = 'user'",
-    )
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT count(*) FROM route_configs WHERE team_id = $1 AND owner_kind = 'user'{filter}"
+    ))
     .bind(team_id.as_uuid())
     .fetch_one(pool)
     .await
@@ -282,7 +334,98 @@ pub async fn delete_route_config(
         ))
         .with_hint("stop the discovery session to remove it"));
     }
-    // Dependents first: listeners bound to this route config block deletion with names.
+    // Dependents first: listeners and AI budgets bound to this route config block deletion.
+    ensure_route_config_unreferenced(tx, team_id, name).await?;
+    let row = sqlx::query(
+        "DELETE FROM route_configs WHERE team_id = $1 AND name = $2 AND version = $3 AND owner_kind = 'user' RETURNING id",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("delete route config: {e}")))?;
+    match row {
+        Some(row) => Ok(RouteConfigId::from(row.get::<Uuid, _>("id"))),
+        None => {
+            let current: Option<i64> = sqlx::query_scalar(
+                "SELECT version FROM route_configs WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'",
+            )
+            .bind(team_id.as_uuid())
+            .bind(name)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| DomainError::internal(format!("delete route config: recheck: {e}")))?;
+            Err(stale_or_missing(
+                "route config",
+                name,
+                current,
+                expected_version,
+            ))
+        }
+    }
+}
+
+/// Move a user route config to another team of the same org, keeping its id. Its cluster
+/// references are re-resolved by name in the target team (a missing cluster is a validation
+/// error, exactly as on create); dependents in the source team block the move.
+pub async fn transfer_route_config(
+    tx: &mut Transaction<'_, Postgres>,
+    source: TeamId,
+    target: TeamRef,
+    name: &str,
+    expected_version: i64,
+) -> DomainResult<RouteConfig> {
+    if route_config_discovery_owned(tx, source, name).await? {
+        return Err(DomainError::conflict(format!(
+            "route config \"{name}\" is owned by a discovery session"
+        ))
+        .with_hint("stop the discovery session to release it"));
+    }
+    ensure_route_config_unreferenced(tx, source, name).await?;
+    let current = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM route_configs WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'"
+    ))
+    .bind(source.as_uuid())
+    .bind(name)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("transfer route config: read: {e}")))?;
+    let Some(current) = current.as_ref().map(rc_from_row).transpose()? else {
+        return Err(DomainError::not_found("route config", name));
+    };
+    if current.version != expected_version {
+        return Err(stale_or_missing(
+            "route config",
+            name,
+            Some(current.version),
+            expected_version,
+        ));
+    }
+    let cluster_ids = resolve_cluster_refs(tx, target.id, "user", &current.spec).await?;
+    // The outgoing refs carry the source team; clear them so the row may change team.
+    replace_cluster_refs(tx, source, current.id.as_uuid(), &[]).await?;
+    let row = sqlx::query(&format!(
+        "UPDATE route_configs SET team_id = $1, version = version + 1, updated_at = now() \
+         WHERE id = $2 RETURNING {COLUMNS}"
+    ))
+    .bind(target.id.as_uuid())
+    .bind(current.id.as_uuid())
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| map_transfer(e, "route config", name))?;
+    let rc = rc_from_row(&row)?;
+    replace_cluster_refs(tx, target.id, rc.id.as_uuid(), &cluster_ids).await?;
+    Ok(rc)
+}
+
+/// The route-config delete/transfer guard: listeners and AI budgets bound to it in its team
+/// block the change with a conflict naming them.
+async fn ensure_route_config_unreferenced(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<()> {
     let dependents: Vec<String> = sqlx::query_scalar(
         "SELECT l.name FROM listeners l \
          JOIN listener_route_config_refs r ON r.listener_id = l.id \
@@ -293,7 +436,7 @@ pub async fn delete_route_config(
     .bind(name)
     .fetch_all(&mut **tx)
     .await
-    .map_err(|e| DomainError::internal(format!("delete route config: dependents: {e}")))?;
+    .map_err(|e| DomainError::internal(format!("route config dependents: {e}")))?;
     if !dependents.is_empty() {
         return Err(DomainError::conflict(format!(
             "route config \"{name}\" is referenced by listeners: {}",
@@ -310,9 +453,7 @@ pub async fn delete_route_config(
     .bind(name)
     .fetch_all(&mut **tx)
     .await
-    .map_err(|e| {
-        DomainError::internal(format!("delete route config: AI budget dependents: {e}"))
-    })?;
+    .map_err(|e| DomainError::internal(format!("route config AI budget dependents: {e}")))?;
     if !dependents.is_empty() {
         return Err(DomainError::conflict(format!(
             "route config \"{name}\" is referenced by AI budgets: {}",
@@ -320,34 +461,7 @@ pub async fn delete_route_config(
         ))
         .with_hint("update or delete those AI budgets first"));
     }
-    let row = sqlx::query(
-        "DELETE FROM route_configs WHERE team_id = $1 AND name = $2 AND version = $3 AND owner_kind = 'user' RETURNING id",
-    )
-    .bind(team_id.as_uuid())
-    .bind(name)
-    .bind(expected_version)
-    .fetch_optional(&mut **tx)
-    .await
-    .map_err(|e| DomainError::internal(format!("delete route config: {e}")))?;
-    match row {
-        Some(row) => Ok(RouteConfigId::from(row.get::<Uuid, _>("id"))),
-        None => {
-            let current: Option<i64> = sqlx::query_scalar(
-                "SELECT version FROM route_configs WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'",
-            )
-            .bind(team_id.as_uuid())
-            .bind(name)
-            .fetch_optional(&mut **tx)
-            .await
-            .map_err(|e| DomainError::internal(format!("delete route config: recheck: {e}")))?;
-            Err(stale_or_missing(
-                "route config",
-                name,
-                current,
-                expected_version,
-            ))
-        }
-    }
+    Ok(())
 }
 
 async fn route_config_discovery_owned(
@@ -422,6 +536,29 @@ pub async fn route_configs_referencing_cluster(
     .map_err(|e| DomainError::internal(format!("cluster dependents: {e}")))
 }
 
+/// Every user route config of the team whose actions reference the cluster, by name (the
+/// full set, unlike the capped delete-guard list).
+pub async fn route_configs_using_cluster(
+    pool: &PgPool,
+    team_id: TeamId,
+    cluster_name: &str,
+) -> DomainResult<Vec<RouteConfig>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM route_configs \
+         WHERE team_id = $1 AND owner_kind = 'user' AND id IN ( \
+             SELECT r.route_config_id FROM route_config_cluster_refs r \
+             JOIN clusters c ON c.id = r.cluster_id \
+             WHERE c.team_id = $1 AND c.name = $2) \
+         ORDER BY name"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(cluster_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("cluster route configs: {e}")))?;
+    rows.iter().map(rc_from_row).collect()
+}
+
 // ---------------- listeners ----------------
 
 fn listener_from_row(row: &PgRow) -> DomainResult<Listener> {
@@ -432,6 +569,13 @@ fn listener_from_row(row: &PgRow) -> DomainResult<Listener> {
         spec: serde_json::from_value(row.get::<serde_json::Value, _>("spec")).map_err(|e| {
             DomainError::internal(format!("listener spec in DB does not parse: {e}"))
         })?,
+        maintenance: row
+            .get::<Option<serde_json::Value>, _>("maintenance")
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| {
+                DomainError::internal(format!("listener maintenance in DB does not parse: {e}"))
+            })?,
         version: row.get("version"),
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
@@ -532,7 +676,7 @@ async fn create_listener_with_owner(
         .map_err(|e| DomainError::internal(format!("serialize listener spec: {e}")))?;
     let row = sqlx::query(&format!(
         "INSERT INTO listeners (id, team_id, org_id, name, spec, owner_kind, owner_id) \
-         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {COLUMNS}"
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {LISTENER_COLUMNS}"
     ))
     .bind(ListenerId::generate().as_uuid())
     .bind(team.id.as_uuid())
@@ -555,7 +699,7 @@ pub async fn get_listener(
     name: &str,
 ) -> DomainResult<Option<Listener>> {
     let row = sqlx::query(&format!(
-        "SELECT {COLUMNS} FROM listeners WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'"
+        "SELECT {LISTENER_COLUMNS} FROM listeners WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'"
     ))
     .bind(team_id.as_uuid())
     .bind(name)
@@ -571,7 +715,7 @@ pub async fn get_listener_by_id(
     id: ListenerId,
 ) -> DomainResult<Option<Listener>> {
     let row = sqlx::query(&format!(
-        "SELECT {COLUMNS} FROM listeners WHERE team_id = $1 AND id = $2"
+        "SELECT {LISTENER_COLUMNS} FROM listeners WHERE team_id = $1 AND id = $2"
     ))
     .bind(team_id.as_uuid())
     .bind(id.as_uuid())
@@ -584,11 +728,15 @@ pub async fn get_listener_by_id(
 pub async fn list_listeners(
     pool: &PgPool,
     team_id: TeamId,
+    source: Option<ResourceSource>,
+    sort: ListSort,
     limit: i64,
     offset: i64,
 ) -> DomainResult<(Vec<Listener>, i64)> {
+    let filter = super::route_generation::source_filter(source, "listeners", "listener_name");
+    let order = order_by(sort);
     let rows = sqlx::query(&format!(
-        "SELECT {COLUMNS} FROM listeners WHERE team_id = $1 AND owner_kind = 'user' ORDER BY name LIMIT $2 OFFSET $3"
+        "SELECT {LISTENER_COLUMNS} FROM listeners WHERE team_id = $1 AND owner_kind = 'user'{filter} ORDER BY {order} LIMIT $2 OFFSET $3"
     ))
     .bind(team_id.as_uuid())
     .bind(limit.clamp(1, 500))
@@ -596,9 +744,9 @@ pub async fn list_listeners(
     .fetch_all(pool)
     .await
     .map_err(|e| DomainError::internal(format!("list listeners: {e}")))?;
-    let total: i64 = sqlx::query_scalar(
-        "SELECT count(*) FROM listeners WHERE team_id = $1 AND owner_kind = 'user'",
-    )
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT count(*) FROM listeners WHERE team_id = $1 AND owner_kind = 'user'{filter}"
+    ))
     .bind(team_id.as_uuid())
     .fetch_one(pool)
     .await
@@ -621,7 +769,7 @@ pub async fn update_listener(
         .map_err(|e| DomainError::internal(format!("serialize listener spec: {e}")))?;
     let row = sqlx::query(&format!(
         "UPDATE listeners SET spec = $1, version = version + 1, updated_at = now() \
-         WHERE team_id = $2 AND name = $3 AND version = $4 AND owner_kind = 'user' RETURNING {COLUMNS}"
+         WHERE team_id = $2 AND name = $3 AND version = $4 AND owner_kind = 'user' RETURNING {LISTENER_COLUMNS}"
     ))
     .bind(spec_json)
     .bind(team.id.as_uuid())
@@ -655,6 +803,53 @@ pub async fn update_listener(
     }
 }
 
+/// Turn a user listener's maintenance mode on (`Some`) or off (`None`), leaving its spec
+/// alone. Bumps the revision like any other listener write.
+pub async fn set_listener_maintenance(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    maintenance: Option<&ListenerMaintenance>,
+    expected_version: i64,
+) -> DomainResult<Listener> {
+    let maintenance_json = maintenance
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| DomainError::internal(format!("serialize listener maintenance: {e}")))?;
+    let row = sqlx::query(&format!(
+        "UPDATE listeners SET maintenance = $1, version = version + 1, updated_at = now() \
+         WHERE team_id = $2 AND name = $3 AND version = $4 AND owner_kind = 'user' RETURNING {LISTENER_COLUMNS}"
+    ))
+    .bind(maintenance_json)
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("set listener maintenance: {e}")))?;
+    match row {
+        Some(row) => listener_from_row(&row),
+        None => {
+            let current: Option<i64> = sqlx::query_scalar(
+                "SELECT version FROM listeners WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'",
+            )
+            .bind(team_id.as_uuid())
+            .bind(name)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| {
+                DomainError::internal(format!("set listener maintenance: recheck: {e}"))
+            })?;
+            Err(stale_or_missing(
+                "listener",
+                name,
+                current,
+                expected_version,
+            ))
+        }
+    }
+}
+
 pub async fn delete_listener(
     tx: &mut Transaction<'_, Postgres>,
     team_id: TeamId,
@@ -697,6 +892,61 @@ pub async fn delete_listener(
     }
 }
 
+/// Move a user listener to another team of the same org, keeping its id. The caller passes the
+/// spec as it should read in the target team (re-namespaced filters); the route-config
+/// reference is re-resolved by name there.
+pub async fn transfer_listener(
+    tx: &mut Transaction<'_, Postgres>,
+    source: TeamId,
+    target: TeamRef,
+    name: &str,
+    spec: &ListenerSpec,
+    expected_version: i64,
+) -> DomainResult<Listener> {
+    if listener_discovery_owned(tx, source, name).await? {
+        return Err(DomainError::conflict(format!(
+            "listener \"{name}\" is owned by a discovery session"
+        ))
+        .with_hint("stop the discovery session to release it"));
+    }
+    let current: Option<(Uuid, i64)> = sqlx::query_as(
+        "SELECT id, version FROM listeners WHERE team_id = $1 AND name = $2 AND owner_kind = 'user'",
+    )
+    .bind(source.as_uuid())
+    .bind(name)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("transfer listener: read: {e}")))?;
+    let Some((id, version)) = current else {
+        return Err(DomainError::not_found("listener", name));
+    };
+    if version != expected_version {
+        return Err(stale_or_missing(
+            "listener",
+            name,
+            Some(version),
+            expected_version,
+        ));
+    }
+    let rc_id = resolve_listener_rc_ref(tx, target.id, "user", spec).await?;
+    replace_listener_rc_ref(tx, source, id, None).await?;
+    let spec_json = serde_json::to_value(spec)
+        .map_err(|e| DomainError::internal(format!("serialize listener spec: {e}")))?;
+    let row = sqlx::query(&format!(
+        "UPDATE listeners SET team_id = $1, spec = $2, version = version + 1, updated_at = now() \
+         WHERE id = $3 RETURNING {LISTENER_COLUMNS}"
+    ))
+    .bind(target.id.as_uuid())
+    .bind(spec_json)
+    .bind(id)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| map_transfer(e, "listener", name))?;
+    let listener = listener_from_row(&row)?;
+    replace_listener_rc_ref(tx, target.id, id, rc_id).await?;
+    Ok(listener)
+}
+
 async fn listener_discovery_owned(
     tx: &mut Transaction<'_, Postgres>,
     team_id: TeamId,
@@ -778,6 +1028,7 @@ pub async fn teams_with_gateway_resources(pool: &PgPool) -> DomainResult<Vec<Tea
          UNION SELECT team_id FROM route_configs
          UNION SELECT team_id FROM listeners
          UNION SELECT team_id FROM secrets
+         UNION SELECT team_id FROM runtime_values
          ORDER BY team_id",
     )
     .fetch_all(pool)
@@ -3,8 +3,8 @@
 
 use fp_domain::authz::{Action, Resource, TeamRef};
 use fp_domain::{
-    Agent, AgentId, AgentKind, DomainError, DomainResult, EntityStatus, OrgId, OrgRole,
-    Organization, Team, TeamId, UserId,
+    Agent, AgentId, AgentKind, DomainError, DomainResult, EntityStatus, EnvoyAdminAccess, OrgId,
+    OrgRole, Organization, Team, TeamId, TeamProfile, UserId,
 };
 use sha2::{Digest, Sha256};
 use sqlx::postgres::PgRow;
@@ -214,12 +214,29 @@ fn org_from_row(row: &PgRow) -> DomainResult<Organization> {
     })
 }
 
+const TEAM_COLUMNS: &str = "id, org_id, name, display_name, description, envoy_admin_port, \
+     envoy_admin_access, status, created_at, updated_at";
+
 fn team_from_row(row: &PgRow) -> DomainResult<Team> {
+    let envoy_admin_port = row
+        .get::<Option<i32>, _>("envoy_admin_port")
+        .map(|port| {
+            u16::try_from(port).map_err(|_| {
+                DomainError::internal(format!("team envoy_admin_port {port} out of range"))
+            })
+        })
+        .transpose()?;
     Ok(Team {
         id: TeamId::from(row.get::<Uuid, _>("id")),
         org_id: OrgId::from(row.get::<Uuid, _>("org_id")),
         name: row.get("name"),
         display_name: row.get("display_name"),
+        description: row.get("description"),
+        envoy_admin_port,
+        envoy_admin_access: row
+            .get::<Option<String>, _>("envoy_admin_access")
+            .map(|raw| EnvoyAdminAccess::parse(&raw))
+            .transpose()?,
         status: parse_status(&row.get::<String, _>("status"))?,
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
@@ -274,23 +291,78 @@ pub async fn create_team_tx(
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
     org_id: OrgId,
     name: &str,
-    display_name: &str,
+    profile: &TeamProfile,
 ) -> DomainResult<Team> {
     fp_domain::validate_name(name)?;
-    let row = sqlx::query(
-        "INSERT INTO teams (id, org_id, name, display_name) VALUES ($1, $2, $3, $4) \
-         RETURNING id, org_id, name, display_name, status, created_at, updated_at",
-    )
+    profile.validate()?;
+    let row = sqlx::query(&format!(
+        "INSERT INTO teams (id, org_id, name, display_name, description, envoy_admin_port, \
+         envoy_admin_access) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {TEAM_COLUMNS}"
+    ))
     .bind(TeamId::generate().as_uuid())
     .bind(org_id.as_uuid())
     .bind(name)
-    .bind(display_name)
+    .bind(&profile.display_name)
+    .bind(&profile.description)
+    .bind(profile.envoy_admin_port.map(i32::from))
+    .bind(profile.envoy_admin_access.map(EnvoyAdminAccess::as_str))
     .fetch_one(&mut **tx)
     .await
-    .map_err(|e| map_unique_violation(e, "team", name))?;
+    .map_err(|e| map_team_violation(e, "create", name, profile))?;
     team_from_row(&row)
 }
 
+/// Replace a team's profile. The name is immutable; `None` when the team does not exist.
+pub async fn update_team_tx(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    team_id: TeamId,
+    profile: &TeamProfile,
+) -> DomainResult<Option<Team>> {
+    profile.validate()?;
+    let row = sqlx::query(&format!(
+        "UPDATE teams SET display_name = $2, description = $3, envoy_admin_port = $4, \
+         envoy_admin_access = $5, updated_at = now() \
+         WHERE id = $1 AND status = 'active' RETURNING {TEAM_COLUMNS}"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(&profile.display_name)
+    .bind(&profile.description)
+    .bind(profile.envoy_admin_port.map(i32::from))
+    .bind(profile.envoy_admin_access.map(EnvoyAdminAccess::as_str))
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| map_team_violation(e, "update", &team_id.to_string(), profile))?;
+    row.as_ref().map(team_from_row).transpose()
+}
+
+pub async fn get_team(pool: &PgPool, team_id: TeamId) -> DomainResult<Option<Team>> {
+    let row = sqlx::query(&format!(
+        "SELECT {TEAM_COLUMNS} FROM teams WHERE id = $1 AND status = 'active'"
+    ))
+    .bind(team_id.as_uuid())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get team: {e}")))?;
+    row.as_ref().map(team_from_row).transpose()
+}
+
+/// A taken admin port is its own conflict; any other unique violation is the team name.
+fn map_team_violation(e: sqlx::Error, op: &str, name: &str, profile: &TeamProfile) -> DomainError {
+    if let sqlx::Error::Database(db) = &e {
+        if db.constraint() == Some("idx_teams_org_envoy_admin_port") {
+            return DomainError::conflict(format!(
+                "envoy admin port {} is already assigned to another team in this org",
+                profile.envoy_admin_port.unwrap_or_default()
+            ))
+            .with_hint("pick a free port or omit envoy_admin_port");
+        }
+        if op == "create" {
+            return map_unique_violation(e, "team", name);
+        }
+    }
+    DomainError::internal(format!("{op} team: {e}"))
+}
+
 /// Standalone create (own transaction). Kept for tests/fixtures; production goes through
 /// [`create_team_tx`] so the event + audit share the transaction.
 pub async fn create_team(
@@ -299,11 +371,14 @@ pub async fn create_team(
     name: &str,
     display_name: &str,
 ) -> DomainResult<Team> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("create team: begin: {e}")))?;
-    let team = create_team_tx(&mut tx, org_id, name, display_name).await?;
+    let profile = TeamProfile {
+        display_name: display_name.into(),
+        ..TeamProfile::default()
+    };
+    let team = create_team_tx(&mut tx, org_id, name, &profile).await?;
     tx.commit()
         .await
         .map_err(|e| DomainError::internal(format!("create team: commit: {e}")))?;
@@ -451,8 +526,7 @@ pub async fn add_org_membership(
     org_id: OrgId,
     role: OrgRole,
 ) -> DomainResult<()> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("add org membership: begin: {e}")))?;
     add_org_membership_in_tx(&mut tx, user_id, org_id, role).await?;
@@ -490,8 +564,7 @@ pub async fn add_grant(
     action: Action,
     created_by: Option<UserId>,
 ) -> DomainResult<()> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("add grant: begin: {e}")))?;
     add_grant_in_tx(
@@ -604,10 +677,9 @@ fn is_fk_violation(e: &sqlx::Error) -> bool {
 
 /// Teams of one org (governance read).
 pub async fn list_teams_for_org(pool: &PgPool, org_id: OrgId) -> DomainResult<Vec<Team>> {
-    let rows = sqlx::query(
-        "SELECT id, org_id, name, display_name, status, created_at, updated_at \
-         FROM teams WHERE org_id = $1 AND status = 'active' ORDER BY name",
-    )
+    let rows = sqlx::query(&format!(
+        "SELECT {TEAM_COLUMNS} FROM teams WHERE org_id = $1 AND status = 'active' ORDER BY name"
+    ))
     .bind(org_id.as_uuid())
     .fetch_all(pool)
     .await
@@ -654,8 +726,7 @@ pub async fn delete_team_tx(
 /// Standalone delete (own transaction). Kept for tests/fixtures; production goes through
 /// [`delete_team_tx`] so the event + audit share the transaction.
 pub async fn delete_team(pool: &PgPool, team_id: TeamId) -> DomainResult<()> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("delete team: begin: {e}")))?;
     delete_team_tx(&mut tx, team_id).await?;
@@ -670,8 +741,7 @@ pub async fn add_team_membership(
     user_id: UserId,
     team_id: TeamId,
 ) -> DomainResult<()> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("add team membership: begin: {e}")))?;
     add_team_membership_in_tx(&mut tx, user_id, team_id).await?;
@@ -733,8 +803,7 @@ pub async fn remove_team_membership(
     user_id: UserId,
     team_id: TeamId,
 ) -> DomainResult<bool> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("remove team membership: begin: {e}")))?;
     let deleted = remove_team_membership_in_tx(&mut tx, user_id, team_id).await?;
@@ -786,8 +855,7 @@ pub async fn list_grants_for_team(
 }
 
 pub async fn delete_grant(pool: &PgPool, team_id: TeamId, grant_id: Uuid) -> DomainResult<bool> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("delete grant: begin: {e}")))?;
     let deleted = delete_grant_in_tx(&mut tx, team_id, grant_id).await?;
@@ -1014,8 +1082,7 @@ pub async fn remove_org_membership(
     user_id: UserId,
     org_id: OrgId,
 ) -> DomainResult<bool> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("remove org membership: begin: {e}")))?;
     let deleted = remove_org_membership_in_tx(&mut tx, user_id, org_id).await?;
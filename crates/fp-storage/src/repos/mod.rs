@@ -7,11 +7,21 @@ pub mod api_lifecycle;
 pub mod audit;
 pub mod bootstrap;
 pub mod clusters;
+pub mod config_rollout;
 pub mod dataplanes;
+pub mod diagnostics;
 pub mod discovery;
+pub mod filter_presets;
 pub mod gateway;
 pub mod identity;
+pub mod org_defaults;
+pub mod proto_descriptors;
+pub mod quotas;
 pub mod rate_limit;
+pub mod retry_policies;
 pub mod route_generation;
+pub mod runtime;
 pub mod secrets;
+pub mod wasm_modules;
+pub mod webhooks;
 pub mod xds_nacks;
@@ -0,0 +1,46 @@
+//! Org-level defaults for new teams. Stored on `organizations`; the listener write path
+//! copies them into a team's first listener, so there are no stored dependents.
+
+use fp_domain::gateway::filters::HttpFilterEntry;
+use fp_domain::{DomainError, DomainResult, OrgId};
+use sqlx::{PgPool, Postgres, Transaction};
+
+/// `None` when the org does not exist.
+pub async fn get_default_listener_filters(
+    pool: &PgPool,
+    org_id: OrgId,
+) -> DomainResult<Option<Vec<HttpFilterEntry>>> {
+    let raw: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT default_listener_filters FROM organizations WHERE id = $1")
+            .bind(org_id.as_uuid())
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| DomainError::internal(format!("get org default filters: {e}")))?;
+    raw.map(|value| {
+        serde_json::from_value(value).map_err(|e| {
+            DomainError::internal(format!("org default filters in DB do not parse: {e}"))
+        })
+    })
+    .transpose()
+}
+
+pub async fn set_default_listener_filters(
+    tx: &mut Transaction<'_, Postgres>,
+    org_id: OrgId,
+    filters: &[HttpFilterEntry],
+) -> DomainResult<()> {
+    let value = serde_json::to_value(filters)
+        .map_err(|e| DomainError::internal(format!("serialize org default filters: {e}")))?;
+    let updated = sqlx::query(
+        "UPDATE organizations SET default_listener_filters = $2, updated_at = now() WHERE id = $1",
+    )
+    .bind(org_id.as_uuid())
+    .bind(value)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("set org default filters: {e}")))?;
+    if updated.rows_affected() == 0 {
+        return Err(DomainError::not_found("organization", &org_id.to_string()));
+    }
+    Ok(())
+}
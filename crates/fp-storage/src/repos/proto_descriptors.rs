@@ -0,0 +1,211 @@
+//! Uploaded protobuf descriptor sets (gRPC-JSON transcoding). Metadata read paths never
+//! select the `descriptor_set` bytes; only the snapshot loader does.
+
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::ProtoDescriptor;
+use fp_domain::{DomainError, DomainResult, ErrorCode, ProtoDescriptorId, TeamId};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+const COLUMNS: &str = "id, team_id, name, services, \
+                       octet_length(descriptor_set)::BIGINT AS size_bytes, version, created_at, \
+                       updated_at";
+
+fn descriptor_from_row(row: &PgRow) -> DomainResult<ProtoDescriptor> {
+    Ok(ProtoDescriptor {
+        id: ProtoDescriptorId::from(row.get::<Uuid, _>("id")),
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        name: row.get("name"),
+        services: serde_json::from_value(row.get::<serde_json::Value, _>("services")).map_err(
+            |e| DomainError::internal(format!("proto descriptor services in DB do not parse: {e}")),
+        )?,
+        size_bytes: row.get("size_bytes"),
+        version: row.get("version"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub async fn create(
+    tx: &mut Transaction<'_, Postgres>,
+    team: TeamRef,
+    name: &str,
+    descriptor_set: &[u8],
+    services: &[String],
+) -> DomainResult<ProtoDescriptor> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO proto_descriptors (id, team_id, org_id, name, descriptor_set, services) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {COLUMNS}"
+    ))
+    .bind(ProtoDescriptorId::generate().as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(name)
+    .bind(descriptor_set)
+    .bind(serde_json::json!(services))
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+            DomainError::conflict(format!(
+                "proto descriptor \"{name}\" already exists in this team"
+            ))
+            .with_hint("choose a different name, or delete the existing descriptor first")
+        }
+        _ => DomainError::internal(format!("create proto descriptor: {e}")),
+    })?;
+    descriptor_from_row(&row)
+}
+
+pub async fn list(
+    pool: &PgPool,
+    team_id: TeamId,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<ProtoDescriptor>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM proto_descriptors WHERE team_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list proto descriptors: {e}")))?;
+    let total = count_for_team(pool, team_id).await?;
+    let mut items = Vec::with_capacity(rows.len());
+    for row in rows {
+        items.push(descriptor_from_row(&row)?);
+    }
+    Ok((items, total))
+}
+
+pub async fn count_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
+    sqlx::query_scalar("SELECT count(*) FROM proto_descriptors WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count proto descriptors: {e}")))
+}
+
+pub async fn get(
+    pool: &PgPool,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Option<ProtoDescriptor>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM proto_descriptors WHERE team_id = $1 AND name = $2"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get proto descriptor: {e}")))?;
+    row.as_ref().map(descriptor_from_row).transpose()
+}
+
+pub async fn get_by_id(
+    pool: &PgPool,
+    team_id: TeamId,
+    id: ProtoDescriptorId,
+) -> DomainResult<Option<ProtoDescriptor>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM proto_descriptors WHERE team_id = $1 AND id = $2"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(id.as_uuid())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get proto descriptor by id: {e}")))?;
+    row.as_ref().map(descriptor_from_row).transpose()
+}
+
+/// The raw bytes of each requested descriptor set the team owns, keyed by id (snapshot build
+/// only). A missing id is simply absent from the result.
+pub async fn load_descriptor_sets(
+    pool: &PgPool,
+    team_id: TeamId,
+    ids: &[ProtoDescriptorId],
+) -> DomainResult<Vec<(ProtoDescriptorId, Vec<u8>)>> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let ids: Vec<Uuid> = ids.iter().map(|id| id.as_uuid()).collect();
+    let rows = sqlx::query(
+        "SELECT id, descriptor_set FROM proto_descriptors WHERE team_id = $1 AND id = ANY($2)",
+    )
+    .bind(team_id.as_uuid())
+    .bind(&ids)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("load proto descriptor sets: {e}")))?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            (
+                ProtoDescriptorId::from(row.get::<Uuid, _>("id")),
+                row.get("descriptor_set"),
+            )
+        })
+        .collect())
+}
+
+/// Listeners whose chain carries a transcoder pointing at `id` (delete guard).
+pub async fn listeners_referencing(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    id: ProtoDescriptorId,
+) -> DomainResult<Vec<String>> {
+    sqlx::query_scalar(
+        "SELECT name FROM listeners WHERE team_id = $1 AND spec->'http_filters' @> \
+           jsonb_build_array(jsonb_build_object('filter', jsonb_build_object( \
+             'type', 'grpc_json_transcoder', 'proto_descriptor_id', $2::text))) \
+         ORDER BY name LIMIT 10",
+    )
+    .bind(team_id.as_uuid())
+    .bind(id.as_uuid().to_string())
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("proto descriptor dependents: {e}")))
+}
+
+pub async fn delete(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    expected_version: i64,
+) -> DomainResult<ProtoDescriptorId> {
+    let row = sqlx::query(
+        "DELETE FROM proto_descriptors WHERE team_id = $1 AND name = $2 AND version = $3 RETURNING id",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("delete proto descriptor: {e}")))?;
+    match row {
+        Some(row) => Ok(ProtoDescriptorId::from(row.get::<Uuid, _>("id"))),
+        None => {
+            let current: Option<i64> = sqlx::query_scalar(
+                "SELECT version FROM proto_descriptors WHERE team_id = $1 AND name = $2",
+            )
+            .bind(team_id.as_uuid())
+            .bind(name)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| DomainError::internal(format!("delete proto descriptor: recheck: {e}")))?;
+            Err(match current {
+                Some(version) => DomainError::new(
+                    ErrorCode::RevisionMismatch,
+                    format!(
+                        "proto descriptor \"{name}\" is at revision {version}, you supplied {expected_version}"
+                    ),
+                )
+                .with_hint("re-read the resource and retry with the current revision"),
+                None => DomainError::not_found("proto descriptor", name),
+            })
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Per-team quota overrides. Keyed by team and resource kind; a missing row means the
+//! service-layer default applies.
+
+use fp_domain::{DomainError, DomainResult, TeamId};
+use sqlx::{PgPool, Postgres, Transaction};
+
+/// The override for one resource kind, if any.
+pub async fn team_quota_override(
+    pool: &PgPool,
+    team_id: TeamId,
+    resource: &str,
+) -> DomainResult<Option<i64>> {
+    sqlx::query_scalar("SELECT max_count FROM team_quotas WHERE team_id = $1 AND resource = $2")
+        .bind(team_id.as_uuid())
+        .bind(resource)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("get team quota: {e}")))
+}
+
+/// Every override for the team, as `(resource, max_count)`.
+pub async fn list_team_quota_overrides(
+    pool: &PgPool,
+    team_id: TeamId,
+) -> DomainResult<Vec<(String, i64)>> {
+    sqlx::query_as(
+        "SELECT resource, max_count FROM team_quotas WHERE team_id = $1 ORDER BY resource",
+    )
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list team quotas: {e}")))
+}
+
+/// Replace the team's overrides with `overrides`; kinds not listed fall back to defaults.
+pub async fn replace_team_quota_overrides(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    overrides: &[(&str, i64)],
+) -> DomainResult<()> {
+    sqlx::query("DELETE FROM team_quotas WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DomainError::internal(format!("clear team quotas: {e}")))?;
+    for (resource, max_count) in overrides {
+        sqlx::query("INSERT INTO team_quotas (team_id, resource, max_count) VALUES ($1, $2, $3)")
+            .bind(team_id.as_uuid())
+            .bind(resource)
+            .bind(max_count)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| DomainError::internal(format!("set team quota: {e}")))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,226 @@
+//! Named retry policies. Route configs reference them by name from inside their spec JSON,
+//! so dependents are found with a JSON path query rather than a join table.
+
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::route_config::{RetryPolicy, RouteConfigSpec};
+use fp_domain::gateway::NamedRetryPolicy;
+use fp_domain::{DomainError, DomainResult, ErrorCode, RetryPolicyId, TeamId};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+const COLUMNS: &str = "id, team_id, name, spec, version, created_at, updated_at";
+
+/// JSON path matching a route action that names `$name` as its retry policy.
+const REFERENCE_PATH: &str = "$.virtual_hosts[*].routes[*].action ? (@.retry_policy_ref == $name)";
+
+fn policy_from_row(row: &PgRow) -> DomainResult<NamedRetryPolicy> {
+    Ok(NamedRetryPolicy {
+        id: RetryPolicyId::from(row.get::<Uuid, _>("id")),
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        name: row.get("name"),
+        spec: serde_json::from_value(row.get::<serde_json::Value, _>("spec")).map_err(|e| {
+            DomainError::internal(format!("retry policy spec in DB does not parse: {e}"))
+        })?,
+        version: row.get("version"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+fn spec_json(spec: &RetryPolicy) -> DomainResult<serde_json::Value> {
+    serde_json::to_value(spec)
+        .map_err(|e| DomainError::internal(format!("serialize retry policy spec: {e}")))
+}
+
+fn stale_or_missing(name: &str, current: Option<i64>, expected: i64) -> DomainError {
+    match current {
+        Some(version) => DomainError::new(
+            ErrorCode::RevisionMismatch,
+            format!("retry policy \"{name}\" is at revision {version}, you supplied {expected}"),
+        )
+        .with_hint("re-read the resource and retry with the current revision"),
+        None => DomainError::not_found("retry policy", name),
+    }
+}
+
+async fn current_version(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Option<i64>> {
+    sqlx::query_scalar("SELECT version FROM retry_policies WHERE team_id = $1 AND name = $2")
+        .bind(team_id.as_uuid())
+        .bind(name)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| DomainError::internal(format!("retry policy: recheck: {e}")))
+}
+
+pub async fn create(
+    tx: &mut Transaction<'_, Postgres>,
+    team: TeamRef,
+    name: &str,
+    spec: &RetryPolicy,
+) -> DomainResult<NamedRetryPolicy> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO retry_policies (id, team_id, org_id, name, spec) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {COLUMNS}"
+    ))
+    .bind(RetryPolicyId::generate().as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(name)
+    .bind(spec_json(spec)?)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+            DomainError::conflict(format!(
+                "retry policy \"{name}\" already exists in this team"
+            ))
+            .with_hint("choose a different name, or update the existing policy")
+        }
+        _ => DomainError::internal(format!("create retry policy: {e}")),
+    })?;
+    policy_from_row(&row)
+}
+
+pub async fn list(
+    pool: &PgPool,
+    team_id: TeamId,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<NamedRetryPolicy>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM retry_policies WHERE team_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list retry policies: {e}")))?;
+    let total = count_for_team(pool, team_id).await?;
+    let mut items = Vec::with_capacity(rows.len());
+    for row in rows {
+        items.push(policy_from_row(&row)?);
+    }
+    Ok((items, total))
+}
+
+pub async fn count_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
+    sqlx::query_scalar("SELECT count(*) FROM retry_policies WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count retry policies: {e}")))
+}
+
+pub async fn get(
+    pool: &PgPool,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Option<NamedRetryPolicy>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM retry_policies WHERE team_id = $1 AND name = $2"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get retry policy: {e}")))?;
+    row.as_ref().map(policy_from_row).transpose()
+}
+
+/// Every policy the team owns, keyed by name (route write checks and snapshot build).
+pub async fn list_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<Vec<NamedRetryPolicy>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM retry_policies WHERE team_id = $1 ORDER BY name LIMIT 500"
+    ))
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list retry policies for team: {e}")))?;
+    rows.iter().map(policy_from_row).collect()
+}
+
+pub async fn update(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    spec: &RetryPolicy,
+    expected_version: i64,
+) -> DomainResult<NamedRetryPolicy> {
+    let row = sqlx::query(&format!(
+        "UPDATE retry_policies SET spec = $1, version = version + 1, updated_at = now() \
+         WHERE team_id = $2 AND name = $3 AND version = $4 RETURNING {COLUMNS}"
+    ))
+    .bind(spec_json(spec)?)
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("update retry policy: {e}")))?;
+    match row {
+        Some(row) => policy_from_row(&row),
+        None => {
+            let current = current_version(tx, team_id, name).await?;
+            Err(stale_or_missing(name, current, expected_version))
+        }
+    }
+}
+
+/// Route configs (name and spec) with at least one route naming `name` as its
+/// `retry_policy_ref`. Feeds the delete guard and the per-try timeout recheck on update.
+pub async fn route_configs_referencing(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Vec<(String, RouteConfigSpec)>> {
+    let rows = sqlx::query(
+        "SELECT name, spec FROM route_configs WHERE team_id = $1 \
+           AND jsonb_path_exists(spec, $2::jsonpath, jsonb_build_object('name', $3::text)) \
+         ORDER BY name",
+    )
+    .bind(team_id.as_uuid())
+    .bind(REFERENCE_PATH)
+    .bind(name)
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("retry policy dependents: {e}")))?;
+    rows.iter()
+        .map(|row| {
+            let spec =
+                serde_json::from_value(row.get::<serde_json::Value, _>("spec")).map_err(|e| {
+                    DomainError::internal(format!("route-config spec in DB does not parse: {e}"))
+                })?;
+            Ok((row.get("name"), spec))
+        })
+        .collect()
+}
+
+pub async fn delete(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    expected_version: i64,
+) -> DomainResult<RetryPolicyId> {
+    let row = sqlx::query(
+        "DELETE FROM retry_policies WHERE team_id = $1 AND name = $2 AND version = $3 RETURNING id",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(expected_version)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("delete retry policy: {e}")))?;
+    match row {
+        Some(row) => Ok(RetryPolicyId::from(row.get::<Uuid, _>("id"))),
+        None => {
+            let current = current_version(tx, team_id, name).await?;
+            Err(stale_or_missing(name, current, expected_version))
+        }
+    }
+}
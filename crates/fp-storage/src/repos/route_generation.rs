@@ -2,8 +2,9 @@
 
 use fp_domain::authz::TeamRef;
 use fp_domain::{
-    DomainError, DomainResult, RouteGenerationPlan, RouteGenerationPlanId, RouteGenerationPlanSpec,
-    RouteGenerationPlanStatus, SpecVersionId, TeamId,
+    ApiDefinitionId, DomainError, DomainResult, ResourceSource, RouteGenerationPlan,
+    RouteGenerationPlanId, RouteGenerationPlanSpec, RouteGenerationPlanStatus, SpecVersionId,
+    TeamId,
 };
 use sqlx::postgres::PgRow;
 use sqlx::{PgPool, Postgres, Row, Transaction};
@@ -24,6 +25,25 @@ fn from_row(row: &PgRow) -> DomainResult<RouteGenerationPlan> {
     })
 }
 
+/// SQL appended to a `FROM {table} WHERE …` list query to keep only rows of `source`: those
+/// whose name an applied plan of the same team generated as `plan_field` (`cluster_name`,
+/// `route_config_name`, `listener_name`), or those no applied plan generated.
+pub(crate) fn source_filter(
+    source: Option<ResourceSource>,
+    table: &'static str,
+    plan_field: &'static str,
+) -> String {
+    let generated = format!(
+        "EXISTS (SELECT 1 FROM route_generation_plans p WHERE p.team_id = {table}.team_id \
+         AND p.status = 'applied' AND p.plan->>'{plan_field}' = {table}.name)"
+    );
+    match source {
+        None => String::new(),
+        Some(ResourceSource::OpenapiImport) => format!(" AND {generated}"),
+        Some(ResourceSource::NativeApi) => format!(" AND NOT {generated}"),
+    }
+}
+
 pub async fn create(
     tx: &mut Transaction<'_, Postgres>,
     team: TeamRef,
@@ -66,6 +86,54 @@ pub async fn get(
     row.as_ref().map(from_row).transpose()
 }
 
+/// A team's plans, newest first, with the total count.
+pub async fn list(
+    pool: &PgPool,
+    team_id: TeamId,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<RouteGenerationPlan>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM route_generation_plans WHERE team_id = $1 \
+         ORDER BY created_at DESC, id DESC LIMIT $2 OFFSET $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list route generation plans: {e}")))?;
+    let total: i64 =
+        sqlx::query_scalar("SELECT count(*) FROM route_generation_plans WHERE team_id = $1")
+            .bind(team_id.as_uuid())
+            .fetch_one(pool)
+            .await
+            .map_err(|e| DomainError::internal(format!("count route generation plans: {e}")))?;
+    Ok((
+        rows.iter().map(from_row).collect::<DomainResult<_>>()?,
+        total,
+    ))
+}
+
+/// The most recently applied plan of an API definition, if any (the one a sync plan supersedes).
+pub async fn latest_applied_for_api(
+    pool: &PgPool,
+    team_id: TeamId,
+    api_definition_id: ApiDefinitionId,
+) -> DomainResult<Option<RouteGenerationPlan>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM route_generation_plans \
+         WHERE team_id = $1 AND api_definition_id = $2 AND status = 'applied' \
+         ORDER BY applied_at DESC LIMIT 1"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(api_definition_id.as_uuid())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("latest applied route generation plan: {e}")))?;
+    row.as_ref().map(from_row).transpose()
+}
+
 pub async fn mark_applied(
     tx: &mut Transaction<'_, Postgres>,
     team_id: TeamId,
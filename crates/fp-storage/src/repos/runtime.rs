@@ -0,0 +1,111 @@
+//! Runtime values served over RTDS. Keyed by (team, key): a write replaces the value and bumps
+//! the version, so there is no create/update split.
+
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::RuntimeValue;
+use fp_domain::{DomainError, DomainResult, TeamId};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+const COLUMNS: &str = "team_id, key, value, version, created_at, updated_at";
+
+fn value_from_row(row: &PgRow) -> RuntimeValue {
+    RuntimeValue {
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        key: row.get("key"),
+        value: row.get("value"),
+        version: row.get("version"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+pub async fn list(
+    pool: &PgPool,
+    team_id: TeamId,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<RuntimeValue>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM runtime_values WHERE team_id = $1 ORDER BY key LIMIT $2 OFFSET $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list runtime values: {e}")))?;
+    let total = count_for_team(pool, team_id).await?;
+    Ok((rows.iter().map(value_from_row).collect(), total))
+}
+
+pub async fn count_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
+    sqlx::query_scalar("SELECT count(*) FROM runtime_values WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count runtime values: {e}")))
+}
+
+pub async fn get(pool: &PgPool, team_id: TeamId, key: &str) -> DomainResult<Option<RuntimeValue>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM runtime_values WHERE team_id = $1 AND key = $2"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(key)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get runtime value: {e}")))?;
+    Ok(row.as_ref().map(value_from_row))
+}
+
+/// Every value the team owns (snapshot build). The per-team quota keeps this well under the
+/// row cap.
+pub async fn list_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<Vec<RuntimeValue>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM runtime_values WHERE team_id = $1 ORDER BY key LIMIT 1000"
+    ))
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list runtime values for team: {e}")))?;
+    Ok(rows.iter().map(value_from_row).collect())
+}
+
+pub async fn upsert(
+    tx: &mut Transaction<'_, Postgres>,
+    team: TeamRef,
+    key: &str,
+    value: &serde_json::Value,
+) -> DomainResult<RuntimeValue> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO runtime_values (team_id, org_id, key, value) VALUES ($1, $2, $3, $4) \
+         ON CONFLICT (team_id, key) DO UPDATE \
+           SET value = EXCLUDED.value, version = runtime_values.version + 1, updated_at = now() \
+         RETURNING {COLUMNS}"
+    ))
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(key)
+    .bind(value)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("set runtime value: {e}")))?;
+    Ok(value_from_row(&row))
+}
+
+/// Returns false when the key was not set.
+pub async fn delete(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    key: &str,
+) -> DomainResult<bool> {
+    let result = sqlx::query("DELETE FROM runtime_values WHERE team_id = $1 AND key = $2")
+        .bind(team_id.as_uuid())
+        .bind(key)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DomainError::internal(format!("delete runtime value: {e}")))?;
+    Ok(result.rows_affected() > 0)
+}
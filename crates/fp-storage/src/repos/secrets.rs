@@ -2,7 +2,9 @@
 //! accidentally echo plaintext because this module has no API that returns decrypted values.
 
 use fp_domain::authz::TeamRef;
-use fp_domain::{DomainError, DomainResult, ErrorCode, Secret, SecretId, SecretType, TeamId};
+use fp_domain::{
+    DomainError, DomainResult, ErrorCode, OrgId, Secret, SecretId, SecretType, TeamId,
+};
 use sqlx::postgres::PgRow;
 use sqlx::types::chrono;
 use sqlx::{PgPool, Postgres, Row, Transaction};
@@ -10,7 +12,7 @@ use std::str::FromStr;
 use uuid::Uuid;
 
 const COLUMNS: &str = "id, team_id, name, description, secret_type, version, encryption_key_id, \
-                       expires_at, created_at, updated_at";
+                       expires_at, expired_at, created_at, updated_at";
 
 fn secret_from_row(row: &PgRow) -> DomainResult<Secret> {
     Ok(Secret {
@@ -22,6 +24,7 @@ fn secret_from_row(row: &PgRow) -> DomainResult<Secret> {
         version: row.get("version"),
         encryption_key_id: row.get("encryption_key_id"),
         expires_at: row.get("expires_at"),
+        expired_at: row.get("expired_at"),
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
     })
@@ -116,6 +119,50 @@ pub async fn delete_expired_for_team(
     Ok(result.rows_affected())
 }
 
+/// A secret the expiry sweep just withdrew; enough to scope its outbox event and notice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpiredSecret {
+    pub id: SecretId,
+    pub team: TeamRef,
+    pub name: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Stamp `expired_at` on up to `limit` secrets whose `expires_at` is at or before `as_of` and
+/// that have not been marked yet. `SKIP LOCKED` lets concurrent control planes split the work
+/// instead of emitting the same expiry twice.
+pub async fn mark_expired_secrets(
+    tx: &mut Transaction<'_, Postgres>,
+    as_of: chrono::DateTime<chrono::Utc>,
+    limit: i64,
+) -> DomainResult<Vec<ExpiredSecret>> {
+    let rows = sqlx::query(
+        "UPDATE secrets SET expired_at = now() \
+         WHERE id IN ( \
+           SELECT id FROM secrets \
+           WHERE expired_at IS NULL AND expires_at <= $1 \
+           ORDER BY expires_at LIMIT $2 FOR UPDATE SKIP LOCKED) \
+         RETURNING id, team_id, org_id, name, expires_at",
+    )
+    .bind(as_of)
+    .bind(limit.max(1))
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("mark expired secrets: {e}")))?;
+    Ok(rows
+        .iter()
+        .map(|row| ExpiredSecret {
+            id: SecretId::from(row.get::<Uuid, _>("id")),
+            team: TeamRef {
+                id: TeamId::from(row.get::<Uuid, _>("team_id")),
+                org_id: OrgId::from(row.get::<Uuid, _>("org_id")),
+            },
+            name: row.get("name"),
+            expires_at: row.get("expires_at"),
+        })
+        .collect())
+}
+
 pub async fn get_secret(
     pool: &PgPool,
     team_id: TeamId,
@@ -186,7 +233,8 @@ pub async fn rotate_secret(
 ) -> DomainResult<Secret> {
     let row = sqlx::query(&format!(
         "UPDATE secrets SET configuration_encrypted = $1, nonce = $2, encryption_key_id = $3, \
-            secret_type = $4, expires_at = $5, version = version + 1, updated_at = now() \
+            secret_type = $4, expires_at = $5, expired_at = NULL, version = version + 1, \
+            updated_at = now() \
          WHERE team_id = $6 AND name = $7 AND version = $8 RETURNING {COLUMNS}"
     ))
     .bind(ciphertext)
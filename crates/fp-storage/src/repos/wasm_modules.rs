@@ -0,0 +1,193 @@
+//! Uploaded proxy-wasm modules (wasm HTTP filter). Metadata read paths never select the
+//! `module` bytes; only the snapshot loader does.
+
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::WasmModule;
+use fp_domain::{DomainError, DomainResult, TeamId, WasmModuleId};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+const COLUMNS: &str = "id, team_id, name, version, sha256, \
+                       octet_length(module)::BIGINT AS size_bytes, created_at";
+
+fn module_from_row(row: &PgRow) -> WasmModule {
+    WasmModule {
+        id: WasmModuleId::from(row.get::<Uuid, _>("id")),
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        name: row.get("name"),
+        version: row.get("version"),
+        sha256: row.get("sha256"),
+        size_bytes: row.get("size_bytes"),
+        created_at: row.get("created_at"),
+    }
+}
+
+/// Store `module` as the next version of `name` (1 for a new name).
+pub async fn create(
+    tx: &mut Transaction<'_, Postgres>,
+    team: TeamRef,
+    name: &str,
+    module: &[u8],
+    sha256: &str,
+) -> DomainResult<WasmModule> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO wasm_modules (id, team_id, org_id, name, version, module, sha256) \
+         VALUES ($1, $2, $3, $4, \
+           COALESCE((SELECT max(version) FROM wasm_modules WHERE team_id = $2 AND name = $4), 0) + 1, \
+           $5, $6) \
+         RETURNING {COLUMNS}"
+    ))
+    .bind(WasmModuleId::generate().as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(name)
+    .bind(module)
+    .bind(sha256)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+            DomainError::conflict(format!(
+                "another version of wasm module \"{name}\" was uploaded concurrently"
+            ))
+            .with_hint("retry the upload")
+        }
+        _ => DomainError::internal(format!("create wasm module: {e}")),
+    })?;
+    Ok(module_from_row(&row))
+}
+
+/// Every version in the team, by name then version. `name` narrows to one module.
+pub async fn list(
+    pool: &PgPool,
+    team_id: TeamId,
+    name: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<WasmModule>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM wasm_modules WHERE team_id = $1 AND ($2::TEXT IS NULL OR name = $2) \
+         ORDER BY name, version LIMIT $3 OFFSET $4"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list wasm modules: {e}")))?;
+    let total: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM wasm_modules WHERE team_id = $1 AND ($2::TEXT IS NULL OR name = $2)",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("count wasm modules: {e}")))?;
+    Ok((rows.iter().map(module_from_row).collect(), total))
+}
+
+pub async fn count_for_team(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
+    sqlx::query_scalar("SELECT count(*) FROM wasm_modules WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count wasm modules: {e}")))
+}
+
+pub async fn get(
+    pool: &PgPool,
+    team_id: TeamId,
+    name: &str,
+    version: i64,
+) -> DomainResult<Option<WasmModule>> {
+    let row = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM wasm_modules WHERE team_id = $1 AND name = $2 AND version = $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(version)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get wasm module: {e}")))?;
+    Ok(row.as_ref().map(module_from_row))
+}
+
+/// The bytes of each requested `(name, version)` the team owns (snapshot build only). A
+/// missing version is simply absent from the result.
+pub async fn load_modules(
+    pool: &PgPool,
+    team_id: TeamId,
+    refs: &[(String, i64)],
+) -> DomainResult<Vec<((String, i64), Vec<u8>)>> {
+    if refs.is_empty() {
+        return Ok(Vec::new());
+    }
+    let (names, versions): (Vec<&str>, Vec<i64>) = refs
+        .iter()
+        .map(|(name, version)| (name.as_str(), *version))
+        .unzip();
+    let rows = sqlx::query(
+        "SELECT m.name, m.version, m.module FROM wasm_modules m \
+         JOIN unnest($2::TEXT[], $3::BIGINT[]) AS r(name, version) \
+           ON m.name = r.name AND m.version = r.version \
+         WHERE m.team_id = $1",
+    )
+    .bind(team_id.as_uuid())
+    .bind(&names)
+    .bind(&versions)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("load wasm modules: {e}")))?;
+    Ok(rows
+        .iter()
+        .map(|row| ((row.get("name"), row.get("version")), row.get("module")))
+        .collect())
+}
+
+/// Listeners whose chain carries a wasm filter pointing at this version (delete guard).
+pub async fn listeners_referencing(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    version: i64,
+) -> DomainResult<Vec<String>> {
+    sqlx::query_scalar(
+        "SELECT name FROM listeners WHERE team_id = $1 AND spec->'http_filters' @> \
+           jsonb_build_array(jsonb_build_object('filter', jsonb_build_object( \
+             'type', 'wasm', 'module', jsonb_build_object( \
+               'source', 'registry', 'name', $2::text, 'version', $3::bigint)))) \
+         ORDER BY name LIMIT 10",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .bind(version)
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("wasm module dependents: {e}")))
+}
+
+pub async fn delete(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+    version: i64,
+) -> DomainResult<()> {
+    let deleted =
+        sqlx::query("DELETE FROM wasm_modules WHERE team_id = $1 AND name = $2 AND version = $3")
+            .bind(team_id.as_uuid())
+            .bind(name)
+            .bind(version)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| DomainError::internal(format!("delete wasm module: {e}")))?
+            .rows_affected();
+    if deleted == 0 {
+        return Err(DomainError::not_found(
+            "wasm module",
+            &format!("{name} v{version}"),
+        ));
+    }
+    Ok(())
+}
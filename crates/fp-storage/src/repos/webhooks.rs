@@ -0,0 +1,215 @@
+//! Team webhook subscriptions, and dead-lettered deliveries: notices that failed every
+//! retry. Dead letters are written by the delivery path (best-effort, like NACK recording)
+//! and read only by the platform-admin listing.
+
+use fp_domain::authz::TeamRef;
+use fp_domain::{
+    DomainError, DomainResult, SecretId, TeamId, WebhookSubscription, WebhookSubscriptionId,
+    WebhookSubscriptionSpec,
+};
+use sqlx::postgres::PgRow;
+use sqlx::types::chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+const SUBSCRIPTION_COLUMNS: &str = "id, team_id, name, url, events, secret_id, created_at";
+
+fn subscription_from_row(row: &PgRow) -> WebhookSubscription {
+    WebhookSubscription {
+        id: WebhookSubscriptionId::from(row.get::<Uuid, _>("id")),
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        name: row.get("name"),
+        spec: WebhookSubscriptionSpec {
+            url: row.get("url"),
+            events: row.get("events"),
+            secret_id: row.get::<Option<Uuid>, _>("secret_id").map(SecretId::from),
+        },
+        created_at: row.get("created_at"),
+    }
+}
+
+pub async fn create_subscription(
+    tx: &mut Transaction<'_, Postgres>,
+    team: TeamRef,
+    name: &str,
+    spec: &WebhookSubscriptionSpec,
+) -> DomainResult<WebhookSubscription> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO webhook_subscriptions (id, team_id, org_id, name, url, events, secret_id) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {SUBSCRIPTION_COLUMNS}"
+    ))
+    .bind(WebhookSubscriptionId::generate().as_uuid())
+    .bind(team.id.as_uuid())
+    .bind(team.org_id.as_uuid())
+    .bind(name)
+    .bind(&spec.url)
+    .bind(&spec.events)
+    .bind(spec.secret_id.map(|id| id.as_uuid()))
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+            DomainError::conflict(format!("webhook \"{name}\" already exists in this team"))
+                .with_hint("choose a different name, or delete the existing webhook first")
+        }
+        _ => DomainError::internal(format!("create webhook subscription: {e}")),
+    })?;
+    Ok(subscription_from_row(&row))
+}
+
+pub async fn list_subscriptions(
+    pool: &PgPool,
+    team_id: TeamId,
+    limit: i64,
+    offset: i64,
+) -> DomainResult<(Vec<WebhookSubscription>, i64)> {
+    let rows = sqlx::query(&format!(
+        "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE team_id = $1 \
+         ORDER BY name LIMIT $2 OFFSET $3"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(limit.clamp(1, 500))
+    .bind(offset.max(0))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list webhook subscriptions: {e}")))?;
+    let total = count_subscriptions(pool, team_id).await?;
+    Ok((rows.iter().map(subscription_from_row).collect(), total))
+}
+
+pub async fn count_subscriptions(pool: &PgPool, team_id: TeamId) -> DomainResult<i64> {
+    sqlx::query_scalar("SELECT count(*) FROM webhook_subscriptions WHERE team_id = $1")
+        .bind(team_id.as_uuid())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::internal(format!("count webhook subscriptions: {e}")))
+}
+
+pub async fn get_subscription(
+    pool: &PgPool,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Option<WebhookSubscription>> {
+    let row = sqlx::query(&format!(
+        "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE team_id = $1 AND name = $2"
+    ))
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("get webhook subscription: {e}")))?;
+    Ok(row.as_ref().map(subscription_from_row))
+}
+
+/// Every subscription the team owns (event fan-out).
+pub async fn subscriptions_for_team(
+    pool: &PgPool,
+    team_id: TeamId,
+) -> DomainResult<Vec<WebhookSubscription>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE team_id = $1 \
+         ORDER BY name LIMIT 500"
+    ))
+    .bind(team_id.as_uuid())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list team webhook subscriptions: {e}")))?;
+    Ok(rows.iter().map(subscription_from_row).collect())
+}
+
+/// Delete by name; `None` when the team has no such subscription.
+pub async fn delete_subscription(
+    tx: &mut Transaction<'_, Postgres>,
+    team_id: TeamId,
+    name: &str,
+) -> DomainResult<Option<WebhookSubscriptionId>> {
+    let id: Option<Uuid> = sqlx::query_scalar(
+        "DELETE FROM webhook_subscriptions WHERE team_id = $1 AND name = $2 RETURNING id",
+    )
+    .bind(team_id.as_uuid())
+    .bind(name)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DomainError::internal(format!("delete webhook subscription: {e}")))?;
+    Ok(id.map(WebhookSubscriptionId::from))
+}
+
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub id: Uuid,
+    pub team_id: TeamId,
+    /// The subscription that gave up; `None` for the operator's secret-expiry webhook, or once
+    /// the subscription is deleted.
+    pub subscription_id: Option<WebhookSubscriptionId>,
+    pub event: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub last_status: Option<i32>,
+    pub last_error: String,
+    pub created_at: DateTime<Utc>,
+}
+
+fn from_row(row: &PgRow) -> DeadLetter {
+    DeadLetter {
+        id: row.get("id"),
+        team_id: TeamId::from(row.get::<Uuid, _>("team_id")),
+        subscription_id: row
+            .get::<Option<Uuid>, _>("subscription_id")
+            .map(WebhookSubscriptionId::from),
+        event: row.get("event"),
+        payload: row.get("payload"),
+        attempts: row.get("attempts"),
+        last_status: row.get("last_status"),
+        last_error: row.get("last_error"),
+        created_at: row.get("created_at"),
+    }
+}
+
+/// What the delivery path records about one abandoned notice.
+#[derive(Debug, Clone)]
+pub struct DeadLetterRecord {
+    pub team_id: TeamId,
+    pub subscription_id: Option<WebhookSubscriptionId>,
+    pub event: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub last_status: Option<i32>,
+    pub last_error: String,
+}
+
+/// Insert one dead letter. The team's org is resolved in SQL; a vanished team makes this a
+/// no-op rather than an error.
+pub async fn record_dead_letter(pool: &PgPool, record: &DeadLetterRecord) -> DomainResult<()> {
+    sqlx::query(
+        "INSERT INTO webhook_dead_letters \
+           (id, team_id, org_id, event, payload, attempts, last_status, last_error, \
+            subscription_id) \
+         SELECT $1, t.id, t.org_id, $3, $4, $5, $6, $7, $8 FROM teams t WHERE t.id = $2",
+    )
+    .bind(Uuid::now_v7())
+    .bind(record.team_id.as_uuid())
+    .bind(&record.event)
+    .bind(&record.payload)
+    .bind(record.attempts)
+    .bind(record.last_status)
+    .bind(&record.last_error)
+    .bind(record.subscription_id.map(|id| id.as_uuid()))
+    .execute(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("record webhook dead letter: {e}")))?;
+    Ok(())
+}
+
+/// Newest dead letters across every team (platform-admin listing only).
+pub async fn list_dead_letters(pool: &PgPool, limit: i64) -> DomainResult<Vec<DeadLetter>> {
+    let rows = sqlx::query(
+        "SELECT id, team_id, subscription_id, event, payload, attempts, last_status, last_error, \
+                created_at \
+         FROM webhook_dead_letters ORDER BY created_at DESC, id DESC LIMIT $1",
+    )
+    .bind(limit.clamp(1, 500))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list webhook dead letters: {e}")))?;
+    Ok(rows.iter().map(from_row).collect())
+}
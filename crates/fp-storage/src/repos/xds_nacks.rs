@@ -80,6 +80,20 @@ pub async fn list(pool: &PgPool, team_id: TeamId, limit: i64) -> DomainResult<Ve
     Ok(rows.iter().map(from_row).collect())
 }
 
+/// Newest NACKs across every team (platform diagnostics only; tenant reads use [`list`]).
+pub async fn list_recent_all(pool: &PgPool, limit: i64) -> DomainResult<Vec<NackEvent>> {
+    let rows = sqlx::query(
+        "SELECT id, team_id, node_id, type_url, version_rejected, error_message, \
+                quarantined_resources, created_at \
+         FROM xds_nack_events ORDER BY created_at DESC LIMIT $1",
+    )
+    .bind(limit.clamp(1, 500))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::internal(format!("list recent nacks: {e}")))?;
+    Ok(rows.iter().map(from_row).collect())
+}
+
 pub async fn count_recent(pool: &PgPool, team_id: TeamId, minutes: i64) -> DomainResult<i64> {
     sqlx::query_scalar(
         "SELECT count(*)::bigint FROM xds_nack_events \
@@ -24,8 +24,7 @@ const DEV_SUBJECT: &str = "dev-user";
 
 /// Seed dev org/team/user/membership. Safe to call on every dev-mode boot.
 pub async fn seed_dev(pool: &PgPool) -> DomainResult<UserId> {
-    let mut tx = pool
-        .begin()
+    let mut tx = crate::begin(pool)
         .await
         .map_err(|e| DomainError::internal(format!("seed: begin transaction: {e}")))?;
 
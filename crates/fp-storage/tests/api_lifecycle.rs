@@ -547,6 +547,10 @@ async fn capture_sessions_are_bounded_and_transitioned_per_team() {
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 20,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -611,6 +615,10 @@ async fn capture_sessions_reject_cross_team_route_scope() {
             max_duration_seconds: None,
             max_bytes: 1024,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -662,6 +670,10 @@ async fn capture_ingest_binding_validates_active_team_and_scope() {
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 20,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -747,6 +759,10 @@ async fn raw_observation_ingest_redacts_and_counts_accepted_rows() {
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -787,7 +803,8 @@ async fn raw_observation_ingest_redacts_and_counts_accepted_rows() {
         &input,
     )
     .await
-    .expect("ingest");
+    .expect("ingest")
+    .expect("sampled");
     tx.commit().await.expect("commit ingest");
 
     assert_eq!(row.request_headers["authorization"], "[REDACTED]");
@@ -807,6 +824,77 @@ async fn raw_observation_ingest_redacts_and_counts_accepted_rows() {
     assert_eq!(refreshed.drop_count, 0);
 }
 
+#[tokio::test]
+async fn capture_controls_sample_and_mask_before_persisting() {
+    let Some(w) = world().await else { return };
+    let route = insert_route_config(&w.pool, w.team_a, &unique("rc")).await;
+    let listener = insert_listener(&w.pool, w.team_a, &unique("listener")).await;
+    let mut tx = w.pool.begin().await.expect("tx");
+    let session = api_lifecycle::create_capture_session(
+        &mut tx,
+        w.team_a,
+        &unique("capture"),
+        &CaptureSessionSpec {
+            api_definition_id: None,
+            route_config_id: Some(route),
+            listener_id: Some(listener),
+            virtual_host: Some("default".into()),
+            route: Some("all".into()),
+            target_sample_count: 100,
+            max_duration_seconds: Some(60),
+            max_bytes: 1 << 20,
+            max_distinct_paths: 10,
+            sample_percent: Some(50),
+            max_body_bytes: Some(1024),
+            redact_headers: vec!["X-Customer-Id".into()],
+            redact_body_fields: vec!["ssn".into()],
+        },
+    )
+    .await
+    .expect("session");
+    tx.commit().await.expect("commit session");
+    assert_eq!(session.redact_body_fields, vec!["ssn".to_string()]);
+
+    let mut stored = Vec::new();
+    for i in 0..20 {
+        let mut input = observation(&format!("req-sample-{i}"), "/customers");
+        input
+            .request_headers
+            .insert("x-customer-id".into(), serde_json::json!("c-42"));
+        input.response_body = Some(r#"{"name":"a","ssn":"123-45-6789"}"#.into());
+        input.body_seen = true;
+        let mut tx = w.pool.begin().await.expect("ingest tx");
+        let row = api_lifecycle::ingest_raw_observation(
+            &mut tx,
+            w.team_a,
+            session.id,
+            None,
+            route,
+            Some(listener),
+            &input,
+        )
+        .await
+        .expect("ingest");
+        tx.commit().await.expect("commit ingest");
+        assert_eq!(row.is_some(), session.samples(&input.request_id));
+        stored.extend(row);
+    }
+    assert!(!stored.is_empty() && stored.len() < 20, "{}", stored.len());
+    let row = &stored[0];
+    assert_eq!(row.request_headers["x-customer-id"], "[REDACTED]");
+    let body: serde_json::Value =
+        serde_json::from_str(row.response_body.as_deref().expect("body")).expect("json");
+    assert_eq!(body["ssn"], "[REDACTED]");
+    assert_eq!(body["name"], "a");
+
+    let refreshed = api_lifecycle::get_capture_session(&w.pool, w.team_a.id, &session.name)
+        .await
+        .expect("get session")
+        .expect("session");
+    assert_eq!(refreshed.sample_count, stored.len() as i64);
+    assert_eq!(refreshed.drop_count, 0);
+}
+
 #[tokio::test]
 async fn raw_observation_body_merge_does_not_increment_sample_count() {
     let Some(w) = world().await else { return };
@@ -826,6 +914,10 @@ async fn raw_observation_body_merge_does_not_increment_sample_count() {
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -854,7 +946,8 @@ async fn raw_observation_body_merge_does_not_increment_sample_count() {
         &mut tx, w.team_a, session.id, None, route, None, &body,
     )
     .await
-    .expect("body ingest");
+    .expect("body ingest")
+    .expect("sampled");
     tx.commit().await.expect("commit body");
 
     assert_eq!(merged.request_body.as_deref(), Some("hello"));
@@ -887,6 +980,10 @@ async fn raw_observation_duplicate_merges_and_same_path_counts_are_incremental()
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 1,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -971,6 +1068,10 @@ async fn raw_observation_quota_drop_does_not_insert_or_move_sample_count() {
             max_duration_seconds: Some(60),
             max_bytes: 5,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -1024,6 +1125,10 @@ async fn raw_observation_truncated_body_quota_uses_reported_original_bytes() {
             max_duration_seconds: Some(60),
             max_bytes: 5,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -1072,6 +1177,10 @@ async fn raw_observation_target_sample_count_completes_session() {
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -1120,6 +1229,10 @@ async fn raw_observation_body_can_merge_after_target_completion() {
             max_duration_seconds: Some(60),
             max_bytes: 4096,
             max_distinct_paths: 10,
+            sample_percent: None,
+            max_body_bytes: None,
+            redact_headers: Vec::new(),
+            redact_body_fields: Vec::new(),
         },
     )
     .await
@@ -1150,7 +1263,8 @@ async fn raw_observation_body_can_merge_after_target_completion() {
         &mut tx, w.team_a, session.id, None, route, None, &body,
     )
     .await
-    .expect("late body merge");
+    .expect("late body merge")
+    .expect("sampled");
     tx.commit().await.expect("commit body");
 
     assert!(merged.body_seen);
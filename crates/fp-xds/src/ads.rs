@@ -1,4 +1,4 @@
-//! ADS (SOTW) server: one bidirectional stream multiplexing CDS/RDS/LDS per dataplane
+//! ADS (SOTW) server: one bidirectional stream multiplexing CDS/RDS/LDS/RTDS per dataplane
 //! (spec/10 §5). Responses come from the snapshot cache (no per-request DB reads); pushes
 //! follow make-before-break type ordering: clusters → routes → listeners.
 //!
@@ -9,7 +9,7 @@
 
 use crate::snapshot::{
     SnapshotCache, CLUSTER_TYPE_URL, ENDPOINT_TYPE_URL, LISTENER_TYPE_URL, ROUTE_TYPE_URL,
-    SECRET_TYPE_URL,
+    RUNTIME_TYPE_URL, SECRET_TYPE_URL,
 };
 use envoy_types::pb::envoy::service::discovery::v3::aggregated_discovery_service_server::{
     AggregatedDiscoveryService, AggregatedDiscoveryServiceServer,
@@ -29,8 +29,10 @@ use tonic::{Request, Response, Status, Streaming};
 use uuid::Uuid;
 
 /// Make-before-break push order (deletes are handled by SOTW full-set semantics):
-/// clusters warm before their endpoints arrive, routes before the listeners that bind them.
-const TYPE_ORDER: [&str; 5] = [
+/// runtime values land before the routes and filters that read them, clusters warm before
+/// their endpoints arrive, routes before the listeners that bind them.
+const TYPE_ORDER: [&str; 6] = [
+    RUNTIME_TYPE_URL,
     CLUSTER_TYPE_URL,
     ENDPOINT_TYPE_URL,
     ROUTE_TYPE_URL,
@@ -205,7 +207,8 @@ impl AdsService {
 #[derive(Default)]
 struct TypeState {
     subscribed: bool,
-    sent_version: Option<u64>,
+    /// `version_info` of the last push.
+    sent_version: Option<String>,
     last_nonce: String,
     /// Sorted resource names of the last request — a request with different names is a
     /// subscription change and must be answered, never classified as an ACK (the warming
@@ -215,7 +218,7 @@ struct TypeState {
 
 fn response_for(
     type_url: &str,
-    version: u64,
+    version: String,
     resources: Vec<envoy_types::pb::google::protobuf::Any>,
     nonce_seq: &mut u64,
 ) -> (DiscoveryResponse, String) {
@@ -223,7 +226,7 @@ fn response_for(
     let nonce = nonce_seq.to_string();
     (
         DiscoveryResponse {
-            version_info: version.to_string(),
+            version_info: version,
             resources,
             type_url: type_url.to_string(),
             nonce: nonce.clone(),
@@ -256,6 +259,67 @@ fn resources_for_response(
         .collect()
 }
 
+/// Envoy's self-reported build as `<agent>/<version>` (e.g. `envoy/1.31.2`), from either form
+/// of the node's user-agent version.
+fn envoy_version(node: &envoy_types::pb::envoy::config::core::v3::Node) -> Option<String> {
+    use envoy_types::pb::envoy::config::core::v3::node::UserAgentVersionType;
+    let version = match node.user_agent_version_type.as_ref()? {
+        UserAgentVersionType::UserAgentVersion(version) => version.clone(),
+        UserAgentVersionType::UserAgentBuildVersion(build) => {
+            let semver = build.version.as_ref()?;
+            format!(
+                "{}.{}.{}",
+                semver.major_number, semver.minor_number, semver.patch
+            )
+        }
+    };
+    if version.is_empty() {
+        return None;
+    }
+    let agent = if node.user_agent_name.is_empty() {
+        "envoy"
+    } else {
+        node.user_agent_name.as_str()
+    };
+    Some(format!("{agent}/{version}"))
+}
+
+/// Push every subscribed type whose snapshot version this stream has not sent, in
+/// make-before-break order. Returns `false` when the client is gone.
+///
+/// A proxy moved between a held and the latest snapshot sees a new `version_info` on every
+/// type, so promotion re-pushes the full set even where the bytes match.
+async fn push_unsent(
+    snapshot: &crate::snapshot::TeamSnapshot,
+    states: &mut HashMap<String, TypeState>,
+    nonce_seq: &mut u64,
+    tx: &tokio::sync::mpsc::Sender<Result<DiscoveryResponse, Status>>,
+) -> bool {
+    for type_url in TYPE_ORDER {
+        let Some(state) = states.get_mut(type_url) else {
+            continue;
+        };
+        if !state.subscribed {
+            continue;
+        }
+        let Some(set) = snapshot.for_type_url(type_url) else {
+            continue;
+        };
+        let version = snapshot.version_info(set);
+        if state.sent_version.as_ref() == Some(&version) {
+            continue;
+        }
+        let resources = resources_for_response(type_url, set, &state.resource_names);
+        let (response, nonce) = response_for(type_url, version.clone(), resources, nonce_seq);
+        state.sent_version = Some(version);
+        state.last_nonce = nonce;
+        if tx.send(Ok(response)).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
 #[tonic::async_trait]
 impl AggregatedDiscoveryService for AdsService {
     type StreamAggregatedResourcesStream =
@@ -282,6 +346,12 @@ impl AggregatedDiscoveryService for AdsService {
                     .get::<crate::server::PeerSpiffe>()
                     .map(|p| p.0.clone())
             });
+        let mut drain = self.cache.drain_signal();
+        if drain.borrow().is_some() {
+            return Err(Status::unavailable(
+                "control plane is shutting down; reconnect to another replica",
+            ));
+        }
         let mut inbound = request.into_inner();
         let cache = self.cache.clone();
         let resolver = self.resolver.clone();
@@ -292,11 +362,16 @@ impl AggregatedDiscoveryService for AdsService {
         tokio::spawn(async move {
             let mut stream_metrics = AdsStreamMetrics::default();
             let mut team: Option<TeamId> = None;
+            let mut dataplane_id: Option<DataplaneId> = None;
             let mut node_label = String::new();
+            // Whether the last snapshot this stream read was a held (pinned) version.
+            let mut serving_held = false;
             let mut certificate_id: Option<Uuid> = None;
+            let mut connection: Option<fp_domain::connected::ProxyStreamGuard> = None;
             let mut states: HashMap<String, TypeState> = HashMap::new();
             let mut nonce_seq: u64 = 0;
             let mut changes = cache.watch();
+            let mut drain_deadline: Option<tokio::time::Instant> = None;
 
             loop {
                 tokio::select! {
@@ -322,9 +397,21 @@ impl AggregatedDiscoveryService for AdsService {
                                     tracing::info!(team = %identity.team_id, node = node_id,
                                         "dataplane connected");
                                     team = Some(identity.team_id);
+                                    dataplane_id = identity.dataplane_id;
                                     node_label = node_id.to_string();
                                     certificate_id = identity.certificate_id;
                                     stream_metrics.opened();
+                                    connection = Some(cache.connected_proxies().register(
+                                        fp_domain::connected::ProxyConnection {
+                                            node_id: node_id.to_string(),
+                                            team_id: identity.team_id,
+                                            dataplane_id: identity.dataplane_id,
+                                            envoy_version: request
+                                                .node
+                                                .as_ref()
+                                                .and_then(envoy_version),
+                                        },
+                                    ));
                                 }
                                 Err(status) => {
                                     let _ = tx.send(Err(status)).await;
@@ -333,6 +420,9 @@ impl AggregatedDiscoveryService for AdsService {
                             }
                         }
                         let Some(team_id) = team else { return };
+                        if let Some(connection) = &connection {
+                            connection.touch();
+                        }
                         let type_url = request.type_url.clone();
                         if !TYPE_ORDER.contains(&type_url.as_str()) {
                             tracing::debug!(type_url, "ignoring unsupported type url");
@@ -357,10 +447,13 @@ impl AggregatedDiscoveryService for AdsService {
                                     error = %error.message, "xDS NACK");
                                 // S5.5: quarantine what changed (serve last-good bytes) and
                                 // persist the event. The cache notification wakes this very
-                                // stream to push the corrected set.
-                                let quarantined = cache
-                                    .apply_nack(team_id, &type_url, &error.message)
-                                    .await;
+                                // stream to push the corrected set. A held snapshot is not
+                                // the latest generation, so it never drives quarantine.
+                                let quarantined = if serving_held {
+                                    Vec::new()
+                                } else {
+                                    cache.apply_nack(team_id, &type_url, &error.message).await
+                                };
                                 if let Some(pool) = &nack_pool {
                                     let record = fp_storage::repos::xds_nacks::NackRecord {
                                         team_id,
@@ -381,6 +474,8 @@ impl AggregatedDiscoveryService for AdsService {
                                         }
                                     });
                                 }
+                            } else if let Some(connection) = &connection {
+                                connection.acked(&type_url, &request.version_info);
                             }
                             state.subscribed = true;
                             continue;
@@ -388,14 +483,19 @@ impl AggregatedDiscoveryService for AdsService {
 
                         // New subscription (or re-subscribe): answer immediately.
                         state.subscribed = true;
-                        let snapshot = cache.team(team_id).await;
+                        let snapshot = cache.team_for(team_id, dataplane_id, &node_label).await;
+                        serving_held = snapshot.held;
+                        if let Some(connection) = &connection {
+                            connection.serving(&snapshot.config_version);
+                        }
                         if let Some(set) = snapshot.for_type_url(&type_url) {
                             let resources =
                                 resources_for_response(&type_url, set, &state.resource_names);
+                            let version = snapshot.version_info(set);
                             let (response, nonce) = response_for(
-                                &type_url, set.version, resources, &mut nonce_seq,
+                                &type_url, version.clone(), resources, &mut nonce_seq,
                             );
-                            state.sent_version = Some(set.version);
+                            state.sent_version = Some(version);
                             state.last_nonce = nonce;
                             if tx.send(Ok(response)).await.is_err() {
                                 return;
@@ -438,28 +538,47 @@ impl AggregatedDiscoveryService for AdsService {
                         if changed_team.is_some() && changed_team != Some(team_id) {
                             continue; // another tenant's change
                         }
-                        let snapshot = cache.team(team_id).await;
-                        // Push changed types in make-before-break order.
-                        for type_url in TYPE_ORDER {
-                            let Some(state) = states.get_mut(type_url) else { continue };
-                            if !state.subscribed {
-                                continue;
-                            }
-                            let Some(set) = snapshot.for_type_url(type_url) else { continue };
-                            if state.sent_version == Some(set.version) {
-                                continue;
-                            }
-                            let resources =
-                                resources_for_response(type_url, set, &state.resource_names);
-                            let (response, nonce) = response_for(
-                                type_url, set.version, resources, &mut nonce_seq,
-                            );
-                            state.sent_version = Some(set.version);
-                            state.last_nonce = nonce;
-                            if tx.send(Ok(response)).await.is_err() {
+                        let snapshot = cache.team_for(team_id, dataplane_id, &node_label).await;
+                        serving_held = snapshot.held;
+                        if let Some(connection) = &connection {
+                            connection.serving(&snapshot.config_version);
+                        }
+                        if !push_unsent(&snapshot, &mut states, &mut nonce_seq, &tx).await {
+                            return;
+                        }
+                    }
+                    // Copy the grace out inside the future: the watch `Ref` must not be
+                    // held across the pushes below (it is not `Send`).
+                    grace = async {
+                        drain
+                            .wait_for(Option::is_some)
+                            .await
+                            .map(|grace| (*grace).unwrap_or_default())
+                    }, if drain_deadline.is_none() => {
+                        let Ok(grace) = grace else {
+                            return; // cache dropped: server shutting down
+                        };
+                        // Final snapshot: anything this stream has not been sent yet goes out
+                        // now, then the stream keeps serving (ACKs, late pushes) until the
+                        // grace period ends.
+                        if let Some(team_id) = team {
+                            let snapshot =
+                                cache.team_for(team_id, dataplane_id, &node_label).await;
+                            if !push_unsent(&snapshot, &mut states, &mut nonce_seq, &tx).await {
                                 return;
                             }
                         }
+                        drain_deadline = Some(tokio::time::Instant::now() + grace);
+                    }
+                    _ = tokio::time::sleep_until(
+                        drain_deadline.unwrap_or_else(tokio::time::Instant::now),
+                    ), if drain_deadline.is_some() => {
+                        tracing::info!(team = ?team, node = %node_label,
+                            "closing xDS stream: drain grace period elapsed");
+                        let _ = tx.send(Err(Status::unavailable(
+                            "control plane is shutting down; reconnect to another replica",
+                        ))).await;
+                        return;
                     }
                 }
             }
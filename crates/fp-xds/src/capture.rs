@@ -2207,6 +2207,7 @@ fn status_from_domain(err: DomainError) -> Status {
         ErrorCode::ValidationFailed => Status::invalid_argument(err.message),
         ErrorCode::Conflict | ErrorCode::QuotaExceeded => Status::failed_precondition(err.message),
         ErrorCode::Unavailable | ErrorCode::RateLimited => Status::unavailable(err.message),
+        ErrorCode::NotConfigured => Status::unimplemented(err.message),
         ErrorCode::Unauthorized => Status::unauthenticated(err.message),
         ErrorCode::Forbidden => Status::permission_denied(err.message),
         _ => Status::internal(err.message),
@@ -3,6 +3,7 @@
 //! identity boundary.
 
 use crate::ads::TeamResolver;
+pub use fp_domain::dataplane::MAX_REPORTED_CLUSTERS;
 use fp_domain::DataplaneId;
 use std::pin::Pin;
 use std::str::FromStr;
@@ -24,7 +25,7 @@ pub struct DiagnosticsReport {
     pub dataplane_id: String,
     #[prost(message, optional, tag = "4")]
     pub observed_at: Option<prost_types::Timestamp>,
-    #[prost(oneof = "diagnostics_report::Payload", tags = "10, 20")]
+    #[prost(oneof = "diagnostics_report::Payload", tags = "10, 20, 30")]
     pub payload: Option<diagnostics_report::Payload>,
 }
 
@@ -35,6 +36,8 @@ pub mod diagnostics_report {
         ListenerState(super::ListenerStateReport),
         #[prost(message, tag = "20")]
         Heartbeat(super::HeartbeatReport),
+        #[prost(message, tag = "30")]
+        ClusterStats(super::ClusterStatsReport),
     }
 }
 
@@ -64,6 +67,35 @@ pub struct HeartbeatReport {
     pub config_verified: bool,
 }
 
+/// Current upstream gauges for every cluster the Envoy knows. Each report replaces the
+/// previous one for the dataplane.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClusterStatsReport {
+    #[prost(message, repeated, tag = "1")]
+    pub clusters: Vec<UpstreamClusterGauges>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpstreamClusterGauges {
+    #[prost(string, tag = "1")]
+    pub cluster_name: String,
+    #[prost(int64, tag = "2")]
+    pub rq_active: i64,
+    #[prost(int64, tag = "3")]
+    pub rq_pending_active: i64,
+    #[prost(int64, tag = "4")]
+    pub cx_active: i64,
+    #[prost(int64, tag = "5")]
+    pub ejections_active: i64,
+    #[prost(int64, tag = "6")]
+    pub membership_healthy: i64,
+    #[prost(int64, tag = "7")]
+    pub membership_total: i64,
+    /// Tripped circuit-breaker gauges as `<priority>.<gauge>`, e.g. `default.rq_open`.
+    #[prost(string, repeated, tag = "8")]
+    pub open_circuit_breakers: Vec<String>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, prost::Enumeration)]
 #[repr(i32)]
 pub enum ResourceType {
@@ -292,6 +324,9 @@ async fn process_report(
         return Ok(ack(ids, AckStatus::Invalid, "invalid diagnostics report"));
     };
     let (requests_delta, errors_delta, warming_delta, verified) = match payload {
+        diagnostics_report::Payload::ClusterStats(report) => {
+            return record_cluster_stats(pool, team_id, bound_dataplane_id, ids, report).await;
+        }
         diagnostics_report::Payload::Heartbeat(heartbeat) => (
             heartbeat.requests_delta,
             heartbeat.errors_delta,
@@ -327,6 +362,43 @@ async fn process_report(
     Ok(ack(ids, AckStatus::Ok, "accepted"))
 }
 
+async fn record_cluster_stats(
+    pool: &sqlx::PgPool,
+    team_id: fp_domain::TeamId,
+    dataplane_id: DataplaneId,
+    ids: Vec<String>,
+    report: ClusterStatsReport,
+) -> Result<DiagnosticsAck, Status> {
+    if report.clusters.len() > MAX_REPORTED_CLUSTERS {
+        return Ok(ack(
+            ids,
+            AckStatus::Invalid,
+            "too many clusters in stats report",
+        ));
+    }
+    let stats: Vec<fp_domain::UpstreamClusterStats> = report
+        .clusters
+        .into_iter()
+        .map(|gauges| fp_domain::UpstreamClusterStats {
+            cluster: gauges.cluster_name,
+            rq_active: gauges.rq_active,
+            rq_pending_active: gauges.rq_pending_active,
+            cx_active: gauges.cx_active,
+            ejections_active: gauges.ejections_active,
+            healthy_hosts: gauges.membership_healthy,
+            total_hosts: gauges.membership_total,
+            open_circuit_breakers: gauges.open_circuit_breakers,
+        })
+        .collect();
+    if let Some(err) = stats.iter().find_map(|entry| entry.validate().err()) {
+        return Ok(ack(ids, AckStatus::Invalid, &err.message));
+    }
+    fp_storage::repos::dataplanes::replace_cluster_stats(pool, team_id, dataplane_id, &stats)
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+    Ok(ack(ids, AckStatus::Ok, "accepted"))
+}
+
 fn ack(ids: Vec<String>, status: AckStatus, message: &str) -> DiagnosticsAck {
     DiagnosticsAck {
         report_ids: ids,
@@ -414,7 +486,8 @@ mod tests {
                 assert_eq!(heartbeat.warming_failures_delta, 0);
                 assert!(heartbeat.config_verified);
             }
-            diagnostics_report::Payload::ListenerState(_) => {
+            diagnostics_report::Payload::ListenerState(_)
+            | diagnostics_report::Payload::ClusterStats(_) => {
                 return Err("payload kind changed while decoding unknown fields".into());
             }
         }
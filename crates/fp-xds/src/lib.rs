@@ -8,3 +8,4 @@ pub mod diagnostics;
 pub mod server;
 pub mod snapshot;
 pub mod translate;
+pub mod validate;
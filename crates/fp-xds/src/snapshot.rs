@@ -13,14 +13,17 @@ use envoy_types::pb::google::protobuf::Any;
 use fp_domain::gateway::cluster::{Cluster, ClusterSpec};
 use fp_domain::gateway::listener::{Listener, ListenerSpec};
 use fp_domain::gateway::route_config::{RouteConfig, RouteConfigSpec};
-use fp_domain::{AiProviderId, ClusterId, ListenerId, RouteConfigId};
+use fp_domain::gateway::runtime::RUNTIME_LAYER_NAME;
+use fp_domain::gateway::MAX_XDS_RESOURCES_PER_TYPE;
+use fp_domain::{AiProviderId, ClusterId, DataplaneId, ListenerId, RouteConfigId};
 use fp_domain::{DomainError, DomainResult, SecretSpec, TeamId};
 use prost::Message;
 use sqlx::{PgPool, Row};
-use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{watch, Mutex, RwLock};
+use tracing::Instrument;
 
 pub const CLUSTER_TYPE_URL: &str = "type.googleapis.com/envoy.config.cluster.v3.Cluster";
 pub const ROUTE_TYPE_URL: &str = "type.googleapis.com/envoy.config.route.v3.RouteConfiguration";
@@ -29,9 +32,10 @@ pub const ENDPOINT_TYPE_URL: &str =
     "type.googleapis.com/envoy.config.endpoint.v3.ClusterLoadAssignment";
 pub const SECRET_TYPE_URL: &str =
     "type.googleapis.com/envoy.extensions.transport_sockets.tls.v3.Secret";
+pub const RUNTIME_TYPE_URL: &str = "type.googleapis.com/envoy.service.runtime.v3.Runtime";
 
 /// One resource type's serving state for one team (the external, per-stream view).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct ResourceSet {
     /// Monotonic per-type version; bumps only when the served bytes change.
     pub version: u64,
@@ -39,16 +43,100 @@ pub struct ResourceSet {
     pub resources: Vec<Any>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct TeamSnapshot {
     pub clusters: ResourceSet,
     pub endpoints: ResourceSet,
     pub routes: ResourceSet,
     pub secrets: ResourceSet,
     pub listeners: ResourceSet,
+    /// The team's RTDS layer (always exactly one resource once built).
+    pub runtime: ResourceSet,
+    /// Digest of the served resources; dataplane pins and rollouts name it.
+    pub config_version: String,
+    /// Served from a held config version instead of latest (staged rollout).
+    pub held: bool,
 }
 
 impl TeamSnapshot {
+    /// `version_info` for one of this snapshot's sets. A held snapshot is recorded without
+    /// per-type versions, so its config version stands in for all of them, except secrets:
+    /// a held snapshot serves the team's current secrets, whose own version is appended.
+    pub fn version_info(&self, set: &ResourceSet) -> String {
+        if !self.held {
+            set.version.to_string()
+        } else if std::ptr::eq(set, &self.secrets) {
+            format!("{}.{}", self.config_version, set.version)
+        } else {
+            self.config_version.clone()
+        }
+    }
+
+    fn sets(&self) -> [(&'static str, &ResourceSet); 6] {
+        [
+            (CLUSTER_TYPE_URL, &self.clusters),
+            (ENDPOINT_TYPE_URL, &self.endpoints),
+            (ROUTE_TYPE_URL, &self.routes),
+            (SECRET_TYPE_URL, &self.secrets),
+            (LISTENER_TYPE_URL, &self.listeners),
+            (RUNTIME_TYPE_URL, &self.runtime),
+        ]
+    }
+
+    /// Recorded form: `{"<type_url>": ["<base64 Any>", ...]}`. Secrets are left out: they
+    /// are decrypted for serving and must not be stored outside the encrypted secrets table.
+    fn to_record(&self) -> serde_json::Value {
+        let engine = base64::engine::general_purpose::STANDARD;
+        self.sets()
+            .into_iter()
+            .filter(|(type_url, _)| *type_url != SECRET_TYPE_URL)
+            .map(|(type_url, set)| {
+                let resources = set
+                    .resources
+                    .iter()
+                    .map(|any| serde_json::Value::String(engine.encode(any.encode_to_vec())))
+                    .collect();
+                (type_url.to_string(), serde_json::Value::Array(resources))
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+
+    fn from_record(config_version: String, record: &serde_json::Value) -> Option<Self> {
+        let engine = base64::engine::general_purpose::STANDARD;
+        let decode = |type_url: &str| -> Option<ResourceSet> {
+            let resources = record
+                .get(type_url)?
+                .as_array()?
+                .iter()
+                .map(|value| {
+                    let bytes = engine.decode(value.as_str()?).ok()?;
+                    Any::decode(bytes.as_slice()).ok()
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(ResourceSet {
+                version: 0,
+                resources,
+            })
+        };
+        Some(Self {
+            clusters: decode(CLUSTER_TYPE_URL)?,
+            endpoints: decode(ENDPOINT_TYPE_URL)?,
+            routes: decode(ROUTE_TYPE_URL)?,
+            // Filled with the team's current secrets when served; see `team_for`.
+            secrets: ResourceSet::default(),
+            listeners: decode(LISTENER_TYPE_URL)?,
+            // Versions recorded before RTDS was served carry no runtime layer.
+            runtime: if record.get(RUNTIME_TYPE_URL).is_some() {
+                decode(RUNTIME_TYPE_URL)?
+            } else {
+                ResourceSet::default()
+            },
+            config_version,
+            held: true,
+        })
+    }
+
     pub fn for_type_url(&self, type_url: &str) -> Option<&ResourceSet> {
         match type_url {
             CLUSTER_TYPE_URL => Some(&self.clusters),
@@ -56,6 +144,7 @@ impl TeamSnapshot {
             ROUTE_TYPE_URL => Some(&self.routes),
             SECRET_TYPE_URL => Some(&self.secrets),
             LISTENER_TYPE_URL => Some(&self.listeners),
+            RUNTIME_TYPE_URL => Some(&self.runtime),
             _ => None,
         }
     }
@@ -189,16 +278,72 @@ impl TypeInternal {
     }
 }
 
+/// Pins and the rollout holding this team's proxies on recorded config versions.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct HeldConfig {
+    dataplanes: HashMap<DataplaneId, String>,
+    /// `(stable_version, canary_percent)`.
+    rollout: Option<(String, u8)>,
+    snapshots: HashMap<String, TeamSnapshot>,
+}
+
 #[derive(Debug, Clone, Default)]
 struct TeamInternal {
+    /// Generation of the rebuild that last installed this team (the version fence).
+    generation: u64,
     clusters: TypeInternal,
     endpoints: TypeInternal,
     routes: TypeInternal,
     secrets: TypeInternal,
     listeners: TypeInternal,
+    runtime: TypeInternal,
+    /// Digest of the served sets, recomputed whenever they change.
+    config_version: String,
+    /// Last config version this cache recorded for the team.
+    recorded_version: Option<String>,
+    held: HeldConfig,
 }
 
 impl TeamInternal {
+    fn latest(&self) -> TeamSnapshot {
+        TeamSnapshot {
+            clusters: self.clusters.to_set(),
+            endpoints: self.endpoints.to_set(),
+            routes: self.routes.to_set(),
+            secrets: self.secrets.to_set(),
+            listeners: self.listeners.to_set(),
+            runtime: self.runtime.to_set(),
+            config_version: self.config_version.clone(),
+            held: false,
+        }
+    }
+
+    /// First 16 hex digits of a SHA-256 over every served resource, in push order.
+    fn refresh_config_version(&mut self) {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        for (type_url, state) in [
+            (CLUSTER_TYPE_URL, &self.clusters),
+            (ENDPOINT_TYPE_URL, &self.endpoints),
+            (ROUTE_TYPE_URL, &self.routes),
+            (SECRET_TYPE_URL, &self.secrets),
+            (LISTENER_TYPE_URL, &self.listeners),
+            (RUNTIME_TYPE_URL, &self.runtime),
+        ] {
+            hasher.update((type_url.len() as u64).to_be_bytes());
+            hasher.update(type_url.as_bytes());
+            hasher.update((state.served.len() as u64).to_be_bytes());
+            for resource in &state.served {
+                hasher.update((resource.any.value.len() as u64).to_be_bytes());
+                hasher.update(&resource.any.value);
+            }
+        }
+        self.config_version = hasher.finalize()[..fp_domain::rollout::CONFIG_VERSION_LEN / 2]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+    }
+
     fn for_type_mut(&mut self, type_url: &str) -> Option<&mut TypeInternal> {
         match type_url {
             CLUSTER_TYPE_URL => Some(&mut self.clusters),
@@ -206,6 +351,7 @@ impl TeamInternal {
             ROUTE_TYPE_URL => Some(&mut self.routes),
             SECRET_TYPE_URL => Some(&mut self.secrets),
             LISTENER_TYPE_URL => Some(&mut self.listeners),
+            RUNTIME_TYPE_URL => Some(&mut self.runtime),
             _ => None,
         }
     }
@@ -225,19 +371,32 @@ pub struct SnapshotCache {
     /// Bumped on every snapshot change; payload is the team that changed.
     change_tx: watch::Sender<(u64, Option<TeamId>)>,
     change_seq: std::sync::atomic::AtomicU64,
+    /// Handed to each rebuild before it reads the database; a later generation read later.
+    rebuild_seq: std::sync::atomic::AtomicU64,
     /// When set, the built-in `rate_limit_cluster` is injected into every team's CDS (S6). The
     /// endpoint is validated once at boot, so synthesis here is expected to succeed.
     rls: Option<translate::RlsClusterConfig>,
+    /// Set once at shutdown to the grace period live ADS streams get before they close.
+    drain_tx: watch::Sender<Option<std::time::Duration>>,
+    /// ADS streams currently open against this cache; shared with the REST inventory.
+    connected: Arc<fp_domain::connected::ConnectedProxies>,
+    /// Each team's translations from its last build, reused for unchanged resources.
+    translations: Mutex<HashMap<TeamId, TranslationMemo>>,
 }
 
 impl Default for SnapshotCache {
     fn default() -> Self {
         let (change_tx, _) = watch::channel((0, None));
+        let (drain_tx, _) = watch::channel(None);
         Self {
             snapshots: RwLock::new(HashMap::new()),
             change_tx,
             change_seq: std::sync::atomic::AtomicU64::new(0),
+            rebuild_seq: std::sync::atomic::AtomicU64::new(0),
             rls: None,
+            drain_tx,
+            connected: fp_domain::connected::ConnectedProxies::new(),
+            translations: Mutex::default(),
         }
     }
 }
@@ -256,21 +415,64 @@ impl SnapshotCache {
         })
     }
 
+    /// Inventory of the ADS streams served from this cache.
+    pub fn connected_proxies(&self) -> Arc<fp_domain::connected::ConnectedProxies> {
+        Arc::clone(&self.connected)
+    }
+
     pub async fn team(&self, team_id: TeamId) -> TeamSnapshot {
         self.snapshots
             .read()
             .await
             .get(&team_id)
-            .map(|internal| TeamSnapshot {
-                clusters: internal.clusters.to_set(),
-                endpoints: internal.endpoints.to_set(),
-                routes: internal.routes.to_set(),
-                secrets: internal.secrets.to_set(),
-                listeners: internal.listeners.to_set(),
-            })
+            .map(TeamInternal::latest)
             .unwrap_or_default()
     }
 
+    /// The snapshot one proxy is served: the version its dataplane pin or the team rollout
+    /// holds it on, else latest. A held version whose recorded snapshot could not be loaded
+    /// falls back to latest rather than leaving the proxy without config. Secrets are never
+    /// held: a held snapshot carries the team's current SDS set, so a rotated or expired
+    /// secret reaches pinned proxies too.
+    pub async fn team_for(
+        &self,
+        team_id: TeamId,
+        dataplane_id: Option<DataplaneId>,
+        node_id: &str,
+    ) -> TeamSnapshot {
+        let snapshots = self.snapshots.read().await;
+        let Some(internal) = snapshots.get(&team_id) else {
+            return TeamSnapshot::default();
+        };
+        let held = &internal.held;
+        let version = fp_domain::rollout::held_version(
+            dataplane_id
+                .and_then(|id| held.dataplanes.get(&id))
+                .map(String::as_str),
+            held.rollout
+                .as_ref()
+                .map(|(stable, percent)| (stable.as_str(), *percent)),
+            node_id,
+        );
+        match version {
+            Some(version) if version != internal.config_version => {
+                match held.snapshots.get(version) {
+                    Some(snapshot) => TeamSnapshot {
+                        secrets: internal.secrets.to_set(),
+                        ..snapshot.clone()
+                    },
+                    None => {
+                        metrics::counter!("fp_xds_held_version_misses_total").increment(1);
+                        tracing::warn!(team = %team_id, node = node_id, version,
+                            "held config version has no recorded snapshot; serving latest");
+                        internal.latest()
+                    }
+                }
+            }
+            _ => internal.latest(),
+        }
+    }
+
     /// Currently quarantined (degraded) resources for a team, all types.
     pub async fn degraded(&self, team_id: TeamId) -> Vec<DegradedResource> {
         let snapshots = self.snapshots.read().await;
@@ -284,6 +486,7 @@ impl SnapshotCache {
             (ROUTE_TYPE_URL, &internal.routes),
             (SECRET_TYPE_URL, &internal.secrets),
             (LISTENER_TYPE_URL, &internal.listeners),
+            (RUNTIME_TYPE_URL, &internal.runtime),
         ] {
             for (name, q) in &state.quarantine {
                 out.push(DegradedResource {
@@ -310,13 +513,17 @@ impl SnapshotCache {
     pub async fn apply_nack(&self, team_id: TeamId, type_url: &str, error: &str) -> Vec<String> {
         let (named, changed) = {
             let mut snapshots = self.snapshots.write().await;
-            let Some(state) = snapshots
-                .get_mut(&team_id)
-                .and_then(|t| t.for_type_mut(type_url))
-            else {
+            let Some(team) = snapshots.get_mut(&team_id) else {
+                return Vec::new();
+            };
+            let Some(state) = team.for_type_mut(type_url) else {
                 return Vec::new();
             };
-            state.apply_nack(error)
+            let outcome = state.apply_nack(error);
+            if outcome.1 {
+                team.refresh_config_version();
+            }
+            outcome
         };
         if !named.is_empty() {
             metrics::counter!("fp_xds_quarantined_resources_total").increment(named.len() as u64);
@@ -342,6 +549,107 @@ impl SnapshotCache {
         self.change_tx.subscribe()
     }
 
+    /// Start draining ADS streams for shutdown: new streams are refused, and each live stream
+    /// pushes whatever it has not yet sent, keeps serving for `grace`, then closes with
+    /// `UNAVAILABLE` so the dataplane reconnects to another replica with its config intact.
+    pub fn begin_drain(&self, grace: std::time::Duration) {
+        self.drain_tx.send_replace(Some(grace));
+    }
+
+    /// The drain signal: `Some(grace)` once [`SnapshotCache::begin_drain`] has been called.
+    pub fn drain_signal(&self) -> watch::Receiver<Option<std::time::Duration>> {
+        self.drain_tx.subscribe()
+    }
+
+    /// Rebuild every team from the database, bypassing remembered translations, and report
+    /// each resource whose cached translation disagreed with the fresh one. Covers teams that
+    /// own gateway resources and teams that are cached but no longer own any. A team whose
+    /// rebuild fails keeps its snapshot and is listed in the report's failures.
+    pub async fn reconcile_all(&self, pool: &PgPool) -> DomainResult<ReconcileReport> {
+        let mut teams: BTreeSet<TeamId> =
+            fp_storage::repos::gateway::teams_with_gateway_resources(pool)
+                .await?
+                .into_iter()
+                .collect();
+        teams.extend(self.snapshots.read().await.keys().copied());
+        let mut report = ReconcileReport {
+            teams_checked: teams.len(),
+            ..ReconcileReport::default()
+        };
+        for team_id in teams {
+            match self.reconcile_team(pool, team_id).await {
+                Ok(found) => report.discrepancies.extend(found),
+                Err(err) => {
+                    tracing::error!(team = %team_id, error = %err, "xDS reconcile rebuild failed");
+                    report.failures.push((team_id, err.message));
+                }
+            }
+        }
+        if !report.discrepancies.is_empty() {
+            metrics::counter!("fp_xds_reconcile_corrections_total")
+                .increment(report.discrepancies.len() as u64);
+            tracing::warn!(
+                corrected = report.discrepancies.len(),
+                "xDS reconcile corrected cached resources that had drifted from the database"
+            );
+        }
+        Ok(report)
+    }
+
+    /// Rebuild one team without remembered translations and return what changed.
+    pub async fn reconcile_team(
+        &self,
+        pool: &PgPool,
+        team_id: TeamId,
+    ) -> DomainResult<Vec<Discrepancy>> {
+        let before = self.built_resources(team_id).await;
+        self.translations.lock().await.remove(&team_id);
+        self.rebuild_team(pool, team_id).await?;
+        let after = self.built_resources(team_id).await;
+        let mut found = Vec::new();
+        for key in before.keys().chain(after.keys()).collect::<BTreeSet<_>>() {
+            let kind = match (before.get(key), after.get(key)) {
+                (None, Some(_)) => DiscrepancyKind::MissingFromCache,
+                (Some(_), None) => DiscrepancyKind::ExtraInCache,
+                (Some(cached), Some(fresh)) if cached != fresh => DiscrepancyKind::Outdated,
+                _ => continue,
+            };
+            found.push(Discrepancy {
+                team_id,
+                type_url: key.0,
+                name: key.1.clone(),
+                kind,
+            });
+        }
+        Ok(found)
+    }
+
+    /// The team's latest translated resources (pre-quarantine), keyed by type and name.
+    async fn built_resources(&self, team_id: TeamId) -> BTreeMap<(&'static str, String), Vec<u8>> {
+        let snapshots = self.snapshots.read().await;
+        let Some(team) = snapshots.get(&team_id) else {
+            return BTreeMap::new();
+        };
+        [
+            (CLUSTER_TYPE_URL, &team.clusters),
+            (ENDPOINT_TYPE_URL, &team.endpoints),
+            (ROUTE_TYPE_URL, &team.routes),
+            (SECRET_TYPE_URL, &team.secrets),
+            (LISTENER_TYPE_URL, &team.listeners),
+            (RUNTIME_TYPE_URL, &team.runtime),
+        ]
+        .into_iter()
+        .flat_map(|(type_url, state)| {
+            state.raw.iter().map(move |resource| {
+                (
+                    (type_url, resource.name.clone()),
+                    resource.any.value.clone(),
+                )
+            })
+        })
+        .collect()
+    }
+
     /// Prime the cache from the database at startup: rebuild every team that owns gateway
     /// resources. Without this, a restarted control plane serves EMPTY snapshots to
     /// reconnecting dataplanes (the outbox cursor is durable, so old events never replay)
@@ -360,9 +668,108 @@ impl SnapshotCache {
 
     /// Rebuild one team's snapshot from the database. Loads, translates, and swaps in the
     /// new sets, bumping each type's version only when its bytes changed.
+    ///
+    /// Rebuilds may interleave (outbox consumer, startup prime, manual repush). Each takes a
+    /// generation before it loads, and a rebuild whose generation is older than the one
+    /// already installed for the team is discarded, so a slow rebuild that read the
+    /// database earlier never overwrites a newer snapshot.
     pub async fn rebuild_team(&self, pool: &PgPool, team_id: TeamId) -> DomainResult<()> {
-        // Load everything the team owns. The 500-row repo cap is the current ceiling per
-        // type; quotas (50/25/100) keep real teams far below it.
+        let generation = self.begin_rebuild();
+        let built = self.build_team(pool, team_id).await?;
+        if self.install_team(team_id, generation, built).await {
+            self.record_config_version(pool, team_id).await;
+        }
+        Ok(())
+    }
+
+    /// Record the team's latest served config so it can be pinned. Best effort: a failure
+    /// leaves that version unpinnable but never blocks serving it.
+    async fn record_config_version(&self, pool: &PgPool, team_id: TeamId) {
+        let (version, record) = {
+            let snapshots = self.snapshots.read().await;
+            let Some(internal) = snapshots.get(&team_id) else {
+                return;
+            };
+            if internal.recorded_version.as_ref() == Some(&internal.config_version) {
+                return;
+            }
+            (
+                internal.config_version.clone(),
+                internal.latest().to_record(),
+            )
+        };
+        match fp_storage::repos::config_rollout::record_version(pool, team_id, &version, &record)
+            .await
+        {
+            Ok(()) => {
+                if let Some(internal) = self.snapshots.write().await.get_mut(&team_id) {
+                    internal.recorded_version = Some(version);
+                }
+            }
+            Err(err) => {
+                metrics::counter!("fp_xds_config_version_record_failures_total").increment(1);
+                tracing::error!(team = %team_id, version, error = %err,
+                    "failed to record xDS config version");
+            }
+        }
+    }
+
+    fn begin_rebuild(&self) -> u64 {
+        self.rebuild_seq
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+            + 1
+    }
+
+    /// Swap in a built team unless a newer rebuild already installed one. Returns whether
+    /// the build was installed (not whether the served bytes changed).
+    async fn install_team(&self, team_id: TeamId, generation: u64, built: BuiltTeam) -> bool {
+        let mut changed = false;
+        {
+            let mut snapshots = self.snapshots.write().await;
+            let entry = snapshots.entry(team_id).or_default();
+            if generation < entry.generation {
+                metrics::counter!("fp_xds_stale_rebuilds_discarded_total").increment(1);
+                tracing::debug!(team = %team_id, generation, installed = entry.generation,
+                    "discarding stale xDS rebuild");
+                return false;
+            }
+            entry.generation = generation;
+            changed |= entry
+                .clusters
+                .install_raw_with_failures(built.clusters, built.cluster_failures);
+            changed |= entry.endpoints.install_raw(built.endpoints);
+            changed |= entry
+                .routes
+                .install_raw_with_failures(built.routes, built.route_failures);
+            changed |= entry
+                .listeners
+                .install_raw_with_failures(built.listeners, built.listener_failures);
+            changed |= entry
+                .secrets
+                .install_raw_with_failures(built.secrets, built.secret_failures);
+            changed |= entry.runtime.install_raw(built.runtime);
+            if changed || entry.config_version.is_empty() {
+                entry.refresh_config_version();
+            }
+            if entry.held != built.held {
+                entry.held = built.held;
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.notify(team_id);
+            metrics::counter!("fp_xds_snapshot_rebuilds_total").increment(1);
+            tracing::info!(team = %team_id, "xDS snapshot rebuilt");
+        }
+        true
+    }
+
+    /// Load and translate everything one team owns, without touching the cache. Every call
+    /// reads all of the team's rows; only translation reuses the previous build's output.
+    async fn build_team(&self, pool: &PgPool, team_id: TeamId) -> DomainResult<BuiltTeam> {
+        // Load everything the team owns, up to MAX_XDS_RESOURCES_PER_TYPE per type; quota
+        // overrides for those kinds cannot exceed it, so no created row is left unserved.
         let XdsResources {
             clusters,
             route_configs,
@@ -373,43 +780,85 @@ impl SnapshotCache {
         } = load_xds_resources(pool, team_id).await?;
         let secrets = fp_storage::repos::secrets::list_encrypted_secrets(pool, team_id).await?;
         let capture_plan = learning_capture_plan(pool, team_id, &route_configs).await?;
+        let artifacts = filter_artifacts(pool, team_id, &listeners).await?;
+        let retry_policies: translate::NamedRetryPolicies =
+            fp_storage::repos::retry_policies::list_for_team(pool, team_id)
+                .await?
+                .into_iter()
+                .map(|policy| (policy.name, policy.spec))
+                .collect();
+
+        // Only resources whose inputs changed since the last build are translated again.
+        let mut previous = self
+            .translations
+            .lock()
+            .await
+            .remove(&team_id)
+            .unwrap_or_default();
+        if previous.retry_policies != retry_policies {
+            previous.routes.clear();
+        }
+        if previous.artifacts != artifacts {
+            previous.listeners.clear();
+        }
+        let mut memo = TranslationMemo::default();
+        let (mut reused, mut translated) = (0_u64, 0_u64);
 
         let mut cluster_named = Vec::with_capacity(clusters.len());
         let mut endpoint_named = Vec::new();
         for xds_cluster in &clusters {
             let cluster = &xds_cluster.cluster;
-            let proto = match translate::cluster_to_proto_with_ai(
-                &cluster.name,
-                &cluster.spec,
-                xds_cluster.ai.as_ref(),
-            ) {
-                Ok(proto) => proto,
-                Err(err) => {
-                    let error = format!("cluster translation failed: {err}");
-                    skip_xds_resource(team_id, "cluster", &cluster.name, &error);
-                    cluster_failures.insert(cluster.name.clone(), error);
-                    continue;
+            let input = (cluster.spec.clone(), xds_cluster.ai.clone());
+            let output = match previous
+                .clusters
+                .remove(&cluster.name)
+                .filter(|entry| entry.input == input)
+            {
+                Some(entry) => {
+                    reused += 1;
+                    entry.output
+                }
+                None => {
+                    let proto = match translate::cluster_to_proto_with_ai(
+                        &cluster.name,
+                        &cluster.spec,
+                        xds_cluster.ai.as_ref(),
+                    ) {
+                        Ok(proto) => proto,
+                        Err(err) => {
+                            let error = format!("cluster translation failed: {err}");
+                            skip_xds_resource(team_id, "cluster", &cluster.name, &error);
+                            cluster_failures.insert(cluster.name.clone(), error);
+                            continue;
+                        }
+                    };
+                    translated += 1;
+                    // EDS clusters get their assignment as a separate resource: endpoint churn
+                    // bumps only the endpoints version, never the cluster bytes (spec/10 §5).
+                    let endpoints = translate::cluster_uses_eds(&cluster.spec).then(|| {
+                        let cla = translate::endpoints_to_proto(&cluster.name, &cluster.spec);
+                        NamedResource {
+                            name: cluster.name.clone(),
+                            any: Any {
+                                type_url: ENDPOINT_TYPE_URL.to_string(),
+                                value: cla.encode_to_vec(),
+                            },
+                        }
+                    });
+                    let cluster = NamedResource {
+                        name: cluster.name.clone(),
+                        any: Any {
+                            type_url: CLUSTER_TYPE_URL.to_string(),
+                            value: proto.encode_to_vec(),
+                        },
+                    };
+                    (cluster, endpoints)
                 }
             };
-            cluster_named.push(NamedResource {
-                name: cluster.name.clone(),
-                any: Any {
-                    type_url: CLUSTER_TYPE_URL.to_string(),
-                    value: proto.encode_to_vec(),
-                },
-            });
-            // EDS clusters get their assignment as a separate resource: endpoint churn
-            // bumps only the endpoints version, never the cluster bytes (spec/10 §5).
-            if translate::cluster_uses_eds(&cluster.spec) {
-                let cla = translate::endpoints_to_proto(&cluster.name, &cluster.spec);
-                endpoint_named.push(NamedResource {
-                    name: cluster.name.clone(),
-                    any: Any {
-                        type_url: ENDPOINT_TYPE_URL.to_string(),
-                        value: cla.encode_to_vec(),
-                    },
-                });
-            }
+            cluster_named.push(output.0.clone());
+            endpoint_named.extend(output.1.clone());
+            memo.clusters
+                .insert(cluster.name.clone(), MemoEntry { input, output });
         }
 
         // S6: inject the CP-synthesized built-in rate-limit cluster into every team's CDS when an
@@ -432,9 +881,41 @@ impl SnapshotCache {
                 }
             }
         }
+        // Failover routes target a CP-synthesized aggregate cluster per (primary, fallback)
+        // pair; the name cannot collide with a team cluster (`--` is not a valid name).
+        let fallback_pairs: BTreeSet<(&str, &str)> = route_configs
+            .iter()
+            .flat_map(|rc| rc.spec.virtual_hosts.iter())
+            .flat_map(|vhost| vhost.routes.iter())
+            .filter_map(|rule| rule.action.fallback_pair())
+            .collect();
+        for (primary, fallback) in fallback_pairs {
+            let proto = translate::fallback_cluster_to_proto(primary, fallback);
+            cluster_named.push(NamedResource {
+                name: proto.name.clone(),
+                any: Any {
+                    type_url: CLUSTER_TYPE_URL.to_string(),
+                    value: proto.encode_to_vec(),
+                },
+            });
+        }
         let mut route_named = Vec::with_capacity(route_configs.len());
         for rc in &route_configs {
-            let proto = match translate::route_config_to_proto(&rc.name, &rc.spec) {
+            if let Some(entry) = previous
+                .routes
+                .remove(&rc.name)
+                .filter(|entry| entry.input == rc.spec)
+            {
+                reused += 1;
+                route_named.push(entry.output.clone());
+                memo.routes.insert(rc.name.clone(), entry);
+                continue;
+            }
+            let proto = match translate::route_config_to_proto_with_retry_policies(
+                &rc.name,
+                &rc.spec,
+                &retry_policies,
+            ) {
                 Ok(proto) => proto,
                 Err(err) => {
                     let error = format!("route-config translation failed: {err}");
@@ -452,13 +933,22 @@ impl SnapshotCache {
                     continue;
                 }
             };
-            route_named.push(NamedResource {
+            translated += 1;
+            let output = NamedResource {
                 name: rc.name.clone(),
                 any: Any {
                     type_url: ROUTE_TYPE_URL.to_string(),
                     value,
                 },
-            });
+            };
+            route_named.push(output.clone());
+            memo.routes.insert(
+                rc.name.clone(),
+                MemoEntry {
+                    input: rc.spec.clone(),
+                    output,
+                },
+            );
         }
         let mut secret_named = Vec::with_capacity(secrets.len());
         let mut secret_failures = HashMap::new();
@@ -501,56 +991,97 @@ impl SnapshotCache {
         let mut listener_named = Vec::with_capacity(listeners.len());
         for xds_listener in &listeners {
             let listener = &xds_listener.listener;
-            // Listeners without a bound route config cannot serve; they stay out of the
-            // snapshot rather than producing a NACK-able resource.
-            if listener.spec.route_config.is_none() {
-                tracing::debug!(team = %team_id, listener = %listener.name,
-                    "skipping unbound listener in snapshot");
-                continue;
-            }
-            if listener
-                .spec
-                .route_config
-                .as_ref()
-                .is_some_and(|name| !route_configs.iter().any(|rc| rc.name == *name))
+            // Maintenance points the listener at a catch-all direct response instead of its
+            // route config, which is not consulted (a missing or unbound one included).
+            let maintenance_spec;
+            let (spec, captures, ai_metadata) = if let Some(maintenance) = &listener.maintenance {
+                let route_name = translate::maintenance_route_config_name(&listener.name);
+                let proto = translate::maintenance_route_config_to_proto(&route_name, maintenance);
+                match translate::encode_route_config_deterministic(&proto) {
+                    Ok(value) => route_named.push(NamedResource {
+                        name: route_name.clone(),
+                        any: Any {
+                            type_url: ROUTE_TYPE_URL.to_string(),
+                            value,
+                        },
+                    }),
+                    Err(err) => {
+                        let error = format!("maintenance route translation failed: {err}");
+                        skip_xds_resource(team_id, "listener", &listener.name, &error);
+                        listener_failures.insert(listener.name.clone(), error);
+                        continue;
+                    }
+                }
+                maintenance_spec = ListenerSpec {
+                    route_config: Some(route_name),
+                    ..listener.spec.clone()
+                };
+                (&maintenance_spec, Vec::new(), None)
+            } else {
+                // Listeners without a bound route config cannot serve; they stay out of the
+                // snapshot rather than producing a NACK-able resource.
+                if listener.spec.route_config.is_none() {
+                    tracing::debug!(team = %team_id, listener = %listener.name,
+                        "skipping unbound listener in snapshot");
+                    continue;
+                }
+                if listener
+                    .spec
+                    .route_config
+                    .as_ref()
+                    .is_some_and(|name| !route_configs.iter().any(|rc| rc.name == *name))
+                {
+                    let error = "listener references an unavailable route config".to_string();
+                    skip_xds_resource(team_id, "listener", &listener.name, &error);
+                    listener_failures.insert(listener.name.clone(), error);
+                    continue;
+                }
+                let route_config_id = listener
+                    .spec
+                    .route_config
+                    .as_ref()
+                    .and_then(|name| route_configs.iter().find(|rc| rc.name == *name))
+                    .map(|rc| rc.id);
+                let captures = route_config_id
+                    .map(|id| {
+                        capture_plan
+                            .iter()
+                            .filter(|capture| {
+                                capture.route_config_id == id.as_uuid()
+                                    && capture
+                                        .listener_id
+                                        .is_none_or(|scope| scope == listener.id.as_uuid())
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                let ai_metadata = route_config_id
+                    .filter(|_| xds_listener.owner_kind == "ai")
+                    .map(|route_config_id| translate::AiProcessorMetadata {
+                        team_id: team_id.as_uuid(),
+                        listener_id: listener.id.as_uuid(),
+                        route_config_id: route_config_id.as_uuid(),
+                    });
+                (&listener.spec, captures, ai_metadata)
+            };
+            let input = (spec.clone(), captures, ai_metadata);
+            if let Some(entry) = previous
+                .listeners
+                .remove(&listener.name)
+                .filter(|entry| entry.input == input)
             {
-                let error = "listener references an unavailable route config".to_string();
-                skip_xds_resource(team_id, "listener", &listener.name, &error);
-                listener_failures.insert(listener.name.clone(), error);
+                reused += 1;
+                listener_named.push(entry.output.clone());
+                memo.listeners.insert(listener.name.clone(), entry);
                 continue;
             }
-            let route_config_id = listener
-                .spec
-                .route_config
-                .as_ref()
-                .and_then(|name| route_configs.iter().find(|rc| rc.name == *name))
-                .map(|rc| rc.id);
-            let captures = route_config_id
-                .map(|id| {
-                    capture_plan
-                        .iter()
-                        .filter(|capture| {
-                            capture.route_config_id == id.as_uuid()
-                                && capture
-                                    .listener_id
-                                    .is_none_or(|scope| scope == listener.id.as_uuid())
-                        })
-                        .cloned()
-                        .collect::<Vec<_>>()
-                })
-                .unwrap_or_default();
-            let ai_metadata = route_config_id
-                .filter(|_| xds_listener.owner_kind == "ai")
-                .map(|route_config_id| translate::AiProcessorMetadata {
-                    team_id: team_id.as_uuid(),
-                    listener_id: listener.id.as_uuid(),
-                    route_config_id: route_config_id.as_uuid(),
-                });
-            let proto = match translate::listener_to_proto_with_learning_and_ai(
+            let proto = match translate::listener_to_proto_with_artifacts(
                 &listener.name,
-                &listener.spec,
-                &captures,
-                ai_metadata.as_ref(),
+                spec,
+                &input.1,
+                input.2.as_ref(),
+                &artifacts,
             ) {
                 Ok(proto) => proto,
                 Err(err) => {
@@ -560,47 +1091,152 @@ impl SnapshotCache {
                     continue;
                 }
             };
-            listener_named.push(NamedResource {
+            translated += 1;
+            let output = NamedResource {
                 name: listener.name.clone(),
                 any: Any {
                     type_url: LISTENER_TYPE_URL.to_string(),
                     value: proto.encode_to_vec(),
                 },
-            });
+            };
+            listener_named.push(output.clone());
+            memo.listeners
+                .insert(listener.name.clone(), MemoEntry { input, output });
         }
+        metrics::counter!("fp_xds_resource_translations_total", "result" => "reused")
+            .increment(reused);
+        metrics::counter!("fp_xds_resource_translations_total", "result" => "translated")
+            .increment(translated);
+        memo.retry_policies = retry_policies;
+        memo.artifacts = artifacts;
+        self.translations.lock().await.insert(team_id, memo);
+
+        let runtime_values: BTreeMap<String, serde_json::Value> =
+            fp_storage::repos::runtime::list_for_team(pool, team_id)
+                .await?
+                .into_iter()
+                .map(|value| (value.key, value.value))
+                .collect();
+        let runtime_named = vec![NamedResource {
+            name: RUNTIME_LAYER_NAME.to_string(),
+            any: Any {
+                type_url: RUNTIME_TYPE_URL.to_string(),
+                value: translate::runtime_layer_to_proto(RUNTIME_LAYER_NAME, &runtime_values)?,
+            },
+        }];
+
+        let holds = fp_storage::repos::config_rollout::load_holds(pool, team_id).await?;
+        let held = HeldConfig {
+            dataplanes: holds.dataplanes.into_iter().collect(),
+            rollout: holds.rollout,
+            snapshots: holds
+                .snapshots
+                .into_iter()
+                .filter_map(|(version, record)| {
+                    let snapshot = TeamSnapshot::from_record(version.clone(), &record);
+                    if snapshot.is_none() {
+                        tracing::error!(team = %team_id, version,
+                            "recorded xDS config version does not decode; it cannot be held");
+                    }
+                    snapshot.map(|snapshot| (version, snapshot))
+                })
+                .collect(),
+        };
 
-        let mut changed = false;
-        {
-            let mut snapshots = self.snapshots.write().await;
-            let entry = snapshots.entry(team_id).or_default();
-            changed |= entry
-                .clusters
-                .install_raw_with_failures(cluster_named, cluster_failures);
-            changed |= entry.endpoints.install_raw(endpoint_named);
-            changed |= entry
-                .routes
-                .install_raw_with_failures(route_named, route_failures);
-            changed |= entry
-                .listeners
-                .install_raw_with_failures(listener_named, listener_failures);
-            changed |= entry
-                .secrets
-                .install_raw_with_failures(secret_named, secret_failures);
-        }
+        Ok(BuiltTeam {
+            clusters: cluster_named,
+            cluster_failures,
+            endpoints: endpoint_named,
+            routes: route_named,
+            route_failures,
+            listeners: listener_named,
+            listener_failures,
+            secrets: secret_named,
+            secret_failures,
+            runtime: runtime_named,
+            held,
+        })
+    }
+}
 
-        if changed {
-            let seq = self
-                .change_seq
-                .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
-                + 1;
-            let _ = self.change_tx.send((seq, Some(team_id)));
-            metrics::counter!("fp_xds_snapshot_rebuilds_total").increment(1);
-            tracing::info!(team = %team_id, "xDS snapshot rebuilt");
+/// Outcome of [`SnapshotCache::reconcile_all`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReconcileReport {
+    pub teams_checked: usize,
+    /// Cached resources the reconcile replaced, added, or removed.
+    pub discrepancies: Vec<Discrepancy>,
+    /// Teams whose rebuild failed, with the error; their snapshots were left as they were.
+    pub failures: Vec<(TeamId, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Discrepancy {
+    pub team_id: TeamId,
+    pub type_url: &'static str,
+    pub name: String,
+    pub kind: DiscrepancyKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiscrepancyKind {
+    /// In the database but absent from the cache.
+    MissingFromCache,
+    /// Cached, but gone from the database.
+    ExtraInCache,
+    /// Cached bytes differed from a fresh translation of the database row.
+    Outdated,
+}
+
+impl DiscrepancyKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MissingFromCache => "missing_from_cache",
+            Self::ExtraInCache => "extra_in_cache",
+            Self::Outdated => "outdated",
         }
-        Ok(())
     }
 }
 
+/// One team's freshly translated resources and per-type translation failures.
+#[derive(Debug, Default)]
+struct BuiltTeam {
+    clusters: Vec<NamedResource>,
+    cluster_failures: HashMap<String, String>,
+    endpoints: Vec<NamedResource>,
+    routes: Vec<NamedResource>,
+    route_failures: HashMap<String, String>,
+    listeners: Vec<NamedResource>,
+    listener_failures: HashMap<String, String>,
+    secrets: Vec<NamedResource>,
+    secret_failures: HashMap<String, String>,
+    runtime: Vec<NamedResource>,
+    held: HeldConfig,
+}
+
+/// One team's translated resources, each kept with the inputs that produced it. Team-wide
+/// inputs (named retry policies for routes, uploaded filter artifacts for listeners) are kept once; when
+/// they change, every entry of the dependent type is translated again.
+#[derive(Default)]
+struct TranslationMemo {
+    retry_policies: translate::NamedRetryPolicies,
+    artifacts: translate::FilterArtifacts,
+    clusters: HashMap<String, MemoEntry<ClusterInput, (NamedResource, Option<NamedResource>)>>,
+    routes: HashMap<String, MemoEntry<RouteConfigSpec, NamedResource>>,
+    listeners: HashMap<String, MemoEntry<ListenerInput, NamedResource>>,
+}
+
+type ClusterInput = (ClusterSpec, Option<translate::AiUpstreamProcessorMetadata>);
+type ListenerInput = (
+    ListenerSpec,
+    Vec<translate::LearningCaptureInjection>,
+    Option<translate::AiProcessorMetadata>,
+);
+
+struct MemoEntry<I, O> {
+    input: I,
+    output: O,
+}
+
 struct XdsResources {
     clusters: Vec<XdsCluster>,
     route_configs: Vec<RouteConfig>,
@@ -620,28 +1256,75 @@ struct XdsListener {
     owner_kind: String,
 }
 
+/// Every descriptor set the team uploaded, plus only the registry wasm modules its listeners
+/// reference: modules run to megabytes and a team may keep many versions around.
+async fn filter_artifacts(
+    pool: &PgPool,
+    team_id: TeamId,
+    listeners: &[XdsListener],
+) -> DomainResult<translate::FilterArtifacts> {
+    use fp_domain::gateway::filters::{HttpFilterSpec, WasmModuleSource};
+    let filters = || {
+        listeners
+            .iter()
+            .flat_map(|xds_listener| &xds_listener.listener.spec.http_filters)
+    };
+    let descriptor_ids: Vec<_> = filters()
+        .filter_map(|entry| match &entry.filter {
+            HttpFilterSpec::GrpcJsonTranscoder(c) => Some(c.proto_descriptor_id),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let descriptor_sets =
+        fp_storage::repos::proto_descriptors::load_descriptor_sets(pool, team_id, &descriptor_ids)
+            .await?
+            .into_iter()
+            .collect();
+    let wasm_refs: Vec<(String, i64)> = filters()
+        .filter_map(|entry| match &entry.filter {
+            HttpFilterSpec::Wasm(c) => match &c.module {
+                WasmModuleSource::Registry { name, version } => Some((name.clone(), *version)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let wasm_modules = fp_storage::repos::wasm_modules::load_modules(pool, team_id, &wasm_refs)
+        .await?
+        .into_iter()
+        .collect();
+    Ok(translate::FilterArtifacts {
+        descriptor_sets,
+        wasm_modules,
+    })
+}
+
 async fn load_xds_resources(pool: &PgPool, team_id: TeamId) -> DomainResult<XdsResources> {
     let ai_clusters = ai_cluster_metadata(pool, team_id).await?;
-    let cluster_rows = sqlx::query(
+    let cluster_rows = sqlx::query(&format!(
         "SELECT id, team_id, name, spec, version, created_at, updated_at, owner_kind \
-         FROM clusters WHERE team_id = $1 ORDER BY name LIMIT 500",
-    )
+         FROM clusters WHERE team_id = $1 ORDER BY name LIMIT {MAX_XDS_RESOURCES_PER_TYPE}",
+    ))
     .bind(team_id.as_uuid())
     .fetch_all(pool)
     .await
     .map_err(|err| DomainError::internal(format!("list xDS clusters: {err}")))?;
-    let route_rows = sqlx::query(
+    let route_rows = sqlx::query(&format!(
         "SELECT id, team_id, name, spec, version, created_at, updated_at, owner_kind \
-         FROM route_configs WHERE team_id = $1 ORDER BY name LIMIT 500",
-    )
+         FROM route_configs WHERE team_id = $1 ORDER BY name LIMIT {MAX_XDS_RESOURCES_PER_TYPE}",
+    ))
     .bind(team_id.as_uuid())
     .fetch_all(pool)
     .await
     .map_err(|err| DomainError::internal(format!("list xDS route configs: {err}")))?;
-    let listener_rows = sqlx::query(
-        "SELECT id, team_id, name, spec, version, created_at, updated_at, owner_kind \
-         FROM listeners WHERE team_id = $1 ORDER BY name LIMIT 500",
-    )
+    let listener_rows = sqlx::query(&format!(
+        "SELECT id, team_id, name, spec, maintenance, version, created_at, updated_at, owner_kind \
+         FROM listeners WHERE team_id = $1 ORDER BY name LIMIT {MAX_XDS_RESOURCES_PER_TYPE}",
+    ))
     .bind(team_id.as_uuid())
     .fetch_all(pool)
     .await
@@ -896,11 +1579,17 @@ fn route_config_from_xds_row(row: &sqlx::postgres::PgRow) -> Result<RouteConfig,
 fn listener_from_xds_row(row: &sqlx::postgres::PgRow) -> Result<Listener, String> {
     let spec = serde_json::from_value::<ListenerSpec>(row.get::<serde_json::Value, _>("spec"))
         .map_err(|err| format!("listener spec in DB does not parse: {err}"))?;
+    let maintenance = row
+        .get::<Option<serde_json::Value>, _>("maintenance")
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|err| format!("listener maintenance in DB does not parse: {err}"))?;
     Ok(Listener {
         id: ListenerId::from(row.get::<uuid::Uuid, _>("id")),
         team_id: TeamId::from(row.get::<uuid::Uuid, _>("team_id")),
         name: row.get("name"),
         spec,
+        maintenance,
         version: row.get("version"),
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
@@ -1058,8 +1747,10 @@ struct SecretKey {
 fn active_secret_key() -> DomainResult<SecretKey> {
     let id = active_key_id()?;
     let raw = std::env::var(ACTIVE_KEY_ENV).map_err(|_| {
-        DomainError::unavailable("secret encryption key is not configured")
-            .with_hint("set FLOWPLANE_SECRET_ENCRYPTION_KEY to a 32-byte or base64-encoded key")
+        DomainError::not_configured(
+            "secret encryption",
+            "FLOWPLANE_SECRET_ENCRYPTION_KEY to a 32-byte or base64-encoded key",
+        )
     })?;
     Ok(SecretKey {
         id,
@@ -1137,22 +1828,142 @@ pub async fn handle_events(
     teams.sort();
     teams.dedup();
     for team_id in teams {
-        cache.rebuild_team(pool, team_id).await?;
+        // One span per rebuild, joined to the trace of every write that triggered it, so a
+        // slow snapshot build is attributable to the API call(s) behind it.
+        let span = tracing::info_span!(
+            "xds_rebuild",
+            team_id = %team_id,
+            events = tracing::field::Empty
+        );
+        let triggering: Vec<&serde_json::Value> = events
+            .iter()
+            .filter(|e| e.scope.team_id == Some(team_id))
+            .map(|e| &e.trace_context)
+            .collect();
+        span.record("events", triggering.len());
+        link_trace_contexts(&span, triggering);
+        cache.rebuild_team(pool, team_id).instrument(span).await?;
     }
     Ok(())
 }
 
-pub const XDS_CONSUMER: &str = "xds-snapshot";
+/// Attach the W3C contexts stored on outbox rows (fp-core `trace_context_json`) to `span`. The
+/// first valid context becomes the parent, so a rebuild triggered by one write nests under that
+/// API call; every further one becomes a span link, so a batch coalescing several writes stays
+/// attributable to each. Rows without a context (system events, tracing off) are skipped.
+fn link_trace_contexts<'a>(
+    span: &tracing::Span,
+    contexts: impl IntoIterator<Item = &'a serde_json::Value>,
+) {
+    use opentelemetry::propagation::Extractor;
+    use opentelemetry::trace::TraceContextExt;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    struct MapExtractor<'a>(&'a serde_json::Map<String, serde_json::Value>);
+    impl Extractor for MapExtractor<'_> {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).and_then(serde_json::Value::as_str)
+        }
 
-#[cfg(test)]
-#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
-mod tests {
-    use super::*;
-    use envoy_types::pb::envoy::config::common::mutation_rules::v3 as mutation_rules;
-    use envoy_types::pb::envoy::config::core::v3 as envoy_core;
-    use envoy_types::pb::envoy::config::listener::v3 as lst;
-    use envoy_types::pb::envoy::extensions::filters::http::ext_proc::v3 as ext_proc;
-    use envoy_types::pb::envoy::extensions::filters::http::router::v3::Router;
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(String::as_str).collect()
+        }
+    }
+
+    let mut parented = false;
+    for value in contexts {
+        let Some(map) = value.as_object() else {
+            continue;
+        };
+        let context = opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.extract(&MapExtractor(map))
+        });
+        let span_context = context.span().span_context().clone();
+        if !span_context.is_valid() {
+            continue;
+        }
+        // Err means the OTel layer is absent or the span already started; links still apply.
+        if !parented && span.set_parent(context).is_ok() {
+            parented = true;
+        } else {
+            span.add_link(span_context);
+        }
+    }
+}
+
+pub const XDS_CONSUMER: &str = "xds-snapshot";
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod trace_link_tests {
+    use super::link_trace_contexts;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn traceparent(trace_id: &str, span_id: &str) -> serde_json::Value {
+        serde_json::json!({ "traceparent": format!("00-{trace_id}-{span_id}-01") })
+    }
+
+    #[test]
+    fn first_valid_context_parents_the_rebuild_and_the_rest_are_links() {
+        opentelemetry::global::set_text_map_propagator(
+            opentelemetry_sdk::propagation::TraceContextPropagator::new(),
+        );
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let first = "0af7651916cd43dd8448eb211c80319c";
+        let contexts = [
+            serde_json::Value::Null,
+            serde_json::json!({}),
+            serde_json::json!({ "traceparent": "not-a-traceparent" }),
+            serde_json::json!("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"),
+            traceparent(first, "b7ad6b7169203331"),
+            traceparent("4bf92f3577b34da6a3ce929d0e0e4736", "00f067aa0ba902b7"),
+            traceparent("5bf92f3577b34da6a3ce929d0e0e4737", "10f067aa0ba902b8"),
+        ];
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("xds.rebuild");
+            link_trace_contexts(&span, &contexts);
+            drop(span);
+        });
+        provider.force_flush().expect("flush");
+
+        let spans = exporter.get_finished_spans().expect("spans");
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        assert_eq!(span.span_context.trace_id().to_string(), first);
+        assert_eq!(span.parent_span_id.to_string(), "b7ad6b7169203331");
+        let linked: Vec<String> = span
+            .links
+            .iter()
+            .map(|link| link.span_context.trace_id().to_string())
+            .collect();
+        assert_eq!(
+            linked,
+            [
+                "4bf92f3577b34da6a3ce929d0e0e4736",
+                "5bf92f3577b34da6a3ce929d0e0e4737"
+            ],
+            "every further valid context is a link; empty or malformed ones are skipped"
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use envoy_types::pb::envoy::config::common::mutation_rules::v3 as mutation_rules;
+    use envoy_types::pb::envoy::config::core::v3 as envoy_core;
+    use envoy_types::pb::envoy::config::listener::v3 as lst;
+    use envoy_types::pb::envoy::extensions::filters::http::ext_proc::v3 as ext_proc;
+    use envoy_types::pb::envoy::extensions::filters::http::router::v3::Router;
     use envoy_types::pb::envoy::extensions::filters::network::http_connection_manager::v3 as hcm;
     use envoy_types::pb::envoy::r#type::matcher::v3 as matcher_type;
     use envoy_types::pb::google::protobuf as wkt;
@@ -1192,9 +2003,11 @@ mod tests {
             use_tls: false,
             upstream_tls: None,
             protocol: None,
+            use_downstream_protocol: false,
             health_checks: None,
             circuit_breakers: None,
             outlier_detection: None,
+            connection_pool: None,
         }
     }
 
@@ -1238,6 +2051,48 @@ mod tests {
         })
     }
 
+    #[tokio::test]
+    async fn stale_rebuild_never_overwrites_a_newer_snapshot() {
+        let cluster = |name: &str| NamedResource {
+            name: name.into(),
+            any: Any {
+                type_url: CLUSTER_TYPE_URL.to_string(),
+                value: name.as_bytes().to_vec(),
+            },
+        };
+        let cache = SnapshotCache::new();
+        let team_id = TeamId::generate();
+
+        // The older rebuild read the database first but finishes last.
+        let older = cache.begin_rebuild();
+        let newer = cache.begin_rebuild();
+        let newer_build = BuiltTeam {
+            clusters: vec![cluster("a"), cluster("b")],
+            ..BuiltTeam::default()
+        };
+        assert!(cache.install_team(team_id, newer, newer_build).await);
+        let published = cache.team(team_id).await.clusters;
+        assert_eq!(published.resources.len(), 2);
+
+        let older_build = BuiltTeam {
+            clusters: vec![cluster("a")],
+            ..BuiltTeam::default()
+        };
+        assert!(!cache.install_team(team_id, older, older_build).await);
+        let after = cache.team(team_id).await.clusters;
+        assert_eq!(after.version, published.version);
+        assert_eq!(after.resources, published.resources);
+
+        // A rebuild that starts after the published one still installs.
+        let next = cache.begin_rebuild();
+        let next_build = BuiltTeam {
+            clusters: vec![cluster("a")],
+            ..BuiltTeam::default()
+        };
+        assert!(cache.install_team(team_id, next, next_build).await);
+        assert_eq!(cache.team(team_id).await.clusters.resources.len(), 1);
+    }
+
     fn rls_cfg() -> translate::RlsClusterConfig {
         translate::RlsClusterConfig {
             grpc_url: "rls.internal:8081".into(),
@@ -1324,7 +2179,9 @@ mod tests {
                     name: "all".into(),
                     matcher: PathMatch::Prefix { prefix: "/".into() },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: RouteAction {
                         cluster: Some(cluster.into()),
                         weighted_clusters: None,
@@ -1334,6 +2191,16 @@ mod tests {
                         template_rewrite: None,
                         timeout_secs: 15,
                         retry_policy: None,
+                        retry_policy_ref: None,
+                        idle_timeout_secs: None,
+                        max_stream_duration_secs: None,
+                        regex_rewrite: None,
+                        host_rewrite_literal: None,
+                        auto_host_rewrite: false,
+                        fallback_cluster: None,
+                        request_mirror_policies: Vec::new(),
+                        request_headers_to_remove: Vec::new(),
+                        hash_policy: Vec::new(),
                         rate_limits: Vec::new(),
                     },
                     filter_overrides: Vec::new(),
@@ -1384,6 +2251,377 @@ mod tests {
         Some((pool, team_a, team_b, ctx_a, ctx_b))
     }
 
+    #[tokio::test]
+    async fn rebuild_translates_only_resources_whose_inputs_changed() {
+        let Some((pool, team, _, ctx, _)) = world().await else {
+            return;
+        };
+        let cache = SnapshotCache::new();
+        let (changed, untouched) = (unique("changed"), unique("untouched"));
+        for name in [&changed, &untouched] {
+            fp_core::services::clusters::create_cluster(
+                &pool,
+                &ctx,
+                team,
+                name,
+                cluster_spec("10.0.0.1"),
+                RequestId::generate(),
+                Default::default(),
+            )
+            .await
+            .expect("cluster");
+        }
+        cache.rebuild_team(&pool, team.id).await.expect("build");
+
+        // Swap the untouched cluster's remembered bytes for a marker: the marker is served
+        // only if the next rebuild reuses that entry instead of translating the cluster again.
+        cache
+            .translations
+            .lock()
+            .await
+            .get_mut(&team.id)
+            .and_then(|memo| memo.clusters.get_mut(&untouched))
+            .expect("memoized cluster")
+            .output
+            .0
+            .any
+            .value = b"memoized".to_vec();
+        let serves = |snapshot: &ResourceSet, needle: &[u8]| {
+            snapshot
+                .resources
+                .iter()
+                .any(|any| any.value.windows(needle.len()).any(|bytes| bytes == needle))
+        };
+
+        fp_core::services::clusters::update_cluster(
+            &pool,
+            &ctx,
+            team,
+            &changed,
+            cluster_spec("10.0.0.2"),
+            1,
+            RequestId::generate(),
+            Default::default(),
+        )
+        .await
+        .expect("update changed");
+        cache.rebuild_team(&pool, team.id).await.expect("rebuild");
+        let snapshot = cache.team(team.id).await;
+        assert!(
+            serves(&snapshot.clusters, b"memoized"),
+            "unchanged cluster reused"
+        );
+        assert!(
+            serves(&snapshot.endpoints, b"10.0.0.2"),
+            "changed cluster translated"
+        );
+
+        fp_core::services::clusters::update_cluster(
+            &pool,
+            &ctx,
+            team,
+            &untouched,
+            cluster_spec("10.0.0.3"),
+            1,
+            RequestId::generate(),
+            Default::default(),
+        )
+        .await
+        .expect("update untouched");
+        cache.rebuild_team(&pool, team.id).await.expect("rebuild");
+        let snapshot = cache.team(team.id).await;
+        assert!(
+            !serves(&snapshot.clusters, b"memoized"),
+            "new input, new translation"
+        );
+        assert!(serves(&snapshot.endpoints, b"10.0.0.3"));
+    }
+
+    #[tokio::test]
+    async fn rebuild_loads_only_the_descriptor_sets_listeners_reference() {
+        use fp_domain::gateway::filters::{
+            GrpcJsonTranscoderConfig, HttpFilterEntry, HttpFilterSpec,
+        };
+        use prost_types::{FileDescriptorProto, FileDescriptorSet, ServiceDescriptorProto};
+        let Some((pool, team, _, ctx, _)) = world().await else {
+            return;
+        };
+        let descriptor_set = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("bookstore.proto".into()),
+                package: Some("bookstore".into()),
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Bookstore".into()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+        .encode_to_vec();
+        let mut ids = Vec::new();
+        for name in ["referenced", "unreferenced"] {
+            let descriptor = fp_core::services::proto_descriptors::create_proto_descriptor(
+                &pool,
+                &ctx,
+                team,
+                name,
+                &descriptor_set,
+                RequestId::generate(),
+            )
+            .await
+            .expect("descriptor");
+            ids.push(descriptor.id);
+        }
+        fp_core::services::gateway::create_listener(
+            &pool,
+            &ctx,
+            team,
+            &unique("grpc"),
+            ListenerSpec {
+                address: "0.0.0.0".into(),
+                port: 19230,
+                public_base_url: None,
+                protocol: fp_domain::gateway::listener::ListenerProtocol::Http,
+                route_config: None,
+                tls_context: None,
+                http_filters: vec![HttpFilterEntry {
+                    filter: HttpFilterSpec::GrpcJsonTranscoder(GrpcJsonTranscoderConfig {
+                        proto_descriptor_id: ids[0],
+                        services: vec!["bookstore.Bookstore".into()],
+                        ignore_unknown_query_parameters: false,
+                        convert_grpc_status: true,
+                    }),
+                    disabled: false,
+                }],
+                access_logs: Vec::new(),
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
+            },
+            RequestId::generate(),
+            false,
+        )
+        .await
+        .expect("listener");
+
+        let resources = load_xds_resources(&pool, team.id).await.expect("load");
+        let artifacts = filter_artifacts(&pool, team.id, &resources.listeners)
+            .await
+            .expect("artifacts");
+        assert_eq!(
+            artifacts.descriptor_sets.keys().collect::<Vec<_>>(),
+            vec![&ids[0]],
+            "the unreferenced descriptor set is not loaded"
+        );
+    }
+
+    #[tokio::test]
+    async fn reconcile_reports_and_repairs_every_kind_of_drift() {
+        let Some((pool, team, _, ctx, _)) = world().await else {
+            return;
+        };
+        let cache = SnapshotCache::new();
+        let (kept, dropped, late) = (unique("kept"), unique("dropped"), unique("late"));
+        for name in [&kept, &dropped] {
+            fp_core::services::clusters::create_cluster(
+                &pool,
+                &ctx,
+                team,
+                name,
+                cluster_spec("10.0.0.1"),
+                RequestId::generate(),
+                Default::default(),
+            )
+            .await
+            .expect("cluster");
+        }
+        cache.rebuild_team(&pool, team.id).await.expect("build");
+
+        // Three ways the cache can drift: a row written without a rebuild, a row removed
+        // without one, and cached bytes that no longer match their row.
+        fp_core::services::clusters::create_cluster(
+            &pool,
+            &ctx,
+            team,
+            &late,
+            cluster_spec("10.0.0.1"),
+            RequestId::generate(),
+            Default::default(),
+        )
+        .await
+        .expect("late cluster");
+        sqlx::query("DELETE FROM clusters WHERE team_id = $1 AND name = $2")
+            .bind(team.id.as_uuid())
+            .bind(&dropped)
+            .execute(&pool)
+            .await
+            .expect("delete behind the cache");
+        if let Some(internal) = cache.snapshots.write().await.get_mut(&team.id) {
+            for resource in &mut internal.clusters.raw {
+                if resource.name == kept {
+                    resource.any.value = b"drifted".to_vec();
+                }
+            }
+        }
+
+        let found = cache
+            .reconcile_team(&pool, team.id)
+            .await
+            .expect("reconcile");
+        let ours: BTreeSet<(&str, &str, DiscrepancyKind)> = found
+            .iter()
+            .map(|d| (d.type_url, d.name.as_str(), d.kind))
+            .collect();
+        let expected: BTreeSet<(&str, &str, DiscrepancyKind)> = [
+            (CLUSTER_TYPE_URL, kept.as_str(), DiscrepancyKind::Outdated),
+            (
+                CLUSTER_TYPE_URL,
+                dropped.as_str(),
+                DiscrepancyKind::ExtraInCache,
+            ),
+            (
+                ENDPOINT_TYPE_URL,
+                dropped.as_str(),
+                DiscrepancyKind::ExtraInCache,
+            ),
+            (
+                CLUSTER_TYPE_URL,
+                late.as_str(),
+                DiscrepancyKind::MissingFromCache,
+            ),
+            (
+                ENDPOINT_TYPE_URL,
+                late.as_str(),
+                DiscrepancyKind::MissingFromCache,
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(ours, expected);
+
+        // Repaired: a second pass finds nothing.
+        let again = cache
+            .reconcile_team(&pool, team.id)
+            .await
+            .expect("reconcile");
+        assert!(again.is_empty(), "{again:?}");
+    }
+
+    #[tokio::test]
+    async fn maintenance_swaps_the_listener_to_a_notice_and_restores_it_exactly() {
+        use envoy_types::pb::envoy::config::route::v3 as rt;
+        use fp_domain::gateway::listener::ListenerMaintenance;
+        let Some((pool, team, _, ctx, _)) = world().await else {
+            return;
+        };
+        let cache = SnapshotCache::new();
+        let upstream = unique("upstream");
+        fp_core::services::clusters::create_cluster(
+            &pool,
+            &ctx,
+            team,
+            &upstream,
+            cluster_spec("10.0.0.1"),
+            RequestId::generate(),
+            Default::default(),
+        )
+        .await
+        .expect("cluster");
+        let rc = unique("routes");
+        fp_core::services::gateway::create_route_config(
+            &pool,
+            &ctx,
+            team,
+            &rc,
+            rc_spec(&upstream),
+            RequestId::generate(),
+        )
+        .await
+        .expect("rc");
+        let edge = unique("edge");
+        fp_core::services::gateway::create_listener(
+            &pool,
+            &ctx,
+            team,
+            &edge,
+            ListenerSpec {
+                address: "0.0.0.0".into(),
+                port: 19220,
+                public_base_url: None,
+                protocol: fp_domain::gateway::listener::ListenerProtocol::Http,
+                route_config: Some(rc.clone()),
+                tls_context: None,
+                http_filters: Vec::new(),
+                access_logs: Vec::new(),
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
+            },
+            RequestId::generate(),
+            false,
+        )
+        .await
+        .expect("listener");
+        cache.rebuild_team(&pool, team.id).await.expect("build");
+        let before = cache.team(team.id).await;
+        let notice_name = translate::maintenance_route_config_name(&edge);
+
+        fp_core::services::gateway::set_listener_maintenance(
+            &pool,
+            &ctx,
+            team,
+            &edge,
+            Some(ListenerMaintenance {
+                status: 503,
+                body: "down for maintenance".into(),
+            }),
+            None,
+            RequestId::generate(),
+        )
+        .await
+        .expect("enable");
+        cache.rebuild_team(&pool, team.id).await.expect("rebuild");
+        let during = cache.team(team.id).await;
+        let notice = during
+            .routes
+            .resources
+            .iter()
+            .map(|any| rt::RouteConfiguration::decode(any.value.as_slice()).expect("route"))
+            .find(|route| route.name == notice_name)
+            .expect("maintenance route config served");
+        let action = notice.virtual_hosts[0].routes[0]
+            .action
+            .clone()
+            .expect("action");
+        let rt::route::Action::DirectResponse(direct) = action else {
+            panic!("expected a direct response, got {action:?}");
+        };
+        assert_eq!(direct.status, 503);
+        // The operator's real route config is still served, just not referenced.
+        assert!(during.routes.resources.len() > before.routes.resources.len());
+        assert_ne!(during.listeners.resources, before.listeners.resources);
+
+        fp_core::services::gateway::set_listener_maintenance(
+            &pool,
+            &ctx,
+            team,
+            &edge,
+            None,
+            None,
+            RequestId::generate(),
+        )
+        .await
+        .expect("disable");
+        cache.rebuild_team(&pool, team.id).await.expect("rebuild");
+        let after = cache.team(team.id).await;
+        assert_eq!(after.listeners.resources, before.listeners.resources);
+        assert_eq!(after.routes.resources, before.routes.resources);
+    }
+
     #[tokio::test]
     async fn events_drive_rebuilds_with_per_type_versions_and_team_isolation() {
         let Some((pool, team_a, team_b, ctx_a, ctx_b)) = world().await else {
@@ -1433,6 +2671,11 @@ mod tests {
                 tls_context: None,
                 http_filters: Vec::new(),
                 access_logs: Vec::new(),
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             },
             RequestId::generate(),
             false,
@@ -1542,6 +2785,143 @@ mod tests {
         );
     }
 
+    fn runtime_layer(snap: &TeamSnapshot) -> BTreeMap<String, wkt::value::Kind> {
+        assert_eq!(snap.runtime.resources.len(), 1, "exactly one RTDS layer");
+        let layer = envoy_types::pb::envoy::service::runtime::v3::Runtime::decode(
+            snap.runtime.resources[0].value.as_slice(),
+        )
+        .expect("decode runtime layer");
+        assert_eq!(layer.name, RUNTIME_LAYER_NAME);
+        layer
+            .layer
+            .expect("layer struct")
+            .fields
+            .into_iter()
+            .filter_map(|(key, value)| value.kind.map(|kind| (key, kind)))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn runtime_values_are_served_as_one_rtds_layer_without_touching_other_types() {
+        let Some((pool, team_a, team_b, ctx_a, _)) = world().await else {
+            return;
+        };
+        let cache = SnapshotCache::new();
+        let consumer = format!("xds-test-{}", unique("c"));
+        fp_storage::outbox::register_consumer_at_head(&pool, &consumer)
+            .await
+            .expect("register");
+        let drain = || async {
+            while fp_storage::outbox::process_batch(&pool, &consumer, 100, |events| {
+                let cache = cache.clone();
+                let pool = pool.clone();
+                async move { handle_events(&cache, &pool, events).await }
+            })
+            .await
+            .expect("process")
+                > 0
+            {}
+        };
+
+        fp_core::services::clusters::create_cluster(
+            &pool,
+            &ctx_a,
+            team_a,
+            &unique("upstream"),
+            cluster_spec("10.0.0.1"),
+            RequestId::generate(),
+            Default::default(),
+        )
+        .await
+        .expect("cluster");
+        for (key, value) in [
+            ("routes.split.enabled", serde_json::json!(true)),
+            ("routes.checkout.canary", serde_json::json!(10)),
+            ("upstream.label", serde_json::json!("blue")),
+        ] {
+            fp_core::services::runtime::set_runtime_value(
+                &pool,
+                &ctx_a,
+                team_a,
+                key,
+                value,
+                RequestId::generate(),
+            )
+            .await
+            .expect("set runtime value");
+        }
+        drain().await;
+        let before = cache.team(team_a.id).await;
+        assert_eq!(
+            runtime_layer(&before),
+            BTreeMap::from([
+                (
+                    "routes.checkout.canary".to_string(),
+                    wkt::value::Kind::NumberValue(10.0)
+                ),
+                (
+                    "routes.split.enabled".to_string(),
+                    wkt::value::Kind::BoolValue(true)
+                ),
+                (
+                    "upstream.label".to_string(),
+                    wkt::value::Kind::StringValue("blue".into())
+                ),
+            ])
+        );
+
+        // A runtime write re-serves the layer alone: no cluster version bump, a new config.
+        fp_core::services::runtime::set_runtime_value(
+            &pool,
+            &ctx_a,
+            team_a,
+            "routes.checkout.canary",
+            serde_json::json!(25),
+            RequestId::generate(),
+        )
+        .await
+        .expect("update runtime value");
+        fp_core::services::runtime::delete_runtime_value(
+            &pool,
+            &ctx_a,
+            team_a,
+            "upstream.label",
+            RequestId::generate(),
+        )
+        .await
+        .expect("delete runtime value");
+        drain().await;
+        let after = cache.team(team_a.id).await;
+        assert_eq!(after.clusters.version, before.clusters.version);
+        assert!(after.runtime.version > before.runtime.version);
+        assert_ne!(after.config_version, before.config_version);
+        let layer = runtime_layer(&after);
+        assert_eq!(
+            layer.get("routes.checkout.canary"),
+            Some(&wkt::value::Kind::NumberValue(25.0))
+        );
+        assert!(!layer.contains_key("upstream.label"));
+
+        // The layer is pinnable like every other type; versions recorded before RTDS was
+        // served decode with an empty runtime set rather than failing to hold.
+        let mut record = after.to_record();
+        let held = TeamSnapshot::from_record("v".into(), &record).expect("decode record");
+        assert_eq!(held.runtime.resources, after.runtime.resources);
+        record
+            .as_object_mut()
+            .expect("record object")
+            .remove(RUNTIME_TYPE_URL);
+        let legacy = TeamSnapshot::from_record("v".into(), &record).expect("decode legacy");
+        assert!(legacy.runtime.resources.is_empty());
+
+        // A team with no values still gets an (empty) layer so its proxies' runtime initializes.
+        cache
+            .rebuild_team(&pool, team_b.id)
+            .await
+            .expect("rebuild b");
+        assert!(runtime_layer(&cache.team(team_b.id).await).is_empty());
+    }
+
     #[tokio::test]
     async fn learning_capture_injection_is_scoped_to_the_session_team() {
         let Some((pool, team_a, team_b, ctx_a, ctx_b)) = world().await else {
@@ -1607,6 +2987,11 @@ mod tests {
                 tls_context: None,
                 http_filters: Vec::new(),
                 access_logs: Vec::new(),
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             },
             RequestId::generate(),
             false,
@@ -1627,6 +3012,11 @@ mod tests {
                 tls_context: None,
                 http_filters: Vec::new(),
                 access_logs: Vec::new(),
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             },
             RequestId::generate(),
             false,
@@ -1674,6 +3064,10 @@ mod tests {
                 max_duration_seconds: Some(60),
                 max_bytes: 4096,
                 max_distinct_paths: 10,
+                sample_percent: None,
+                max_body_bytes: None,
+                redact_headers: Vec::new(),
+                redact_body_fields: Vec::new(),
             },
         )
         .await
@@ -1801,6 +3195,191 @@ mod tests {
         assert!(degraded[0].error.contains("secret translation failed"));
     }
 
+    #[tokio::test]
+    async fn expired_secret_is_flagged_and_withdrawn_from_sds() {
+        let _guard = ENV_LOCK.lock().await;
+        let Some((pool, team_a, _team_b, ctx_a, _ctx_b)) = world().await else {
+            return;
+        };
+        std::env::set_var(
+            "FLOWPLANE_SECRET_ENCRYPTION_KEY",
+            "12345678901234567890123456789012",
+        );
+        let cache = SnapshotCache::new();
+        let consumer = format!("xds-test-{}", unique("c"));
+        fp_storage::outbox::register_consumer_at_head(&pool, &consumer)
+            .await
+            .expect("register");
+        let drain = || async {
+            fp_storage::outbox::process_batch(&pool, &consumer, 100, |events| {
+                let cache = cache.clone();
+                let pool = pool.clone();
+                async move { handle_events(&cache, &pool, events).await }
+            })
+            .await
+            .expect("process")
+        };
+
+        let secret = fp_core::services::secrets::create_secret(
+            &pool,
+            &ctx_a,
+            team_a,
+            fp_core::services::secrets::SecretWrite {
+                name: "short-lived",
+                description: "",
+                spec: SecretSpec::GenericSecret {
+                    secret: "aGVsbG8=".into(),
+                },
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+            },
+            RequestId::generate(),
+        )
+        .await
+        .expect("secret");
+        drain().await;
+        let before = cache.team(team_a.id).await.secrets;
+        assert_eq!(before.resources.len(), 1);
+
+        // Not yet due: the sweep leaves it alone.
+        let expired = fp_core::services::secrets::expire_due_secrets(&pool, chrono::Utc::now())
+            .await
+            .expect("sweep");
+        assert!(expired.iter().all(|e| e.id != secret.id));
+
+        sqlx::query("UPDATE secrets SET expires_at = now() - interval '1 minute' WHERE id = $1")
+            .bind(secret.id.as_uuid())
+            .execute(&pool)
+            .await
+            .expect("backdate expiry");
+        let expired = fp_core::services::secrets::expire_due_secrets(&pool, chrono::Utc::now())
+            .await
+            .expect("sweep");
+        let hit = expired
+            .iter()
+            .find(|e| e.id == secret.id)
+            .expect("due secret is swept");
+        assert_eq!(hit.team, team_a);
+        assert_eq!(hit.name, "short-lived");
+        let flagged = fp_storage::repos::secrets::get_secret(&pool, team_a.id, "short-lived")
+            .await
+            .expect("get")
+            .expect("row kept");
+        assert!(flagged.expired_at.is_some());
+
+        assert!(drain().await >= 1);
+        let after = cache.team(team_a.id).await.secrets;
+        assert!(after.resources.is_empty(), "expired secret still served");
+        assert!(after.version > before.version);
+
+        // Already marked: a second pass emits nothing for it.
+        let again = fp_core::services::secrets::expire_due_secrets(&pool, chrono::Utc::now())
+            .await
+            .expect("sweep");
+        assert!(again.iter().all(|e| e.id != secret.id));
+    }
+
+    #[tokio::test]
+    async fn recorded_versions_hold_no_secrets_and_pins_serve_current_sds() {
+        let _guard = ENV_LOCK.lock().await;
+        let Some((pool, team_a, _team_b, ctx_a, _ctx_b)) = world().await else {
+            return;
+        };
+        std::env::set_var(
+            "FLOWPLANE_SECRET_ENCRYPTION_KEY",
+            "12345678901234567890123456789012",
+        );
+        let cache = SnapshotCache::new();
+        let consumer = format!("xds-test-{}", unique("c"));
+        fp_storage::outbox::register_consumer_at_head(&pool, &consumer)
+            .await
+            .expect("register");
+        let drain = || async {
+            fp_storage::outbox::process_batch(&pool, &consumer, 100, |events| {
+                let cache = cache.clone();
+                let pool = pool.clone();
+                async move { handle_events(&cache, &pool, events).await }
+            })
+            .await
+            .expect("process")
+        };
+
+        let secret = fp_core::services::secrets::create_secret(
+            &pool,
+            &ctx_a,
+            team_a,
+            fp_core::services::secrets::SecretWrite {
+                name: "recorded-key",
+                description: "",
+                spec: SecretSpec::GenericSecret {
+                    secret: "c3VwZXItc2VjcmV0LXZhbHVl".into(),
+                },
+                expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+            },
+            RequestId::generate(),
+        )
+        .await
+        .expect("secret");
+        drain().await;
+        let served = cache.team(team_a.id).await;
+        assert_eq!(served.secrets.resources.len(), 1);
+
+        let record: serde_json::Value = sqlx::query_scalar(
+            "SELECT snapshot FROM xds_config_versions WHERE team_id = $1 AND config_version = $2",
+        )
+        .bind(team_a.id.as_uuid())
+        .bind(&served.config_version)
+        .fetch_one(&pool)
+        .await
+        .expect("recorded version");
+        assert!(record.get(SECRET_TYPE_URL).is_none());
+        let text = record.to_string();
+        let engine = base64::engine::general_purpose::STANDARD;
+        assert!(!text.contains(&engine.encode(served.secrets.resources[0].encode_to_vec())));
+        assert!(!text.contains(&engine.encode("super-secret-value")));
+
+        // Pin a dataplane on that version, then expire the secret: the pinned proxy keeps
+        // its held clusters and listeners but loses the secret with everyone else.
+        let mut tx = pool.begin().await.expect("begin");
+        let dataplane =
+            fp_storage::repos::dataplanes::create_dataplane(&mut tx, team_a, &unique("pinned"), "")
+                .await
+                .expect("dataplane");
+        fp_storage::repos::dataplanes::set_pinned_config_version(
+            &mut tx,
+            team_a.id,
+            &dataplane.name,
+            Some(&served.config_version),
+        )
+        .await
+        .expect("pin");
+        tx.commit().await.expect("commit");
+        sqlx::query("UPDATE secrets SET expires_at = now() - interval '1 minute' WHERE id = $1")
+            .bind(secret.id.as_uuid())
+            .execute(&pool)
+            .await
+            .expect("backdate expiry");
+        fp_core::services::secrets::expire_due_secrets(&pool, chrono::Utc::now())
+            .await
+            .expect("sweep");
+        assert!(drain().await >= 1);
+
+        let pinned = cache
+            .team_for(team_a.id, Some(dataplane.id), "pinned-node")
+            .await;
+        assert!(pinned.held);
+        assert_eq!(pinned.config_version, served.config_version);
+        assert_eq!(pinned.clusters.resources, served.clusters.resources);
+        assert!(
+            pinned.secrets.resources.is_empty(),
+            "expired secret still served"
+        );
+        assert_ne!(
+            pinned.version_info(&pinned.secrets),
+            pinned.version_info(&pinned.clusters),
+            "a held proxy sees SDS changes under their own version"
+        );
+    }
+
     #[tokio::test]
     async fn malformed_route_config_degrades_without_blocking_rebuild_or_outbox() {
         let Some((pool, team_a, team_b, ctx_a, ctx_b)) = world().await else {
@@ -1848,6 +3427,11 @@ mod tests {
                 tls_context: None,
                 http_filters: Vec::new(),
                 access_logs: Vec::new(),
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             },
             RequestId::generate(),
             false,
@@ -11,33 +11,43 @@ use envoy_types::pb::envoy::config::ratelimit::v3 as ratelimit_cfg;
 use envoy_types::pb::envoy::config::route::v3 as rt;
 use envoy_types::pb::envoy::extensions::access_loggers::file::v3 as file_accesslog;
 use envoy_types::pb::envoy::extensions::access_loggers::grpc::v3 as grpc_accesslog;
+use envoy_types::pb::envoy::extensions::access_loggers::open_telemetry::v3 as otel_accesslog;
 use envoy_types::pb::envoy::extensions::clusters::aggregate::v3 as aggregate_cluster;
 use envoy_types::pb::envoy::extensions::filters::http::ext_proc::v3 as ext_proc;
+use envoy_types::pb::envoy::extensions::filters::http::grpc_json_transcoder::v3 as grpc_json_transcoder;
 use envoy_types::pb::envoy::extensions::filters::http::ratelimit::v3 as rate_limit_filter;
 use envoy_types::pb::envoy::extensions::filters::http::router::v3::Router;
 use envoy_types::pb::envoy::extensions::filters::http::upstream_codec::v3 as upstream_codec;
 use envoy_types::pb::envoy::extensions::filters::network::http_connection_manager::v3 as hcm;
 use envoy_types::pb::envoy::extensions::path::rewrite::uri_template::v3 as uri_template_rewrite;
 use envoy_types::pb::envoy::extensions::retry::priority::previous_priorities::v3 as previous_priorities;
+use envoy_types::pb::envoy::extensions::transport_sockets::quic::v3 as quic_transport;
 use envoy_types::pb::envoy::extensions::transport_sockets::tls::v3 as tls;
 use envoy_types::pb::envoy::extensions::upstreams::http::v3 as upstream_http;
 use envoy_types::pb::envoy::r#type::matcher::v3 as matcher_type;
 use envoy_types::pb::envoy::r#type::v3 as envoy_type;
 use envoy_types::pb::google::protobuf as wkt;
+use envoy_types::pb::opentelemetry::proto::common::v1 as otel_common;
 use fp_domain::gateway::cluster::{
     CircuitBreakerThresholds, ClusterSpec, DnsLookupFamily, HealthCheck, HttpHealthCheckMethod,
     LbPolicy, RingHashFunction, UpstreamProtocol,
 };
-use fp_domain::gateway::listener::{ListenerProtocol, ListenerSpec, ListenerTlsConfig};
-use fp_domain::gateway::route_config::{PathMatch, RouteConfigSpec};
-use fp_domain::{DomainError, DomainResult, SecretSpec};
+use fp_domain::gateway::listener::{
+    ListenerFilterSpec, ListenerProtocol, ListenerSpec, ListenerTlsConfig, NetworkFilterSpec,
+    ProxyProtocolVersion, TracingProvider,
+};
+use fp_domain::gateway::route_config::{fallback_aggregate_name, PathMatch, RouteConfigSpec};
+use fp_domain::{DomainError, DomainResult, ProtoDescriptorId, SecretSpec};
 use prost::Message;
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 pub const LEARNING_ALS_CLUSTER: &str = "xds_cluster";
 pub const LEARNING_EXT_PROC_CLUSTER: &str = "xds_cluster";
 pub const AI_EXT_PROC_CLUSTER: &str = "xds_cluster";
 const LEARNING_ALS_NAME: &str = "envoy.access_loggers.http_grpc";
+/// `log_name` on OTLP access logs; collectors see it as a built-in resource label.
+const OTEL_ACCESS_LOG_NAME: &str = "flowplane_access_log";
 pub(crate) const LEARNING_EXT_PROC_FILTER_PREFIX: &str =
     "envoy.filters.http.ext_proc.flowplane_learning.";
 const AI_EXT_PROC_FILTER_NAME: &str = "envoy.filters.http.ext_proc.flowplane_ai";
@@ -69,6 +79,23 @@ pub struct AiProcessorMetadata {
     pub route_config_id: uuid::Uuid,
 }
 
+/// A team's uploaded `FileDescriptorSet` bytes, keyed by descriptor id.
+pub type ProtoDescriptorSets = std::collections::HashMap<ProtoDescriptorId, Vec<u8>>;
+
+/// A team's registry wasm modules referenced by its listeners, keyed by `(name, version)`.
+pub type WasmModules = std::collections::HashMap<(String, i64), Vec<u8>>;
+
+/// Uploaded bytes that listener filters reference by id or name and that translation inlines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterArtifacts {
+    pub descriptor_sets: ProtoDescriptorSets,
+    pub wasm_modules: WasmModules,
+}
+
+/// A team's named retry policies, keyed by name (`retry_policy_ref` targets).
+pub type NamedRetryPolicies =
+    std::collections::HashMap<String, fp_domain::gateway::route_config::RetryPolicy>;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AiUpstreamProcessorMetadata {
     pub team_id: uuid::Uuid,
@@ -78,7 +105,7 @@ pub struct AiUpstreamProcessorMetadata {
     pub failover_chain: Vec<(uuid::Uuid, i32)>,
 }
 
-fn any<M: Message>(type_url: &str, msg: &M) -> wkt::Any {
+pub(crate) fn any<M: Message>(type_url: &str, msg: &M) -> wkt::Any {
     wkt::Any {
         type_url: type_url.to_string(),
         value: msg.encode_to_vec(),
@@ -184,6 +211,87 @@ struct StableConfigRbac {
         Option<envoy_types::pb::envoy::config::rbac::v3::rbac::AuditLoggingOptions>,
 }
 
+#[derive(Clone, PartialEq, Message)]
+struct StableExtAuthzPerRoute {
+    #[prost(oneof = "StableExtAuthzPerRouteOverride", tags = "1, 2")]
+    r#override: Option<StableExtAuthzPerRouteOverride>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum StableExtAuthzPerRouteOverride {
+    #[prost(bool, tag = "1")]
+    Disabled(bool),
+    #[prost(message, tag = "2")]
+    CheckSettings(StableExtAuthzCheckSettings),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct StableExtAuthzCheckSettings {
+    #[prost(btree_map = "string, string", tag = "1")]
+    context_extensions: BTreeMap<String, String>,
+    #[prost(bool, tag = "2")]
+    disable_request_body_buffering: bool,
+}
+
+/// `FileAccessLog` with a `json_format` log format, field for field, with the Struct's
+/// map sorted.
+#[derive(Clone, PartialEq, Message)]
+struct StableJsonFileAccessLog {
+    #[prost(string, tag = "1")]
+    path: String,
+    #[prost(message, optional, tag = "5")]
+    log_format: Option<StableJsonFormatString>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct StableJsonFormatString {
+    #[prost(message, optional, tag = "2")]
+    json_format: Option<StableStruct>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct StableStruct {
+    #[prost(btree_map = "string, message", tag = "1")]
+    fields: BTreeMap<String, StableStringValue>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct StableStringValue {
+    #[prost(string, tag = "3")]
+    string_value: String,
+}
+
+/// RTDS `Runtime`, field for field, with the layer Struct's map sorted.
+#[derive(Clone, PartialEq, Message)]
+struct StableRuntime {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(message, optional, tag = "2")]
+    layer: Option<StableRuntimeLayer>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct StableRuntimeLayer {
+    #[prost(btree_map = "string, message", tag = "1")]
+    fields: BTreeMap<String, StableScalarValue>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct StableScalarValue {
+    #[prost(oneof = "StableScalarKind", tags = "2, 3, 4")]
+    kind: Option<StableScalarKind>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum StableScalarKind {
+    #[prost(double, tag = "2")]
+    Number(f64),
+    #[prost(string, tag = "3")]
+    String(String),
+    #[prost(bool, tag = "4")]
+    Bool(bool),
+}
+
 impl From<&rt::RouteConfiguration> for StableRouteConfiguration {
     fn from(proto: &rt::RouteConfiguration) -> Self {
         Self {
@@ -285,6 +393,31 @@ impl From<&envoy_types::pb::envoy::config::rbac::v3::Rbac> for StableConfigRbac
     }
 }
 
+impl From<&envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3::ExtAuthzPerRoute>
+    for StableExtAuthzPerRoute
+{
+    fn from(
+        proto: &envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3::ExtAuthzPerRoute,
+    ) -> Self {
+        use envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3::ext_authz_per_route::Override;
+        Self {
+            r#override: proto.r#override.as_ref().map(|ov| match ov {
+                Override::Disabled(disabled) => StableExtAuthzPerRouteOverride::Disabled(*disabled),
+                Override::CheckSettings(settings) => {
+                    StableExtAuthzPerRouteOverride::CheckSettings(StableExtAuthzCheckSettings {
+                        context_extensions: settings
+                            .context_extensions
+                            .iter()
+                            .map(|(key, value)| (key.clone(), value.clone()))
+                            .collect(),
+                        disable_request_body_buffering: settings.disable_request_body_buffering,
+                    })
+                }
+            }),
+        }
+    }
+}
+
 fn verified_stable_encode<M, S>(label: &str, original: &M, stable: S) -> DomainResult<Vec<u8>>
 where
     M: Message + Default + PartialEq,
@@ -311,6 +444,61 @@ pub(crate) fn encode_route_config_deterministic(
     )
 }
 
+/// A team's RTDS layer: one `Runtime` resource holding every runtime value, present (and
+/// empty) even when the team has set none so the proxy's runtime finishes initializing. The
+/// layer is a protobuf Struct (a HashMap): it is encoded through the sorted mirror so the
+/// bytes, and the config version, do not churn.
+pub fn runtime_layer_to_proto(
+    layer_name: &str,
+    values: &BTreeMap<String, serde_json::Value>,
+) -> DomainResult<Vec<u8>> {
+    let mut fields = BTreeMap::new();
+    for (key, value) in values {
+        let kind = match value {
+            serde_json::Value::Bool(b) => StableScalarKind::Bool(*b),
+            serde_json::Value::String(s) => StableScalarKind::String(s.clone()),
+            serde_json::Value::Number(n) => {
+                StableScalarKind::Number(n.as_f64().ok_or_else(|| {
+                    DomainError::internal(format!("runtime value \"{key}\" is not a finite number"))
+                })?)
+            }
+            _ => {
+                return Err(DomainError::internal(format!(
+                    "runtime value \"{key}\" is not a scalar"
+                )))
+            }
+        };
+        fields.insert(key.clone(), StableScalarValue { kind: Some(kind) });
+    }
+    let proto = envoy_types::pb::envoy::service::runtime::v3::Runtime {
+        name: layer_name.to_string(),
+        layer: Some(wkt::Struct {
+            fields: fields
+                .iter()
+                .map(|(key, value)| {
+                    let kind = match &value.kind {
+                        Some(StableScalarKind::Number(n)) => wkt::value::Kind::NumberValue(*n),
+                        Some(StableScalarKind::String(s)) => {
+                            wkt::value::Kind::StringValue(s.clone())
+                        }
+                        Some(StableScalarKind::Bool(b)) => wkt::value::Kind::BoolValue(*b),
+                        None => wkt::value::Kind::NullValue(0),
+                    };
+                    (key.clone(), wkt::Value { kind: Some(kind) })
+                })
+                .collect(),
+        }),
+    };
+    verified_stable_encode(
+        "runtime layer",
+        &proto,
+        StableRuntime {
+            name: layer_name.to_string(),
+            layer: Some(StableRuntimeLayer { fields }),
+        },
+    )
+}
+
 fn encode_jwt_auth_deterministic(
     proto: &envoy_types::pb::envoy::extensions::filters::http::jwt_authn::v3::JwtAuthentication,
 ) -> DomainResult<Vec<u8>> {
@@ -321,13 +509,23 @@ fn encode_jwt_auth_deterministic(
     )
 }
 
+fn encode_ext_authz_per_route_deterministic(
+    proto: &envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3::ExtAuthzPerRoute,
+) -> DomainResult<Vec<u8>> {
+    verified_stable_encode(
+        "ext_authz per-route",
+        proto,
+        StableExtAuthzPerRoute::from(proto),
+    )
+}
+
 fn encode_http_rbac_deterministic(
     proto: &envoy_types::pb::envoy::extensions::filters::http::rbac::v3::Rbac,
 ) -> DomainResult<Vec<u8>> {
     verified_stable_encode("HTTP RBAC", proto, StableHttpRbac::from(proto))
 }
 
-fn duration(secs: u32) -> wkt::Duration {
+pub(crate) fn duration(secs: u32) -> wkt::Duration {
     wkt::Duration {
         seconds: i64::from(secs),
         nanos: 0,
@@ -353,7 +551,7 @@ fn millis_duration(ms: u64) -> wkt::Duration {
     }
 }
 
-fn socket_address(host: &str, port: u16) -> core::Address {
+pub(crate) fn socket_address(host: &str, port: u16) -> core::Address {
     core::Address {
         address: Some(core::address::Address::SocketAddress(core::SocketAddress {
             address: host.to_string(),
@@ -738,6 +936,28 @@ pub fn rls_cluster_to_proto(cfg: &RlsClusterConfig) -> DomainResult<exc::Cluster
     })
 }
 
+/// The aggregate cluster a `fallback_cluster` route targets (see
+/// [`fallback_aggregate_name`]): `[primary, fallback]` in priority order.
+pub fn fallback_cluster_to_proto(primary: &str, fallback: &str) -> exc::Cluster {
+    exc::Cluster {
+        name: fallback_aggregate_name(primary, fallback),
+        connect_timeout: Some(duration(5)),
+        cluster_discovery_type: Some(exc::cluster::ClusterDiscoveryType::ClusterType(
+            exc::cluster::CustomClusterType {
+                name: "envoy.clusters.aggregate".to_string(),
+                typed_config: Some(any(
+                    "type.googleapis.com/envoy.extensions.clusters.aggregate.v3.ClusterConfig",
+                    &aggregate_cluster::ClusterConfig {
+                        clusters: vec![primary.to_string(), fallback.to_string()],
+                    },
+                )),
+            },
+        )),
+        lb_policy: exc::cluster::LbPolicy::ClusterProvided as i32,
+        ..Default::default()
+    }
+}
+
 /// mTLS transport socket for the RLS cluster: present the dataplane client cert/key and verify
 /// the RLS server cert against the CA. Files are read by Envoy on the dataplane host.
 fn rls_transport_socket(tls: &RlsClusterTls) -> core::TransportSocket {
@@ -860,6 +1080,12 @@ fn upstream_tls_context(spec: &ClusterSpec) -> tls::UpstreamTlsContext {
     tls::UpstreamTlsContext {
         common_tls_context: Some(tls::CommonTlsContext {
             validation_context_type,
+            // `protocol: auto` picks the codec from ALPN, so offer both.
+            alpn_protocols: if spec.protocol == Some(UpstreamProtocol::Auto) {
+                vec!["h2".to_string(), "http/1.1".to_string()]
+            } else {
+                Vec::new()
+            },
             ..Default::default()
         }),
         sni: tls_spec.and_then(|tls| tls.sni.clone()).unwrap_or_default(),
@@ -936,6 +1162,7 @@ fn circuit_breaker_to_proto(
         max_pending_requests: Some(u32_value(cb.max_pending_requests)),
         max_requests: Some(u32_value(cb.max_requests)),
         max_retries: Some(u32_value(cb.max_retries)),
+        max_connection_pools: cb.max_connection_pools.map(u32_value),
         ..Default::default()
     }
 }
@@ -957,43 +1184,76 @@ fn upstream_protocol_options(
     spec: &ClusterSpec,
     ai: Option<&AiUpstreamProcessorMetadata>,
 ) -> std::collections::HashMap<String, wkt::Any> {
-    let Some(protocol) = spec.protocol else {
-        if let Some(ai) = ai {
-            return upstream_http_options(None, ai);
+    let pool = spec.connection_pool.as_ref();
+    let protocol_options = if spec.use_downstream_protocol {
+        Some(use_downstream_http_config())
+    } else {
+        match spec.protocol {
+            None | Some(UpstreamProtocol::Http1) => None,
+            Some(UpstreamProtocol::Http2 | UpstreamProtocol::Grpc) => Some(explicit_http2_config()),
+            Some(UpstreamProtocol::Auto) => Some(auto_http_config()),
+        }
+    }
+    .map(|options| {
+        with_max_concurrent_streams(options, pool.and_then(|p| p.max_concurrent_streams))
+    });
+    let common_http_protocol_options =
+        pool.and_then(|p| p.max_requests_per_connection)
+            .map(|max| core::HttpProtocolOptions {
+                max_requests_per_connection: Some(u32_value(max)),
+                ..Default::default()
+            });
+    let mut options = match ai {
+        Some(ai) => upstream_http_options(protocol_options, ai),
+        // Plain HTTP/1.1 with no limits needs no protocol options at all.
+        None if protocol_options.is_none() && common_http_protocol_options.is_none() => {
+            return Default::default();
         }
-        return std::collections::HashMap::new();
+        None => upstream_http::HttpProtocolOptions {
+            upstream_protocol_options: Some(protocol_options.unwrap_or_else(explicit_http1_config)),
+            ..Default::default()
+        },
     };
-    match protocol {
-        UpstreamProtocol::Http1 => ai
-            .map(|ai| upstream_http_options(None, ai))
-            .unwrap_or_default(),
-        UpstreamProtocol::Http2 | UpstreamProtocol::Grpc => {
-            let protocol_options = explicit_http2_config();
-            if let Some(ai) = ai {
-                upstream_http_options(Some(protocol_options), ai)
-            } else {
-                let options = upstream_http::HttpProtocolOptions {
-                    upstream_protocol_options: Some(protocol_options),
-                    ..Default::default()
-                };
-                std::iter::once((
-                    "envoy.extensions.upstreams.http.v3.HttpProtocolOptions".to_string(),
-                    any(
-                        "type.googleapis.com/envoy.extensions.upstreams.http.v3.HttpProtocolOptions",
-                        &options,
-                    ),
-                ))
-                .collect()
-            }
+    options.common_http_protocol_options = common_http_protocol_options;
+    std::iter::once((
+        "envoy.extensions.upstreams.http.v3.HttpProtocolOptions".to_string(),
+        any(
+            "type.googleapis.com/envoy.extensions.upstreams.http.v3.HttpProtocolOptions",
+            &options,
+        ),
+    ))
+    .collect()
+}
+
+/// Cap the streams of every HTTP/2 option set in `options`; HTTP/1.1 has no streams.
+fn with_max_concurrent_streams(
+    mut options: upstream_http::http_protocol_options::UpstreamProtocolOptions,
+    max: Option<u32>,
+) -> upstream_http::http_protocol_options::UpstreamProtocolOptions {
+    use upstream_http::http_protocol_options::explicit_http_config::ProtocolConfig;
+    use upstream_http::http_protocol_options::UpstreamProtocolOptions;
+    let Some(max) = max else { return options };
+    let http2 = match &mut options {
+        UpstreamProtocolOptions::ExplicitHttpConfig(config) => match &mut config.protocol_config {
+            Some(ProtocolConfig::Http2ProtocolOptions(http2)) => Some(http2),
+            _ => None,
+        },
+        UpstreamProtocolOptions::UseDownstreamProtocolConfig(config) => {
+            config.http2_protocol_options.as_mut()
         }
+        UpstreamProtocolOptions::AutoConfig(config) => config.http2_protocol_options.as_mut(),
+    };
+    if let Some(http2) = http2 {
+        http2.max_concurrent_streams = Some(u32_value(max));
     }
+    options
 }
 
 fn upstream_http_options(
     protocol_options: Option<upstream_http::http_protocol_options::UpstreamProtocolOptions>,
     ai: &AiUpstreamProcessorMetadata,
-) -> std::collections::HashMap<String, wkt::Any> {
-    let options = upstream_http::HttpProtocolOptions {
+) -> upstream_http::HttpProtocolOptions {
+    upstream_http::HttpProtocolOptions {
         upstream_protocol_options: Some(protocol_options.unwrap_or_else(explicit_http1_config)),
         http_filters: vec![
             ai_upstream_ext_proc_filter(ai),
@@ -1007,15 +1267,7 @@ fn upstream_http_options(
             },
         ],
         ..Default::default()
-    };
-    std::iter::once((
-        "envoy.extensions.upstreams.http.v3.HttpProtocolOptions".to_string(),
-        any(
-            "type.googleapis.com/envoy.extensions.upstreams.http.v3.HttpProtocolOptions",
-            &options,
-        ),
-    ))
-    .collect()
+    }
 }
 
 fn ai_upstream_ext_proc_filter(ai: &AiUpstreamProcessorMetadata) -> hcm::HttpFilter {
@@ -1103,16 +1355,40 @@ fn ai_upstream_grpc_service(ai: &AiUpstreamProcessorMetadata) -> core::GrpcServi
 pub fn route_config_to_proto(
     name: &str,
     spec: &RouteConfigSpec,
+) -> DomainResult<rt::RouteConfiguration> {
+    route_config_to_proto_with_retry_policies(name, spec, &NamedRetryPolicies::new())
+}
+
+/// [`route_config_to_proto`] with the team's named retry policies: a route's
+/// `retry_policy_ref` expands to exactly the Envoy retry policy the inline form would.
+pub fn route_config_to_proto_with_retry_policies(
+    name: &str,
+    spec: &RouteConfigSpec,
+    retry_policies: &NamedRetryPolicies,
 ) -> DomainResult<rt::RouteConfiguration> {
     let mut virtual_hosts = Vec::with_capacity(spec.virtual_hosts.len());
     for vhost in &spec.virtual_hosts {
         let mut routes = Vec::with_capacity(vhost.routes.len());
+        let mut include_request_attempt_count = false;
         for rule in &vhost.routes {
+            let retry = effective_retry_policy(rule, retry_policies)?;
+            let retry = fallback_retry_policy(rule, retry);
+            include_request_attempt_count |= retry
+                .as_deref()
+                .is_some_and(|retry| retry.previous_priorities_retry);
             routes.push(rt::Route {
                 name: rule.name.clone(),
                 r#match: Some(route_match_proto(rule)?),
-                action: Some(route_action_proto(rule)?),
+                action: Some(route_action_proto(rule, retry.as_deref())?),
                 typed_per_filter_config: overrides_to_typed_config(&rule.filter_overrides)?,
+                // Applied by the router, i.e. after every HTTP filter's mutations. Lowercased
+                // so spellings that differ only in case encode to the same bytes.
+                request_headers_to_remove: rule
+                    .action
+                    .request_headers_to_remove
+                    .iter()
+                    .map(|name| name.to_ascii_lowercase())
+                    .collect(),
                 ..Default::default()
             });
         }
@@ -1120,14 +1396,9 @@ pub fn route_config_to_proto(
             name: vhost.name.clone(),
             domains: vhost.domains.clone(),
             routes,
-            rate_limits: rate_limits_to_proto(&vhost.rate_limits),
+            rate_limits: rate_limits_to_proto(&vhost.rate_limits)?,
             typed_per_filter_config: overrides_to_typed_config(&vhost.filter_overrides)?,
-            include_request_attempt_count: vhost.routes.iter().any(|rule| {
-                rule.action
-                    .retry_policy
-                    .as_ref()
-                    .is_some_and(|retry| retry.previous_priorities_retry)
-            }),
+            include_request_attempt_count,
             ..Default::default()
         });
     }
@@ -1139,8 +1410,90 @@ pub fn route_config_to_proto(
     })
 }
 
+/// RDS name of the route config a listener in maintenance mode is pointed at. Resource names
+/// never contain `:`, so it cannot collide with a team route config.
+pub fn maintenance_route_config_name(listener: &str) -> String {
+    format!("{listener}:maintenance")
+}
+
+/// The catch-all route config a listener in maintenance mode serves: every host and path
+/// gets the maintenance direct response.
+pub fn maintenance_route_config_to_proto(
+    name: &str,
+    maintenance: &fp_domain::gateway::listener::ListenerMaintenance,
+) -> rt::RouteConfiguration {
+    rt::RouteConfiguration {
+        name: name.to_string(),
+        virtual_hosts: vec![rt::VirtualHost {
+            name: "maintenance".to_string(),
+            domains: vec!["*".to_string()],
+            routes: vec![rt::Route {
+                name: "maintenance".to_string(),
+                r#match: Some(rt::RouteMatch {
+                    path_specifier: Some(rt::route_match::PathSpecifier::Prefix("/".to_string())),
+                    ..Default::default()
+                }),
+                action: Some(rt::route::Action::DirectResponse(
+                    rt::DirectResponseAction {
+                        status: u32::from(maintenance.status),
+                        body: Some(core::DataSource {
+                            specifier: Some(core::data_source::Specifier::InlineString(
+                                maintenance.body.clone(),
+                            )),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+/// The inline retry policy, or the named one `retry_policy_ref` points at.
+fn effective_retry_policy<'a>(
+    rule: &'a fp_domain::gateway::route_config::RouteRule,
+    retry_policies: &'a NamedRetryPolicies,
+) -> DomainResult<Option<&'a fp_domain::gateway::route_config::RetryPolicy>> {
+    match &rule.action.retry_policy_ref {
+        Some(name) => retry_policies.get(name).map(Some).ok_or_else(|| {
+            DomainError::validation(format!(
+                "route \"{}\": retry policy \"{name}\" is not defined",
+                rule.name
+            ))
+        }),
+        None => Ok(rule.action.retry_policy.as_ref()),
+    }
+}
+
+/// A `fallback_cluster` route always retries onto the next priority of its aggregate
+/// cluster: the route's own retry policy with `previous_priorities_retry` forced on, or a
+/// single retry on `5xx` when the route has none.
+fn fallback_retry_policy<'a>(
+    rule: &fp_domain::gateway::route_config::RouteRule,
+    retry: Option<&'a fp_domain::gateway::route_config::RetryPolicy>,
+) -> Option<Cow<'a, fp_domain::gateway::route_config::RetryPolicy>> {
+    use fp_domain::gateway::route_config::RetryPolicy;
+    if rule.action.fallback_pair().is_none() {
+        return retry.map(Cow::Borrowed);
+    }
+    let mut retry = retry.cloned().unwrap_or(RetryPolicy {
+        retry_on: "5xx".into(),
+        num_retries: Some(1),
+        per_try_timeout_secs: None,
+        retriable_status_codes: Vec::new(),
+        previous_priorities_retry: true,
+    });
+    retry.previous_priorities_retry = true;
+    Some(Cow::Owned(retry))
+}
+
 fn route_action_proto(
     rule: &fp_domain::gateway::route_config::RouteRule,
+    retry: Option<&fp_domain::gateway::route_config::RetryPolicy>,
 ) -> DomainResult<rt::route::Action> {
     use fp_domain::gateway::route_config::RedirectResponseCode;
     if let Some(direct) = &rule.action.direct_response {
@@ -1206,7 +1559,9 @@ fn route_action_proto(
         }));
     }
 
-    let cluster_specifier = if let Some(cluster) = &rule.action.cluster {
+    let cluster_specifier = if let Some((primary, fallback)) = rule.action.fallback_pair() {
+        rt::route_action::ClusterSpecifier::Cluster(fallback_aggregate_name(primary, fallback))
+    } else if let Some(cluster) = &rule.action.cluster {
         rt::route_action::ClusterSpecifier::Cluster(cluster.clone())
     } else {
         rt::route_action::ClusterSpecifier::WeightedClusters(rt::WeightedCluster {
@@ -1238,31 +1593,122 @@ fn route_action_proto(
         }
     });
 
+    let host_rewrite_specifier = match &rule.action.host_rewrite_literal {
+        Some(host) => Some(rt::route_action::HostRewriteSpecifier::HostRewriteLiteral(
+            host.clone(),
+        )),
+        None if rule.action.auto_host_rewrite => Some(
+            rt::route_action::HostRewriteSpecifier::AutoHostRewrite(bool_value(true)),
+        ),
+        None => None,
+    };
+
     Ok(rt::route::Action::Route(rt::RouteAction {
         cluster_specifier: Some(cluster_specifier),
         prefix_rewrite: rule.action.prefix_rewrite.clone().unwrap_or_default(),
         path_rewrite_policy,
+        regex_rewrite: rule.action.regex_rewrite.as_ref().map(|rewrite| {
+            matcher_type::RegexMatchAndSubstitute {
+                pattern: Some(safe_regex(&rewrite.pattern)),
+                substitution: rewrite.substitution.clone(),
+            }
+        }),
+        host_rewrite_specifier,
         timeout: Some(duration(rule.action.timeout_secs)),
-        retry_policy: rule.action.retry_policy.as_ref().map(retry_policy_to_proto),
-        rate_limits: rate_limits_to_proto(&rule.action.rate_limits),
+        idle_timeout: rule.action.idle_timeout_secs.map(duration),
+        max_stream_duration: rule.action.max_stream_duration_secs.map(|secs| {
+            rt::route_action::MaxStreamDuration {
+                max_stream_duration: Some(duration(secs)),
+                ..Default::default()
+            }
+        }),
+        request_mirror_policies: rule
+            .action
+            .request_mirror_policies
+            .iter()
+            .map(|mirror| rt::route_action::RequestMirrorPolicy {
+                cluster: mirror.cluster.clone(),
+                runtime_fraction: Some(core::RuntimeFractionalPercent {
+                    default_value: Some(envoy_types::pb::envoy::r#type::v3::FractionalPercent {
+                        numerator: mirror.runtime_fraction,
+                        denominator: 0, // HUNDRED
+                    }),
+                    runtime_key: String::new(),
+                }),
+                ..Default::default()
+            })
+            .collect(),
+        retry_policy: retry.map(retry_policy_to_proto),
+        rate_limits: rate_limits_to_proto(&rule.action.rate_limits)?,
+        hash_policy: rule
+            .action
+            .hash_policy
+            .iter()
+            .map(hash_policy_to_proto)
+            .collect(),
         ..Default::default()
     }))
 }
 
+fn hash_policy_to_proto(
+    policy: &fp_domain::gateway::route_config::HashPolicy,
+) -> rt::route_action::HashPolicy {
+    use fp_domain::gateway::route_config::HashPolicy;
+    use rt::route_action::hash_policy::{self, PolicySpecifier};
+    let (specifier, terminal) = match policy {
+        HashPolicy::Header {
+            header_name,
+            terminal,
+        } => (
+            PolicySpecifier::Header(hash_policy::Header {
+                header_name: header_name.to_ascii_lowercase(),
+                regex_rewrite: None,
+            }),
+            *terminal,
+        ),
+        HashPolicy::Cookie {
+            name,
+            ttl_secs,
+            path,
+            terminal,
+        } => (
+            PolicySpecifier::Cookie(hash_policy::Cookie {
+                name: name.clone(),
+                ttl: ttl_secs.map(duration),
+                path: path.clone().unwrap_or_default(),
+                attributes: Vec::new(),
+            }),
+            *terminal,
+        ),
+        HashPolicy::SourceIp { terminal } => (
+            PolicySpecifier::ConnectionProperties(hash_policy::ConnectionProperties {
+                source_ip: true,
+            }),
+            *terminal,
+        ),
+    };
+    rt::route_action::HashPolicy {
+        policy_specifier: Some(specifier),
+        terminal,
+    }
+}
+
 fn rate_limits_to_proto(
     limits: &[fp_domain::gateway::route_config::RateLimitDefinition],
-) -> Vec<rt::RateLimit> {
+) -> DomainResult<Vec<rt::RateLimit>> {
     limits
         .iter()
-        .map(|limit| rt::RateLimit {
-            stage: limit.stage.map(u32_value),
-            disable_key: limit.disable_key.clone().unwrap_or_default(),
-            actions: limit
-                .actions
-                .iter()
-                .map(rate_limit_action_to_proto)
-                .collect(),
-            ..Default::default()
+        .map(|limit| {
+            Ok(rt::RateLimit {
+                stage: limit.stage.map(u32_value),
+                disable_key: limit.disable_key.clone().unwrap_or_default(),
+                actions: limit
+                    .actions
+                    .iter()
+                    .map(rate_limit_action_to_proto)
+                    .collect::<DomainResult<_>>()?,
+                ..Default::default()
+            })
         })
         .collect()
 }
@@ -1279,7 +1725,8 @@ fn explicit_http1_config() -> upstream_http::http_protocol_options::UpstreamProt
     )
 }
 
-fn explicit_http2_config() -> upstream_http::http_protocol_options::UpstreamProtocolOptions {
+pub(crate) fn explicit_http2_config(
+) -> upstream_http::http_protocol_options::UpstreamProtocolOptions {
     upstream_http::http_protocol_options::UpstreamProtocolOptions::ExplicitHttpConfig(
         upstream_http::http_protocol_options::ExplicitHttpConfig {
             protocol_config: Some(
@@ -1291,9 +1738,33 @@ fn explicit_http2_config() -> upstream_http::http_protocol_options::UpstreamProt
     )
 }
 
+/// HTTP/2 or HTTP/1.1 upstream, whichever ALPN negotiates on each new connection. The
+/// cluster's TLS context offers both protocols (see [`upstream_tls_context`]).
+fn auto_http_config() -> upstream_http::http_protocol_options::UpstreamProtocolOptions {
+    upstream_http::http_protocol_options::UpstreamProtocolOptions::AutoConfig(
+        upstream_http::http_protocol_options::AutoHttpConfig {
+            http_protocol_options: Some(core::Http1ProtocolOptions::default()),
+            http2_protocol_options: Some(core::Http2ProtocolOptions::default()),
+            ..Default::default()
+        },
+    )
+}
+
+/// HTTP/1.1 or HTTP/2 upstream, whichever the downstream connection used. Both option
+/// sets are present so neither protocol falls back to Envoy's unset behaviour.
+fn use_downstream_http_config() -> upstream_http::http_protocol_options::UpstreamProtocolOptions {
+    upstream_http::http_protocol_options::UpstreamProtocolOptions::UseDownstreamProtocolConfig(
+        upstream_http::http_protocol_options::UseDownstreamHttpConfig {
+            http_protocol_options: Some(core::Http1ProtocolOptions::default()),
+            http2_protocol_options: Some(core::Http2ProtocolOptions::default()),
+            ..Default::default()
+        },
+    )
+}
+
 fn rate_limit_action_to_proto(
     action: &fp_domain::gateway::route_config::RateLimitAction,
-) -> rt::rate_limit::Action {
+) -> DomainResult<rt::rate_limit::Action> {
     use fp_domain::gateway::route_config::RateLimitAction;
     let action_specifier = match action {
         RateLimitAction::RequestHeaders {
@@ -1317,10 +1788,30 @@ fn rate_limit_action_to_proto(
                 default_value: String::new(),
             },
         ),
+        RateLimitAction::RemoteAddress => rt::rate_limit::action::ActionSpecifier::RemoteAddress(
+            rt::rate_limit::action::RemoteAddress {},
+        ),
+        RateLimitAction::HeaderValueMatch {
+            descriptor_value,
+            descriptor_key,
+            expect_match,
+            headers,
+        } => rt::rate_limit::action::ActionSpecifier::HeaderValueMatch(
+            rt::rate_limit::action::HeaderValueMatch {
+                descriptor_key: descriptor_key.clone().unwrap_or_default(),
+                descriptor_value: descriptor_value.clone(),
+                expect_match: Some(bool_value(*expect_match)),
+                headers: headers
+                    .iter()
+                    .map(header_match_to_proto)
+                    .collect::<DomainResult<_>>()?,
+                default_value: String::new(),
+            },
+        ),
     };
-    rt::rate_limit::Action {
+    Ok(rt::rate_limit::Action {
         action_specifier: Some(action_specifier),
-    }
+    })
 }
 
 fn retry_policy_to_proto(retry: &fp_domain::gateway::route_config::RetryPolicy) -> rt::RetryPolicy {
@@ -1405,11 +1896,41 @@ fn overrides_to_typed_config(
                     ),
                 );
             }
+            FilterOverride::ExtAuthz(c) => {
+                map.insert(
+                    "envoy.filters.http.ext_authz".to_string(),
+                    any_with_value(
+                        "type.googleapis.com/envoy.extensions.filters.http.ext_authz.v3.ExtAuthzPerRoute",
+                        encode_ext_authz_per_route_deterministic(&ext_authz_per_route_to_proto(c))?,
+                    ),
+                );
+            }
         }
     }
     Ok(map)
 }
 
+fn ext_authz_per_route_to_proto(
+    c: &fp_domain::gateway::filters::ExtAuthzOverride,
+) -> envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3::ExtAuthzPerRoute {
+    use envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3 as ea;
+    let r#override = match &c.check_settings {
+        Some(settings) => ea::ext_authz_per_route::Override::CheckSettings(ea::CheckSettings {
+            context_extensions: settings
+                .context_extensions
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            disable_request_body_buffering: settings.disable_request_body_buffering,
+            ..Default::default()
+        }),
+        None => ea::ext_authz_per_route::Override::Disabled(c.disabled),
+    };
+    ea::ExtAuthzPerRoute {
+        r#override: Some(r#override),
+    }
+}
+
 fn envoy_filter_name(kind: &str) -> DomainResult<&'static str> {
     match kind {
         "cors" => Ok("envoy.filters.http.cors"),
@@ -1420,6 +1941,9 @@ fn envoy_filter_name(kind: &str) -> DomainResult<&'static str> {
         "jwt_auth" => Ok("envoy.filters.http.jwt_authn"),
         "ext_authz" => Ok("envoy.filters.http.ext_authz"),
         "rbac" => Ok("envoy.filters.http.rbac"),
+        "grpc_json_transcoder" => Ok("envoy.filters.http.grpc_json_transcoder"),
+        "custom_response" => Ok("envoy.filters.http.custom_response"),
+        "wasm" => Ok("envoy.filters.http.wasm"),
         other => Err(DomainError::validation(format!(
             "unknown filter type \"{other}\""
         ))),
@@ -1437,17 +1961,35 @@ fn local_rate_limit_to_any(c: &fp_domain::gateway::filters::LocalRateLimitConfig
         }),
         runtime_key: String::new(),
     };
+    use envoy_types::pb::envoy::extensions::common::ratelimit::v3 as common_rl;
+    let bucket = |b: &fp_domain::gateway::filters::TokenBucket| {
+        envoy_types::pb::envoy::r#type::v3::TokenBucket {
+            max_tokens: b.max_tokens,
+            tokens_per_fill: Some(u32_value(b.tokens_per_fill.unwrap_or(b.max_tokens))),
+            fill_interval: Some(millis_duration(b.fill_interval_ms)),
+        }
+    };
     let proto = lrl::LocalRateLimit {
         stat_prefix: c.stat_prefix.clone(),
-        token_bucket: Some(envoy_types::pb::envoy::r#type::v3::TokenBucket {
-            max_tokens: c.token_bucket.max_tokens,
-            tokens_per_fill: Some(u32_value(
-                c.token_bucket
-                    .tokens_per_fill
-                    .unwrap_or(c.token_bucket.max_tokens),
-            )),
-            fill_interval: Some(millis_duration(c.token_bucket.fill_interval_ms)),
-        }),
+        token_bucket: Some(bucket(&c.token_bucket)),
+        // Matched against the descriptors the route's `rate_limits` actions generate
+        // (stage 0, the filter's default stage).
+        descriptors: c
+            .descriptors
+            .iter()
+            .map(|d| common_rl::LocalRateLimitDescriptor {
+                entries: d
+                    .entries
+                    .iter()
+                    .map(|e| common_rl::rate_limit_descriptor::Entry {
+                        key: e.key.clone(),
+                        value: e.value.clone(),
+                    })
+                    .collect(),
+                token_bucket: d.token_bucket.as_ref().map(bucket),
+                shadow_mode: false,
+            })
+            .collect(),
         status: c
             .status_code
             .map(|code| envoy_types::pb::envoy::r#type::v3::HttpStatus {
@@ -1464,17 +2006,102 @@ fn local_rate_limit_to_any(c: &fp_domain::gateway::filters::LocalRateLimitConfig
     )
 }
 
-fn cors_policy_to_proto(
-    c: &fp_domain::gateway::filters::CorsConfig,
-) -> envoy_types::pb::envoy::extensions::filters::http::cors::v3::CorsPolicy {
-    use envoy_types::pb::envoy::r#type::matcher::v3 as sm;
-    use fp_domain::gateway::filters::OriginMatcher;
-    let allow_origin_string_match = c
-        .allow_origin
-        .iter()
-        .map(|m| {
-            let pattern = match m {
-                OriginMatcher::Exact { value } => {
+/// Translate one listener network filter. The HCM marker never reaches here — the listener
+/// always appends its own HCM last.
+fn network_filter_to_proto(filter: &NetworkFilterSpec) -> Option<lst::Filter> {
+    use envoy_types::pb::envoy::extensions::filters::network::local_ratelimit::v3 as net_lrl;
+
+    match filter {
+        NetworkFilterSpec::LocalRateLimit(c) => {
+            let proto = net_lrl::LocalRateLimit {
+                stat_prefix: c.stat_prefix.clone(),
+                token_bucket: Some(envoy_type::TokenBucket {
+                    max_tokens: c.token_bucket.max_tokens,
+                    tokens_per_fill: Some(u32_value(
+                        c.token_bucket
+                            .tokens_per_fill
+                            .unwrap_or(c.token_bucket.max_tokens),
+                    )),
+                    fill_interval: Some(millis_duration(c.token_bucket.fill_interval_ms)),
+                }),
+                ..Default::default()
+            };
+            Some(lst::Filter {
+                name: "envoy.filters.network.local_ratelimit".to_string(),
+                config_type: Some(lst::filter::ConfigType::TypedConfig(any(
+                    "type.googleapis.com/envoy.extensions.filters.network.local_ratelimit.v3.LocalRateLimit",
+                    &proto,
+                ))),
+            })
+        }
+        NetworkFilterSpec::HttpConnectionManager => None,
+    }
+}
+
+fn listener_filter_to_proto(filter: &ListenerFilterSpec) -> lst::ListenerFilter {
+    use envoy_types::pb::envoy::extensions::filters::listener::original_dst::v3 as original_dst;
+    use envoy_types::pb::envoy::extensions::filters::listener::proxy_protocol::v3 as proxy_protocol;
+    use envoy_types::pb::envoy::extensions::filters::listener::tls_inspector::v3 as tls_inspector;
+
+    match filter {
+        ListenerFilterSpec::ProxyProtocol(c) => {
+            // Envoy lists the versions it refuses; the spec lists the ones it accepts.
+            let disallowed_versions = if c.versions.is_empty() {
+                Vec::new()
+            } else {
+                [ProxyProtocolVersion::V1, ProxyProtocolVersion::V2]
+                    .into_iter()
+                    .filter(|v| !c.versions.contains(v))
+                    .map(|v| match v {
+                        ProxyProtocolVersion::V1 => core::proxy_protocol_config::Version::V1,
+                        ProxyProtocolVersion::V2 => core::proxy_protocol_config::Version::V2,
+                    } as i32)
+                    .collect()
+            };
+            lst::ListenerFilter {
+                name: "envoy.filters.listener.proxy_protocol".to_string(),
+                config_type: Some(lst::listener_filter::ConfigType::TypedConfig(any(
+                    "type.googleapis.com/envoy.extensions.filters.listener.proxy_protocol.v3.ProxyProtocol",
+                    &proxy_protocol::ProxyProtocol {
+                        allow_requests_without_proxy_protocol: c
+                            .allow_requests_without_proxy_protocol,
+                        disallowed_versions,
+                        ..Default::default()
+                    },
+                ))),
+                ..Default::default()
+            }
+        }
+        ListenerFilterSpec::TlsInspector => lst::ListenerFilter {
+            name: "envoy.filters.listener.tls_inspector".to_string(),
+            config_type: Some(lst::listener_filter::ConfigType::TypedConfig(any(
+                "type.googleapis.com/envoy.extensions.filters.listener.tls_inspector.v3.TlsInspector",
+                &tls_inspector::TlsInspector::default(),
+            ))),
+            ..Default::default()
+        },
+        ListenerFilterSpec::OriginalDst => lst::ListenerFilter {
+            name: "envoy.filters.listener.original_dst".to_string(),
+            config_type: Some(lst::listener_filter::ConfigType::TypedConfig(any(
+                "type.googleapis.com/envoy.extensions.filters.listener.original_dst.v3.OriginalDst",
+                &original_dst::OriginalDst::default(),
+            ))),
+            ..Default::default()
+        },
+    }
+}
+
+fn cors_policy_to_proto(
+    c: &fp_domain::gateway::filters::CorsConfig,
+) -> envoy_types::pb::envoy::extensions::filters::http::cors::v3::CorsPolicy {
+    use envoy_types::pb::envoy::r#type::matcher::v3 as sm;
+    use fp_domain::gateway::filters::OriginMatcher;
+    let allow_origin_string_match = c
+        .allow_origin
+        .iter()
+        .map(|m| {
+            let pattern = match m {
+                OriginMatcher::Exact { value } => {
                     sm::string_matcher::MatchPattern::Exact(value.clone())
                 }
                 OriginMatcher::Prefix { value } => {
@@ -1486,6 +2113,9 @@ fn cors_policy_to_proto(
                 OriginMatcher::Contains { value } => {
                     sm::string_matcher::MatchPattern::Contains(value.clone())
                 }
+                OriginMatcher::SafeRegex { regex } => {
+                    sm::string_matcher::MatchPattern::SafeRegex(safe_regex(regex))
+                }
             };
             sm::StringMatcher {
                 match_pattern: Some(pattern),
@@ -1516,6 +2146,7 @@ const ROUTER_TYPE_URL: &str = "type.googleapis.com/envoy.extensions.filters.http
 /// the router is appended by the caller.
 fn http_filter_to_proto(
     entry: &fp_domain::gateway::filters::HttpFilterEntry,
+    artifacts: &FilterArtifacts,
 ) -> DomainResult<hcm::HttpFilter> {
     use envoy_types::pb::envoy::extensions::filters::http::header_mutation::v3 as hm;
     use fp_domain::gateway::filters::HttpFilterSpec;
@@ -1676,6 +2307,35 @@ fn http_filter_to_proto(
                 &global_rate_limit_to_proto(c),
             ),
         ),
+        HttpFilterSpec::GrpcJsonTranscoder(c) => (
+            "envoy.filters.http.grpc_json_transcoder",
+            any(
+                "type.googleapis.com/envoy.extensions.filters.http.grpc_json_transcoder.v3.GrpcJsonTranscoder",
+                &grpc_json_transcoder_to_proto(c, &artifacts.descriptor_sets)?,
+            ),
+        ),
+        HttpFilterSpec::CustomResponse(c) => (
+            "envoy.filters.http.custom_response",
+            any(
+                "type.googleapis.com/envoy.extensions.filters.http.custom_response.v3.CustomResponse",
+                &custom_response_to_proto(c),
+            ),
+        ),
+        HttpFilterSpec::Wasm(c) => (
+            "envoy.filters.http.wasm",
+            any(
+                "type.googleapis.com/envoy.extensions.filters.http.wasm.v3.Wasm",
+                &wasm_to_proto(c, &artifacts.wasm_modules)?,
+            ),
+        ),
+        // The listener write path replaces presets with their filter, so a stored spec
+        // never carries one; refuse rather than emit a chain with a hole in it.
+        HttpFilterSpec::Preset(r) => {
+            return Err(DomainError::internal(format!(
+                "filter preset \"{}\" was not instantiated before translation",
+                r.name
+            )))
+        }
     };
     Ok(hcm::HttpFilter {
         name: name.to_string(),
@@ -1685,6 +2345,229 @@ fn http_filter_to_proto(
     })
 }
 
+/// A proxy-wasm plugin with its own VM (empty `vm_id`: Envoy shares VMs by code hash). The
+/// plugin configuration is a `StringValue`, which Envoy hands to the module unwrapped.
+/// Registry modules are inlined like uploaded descriptor sets; a missing one fails translation.
+fn wasm_to_proto(
+    c: &fp_domain::gateway::filters::WasmConfig,
+    modules: &WasmModules,
+) -> DomainResult<envoy_types::pb::envoy::extensions::filters::http::wasm::v3::Wasm> {
+    use envoy_types::pb::envoy::extensions::filters::http::wasm::v3 as wasm_filter;
+    use envoy_types::pb::envoy::extensions::wasm::v3 as wasm;
+    use fp_domain::gateway::filters::WasmModuleSource;
+    let code = match &c.module {
+        WasmModuleSource::Inline { wasm_base64 } => {
+            core::async_data_source::Specifier::Local(core::DataSource {
+                // Validation decoded it already; an undecodable module cannot be stored.
+                specifier: Some(core::data_source::Specifier::InlineBytes(
+                    base64::engine::general_purpose::STANDARD
+                        .decode(wasm_base64)
+                        .unwrap_or_default(),
+                )),
+                ..Default::default()
+            })
+        }
+        WasmModuleSource::Remote {
+            uri,
+            cluster,
+            sha256,
+            timeout_ms,
+        } => core::async_data_source::Specifier::Remote(core::RemoteDataSource {
+            http_uri: Some(core::HttpUri {
+                uri: uri.clone(),
+                timeout: Some(millis_duration(*timeout_ms)),
+                http_upstream_type: Some(core::http_uri::HttpUpstreamType::Cluster(
+                    cluster.clone(),
+                )),
+            }),
+            sha256: sha256.clone(),
+            retry_policy: None,
+        }),
+        WasmModuleSource::Registry { name, version } => {
+            let bytes = modules.get(&(name.clone(), *version)).ok_or_else(|| {
+                DomainError::validation(format!(
+                    "wasm: module \"{name}\" v{version} is not available"
+                ))
+            })?;
+            core::async_data_source::Specifier::Local(core::DataSource {
+                specifier: Some(core::data_source::Specifier::InlineBytes(bytes.clone())),
+                ..Default::default()
+            })
+        }
+    };
+    Ok(wasm_filter::Wasm {
+        config: Some(wasm::PluginConfig {
+            name: "wasm".to_string(),
+            root_id: c.root_id.clone().unwrap_or_default(),
+            configuration: c.config.as_ref().map(|config| {
+                any(
+                    "type.googleapis.com/google.protobuf.StringValue",
+                    &wkt::StringValue {
+                        value: config.clone(),
+                    },
+                )
+            }),
+            failure_policy: if c.fail_open {
+                wasm::FailurePolicy::FailOpen as i32
+            } else {
+                wasm::FailurePolicy::FailClosed as i32
+            },
+            vm: Some(wasm::plugin_config::Vm::VmConfig(wasm::VmConfig {
+                runtime: c.runtime.envoy_name().to_string(),
+                code: Some(core::AsyncDataSource {
+                    specifier: Some(code),
+                }),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }),
+    })
+}
+
+/// One matcher-list entry per configured status match, each answering with a
+/// `LocalResponsePolicy`. Validation guarantees the matches are disjoint, so Envoy's
+/// first-match semantics never depend on their order.
+fn custom_response_to_proto(
+    c: &fp_domain::gateway::filters::CustomResponseConfig,
+) -> envoy_types::pb::envoy::extensions::filters::http::custom_response::v3::CustomResponse {
+    use envoy_types::pb::envoy::extensions::filters::http::custom_response::v3 as cr;
+    use envoy_types::pb::envoy::extensions::http::custom_response::local_response_policy::v3 as lrp;
+    use envoy_types::pb::xds::core::v3 as xds_core;
+    use envoy_types::pb::xds::r#type::matcher::v3 as xm;
+    use xm::matcher::matcher_list::{predicate, FieldMatcher, Predicate};
+
+    let matchers = c
+        .matchers
+        .iter()
+        .map(|m| {
+            let (start, end) = m.status.bounds();
+            let match_pattern = if start == end {
+                xm::string_matcher::MatchPattern::Exact(start.to_string())
+            } else {
+                xm::string_matcher::MatchPattern::SafeRegex(xm::RegexMatcher {
+                    regex: status_range_regex(start, end),
+                    engine_type: Some(xm::regex_matcher::EngineType::GoogleRe2(
+                        xm::regex_matcher::GoogleRe2::default(),
+                    )),
+                })
+            };
+            let policy = lrp::LocalResponsePolicy {
+                body: m.body.as_ref().map(|body| core::DataSource {
+                    specifier: Some(core::data_source::Specifier::InlineString(body.clone())),
+                    ..Default::default()
+                }),
+                status_code: m.status_code.map(|code| u32_value(u32::from(code))),
+                response_headers_to_add: m
+                    .response_headers_to_add
+                    .iter()
+                    .map(|hv| core::HeaderValueOption {
+                        header: Some(core::HeaderValue {
+                            key: hv.key.clone(),
+                            value: hv.value.clone(),
+                            ..Default::default()
+                        }),
+                        append_action: if hv.append {
+                            core::header_value_option::HeaderAppendAction::AppendIfExistsOrAdd
+                                as i32
+                        } else {
+                            core::header_value_option::HeaderAppendAction::OverwriteIfExistsOrAdd
+                                as i32
+                        },
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            };
+            FieldMatcher {
+                predicate: Some(Predicate {
+                    match_type: Some(predicate::MatchType::SinglePredicate(
+                        predicate::SinglePredicate {
+                            input: Some(xds_core::TypedExtensionConfig {
+                                name: "status_code".to_string(),
+                                typed_config: Some(any(
+                                    "type.googleapis.com/envoy.type.matcher.v3.HttpResponseStatusCodeMatchInput",
+                                    &envoy_types::pb::envoy::r#type::matcher::v3::HttpResponseStatusCodeMatchInput {},
+                                )),
+                            }),
+                            matcher: Some(predicate::single_predicate::Matcher::ValueMatch(
+                                xm::StringMatcher {
+                                    match_pattern: Some(match_pattern),
+                                    ..Default::default()
+                                },
+                            )),
+                        },
+                    )),
+                }),
+                on_match: Some(xm::matcher::OnMatch {
+                    on_match: Some(xm::matcher::on_match::OnMatch::Action(
+                        xds_core::TypedExtensionConfig {
+                            name: "local_response".to_string(),
+                            typed_config: Some(any(
+                                "type.googleapis.com/envoy.extensions.http.custom_response.local_response_policy.v3.LocalResponsePolicy",
+                                &policy,
+                            )),
+                        },
+                    )),
+                    ..Default::default()
+                }),
+            }
+        })
+        .collect();
+    cr::CustomResponse {
+        custom_response_matcher: Some(xm::Matcher {
+            matcher_type: Some(xm::matcher::MatcherType::MatcherList(
+                xm::matcher::MatcherList { matchers },
+            )),
+            ..Default::default()
+        }),
+    }
+}
+
+/// Anchored RE2 alternation matching the status codes `start..=end`, folding whole
+/// hundreds (`5\d\d`) and tens (`40\d`) so a class-wide range stays one short term.
+fn status_range_regex(start: u16, end: u16) -> String {
+    let mut terms = Vec::new();
+    let mut code = start;
+    while code <= end {
+        if code.is_multiple_of(100) && code + 99 <= end {
+            terms.push(format!("{}\\d\\d", code / 100));
+            code += 100;
+        } else if code.is_multiple_of(10) && code + 9 <= end {
+            terms.push(format!("{}\\d", code / 10));
+            code += 10;
+        } else {
+            terms.push(code.to_string());
+            code += 1;
+        }
+    }
+    format!("^(?:{})$", terms.join("|"))
+}
+
+/// The descriptor set is inlined as `proto_descriptor_bin`: the CP is the only place the
+/// bytes live, so there is no file for Envoy to read.
+fn grpc_json_transcoder_to_proto(
+    c: &fp_domain::gateway::filters::GrpcJsonTranscoderConfig,
+    descriptors: &ProtoDescriptorSets,
+) -> DomainResult<grpc_json_transcoder::GrpcJsonTranscoder> {
+    let bytes = descriptors.get(&c.proto_descriptor_id).ok_or_else(|| {
+        DomainError::validation(format!(
+            "grpc_json_transcoder: proto descriptor {} is not available",
+            c.proto_descriptor_id
+        ))
+    })?;
+    Ok(grpc_json_transcoder::GrpcJsonTranscoder {
+        services: c.services.clone(),
+        ignore_unknown_query_parameters: c.ignore_unknown_query_parameters,
+        convert_grpc_status: c.convert_grpc_status,
+        descriptor_set: Some(
+            grpc_json_transcoder::grpc_json_transcoder::DescriptorSet::ProtoDescriptorBin(
+                bytes.clone(),
+            ),
+        ),
+        ..Default::default()
+    })
+}
+
 fn global_rate_limit_to_proto(
     c: &fp_domain::gateway::filters::GlobalRateLimitConfig,
 ) -> rate_limit_filter::RateLimit {
@@ -1726,7 +2609,7 @@ fn global_rate_limit_to_proto(
         status_on_error: c
             .status_on_error
             .map(|code| envoy_type::HttpStatus { code: code as i32 }),
-        stat_prefix: c.stat_prefix.clone().unwrap_or_default(),
+        stat_prefix: c.effective_stat_prefix(),
         ..Default::default()
     }
 }
@@ -1842,8 +2725,20 @@ fn ext_authz_to_proto(
     c: &fp_domain::gateway::filters::ExtAuthzConfig,
 ) -> envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3::ExtAuthz {
     use envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3 as ea;
-    ea::ExtAuthz {
-        services: Some(ea::ext_authz::Services::GrpcService(core::GrpcService {
+    use envoy_types::pb::envoy::r#type::matcher::v3 as sm;
+    let services = match &c.http_service {
+        Some(http) => ea::ext_authz::Services::HttpService(ea::HttpService {
+            server_uri: Some(core::HttpUri {
+                uri: http.uri.clone(),
+                timeout: Some(millis_duration(c.timeout_ms)),
+                http_upstream_type: Some(core::http_uri::HttpUpstreamType::Cluster(
+                    c.cluster.clone(),
+                )),
+            }),
+            path_prefix: http.path_prefix().to_string(),
+            ..Default::default()
+        }),
+        None => ea::ext_authz::Services::GrpcService(core::GrpcService {
             target_specifier: Some(core::grpc_service::TargetSpecifier::EnvoyGrpc(
                 core::grpc_service::EnvoyGrpc {
                     cluster_name: c.cluster.clone(),
@@ -1852,7 +2747,27 @@ fn ext_authz_to_proto(
             )),
             timeout: Some(millis_duration(c.timeout_ms)),
             ..Default::default()
-        })),
+        }),
+    };
+    // Filter-level `allowed_headers` (the per-service `AuthorizationRequest` field is
+    // deprecated); only HTTP mode exposes it.
+    let allowed_headers = c
+        .http_service
+        .as_ref()
+        .filter(|http| !http.allowed_headers.is_empty())
+        .map(|http| sm::ListStringMatcher {
+            patterns: http
+                .allowed_headers
+                .iter()
+                .map(|name| sm::StringMatcher {
+                    match_pattern: Some(sm::string_matcher::MatchPattern::Exact(name.clone())),
+                    ignore_case: true,
+                })
+                .collect(),
+        });
+    ea::ExtAuthz {
+        services: Some(services),
+        allowed_headers,
         failure_mode_allow: c.failure_mode_allow,
         include_peer_certificate: c.include_peer_certificate,
         ..Default::default()
@@ -1992,6 +2907,7 @@ fn route_match_proto(
         headers: rule
             .headers
             .iter()
+            .chain(rule.method_matcher().as_ref())
             .map(header_match_to_proto)
             .collect::<DomainResult<Vec<_>>>()?,
         query_parameters: rule
@@ -1999,10 +2915,33 @@ fn route_match_proto(
             .iter()
             .map(query_match_to_proto)
             .collect::<DomainResult<Vec<_>>>()?,
+        runtime_fraction: rule
+            .runtime_fraction
+            .as_ref()
+            .map(runtime_fraction_to_proto),
         ..Default::default()
     })
 }
 
+fn runtime_fraction_to_proto(
+    fraction: &fp_domain::gateway::route_config::RuntimeFractionMatch,
+) -> core::RuntimeFractionalPercent {
+    use envoy_type::fractional_percent::DenominatorType;
+    use fp_domain::gateway::route_config::FractionDenominator;
+    let denominator = match fraction.denominator {
+        FractionDenominator::Hundred => DenominatorType::Hundred,
+        FractionDenominator::TenThousand => DenominatorType::TenThousand,
+        FractionDenominator::Million => DenominatorType::Million,
+    };
+    core::RuntimeFractionalPercent {
+        default_value: Some(envoy_type::FractionalPercent {
+            numerator: fraction.numerator,
+            denominator: denominator as i32,
+        }),
+        runtime_key: fraction.runtime_key.clone().unwrap_or_default(),
+    }
+}
+
 fn route_path_specifier(matcher: &PathMatch) -> rt::route_match::PathSpecifier {
     match matcher {
         PathMatch::Prefix { prefix } => rt::route_match::PathSpecifier::Prefix(prefix.clone()),
@@ -2148,12 +3087,16 @@ fn header_mutation_entry(
     _removal: bool,
 ) -> envoy_types::pb::envoy::config::common::mutation_rules::v3::HeaderMutation {
     use envoy_types::pb::envoy::config::common::mutation_rules::v3 as mr;
+    let value = match &hv.when {
+        Some(when) => conditional_header_value(when, &hv.value),
+        None => hv.value.clone(),
+    };
     mr::HeaderMutation {
         action: Some(mr::header_mutation::Action::Append(
             core::HeaderValueOption {
                 header: Some(core::HeaderValue {
                     key: hv.key.clone(),
-                    value: hv.value.clone(),
+                    value,
                     ..Default::default()
                 }),
                 append_action: if hv.append {
@@ -2167,6 +3110,29 @@ fn header_mutation_entry(
     }
 }
 
+/// `%CEL(cond ? 'value' : '')%`: a miss yields an empty value, which Envoy drops instead of
+/// adding the header. Validation keeps `value` free of `%`, so it cannot end the operator.
+fn conditional_header_value(
+    when: &fp_domain::gateway::filters::HeaderCondition,
+    value: &str,
+) -> String {
+    let mut conditions = Vec::new();
+    if let Some(status) = when.response_code {
+        conditions.push(match status.bounds() {
+            (start, end) if start == end => format!("response.code == {start}"),
+            (start, end) => format!("(response.code >= {start} && response.code <= {end})"),
+        });
+    }
+    if let Some(name) = &when.request_header_present {
+        conditions.push(format!(
+            "'{}' in request.headers",
+            name.to_ascii_lowercase()
+        ));
+    }
+    let literal = value.replace('\\', "\\\\").replace('\'', "\\'");
+    format!("%CEL({} ? '{literal}' : '')%", conditions.join(" && "))
+}
+
 fn header_removal_entry(
     key: &str,
 ) -> envoy_types::pb::envoy::config::common::mutation_rules::v3::HeaderMutation {
@@ -2193,6 +3159,19 @@ pub fn listener_to_proto_with_learning_and_ai(
     spec: &ListenerSpec,
     captures: &[LearningCaptureInjection],
     ai: Option<&AiProcessorMetadata>,
+) -> DomainResult<lst::Listener> {
+    listener_to_proto_with_artifacts(name, spec, captures, ai, &FilterArtifacts::default())
+}
+
+/// Full listener translation: `artifacts` carries the team's uploaded descriptor sets for
+/// `grpc_json_transcoder` filters and registry modules for `wasm` filters (a filter whose
+/// upload is missing fails translation).
+pub fn listener_to_proto_with_artifacts(
+    name: &str,
+    spec: &ListenerSpec,
+    captures: &[LearningCaptureInjection],
+    ai: Option<&AiProcessorMetadata>,
+    artifacts: &FilterArtifacts,
 ) -> DomainResult<lst::Listener> {
     let route_config_name = spec.route_config.clone().ok_or_else(|| {
         DomainError::validation(format!(
@@ -2203,7 +3182,7 @@ pub fn listener_to_proto_with_learning_and_ai(
     // Chain: declared filters in order, router appended last (spec/04 §4.2).
     let mut http_filters = Vec::with_capacity(spec.http_filters.len() + captures.len() + 1);
     for entry in &spec.http_filters {
-        http_filters.push(http_filter_to_proto(entry)?);
+        http_filters.push(http_filter_to_proto(entry, artifacts)?);
     }
     if ai.is_some() {
         http_filters.push(ai_ext_proc_filter(ai));
@@ -2230,12 +3209,13 @@ pub fn listener_to_proto_with_learning_and_ai(
             },
         )),
         http_filters,
-        access_log: access_logs_to_proto(&spec.access_logs)
+        access_log: access_logs_to_proto(&spec.access_logs)?
             .into_iter()
             .chain(captures.iter().map(learning_access_log))
             .collect(),
         generate_request_id: Some(bool_value(true)),
         always_set_request_id_in_response: true,
+        tracing: spec.tracing.as_ref().map(tracing_to_proto),
         ..Default::default()
     };
     if ai.is_some() {
@@ -2262,26 +3242,71 @@ pub fn listener_to_proto_with_learning_and_ai(
                 cidr_ranges: vec![cidr_range("240.0.0.0/4")],
             });
     }
-    let transport_socket = spec
-        .tls_context
-        .as_ref()
-        .map(downstream_tls_transport_socket)
-        .transpose()?;
+    // HTTP/3 listeners bind UDP and terminate QUIC; validation guarantees their TLS context.
+    let http3 = spec.protocol == ListenerProtocol::Http3;
+    if http3 {
+        manager.http3_protocol_options = Some(core::Http3ProtocolOptions::default());
+    }
+    if let Some(limits) = &spec.http_limits {
+        manager.max_request_headers_kb = limits.max_request_headers_kb.map(u32_value);
+        if limits.max_requests_per_connection.is_some() || limits.idle_timeout_secs.is_some() {
+            manager.common_http_protocol_options = Some(core::HttpProtocolOptions {
+                idle_timeout: limits.idle_timeout_secs.map(duration),
+                max_requests_per_connection: limits.max_requests_per_connection.map(u32_value),
+                ..Default::default()
+            });
+        }
+    }
+    let transport_socket = match &spec.tls_context {
+        Some(config) if http3 => Some(downstream_quic_transport_socket(config)?),
+        Some(config) => Some(downstream_tls_transport_socket(config)?),
+        None => None,
+    };
+    let mut address = socket_address(&spec.address, spec.port);
+    if let (true, Some(core::address::Address::SocketAddress(socket))) =
+        (http3, address.address.as_mut())
+    {
+        socket.protocol = core::socket_address::Protocol::Udp as i32;
+    }
 
     Ok(lst::Listener {
         name: name.to_string(),
-        address: Some(socket_address(&spec.address, spec.port)),
+        address: Some(address),
+        listener_filters: spec
+            .listener_filters
+            .iter()
+            .map(listener_filter_to_proto)
+            .collect(),
+        udp_listener_config: http3.then(|| lst::UdpListenerConfig {
+            quic_options: Some(lst::QuicProtocolOptions::default()),
+            ..Default::default()
+        }),
         filter_chains: vec![lst::FilterChain {
-            filters: vec![lst::Filter {
-                name: "envoy.filters.network.http_connection_manager".to_string(),
-                config_type: Some(lst::filter::ConfigType::TypedConfig(any(
-                    HCM_TYPE_URL,
-                    &manager,
-                ))),
-            }],
+            // Network filters in declared order, the HCM always last.
+            filters: spec
+                .network_filters
+                .iter()
+                .filter_map(network_filter_to_proto)
+                .chain(std::iter::once(lst::Filter {
+                    name: "envoy.filters.network.http_connection_manager".to_string(),
+                    config_type: Some(lst::filter::ConfigType::TypedConfig(any(
+                        HCM_TYPE_URL,
+                        &manager,
+                    ))),
+                }))
+                .collect(),
             transport_socket,
             ..Default::default()
         }],
+        connection_balance_config: spec.exact_balance.then_some(
+            lst::listener::ConnectionBalanceConfig {
+                balance_type: Some(
+                    lst::listener::connection_balance_config::BalanceType::ExactBalance(
+                        lst::listener::connection_balance_config::ExactBalance {},
+                    ),
+                ),
+            },
+        ),
         ..Default::default()
     })
 }
@@ -2493,44 +3518,194 @@ fn ext_proc_filter(capture: &LearningCaptureInjection) -> hcm::HttpFilter {
 
 fn access_logs_to_proto(
     logs: &[fp_domain::gateway::listener::AccessLogConfig],
-) -> Vec<accesslog::AccessLog> {
+) -> DomainResult<Vec<accesslog::AccessLog>> {
     logs.iter()
         .map(|log| {
-            let access_log_format = log.text_format.as_ref().map(|format| {
-                file_accesslog::file_access_log::AccessLogFormat::LogFormat(
+            if let Some(otel) = &log.otel {
+                return Ok(otel_access_log(log, otel));
+            }
+            let access_log_format = if let Some(fields) = &log.json_format {
+                Some(file_accesslog::file_access_log::AccessLogFormat::LogFormat(
                     core::SubstitutionFormatString {
-                        format: Some(core::substitution_format_string::Format::TextFormatSource(
-                            core::DataSource {
-                                specifier: Some(core::data_source::Specifier::InlineString(
-                                    format.clone(),
-                                )),
-                                ..Default::default()
+                        format: Some(core::substitution_format_string::Format::JsonFormat(
+                            wkt::Struct {
+                                fields: fields
+                                    .iter()
+                                    .map(|(key, value)| {
+                                        (
+                                            key.clone(),
+                                            wkt::Value {
+                                                kind: Some(wkt::value::Kind::StringValue(
+                                                    value.clone(),
+                                                )),
+                                            },
+                                        )
+                                    })
+                                    .collect(),
                             },
                         )),
                         ..Default::default()
                     },
-                )
-            });
+                ))
+            } else {
+                log.text_format.as_ref().map(|format| {
+                    file_accesslog::file_access_log::AccessLogFormat::LogFormat(
+                        core::SubstitutionFormatString {
+                            format: Some(
+                                core::substitution_format_string::Format::TextFormatSource(
+                                    core::DataSource {
+                                        specifier: Some(
+                                            core::data_source::Specifier::InlineString(
+                                                format.clone(),
+                                            ),
+                                        ),
+                                        ..Default::default()
+                                    },
+                                ),
+                            ),
+                            ..Default::default()
+                        },
+                    )
+                })
+            };
             let file = file_accesslog::FileAccessLog {
                 path: log.path.clone(),
                 access_log_format,
             };
-            accesslog::AccessLog {
+            // A JSON format is a protobuf Struct (a HashMap): encode it through the sorted
+            // mirror so the listener bytes, and the config version, do not churn.
+            let value = match &log.json_format {
+                Some(fields) => verified_stable_encode(
+                    "JSON access log",
+                    &file,
+                    StableJsonFileAccessLog {
+                        path: log.path.clone(),
+                        log_format: Some(StableJsonFormatString {
+                            json_format: Some(StableStruct {
+                                fields: fields
+                                    .iter()
+                                    .map(|(key, value)| {
+                                        (
+                                            key.clone(),
+                                            StableStringValue {
+                                                string_value: value.clone(),
+                                            },
+                                        )
+                                    })
+                                    .collect(),
+                            }),
+                        }),
+                    },
+                )?,
+                None => file.encode_to_vec(),
+            };
+            Ok(accesslog::AccessLog {
                 name: "envoy.access_loggers.file".to_string(),
                 filter: None,
-                config_type: Some(accesslog::access_log::ConfigType::TypedConfig(any(
-                    "type.googleapis.com/envoy.extensions.access_loggers.file.v3.FileAccessLog",
-                    &file,
-                ))),
-            }
+                config_type: Some(accesslog::access_log::ConfigType::TypedConfig(
+                    any_with_value(
+                        "type.googleapis.com/envoy.extensions.access_loggers.file.v3.FileAccessLog",
+                        value,
+                    ),
+                )),
+            })
         })
         .collect()
 }
 
+/// Envoy's `open_telemetry` access logger exporting over OTLP/gRPC to the collector cluster.
+/// `text_format` becomes the record body and `json_format` its attributes. Every list here
+/// is built from a `BTreeMap`, so the encoding is already deterministic.
+fn otel_access_log(
+    log: &fp_domain::gateway::listener::AccessLogConfig,
+    otel: &fp_domain::gateway::listener::OtelAccessLogConfig,
+) -> accesslog::AccessLog {
+    let string_value = |value: &str| otel_common::AnyValue {
+        value: Some(otel_common::any_value::Value::StringValue(
+            value.to_string(),
+        )),
+    };
+    let key_values = |pairs: &BTreeMap<String, String>| otel_common::KeyValueList {
+        values: pairs
+            .iter()
+            .map(|(key, value)| otel_common::KeyValue {
+                key: key.clone(),
+                value: Some(string_value(value)),
+                ..Default::default()
+            })
+            .collect(),
+    };
+    // `common_config` is deprecated in favour of `grpc_service`, which older Envoy releases
+    // do not read; every release still accepts `common_config`.
+    #[allow(deprecated)]
+    let config = otel_accesslog::OpenTelemetryAccessLogConfig {
+        common_config: Some(grpc_accesslog::CommonGrpcAccessLogConfig {
+            log_name: OTEL_ACCESS_LOG_NAME.to_string(),
+            grpc_service: Some(core::GrpcService {
+                target_specifier: Some(core::grpc_service::TargetSpecifier::EnvoyGrpc(
+                    core::grpc_service::EnvoyGrpc {
+                        cluster_name: otel.cluster.clone(),
+                        ..Default::default()
+                    },
+                )),
+                ..Default::default()
+            }),
+            transport_api_version: core::ApiVersion::V3 as i32,
+            ..Default::default()
+        }),
+        resource_attributes: (!otel.resource_attributes.is_empty())
+            .then(|| key_values(&otel.resource_attributes)),
+        body: log.text_format.as_deref().map(string_value),
+        attributes: log.json_format.as_ref().map(key_values),
+        ..Default::default()
+    };
+    accesslog::AccessLog {
+        name: "envoy.access_loggers.open_telemetry".to_string(),
+        filter: None,
+        config_type: Some(accesslog::access_log::ConfigType::TypedConfig(any(
+            "type.googleapis.com/envoy.extensions.access_loggers.open_telemetry.v3.OpenTelemetryAccessLogConfig",
+            &config,
+        ))),
+    }
+}
+
+fn tracing_to_proto(
+    tracing: &fp_domain::gateway::listener::ListenerTracingConfig,
+) -> hcm::http_connection_manager::Tracing {
+    use envoy_types::pb::envoy::config::trace::v3 as trace;
+    let TracingProvider::Opentelemetry(otel) = &tracing.provider;
+    hcm::http_connection_manager::Tracing {
+        random_sampling: tracing
+            .sampling_percent
+            .map(|value| envoy_type::Percent { value }),
+        provider: Some(trace::tracing::Http {
+            name: "envoy.tracers.opentelemetry".to_string(),
+            config_type: Some(trace::tracing::http::ConfigType::TypedConfig(any(
+                "type.googleapis.com/envoy.config.trace.v3.OpenTelemetryConfig",
+                &trace::OpenTelemetryConfig {
+                    grpc_service: Some(core::GrpcService {
+                        target_specifier: Some(core::grpc_service::TargetSpecifier::EnvoyGrpc(
+                            core::grpc_service::EnvoyGrpc {
+                                cluster_name: otel.cluster.clone(),
+                                ..Default::default()
+                            },
+                        )),
+                        ..Default::default()
+                    }),
+                    service_name: otel.service_name.clone(),
+                    ..Default::default()
+                },
+            ))),
+        }),
+        ..Default::default()
+    }
+}
+
 fn listener_codec_type(spec: &ListenerSpec) -> hcm::http_connection_manager::CodecType {
     match spec.protocol {
         ListenerProtocol::Http2 => hcm::http_connection_manager::CodecType::Http2,
         ListenerProtocol::Https => hcm::http_connection_manager::CodecType::Auto,
+        ListenerProtocol::Http3 => hcm::http_connection_manager::CodecType::Http3,
         ListenerProtocol::Http if spec.tls_context.is_some() => {
             hcm::http_connection_manager::CodecType::Auto
         }
@@ -2541,6 +3716,33 @@ fn listener_codec_type(spec: &ListenerSpec) -> hcm::http_connection_manager::Cod
 fn downstream_tls_transport_socket(
     config: &ListenerTlsConfig,
 ) -> DomainResult<core::TransportSocket> {
+    Ok(core::TransportSocket {
+        name: "envoy.transport_sockets.tls".to_string(),
+        config_type: Some(core::transport_socket::ConfigType::TypedConfig(any(
+            "type.googleapis.com/envoy.extensions.transport_sockets.tls.v3.DownstreamTlsContext",
+            &downstream_tls_context(config)?,
+        ))),
+    })
+}
+
+/// QUIC carries its own TLS 1.3 handshake, so an HTTP/3 listener wraps the same downstream
+/// TLS context in the QUIC transport socket instead of the TCP one.
+fn downstream_quic_transport_socket(
+    config: &ListenerTlsConfig,
+) -> DomainResult<core::TransportSocket> {
+    Ok(core::TransportSocket {
+        name: "envoy.transport_sockets.quic".to_string(),
+        config_type: Some(core::transport_socket::ConfigType::TypedConfig(any(
+            "type.googleapis.com/envoy.extensions.transport_sockets.quic.v3.QuicDownstreamTransport",
+            &quic_transport::QuicDownstreamTransport {
+                downstream_tls_context: Some(downstream_tls_context(config)?),
+                ..Default::default()
+            },
+        ))),
+    })
+}
+
+fn downstream_tls_context(config: &ListenerTlsConfig) -> DomainResult<tls::DownstreamTlsContext> {
     let mut common = tls::CommonTlsContext::default();
     if let Some(secret_name) = &config.tls_certificate_sds_secret_name {
         common
@@ -2576,18 +3778,10 @@ fn downstream_tls_transport_socket(
             })
         };
 
-    Ok(core::TransportSocket {
-        name: "envoy.transport_sockets.tls".to_string(),
-        config_type: Some(core::transport_socket::ConfigType::TypedConfig(any(
-            "type.googleapis.com/envoy.extensions.transport_sockets.tls.v3.DownstreamTlsContext",
-            &tls::DownstreamTlsContext {
-                common_tls_context: Some(common),
-                require_client_certificate: config
-                    .require_client_certificate
-                    .then(|| bool_value(true)),
-                ..Default::default()
-            },
-        ))),
+    Ok(tls::DownstreamTlsContext {
+        common_tls_context: Some(common),
+        require_client_certificate: config.require_client_certificate.then(|| bool_value(true)),
+        ..Default::default()
     })
 }
 
@@ -2607,9 +3801,10 @@ mod tests {
         HttpHealthCheckMethod, MaglevPolicy, OutlierDetection, UpstreamProtocol, UpstreamTlsConfig,
     };
     use fp_domain::gateway::route_config::{
-        DirectResponseAction, HeaderMatch, HeaderValueMatch, QueryParameterMatch, QueryValueMatch,
-        RateLimitAction, RateLimitDefinition, RedirectAction, RedirectResponseCode, RetryPolicy,
-        RouteAction, RouteRule, VirtualHost, WeightedClusterTarget,
+        DirectResponseAction, FractionDenominator, HashPolicy, HeaderMatch, HeaderValueMatch,
+        QueryParameterMatch, QueryValueMatch, RateLimitAction, RateLimitDefinition, RedirectAction,
+        RedirectResponseCode, RegexRewrite, RequestMirrorPolicy, RetryPolicy, RouteAction,
+        RouteRule, RuntimeFractionMatch, VirtualHost, WeightedClusterTarget,
     };
 
     fn route_action(cluster: &str) -> RouteAction {
@@ -2622,6 +3817,16 @@ mod tests {
             template_rewrite: None,
             timeout_secs: 15,
             retry_policy: None,
+            retry_policy_ref: None,
+            idle_timeout_secs: None,
+            max_stream_duration_secs: None,
+            regex_rewrite: None,
+            host_rewrite_literal: None,
+            auto_host_rewrite: false,
+            fallback_cluster: None,
+            request_mirror_policies: Vec::new(),
+            request_headers_to_remove: Vec::new(),
+            hash_policy: Vec::new(),
             rate_limits: Vec::new(),
         }
     }
@@ -2650,9 +3855,11 @@ mod tests {
             use_tls: true,
             upstream_tls: None,
             protocol: None,
+            use_downstream_protocol: false,
             health_checks: None,
             circuit_breakers: None,
             outlier_detection: None,
+            connection_pool: None,
         }
     }
 
@@ -2880,9 +4087,11 @@ mod tests {
             use_tls: false,
             upstream_tls: None,
             protocol: None,
+            use_downstream_protocol: false,
             health_checks: None,
             circuit_breakers: None,
             outlier_detection: None,
+            connection_pool: None,
         };
 
         let cluster = cluster_to_proto("ai-route-agg-default-1", &spec).expect("translate");
@@ -2937,6 +4146,7 @@ mod tests {
                 insecure_skip_verify: false,
             }),
             protocol: Some(UpstreamProtocol::Grpc),
+            use_downstream_protocol: false,
             health_checks: Some(vec![HealthCheck::Http(HttpHealthCheck {
                 path: "/healthz".into(),
                 host: Some("api.example.com".into()),
@@ -2953,6 +4163,7 @@ mod tests {
                     max_pending_requests: 200,
                     max_requests: 300,
                     max_retries: 3,
+                    max_connection_pools: Some(64),
                 }),
                 high: None,
             }),
@@ -2963,6 +4174,7 @@ mod tests {
                 max_ejection_percent: 50,
                 min_hosts: Some(3),
             }),
+            connection_pool: None,
         };
         let proto = cluster_to_proto("api", &spec).expect("translate");
         assert_eq!(proto.lb_policy, exc::cluster::LbPolicy::Maglev as i32);
@@ -2996,6 +4208,18 @@ mod tests {
                 .len(),
             1
         );
+        assert_eq!(
+            proto
+                .circuit_breakers
+                .as_ref()
+                .expect("circuit breakers")
+                .thresholds[0]
+                .max_connection_pools
+                .as_ref()
+                .expect("max connection pools")
+                .value,
+            64
+        );
         assert_eq!(
             proto
                 .outlier_detection
@@ -3009,6 +4233,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn use_downstream_protocol_emits_both_http_option_sets() {
+        let spec = ClusterSpec {
+            use_downstream_protocol: true,
+            ..cluster_spec()
+        };
+        spec.validate().expect("downstream protocol validates");
+        let proto = cluster_to_proto("api", &spec).expect("translate");
+        let options_any = proto
+            .typed_extension_protocol_options
+            .get("envoy.extensions.upstreams.http.v3.HttpProtocolOptions")
+            .expect("http protocol options");
+        let options = upstream_http::HttpProtocolOptions::decode(options_any.value.as_slice())
+            .expect("decode options");
+        let Some(
+            upstream_http::http_protocol_options::UpstreamProtocolOptions::UseDownstreamProtocolConfig(
+                config,
+            ),
+        ) = options.upstream_protocol_options
+        else {
+            panic!("expected UseDownstreamProtocolConfig");
+        };
+        assert!(config.http_protocol_options.is_some());
+        assert!(config.http2_protocol_options.is_some());
+
+        let err = ClusterSpec {
+            protocol: Some(UpstreamProtocol::Http2),
+            ..spec
+        }
+        .validate()
+        .expect_err("explicit http2 conflicts");
+        assert!(err.message.contains("mutually exclusive"));
+    }
+
+    #[test]
+    fn connection_pool_limits_land_in_protocol_options() {
+        use fp_domain::gateway::cluster::ConnectionPoolLimits;
+        let decode = |spec: &ClusterSpec| {
+            let proto = cluster_to_proto("api", spec).expect("translate");
+            let options_any = proto
+                .typed_extension_protocol_options
+                .get("envoy.extensions.upstreams.http.v3.HttpProtocolOptions")
+                .expect("http protocol options");
+            upstream_http::HttpProtocolOptions::decode(options_any.value.as_slice())
+                .expect("decode options")
+        };
+
+        // HTTP/1.1 with only a request cap still gets explicit HTTP/1.1 options.
+        let spec = ClusterSpec {
+            connection_pool: Some(ConnectionPoolLimits {
+                max_requests_per_connection: Some(500),
+                max_concurrent_streams: None,
+            }),
+            ..cluster_spec()
+        };
+        let options = decode(&spec);
+        assert_eq!(
+            options
+                .common_http_protocol_options
+                .and_then(|c| c.max_requests_per_connection)
+                .map(|v| v.value),
+            Some(500)
+        );
+        assert_eq!(
+            options.upstream_protocol_options,
+            Some(explicit_http1_config())
+        );
+
+        let spec = ClusterSpec {
+            protocol: Some(UpstreamProtocol::Http2),
+            connection_pool: Some(ConnectionPoolLimits {
+                max_requests_per_connection: None,
+                max_concurrent_streams: Some(64),
+            }),
+            ..cluster_spec()
+        };
+        let options = decode(&spec);
+        assert!(options.common_http_protocol_options.is_none());
+        let Some(
+            upstream_http::http_protocol_options::UpstreamProtocolOptions::ExplicitHttpConfig(
+                config,
+            ),
+        ) = options.upstream_protocol_options
+        else {
+            panic!("expected explicit HTTP/2");
+        };
+        let Some(
+            upstream_http::http_protocol_options::explicit_http_config::ProtocolConfig::Http2ProtocolOptions(
+                http2,
+            ),
+        ) = config.protocol_config
+        else {
+            panic!("expected HTTP/2 options");
+        };
+        assert_eq!(http2.max_concurrent_streams.map(|v| v.value), Some(64));
+
+        assert!(cluster_to_proto("api", &cluster_spec())
+            .expect("translate")
+            .typed_extension_protocol_options
+            .is_empty());
+    }
+
+    #[test]
+    fn auto_protocol_negotiates_over_alpn() {
+        let spec = ClusterSpec {
+            protocol: Some(UpstreamProtocol::Auto),
+            ..cluster_spec()
+        };
+        spec.validate().expect("auto protocol over TLS validates");
+        let proto = cluster_to_proto("api", &spec).expect("translate");
+        let options_any = proto
+            .typed_extension_protocol_options
+            .get("envoy.extensions.upstreams.http.v3.HttpProtocolOptions")
+            .expect("http protocol options");
+        let options = upstream_http::HttpProtocolOptions::decode(options_any.value.as_slice())
+            .expect("decode options");
+        let Some(upstream_http::http_protocol_options::UpstreamProtocolOptions::AutoConfig(config)) =
+            options.upstream_protocol_options
+        else {
+            panic!("expected AutoConfig");
+        };
+        assert!(config.http_protocol_options.is_some());
+        assert!(config.http2_protocol_options.is_some());
+        let alpn = upstream_tls_context_of("api", &spec)
+            .common_tls_context
+            .expect("common")
+            .alpn_protocols;
+        assert_eq!(alpn, ["h2", "http/1.1"]);
+        assert!(upstream_tls_context_of("api", &cluster_spec())
+            .common_tls_context
+            .expect("common")
+            .alpn_protocols
+            .is_empty());
+    }
+
     #[test]
     fn ai_cluster_translation_adds_upstream_ext_proc_without_secret() {
         let ai = AiUpstreamProcessorMetadata {
@@ -3097,7 +4456,9 @@ mod tests {
                             path: "/health".into(),
                         },
                         headers: Vec::new(),
+                        methods: Vec::new(),
                         query_parameters: Vec::new(),
+                        runtime_fraction: None,
                         action: route_action("c1"),
                         filter_overrides: Vec::new(),
                     },
@@ -3107,7 +4468,9 @@ mod tests {
                             prefix: "/api".into(),
                         },
                         headers: Vec::new(),
+                        methods: Vec::new(),
                         query_parameters: Vec::new(),
+                        runtime_fraction: None,
                         action: RouteAction {
                             cluster: Some("c2".into()),
                             weighted_clusters: None,
@@ -3117,6 +4480,16 @@ mod tests {
                             template_rewrite: None,
                             timeout_secs: 30,
                             retry_policy: None,
+                            retry_policy_ref: None,
+                            idle_timeout_secs: None,
+                            max_stream_duration_secs: None,
+                            regex_rewrite: None,
+                            host_rewrite_literal: None,
+                            auto_host_rewrite: false,
+                            fallback_cluster: None,
+                            request_mirror_policies: Vec::new(),
+                            request_headers_to_remove: Vec::new(),
+                            hash_policy: Vec::new(),
                             rate_limits: Vec::new(),
                         },
                         filter_overrides: Vec::new(),
@@ -3127,7 +4500,9 @@ mod tests {
                             template: "/users/{id}".into(),
                         },
                         headers: Vec::new(),
+                        methods: Vec::new(),
                         query_parameters: Vec::new(),
+                        runtime_fraction: None,
                         action: RouteAction {
                             cluster: Some("c3".into()),
                             weighted_clusters: None,
@@ -3137,6 +4512,16 @@ mod tests {
                             template_rewrite: Some("/{id}".into()),
                             timeout_secs: 15,
                             retry_policy: None,
+                            retry_policy_ref: None,
+                            idle_timeout_secs: None,
+                            max_stream_duration_secs: None,
+                            regex_rewrite: None,
+                            host_rewrite_literal: None,
+                            auto_host_rewrite: false,
+                            fallback_cluster: None,
+                            request_mirror_policies: Vec::new(),
+                            request_headers_to_remove: Vec::new(),
+                            hash_policy: Vec::new(),
                             rate_limits: Vec::new(),
                         },
                         filter_overrides: Vec::new(),
@@ -3182,7 +4567,9 @@ mod tests {
                         path: "/chat".into(),
                     },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: RouteAction {
                         cluster: None,
                         weighted_clusters: None,
@@ -3195,6 +4582,16 @@ mod tests {
                         template_rewrite: None,
                         timeout_secs: 15,
                         retry_policy: None,
+                        retry_policy_ref: None,
+                        idle_timeout_secs: None,
+                        max_stream_duration_secs: None,
+                        regex_rewrite: None,
+                        host_rewrite_literal: None,
+                        auto_host_rewrite: false,
+                        fallback_cluster: None,
+                        request_mirror_policies: Vec::new(),
+                        request_headers_to_remove: Vec::new(),
+                        hash_policy: Vec::new(),
                         rate_limits: Vec::new(),
                     },
                     filter_overrides: Vec::new(),
@@ -3239,10 +4636,16 @@ mod tests {
                             invert_match: false,
                             matcher: HeaderValueMatch::Exact { value: "2".into() },
                         }],
+                        methods: Vec::new(),
                         query_parameters: vec![QueryParameterMatch {
                             name: "preview".into(),
                             matcher: QueryValueMatch::Present { value: true },
                         }],
+                        runtime_fraction: Some(RuntimeFractionMatch {
+                            numerator: 250,
+                            denominator: FractionDenominator::TenThousand,
+                            runtime_key: Some("routes.split.enabled".into()),
+                        }),
                         action: RouteAction {
                             cluster: None,
                             weighted_clusters: Some(vec![
@@ -3267,6 +4670,16 @@ mod tests {
                                 retriable_status_codes: vec![502, 503],
                                 previous_priorities_retry: false,
                             }),
+                            retry_policy_ref: None,
+                            idle_timeout_secs: None,
+                            max_stream_duration_secs: None,
+                            regex_rewrite: None,
+                            host_rewrite_literal: None,
+                            auto_host_rewrite: false,
+                            fallback_cluster: None,
+                            request_mirror_policies: Vec::new(),
+                            request_headers_to_remove: Vec::new(),
+                            hash_policy: Vec::new(),
                             rate_limits: vec![RateLimitDefinition {
                                 stage: Some(1),
                                 disable_key: Some("rl.disable.preview".into()),
@@ -3291,7 +4704,9 @@ mod tests {
                             prefix: "/old".into(),
                         },
                         headers: Vec::new(),
+                        methods: Vec::new(),
                         query_parameters: Vec::new(),
+                        runtime_fraction: None,
                         action: RouteAction {
                             cluster: None,
                             weighted_clusters: None,
@@ -3309,6 +4724,16 @@ mod tests {
                             template_rewrite: None,
                             timeout_secs: 15,
                             retry_policy: None,
+                            retry_policy_ref: None,
+                            idle_timeout_secs: None,
+                            max_stream_duration_secs: None,
+                            regex_rewrite: None,
+                            host_rewrite_literal: None,
+                            auto_host_rewrite: false,
+                            fallback_cluster: None,
+                            request_mirror_policies: Vec::new(),
+                            request_headers_to_remove: Vec::new(),
+                            hash_policy: Vec::new(),
                             rate_limits: Vec::new(),
                         },
                         filter_overrides: Vec::new(),
@@ -3335,6 +4760,25 @@ mod tests {
         ));
         assert_eq!(split_match.headers.len(), 1);
         assert_eq!(split_match.query_parameters.len(), 1);
+        let fraction = split_match
+            .runtime_fraction
+            .as_ref()
+            .expect("runtime fraction");
+        assert_eq!(fraction.runtime_key, "routes.split.enabled");
+        let share = fraction.default_value.as_ref().expect("default share");
+        assert_eq!(
+            (share.numerator, share.denominator),
+            (
+                250,
+                envoy_type::fractional_percent::DenominatorType::TenThousand as i32
+            )
+        );
+        assert!(routes[1]
+            .r#match
+            .as_ref()
+            .expect("redirect match")
+            .runtime_fraction
+            .is_none());
 
         let split_action = match routes[0].action.as_ref().expect("split action") {
             rt::route::Action::Route(action) => action,
@@ -3378,6 +4822,231 @@ mod tests {
         assert!(redirect.strip_query);
     }
 
+    #[test]
+    fn json_access_logs_encode_a_sorted_struct() {
+        let fields: BTreeMap<String, String> = (0..16)
+            .map(|i| (format!("field_{i:02}"), "%RESPONSE_CODE%".to_string()))
+            .chain([("method".to_string(), "%REQ(:METHOD)%".to_string())])
+            .collect();
+        let spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: vec![fp_domain::gateway::listener::AccessLogConfig {
+                path: "/dev/stdout".into(),
+                text_format: None,
+                json_format: Some(fields.clone()),
+                otel: None,
+            }],
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let first = listener_to_proto("edge", &spec).expect("translate");
+        for _ in 0..8 {
+            assert_eq!(
+                listener_to_proto("edge", &spec)
+                    .expect("translate")
+                    .encode_to_vec(),
+                first.encode_to_vec(),
+                "JSON access log encoding must be deterministic"
+            );
+        }
+        let manager = match &first.filter_chains[0].filters[0].config_type {
+            Some(lst::filter::ConfigType::TypedConfig(a)) => {
+                hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+            }
+            _ => panic!("expected typed HCM"),
+        };
+        let accesslog::access_log::ConfigType::TypedConfig(any) = manager.access_log[0]
+            .config_type
+            .as_ref()
+            .expect("access log config");
+        let file = file_accesslog::FileAccessLog::decode(any.value.as_slice()).expect("file log");
+        assert_eq!(file.path, "/dev/stdout");
+        let Some(file_accesslog::file_access_log::AccessLogFormat::LogFormat(format)) =
+            file.access_log_format
+        else {
+            panic!("expected a log_format");
+        };
+        let Some(core::substitution_format_string::Format::JsonFormat(json)) = format.format else {
+            panic!("expected a json_format");
+        };
+        assert_eq!(json.fields.len(), 17);
+        assert_eq!(
+            json.fields["method"].kind,
+            Some(wkt::value::Kind::StringValue("%REQ(:METHOD)%".into()))
+        );
+    }
+
+    #[test]
+    fn otel_access_logs_export_to_the_collector_cluster() {
+        let mut spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        spec.access_logs = serde_json::from_value(serde_json::json!([{
+            "otel": {
+                "cluster": "otel-collector",
+                "resource_attributes": {"service.name": "edge", "deployment.environment": "prod"}
+            },
+            "text_format": "%REQ(:METHOD)% %RESPONSE_CODE%",
+            "json_format": {"status": "%RESPONSE_CODE%", "method": "%REQ(:METHOD)%"}
+        }]))
+        .expect("otel access log");
+        let listener = listener_to_proto("edge", &spec).expect("translate");
+        let manager = match &listener.filter_chains[0].filters[0].config_type {
+            Some(lst::filter::ConfigType::TypedConfig(a)) => {
+                hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+            }
+            _ => panic!("expected typed HCM"),
+        };
+        let log = &manager.access_log[0];
+        assert_eq!(log.name, "envoy.access_loggers.open_telemetry");
+        let Some(accesslog::access_log::ConfigType::TypedConfig(any)) = &log.config_type else {
+            panic!("expected typed access log config");
+        };
+        let config = otel_accesslog::OpenTelemetryAccessLogConfig::decode(any.value.as_slice())
+            .expect("otel config");
+        #[allow(deprecated)]
+        let common = config.common_config.expect("common config");
+        assert!(matches!(
+            common.grpc_service.and_then(|g| g.target_specifier),
+            Some(core::grpc_service::TargetSpecifier::EnvoyGrpc(g)) if g.cluster_name == "otel-collector"
+        ));
+        let keys = |list: Option<otel_common::KeyValueList>| -> Vec<String> {
+            list.expect("key values")
+                .values
+                .into_iter()
+                .map(|kv| kv.key)
+                .collect()
+        };
+        assert_eq!(
+            keys(config.resource_attributes),
+            ["deployment.environment", "service.name"]
+        );
+        assert_eq!(keys(config.attributes), ["method", "status"]);
+        assert_eq!(
+            config.body.and_then(|b| b.value),
+            Some(otel_common::any_value::Value::StringValue(
+                "%REQ(:METHOD)% %RESPONSE_CODE%".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn opentelemetry_tracing_samples_at_the_listener() {
+        let mut spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        assert!(
+            hcm_of(&spec).tracing.is_none(),
+            "no tracing unless asked for"
+        );
+
+        spec.tracing = serde_json::from_value(serde_json::json!({
+            "provider": {"type": "opentelemetry", "cluster": "otel-collector", "service_name": "edge"},
+            "sampling_percent": 2.5
+        }))
+        .expect("tracing");
+        let tracing = hcm_of(&spec).tracing.expect("tracing");
+        assert_eq!(tracing.random_sampling.map(|p| p.value), Some(2.5));
+        let provider = tracing.provider.expect("provider");
+        assert_eq!(provider.name, "envoy.tracers.opentelemetry");
+        let Some(
+            envoy_types::pb::envoy::config::trace::v3::tracing::http::ConfigType::TypedConfig(any),
+        ) = provider.config_type
+        else {
+            panic!("expected typed tracer config");
+        };
+        let config = envoy_types::pb::envoy::config::trace::v3::OpenTelemetryConfig::decode(
+            any.value.as_slice(),
+        )
+        .expect("opentelemetry config");
+        assert_eq!(config.service_name, "edge");
+        assert!(matches!(
+            config.grpc_service.and_then(|g| g.target_specifier),
+            Some(core::grpc_service::TargetSpecifier::EnvoyGrpc(g)) if g.cluster_name == "otel-collector"
+        ));
+    }
+
+    #[test]
+    fn http_limits_set_only_the_configured_hcm_options() {
+        let mut spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let manager = hcm_of(&spec);
+        assert!(manager.max_request_headers_kb.is_none());
+        assert!(manager.common_http_protocol_options.is_none());
+
+        spec.http_limits = Some(fp_domain::gateway::listener::HttpLimits {
+            max_request_headers_kb: Some(96),
+            max_requests_per_connection: None,
+            idle_timeout_secs: None,
+        });
+        let manager = hcm_of(&spec);
+        assert_eq!(manager.max_request_headers_kb.map(|v| v.value), Some(96));
+        assert!(
+            manager.common_http_protocol_options.is_none(),
+            "no connection options unless a connection limit is set"
+        );
+
+        spec.http_limits = Some(fp_domain::gateway::listener::HttpLimits {
+            max_request_headers_kb: None,
+            max_requests_per_connection: Some(1000),
+            idle_timeout_secs: Some(300),
+        });
+        let options = hcm_of(&spec)
+            .common_http_protocol_options
+            .expect("connection options");
+        assert_eq!(options.idle_timeout.map(|d| d.seconds), Some(300));
+        assert_eq!(
+            options.max_requests_per_connection.map(|v| v.value),
+            Some(1000)
+        );
+    }
+
     #[test]
     fn listener_requires_a_bound_route_config() {
         let unbound = ListenerSpec {
@@ -3389,6 +5058,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         assert!(listener_to_proto("edge", &unbound).is_err());
 
@@ -3402,8 +5076,15 @@ mod tests {
             access_logs: vec![fp_domain::gateway::listener::AccessLogConfig {
                 path: "/var/log/envoy/access.log".into(),
                 text_format: Some("%REQ(:METHOD)% %RESPONSE_CODE%\n".into()),
+                json_format: None,
+                otel: None,
             }],
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let proto = listener_to_proto("edge", &bound).expect("translate");
         assert_eq!(proto.filter_chains.len(), 1);
@@ -3458,44 +5139,191 @@ mod tests {
     }
 
     #[test]
-    fn listener_tls_context_uses_sds_over_ads() {
+    fn network_local_rate_limit_runs_before_the_hcm() {
+        use envoy_types::pb::envoy::extensions::filters::network::local_ratelimit::v3 as net_lrl;
+        use fp_domain::gateway::filters::TokenBucket;
+        use fp_domain::gateway::listener::NetworkLocalRateLimitConfig;
+
         let spec = ListenerSpec {
             address: "0.0.0.0".into(),
-            port: 10443,
+            port: 10001,
             public_base_url: None,
-            protocol: ListenerProtocol::Https,
+            protocol: ListenerProtocol::Http,
             route_config: Some("orders".into()),
             http_filters: Vec::new(),
             access_logs: Vec::new(),
-            tls_context: Some(ListenerTlsConfig {
-                cert_chain_file: None,
-                private_key_file: None,
-                ca_cert_file: None,
-                require_client_certificate: false,
-                tls_certificate_sds_secret_name: Some("edge-cert".into()),
-                validation_context_sds_secret_name: Some("edge-ca".into()),
-            }),
+            tls_context: None,
+            network_filters: vec![
+                NetworkFilterSpec::LocalRateLimit(NetworkLocalRateLimitConfig {
+                    stat_prefix: "edge_conn".into(),
+                    token_bucket: TokenBucket {
+                        max_tokens: 100,
+                        tokens_per_fill: Some(10),
+                        fill_interval_ms: 1000,
+                    },
+                }),
+                NetworkFilterSpec::HttpConnectionManager,
+            ],
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
-        let proto = listener_to_proto("edge-tls", &spec).expect("translate");
-        let manager = match &proto.filter_chains[0].filters[0].config_type {
+        spec.validate().expect("valid listener");
+        let proto = listener_to_proto("edge", &spec).expect("translate");
+        let filters = &proto.filter_chains[0].filters;
+        let names: Vec<_> = filters.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "envoy.filters.network.local_ratelimit",
+                "envoy.filters.network.http_connection_manager"
+            ],
+            "the explicit HCM marker is not emitted twice"
+        );
+        let limit = match &filters[0].config_type {
             Some(lst::filter::ConfigType::TypedConfig(a)) => {
-                hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+                assert!(a
+                    .type_url
+                    .ends_with("network.local_ratelimit.v3.LocalRateLimit"));
+                net_lrl::LocalRateLimit::decode(a.value.as_slice()).expect("local ratelimit")
             }
-            _ => panic!("expected typed HCM"),
+            _ => panic!("expected typed network filter"),
+        };
+        assert_eq!(limit.stat_prefix, "edge_conn");
+        let bucket = limit.token_bucket.expect("bucket");
+        assert_eq!(bucket.max_tokens, 100);
+        assert_eq!(bucket.tokens_per_fill.map(|v| v.value), Some(10));
+        assert_eq!(bucket.fill_interval.map(|d| d.seconds), Some(1));
+    }
+
+    #[test]
+    fn proxy_protocol_listener_filter_refuses_unlisted_versions() {
+        use envoy_types::pb::envoy::extensions::filters::listener::proxy_protocol::v3 as proxy_protocol;
+        use fp_domain::gateway::listener::ProxyProtocolConfig;
+
+        let mut spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: vec![ListenerFilterSpec::ProxyProtocol(ProxyProtocolConfig {
+                versions: vec![ProxyProtocolVersion::V2],
+                allow_requests_without_proxy_protocol: true,
+            })],
+            http_limits: None,
+            exact_balance: false,
+        };
+        let decode = |spec: &ListenerSpec| {
+            let proto = listener_to_proto("edge", spec).expect("translate");
+            assert_eq!(proto.listener_filters.len(), 1);
+            let filter = &proto.listener_filters[0];
+            assert_eq!(filter.name, "envoy.filters.listener.proxy_protocol");
+            let Some(lst::listener_filter::ConfigType::TypedConfig(any)) = &filter.config_type
+            else {
+                panic!("expected typed listener filter");
+            };
+            proxy_protocol::ProxyProtocol::decode(any.value.as_slice()).expect("proxy protocol")
         };
+        let config = decode(&spec);
+        assert!(config.allow_requests_without_proxy_protocol);
         assert_eq!(
-            manager.codec_type,
-            hcm::http_connection_manager::CodecType::Auto as i32
+            config.disallowed_versions,
+            [core::proxy_protocol_config::Version::V1 as i32]
         );
-        let socket = proto.filter_chains[0]
-            .transport_socket
-            .as_ref()
-            .expect("transport socket");
-        assert_eq!(socket.name, "envoy.transport_sockets.tls");
-        let Some(core::transport_socket::ConfigType::TypedConfig(any)) = &socket.config_type else {
-            panic!("expected typed downstream tls context");
+
+        let ListenerFilterSpec::ProxyProtocol(c) = &mut spec.listener_filters[0] else {
+            panic!("expected proxy_protocol");
         };
-        assert_eq!(
+        c.versions.clear();
+        assert!(decode(&spec).disallowed_versions.is_empty());
+    }
+
+    #[test]
+    fn listener_filters_keep_declared_order() {
+        let spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: vec![
+                ListenerFilterSpec::OriginalDst,
+                ListenerFilterSpec::TlsInspector,
+            ],
+            http_limits: None,
+            exact_balance: false,
+        };
+        let proto = listener_to_proto("edge", &spec).expect("translate");
+        let names: Vec<_> = proto
+            .listener_filters
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "envoy.filters.listener.original_dst",
+                "envoy.filters.listener.tls_inspector"
+            ]
+        );
+    }
+
+    #[test]
+    fn listener_tls_context_uses_sds_over_ads() {
+        let spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10443,
+            public_base_url: None,
+            protocol: ListenerProtocol::Https,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: Some(ListenerTlsConfig {
+                cert_chain_file: None,
+                private_key_file: None,
+                ca_cert_file: None,
+                require_client_certificate: false,
+                tls_certificate_sds_secret_name: Some("edge-cert".into()),
+                validation_context_sds_secret_name: Some("edge-ca".into()),
+            }),
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let proto = listener_to_proto("edge-tls", &spec).expect("translate");
+        let manager = match &proto.filter_chains[0].filters[0].config_type {
+            Some(lst::filter::ConfigType::TypedConfig(a)) => {
+                hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+            }
+            _ => panic!("expected typed HCM"),
+        };
+        assert_eq!(
+            manager.codec_type,
+            hcm::http_connection_manager::CodecType::Auto as i32
+        );
+        let socket = proto.filter_chains[0]
+            .transport_socket
+            .as_ref()
+            .expect("transport socket");
+        assert_eq!(socket.name, "envoy.transport_sockets.tls");
+        let Some(core::transport_socket::ConfigType::TypedConfig(any)) = &socket.config_type else {
+            panic!("expected typed downstream tls context");
+        };
+        assert_eq!(
             any.type_url,
             "type.googleapis.com/envoy.extensions.transport_sockets.tls.v3.DownstreamTlsContext"
         );
@@ -3520,6 +5348,107 @@ mod tests {
         }
     }
 
+    #[test]
+    fn http3_listener_binds_udp_with_quic_transport() {
+        let spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10443,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http3,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: Some(ListenerTlsConfig {
+                cert_chain_file: Some("/etc/envoy/tls/edge.crt".into()),
+                private_key_file: Some("/etc/envoy/tls/edge.key".into()),
+                ca_cert_file: None,
+                require_client_certificate: false,
+                tls_certificate_sds_secret_name: None,
+                validation_context_sds_secret_name: None,
+            }),
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let proto = listener_to_proto("edge-h3", &spec).expect("translate");
+        let Some(core::address::Address::SocketAddress(socket)) =
+            proto.address.as_ref().and_then(|a| a.address.as_ref())
+        else {
+            panic!("expected socket address");
+        };
+        assert_eq!(socket.protocol, core::socket_address::Protocol::Udp as i32);
+        let udp = proto.udp_listener_config.expect("udp listener config");
+        assert!(udp.quic_options.is_some());
+
+        let manager = match &proto.filter_chains[0].filters[0].config_type {
+            Some(lst::filter::ConfigType::TypedConfig(a)) => {
+                hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+            }
+            _ => panic!("expected typed HCM"),
+        };
+        assert_eq!(
+            manager.codec_type,
+            hcm::http_connection_manager::CodecType::Http3 as i32
+        );
+        assert!(manager.http3_protocol_options.is_some());
+
+        let socket = proto.filter_chains[0]
+            .transport_socket
+            .as_ref()
+            .expect("transport socket");
+        assert_eq!(socket.name, "envoy.transport_sockets.quic");
+        let Some(core::transport_socket::ConfigType::TypedConfig(any)) = &socket.config_type else {
+            panic!("expected typed quic transport");
+        };
+        let quic = quic_transport::QuicDownstreamTransport::decode(any.value.as_slice())
+            .expect("quic transport");
+        let common = quic
+            .downstream_tls_context
+            .and_then(|c| c.common_tls_context)
+            .expect("tls context");
+        assert_eq!(common.tls_certificates.len(), 1);
+
+        let tcp = listener_to_proto(
+            "edge-tls",
+            &ListenerSpec {
+                protocol: ListenerProtocol::Https,
+                ..spec
+            },
+        )
+        .expect("translate");
+        assert!(tcp.udp_listener_config.is_none());
+    }
+
+    #[test]
+    fn exact_balance_sets_the_listener_connection_balancer() {
+        let mut spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: Vec::new(),
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let proto = listener_to_proto("edge", &spec).expect("translate");
+        assert!(proto.connection_balance_config.is_none());
+
+        spec.exact_balance = true;
+        let proto = listener_to_proto("edge", &spec).expect("translate");
+        assert!(matches!(
+            proto.connection_balance_config.and_then(|c| c.balance_type),
+            Some(lst::listener::connection_balance_config::BalanceType::ExactBalance(_))
+        ));
+    }
+
     #[test]
     fn filter_chain_keeps_order_router_last_and_cors_rejected() {
         {
@@ -3534,6 +5463,7 @@ mod tests {
                             fill_interval_ms: 1000,
                         },
                         status_code: Some(429),
+                        descriptors: Vec::new(),
                     }),
                     disabled: false,
                 },
@@ -3543,6 +5473,7 @@ mod tests {
                             key: "x-edge".into(),
                             value: "1".into(),
                             append: false,
+                            when: None,
                         }],
                         request_headers_to_remove: vec!["x-internal".into()],
                         response_headers_to_add: vec![],
@@ -3576,6 +5507,11 @@ mod tests {
                 http_filters: chain,
                 access_logs: Vec::new(),
                 tls_context: None,
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
             };
             let proto = listener_to_proto("edge", &spec).expect("translate");
             let manager = match &proto.filter_chains[0].filters[0].config_type {
@@ -3634,96 +5570,1094 @@ mod tests {
                 other => panic!("unexpected rate-limit grpc target: {other:?}"),
             }
 
-            // cors in the chain is the empty marker; the policy travels per-scope.
-            let cors_spec = ListenerSpec {
-                address: "0.0.0.0".into(),
-                port: 10002,
-                public_base_url: None,
-                protocol: ListenerProtocol::Http,
-                route_config: Some("orders".into()),
-                http_filters: vec![HttpFilterEntry {
-                    filter: HttpFilterSpec::Cors(CorsConfig {
-                        allow_origin: vec![OriginMatcher::Exact {
-                            value: "https://a.example".into(),
-                        }],
-                        allow_methods: vec![],
-                        allow_headers: vec![],
-                        expose_headers: vec![],
-                        max_age_seconds: None,
-                        allow_credentials: false,
-                    }),
-                    disabled: false,
+            // cors in the chain is the empty marker; the policy travels per-scope.
+            let cors_spec = ListenerSpec {
+                address: "0.0.0.0".into(),
+                port: 10002,
+                public_base_url: None,
+                protocol: ListenerProtocol::Http,
+                route_config: Some("orders".into()),
+                http_filters: vec![HttpFilterEntry {
+                    filter: HttpFilterSpec::Cors(CorsConfig {
+                        allow_origin: vec![OriginMatcher::Exact {
+                            value: "https://a.example".into(),
+                        }],
+                        allow_methods: vec![],
+                        allow_headers: vec![],
+                        expose_headers: vec![],
+                        max_age_seconds: None,
+                        allow_credentials: false,
+                    }),
+                    disabled: false,
+                }],
+                access_logs: Vec::new(),
+                tls_context: None,
+                network_filters: Vec::new(),
+                tracing: None,
+                listener_filters: Vec::new(),
+                http_limits: None,
+                exact_balance: false,
+            };
+            let proto = listener_to_proto("edge2", &cors_spec).expect("cors chain marker");
+            let manager = match &proto.filter_chains[0].filters[0].config_type {
+                Some(lst::filter::ConfigType::TypedConfig(a)) => {
+                    hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+                }
+                _ => panic!("expected typed HCM"),
+            };
+            assert_eq!(manager.http_filters[0].name, "envoy.filters.http.cors");
+        }
+    }
+
+    #[test]
+    fn global_rate_limit_filter_carries_domain_and_per_domain_stat_prefix() {
+        use fp_domain::gateway::filters::*;
+        let composed = format!("{}|{}|orders", uuid::Uuid::now_v7(), uuid::Uuid::now_v7());
+        let cfg = GlobalRateLimitConfig {
+            domain: composed.clone(),
+            service_cluster: "rate_limit_cluster".into(),
+            timeout_ms: 20,
+            failure_mode_deny: false,
+            stage: 0,
+            request_type: RateLimitRequestType::Both,
+            stat_prefix: None,
+            enable_x_ratelimit_headers: false,
+            disable_x_envoy_ratelimited_header: false,
+            rate_limited_status: None,
+            status_on_error: None,
+        };
+        let rls = global_rate_limit_to_proto(&cfg);
+        assert_eq!(
+            rls.domain, composed,
+            "the composed domain reaches Envoy verbatim"
+        );
+        assert_eq!(
+            rls.stat_prefix, "orders",
+            "stats split per base domain when no prefix is given"
+        );
+
+        let rls = global_rate_limit_to_proto(&GlobalRateLimitConfig {
+            stat_prefix: Some("edge_rls".into()),
+            ..cfg
+        });
+        assert_eq!(rls.stat_prefix, "edge_rls");
+    }
+
+    #[test]
+    fn grpc_json_transcoder_inlines_the_referenced_descriptor_set() {
+        use fp_domain::gateway::filters::*;
+        let descriptor_id = ProtoDescriptorId::generate();
+        let spec = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10000,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: vec![HttpFilterEntry {
+                filter: HttpFilterSpec::GrpcJsonTranscoder(GrpcJsonTranscoderConfig {
+                    proto_descriptor_id: descriptor_id,
+                    services: vec!["bookstore.Bookstore".into()],
+                    ignore_unknown_query_parameters: true,
+                    convert_grpc_status: false,
+                }),
+                disabled: false,
+            }],
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let artifacts = FilterArtifacts {
+            descriptor_sets: ProtoDescriptorSets::from([(descriptor_id, vec![0x0a, 0x00])]),
+            ..Default::default()
+        };
+        let proto = listener_to_proto_with_artifacts("grpc", &spec, &[], None, &artifacts)
+            .expect("translate");
+        let manager = match &proto.filter_chains[0].filters[0].config_type {
+            Some(lst::filter::ConfigType::TypedConfig(a)) => {
+                hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
+            }
+            _ => panic!("expected typed HCM"),
+        };
+        let filter = &manager.http_filters[0];
+        assert_eq!(filter.name, "envoy.filters.http.grpc_json_transcoder");
+        let Some(hcm::http_filter::ConfigType::TypedConfig(any)) = &filter.config_type else {
+            panic!("expected typed transcoder config");
+        };
+        let transcoder = grpc_json_transcoder::GrpcJsonTranscoder::decode(any.value.as_slice())
+            .expect("transcoder");
+        assert_eq!(transcoder.services, vec!["bookstore.Bookstore".to_string()]);
+        assert!(transcoder.ignore_unknown_query_parameters);
+        assert_eq!(
+            transcoder.descriptor_set,
+            Some(
+                grpc_json_transcoder::grpc_json_transcoder::DescriptorSet::ProtoDescriptorBin(
+                    vec![0x0a, 0x00]
+                )
+            )
+        );
+
+        let err = listener_to_proto("grpc", &spec).expect_err("descriptor bytes not supplied");
+        assert!(err.message.contains("not available"), "{}", err.message);
+    }
+
+    #[test]
+    fn local_rate_limit_descriptors_emit_per_key_buckets() {
+        use envoy_types::pb::envoy::extensions::filters::http::local_ratelimit::v3 as lrl;
+        use fp_domain::gateway::filters::*;
+
+        let config = LocalRateLimitConfig {
+            stat_prefix: "edge".into(),
+            token_bucket: TokenBucket {
+                max_tokens: 100,
+                tokens_per_fill: None,
+                fill_interval_ms: 1000,
+            },
+            status_code: None,
+            descriptors: vec![LocalRateLimitDescriptor {
+                entries: vec![
+                    DescriptorEntry {
+                        key: "remote_address".into(),
+                        value: "10.0.0.1".into(),
+                    },
+                    DescriptorEntry {
+                        key: "plan".into(),
+                        value: "free".into(),
+                    },
+                ],
+                token_bucket: Some(TokenBucket {
+                    max_tokens: 5,
+                    tokens_per_fill: Some(1),
+                    fill_interval_ms: 60_000,
+                }),
+            }],
+        };
+        let any = local_rate_limit_to_any(&config);
+        let proto = lrl::LocalRateLimit::decode(any.value.as_slice()).expect("decode");
+        assert_eq!(proto.token_bucket.as_ref().unwrap().max_tokens, 100);
+        assert_eq!(proto.descriptors.len(), 1);
+        let descriptor = &proto.descriptors[0];
+        let entries: Vec<_> = descriptor
+            .entries
+            .iter()
+            .map(|e| (e.key.as_str(), e.value.as_str()))
+            .collect();
+        assert_eq!(
+            entries,
+            [("remote_address", "10.0.0.1"), ("plan", "free")],
+            "entry order is preserved"
+        );
+        let bucket = descriptor.token_bucket.as_ref().expect("own bucket");
+        assert_eq!(bucket.max_tokens, 5);
+        assert_eq!(bucket.tokens_per_fill.as_ref().unwrap().value, 1);
+        assert_eq!(bucket.fill_interval.as_ref().unwrap().seconds, 60);
+        assert_eq!(proto.stage, 0);
+    }
+
+    #[test]
+    fn wasm_filter_carries_the_module_and_plugin_config() {
+        use envoy_types::pb::envoy::extensions::filters::http::wasm::v3 as wasm_filter;
+        use envoy_types::pb::envoy::extensions::wasm::v3 as wasm;
+        use fp_domain::gateway::filters::*;
+
+        let entry = HttpFilterEntry {
+            filter: HttpFilterSpec::Wasm(WasmConfig {
+                module: WasmModuleSource::Remote {
+                    uri: "https://artifacts.example/add-header.wasm".into(),
+                    cluster: "artifacts".into(),
+                    sha256: "ab".repeat(32),
+                    timeout_ms: 2500,
+                },
+                runtime: WasmRuntime::V8,
+                config: Some(r#"{"header":"x-wasm"}"#.into()),
+                root_id: Some("add_header".into()),
+                fail_open: false,
+            }),
+            disabled: false,
+        };
+        entry.filter.validate().expect("remote module");
+        let filter = http_filter_to_proto(&entry, &FilterArtifacts::default()).expect("translate");
+        assert_eq!(filter.name, "envoy.filters.http.wasm");
+        let Some(hcm::http_filter::ConfigType::TypedConfig(any)) = &filter.config_type else {
+            panic!("expected typed wasm config");
+        };
+        let plugin = wasm_filter::Wasm::decode(any.value.as_slice())
+            .expect("wasm")
+            .config
+            .expect("plugin config");
+        assert_eq!(plugin.root_id, "add_header");
+        assert_eq!(
+            plugin.failure_policy,
+            wasm::FailurePolicy::FailClosed as i32
+        );
+        let configuration = plugin.configuration.expect("plugin configuration");
+        assert_eq!(
+            configuration.type_url,
+            "type.googleapis.com/google.protobuf.StringValue"
+        );
+        assert_eq!(
+            wkt::StringValue::decode(configuration.value.as_slice())
+                .unwrap()
+                .value,
+            r#"{"header":"x-wasm"}"#
+        );
+        let Some(wasm::plugin_config::Vm::VmConfig(vm)) = plugin.vm else {
+            panic!("expected an inline VM config");
+        };
+        assert_eq!(vm.runtime, "envoy.wasm.runtime.v8");
+        let Some(core::async_data_source::Specifier::Remote(remote)) =
+            vm.code.and_then(|code| code.specifier)
+        else {
+            panic!("expected a remote module");
+        };
+        assert_eq!(remote.sha256, "ab".repeat(32));
+        let http_uri = remote.http_uri.expect("http uri");
+        assert_eq!(http_uri.uri, "https://artifacts.example/add-header.wasm");
+        assert_eq!(
+            http_uri.http_upstream_type,
+            Some(core::http_uri::HttpUpstreamType::Cluster(
+                "artifacts".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn registry_wasm_modules_are_inlined_from_the_team_artifacts() {
+        use envoy_types::pb::envoy::extensions::filters::http::wasm::v3 as wasm_filter;
+        use envoy_types::pb::envoy::extensions::wasm::v3 as wasm;
+        use fp_domain::gateway::filters::*;
+
+        let entry = HttpFilterEntry {
+            filter: HttpFilterSpec::Wasm(WasmConfig {
+                module: WasmModuleSource::Registry {
+                    name: "authz-plugin".into(),
+                    version: 2,
+                },
+                runtime: WasmRuntime::Wasmtime,
+                config: None,
+                root_id: None,
+                fail_open: true,
+            }),
+            disabled: false,
+        };
+        entry.filter.validate().expect("registry module");
+        let err = http_filter_to_proto(&entry, &FilterArtifacts::default()).unwrap_err();
+        assert!(err.message.contains("\"authz-plugin\" v2 is not available"));
+
+        let module = b"\0asm\x01\0\0\0".to_vec();
+        let artifacts = FilterArtifacts {
+            wasm_modules: WasmModules::from([(("authz-plugin".into(), 2), module.clone())]),
+            ..Default::default()
+        };
+        let filter = http_filter_to_proto(&entry, &artifacts).expect("translate");
+        let Some(hcm::http_filter::ConfigType::TypedConfig(any)) = &filter.config_type else {
+            panic!("expected typed wasm config");
+        };
+        let plugin = wasm_filter::Wasm::decode(any.value.as_slice())
+            .expect("wasm")
+            .config
+            .expect("plugin config");
+        assert_eq!(plugin.failure_policy, wasm::FailurePolicy::FailOpen as i32);
+        let Some(wasm::plugin_config::Vm::VmConfig(vm)) = plugin.vm else {
+            panic!("expected an inline VM config");
+        };
+        assert_eq!(vm.runtime, "envoy.wasm.runtime.wasmtime");
+        let Some(core::async_data_source::Specifier::Local(local)) =
+            vm.code.and_then(|code| code.specifier)
+        else {
+            panic!("expected an inlined module");
+        };
+        assert_eq!(
+            local.specifier,
+            Some(core::data_source::Specifier::InlineBytes(module))
+        );
+    }
+
+    #[test]
+    fn custom_response_maps_each_status_match_to_a_local_response() {
+        use envoy_types::pb::envoy::extensions::filters::http::custom_response::v3 as cr;
+        use envoy_types::pb::envoy::extensions::http::custom_response::local_response_policy::v3 as lrp;
+        use envoy_types::pb::xds::r#type::matcher::v3 as xm;
+        use fp_domain::gateway::filters::*;
+        use xm::matcher::matcher_list::predicate;
+
+        assert_eq!(status_range_regex(500, 599), "^(?:5\\d\\d)$");
+        assert_eq!(status_range_regex(498, 511), "^(?:498|499|50\\d|510|511)$");
+
+        let entry = HttpFilterEntry {
+            filter: HttpFilterSpec::CustomResponse(CustomResponseConfig {
+                matchers: vec![
+                    CustomResponseMatcher {
+                        status: StatusCodeMatch::Range {
+                            start: 500,
+                            end: 599,
+                        },
+                        status_code: Some(503),
+                        body: Some(r#"{"error":"maintenance"}"#.into()),
+                        response_headers_to_add: vec![HeaderValue {
+                            key: "content-type".into(),
+                            value: "application/json".into(),
+                            append: false,
+                            when: None,
+                        }],
+                    },
+                    CustomResponseMatcher {
+                        status: StatusCodeMatch::Code { code: 404 },
+                        status_code: None,
+                        body: Some("<h1>Not here</h1>".into()),
+                        response_headers_to_add: Vec::new(),
+                    },
+                ],
+            }),
+            disabled: false,
+        };
+        entry.filter.validate().expect("disjoint matchers");
+        let filter = http_filter_to_proto(&entry, &FilterArtifacts::default()).expect("translate");
+        assert_eq!(filter.name, "envoy.filters.http.custom_response");
+        let Some(hcm::http_filter::ConfigType::TypedConfig(any)) = &filter.config_type else {
+            panic!("expected typed custom_response config");
+        };
+        let proto = cr::CustomResponse::decode(any.value.as_slice()).expect("custom response");
+        let Some(xm::matcher::MatcherType::MatcherList(list)) =
+            proto.custom_response_matcher.expect("matcher").matcher_type
+        else {
+            panic!("expected a matcher list");
+        };
+        assert_eq!(list.matchers.len(), 2);
+        let pattern_of = |i: usize| {
+            let Some(predicate::MatchType::SinglePredicate(single)) = list.matchers[i]
+                .predicate
+                .as_ref()
+                .and_then(|p| p.match_type.clone())
+            else {
+                panic!("single predicate");
+            };
+            assert_eq!(
+                single
+                    .input
+                    .expect("input")
+                    .typed_config
+                    .expect("any")
+                    .type_url,
+                "type.googleapis.com/envoy.type.matcher.v3.HttpResponseStatusCodeMatchInput"
+            );
+            let Some(predicate::single_predicate::Matcher::ValueMatch(value)) = single.matcher
+            else {
+                panic!("value match");
+            };
+            value.match_pattern.expect("pattern")
+        };
+        assert!(matches!(
+            pattern_of(0),
+            xm::string_matcher::MatchPattern::SafeRegex(ref r) if r.regex == "^(?:5\\d\\d)$"
+        ));
+        assert_eq!(
+            pattern_of(1),
+            xm::string_matcher::MatchPattern::Exact("404".into())
+        );
+        let Some(xm::matcher::on_match::OnMatch::Action(action)) =
+            list.matchers[0].on_match.clone().and_then(|m| m.on_match)
+        else {
+            panic!("local response action");
+        };
+        let policy =
+            lrp::LocalResponsePolicy::decode(action.typed_config.expect("policy").value.as_slice())
+                .expect("policy");
+        assert_eq!(policy.status_code, Some(u32_value(503)));
+        assert_eq!(policy.response_headers_to_add.len(), 1);
+    }
+
+    #[test]
+    fn filter_overrides_become_typed_per_filter_config() {
+        use fp_domain::gateway::filters::*;
+        use fp_domain::gateway::route_config::{RouteRule, VirtualHost};
+        let spec = RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "quiet".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/quiet".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action: route_action("c"),
+                    filter_overrides: vec![FilterOverride::Disable {
+                        filter_type: "local_rate_limit".into(),
+                    }],
+                }],
+                rate_limits: Vec::new(),
+                filter_overrides: vec![FilterOverride::Cors(CorsConfig {
+                    allow_origin: vec![
+                        OriginMatcher::Suffix {
+                            value: ".example".into(),
+                        },
+                        OriginMatcher::SafeRegex {
+                            regex: r"https://.*\.example\.com".into(),
+                        },
+                    ],
+                    allow_methods: vec!["GET".into(), "POST".into()],
+                    allow_headers: vec![],
+                    expose_headers: vec![],
+                    max_age_seconds: Some(600),
+                    allow_credentials: true,
+                })],
+            }],
+        };
+        let proto = route_config_to_proto("orders", &spec).expect("translate");
+        let vhost = &proto.virtual_hosts[0];
+        let cors = vhost
+            .typed_per_filter_config
+            .get("envoy.filters.http.cors")
+            .expect("vhost cors policy");
+        assert!(cors.type_url.ends_with("cors.v3.CorsPolicy"));
+        let policy =
+            envoy_types::pb::envoy::extensions::filters::http::cors::v3::CorsPolicy::decode(
+                cors.value.as_slice(),
+            )
+            .expect("decode policy");
+        assert_eq!(policy.allow_methods, "GET,POST");
+        assert_eq!(policy.max_age, "600");
+        assert_eq!(policy.allow_origin_string_match.len(), 2);
+        assert!(matches!(
+            &policy.allow_origin_string_match[1].match_pattern,
+            Some(matcher_type::string_matcher::MatchPattern::SafeRegex(r))
+                if r.regex == r"https://.*\.example\.com"
+        ));
+
+        let disable = vhost.routes[0]
+            .typed_per_filter_config
+            .get("envoy.filters.http.local_ratelimit")
+            .expect("route disable override");
+        assert!(disable.type_url.ends_with("route.v3.FilterConfig"));
+        let cfg = rt::FilterConfig::decode(disable.value.as_slice()).expect("decode");
+        assert!(cfg.disabled);
+    }
+
+    #[test]
+    fn route_stream_timeouts_emit_idle_timeout_and_max_stream_duration() {
+        let forward_of = |action: RouteAction| {
+            let spec = RouteConfigSpec {
+                virtual_hosts: vec![VirtualHost {
+                    name: "default".into(),
+                    domains: vec!["*".into()],
+                    routes: vec![RouteRule {
+                        name: "events".into(),
+                        matcher: PathMatch::Prefix {
+                            prefix: "/events".into(),
+                        },
+                        headers: Vec::new(),
+                        methods: Vec::new(),
+                        query_parameters: Vec::new(),
+                        runtime_fraction: None,
+                        action,
+                        filter_overrides: Vec::new(),
+                    }],
+                    rate_limits: Vec::new(),
+                    filter_overrides: Vec::new(),
+                }],
+            };
+            let proto = route_config_to_proto("events", &spec).expect("translate");
+            match proto.virtual_hosts[0].routes[0].action.clone() {
+                Some(rt::route::Action::Route(route)) => route,
+                other => panic!("forward route, got {other:?}"),
+            }
+        };
+
+        let route = forward_of(RouteAction {
+            idle_timeout_secs: Some(0),
+            max_stream_duration_secs: Some(3600),
+            ..route_action("c")
+        });
+        assert_eq!(
+            route.idle_timeout,
+            Some(duration(0)),
+            "0 disables, as Envoy expects"
+        );
+        assert_eq!(
+            route
+                .max_stream_duration
+                .and_then(|m| m.max_stream_duration),
+            Some(duration(3600))
+        );
+
+        let route = forward_of(route_action("c"));
+        assert!(route.idle_timeout.is_none() && route.max_stream_duration.is_none());
+    }
+
+    #[test]
+    fn route_regex_and_host_rewrites_emit_on_the_route_action() {
+        let forward_of = |action: RouteAction| {
+            let spec = RouteConfigSpec {
+                virtual_hosts: vec![VirtualHost {
+                    name: "default".into(),
+                    domains: vec!["*".into()],
+                    routes: vec![RouteRule {
+                        name: "legacy".into(),
+                        matcher: PathMatch::Prefix {
+                            prefix: "/legacy".into(),
+                        },
+                        headers: Vec::new(),
+                        methods: Vec::new(),
+                        query_parameters: Vec::new(),
+                        runtime_fraction: None,
+                        action,
+                        filter_overrides: Vec::new(),
+                    }],
+                    rate_limits: Vec::new(),
+                    filter_overrides: Vec::new(),
+                }],
+            };
+            let proto = route_config_to_proto("legacy", &spec).expect("translate");
+            match proto.virtual_hosts[0].routes[0].action.clone() {
+                Some(rt::route::Action::Route(route)) => route,
+                other => panic!("forward route, got {other:?}"),
+            }
+        };
+
+        let route = forward_of(RouteAction {
+            regex_rewrite: Some(RegexRewrite {
+                pattern: "^/legacy/(\\w+)$".into(),
+                substitution: "/v2/\\1".into(),
+            }),
+            host_rewrite_literal: Some("api.internal".into()),
+            ..route_action("c")
+        });
+        let rewrite = route.regex_rewrite.expect("regex_rewrite");
+        assert_eq!(rewrite.pattern.expect("pattern").regex, "^/legacy/(\\w+)$");
+        assert_eq!(rewrite.substitution, "/v2/\\1");
+        assert_eq!(
+            route.host_rewrite_specifier,
+            Some(rt::route_action::HostRewriteSpecifier::HostRewriteLiteral(
+                "api.internal".into()
+            ))
+        );
+
+        let route = forward_of(RouteAction {
+            auto_host_rewrite: true,
+            ..route_action("c")
+        });
+        assert_eq!(
+            route.host_rewrite_specifier,
+            Some(rt::route_action::HostRewriteSpecifier::AutoHostRewrite(
+                bool_value(true)
+            ))
+        );
+        assert!(route.regex_rewrite.is_none());
+    }
+
+    #[test]
+    fn named_retry_policy_reference_translates_like_the_inline_policy() {
+        let retry = RetryPolicy {
+            retry_on: "5xx,reset".into(),
+            num_retries: Some(3),
+            per_try_timeout_secs: Some(4),
+            retriable_status_codes: vec![503],
+            previous_priorities_retry: true,
+        };
+        let spec_with = |action: RouteAction| RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "orders".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/orders".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action,
+                    filter_overrides: Vec::new(),
+                }],
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        let inline = spec_with(RouteAction {
+            retry_policy: Some(retry.clone()),
+            ..route_action("c")
+        });
+        let named = spec_with(RouteAction {
+            retry_policy_ref: Some("standard".into()),
+            ..route_action("c")
+        });
+        named.validate().expect("named reference validates");
+        let policies = NamedRetryPolicies::from([("standard".to_string(), retry)]);
+
+        let expected = route_config_to_proto("orders", &inline).expect("inline");
+        let actual =
+            route_config_to_proto_with_retry_policies("orders", &named, &policies).expect("named");
+        assert_eq!(actual, expected);
+        assert!(actual.virtual_hosts[0].include_request_attempt_count);
+
+        let err = route_config_to_proto("orders", &named).expect_err("undefined policy");
+        assert!(err
+            .message
+            .contains("retry policy \"standard\" is not defined"));
+    }
+
+    #[test]
+    fn fallback_cluster_serializes_into_aggregate_target_and_priority_retry() {
+        let spec_with = |action: RouteAction| RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "orders".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/orders".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action,
+                    filter_overrides: Vec::new(),
+                }],
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        let route_of =
+            |proto: rt::RouteConfiguration| match proto.virtual_hosts[0].routes[0].action.clone() {
+                Some(rt::route::Action::Route(route)) => route,
+                other => panic!("forward route, got {other:?}"),
+            };
+
+        // No retry policy of its own: one retry on 5xx, moved to the next priority.
+        let spec = spec_with(RouteAction {
+            fallback_cluster: Some("orders-dr".into()),
+            ..route_action("orders")
+        });
+        spec.validate().expect("fallback validates");
+        let proto = route_config_to_proto("orders", &spec).expect("translate");
+        assert!(proto.virtual_hosts[0].include_request_attempt_count);
+        let route = route_of(proto);
+        assert_eq!(
+            route.cluster_specifier,
+            Some(rt::route_action::ClusterSpecifier::Cluster(
+                "orders--fallback--orders-dr".into()
+            ))
+        );
+        let retry = route.retry_policy.expect("fallback implies a retry policy");
+        assert_eq!(retry.retry_on, "5xx");
+        assert_eq!(retry.num_retries, Some(u32_value(1)));
+        assert_eq!(
+            retry.retry_priority.map(|p| p.name).as_deref(),
+            Some("envoy.retry_priorities.previous_priorities")
+        );
+
+        // The route's own policy is kept, with the priority move forced on.
+        let spec = spec_with(RouteAction {
+            fallback_cluster: Some("orders-dr".into()),
+            retry_policy: Some(RetryPolicy {
+                retry_on: "5xx,connect-failure".into(),
+                num_retries: Some(2),
+                per_try_timeout_secs: None,
+                retriable_status_codes: Vec::new(),
+                previous_priorities_retry: false,
+            }),
+            ..route_action("orders")
+        });
+        let retry = route_of(route_config_to_proto("orders", &spec).expect("translate"))
+            .retry_policy
+            .expect("retry");
+        assert_eq!(retry.retry_on, "5xx,connect-failure");
+        assert_eq!(retry.num_retries, Some(u32_value(2)));
+        assert!(retry.retry_priority.is_some());
+
+        let aggregate = fallback_cluster_to_proto("orders", "orders-dr");
+        assert_eq!(aggregate.name, "orders--fallback--orders-dr");
+        assert_eq!(
+            aggregate.lb_policy,
+            exc::cluster::LbPolicy::ClusterProvided as i32
+        );
+        let Some(exc::cluster::ClusterDiscoveryType::ClusterType(custom)) =
+            aggregate.cluster_discovery_type
+        else {
+            panic!("aggregate cluster type");
+        };
+        let config = aggregate_cluster::ClusterConfig::decode(
+            custom.typed_config.expect("typed config").value.as_slice(),
+        )
+        .expect("decode");
+        assert_eq!(config.clusters, vec!["orders", "orders-dr"]);
+    }
+
+    #[test]
+    fn request_mirror_policies_serialize_with_percent_fraction() {
+        let spec = RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "orders".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/orders".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action: RouteAction {
+                        request_mirror_policies: vec![RequestMirrorPolicy {
+                            cluster: "orders-v2".into(),
+                            runtime_fraction: 10,
+                        }],
+                        ..route_action("orders")
+                    },
+                    filter_overrides: Vec::new(),
+                }],
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        spec.validate().expect("mirror validates");
+        assert!(spec.referenced_clusters().contains("orders-v2"));
+        let proto = route_config_to_proto("orders", &spec).expect("translate");
+        let Some(rt::route::Action::Route(route)) = proto.virtual_hosts[0].routes[0].action.clone()
+        else {
+            panic!("forward route");
+        };
+        assert_eq!(
+            route.cluster_specifier,
+            Some(rt::route_action::ClusterSpecifier::Cluster("orders".into()))
+        );
+        let [mirror] = route.request_mirror_policies.as_slice() else {
+            panic!("one mirror policy");
+        };
+        assert_eq!(mirror.cluster, "orders-v2");
+        let fraction = mirror
+            .runtime_fraction
+            .as_ref()
+            .and_then(|f| f.default_value)
+            .expect("fraction");
+        assert_eq!((fraction.numerator, fraction.denominator), (10, 0));
+    }
+
+    #[test]
+    fn hash_policies_keep_their_order_and_terminal_flags() {
+        use rt::route_action::hash_policy::{self, PolicySpecifier};
+        let spec = RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "carts".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/carts".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action: RouteAction {
+                        hash_policy: vec![
+                            HashPolicy::Cookie {
+                                name: "route".into(),
+                                ttl_secs: Some(3600),
+                                path: Some("/".into()),
+                                terminal: true,
+                            },
+                            HashPolicy::Header {
+                                header_name: "X-User-Id".into(),
+                                terminal: false,
+                            },
+                            HashPolicy::SourceIp { terminal: false },
+                        ],
+                        ..route_action("carts")
+                    },
+                    filter_overrides: Vec::new(),
+                }],
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        spec.validate().expect("hash policy validates");
+        let proto = route_config_to_proto("carts", &spec).expect("translate");
+        let Some(rt::route::Action::Route(route)) = proto.virtual_hosts[0].routes[0].action.clone()
+        else {
+            panic!("forward route");
+        };
+        let policies: Vec<_> = route
+            .hash_policy
+            .iter()
+            .map(|policy| (policy.policy_specifier.clone(), policy.terminal))
+            .collect();
+        assert_eq!(
+            policies,
+            vec![
+                (
+                    Some(PolicySpecifier::Cookie(hash_policy::Cookie {
+                        name: "route".into(),
+                        ttl: Some(duration(3600)),
+                        path: "/".into(),
+                        attributes: Vec::new(),
+                    })),
+                    true
+                ),
+                (
+                    Some(PolicySpecifier::Header(hash_policy::Header {
+                        header_name: "x-user-id".into(),
+                        regex_rewrite: None,
+                    })),
+                    false
+                ),
+                (
+                    Some(PolicySpecifier::ConnectionProperties(
+                        hash_policy::ConnectionProperties { source_ip: true }
+                    )),
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn conditional_header_becomes_a_cel_value_that_is_empty_on_a_miss() {
+        use envoy_types::pb::envoy::config::common::mutation_rules::v3 as mr;
+        use fp_domain::gateway::filters::{HeaderCondition, HeaderValue, StatusCodeMatch};
+        let value_of = |hv: &HeaderValue| match header_mutation_entry(hv, false).action {
+            Some(mr::header_mutation::Action::Append(option)) => {
+                option.header.expect("header").value
+            }
+            other => panic!("expected append, got {other:?}"),
+        };
+        let mut hv = HeaderValue {
+            key: "cache-control".into(),
+            value: "public, max-age=60".into(),
+            append: false,
+            when: None,
+        };
+        assert_eq!(value_of(&hv), "public, max-age=60");
+
+        hv.when = Some(HeaderCondition {
+            response_code: Some(StatusCodeMatch::Code { code: 200 }),
+            request_header_present: None,
+        });
+        assert_eq!(
+            value_of(&hv),
+            "%CEL(response.code == 200 ? 'public, max-age=60' : '')%"
+        );
+
+        hv.value = r"it's a\b".into();
+        hv.when = Some(HeaderCondition {
+            response_code: Some(StatusCodeMatch::Range {
+                start: 200,
+                end: 299,
+            }),
+            request_header_present: Some("X-Cacheable".into()),
+        });
+        assert_eq!(
+            value_of(&hv),
+            r"%CEL((response.code >= 200 && response.code <= 299) && 'x-cacheable' in request.headers ? 'it\'s a\\b' : '')%"
+        );
+    }
+
+    #[test]
+    fn route_header_removal_runs_after_filter_header_mutation() {
+        use fp_domain::gateway::filters::*;
+        // The listener's header_mutation adds x-debug; the route removes it. Envoy applies
+        // route-level removals in the router, which is always the last HTTP filter.
+        let listener = ListenerSpec {
+            address: "0.0.0.0".into(),
+            port: 10001,
+            public_base_url: None,
+            protocol: ListenerProtocol::Http,
+            route_config: Some("orders".into()),
+            http_filters: vec![HttpFilterEntry {
+                filter: HttpFilterSpec::HeaderMutation(HeaderMutationConfig {
+                    request_headers_to_add: vec![HeaderValue {
+                        key: "x-debug".into(),
+                        value: "1".into(),
+                        append: false,
+                        when: None,
+                    }],
+                    request_headers_to_remove: vec![],
+                    response_headers_to_add: vec![],
+                    response_headers_to_remove: vec![],
+                }),
+                disabled: false,
+            }],
+            access_logs: Vec::new(),
+            tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
+        };
+        let names: Vec<_> = hcm_of(&listener)
+            .http_filters
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "envoy.filters.http.header_mutation",
+                "envoy.filters.http.router"
+            ]
+        );
+
+        let spec_removing = |name: &str| RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "orders".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/orders".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action: RouteAction {
+                        request_headers_to_remove: vec![name.into()],
+                        hash_policy: Vec::new(),
+                        ..route_action("orders")
+                    },
+                    filter_overrides: Vec::new(),
                 }],
-                access_logs: Vec::new(),
-                tls_context: None,
-            };
-            let proto = listener_to_proto("edge2", &cors_spec).expect("cors chain marker");
-            let manager = match &proto.filter_chains[0].filters[0].config_type {
-                Some(lst::filter::ConfigType::TypedConfig(a)) => {
-                    hcm::HttpConnectionManager::decode(a.value.as_slice()).expect("hcm")
-                }
-                _ => panic!("expected typed HCM"),
-            };
-            assert_eq!(manager.http_filters[0].name, "envoy.filters.http.cors");
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        let spec = spec_removing("X-Debug");
+        spec.validate().expect("removal validates");
+        let proto = route_config_to_proto("orders", &spec).expect("translate");
+        assert_eq!(
+            proto.virtual_hosts[0].routes[0].request_headers_to_remove,
+            vec!["x-debug".to_string()]
+        );
+        // Same config, same bytes: repeated builds and case-only respellings are identical.
+        let bytes = proto.encode_to_vec();
+        for again in [spec_removing("X-Debug"), spec_removing("x-debug")] {
+            let proto = route_config_to_proto("orders", &again).expect("translate");
+            assert_eq!(proto.encode_to_vec(), bytes);
         }
     }
 
     #[test]
-    fn filter_overrides_become_typed_per_filter_config() {
-        use fp_domain::gateway::filters::*;
-        use fp_domain::gateway::route_config::{RouteRule, VirtualHost};
+    fn multi_method_route_is_one_rule_with_a_method_regex() {
         let spec = RouteConfigSpec {
             virtual_hosts: vec![VirtualHost {
                 name: "default".into(),
                 domains: vec!["*".into()],
                 routes: vec![RouteRule {
-                    name: "quiet".into(),
+                    name: "orders".into(),
                     matcher: PathMatch::Prefix {
-                        prefix: "/quiet".into(),
+                        prefix: "/orders".into(),
                     },
                     headers: Vec::new(),
+                    methods: vec!["GET".into(), "POST".into()],
                     query_parameters: Vec::new(),
-                    action: route_action("c"),
-                    filter_overrides: vec![FilterOverride::Disable {
-                        filter_type: "local_rate_limit".into(),
-                    }],
+                    runtime_fraction: None,
+                    action: route_action("orders"),
+                    filter_overrides: Vec::new(),
                 }],
                 rate_limits: Vec::new(),
-                filter_overrides: vec![FilterOverride::Cors(CorsConfig {
-                    allow_origin: vec![OriginMatcher::Suffix {
-                        value: ".example".into(),
-                    }],
-                    allow_methods: vec!["GET".into(), "POST".into()],
-                    allow_headers: vec![],
-                    expose_headers: vec![],
-                    max_age_seconds: Some(600),
-                    allow_credentials: true,
-                })],
+                filter_overrides: Vec::new(),
             }],
         };
+        spec.validate().expect("multi-method route validates");
         let proto = route_config_to_proto("orders", &spec).expect("translate");
-        let vhost = &proto.virtual_hosts[0];
-        let cors = vhost
-            .typed_per_filter_config
-            .get("envoy.filters.http.cors")
-            .expect("vhost cors policy");
-        assert!(cors.type_url.ends_with("cors.v3.CorsPolicy"));
-        let policy =
-            envoy_types::pb::envoy::extensions::filters::http::cors::v3::CorsPolicy::decode(
-                cors.value.as_slice(),
-            )
-            .expect("decode policy");
-        assert_eq!(policy.allow_methods, "GET,POST");
-        assert_eq!(policy.max_age, "600");
-        assert_eq!(policy.allow_origin_string_match.len(), 1);
+        let routes = &proto.virtual_hosts[0].routes;
+        assert_eq!(routes.len(), 1, "GET and POST share one rule");
+        let headers = &routes[0].r#match.as_ref().expect("match").headers;
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].name, ":method");
+        let Some(rt::header_matcher::HeaderMatchSpecifier::StringMatch(string_match)) =
+            &headers[0].header_match_specifier
+        else {
+            panic!("expected a string matcher");
+        };
+        let Some(matcher_type::string_matcher::MatchPattern::SafeRegex(regex)) =
+            &string_match.match_pattern
+        else {
+            panic!("expected a safe regex");
+        };
+        assert_eq!(regex.regex, "^(?:GET|POST)$");
+    }
 
-        let disable = vhost.routes[0]
-            .typed_per_filter_config
-            .get("envoy.filters.http.local_ratelimit")
-            .expect("route disable override");
-        assert!(disable.type_url.ends_with("route.v3.FilterConfig"));
-        let cfg = rt::FilterConfig::decode(disable.value.as_slice()).expect("decode");
-        assert!(cfg.disabled);
+    #[test]
+    fn structured_rate_limit_descriptors_emit_envoy_actions() {
+        let spec = RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![RouteRule {
+                    name: "orders".into(),
+                    matcher: PathMatch::Prefix {
+                        prefix: "/orders".into(),
+                    },
+                    headers: Vec::new(),
+                    methods: Vec::new(),
+                    query_parameters: Vec::new(),
+                    runtime_fraction: None,
+                    action: RouteAction {
+                        rate_limits: vec![RateLimitDefinition {
+                            stage: None,
+                            disable_key: None,
+                            actions: vec![
+                                RateLimitAction::RemoteAddress,
+                                RateLimitAction::HeaderValueMatch {
+                                    descriptor_value: "free".into(),
+                                    descriptor_key: Some("plan".into()),
+                                    expect_match: false,
+                                    headers: vec![HeaderMatch {
+                                        name: "x-plan".into(),
+                                        invert_match: false,
+                                        matcher: HeaderValueMatch::Exact {
+                                            value: "premium".into(),
+                                        },
+                                    }],
+                                },
+                            ],
+                        }],
+                        ..route_action("orders")
+                    },
+                    filter_overrides: Vec::new(),
+                }],
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        spec.validate().expect("descriptors validate");
+        let proto = route_config_to_proto("orders", &spec).expect("translate");
+        let Some(rt::route::Action::Route(route)) = proto.virtual_hosts[0].routes[0].action.clone()
+        else {
+            panic!("forward route");
+        };
+        let specifiers: Vec<_> = route.rate_limits[0]
+            .actions
+            .iter()
+            .map(|action| action.action_specifier.clone().expect("specifier"))
+            .collect();
+        assert_eq!(
+            specifiers[0],
+            rt::rate_limit::action::ActionSpecifier::RemoteAddress(
+                rt::rate_limit::action::RemoteAddress {}
+            )
+        );
+        let rt::rate_limit::action::ActionSpecifier::HeaderValueMatch(matched) = &specifiers[1]
+        else {
+            panic!("header_value_match action");
+        };
+        assert_eq!(matched.descriptor_key, "plan");
+        assert_eq!(matched.descriptor_value, "free");
+        assert_eq!(matched.expect_match, Some(wkt::BoolValue { value: false }));
+        assert_eq!(matched.headers.len(), 1);
+        assert_eq!(matched.headers[0].name, "x-plan");
     }
 
     #[test]
@@ -3742,7 +6676,9 @@ mod tests {
                         prefix: "/admin".into(),
                     },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: route_action("backend"),
                     filter_overrides: vec![
                         FilterOverride::Disable {
@@ -3756,6 +6692,7 @@ mod tests {
                                 fill_interval_ms: 1000,
                             },
                             status_code: Some(429),
+                            descriptors: Vec::new(),
                         }),
                     ],
                 }],
@@ -3874,6 +6811,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let ai = AiProcessorMetadata {
             team_id: uuid::Uuid::now_v7(),
@@ -3949,6 +6891,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
 
         let manager = hcm_of_named("ai-user-listener", &spec);
@@ -3972,6 +6919,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let ai = AiProcessorMetadata {
             team_id: uuid::Uuid::now_v7(),
@@ -4018,6 +6970,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
 
         let manager = hcm_of(&spec);
@@ -4045,6 +7002,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let proto = listener_to_proto("edge", &spec).expect("translate");
         let actual = match &proto.filter_chains[0].filters[0].config_type {
@@ -4094,6 +7056,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let ai = AiProcessorMetadata {
             team_id: uuid::Uuid::now_v7(),
@@ -4153,6 +7120,11 @@ mod tests {
             http_filters: Vec::new(),
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let capture = LearningCaptureInjection {
             session_id,
@@ -4330,6 +7302,7 @@ mod tests {
                         timeout_ms: 200,
                         failure_mode_allow: false,
                         include_peer_certificate: true,
+                        http_service: None,
                     }),
                     disabled: false,
                 },
@@ -4343,6 +7316,11 @@ mod tests {
             ],
             access_logs: Vec::new(),
             tls_context: None,
+            network_filters: Vec::new(),
+            tracing: None,
+            listener_filters: Vec::new(),
+            http_limits: None,
+            exact_balance: false,
         };
         let manager = hcm_of(&spec);
         let names: Vec<_> = manager
@@ -4391,6 +7369,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ext_authz_http_mode_emits_http_service_on_the_cluster() {
+        use envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3 as ea;
+        use fp_domain::gateway::filters::{ExtAuthzConfig, ExtAuthzHttpService};
+        let mut cfg = ExtAuthzConfig {
+            cluster: "authz".into(),
+            timeout_ms: 250,
+            failure_mode_allow: true,
+            include_peer_certificate: false,
+            http_service: Some(ExtAuthzHttpService {
+                uri: "http://authz.internal:9000/check".into(),
+                allowed_headers: vec!["x-user".into()],
+            }),
+        };
+        let proto = ext_authz_to_proto(&cfg);
+        assert!(proto.failure_mode_allow);
+        let allowed = proto.allowed_headers.expect("allowed headers");
+        assert_eq!(allowed.patterns.len(), 1);
+        assert!(allowed.patterns[0].ignore_case);
+        let Some(ea::ext_authz::Services::HttpService(http)) = proto.services else {
+            panic!("http service");
+        };
+        let server = http.server_uri.expect("server uri");
+        assert_eq!(
+            server.http_upstream_type,
+            Some(core::http_uri::HttpUpstreamType::Cluster("authz".into()))
+        );
+        assert_eq!(server.timeout, Some(millis_duration(250)));
+        assert_eq!(http.path_prefix, "/check");
+
+        // Without `http_service` the same cluster is called over gRPC.
+        cfg.http_service = None;
+        assert!(matches!(
+            ext_authz_to_proto(&cfg).services,
+            Some(ea::ext_authz::Services::GrpcService(_))
+        ));
+    }
+
     #[test]
     fn jwt_per_route_override_emits_reference_only_config() {
         use fp_domain::gateway::filters::*;
@@ -4405,7 +7421,9 @@ mod tests {
                         prefix: "/admin".into(),
                     },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: route_action("c"),
                     filter_overrides: vec![FilterOverride::JwtAuth {
                         requirement_name: "admins-only".into(),
@@ -4439,6 +7457,91 @@ mod tests {
             Some(envoy_types::pb::envoy::extensions::filters::http::jwt_authn::v3::per_route_config::RequirementSpecifier::RequirementName(n)) if n == "admins-only"
         ));
     }
+
+    #[test]
+    fn ext_authz_per_route_override_disables_or_adjusts_the_check() {
+        use envoy_types::pb::envoy::extensions::filters::http::ext_authz::v3 as ea;
+        use fp_domain::gateway::filters::*;
+        use fp_domain::gateway::route_config::{RouteRule, VirtualHost};
+        let rule = |name: &str, prefix: &str, ov: ExtAuthzOverride| RouteRule {
+            name: name.into(),
+            matcher: PathMatch::Prefix {
+                prefix: prefix.into(),
+            },
+            headers: Vec::new(),
+            methods: Vec::new(),
+            query_parameters: Vec::new(),
+            runtime_fraction: None,
+            action: route_action("c"),
+            filter_overrides: vec![FilterOverride::ExtAuthz(ov)],
+        };
+        let spec = RouteConfigSpec {
+            virtual_hosts: vec![VirtualHost {
+                name: "default".into(),
+                domains: vec!["*".into()],
+                routes: vec![
+                    rule(
+                        "health",
+                        "/healthz",
+                        ExtAuthzOverride {
+                            disabled: true,
+                            check_settings: None,
+                        },
+                    ),
+                    rule(
+                        "orders",
+                        "/orders",
+                        ExtAuthzOverride {
+                            disabled: false,
+                            check_settings: Some(ExtAuthzCheckSettings {
+                                context_extensions: std::collections::BTreeMap::from([
+                                    ("tier".to_string(), "gold".to_string()),
+                                    ("route".to_string(), "orders".to_string()),
+                                ]),
+                                disable_request_body_buffering: true,
+                            }),
+                        },
+                    ),
+                ],
+                rate_limits: Vec::new(),
+                filter_overrides: Vec::new(),
+            }],
+        };
+        let proto = route_config_to_proto("orders", &spec).expect("translate");
+        let per_route = |index: usize| {
+            let any = proto.virtual_hosts[0].routes[index]
+                .typed_per_filter_config
+                .get("envoy.filters.http.ext_authz")
+                .expect("ext_authz per-route");
+            assert_eq!(
+                any.type_url,
+                "type.googleapis.com/envoy.extensions.filters.http.ext_authz.v3.ExtAuthzPerRoute"
+            );
+            ea::ExtAuthzPerRoute::decode(any.value.as_slice()).expect("decode")
+        };
+        assert_eq!(
+            per_route(0).r#override,
+            Some(ea::ext_authz_per_route::Override::Disabled(true))
+        );
+        let Some(ea::ext_authz_per_route::Override::CheckSettings(settings)) =
+            per_route(1).r#override
+        else {
+            panic!("expected check_settings");
+        };
+        assert_eq!(settings.context_extensions.len(), 2);
+        assert_eq!(settings.context_extensions["tier"], "gold");
+        assert!(settings.disable_request_body_buffering);
+
+        // Multi-entry context maps still encode to identical bytes.
+        assert_eq!(
+            route_config_to_proto("orders", &spec)
+                .expect("a")
+                .encode_to_vec(),
+            route_config_to_proto("orders", &spec)
+                .expect("b")
+                .encode_to_vec(),
+        );
+    }
 }
 
 #[cfg(test)]
@@ -4457,6 +7560,16 @@ mod type_url_tests {
             template_rewrite: None,
             timeout_secs: 15,
             retry_policy: None,
+            retry_policy_ref: None,
+            idle_timeout_secs: None,
+            max_stream_duration_secs: None,
+            regex_rewrite: None,
+            host_rewrite_literal: None,
+            auto_host_rewrite: false,
+            fallback_cluster: None,
+            request_mirror_policies: Vec::new(),
+            request_headers_to_remove: Vec::new(),
+            hash_policy: Vec::new(),
             rate_limits: Vec::new(),
         }
     }
@@ -4475,7 +7588,9 @@ mod type_url_tests {
                         template: "/users/{id}".into(),
                     },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: route_action("c"),
                     filter_overrides: Vec::new(),
                 }],
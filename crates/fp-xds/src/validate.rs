@@ -0,0 +1,282 @@
+//! Optional pre-push check of generated config against a real Envoy binary
+//! (`envoy --mode validate`). The Rust proto types accept anything that encodes; this catches
+//! the version-specific issues (unknown extensions, removed fields, semantic checks) only the
+//! Envoy the fleet runs knows about. Enabled by `FLOWPLANE_ENVOY_VALIDATE_BINARY`.
+
+use crate::translate::{any, duration, explicit_http2_config, listener_to_proto, socket_address};
+use envoy_types::pb::envoy::config::bootstrap::v3 as bootstrap;
+use envoy_types::pb::envoy::config::cluster::v3 as exc;
+use envoy_types::pb::envoy::config::core::v3 as core;
+use envoy_types::pb::envoy::config::endpoint::v3 as ep;
+use envoy_types::pb::envoy::config::listener::v3 as lst;
+use envoy_types::pb::envoy::extensions::upstreams::http::v3 as upstream_http;
+use fp_domain::gateway::listener::ListenerSpec;
+use fp_domain::{DomainError, DomainResult};
+use prost::Message;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// How long one `envoy --mode validate` run may take before it is killed.
+pub const DEFAULT_VALIDATE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Validation runs allowed at once per control-plane replica; more are turned away.
+const MAX_CONCURRENT_VALIDATIONS: usize = 4;
+/// Envoy output beyond this many bytes is cut from the error returned to the caller.
+const MAX_REPORTED_OUTPUT: usize = 4096;
+/// The ADS cluster name the rendered bootstraps use; generated resources reference it.
+const XDS_CLUSTER: &str = "xds_cluster";
+
+/// Runs a configured `envoy` binary in validate mode. Nothing is started: Envoy parses the
+/// config, instantiates every extension, and exits.
+#[derive(Debug, Clone)]
+pub struct EnvoyValidator {
+    binary: PathBuf,
+    timeout: Duration,
+    permits: Arc<Semaphore>,
+}
+
+impl EnvoyValidator {
+    pub fn new(binary: impl Into<PathBuf>) -> Self {
+        Self {
+            binary: binary.into(),
+            timeout: DEFAULT_VALIDATE_TIMEOUT,
+            permits: Arc::new(Semaphore::new(MAX_CONCURRENT_VALIDATIONS)),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Validate a YAML bootstrap, e.g. the one the dataplane `envoy-config` endpoint renders.
+    pub async fn validate_bootstrap_yaml(&self, yaml: &str) -> DomainResult<()> {
+        self.run("yaml", yaml.as_bytes()).await
+    }
+
+    /// Translate a listener exactly as the xDS server would and validate it inside a minimal
+    /// bootstrap. Route configs, clusters and secrets it names arrive over ADS at runtime and
+    /// are not resolved here.
+    pub async fn validate_listener(&self, name: &str, spec: &ListenerSpec) -> DomainResult<()> {
+        let listener = listener_to_proto(name, spec)?;
+        self.run("pb", &listener_bootstrap(listener).encode_to_vec())
+            .await
+    }
+
+    /// Write `config` to a temp file (Envoy picks the format from the extension) and run
+    /// the binary against it.
+    async fn run(&self, extension: &str, config: &[u8]) -> DomainResult<()> {
+        let Ok(_permit) = self.permits.try_acquire() else {
+            return Err(
+                DomainError::unavailable("envoy validation is busy; retry shortly")
+                    .with_retry_after(1),
+            );
+        };
+        let path = std::env::temp_dir().join(format!(
+            "flowplane-envoy-validate-{}.{extension}",
+            uuid::Uuid::new_v4()
+        ));
+        tokio::fs::write(&path, config).await.map_err(|e| {
+            DomainError::internal(format!("write config for envoy validation: {e}"))
+        })?;
+        let outcome = self.exec(&path).await;
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            tracing::warn!(path = %path.display(), error = %e, "envoy validation temp file left behind");
+        }
+        metrics::counter!(
+            "fp_envoy_validations_total",
+            "outcome" => match &outcome {
+                Ok(()) => "valid",
+                Err(e) if e.code == fp_domain::ErrorCode::ValidationFailed => "rejected",
+                Err(_) => "error",
+            }
+        )
+        .increment(1);
+        outcome
+    }
+
+    async fn exec(&self, path: &Path) -> DomainResult<()> {
+        let mut command = tokio::process::Command::new(&self.binary);
+        command
+            .args(["--mode", "validate", "--log-level", "error", "-c"])
+            .arg(path)
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+        let output = match tokio::time::timeout(self.timeout, command.output()).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => {
+                return Err(DomainError::unavailable(format!(
+                    "cannot run envoy binary {}: {e}",
+                    self.binary.display()
+                )))
+            }
+            Err(_) => {
+                return Err(DomainError::unavailable(format!(
+                    "envoy validation did not finish within {} s",
+                    self.timeout.as_secs()
+                )))
+            }
+        };
+        if output.status.success() {
+            return Ok(());
+        }
+        let report = envoy_report(&output.stderr, &output.stdout, path);
+        Err(
+            DomainError::validation(format!("envoy rejected the config: {report}"))
+                .with_details(serde_json::json!({ "envoy_output": report })),
+        )
+    }
+}
+
+/// Envoy's diagnostics (stderr, else stdout), with the temp path elided and bounded in size.
+fn envoy_report(stderr: &[u8], stdout: &[u8], path: &Path) -> String {
+    let raw = if stderr.iter().any(|b| !b.is_ascii_whitespace()) {
+        stderr
+    } else {
+        stdout
+    };
+    let mut report = String::from_utf8_lossy(raw)
+        .replace(&path.display().to_string(), "<config>")
+        .trim()
+        .to_string();
+    if report.len() > MAX_REPORTED_OUTPUT {
+        let mut cut = MAX_REPORTED_OUTPUT;
+        while !report.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        report.truncate(cut);
+        report.push_str(" …");
+    }
+    if report.is_empty() {
+        report = "envoy exited with a failure status and no output".to_string();
+    }
+    report
+}
+
+/// The listener as a static resource, plus the ADS plumbing its `rds`/`sds` config sources
+/// point at, so Envoy accepts the references without fetching them.
+fn listener_bootstrap(listener: lst::Listener) -> bootstrap::Bootstrap {
+    let ads_cluster = exc::Cluster {
+        name: XDS_CLUSTER.to_string(),
+        connect_timeout: Some(duration(1)),
+        cluster_discovery_type: Some(exc::cluster::ClusterDiscoveryType::Type(
+            exc::cluster::DiscoveryType::Static as i32,
+        )),
+        load_assignment: Some(ep::ClusterLoadAssignment {
+            cluster_name: XDS_CLUSTER.to_string(),
+            endpoints: vec![ep::LocalityLbEndpoints {
+                lb_endpoints: vec![ep::LbEndpoint {
+                    host_identifier: Some(ep::lb_endpoint::HostIdentifier::Endpoint(
+                        ep::Endpoint {
+                            address: Some(socket_address("127.0.0.1", 18000)),
+                            ..Default::default()
+                        },
+                    )),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        typed_extension_protocol_options: std::iter::once((
+            "envoy.extensions.upstreams.http.v3.HttpProtocolOptions".to_string(),
+            any(
+                "type.googleapis.com/envoy.extensions.upstreams.http.v3.HttpProtocolOptions",
+                &upstream_http::HttpProtocolOptions {
+                    upstream_protocol_options: Some(explicit_http2_config()),
+                    ..Default::default()
+                },
+            ),
+        ))
+        .collect(),
+        ..Default::default()
+    };
+    bootstrap::Bootstrap {
+        node: Some(core::Node {
+            id: "flowplane-validate".to_string(),
+            cluster: "flowplane-validate".to_string(),
+            ..Default::default()
+        }),
+        static_resources: Some(bootstrap::bootstrap::StaticResources {
+            listeners: vec![listener],
+            clusters: vec![ads_cluster],
+            ..Default::default()
+        }),
+        dynamic_resources: Some(bootstrap::bootstrap::DynamicResources {
+            ads_config: Some(core::ApiConfigSource {
+                api_type: core::api_config_source::ApiType::Grpc as i32,
+                transport_api_version: core::ApiVersion::V3 as i32,
+                grpc_services: vec![core::GrpcService {
+                    target_specifier: Some(core::grpc_service::TargetSpecifier::EnvoyGrpc(
+                        core::grpc_service::EnvoyGrpc {
+                            cluster_name: XDS_CLUSTER.to_string(),
+                            ..Default::default()
+                        },
+                    )),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[cfg(all(test, unix))]
+#[allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    /// A stand-in `envoy` that rejects any config containing `reject-me`.
+    fn fake_envoy(dir: &Path) -> PathBuf {
+        let script = dir.join("envoy");
+        std::fs::write(
+            &script,
+            "#!/bin/sh\n\
+             while [ \"$1\" != \"-c\" ]; do shift; done\n\
+             if grep -q reject-me \"$2\"; then\n\
+               echo \"error initializing configuration '$2': Protobuf message has unknown fields\" >&2\n\
+               exit 1\n\
+             fi\n\
+             echo \"configuration '$2' OK\"\n",
+        )
+        .expect("write fake envoy");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod fake envoy");
+        script
+    }
+
+    #[tokio::test]
+    async fn envoy_verdict_and_diagnostics_reach_the_caller() {
+        let dir = std::env::temp_dir().join(format!("fp-envoy-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let validator = EnvoyValidator::new(fake_envoy(&dir));
+
+        validator
+            .validate_bootstrap_yaml("node:\n  id: ok\n")
+            .await
+            .expect("accepted config");
+        let err = validator
+            .validate_bootstrap_yaml("node:\n  id: reject-me\n")
+            .await
+            .expect_err("rejected config");
+        assert_eq!(err.code, fp_domain::ErrorCode::ValidationFailed);
+        assert!(
+            err.message
+                .contains("'<config>': Protobuf message has unknown fields"),
+            "{}",
+            err.message
+        );
+
+        let missing = EnvoyValidator::new(dir.join("absent"))
+            .validate_bootstrap_yaml("node: {}\n")
+            .await
+            .expect_err("missing binary");
+        assert_eq!(missing.code, fp_domain::ErrorCode::Unavailable);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -3,6 +3,7 @@
 
 #![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
 
+use envoy_types::pb::envoy::config::core::v3::node::UserAgentVersionType;
 use envoy_types::pb::envoy::config::core::v3::Node;
 use envoy_types::pb::envoy::service::discovery::v3::aggregated_discovery_service_client::AggregatedDiscoveryServiceClient;
 use envoy_types::pb::envoy::service::discovery::v3::DiscoveryRequest;
@@ -40,9 +41,11 @@ fn cluster_spec(host: &str) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -144,6 +147,10 @@ async fn subscribe_receive_ack_and_live_push() {
         .send(DiscoveryRequest {
             node: Some(Node {
                 id: format!("team={}/dp-test", team.id),
+                user_agent_name: "envoy".into(),
+                user_agent_version_type: Some(UserAgentVersionType::UserAgentVersion(
+                    "1.31.2".into(),
+                )),
                 ..Default::default()
             }),
             type_url: CLUSTER_TYPE_URL.to_string(),
@@ -208,6 +215,25 @@ async fn subscribe_receive_ack_and_live_push() {
     );
     assert_eq!(push.resources.len(), 1);
 
+    // The live inventory shows the stream with the version it ACKed, until it disconnects.
+    let connected = cache.connected_proxies().for_team(team.id);
+    assert_eq!(connected.len(), 1);
+    assert_eq!(connected[0].node_id, format!("team={}/dp-test", team.id));
+    assert_eq!(connected[0].envoy_version.as_deref(), Some("envoy/1.31.2"));
+    assert_eq!(
+        connected[0].acked_versions.get(CLUSTER_TYPE_URL),
+        Some(&first_version)
+    );
+    drop(req_tx);
+    drop(responses);
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !cache.connected_proxies().for_team(team.id).is_empty() {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("a closed stream leaves the inventory");
+
     let _ = stop_tx.send(());
     let _ = tokio::time::timeout(Duration::from_secs(5), server).await;
 }
@@ -505,7 +531,9 @@ async fn subscription_change_echoing_last_nonce_is_answered() {
                         prefix: "/".into(),
                     },
                     headers: Vec::new(),
+                    methods: Vec::new(),
                     query_parameters: Vec::new(),
+                    runtime_fraction: None,
                     action: fp_domain::gateway::route_config::RouteAction {
                         cluster: Some(upstream.clone()),
                         weighted_clusters: None,
@@ -515,6 +543,16 @@ async fn subscription_change_echoing_last_nonce_is_answered() {
                         template_rewrite: None,
                         timeout_secs: 15,
                         retry_policy: None,
+                        retry_policy_ref: None,
+                        idle_timeout_secs: None,
+                        max_stream_duration_secs: None,
+                        regex_rewrite: None,
+                        host_rewrite_literal: None,
+                        auto_host_rewrite: false,
+                        fallback_cluster: None,
+                        request_mirror_policies: Vec::new(),
+                        request_headers_to_remove: Vec::new(),
+                        hash_policy: Vec::new(),
                         rate_limits: Vec::new(),
                     },
                     filter_overrides: Vec::new(),
@@ -654,3 +692,343 @@ async fn stream_without_team_identity_is_rejected() {
     );
     let _ = stop_tx.send(());
 }
+
+#[tokio::test]
+async fn drain_keeps_streams_serving_through_grace_then_closes_them() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let team_row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: team_row.id,
+        org_id: org.id,
+    };
+    let user = identity::upsert_user_by_subject(&pool, &unique("sub"), "x@x.test", "X")
+        .await
+        .expect("u");
+    identity::add_org_membership(&pool, user, org.id, OrgRole::Admin)
+        .await
+        .expect("m");
+    let ctx = PrincipalCtx::User {
+        user_id: user,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    let cache = SnapshotCache::new();
+    let consumer = format!("ads-test-{}", unique("c"));
+    fp_storage::outbox::register_consumer_at_head(&pool, &consumer)
+        .await
+        .expect("register");
+    let process = |cache: Arc<SnapshotCache>, pool: sqlx::PgPool, consumer: String| async move {
+        while fp_storage::outbox::process_batch(&pool, &consumer, 1000, |events| {
+            let cache = cache.clone();
+            let pool = pool.clone();
+            async move { handle_events(&cache, &pool, events).await }
+        })
+        .await
+        .expect("process")
+            > 0
+        {}
+    };
+    let upstream = unique("upstream");
+    fp_core::services::clusters::create_cluster(
+        &pool,
+        &ctx,
+        team,
+        &upstream,
+        cluster_spec("10.0.0.1"),
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("cluster");
+    process(cache.clone(), pool.clone(), consumer.clone()).await;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    drop(listener);
+    let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+    let server_cache = cache.clone();
+    let server = tokio::spawn(async move {
+        fp_xds::server::serve_plaintext(
+            addr,
+            server_cache,
+            Arc::new(NodeIdTeamResolver),
+            None,
+            async {
+                let _ = stop_rx.await;
+            },
+        )
+        .await
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let mut client = AggregatedDiscoveryServiceClient::connect(format!("http://{addr}"))
+        .await
+        .expect("client connect");
+    let subscribe = || DiscoveryRequest {
+        node: Some(Node {
+            id: format!("team={}/dp-test", team.id),
+            ..Default::default()
+        }),
+        type_url: CLUSTER_TYPE_URL.to_string(),
+        ..Default::default()
+    };
+    let (req_tx, req_rx) = tokio::sync::mpsc::channel::<DiscoveryRequest>(8);
+    req_tx.send(subscribe()).await.expect("send subscribe");
+    let mut responses = client
+        .stream_aggregated_resources(tokio_stream::wrappers::ReceiverStream::new(req_rx))
+        .await
+        .expect("stream")
+        .into_inner();
+    let first = tokio::time::timeout(Duration::from_secs(5), responses.message())
+        .await
+        .expect("timely")
+        .expect("ok")
+        .expect("response");
+
+    // Shutdown begins: the open stream is still served during the grace period.
+    cache.begin_drain(Duration::from_secs(2));
+    let mut new_spec = cluster_spec("10.0.0.99");
+    new_spec.connect_timeout_secs = 9;
+    fp_core::services::clusters::update_cluster(
+        &pool,
+        &ctx,
+        team,
+        &upstream,
+        new_spec,
+        1,
+        RequestId::generate(),
+        Default::default(),
+    )
+    .await
+    .expect("update");
+    process(cache.clone(), pool.clone(), consumer.clone()).await;
+    let push = tokio::time::timeout(Duration::from_secs(5), responses.message())
+        .await
+        .expect("push timely")
+        .expect("ok")
+        .expect("push during grace");
+    assert_ne!(push.version_info, first.version_info);
+
+    // A stream opened after the drain started is refused.
+    let (late_tx, late_rx) = tokio::sync::mpsc::channel::<DiscoveryRequest>(2);
+    late_tx.send(subscribe()).await.expect("send");
+    let late = client
+        .stream_aggregated_resources(tokio_stream::wrappers::ReceiverStream::new(late_rx))
+        .await;
+    assert_eq!(
+        late.expect_err("new streams refused while draining").code(),
+        tonic::Code::Unavailable
+    );
+
+    // Once the grace elapses the stream closes with UNAVAILABLE so Envoy reconnects.
+    let closed = tokio::time::timeout(Duration::from_secs(5), responses.message())
+        .await
+        .expect("closed within the grace period");
+    assert_eq!(
+        closed.expect_err("stream closed").code(),
+        tonic::Code::Unavailable
+    );
+
+    let _ = stop_tx.send(());
+    let _ = tokio::time::timeout(Duration::from_secs(5), server).await;
+}
+
+#[tokio::test]
+async fn rollout_and_pins_hold_proxies_on_a_recorded_version_until_promoted() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let team_row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: team_row.id,
+        org_id: org.id,
+    };
+    let user = identity::upsert_user_by_subject(&pool, &unique("sub"), "x@x.test", "X")
+        .await
+        .expect("u");
+    identity::add_org_membership(&pool, user, org.id, OrgRole::Admin)
+        .await
+        .expect("m");
+    let ctx = PrincipalCtx::User {
+        user_id: user,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    let cache = SnapshotCache::new();
+    let consumer = format!("rollout-test-{}", unique("c"));
+    fp_storage::outbox::register_consumer_at_head(&pool, &consumer)
+        .await
+        .expect("register");
+    let drain = |cache: Arc<SnapshotCache>, pool: sqlx::PgPool, consumer: String| async move {
+        while fp_storage::outbox::process_batch(&pool, &consumer, 1000, |events| {
+            let cache = cache.clone();
+            let pool = pool.clone();
+            async move { handle_events(&cache, &pool, events).await }
+        })
+        .await
+        .expect("process")
+            > 0
+        {}
+    };
+    let create = |name: String| {
+        let (pool, ctx) = (pool.clone(), ctx.clone());
+        async move {
+            fp_core::services::clusters::create_cluster(
+                &pool,
+                &ctx,
+                team,
+                &name,
+                cluster_spec("10.0.0.1"),
+                RequestId::generate(),
+                Default::default(),
+            )
+            .await
+            .expect("cluster")
+        }
+    };
+    use fp_core::services::dataplanes as dp;
+
+    create(unique("first")).await;
+    drain(cache.clone(), pool.clone(), consumer.clone()).await;
+    let stable = cache.team(team.id).await.config_version;
+    let recorded = dp::list_config_versions(&pool, &ctx, team, RequestId::generate())
+        .await
+        .expect("versions");
+    assert_eq!(recorded[0].config_version, stable, "latest is recorded");
+
+    let edge = dp::create_dataplane(&pool, &ctx, team, "edge", "", RequestId::generate())
+        .await
+        .expect("dataplane");
+    let unknown = dp::pin_dataplane(
+        &pool,
+        &ctx,
+        team,
+        "edge",
+        "0000000000000000",
+        RequestId::generate(),
+    )
+    .await
+    .expect_err("only recorded versions can be held");
+    assert_eq!(unknown.code, fp_domain::ErrorCode::NotFound);
+    dp::pin_dataplane(&pool, &ctx, team, "edge", &stable, RequestId::generate())
+        .await
+        .expect("pin");
+    dp::set_config_rollout(&pool, &ctx, team, &stable, 0, RequestId::generate())
+        .await
+        .expect("rollout");
+    create(unique("second")).await;
+    drain(cache.clone(), pool.clone(), consumer.clone()).await;
+    assert_eq!(cache.team(team.id).await.clusters.resources.len(), 2);
+
+    // A dev-resolved stream has no dataplane row; the 0% rollout holds it on `stable`.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    drop(listener);
+    let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+    let server_cache = cache.clone();
+    let server = tokio::spawn(async move {
+        fp_xds::server::serve_plaintext(
+            addr,
+            server_cache,
+            Arc::new(NodeIdTeamResolver),
+            None,
+            async {
+                let _ = stop_rx.await;
+            },
+        )
+        .await
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let mut client = AggregatedDiscoveryServiceClient::connect(format!("http://{addr}"))
+        .await
+        .expect("client connect");
+    let (req_tx, req_rx) = tokio::sync::mpsc::channel::<DiscoveryRequest>(8);
+    req_tx
+        .send(DiscoveryRequest {
+            node: Some(Node {
+                id: format!("team={}/canary-test", team.id),
+                ..Default::default()
+            }),
+            type_url: CLUSTER_TYPE_URL.to_string(),
+            ..Default::default()
+        })
+        .await
+        .expect("send subscribe");
+    let mut responses = client
+        .stream_aggregated_resources(tokio_stream::wrappers::ReceiverStream::new(req_rx))
+        .await
+        .expect("stream")
+        .into_inner();
+    let held = tokio::time::timeout(Duration::from_secs(5), responses.message())
+        .await
+        .expect("timely")
+        .expect("ok")
+        .expect("response");
+    assert_eq!(held.version_info, stable);
+    assert_eq!(
+        held.resources.len(),
+        1,
+        "the held snapshot predates the change"
+    );
+    assert_eq!(
+        cache.connected_proxies().for_team(team.id)[0]
+            .config_version
+            .as_deref(),
+        Some(stable.as_str())
+    );
+
+    dp::promote_config_rollout(&pool, &ctx, team, RequestId::generate())
+        .await
+        .expect("promote rollout");
+    drain(cache.clone(), pool.clone(), consumer.clone()).await;
+    let promoted = tokio::time::timeout(Duration::from_secs(5), responses.message())
+        .await
+        .expect("push timely")
+        .expect("ok")
+        .expect("response");
+    assert_eq!(promoted.resources.len(), 2, "promotion pushes latest");
+
+    // The pinned dataplane outlives the rollout until it is promoted itself.
+    let pinned = cache.team_for(team.id, Some(edge.id), "edge-node").await;
+    assert!(pinned.held);
+    assert_eq!(pinned.clusters.resources.len(), 1);
+    dp::promote_dataplane(&pool, &ctx, team, "edge", RequestId::generate())
+        .await
+        .expect("promote dataplane");
+    drain(cache.clone(), pool.clone(), consumer.clone()).await;
+    let latest = cache.team_for(team.id, Some(edge.id), "edge-node").await;
+    assert!(!latest.held);
+    assert_eq!(latest.clusters.resources.len(), 2);
+
+    drop(req_tx);
+    let _ = stop_tx.send(());
+    let _ = tokio::time::timeout(Duration::from_secs(5), server).await;
+}
@@ -0,0 +1,203 @@
+//! Cluster stats end to end: a cluster-stats report shaped like the agent's, sent over the
+//! diagnostics stream, is what the cluster stats read returns for that dataplane.
+//!
+//! DB-backed; self-skips when `FLOWPLANE_TEST_DATABASE_URL` is unset.
+
+#![allow(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+use fp_core::services::clusters as cluster_svc;
+use fp_core::services::dataplanes::cluster_stats;
+use fp_core::{GrantSet, PrincipalCtx};
+use fp_domain::authz::TeamRef;
+use fp_domain::gateway::cluster::{ClusterSpec, Endpoint, LbPolicy};
+use fp_domain::{DataplaneId, OrgRole, RequestId, TeamId};
+use fp_storage::repos::{dataplanes, identity};
+use fp_xds::ads::{PeerIdentity, TeamResolver};
+use fp_xds::diagnostics::{
+    diagnostics_report, AckStatus, ClusterStatsReport, DiagnosticsReport,
+    EnvoyDiagnosticsServiceClient, UpstreamClusterGauges,
+};
+use fp_xds::snapshot::SnapshotCache;
+use std::sync::Arc;
+use std::time::Duration;
+use tonic::Status;
+
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}-{}",
+        &uuid::Uuid::now_v7().simple().to_string()[20..]
+    )
+}
+
+fn cluster_spec() -> ClusterSpec {
+    ClusterSpec {
+        aggregate_clusters: Vec::new(),
+        endpoints: vec![Endpoint {
+            host: "10.0.0.7".into(),
+            port: 8080,
+            weight: None,
+        }],
+        lb_policy: LbPolicy::RoundRobin,
+        least_request: None,
+        ring_hash: None,
+        maglev: None,
+        dns_lookup_family: None,
+        connect_timeout_secs: 5,
+        use_tls: false,
+        upstream_tls: None,
+        protocol: None,
+        use_downstream_protocol: false,
+        health_checks: None,
+        circuit_breakers: None,
+        outlier_detection: None,
+        connection_pool: None,
+    }
+}
+
+/// Stands in for the certificate registry: every stream is bound to one dataplane.
+struct BoundDataplane {
+    team_id: TeamId,
+    dataplane_id: DataplaneId,
+}
+
+#[tonic::async_trait]
+impl TeamResolver for BoundDataplane {
+    async fn resolve(
+        &self,
+        _node_id: &str,
+        _peer_spiffe: Option<&str>,
+    ) -> Result<PeerIdentity, Status> {
+        Ok(PeerIdentity {
+            team_id: self.team_id,
+            dataplane_id: Some(self.dataplane_id),
+            certificate_id: None,
+        })
+    }
+}
+
+#[tokio::test]
+async fn agent_cluster_stats_report_is_served_by_the_cluster_stats_read() {
+    let Ok(url) = std::env::var("FLOWPLANE_TEST_DATABASE_URL") else {
+        eprintln!("skipping: FLOWPLANE_TEST_DATABASE_URL not set");
+        return;
+    };
+    let pool = fp_storage::connect(&url, 8).await.expect("connect");
+    fp_storage::migrate(&pool).await.expect("migrate");
+
+    let org = identity::create_org(&pool, &unique("org"), "")
+        .await
+        .expect("org");
+    let row = identity::create_team(&pool, org.id, &unique("team"), "")
+        .await
+        .expect("team");
+    let team = TeamRef {
+        id: row.id,
+        org_id: org.id,
+    };
+    let user_id = identity::upsert_user_by_subject(&pool, &unique("sub"), "a@t.test", "A")
+        .await
+        .expect("user");
+    identity::add_org_membership(&pool, user_id, org.id, OrgRole::Admin)
+        .await
+        .expect("membership");
+    let admin = PrincipalCtx::User {
+        user_id,
+        platform_admin: false,
+        org_selector_required: false,
+        org: Some((org.id, OrgRole::Admin)),
+        grants: GrantSet::default(),
+    };
+    let rid = RequestId::generate();
+    cluster_svc::create_cluster(
+        &pool,
+        &admin,
+        team,
+        "orders",
+        cluster_spec(),
+        rid,
+        Default::default(),
+    )
+    .await
+    .expect("create cluster");
+    let mut tx = pool.begin().await.expect("begin");
+    let edge = dataplanes::create_dataplane(&mut tx, team, "edge-a", "")
+        .await
+        .expect("dataplane");
+    tx.commit().await.expect("commit");
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    drop(listener);
+    let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+    let resolver = Arc::new(BoundDataplane {
+        team_id: team.id,
+        dataplane_id: edge.id,
+    });
+    let server_pool = pool.clone();
+    let server = tokio::spawn(async move {
+        fp_xds::server::serve_plaintext(
+            addr,
+            SnapshotCache::new(),
+            resolver,
+            Some(server_pool),
+            async {
+                let _ = stop_rx.await;
+            },
+        )
+        .await
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let channel = tonic::transport::Endpoint::from_shared(format!("http://{addr}"))
+        .expect("endpoint")
+        .connect()
+        .await
+        .expect("client connect");
+    let mut client = EnvoyDiagnosticsServiceClient::new(channel);
+    let report = DiagnosticsReport {
+        schema_version: 1,
+        report_id: uuid::Uuid::now_v7().to_string(),
+        dataplane_id: edge.id.to_string(),
+        observed_at: None,
+        payload: Some(diagnostics_report::Payload::ClusterStats(
+            ClusterStatsReport {
+                clusters: vec![UpstreamClusterGauges {
+                    cluster_name: "orders".into(),
+                    rq_active: 3,
+                    rq_pending_active: 1,
+                    cx_active: 2,
+                    ejections_active: 0,
+                    membership_healthy: 1,
+                    membership_total: 1,
+                    open_circuit_breakers: vec!["default.rq_pending_open".into()],
+                }],
+            },
+        )),
+    };
+    let mut acks = client
+        .report_diagnostics(tokio_stream::iter(vec![report]))
+        .await
+        .expect("open stream")
+        .into_inner();
+    let ack = acks.message().await.expect("ack").expect("one ack");
+    assert_eq!(ack.status, AckStatus::Ok as i32, "{}", ack.message);
+
+    let stats = cluster_stats(&pool, &admin, team, "orders", rid)
+        .await
+        .expect("stats");
+    assert_eq!(stats.dataplanes.len(), 1);
+    let entry = &stats.dataplanes[0];
+    assert_eq!(entry.dataplane, "edge-a");
+    assert_eq!(entry.stats.rq_active, 3);
+    assert_eq!(entry.stats.rq_pending_active, 1);
+    assert_eq!(entry.stats.healthy_hosts, 1);
+    assert_eq!(
+        entry.stats.open_circuit_breakers,
+        vec!["default.rq_pending_open"]
+    );
+
+    let _ = stop_tx.send(());
+    let _ = server.await;
+}
@@ -45,9 +45,11 @@ fn cluster_spec(host: &str) -> ClusterSpec {
         use_tls: false,
         upstream_tls: None,
         protocol: None,
+        use_downstream_protocol: false,
         health_checks: None,
         circuit_breakers: None,
         outlier_detection: None,
+        connection_pool: None,
     }
 }
 
@@ -60,7 +62,9 @@ fn route_config_spec(cluster: &str) -> RouteConfigSpec {
                 name: "all".into(),
                 matcher: PathMatch::Prefix { prefix: "/".into() },
                 headers: Vec::new(),
+                methods: Vec::new(),
                 query_parameters: Vec::new(),
+                runtime_fraction: None,
                 action: RouteAction {
                     cluster: Some(cluster.into()),
                     weighted_clusters: None,
@@ -70,6 +74,16 @@ fn route_config_spec(cluster: &str) -> RouteConfigSpec {
                     template_rewrite: None,
                     timeout_secs: 15,
                     retry_policy: None,
+                    retry_policy_ref: None,
+                    idle_timeout_secs: None,
+                    max_stream_duration_secs: None,
+                    regex_rewrite: None,
+                    host_rewrite_literal: None,
+                    auto_host_rewrite: false,
+                    fallback_cluster: None,
+                    request_mirror_policies: Vec::new(),
+                    request_headers_to_remove: Vec::new(),
+                    hash_policy: Vec::new(),
                     rate_limits: Vec::new(),
                 },
                 filter_overrides: Vec::new(),
@@ -90,6 +104,11 @@ fn listener_spec(port: u16, route_config: &str) -> ListenerSpec {
         http_filters: Vec::new(),
         access_logs: Vec::new(),
         tls_context: None,
+        network_filters: Vec::new(),
+        tracing: None,
+        listener_filters: Vec::new(),
+        http_limits: None,
+        exact_balance: false,
     }
 }
 